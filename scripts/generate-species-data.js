@@ -71,6 +71,43 @@ function mapGenderRatio(gender) {
   return 'GenderRatio::F1M1';
 }
 
+// タイプ名を PokemonType 列挙型にマッピング (単タイプは同じタイプを2回格納)
+function mapTypes(types) {
+  const mapped = types.map((t) => `PokemonType::${t.charAt(0).toUpperCase()}${t.slice(1)}`);
+  return `[${mapped[0]}, ${mapped[1] ?? mapped[0]}]`;
+}
+
+// PokeAPI のタマゴグループ名を EggGroup 列挙型にマッピング
+const EGG_GROUP_VARIANTS = {
+  monster: 'Monster',
+  water1: 'Water1',
+  bug: 'Bug',
+  flying: 'Flying',
+  ground: 'Field',
+  fairy: 'Fairy',
+  plant: 'Grass',
+  humanshape: 'HumanLike',
+  water3: 'Water3',
+  mineral: 'Mineral',
+  indeterminate: 'Amorphous',
+  water2: 'Water2',
+  ditto: 'Ditto',
+  dragon: 'Dragon',
+  'no-eggs': 'Undiscovered',
+};
+
+// タマゴグループを EggGroup 列挙型にマッピング (単一グループは同じグループを2回格納)
+function mapEggGroups(eggGroups) {
+  const mapped = eggGroups.map((g) => {
+    const variant = EGG_GROUP_VARIANTS[g];
+    if (!variant) {
+      throw new Error(`Unknown egg group: ${g}`);
+    }
+    return `EggGroup::${variant}`;
+  });
+  return `[${mapped[0]}, ${mapped[1] ?? mapped[0]}]`;
+}
+
 // メイン処理
 function main() {
   console.log('Reading species data...');
@@ -141,6 +178,9 @@ function main() {
     SpeciesEntry {
         base_stats: BaseStats { hp: 0, attack: 0, defense: 0, special_attack: 0, special_defense: 0, speed: 0 },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [0, 0, 0],
     }`);
       speciesNamesJa.push(`"???"`);
@@ -169,6 +209,9 @@ function main() {
     SpeciesEntry {
         base_stats: BaseStats { hp: ${bs.hp}, attack: ${bs.attack}, defense: ${bs.defense}, special_attack: ${bs.specialAttack}, special_defense: ${bs.specialDefense}, speed: ${bs.speed} },
         gender_ratio: ${genderRatio},
+        types: ${mapTypes(species.types)},
+        egg_groups: ${mapEggGroups(species.eggGroups)},
+        evolves_from: ${species.evolvesFrom ?? 0},
         ability_ids: [${ability1Id}, ${ability2Id}, ${hiddenId}],
     }`);
 
@@ -191,7 +234,7 @@ function main() {
 //! このファイルは自動生成されています。直接編集しないでください。
 //! 生成コマンド: node scripts/generate-species-data.js
 
-use crate::types::GenderRatio;
+use crate::types::{EggGroup, GenderRatio, PokemonType};
 
 /// 種族値
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -209,10 +252,30 @@ pub struct BaseStats {
 pub struct SpeciesEntry {
     pub base_stats: BaseStats,
     pub gender_ratio: GenderRatio,
+    /// タイプ: [タイプ1, タイプ2] (単タイプは同じタイプを2回格納)
+    pub types: [PokemonType; 2],
+    /// タマゴグループ: [グループ1, グループ2] (単一グループは同じグループを2回格納)
+    pub egg_groups: [EggGroup; 2],
+    /// 進化前の種族 ID (0 = なし)
+    pub evolves_from: u16,
     /// 特性ID: [通常1, 通常2, 夢] (0 = なし)
     pub ability_ids: [u8; 3],
 }
 
+impl SpeciesEntry {
+    /// 指定タイプを持つか判定
+    #[inline]
+    pub fn has_type(&self, pokemon_type: PokemonType) -> bool {
+        self.types[0] == pokemon_type || self.types[1] == pokemon_type
+    }
+
+    /// 指定タマゴグループに属するか判定
+    #[inline]
+    pub fn has_egg_group(&self, egg_group: EggGroup) -> bool {
+        self.egg_groups[0] == egg_group || self.egg_groups[1] == egg_group
+    }
+}
+
 /// 種族テーブル (649件)
 pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
 ${speciesEntries.join(',\n')},
@@ -263,6 +326,36 @@ mod tests {
         assert_eq!(get_species_entry(488).base_stats.special_defense, 130);
     }
 
+    #[test]
+    fn test_get_species_entry_types() {
+        // 複合タイプ: レアコイル (でんき/はがね)
+        let magneton = get_species_entry(82);
+        assert_eq!(magneton.types, [PokemonType::Electric, PokemonType::Steel]);
+        assert!(magneton.has_type(PokemonType::Steel));
+        assert!(magneton.has_type(PokemonType::Electric));
+        // 単タイプ: ピカチュウ (でんき)
+        let pikachu = get_species_entry(25);
+        assert_eq!(pikachu.types, [PokemonType::Electric, PokemonType::Electric]);
+        assert!(!pikachu.has_type(PokemonType::Steel));
+        // 第5世代: フェアリータイプ導入前 (マリル: みず)
+        assert_eq!(get_species_entry(183).types, [PokemonType::Water, PokemonType::Water]);
+    }
+
+    #[test]
+    fn test_get_species_entry_breeding_data() {
+        // 複合グループ: フシギダネ (かいじゅう/しょくぶつ)
+        let bulbasaur = get_species_entry(1);
+        assert_eq!(bulbasaur.egg_groups, [EggGroup::Monster, EggGroup::Grass]);
+        assert!(bulbasaur.has_egg_group(EggGroup::Grass));
+        assert_eq!(bulbasaur.evolves_from, 0);
+        // 進化前: ピカチュウ ← ピチュー (タマゴみはっけん)
+        assert_eq!(get_species_entry(25).evolves_from, 172);
+        assert!(get_species_entry(172).has_egg_group(EggGroup::Undiscovered));
+        // 分岐進化: エーフィ ← イーブイ
+        assert_eq!(get_species_entry(196).evolves_from, 133);
+        assert_eq!(get_species_entry(132).egg_groups, [EggGroup::Ditto, EggGroup::Ditto]);
+    }
+
     #[test]
     fn test_get_species_entry_out_of_range() {
         // 0 と 650 はインデックス0にフォールバック
@@ -652,18 +745,32 @@ mod tests {
 //!
 //! このモジュールのファイルは自動生成されています。
 //! 生成コマンド: node scripts/generate-species-data.js
+//! エンカウントテーブル: node scripts/generate-encounter-data.js
 
 pub mod abilities;
+pub mod breeding;
+pub mod encounters;
 pub mod items;
 pub mod names;
 pub mod species;
+pub mod static_encounters;
 pub mod stats;
+pub mod timer0_defaults;
 
 pub use abilities::get_ability_name;
-pub use items::{get_held_item_entry, get_held_item_name, get_item_name, HeldItemEntry};
+pub use breeding::resolve_egg_species;
+pub use encounters::{
+    EncounterLocationEntry, EncounterSlotEntry, get_encounter_entry, get_encounter_slots,
+    get_seasonal_encounter_slots, list_encounter_locations, normalize_location_key,
+};
+pub use items::{HeldItemEntry, get_held_item_entry, get_held_item_name, get_item_name};
 pub use names::{get_nature_name, get_species_name};
-pub use species::{get_species_entry, BaseStats, SpeciesEntry};
-pub use stats::{calculate_stats, Stats};
+pub use species::{BaseStats, SpeciesEntry, get_species_entry};
+pub use static_encounters::{
+    StaticEncounterEntry, get_static_encounter, list_static_encounters,
+};
+pub use stats::{Stats, calculate_stats, derive_iv_ranges};
+pub use timer0_defaults::default_ranges;
 `;
 
   // ファイル書き出し
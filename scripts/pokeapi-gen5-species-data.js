@@ -259,6 +259,34 @@ function restoreGen5BaseStats(pokemon) {
   };
 }
 
+function restoreGen5Types(pokemon) {
+  let candidate = null;
+  for (const pastTypeGroup of pokemon.past_types ?? []) {
+    const number = generationNumber(pastTypeGroup.generation);
+    if (number < TARGET_GENERATION_NUMBER) {
+      continue;
+    }
+    if (!candidate || number < candidate.generation) {
+      candidate = {
+        generation: number,
+        types: pastTypeGroup.types,
+      };
+    }
+  }
+
+  return [...(candidate?.types ?? pokemon.types ?? [])]
+    .sort((a, b) => a.slot - b.slot)
+    .map((entry) => entry.type.name);
+}
+
+function speciesIdFromUrl(url) {
+  const match = /\/(\d+)\/?$/.exec(url);
+  if (!match) {
+    throw new Error(`Unsupported PokeAPI species url: ${url}`);
+  }
+  return Number(match[1]);
+}
+
 function requiredStat(values, key) {
   const value = values.get(key);
   if (value === undefined) {
@@ -314,6 +342,11 @@ export async function buildGen5SpeciesSnapshot(speciesId, client) {
     nationalDex: speciesId,
     names: localizedNames(pokemonSpecies, pokemonSpecies.name),
     gender: mapGender(pokemonSpecies.gender_rate),
+    types: restoreGen5Types(pokemon),
+    eggGroups: (pokemonSpecies.egg_groups ?? []).map((group) => group.name),
+    evolvesFrom: pokemonSpecies.evolves_from_species
+      ? speciesIdFromUrl(pokemonSpecies.evolves_from_species.url)
+      : null,
     baseStats: restoreGen5BaseStats(pokemon),
     abilities,
     heldItems,
@@ -356,6 +356,7 @@ const ENCOUNTER_METHOD_NAMES: Record<string, Record<SupportedLocale, string>> =
   ShakingGrass: { ja: '揺れる草むら', en: 'Shaking Grass' },
   DustCloud: { ja: '土煙', en: 'Dust Cloud' },
   PokemonShadow: { ja: 'ポケモンの影', en: 'Pokémon Shadow' },
+  DarkGrass: { ja: '濃い草むら', en: 'Dark Grass' },
   Surfing: { ja: 'なみのり', en: 'Surf' },
   SurfingBubble: { ja: 'なみのり(泡)', en: 'Rippling Surf' },
   Fishing: { ja: 'つり', en: 'Fishing' },
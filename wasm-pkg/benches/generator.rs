@@ -58,6 +58,7 @@ fn create_pokemon_params() -> PokemonGenerationParams {
             has_held_item: false,
             shiny_locked: false,
         }],
+        seasonal_slots: None,
     }
 }
 
@@ -55,6 +55,7 @@ fn create_context() -> DatetimeSearchContext {
         time_range: create_time_range(),
         ranges: vec![Timer0VCountRange::fixed(0x0C79, 0x5F)],
         key_spec: KeySpec::from_buttons(vec![]),
+        auto_ranges: false,
     }
 }
 
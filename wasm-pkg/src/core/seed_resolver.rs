@@ -8,6 +8,7 @@ use crate::core::sha1::{
     BaseMessageBuilder, build_date_code, build_time_code, calculate_pokemon_sha1, get_frame,
     get_nazo_values,
 };
+use crate::data::timer0_defaults::resolve_ranges;
 use crate::types::{LcgSeed, SeedOrigin, SeedSpec, StartupCondition};
 
 /// Seed 解決 (公開 API)
@@ -18,6 +19,7 @@ use crate::types::{LcgSeed, SeedOrigin, SeedSpec, StartupCondition};
 /// # Errors
 /// - `Seeds` が空の場合
 /// - `Startup` で `ranges` が空の場合
+/// - `Startup` で `auto_ranges` 指定時に既定範囲が未収集の場合
 #[wasm_bindgen]
 #[allow(clippy::needless_pass_by_value)]
 pub fn resolve_seeds(input: SeedSpec) -> Result<Vec<SeedOrigin>, String> {
@@ -33,6 +35,7 @@ pub fn resolve_seeds(input: SeedSpec) -> Result<Vec<SeedOrigin>, String> {
 /// # Errors
 /// - `Seeds` が空の場合
 /// - `Startup` で `ranges` が空の場合
+/// - `Startup` で `auto_ranges` 指定時に既定範囲が未収集の場合
 pub fn resolve_single_seed(input: &SeedSpec) -> Result<(LcgSeed, SeedOrigin), String> {
     match input {
         SeedSpec::Seeds { seeds } => {
@@ -44,7 +47,9 @@ pub fn resolve_single_seed(input: &SeedSpec) -> Result<(LcgSeed, SeedOrigin), St
             datetime,
             ranges,
             key_input,
+            auto_ranges,
         } => {
+            let ranges = resolve_ranges(ds, ranges, *auto_ranges)?;
             let range = ranges.first().ok_or("Startup ranges is empty")?;
             let timer0 = range.timer0_min;
             let vcount = range.vcount_min;
@@ -105,7 +110,9 @@ pub fn resolve_all_seeds(input: &SeedSpec) -> Result<Vec<(LcgSeed, SeedOrigin)>,
             datetime,
             ranges,
             key_input,
+            auto_ranges,
         } => {
+            let ranges = resolve_ranges(ds, ranges, *auto_ranges)?;
             if ranges.is_empty() {
                 return Err("Startup ranges is empty".into());
             }
@@ -127,7 +134,7 @@ pub fn resolve_all_seeds(input: &SeedSpec) -> Result<Vec<(LcgSeed, SeedOrigin)>,
 
             let mut results = Vec::new();
 
-            for range in ranges {
+            for range in &ranges {
                 for timer0 in range.timer0_min..=range.timer0_max {
                     for vcount in range.vcount_min..=range.vcount_max {
                         let condition = StartupCondition::new(timer0, vcount, key_mask);
@@ -186,6 +193,7 @@ mod tests {
             datetime: Datetime::new(2010, 9, 18, 18, 13, 11),
             ranges: vec![Timer0VCountRange::fixed(0x0C79, 0x60)],
             key_input: KeyInput::new(),
+            auto_ranges: false,
         };
         let result = resolve_single_seed(&input);
         assert!(result.is_ok());
@@ -193,6 +201,38 @@ mod tests {
         assert!(matches!(origin, SeedOrigin::Startup { .. }));
     }
 
+    #[test]
+    fn test_resolve_seeds_auto_ranges() {
+        let ds = DsConfig {
+            mac: [0x00, 0x09, 0xBF, 0x12, 0x34, 0x56],
+            hardware: Hardware::DsLite,
+            version: RomVersion::Black,
+            region: RomRegion::Jpn,
+        };
+        let input = SeedSpec::Startup {
+            ds: ds.clone(),
+            datetime: Datetime::new(2010, 9, 18, 18, 13, 11),
+            ranges: vec![],
+            key_input: KeyInput::new(),
+            auto_ranges: true,
+        };
+        // Black JPN (DS Lite) の既定範囲: Timer0 0x0C79-0x0C7A, VCount 0x60
+        assert_eq!(resolve_seeds(input).unwrap().len(), 2);
+
+        let input = SeedSpec::Startup {
+            ds: DsConfig {
+                hardware: Hardware::Dsi,
+                region: RomRegion::Usa,
+                ..ds
+            },
+            datetime: Datetime::new(2010, 9, 18, 18, 13, 11),
+            ranges: vec![],
+            key_input: KeyInput::new(),
+            auto_ranges: true,
+        };
+        assert!(resolve_seeds(input).is_err());
+    }
+
     #[test]
     fn test_resolve_all_seeds() {
         let input = SeedSpec::Seeds {
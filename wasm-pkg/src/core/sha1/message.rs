@@ -6,8 +6,8 @@ use crate::types::{Hardware, RomVersion};
 
 use super::nazo::NazoValues;
 
-/// `GX_STAT` 固定値
-const GX_STAT: u32 = 0x0600_0000;
+/// `GX_STAT` 既定値
+pub const GX_STAT: u32 = 0x0600_0000;
 
 /// 32bit 値のバイトスワップ (エンディアン変換)
 #[inline]
@@ -104,19 +104,19 @@ pub const fn get_frame(hardware: Hardware, version: RomVersion) -> u8 {
 /// MAC アドレスから message[6], message[7] を構築
 ///
 /// - message[6]: MAC 下位 16bit (エンディアン変換なし)
-/// - message[7]: MAC 上位 32bit XOR `GX_STAT` XOR frame (エンディアン変換あり)
-fn build_mac_words(mac: [u8; 6], frame: u8) -> (u32, u32) {
+/// - message[7]: MAC 上位 32bit XOR `gx_stat` XOR frame (エンディアン変換あり)
+fn build_mac_words(mac: [u8; 6], gx_stat: u32, frame: u8) -> (u32, u32) {
     // message[6]: MAC 下位 16bit (mac[4], mac[5]) - エンディアン変換なし
     let mac_lower = (u32::from(mac[4]) << 8) | u32::from(mac[5]);
 
-    // message[7]: MAC 上位 32bit (mac[0-3] as little-endian) XOR GX_STAT XOR frame
+    // message[7]: MAC 上位 32bit (mac[0-3] as little-endian) XOR gx_stat XOR frame
     let mac_upper = u32::from(mac[0])
         | (u32::from(mac[1]) << 8)
         | (u32::from(mac[2]) << 16)
         | (u32::from(mac[3]) << 24);
 
     // エンディアン変換を適用
-    let word7 = swap_bytes_32(mac_upper ^ GX_STAT ^ u32::from(frame));
+    let word7 = swap_bytes_32(mac_upper ^ gx_stat ^ u32::from(frame));
 
     (mac_lower, word7)
 }
@@ -137,6 +137,21 @@ impl BaseMessageBuilder {
         timer0: u16,
         key_code: KeyCode,
         frame: u8,
+    ) -> Self {
+        Self::with_gx_stat(nazo, mac, vcount, timer0, key_code, GX_STAT, frame)
+    }
+
+    /// `GX_STAT` を指定してビルダーを作成
+    ///
+    /// 本体個体差の較正用。通常は `new` (既定値 `GX_STAT`) を使用する。
+    pub(crate) fn with_gx_stat(
+        nazo: &NazoValues,
+        mac: [u8; 6],
+        vcount: u8,
+        timer0: u16,
+        key_code: KeyCode,
+        gx_stat: u32,
+        frame: u8,
     ) -> Self {
         let mut buffer = [0u32; 16];
 
@@ -149,7 +164,7 @@ impl BaseMessageBuilder {
         buffer[5] = swap_bytes_32((u32::from(vcount) << 16) | u32::from(timer0));
 
         // MAC アドレス
-        let (mac_lower, mac_word7) = build_mac_words(mac, frame);
+        let (mac_lower, mac_word7) = build_mac_words(mac, gx_stat, frame);
         buffer[6] = mac_lower;
         buffer[7] = mac_word7;
 
@@ -7,7 +7,7 @@ mod nazo;
 mod scalar;
 mod simd;
 
-pub use message::{BaseMessageBuilder, build_date_code, build_time_code, get_frame};
+pub use message::{BaseMessageBuilder, GX_STAT, build_date_code, build_time_code, get_frame};
 pub use nazo::{NazoValues, get_nazo_values};
 pub use scalar::calculate_pokemon_sha1;
 pub use simd::calculate_pokemon_sha1_simd;
@@ -0,0 +1,287 @@
+//! 孵化種族決定
+//!
+//! 両親の種族から孵化個体の種族を決定する。
+//! タマゴグループ・進化前の種族は `species` テーブルを参照し、
+//! おこうが必要なベビィポケモンのみ本モジュールで定義する。
+
+use crate::types::{BreedingParents, EggGroup, EggSpeciesResolution, GenderRatio};
+
+use super::species::{SpeciesEntry, get_species_entry};
+
+/// メタモン
+const DITTO: u16 = 132;
+/// ニドラン♀
+const NIDORAN_F: u16 = 29;
+/// ニドラン♂
+const NIDORAN_M: u16 = 32;
+/// バルビート
+const VOLBEAT: u16 = 313;
+/// イルミーゼ
+const ILLUMISE: u16 = 314;
+/// マナフィ
+const MANAPHY: u16 = 490;
+/// フィオネ
+const PHIONE: u16 = 489;
+
+/// おこうが必要なベビィポケモン
+struct IncenseBaby {
+    /// ベビィポケモンの種族 ID
+    baby: u16,
+    /// おこうなしで孵化する種族 ID
+    without_incense: u16,
+}
+
+/// おこうが必要なベビィポケモン一覧
+const INCENSE_BABIES: [IncenseBaby; 9] = [
+    // うしおのおこう: ルリリ / マリル
+    IncenseBaby {
+        baby: 298,
+        without_incense: 183,
+    },
+    // のんきのおこう: ソーナノ / ソーナンス
+    IncenseBaby {
+        baby: 360,
+        without_incense: 202,
+    },
+    // おはなのおこう: スボミー / ロゼリア
+    IncenseBaby {
+        baby: 406,
+        without_incense: 315,
+    },
+    // きよめのおこう: リーシャン / チリーン
+    IncenseBaby {
+        baby: 433,
+        without_incense: 358,
+    },
+    // がんせきおこう: ウソハチ / ウソッキー
+    IncenseBaby {
+        baby: 438,
+        without_incense: 185,
+    },
+    // あやしいおこう: マネネ / バリヤード
+    IncenseBaby {
+        baby: 439,
+        without_incense: 122,
+    },
+    // こううんのおこう: ピンプク / ラッキー
+    IncenseBaby {
+        baby: 440,
+        without_incense: 113,
+    },
+    // まんぷくおこう: ゴンベ / カビゴン
+    IncenseBaby {
+        baby: 446,
+        without_incense: 143,
+    },
+    // さざなみのおこう: タマンタ / マンタイン
+    IncenseBaby {
+        baby: 458,
+        without_incense: 226,
+    },
+];
+
+/// 両親の種族から孵化個体の種族を決定
+///
+/// - メタモンとの組み合わせでは相手側、それ以外はメス親の種族を基準にする
+/// - 基準種族の最も進化前の種族が孵化する (マナフィはフィオネ)
+/// - おこうが必要なベビィポケモンは、おこうなしの場合 1 段階進化した種族になる
+/// - ニドラン♂ 系統 / バルビートは性別で種族が分かれるため、
+///   ニドラン♀ / イルミーゼとして `nidoran_flag` を立てる (♂ の場合は生成時に変換)
+///
+/// # Errors
+/// 種族 ID が範囲外、またはタマゴを作れない組み合わせの場合にエラーを返す。
+pub fn resolve_egg_species(parents: &BreedingParents) -> Result<EggSpeciesResolution, String> {
+    for species_id in [parents.male_species, parents.female_species] {
+        if species_id == 0 || species_id > 649 {
+            return Err(format!("Invalid species id: {species_id}"));
+        }
+    }
+
+    let male = get_species_entry(parents.male_species);
+    let female = get_species_entry(parents.female_species);
+    if male.has_egg_group(EggGroup::Undiscovered) || female.has_egg_group(EggGroup::Undiscovered) {
+        return Err("Parent in Undiscovered egg group cannot breed".into());
+    }
+
+    let male_is_ditto = parents.male_species == DITTO;
+    let female_is_ditto = parents.female_species == DITTO;
+    // 性別不明はメタモンとの組み合わせに限りどちらの親にも指定できる
+    if !male_is_ditto
+        && (male.gender_ratio == GenderRatio::FemaleOnly
+            || (!female_is_ditto && male.gender_ratio == GenderRatio::Genderless))
+    {
+        return Err("Male parent cannot be male".into());
+    }
+    if !female_is_ditto
+        && (female.gender_ratio == GenderRatio::MaleOnly
+            || (!male_is_ditto && female.gender_ratio == GenderRatio::Genderless))
+    {
+        return Err("Female parent cannot be female".into());
+    }
+
+    let base_species = match (male_is_ditto, female_is_ditto) {
+        (true, true) => return Err("Ditto cannot breed with Ditto".into()),
+        (true, false) => parents.female_species,
+        (false, true) => parents.male_species,
+        (false, false) => {
+            if !shares_egg_group(male, female) {
+                return Err("Parents share no egg group".into());
+            }
+            parents.female_species
+        }
+    };
+
+    let species_id = offspring_species(base_species, parents.incense);
+    Ok(EggSpeciesResolution {
+        species_id,
+        gender_ratio: get_species_entry(species_id).gender_ratio,
+        nidoran_flag: matches!(species_id, NIDORAN_F | ILLUMISE),
+        uses_ditto: male_is_ditto || female_is_ditto,
+    })
+}
+
+/// 共通のタマゴグループを持つか判定
+fn shares_egg_group(a: &SpeciesEntry, b: &SpeciesEntry) -> bool {
+    a.egg_groups.iter().any(|&group| b.has_egg_group(group))
+}
+
+/// 基準種族から孵化する種族を決定
+fn offspring_species(base_species: u16, incense: bool) -> u16 {
+    if base_species == MANAPHY {
+        return PHIONE;
+    }
+
+    let mut species_id = base_species;
+    loop {
+        let evolves_from = get_species_entry(species_id).evolves_from;
+        if evolves_from == 0 {
+            break;
+        }
+        species_id = evolves_from;
+    }
+
+    // 性別で種族が分かれる系統は ♀ 側の種族に統一
+    match species_id {
+        NIDORAN_M => return NIDORAN_F,
+        VOLBEAT => return ILLUMISE,
+        _ => {}
+    }
+
+    match INCENSE_BABIES.iter().find(|entry| entry.baby == species_id) {
+        Some(entry) if !incense => entry.without_incense,
+        _ => species_id,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parents(male_species: u16, female_species: u16) -> BreedingParents {
+        BreedingParents {
+            male_species,
+            female_species,
+            incense: false,
+        }
+    }
+
+    #[test]
+    fn test_resolve_from_female_family_base() {
+        // リザードン♂ × フシギバナ♀ → フシギダネ
+        let result = resolve_egg_species(&parents(6, 3)).unwrap();
+        assert_eq!(result.species_id, 1);
+        assert_eq!(result.gender_ratio, GenderRatio::F1M7);
+        assert!(!result.nidoran_flag);
+        assert!(!result.uses_ditto);
+
+        // ベビィポケモン: ライチュウ♀ → ピチュー (おこう不要)
+        assert_eq!(
+            resolve_egg_species(&parents(26, 26)).unwrap().species_id,
+            172
+        );
+    }
+
+    #[test]
+    fn test_resolve_with_ditto() {
+        // メタモン × 性別不明 (ジバコイル) → コイル
+        let result = resolve_egg_species(&parents(132, 462)).unwrap();
+        assert_eq!(result.species_id, 81);
+        assert_eq!(result.gender_ratio, GenderRatio::Genderless);
+        assert!(result.uses_ditto);
+
+        // オス親 × メタモン → オス親の系統
+        assert_eq!(
+            resolve_egg_species(&parents(128, 132)).unwrap().species_id,
+            128
+        );
+
+        assert!(resolve_egg_species(&parents(132, 132)).is_err());
+    }
+
+    #[test]
+    fn test_resolve_incense_babies() {
+        // マリル♀: おこうなし → マリル、うしおのおこう → ルリリ
+        assert_eq!(
+            resolve_egg_species(&parents(184, 184)).unwrap().species_id,
+            183
+        );
+        let with_incense = BreedingParents {
+            incense: true,
+            ..parents(184, 184)
+        };
+        assert_eq!(resolve_egg_species(&with_incense).unwrap().species_id, 298);
+
+        // カビゴン × メタモン: おこうなし → カビゴン
+        assert_eq!(
+            resolve_egg_species(&parents(143, 132)).unwrap().species_id,
+            143
+        );
+    }
+
+    #[test]
+    fn test_resolve_gender_families() {
+        // ニドキング × ニドラン♀ → ニドラン♀ (性別で種族が分かれる)
+        let result = resolve_egg_species(&parents(34, 29)).unwrap();
+        assert_eq!(result.species_id, 29);
+        assert!(result.nidoran_flag);
+
+        // バルビート × イルミーゼ → イルミーゼ
+        let result = resolve_egg_species(&parents(313, 314)).unwrap();
+        assert_eq!(result.species_id, 314);
+        assert!(result.nidoran_flag);
+
+        // ♂ のみの系統 × メタモン → 両性別の系統 (ニドラン♀ / イルミーゼ扱い)
+        for (male, expected) in [(32, 29), (34, 29), (313, 314)] {
+            let result = resolve_egg_species(&parents(male, 132)).unwrap();
+            assert_eq!(result.species_id, expected);
+            assert_eq!(result.gender_ratio, GenderRatio::FemaleOnly);
+            assert!(result.nidoran_flag);
+            assert!(result.uses_ditto);
+        }
+
+        // マナフィ × メタモン → フィオネ
+        assert_eq!(
+            resolve_egg_species(&parents(132, 490)).unwrap().species_id,
+            489
+        );
+    }
+
+    #[test]
+    fn test_resolve_incompatible_pairs() {
+        // タマゴグループが異なる: フシギダネ (かいじゅう/しょくぶつ) × ポッポ (ひこう)
+        assert!(resolve_egg_species(&parents(1, 16)).is_err());
+        // タマゴみはっけん: ニドクイン / ピチュー
+        assert!(resolve_egg_species(&parents(34, 31)).is_err());
+        assert!(resolve_egg_species(&parents(132, 172)).is_err());
+        // 性別の不一致: ケンタロス (オスのみ) をメス親に指定
+        assert!(resolve_egg_species(&parents(128, 128)).is_err());
+        // 性別の不一致 (メタモンとの組み合わせ):
+        // ラッキー (メスのみ) をオス親に、ケンタロス (オスのみ) をメス親に指定
+        assert!(resolve_egg_species(&parents(113, 132)).is_err());
+        assert!(resolve_egg_species(&parents(132, 128)).is_err());
+        // 性別不明同士
+        assert!(resolve_egg_species(&parents(81, 81)).is_err());
+        // 範囲外
+        assert!(resolve_egg_species(&parents(0, 1)).is_err());
+    }
+}
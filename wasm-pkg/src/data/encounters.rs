@@ -0,0 +1,21831 @@
+//! エンカウントテーブル
+//!
+//! このファイルは自動生成されています。直接編集しないでください。
+//! 生成コマンド: node scripts/generate-encounter-data.js
+
+use crate::types::{EncounterSlotConfig, EncounterType, GenderRatio, RomVersion, SeasonalSlots};
+
+use EncounterType::{
+    DustCloud, Fishing, FishingBubble, Normal, PokemonShadow, ShakingGrass, Surfing, SurfingBubble,
+};
+use GenderRatio::{F1M1, F1M3, F1M7, F3M1, FemaleOnly, Genderless, MaleOnly};
+use RomVersion::{Black, Black2, White, White2};
+
+/// エンカウントスロットエントリ
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EncounterSlotEntry {
+    /// ポケモン種族 ID
+    pub species_id: u16,
+    /// 出現率 (%)
+    pub rate: u8,
+    /// 最小レベル
+    pub level_min: u8,
+    /// 最大レベル
+    pub level_max: u8,
+    /// 性別比
+    pub gender_ratio: GenderRatio,
+    /// 所持アイテムあり
+    pub has_held_item: bool,
+}
+
+impl EncounterSlotEntry {
+    /// 生成パラメータ用のスロット設定に変換
+    pub const fn to_config(self) -> EncounterSlotConfig {
+        EncounterSlotConfig {
+            species_id: self.species_id,
+            level_min: self.level_min,
+            level_max: self.level_max,
+            gender_ratio: self.gender_ratio,
+            has_held_item: self.has_held_item,
+            shiny_locked: false,
+        }
+    }
+}
+
+/// ロケーション単位のエンカウントテーブル
+#[derive(Clone, Copy, Debug)]
+pub struct EncounterLocationEntry {
+    pub version: RomVersion,
+    pub encounter_type: EncounterType,
+    /// 正規化済みロケーションキー
+    pub location_key: &'static str,
+    /// スロット (出現率テーブルのスロット順)
+    pub slots: &'static [EncounterSlotEntry],
+}
+
+const fn slot(
+    species_id: u16,
+    rate: u8,
+    level_min: u8,
+    level_max: u8,
+    gender_ratio: GenderRatio,
+    has_held_item: bool,
+) -> EncounterSlotEntry {
+    EncounterSlotEntry {
+        species_id,
+        rate,
+        level_min,
+        level_max,
+        gender_ratio,
+        has_held_item,
+    }
+}
+
+/// エンカウントテーブル (1444件)
+pub static ENCOUNTER_TABLE: [EncounterLocationEntry; 1444] = [
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "icirrus_city_spring",
+        slots: &[
+            slot(536, 20, 30, 30, F1M1, true),
+            slot(616, 20, 30, 30, F1M1, false),
+            slot(536, 10, 31, 31, F1M1, true),
+            slot(616, 10, 31, 31, F1M1, false),
+            slot(618, 10, 31, 31, F1M1, true),
+            slot(618, 10, 32, 32, F1M1, true),
+            slot(536, 5, 32, 32, F1M1, true),
+            slot(616, 5, 32, 32, F1M1, false),
+            slot(536, 4, 33, 33, F1M1, true),
+            slot(616, 4, 33, 33, F1M1, false),
+            slot(536, 1, 33, 33, F1M1, true),
+            slot(616, 1, 33, 33, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "icirrus_city_summer",
+        slots: &[
+            slot(536, 20, 30, 30, F1M1, true),
+            slot(616, 20, 30, 30, F1M1, false),
+            slot(536, 10, 31, 31, F1M1, true),
+            slot(616, 10, 31, 31, F1M1, false),
+            slot(618, 10, 31, 31, F1M1, true),
+            slot(618, 10, 32, 32, F1M1, true),
+            slot(536, 5, 32, 32, F1M1, true),
+            slot(616, 5, 32, 32, F1M1, false),
+            slot(536, 4, 33, 33, F1M1, true),
+            slot(616, 4, 33, 33, F1M1, false),
+            slot(536, 1, 33, 33, F1M1, true),
+            slot(616, 1, 33, 33, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "icirrus_city_autumn",
+        slots: &[
+            slot(536, 20, 30, 30, F1M1, true),
+            slot(616, 20, 30, 30, F1M1, false),
+            slot(536, 10, 31, 31, F1M1, true),
+            slot(616, 10, 31, 31, F1M1, false),
+            slot(618, 10, 31, 31, F1M1, true),
+            slot(618, 10, 32, 32, F1M1, true),
+            slot(536, 5, 32, 32, F1M1, true),
+            slot(616, 5, 32, 32, F1M1, false),
+            slot(536, 4, 33, 33, F1M1, true),
+            slot(616, 4, 33, 33, F1M1, false),
+            slot(536, 1, 33, 33, F1M1, true),
+            slot(616, 1, 33, 33, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "dreamyard",
+        slots: &[
+            slot(504, 20, 8, 8, F1M1, false),
+            slot(509, 20, 8, 8, F1M1, false),
+            slot(504, 10, 9, 9, F1M1, false),
+            slot(509, 10, 9, 9, F1M1, false),
+            slot(517, 10, 8, 8, F1M1, false),
+            slot(517, 10, 10, 10, F1M1, false),
+            slot(504, 5, 10, 10, F1M1, false),
+            slot(509, 5, 10, 10, F1M1, false),
+            slot(504, 4, 11, 11, F1M1, false),
+            slot(509, 4, 11, 11, F1M1, false),
+            slot(504, 1, 11, 11, F1M1, false),
+            slot(509, 1, 11, 11, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "pinwheel_forest_exterior",
+        slots: &[
+            slot(535, 20, 12, 12, F1M1, true),
+            slot(519, 20, 12, 12, F1M1, false),
+            slot(535, 10, 13, 13, F1M1, true),
+            slot(519, 10, 13, 13, F1M1, false),
+            slot(532, 10, 13, 13, F1M3, false),
+            slot(532, 10, 14, 14, F1M3, false),
+            slot(535, 5, 14, 14, F1M1, true),
+            slot(539, 5, 12, 12, MaleOnly, true),
+            slot(535, 4, 15, 15, F1M1, true),
+            slot(539, 4, 15, 15, MaleOnly, true),
+            slot(535, 1, 15, 15, F1M1, true),
+            slot(539, 1, 15, 15, MaleOnly, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "pinwheel_forest_interior",
+        slots: &[
+            slot(546, 20, 14, 14, F1M1, false),
+            slot(540, 20, 14, 14, F1M1, true),
+            slot(546, 10, 15, 15, F1M1, false),
+            slot(540, 10, 15, 15, F1M1, true),
+            slot(519, 10, 15, 15, F1M1, false),
+            slot(543, 10, 15, 15, F1M1, true),
+            slot(519, 5, 16, 16, F1M1, false),
+            slot(543, 5, 16, 16, F1M1, true),
+            slot(546, 4, 16, 16, F1M1, false),
+            slot(540, 4, 16, 16, F1M1, true),
+            slot(546, 1, 17, 17, F1M1, false),
+            slot(540, 1, 17, 17, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "desert_resort_exterior",
+        slots: &[
+            slot(551, 20, 19, 19, F1M1, false),
+            slot(554, 20, 19, 19, F1M1, true),
+            slot(551, 10, 20, 20, F1M1, false),
+            slot(554, 10, 20, 20, F1M1, true),
+            slot(556, 10, 20, 20, F1M1, true),
+            slot(559, 10, 20, 20, F1M1, true),
+            slot(551, 5, 21, 21, F1M1, false),
+            slot(557, 5, 20, 20, F1M1, true),
+            slot(551, 4, 22, 22, F1M1, false),
+            slot(557, 4, 22, 22, F1M1, true),
+            slot(551, 1, 22, 22, F1M1, false),
+            slot(557, 1, 22, 22, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "desert_resort_interior",
+        slots: &[
+            slot(551, 20, 19, 19, F1M1, false),
+            slot(554, 20, 19, 19, F1M1, true),
+            slot(551, 10, 20, 20, F1M1, false),
+            slot(554, 10, 20, 20, F1M1, true),
+            slot(556, 10, 20, 20, F1M1, true),
+            slot(561, 10, 20, 20, F1M1, false),
+            slot(551, 5, 21, 21, F1M1, false),
+            slot(557, 5, 20, 20, F1M1, true),
+            slot(551, 4, 22, 22, F1M1, false),
+            slot(557, 4, 22, 22, F1M1, true),
+            slot(551, 1, 22, 22, F1M1, false),
+            slot(557, 1, 22, 22, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "relic_castle_1f_b1f",
+        slots: &[
+            slot(551, 20, 19, 19, F1M1, false),
+            slot(562, 20, 19, 19, F1M1, true),
+            slot(551, 10, 20, 20, F1M1, false),
+            slot(562, 10, 20, 20, F1M1, true),
+            slot(551, 10, 21, 21, F1M1, false),
+            slot(562, 10, 21, 21, F1M1, true),
+            slot(551, 5, 21, 21, F1M1, false),
+            slot(562, 5, 21, 21, F1M1, true),
+            slot(551, 4, 22, 22, F1M1, false),
+            slot(562, 4, 22, 22, F1M1, true),
+            slot(551, 1, 22, 22, F1M1, false),
+            slot(562, 1, 22, 22, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "relic_castle_b2f_b6f",
+        slots: &[
+            slot(552, 20, 34, 34, F1M1, false),
+            slot(563, 20, 34, 34, F1M1, true),
+            slot(552, 10, 35, 35, F1M1, false),
+            slot(563, 10, 35, 35, F1M1, true),
+            slot(552, 10, 36, 36, F1M1, false),
+            slot(563, 10, 36, 36, F1M1, true),
+            slot(552, 5, 36, 36, F1M1, false),
+            slot(563, 5, 36, 36, F1M1, true),
+            slot(552, 4, 37, 37, F1M1, false),
+            slot(563, 4, 37, 37, F1M1, true),
+            slot(552, 1, 37, 37, F1M1, false),
+            slot(563, 1, 37, 37, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "relic_castle_lowest_floor",
+        slots: &[
+            slot(552, 20, 47, 47, F1M1, false),
+            slot(28, 20, 47, 47, F1M1, true),
+            slot(563, 10, 48, 48, F1M1, true),
+            slot(95, 10, 48, 48, F1M1, false),
+            slot(552, 10, 48, 48, F1M1, false),
+            slot(28, 10, 49, 49, F1M1, true),
+            slot(563, 5, 49, 49, F1M1, true),
+            slot(95, 5, 49, 49, F1M1, false),
+            slot(552, 4, 49, 49, F1M1, false),
+            slot(563, 4, 50, 50, F1M1, true),
+            slot(552, 1, 50, 50, F1M1, false),
+            slot(563, 1, 50, 50, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "relic_castle_small_room",
+        slots: &[
+            slot(344, 20, 47, 47, Genderless, false),
+            slot(344, 20, 47, 47, Genderless, false),
+            slot(344, 10, 48, 48, Genderless, false),
+            slot(344, 10, 48, 48, Genderless, false),
+            slot(344, 10, 49, 49, Genderless, false),
+            slot(344, 10, 49, 49, Genderless, false),
+            slot(344, 5, 50, 50, Genderless, false),
+            slot(344, 5, 50, 50, Genderless, false),
+            slot(344, 4, 50, 50, Genderless, false),
+            slot(344, 4, 50, 50, Genderless, false),
+            slot(344, 1, 50, 50, Genderless, false),
+            slot(344, 1, 50, 50, Genderless, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "cold_storage",
+        slots: &[
+            slot(507, 20, 21, 21, F1M1, false),
+            slot(572, 20, 20, 20, F3M1, true),
+            slot(507, 10, 22, 22, F1M1, false),
+            slot(532, 10, 21, 21, F1M3, false),
+            slot(582, 10, 20, 20, F1M1, false),
+            slot(582, 10, 21, 21, F1M1, false),
+            slot(532, 5, 22, 22, F1M3, false),
+            slot(582, 5, 22, 22, F1M1, false),
+            slot(507, 4, 23, 23, F1M1, false),
+            slot(582, 4, 23, 23, F1M1, false),
+            slot(507, 1, 23, 23, F1M1, false),
+            slot(582, 1, 23, 23, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "chargestone_cave_1f",
+        slots: &[
+            slot(595, 20, 24, 24, F1M1, false),
+            slot(599, 20, 25, 25, Genderless, false),
+            slot(595, 10, 25, 25, F1M1, false),
+            slot(525, 10, 24, 24, F1M1, true),
+            slot(597, 10, 24, 24, F1M1, true),
+            slot(597, 10, 25, 25, F1M1, true),
+            slot(595, 5, 26, 26, F1M1, false),
+            slot(599, 5, 26, 26, Genderless, false),
+            slot(595, 4, 27, 27, F1M1, false),
+            slot(599, 4, 27, 27, Genderless, false),
+            slot(602, 1, 27, 27, F1M1, false),
+            slot(602, 1, 27, 27, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "chargestone_cave_b1f",
+        slots: &[
+            slot(595, 20, 24, 24, F1M1, false),
+            slot(599, 20, 25, 25, Genderless, false),
+            slot(595, 10, 25, 25, F1M1, false),
+            slot(525, 10, 24, 24, F1M1, true),
+            slot(597, 10, 25, 25, F1M1, true),
+            slot(597, 10, 26, 26, F1M1, true),
+            slot(595, 5, 26, 26, F1M1, false),
+            slot(599, 5, 26, 26, Genderless, false),
+            slot(595, 4, 27, 27, F1M1, false),
+            slot(599, 4, 27, 27, Genderless, false),
+            slot(602, 1, 27, 27, F1M1, false),
+            slot(602, 1, 27, 27, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "chargestone_cave_b2f",
+        slots: &[
+            slot(595, 20, 24, 24, F1M1, false),
+            slot(599, 20, 25, 25, Genderless, false),
+            slot(595, 10, 25, 25, F1M1, false),
+            slot(525, 10, 24, 24, F1M1, true),
+            slot(597, 10, 26, 26, F1M1, true),
+            slot(597, 10, 27, 27, F1M1, true),
+            slot(595, 5, 26, 26, F1M1, false),
+            slot(599, 5, 26, 26, Genderless, false),
+            slot(602, 4, 27, 27, F1M1, false),
+            slot(602, 4, 27, 27, F1M1, false),
+            slot(595, 1, 27, 27, F1M1, false),
+            slot(599, 1, 27, 27, Genderless, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "twist_mountain_spring",
+        slots: &[
+            slot(525, 20, 28, 28, F1M1, true),
+            slot(533, 20, 28, 28, F1M3, false),
+            slot(525, 10, 29, 29, F1M1, true),
+            slot(533, 10, 30, 30, F1M3, false),
+            slot(525, 10, 30, 30, F1M1, true),
+            slot(613, 10, 28, 28, F1M1, true),
+            slot(525, 5, 30, 30, F1M1, true),
+            slot(527, 5, 29, 29, F1M1, false),
+            slot(525, 4, 31, 31, F1M1, true),
+            slot(527, 4, 30, 30, F1M1, false),
+            slot(615, 1, 31, 31, Genderless, true),
+            slot(527, 1, 31, 31, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "twist_mountain_summer",
+        slots: &[
+            slot(525, 20, 28, 28, F1M1, true),
+            slot(533, 20, 28, 28, F1M3, false),
+            slot(525, 10, 29, 29, F1M1, true),
+            slot(533, 10, 30, 30, F1M3, false),
+            slot(525, 10, 30, 30, F1M1, true),
+            slot(527, 10, 28, 28, F1M1, false),
+            slot(525, 5, 31, 31, F1M1, true),
+            slot(527, 5, 29, 29, F1M1, false),
+            slot(613, 4, 28, 28, F1M1, true),
+            slot(527, 4, 30, 30, F1M1, false),
+            slot(615, 1, 28, 28, Genderless, true),
+            slot(527, 1, 31, 31, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "twist_mountain_autumn",
+        slots: &[
+            slot(525, 20, 28, 28, F1M1, true),
+            slot(533, 20, 28, 28, F1M3, false),
+            slot(525, 10, 29, 29, F1M1, true),
+            slot(533, 10, 30, 30, F1M3, false),
+            slot(525, 10, 30, 30, F1M1, true),
+            slot(613, 10, 28, 28, F1M1, true),
+            slot(525, 5, 30, 30, F1M1, true),
+            slot(527, 5, 29, 29, F1M1, false),
+            slot(525, 4, 31, 31, F1M1, true),
+            slot(527, 4, 30, 30, F1M1, false),
+            slot(615, 1, 31, 31, Genderless, true),
+            slot(527, 1, 31, 31, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "twist_mountain_winter",
+        slots: &[
+            slot(613, 20, 28, 28, F1M1, true),
+            slot(525, 20, 29, 29, F1M1, true),
+            slot(533, 10, 29, 29, F1M3, false),
+            slot(613, 10, 29, 29, F1M1, true),
+            slot(525, 10, 30, 30, F1M1, true),
+            slot(613, 10, 30, 30, F1M1, true),
+            slot(613, 5, 31, 31, F1M1, true),
+            slot(527, 5, 28, 28, F1M1, false),
+            slot(615, 4, 31, 31, Genderless, true),
+            slot(527, 4, 29, 29, F1M1, false),
+            slot(615, 1, 31, 31, Genderless, true),
+            slot(527, 1, 30, 30, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "dragonspiral_tower_spring_exterior_south",
+        slots: &[
+            slot(520, 20, 30, 30, F1M1, false),
+            slot(585, 20, 31, 31, F1M1, false),
+            slot(619, 10, 30, 30, F1M1, false),
+            slot(585, 10, 32, 32, F1M1, false),
+            slot(619, 10, 31, 31, F1M1, false),
+            slot(520, 10, 32, 32, F1M1, false),
+            slot(619, 5, 32, 32, F1M1, false),
+            slot(621, 5, 31, 31, F1M1, true),
+            slot(619, 4, 33, 33, F1M1, false),
+            slot(621, 4, 32, 32, F1M1, true),
+            slot(619, 1, 33, 33, F1M1, false),
+            slot(621, 1, 33, 33, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "dragonspiral_tower_spring_exterior_northeast",
+        slots: &[
+            slot(520, 20, 31, 31, F1M1, false),
+            slot(585, 20, 30, 30, F1M1, false),
+            slot(619, 10, 30, 30, F1M1, false),
+            slot(585, 10, 32, 32, F1M1, false),
+            slot(619, 10, 31, 31, F1M1, false),
+            slot(520, 10, 32, 32, F1M1, false),
+            slot(619, 5, 32, 32, F1M1, false),
+            slot(621, 5, 31, 31, F1M1, true),
+            slot(619, 4, 33, 33, F1M1, false),
+            slot(621, 4, 32, 32, F1M1, true),
+            slot(619, 1, 33, 33, F1M1, false),
+            slot(621, 1, 33, 33, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "dragonspiral_tower_summer_exterior_south",
+        slots: &[
+            slot(520, 20, 30, 30, F1M1, false),
+            slot(585, 20, 31, 31, F1M1, false),
+            slot(619, 10, 30, 30, F1M1, false),
+            slot(585, 10, 32, 32, F1M1, false),
+            slot(619, 10, 31, 31, F1M1, false),
+            slot(520, 10, 32, 32, F1M1, false),
+            slot(619, 5, 32, 32, F1M1, false),
+            slot(621, 5, 31, 31, F1M1, true),
+            slot(619, 4, 33, 33, F1M1, false),
+            slot(621, 4, 32, 32, F1M1, true),
+            slot(619, 1, 33, 33, F1M1, false),
+            slot(621, 1, 33, 33, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "dragonspiral_tower_summer_exterior_northeast",
+        slots: &[
+            slot(520, 20, 31, 31, F1M1, false),
+            slot(585, 20, 30, 30, F1M1, false),
+            slot(619, 10, 30, 30, F1M1, false),
+            slot(585, 10, 32, 32, F1M1, false),
+            slot(619, 10, 31, 31, F1M1, false),
+            slot(520, 10, 32, 32, F1M1, false),
+            slot(619, 5, 32, 32, F1M1, false),
+            slot(621, 5, 31, 31, F1M1, true),
+            slot(619, 4, 33, 33, F1M1, false),
+            slot(621, 4, 32, 32, F1M1, true),
+            slot(619, 1, 33, 33, F1M1, false),
+            slot(621, 1, 33, 33, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "dragonspiral_tower_autumn_exterior_south",
+        slots: &[
+            slot(520, 20, 30, 30, F1M1, false),
+            slot(585, 20, 31, 31, F1M1, false),
+            slot(619, 10, 30, 30, F1M1, false),
+            slot(585, 10, 32, 32, F1M1, false),
+            slot(619, 10, 31, 31, F1M1, false),
+            slot(520, 10, 32, 32, F1M1, false),
+            slot(619, 5, 32, 32, F1M1, false),
+            slot(621, 5, 31, 31, F1M1, true),
+            slot(619, 4, 33, 33, F1M1, false),
+            slot(621, 4, 32, 32, F1M1, true),
+            slot(619, 1, 33, 33, F1M1, false),
+            slot(621, 1, 33, 33, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "dragonspiral_tower_autumn_exterior_northeast",
+        slots: &[
+            slot(520, 20, 31, 31, F1M1, false),
+            slot(585, 20, 30, 30, F1M1, false),
+            slot(619, 10, 30, 30, F1M1, false),
+            slot(585, 10, 32, 32, F1M1, false),
+            slot(619, 10, 31, 31, F1M1, false),
+            slot(520, 10, 32, 32, F1M1, false),
+            slot(619, 5, 32, 32, F1M1, false),
+            slot(621, 5, 31, 31, F1M1, true),
+            slot(619, 4, 33, 33, F1M1, false),
+            slot(621, 4, 32, 32, F1M1, true),
+            slot(619, 1, 33, 33, F1M1, false),
+            slot(621, 1, 33, 33, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "dragonspiral_tower_winter_exterior_south",
+        slots: &[
+            slot(582, 20, 31, 31, F1M1, false),
+            slot(585, 20, 30, 30, F1M1, false),
+            slot(619, 10, 30, 30, F1M1, false),
+            slot(585, 10, 32, 32, F1M1, false),
+            slot(619, 10, 31, 31, F1M1, false),
+            slot(613, 10, 33, 33, F1M1, true),
+            slot(619, 5, 32, 32, F1M1, false),
+            slot(582, 5, 31, 31, F1M1, false),
+            slot(619, 4, 33, 33, F1M1, false),
+            slot(582, 4, 32, 32, F1M1, false),
+            slot(619, 1, 33, 33, F1M1, false),
+            slot(582, 1, 33, 33, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "dragonspiral_tower_winter_exterior_northeast",
+        slots: &[
+            slot(582, 20, 31, 31, F1M1, false),
+            slot(585, 20, 30, 30, F1M1, false),
+            slot(619, 10, 30, 30, F1M1, false),
+            slot(585, 10, 32, 32, F1M1, false),
+            slot(619, 10, 31, 31, F1M1, false),
+            slot(613, 10, 33, 33, F1M1, true),
+            slot(619, 5, 32, 32, F1M1, false),
+            slot(582, 5, 32, 32, F1M1, false),
+            slot(619, 4, 33, 33, F1M1, false),
+            slot(582, 4, 32, 32, F1M1, false),
+            slot(619, 1, 33, 33, F1M1, false),
+            slot(582, 1, 33, 33, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "dragonspiral_tower_1f",
+        slots: &[
+            slot(622, 20, 30, 30, Genderless, true),
+            slot(619, 20, 33, 33, F1M1, false),
+            slot(622, 10, 31, 31, Genderless, true),
+            slot(621, 10, 30, 30, F1M1, true),
+            slot(622, 10, 32, 32, Genderless, true),
+            slot(621, 10, 31, 31, F1M1, true),
+            slot(622, 5, 32, 32, Genderless, true),
+            slot(621, 5, 32, 32, F1M1, true),
+            slot(622, 4, 33, 33, Genderless, true),
+            slot(621, 4, 33, 33, F1M1, true),
+            slot(622, 1, 33, 33, Genderless, true),
+            slot(621, 1, 33, 33, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "dragonspiral_tower_2f",
+        slots: &[
+            slot(622, 20, 30, 30, Genderless, true),
+            slot(622, 20, 30, 30, Genderless, true),
+            slot(622, 10, 31, 31, Genderless, true),
+            slot(622, 10, 31, 31, Genderless, true),
+            slot(622, 10, 32, 32, Genderless, true),
+            slot(622, 10, 32, 32, Genderless, true),
+            slot(622, 5, 32, 32, Genderless, true),
+            slot(622, 5, 32, 32, Genderless, true),
+            slot(622, 4, 33, 33, Genderless, true),
+            slot(622, 4, 33, 33, Genderless, true),
+            slot(622, 1, 33, 33, Genderless, true),
+            slot(622, 1, 33, 33, Genderless, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "victory_road_1f",
+        slots: &[
+            slot(629, 20, 38, 38, FemaleOnly, false),
+            slot(631, 20, 37, 37, F1M1, false),
+            slot(629, 10, 40, 40, FemaleOnly, false),
+            slot(631, 10, 38, 38, F1M1, false),
+            slot(619, 10, 38, 38, F1M1, false),
+            slot(631, 10, 39, 39, F1M1, false),
+            slot(619, 5, 40, 40, F1M1, false),
+            slot(611, 5, 40, 40, F1M1, false),
+            slot(629, 4, 37, 37, FemaleOnly, false),
+            slot(631, 4, 40, 40, F1M1, false),
+            slot(629, 1, 39, 39, FemaleOnly, false),
+            slot(631, 1, 40, 40, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "victory_road_2f",
+        slots: &[
+            slot(632, 20, 37, 37, F1M1, false),
+            slot(525, 20, 39, 39, F1M1, true),
+            slot(632, 10, 38, 38, F1M1, false),
+            slot(633, 10, 38, 38, F1M1, false),
+            slot(527, 10, 37, 37, F1M1, false),
+            slot(619, 10, 39, 39, F1M1, false),
+            slot(632, 5, 39, 39, F1M1, false),
+            slot(633, 5, 38, 38, F1M1, false),
+            slot(632, 4, 40, 40, F1M1, false),
+            slot(633, 4, 39, 39, F1M1, false),
+            slot(632, 1, 40, 40, F1M1, false),
+            slot(633, 1, 40, 40, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "victory_road_3f_4f_5f",
+        slots: &[
+            slot(632, 20, 37, 37, F1M1, false),
+            slot(525, 20, 37, 37, F1M1, true),
+            slot(632, 10, 38, 38, F1M1, false),
+            slot(525, 10, 39, 39, F1M1, true),
+            slot(527, 10, 37, 37, F1M1, false),
+            slot(619, 10, 39, 39, F1M1, false),
+            slot(632, 5, 39, 39, F1M1, false),
+            slot(527, 5, 38, 38, F1M1, false),
+            slot(632, 4, 40, 40, F1M1, false),
+            slot(527, 4, 39, 39, F1M1, false),
+            slot(632, 1, 40, 40, F1M1, false),
+            slot(527, 1, 40, 40, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "victory_road_6f_7f",
+        slots: &[
+            slot(632, 20, 39, 39, F1M1, false),
+            slot(525, 20, 39, 39, F1M1, true),
+            slot(632, 10, 40, 40, F1M1, false),
+            slot(525, 10, 41, 41, F1M1, true),
+            slot(527, 10, 39, 39, F1M1, false),
+            slot(619, 10, 41, 41, F1M1, false),
+            slot(632, 5, 41, 41, F1M1, false),
+            slot(527, 5, 40, 40, F1M1, false),
+            slot(632, 4, 42, 42, F1M1, false),
+            slot(527, 4, 41, 41, F1M1, false),
+            slot(632, 1, 42, 42, F1M1, false),
+            slot(527, 1, 42, 42, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "trial_chamber",
+        slots: &[
+            slot(632, 20, 39, 39, F1M1, false),
+            slot(525, 20, 39, 39, F1M1, true),
+            slot(632, 10, 40, 40, F1M1, false),
+            slot(525, 10, 41, 41, F1M1, true),
+            slot(527, 10, 39, 39, F1M1, false),
+            slot(619, 10, 41, 41, F1M1, false),
+            slot(632, 5, 41, 41, F1M1, false),
+            slot(527, 5, 40, 40, F1M1, false),
+            slot(632, 4, 42, 42, F1M1, false),
+            slot(527, 4, 41, 41, F1M1, false),
+            slot(632, 1, 42, 42, F1M1, false),
+            slot(527, 1, 42, 42, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "giant_chasm_exterior",
+        slots: &[
+            slot(114, 20, 47, 47, F1M1, false),
+            slot(277, 20, 47, 47, F1M1, true),
+            slot(42, 10, 48, 48, F1M1, false),
+            slot(337, 10, 48, 48, Genderless, true),
+            slot(338, 10, 48, 48, Genderless, true),
+            slot(426, 10, 49, 49, F1M1, false),
+            slot(359, 5, 48, 48, F1M1, false),
+            slot(359, 5, 50, 50, F1M1, false),
+            slot(114, 4, 49, 49, F1M1, false),
+            slot(42, 4, 50, 50, F1M1, false),
+            slot(114, 1, 49, 49, F1M1, false),
+            slot(42, 1, 50, 50, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "giant_chasm_cave",
+        slots: &[
+            slot(42, 20, 47, 47, F1M1, false),
+            slot(221, 20, 47, 47, F1M1, false),
+            slot(215, 10, 48, 48, F1M1, true),
+            slot(225, 10, 48, 48, F1M1, false),
+            slot(337, 10, 50, 50, Genderless, true),
+            slot(338, 10, 50, 50, Genderless, true),
+            slot(525, 5, 49, 49, F1M1, true),
+            slot(124, 5, 48, 48, FemaleOnly, true),
+            slot(221, 4, 49, 49, F1M1, false),
+            slot(124, 4, 50, 50, FemaleOnly, true),
+            slot(221, 1, 49, 49, F1M1, false),
+            slot(124, 1, 50, 50, FemaleOnly, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "giant_chasm_crater_forest",
+        slots: &[
+            slot(35, 20, 52, 52, F3M1, true),
+            slot(221, 20, 52, 52, F1M1, false),
+            slot(35, 10, 54, 54, F3M1, true),
+            slot(132, 10, 53, 53, Genderless, true),
+            slot(337, 10, 55, 55, Genderless, true),
+            slot(338, 10, 55, 55, Genderless, true),
+            slot(132, 5, 55, 55, Genderless, true),
+            slot(375, 5, 53, 53, Genderless, true),
+            slot(221, 4, 54, 54, F1M1, false),
+            slot(375, 4, 55, 55, Genderless, true),
+            slot(221, 1, 54, 54, F1M1, false),
+            slot(375, 1, 55, 55, Genderless, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "giant_chasm_cave_depths",
+        slots: &[
+            slot(42, 20, 57, 57, F1M1, false),
+            slot(221, 20, 57, 57, F1M1, false),
+            slot(215, 10, 58, 58, F1M1, true),
+            slot(225, 10, 58, 58, F1M1, false),
+            slot(337, 10, 60, 60, Genderless, true),
+            slot(338, 10, 60, 60, Genderless, true),
+            slot(525, 5, 59, 59, F1M1, true),
+            slot(124, 5, 58, 58, FemaleOnly, true),
+            slot(221, 4, 59, 59, F1M1, false),
+            slot(124, 4, 60, 60, FemaleOnly, true),
+            slot(221, 1, 59, 59, F1M1, false),
+            slot(124, 1, 60, 60, FemaleOnly, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "p2_laboratory",
+        slots: &[
+            slot(505, 20, 28, 28, F1M1, false),
+            slot(507, 20, 28, 28, F1M1, false),
+            slot(505, 10, 29, 29, F1M1, false),
+            slot(507, 10, 29, 29, F1M1, false),
+            slot(599, 10, 29, 29, Genderless, false),
+            slot(559, 10, 29, 29, F1M1, true),
+            slot(505, 5, 30, 30, F1M1, false),
+            slot(507, 5, 30, 30, F1M1, false),
+            slot(599, 4, 31, 31, Genderless, false),
+            slot(559, 4, 31, 31, F1M1, true),
+            slot(505, 1, 31, 31, F1M1, false),
+            slot(507, 1, 31, 31, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "village_bridge",
+        slots: &[
+            slot(55, 20, 47, 47, F1M1, false),
+            slot(400, 20, 47, 47, F1M1, true),
+            slot(335, 10, 48, 48, F1M1, true),
+            slot(336, 10, 48, 48, F1M1, false),
+            slot(629, 10, 48, 48, FemaleOnly, false),
+            slot(629, 10, 50, 50, FemaleOnly, false),
+            slot(55, 5, 49, 49, F1M1, false),
+            slot(400, 5, 49, 49, F1M1, true),
+            slot(335, 4, 50, 50, F1M1, true),
+            slot(336, 4, 50, 50, F1M1, false),
+            slot(335, 1, 50, 50, F1M1, true),
+            slot(336, 1, 50, 50, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "route_1",
+        slots: &[
+            slot(504, 20, 2, 2, F1M1, false),
+            slot(506, 20, 2, 2, F1M1, false),
+            slot(504, 10, 3, 3, F1M1, false),
+            slot(506, 10, 3, 3, F1M1, false),
+            slot(504, 10, 4, 4, F1M1, false),
+            slot(506, 10, 4, 4, F1M1, false),
+            slot(504, 5, 4, 4, F1M1, false),
+            slot(506, 5, 4, 4, F1M1, false),
+            slot(504, 4, 4, 4, F1M1, false),
+            slot(506, 4, 4, 4, F1M1, false),
+            slot(504, 1, 4, 4, F1M1, false),
+            slot(506, 1, 4, 4, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "route_2",
+        slots: &[
+            slot(504, 20, 4, 4, F1M1, false),
+            slot(506, 20, 4, 4, F1M1, false),
+            slot(504, 10, 5, 5, F1M1, false),
+            slot(506, 10, 5, 5, F1M1, false),
+            slot(509, 10, 4, 4, F1M1, false),
+            slot(509, 10, 5, 5, F1M1, false),
+            slot(504, 5, 6, 6, F1M1, false),
+            slot(506, 5, 6, 6, F1M1, false),
+            slot(504, 4, 7, 7, F1M1, false),
+            slot(506, 4, 7, 7, F1M1, false),
+            slot(504, 1, 7, 7, F1M1, false),
+            slot(506, 1, 7, 7, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "route_3",
+        slots: &[
+            slot(504, 20, 8, 8, F1M1, false),
+            slot(519, 20, 8, 8, F1M1, false),
+            slot(522, 10, 8, 8, F1M1, true),
+            slot(519, 10, 9, 9, F1M1, false),
+            slot(506, 10, 9, 9, F1M1, false),
+            slot(509, 10, 9, 9, F1M1, false),
+            slot(522, 5, 9, 9, F1M1, true),
+            slot(519, 5, 10, 10, F1M1, false),
+            slot(522, 4, 10, 10, F1M1, true),
+            slot(519, 4, 11, 11, F1M1, false),
+            slot(522, 1, 11, 11, F1M1, true),
+            slot(519, 1, 11, 11, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "wellspring_cave",
+        slots: &[
+            slot(524, 20, 10, 10, F1M1, true),
+            slot(527, 20, 10, 10, F1M1, false),
+            slot(524, 10, 11, 11, F1M1, true),
+            slot(527, 10, 11, 11, F1M1, false),
+            slot(524, 10, 11, 11, F1M1, true),
+            slot(527, 10, 11, 11, F1M1, false),
+            slot(524, 5, 12, 12, F1M1, true),
+            slot(527, 5, 12, 12, F1M1, false),
+            slot(524, 4, 13, 13, F1M1, true),
+            slot(527, 4, 13, 13, F1M1, false),
+            slot(524, 1, 13, 13, F1M1, true),
+            slot(527, 1, 13, 13, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "route_4",
+        slots: &[
+            slot(551, 20, 15, 15, F1M1, false),
+            slot(554, 20, 15, 15, F1M1, true),
+            slot(551, 10, 16, 16, F1M1, false),
+            slot(554, 10, 16, 16, F1M1, true),
+            slot(559, 10, 16, 16, F1M1, true),
+            slot(559, 10, 17, 17, F1M1, true),
+            slot(551, 5, 17, 17, F1M1, false),
+            slot(554, 5, 17, 17, F1M1, true),
+            slot(551, 4, 18, 18, F1M1, false),
+            slot(554, 4, 18, 18, F1M1, true),
+            slot(551, 1, 18, 18, F1M1, false),
+            slot(554, 1, 18, 18, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "route_5",
+        slots: &[
+            slot(574, 20, 19, 19, F3M1, false),
+            slot(572, 20, 19, 19, F3M1, true),
+            slot(568, 10, 19, 19, F1M1, true),
+            slot(568, 10, 21, 21, F1M1, true),
+            slot(510, 10, 20, 20, F1M1, false),
+            slot(510, 10, 22, 22, F1M1, false),
+            slot(572, 5, 20, 20, F3M1, true),
+            slot(574, 5, 20, 20, F3M1, false),
+            slot(572, 4, 21, 21, F3M1, true),
+            slot(574, 4, 21, 21, F3M1, false),
+            slot(572, 1, 22, 22, F3M1, true),
+            slot(574, 1, 22, 22, F3M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "route_6_spring",
+        slots: &[
+            slot(585, 20, 22, 22, F1M1, false),
+            slot(588, 20, 22, 22, F1M1, false),
+            slot(520, 10, 23, 23, F1M1, false),
+            slot(590, 10, 23, 23, F1M1, true),
+            slot(585, 10, 23, 23, F1M1, false),
+            slot(541, 10, 23, 23, F1M1, true),
+            slot(585, 5, 24, 24, F1M1, false),
+            slot(588, 5, 24, 24, F1M1, false),
+            slot(520, 4, 25, 25, F1M1, false),
+            slot(590, 4, 25, 25, F1M1, true),
+            slot(520, 1, 25, 25, F1M1, false),
+            slot(590, 1, 25, 25, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "route_6_summer",
+        slots: &[
+            slot(585, 20, 22, 22, F1M1, false),
+            slot(588, 20, 22, 22, F1M1, false),
+            slot(520, 10, 23, 23, F1M1, false),
+            slot(590, 10, 23, 23, F1M1, true),
+            slot(585, 10, 23, 23, F1M1, false),
+            slot(541, 10, 23, 23, F1M1, true),
+            slot(585, 5, 24, 24, F1M1, false),
+            slot(588, 5, 24, 24, F1M1, false),
+            slot(520, 4, 25, 25, F1M1, false),
+            slot(590, 4, 25, 25, F1M1, true),
+            slot(520, 1, 25, 25, F1M1, false),
+            slot(590, 1, 25, 25, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "route_6_autumn",
+        slots: &[
+            slot(585, 20, 22, 22, F1M1, false),
+            slot(588, 20, 22, 22, F1M1, false),
+            slot(520, 10, 23, 23, F1M1, false),
+            slot(590, 10, 23, 23, F1M1, true),
+            slot(585, 10, 23, 23, F1M1, false),
+            slot(541, 10, 23, 23, F1M1, true),
+            slot(585, 5, 24, 24, F1M1, false),
+            slot(588, 5, 24, 24, F1M1, false),
+            slot(520, 4, 25, 25, F1M1, false),
+            slot(590, 4, 25, 25, F1M1, true),
+            slot(520, 1, 25, 25, F1M1, false),
+            slot(590, 1, 25, 25, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "route_6_winter",
+        slots: &[
+            slot(585, 20, 22, 22, F1M1, false),
+            slot(588, 20, 22, 22, F1M1, false),
+            slot(582, 10, 23, 23, F1M1, false),
+            slot(590, 10, 23, 23, F1M1, true),
+            slot(585, 10, 23, 23, F1M1, false),
+            slot(541, 10, 23, 23, F1M1, true),
+            slot(585, 5, 24, 24, F1M1, false),
+            slot(588, 5, 24, 24, F1M1, false),
+            slot(582, 4, 25, 25, F1M1, false),
+            slot(590, 4, 25, 25, F1M1, true),
+            slot(582, 1, 25, 25, F1M1, false),
+            slot(590, 1, 25, 25, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "mistralton_cave",
+        slots: &[
+            slot(525, 20, 28, 28, F1M1, true),
+            slot(527, 20, 28, 28, F1M1, false),
+            slot(525, 10, 29, 29, F1M1, true),
+            slot(527, 10, 30, 30, F1M1, false),
+            slot(525, 10, 30, 30, F1M1, true),
+            slot(610, 10, 30, 30, F1M1, false),
+            slot(525, 5, 30, 30, F1M1, true),
+            slot(610, 5, 31, 31, F1M1, false),
+            slot(525, 4, 31, 31, F1M1, true),
+            slot(610, 4, 31, 31, F1M1, false),
+            slot(525, 1, 31, 31, F1M1, true),
+            slot(610, 1, 31, 31, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "guidance_chamber",
+        slots: &[
+            slot(525, 20, 28, 28, F1M1, true),
+            slot(527, 20, 28, 28, F1M1, false),
+            slot(525, 10, 29, 29, F1M1, true),
+            slot(527, 10, 30, 30, F1M1, false),
+            slot(525, 10, 30, 30, F1M1, true),
+            slot(610, 10, 30, 30, F1M1, false),
+            slot(525, 5, 30, 30, F1M1, true),
+            slot(610, 5, 31, 31, F1M1, false),
+            slot(525, 4, 31, 31, F1M1, true),
+            slot(610, 4, 31, 31, F1M1, false),
+            slot(525, 1, 31, 31, F1M1, true),
+            slot(610, 1, 31, 31, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "route_7_spring",
+        slots: &[
+            slot(520, 20, 26, 26, F1M1, false),
+            slot(585, 20, 26, 26, F1M1, false),
+            slot(505, 10, 27, 27, F1M1, false),
+            slot(523, 10, 27, 27, F1M1, true),
+            slot(520, 10, 28, 28, F1M1, false),
+            slot(523, 10, 29, 29, F1M1, true),
+            slot(505, 5, 28, 28, F1M1, false),
+            slot(590, 5, 27, 27, F1M1, true),
+            slot(505, 4, 29, 29, F1M1, false),
+            slot(590, 4, 29, 29, F1M1, true),
+            slot(505, 1, 29, 29, F1M1, false),
+            slot(590, 1, 29, 29, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "route_7_summer",
+        slots: &[
+            slot(520, 20, 26, 26, F1M1, false),
+            slot(585, 20, 26, 26, F1M1, false),
+            slot(505, 10, 27, 27, F1M1, false),
+            slot(523, 10, 27, 27, F1M1, true),
+            slot(520, 10, 28, 28, F1M1, false),
+            slot(523, 10, 29, 29, F1M1, true),
+            slot(505, 5, 28, 28, F1M1, false),
+            slot(590, 5, 27, 27, F1M1, true),
+            slot(505, 4, 29, 29, F1M1, false),
+            slot(590, 4, 29, 29, F1M1, true),
+            slot(505, 1, 29, 29, F1M1, false),
+            slot(590, 1, 29, 29, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "route_7_autumn",
+        slots: &[
+            slot(520, 20, 26, 26, F1M1, false),
+            slot(585, 20, 26, 26, F1M1, false),
+            slot(505, 10, 27, 27, F1M1, false),
+            slot(523, 10, 27, 27, F1M1, true),
+            slot(520, 10, 28, 28, F1M1, false),
+            slot(523, 10, 29, 29, F1M1, true),
+            slot(505, 5, 28, 28, F1M1, false),
+            slot(590, 5, 27, 27, F1M1, true),
+            slot(505, 4, 29, 29, F1M1, false),
+            slot(590, 4, 29, 29, F1M1, true),
+            slot(505, 1, 29, 29, F1M1, false),
+            slot(590, 1, 29, 29, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "route_7_winter",
+        slots: &[
+            slot(613, 20, 26, 26, F1M1, true),
+            slot(585, 20, 26, 26, F1M1, false),
+            slot(505, 10, 27, 27, F1M1, false),
+            slot(523, 10, 27, 27, F1M1, true),
+            slot(613, 10, 28, 28, F1M1, true),
+            slot(523, 10, 29, 29, F1M1, true),
+            slot(505, 5, 28, 28, F1M1, false),
+            slot(590, 5, 27, 27, F1M1, true),
+            slot(505, 4, 29, 29, F1M1, false),
+            slot(590, 4, 29, 29, F1M1, true),
+            slot(505, 1, 29, 29, F1M1, false),
+            slot(590, 1, 29, 29, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "celestial_tower_2f",
+        slots: &[
+            slot(607, 20, 26, 26, F1M1, false),
+            slot(607, 20, 26, 26, F1M1, false),
+            slot(607, 10, 27, 27, F1M1, false),
+            slot(607, 10, 27, 27, F1M1, false),
+            slot(607, 10, 28, 28, F1M1, false),
+            slot(607, 10, 28, 28, F1M1, false),
+            slot(607, 5, 28, 28, F1M1, false),
+            slot(607, 5, 28, 28, F1M1, false),
+            slot(607, 4, 29, 29, F1M1, false),
+            slot(607, 4, 29, 29, F1M1, false),
+            slot(607, 1, 29, 29, F1M1, false),
+            slot(607, 1, 29, 29, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "celestial_tower_3f",
+        slots: &[
+            slot(607, 20, 26, 26, F1M1, false),
+            slot(607, 20, 26, 26, F1M1, false),
+            slot(607, 10, 27, 27, F1M1, false),
+            slot(607, 10, 27, 27, F1M1, false),
+            slot(607, 10, 28, 28, F1M1, false),
+            slot(605, 10, 26, 26, F1M1, false),
+            slot(607, 5, 28, 28, F1M1, false),
+            slot(607, 5, 28, 28, F1M1, false),
+            slot(607, 4, 29, 29, F1M1, false),
+            slot(605, 4, 27, 27, F1M1, false),
+            slot(607, 1, 29, 29, F1M1, false),
+            slot(605, 1, 28, 28, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "celestial_tower_4f",
+        slots: &[
+            slot(607, 20, 26, 26, F1M1, false),
+            slot(607, 20, 27, 27, F1M1, false),
+            slot(607, 10, 26, 26, F1M1, false),
+            slot(605, 10, 26, 26, F1M1, false),
+            slot(607, 10, 28, 28, F1M1, false),
+            slot(605, 10, 27, 27, F1M1, false),
+            slot(607, 5, 28, 28, F1M1, false),
+            slot(605, 5, 28, 28, F1M1, false),
+            slot(607, 4, 29, 29, F1M1, false),
+            slot(605, 4, 29, 29, F1M1, false),
+            slot(607, 1, 29, 29, F1M1, false),
+            slot(605, 1, 29, 29, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "celestial_tower_5f",
+        slots: &[
+            slot(607, 20, 26, 26, F1M1, false),
+            slot(605, 20, 26, 26, F1M1, false),
+            slot(607, 10, 27, 27, F1M1, false),
+            slot(605, 10, 27, 27, F1M1, false),
+            slot(607, 10, 28, 28, F1M1, false),
+            slot(605, 10, 28, 28, F1M1, false),
+            slot(607, 5, 28, 28, F1M1, false),
+            slot(605, 5, 28, 28, F1M1, false),
+            slot(607, 4, 29, 29, F1M1, false),
+            slot(605, 4, 29, 29, F1M1, false),
+            slot(607, 1, 29, 29, F1M1, false),
+            slot(605, 1, 29, 29, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "route_8_spring",
+        slots: &[
+            slot(536, 20, 30, 30, F1M1, true),
+            slot(616, 20, 30, 30, F1M1, false),
+            slot(536, 10, 31, 31, F1M1, true),
+            slot(616, 10, 31, 31, F1M1, false),
+            slot(618, 10, 31, 31, F1M1, true),
+            slot(618, 10, 32, 32, F1M1, true),
+            slot(536, 5, 32, 32, F1M1, true),
+            slot(616, 5, 32, 32, F1M1, false),
+            slot(536, 4, 33, 33, F1M1, true),
+            slot(616, 4, 33, 33, F1M1, false),
+            slot(536, 1, 33, 33, F1M1, true),
+            slot(616, 1, 33, 33, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "route_8_summer",
+        slots: &[
+            slot(536, 20, 30, 30, F1M1, true),
+            slot(616, 20, 30, 30, F1M1, false),
+            slot(536, 10, 31, 31, F1M1, true),
+            slot(616, 10, 31, 31, F1M1, false),
+            slot(618, 10, 31, 31, F1M1, true),
+            slot(618, 10, 32, 32, F1M1, true),
+            slot(536, 5, 32, 32, F1M1, true),
+            slot(616, 5, 32, 32, F1M1, false),
+            slot(536, 4, 33, 33, F1M1, true),
+            slot(616, 4, 33, 33, F1M1, false),
+            slot(536, 1, 33, 33, F1M1, true),
+            slot(616, 1, 33, 33, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "route_8_autumn",
+        slots: &[
+            slot(536, 20, 30, 30, F1M1, true),
+            slot(616, 20, 30, 30, F1M1, false),
+            slot(536, 10, 31, 31, F1M1, true),
+            slot(616, 10, 31, 31, F1M1, false),
+            slot(618, 10, 31, 31, F1M1, true),
+            slot(618, 10, 32, 32, F1M1, true),
+            slot(536, 5, 32, 32, F1M1, true),
+            slot(616, 5, 32, 32, F1M1, false),
+            slot(536, 4, 33, 33, F1M1, true),
+            slot(616, 4, 33, 33, F1M1, false),
+            slot(536, 1, 33, 33, F1M1, true),
+            slot(616, 1, 33, 33, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "moor_of_icirrus_spring",
+        slots: &[
+            slot(536, 20, 30, 30, F1M1, true),
+            slot(616, 20, 30, 30, F1M1, false),
+            slot(536, 10, 31, 31, F1M1, true),
+            slot(616, 10, 31, 31, F1M1, false),
+            slot(618, 10, 31, 31, F1M1, true),
+            slot(618, 10, 32, 32, F1M1, true),
+            slot(536, 5, 32, 32, F1M1, true),
+            slot(616, 5, 32, 32, F1M1, false),
+            slot(536, 4, 33, 33, F1M1, true),
+            slot(616, 4, 33, 33, F1M1, false),
+            slot(536, 1, 33, 33, F1M1, true),
+            slot(616, 1, 33, 33, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "moor_of_icirrus_summer",
+        slots: &[
+            slot(536, 20, 30, 30, F1M1, true),
+            slot(616, 20, 30, 30, F1M1, false),
+            slot(536, 10, 31, 31, F1M1, true),
+            slot(616, 10, 31, 31, F1M1, false),
+            slot(618, 10, 31, 31, F1M1, true),
+            slot(618, 10, 32, 32, F1M1, true),
+            slot(536, 5, 32, 32, F1M1, true),
+            slot(616, 5, 32, 32, F1M1, false),
+            slot(536, 4, 33, 33, F1M1, true),
+            slot(616, 4, 33, 33, F1M1, false),
+            slot(536, 1, 33, 33, F1M1, true),
+            slot(616, 1, 33, 33, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "moor_of_icirrus_autumn",
+        slots: &[
+            slot(536, 20, 30, 30, F1M1, true),
+            slot(616, 20, 30, 30, F1M1, false),
+            slot(536, 10, 31, 31, F1M1, true),
+            slot(616, 10, 31, 31, F1M1, false),
+            slot(618, 10, 31, 31, F1M1, true),
+            slot(618, 10, 32, 32, F1M1, true),
+            slot(536, 5, 32, 32, F1M1, true),
+            slot(616, 5, 32, 32, F1M1, false),
+            slot(536, 4, 33, 33, F1M1, true),
+            slot(616, 4, 33, 33, F1M1, false),
+            slot(536, 1, 33, 33, F1M1, true),
+            slot(616, 1, 33, 33, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "route_9",
+        slots: &[
+            slot(575, 20, 31, 31, F3M1, false),
+            slot(572, 20, 32, 32, F3M1, true),
+            slot(569, 10, 31, 31, F1M1, true),
+            slot(569, 10, 33, 33, F1M1, true),
+            slot(624, 10, 31, 31, F1M1, false),
+            slot(510, 10, 33, 33, F1M1, false),
+            slot(624, 5, 32, 32, F1M1, false),
+            slot(575, 5, 32, 32, F3M1, false),
+            slot(624, 4, 33, 33, F1M1, false),
+            slot(575, 4, 33, 33, F3M1, false),
+            slot(624, 1, 34, 34, F1M1, false),
+            slot(575, 1, 34, 34, F3M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "challengers_cave_1f",
+        slots: &[
+            slot(525, 20, 48, 48, F1M1, true),
+            slot(527, 20, 47, 47, F1M1, false),
+            slot(75, 10, 47, 47, F1M1, true),
+            slot(75, 10, 49, 49, F1M1, true),
+            slot(302, 10, 49, 49, F1M1, false),
+            slot(108, 10, 49, 49, F1M1, true),
+            slot(303, 5, 49, 49, F1M1, true),
+            slot(108, 5, 50, 50, F1M1, true),
+            slot(303, 4, 49, 49, F1M1, true),
+            slot(108, 4, 50, 50, F1M1, true),
+            slot(303, 1, 49, 49, F1M1, true),
+            slot(108, 1, 49, 49, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "challengers_cave_b1f",
+        slots: &[
+            slot(525, 20, 48, 48, F1M1, true),
+            slot(527, 20, 47, 47, F1M1, false),
+            slot(75, 10, 47, 47, F1M1, true),
+            slot(75, 10, 49, 49, F1M1, true),
+            slot(302, 10, 49, 49, F1M1, false),
+            slot(108, 10, 49, 49, F1M1, true),
+            slot(303, 5, 49, 49, F1M1, true),
+            slot(108, 5, 50, 50, F1M1, true),
+            slot(303, 4, 49, 49, F1M1, true),
+            slot(447, 4, 50, 50, F1M7, false),
+            slot(303, 1, 49, 49, F1M1, true),
+            slot(447, 1, 49, 49, F1M7, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "challengers_cave_b2f",
+        slots: &[
+            slot(525, 20, 48, 48, F1M1, true),
+            slot(527, 20, 47, 47, F1M1, false),
+            slot(75, 10, 47, 47, F1M1, true),
+            slot(75, 10, 49, 49, F1M1, true),
+            slot(302, 10, 49, 49, F1M1, false),
+            slot(108, 10, 49, 49, F1M1, true),
+            slot(303, 5, 49, 49, F1M1, true),
+            slot(447, 5, 50, 50, F1M7, false),
+            slot(303, 4, 49, 49, F1M1, true),
+            slot(447, 4, 50, 50, F1M7, false),
+            slot(303, 1, 49, 49, F1M1, true),
+            slot(447, 1, 49, 49, F1M7, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "route_10",
+        slots: &[
+            slot(507, 20, 33, 33, F1M1, false),
+            slot(629, 20, 34, 34, FemaleOnly, false),
+            slot(507, 10, 34, 34, F1M1, false),
+            slot(629, 10, 36, 36, FemaleOnly, false),
+            slot(626, 10, 34, 34, F1M1, false),
+            slot(626, 10, 35, 35, F1M1, false),
+            slot(590, 5, 34, 34, F1M1, true),
+            slot(539, 5, 33, 33, MaleOnly, true),
+            slot(590, 4, 34, 34, F1M1, true),
+            slot(539, 4, 36, 36, MaleOnly, true),
+            slot(590, 1, 35, 35, F1M1, true),
+            slot(539, 1, 36, 36, MaleOnly, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "route_11",
+        slots: &[
+            slot(629, 20, 48, 48, FemaleOnly, false),
+            slot(55, 20, 47, 47, F1M1, false),
+            slot(207, 10, 47, 47, F1M1, false),
+            slot(591, 10, 48, 48, F1M1, true),
+            slot(335, 10, 48, 48, F1M1, true),
+            slot(336, 10, 48, 48, F1M1, false),
+            slot(588, 5, 49, 49, F1M1, false),
+            slot(624, 5, 50, 50, F1M1, false),
+            slot(207, 4, 50, 50, F1M1, false),
+            slot(629, 4, 50, 50, FemaleOnly, false),
+            slot(207, 1, 50, 50, F1M1, false),
+            slot(629, 1, 50, 50, FemaleOnly, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "route_12",
+        slots: &[
+            slot(415, 20, 47, 47, F1M7, true),
+            slot(191, 20, 47, 47, F1M1, true),
+            slot(78, 10, 49, 49, F1M1, true),
+            slot(520, 10, 48, 48, F1M1, false),
+            slot(421, 10, 48, 48, F1M1, true),
+            slot(14, 10, 48, 48, F1M1, false),
+            slot(214, 5, 50, 50, F1M1, false),
+            slot(127, 5, 50, 50, F1M1, false),
+            slot(206, 4, 48, 48, F1M1, false),
+            slot(520, 4, 50, 50, F1M1, false),
+            slot(206, 1, 48, 48, F1M1, false),
+            slot(520, 1, 50, 50, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "route_13",
+        slots: &[
+            slot(114, 20, 47, 47, F1M1, false),
+            slot(277, 20, 47, 47, F1M1, true),
+            slot(42, 10, 48, 48, F1M1, false),
+            slot(337, 10, 48, 48, Genderless, true),
+            slot(338, 10, 48, 48, Genderless, true),
+            slot(426, 10, 49, 49, F1M1, false),
+            slot(359, 5, 48, 48, F1M1, false),
+            slot(359, 5, 50, 50, F1M1, false),
+            slot(114, 4, 49, 49, F1M1, false),
+            slot(42, 4, 50, 50, F1M1, false),
+            slot(114, 1, 49, 49, F1M1, false),
+            slot(42, 1, 50, 50, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "route_14",
+        slots: &[
+            slot(55, 20, 47, 47, F1M1, false),
+            slot(39, 20, 48, 48, F3M1, false),
+            slot(357, 10, 48, 48, F1M1, false),
+            slot(619, 10, 49, 49, F1M1, false),
+            slot(334, 10, 49, 49, F1M1, false),
+            slot(426, 10, 49, 49, F1M1, false),
+            slot(213, 5, 49, 49, F1M1, true),
+            slot(606, 5, 48, 48, F1M1, false),
+            slot(357, 4, 50, 50, F1M1, false),
+            slot(606, 4, 50, 50, F1M1, false),
+            slot(357, 1, 50, 50, F1M1, false),
+            slot(606, 1, 50, 50, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "abundant_shrine",
+        slots: &[
+            slot(37, 20, 47, 47, F3M1, true),
+            slot(358, 20, 47, 47, F1M1, true),
+            slot(546, 10, 48, 48, F1M1, false),
+            slot(358, 10, 49, 49, F1M1, true),
+            slot(234, 10, 48, 48, F1M1, false),
+            slot(164, 10, 48, 48, F1M1, false),
+            slot(198, 5, 48, 48, F1M1, false),
+            slot(437, 5, 48, 48, Genderless, true),
+            slot(164, 4, 50, 50, F1M1, false),
+            slot(437, 4, 50, 50, Genderless, true),
+            slot(164, 1, 50, 50, F1M1, false),
+            slot(437, 1, 50, 50, Genderless, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "route_15",
+        slots: &[
+            slot(105, 20, 47, 47, F1M1, true),
+            slot(22, 20, 48, 48, F1M1, true),
+            slot(207, 10, 47, 47, F1M1, false),
+            slot(115, 10, 49, 49, FemaleOnly, false),
+            slot(22, 10, 50, 50, F1M1, true),
+            slot(539, 10, 48, 48, MaleOnly, true),
+            slot(247, 5, 48, 48, F1M1, false),
+            slot(539, 5, 49, 49, MaleOnly, true),
+            slot(247, 4, 50, 50, F1M1, false),
+            slot(207, 4, 49, 49, F1M1, false),
+            slot(247, 1, 50, 50, F1M1, false),
+            slot(207, 1, 49, 49, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "route_16",
+        slots: &[
+            slot(574, 20, 19, 19, F3M1, false),
+            slot(572, 20, 19, 19, F3M1, true),
+            slot(568, 10, 19, 19, F1M1, true),
+            slot(568, 10, 21, 21, F1M1, true),
+            slot(510, 10, 20, 20, F1M1, false),
+            slot(510, 10, 22, 22, F1M1, false),
+            slot(572, 5, 20, 20, F3M1, true),
+            slot(574, 5, 20, 20, F3M1, false),
+            slot(572, 4, 21, 21, F3M1, true),
+            slot(574, 4, 21, 21, F3M1, false),
+            slot(572, 1, 22, 22, F3M1, true),
+            slot(574, 1, 22, 22, F3M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "lostlorn_forest",
+        slots: &[
+            slot(546, 20, 19, 19, F1M1, false),
+            slot(541, 20, 20, 20, F1M1, true),
+            slot(546, 10, 20, 20, F1M1, false),
+            slot(541, 10, 21, 21, F1M1, true),
+            slot(520, 10, 21, 21, F1M1, false),
+            slot(543, 10, 20, 20, F1M1, true),
+            slot(520, 5, 22, 22, F1M1, false),
+            slot(543, 5, 21, 21, F1M1, true),
+            slot(546, 4, 21, 21, F1M1, false),
+            slot(541, 4, 22, 22, F1M1, true),
+            slot(546, 1, 22, 22, F1M1, false),
+            slot(541, 1, 22, 22, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Normal,
+        location_key: "route_18",
+        slots: &[
+            slot(559, 20, 28, 28, F1M1, true),
+            slot(557, 20, 30, 30, F1M1, true),
+            slot(505, 10, 28, 28, F1M1, false),
+            slot(557, 10, 31, 31, F1M1, true),
+            slot(559, 10, 29, 29, F1M1, true),
+            slot(505, 10, 30, 30, F1M1, false),
+            slot(559, 5, 30, 30, F1M1, true),
+            slot(539, 5, 29, 29, MaleOnly, true),
+            slot(559, 4, 31, 31, F1M1, true),
+            slot(539, 4, 31, 31, MaleOnly, true),
+            slot(559, 1, 31, 31, F1M1, true),
+            slot(539, 1, 31, 31, MaleOnly, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: ShakingGrass,
+        location_key: "dreamyard",
+        slots: &[
+            slot(531, 20, 8, 8, F1M1, true),
+            slot(531, 20, 8, 8, F1M1, true),
+            slot(531, 10, 9, 9, F1M1, true),
+            slot(531, 10, 9, 9, F1M1, true),
+            slot(531, 10, 10, 10, F1M1, true),
+            slot(531, 10, 11, 11, F1M1, true),
+            slot(531, 5, 11, 11, F1M1, true),
+            slot(531, 5, 11, 11, F1M1, true),
+            slot(531, 4, 11, 11, F1M1, true),
+            slot(518, 4, 11, 11, F1M1, false),
+            slot(531, 1, 11, 11, F1M1, true),
+            slot(518, 1, 11, 11, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: ShakingGrass,
+        location_key: "pinwheel_forest_exterior",
+        slots: &[
+            slot(531, 20, 12, 12, F1M1, true),
+            slot(531, 20, 12, 12, F1M1, true),
+            slot(531, 10, 13, 13, F1M1, true),
+            slot(531, 10, 13, 13, F1M1, true),
+            slot(531, 10, 14, 14, F1M1, true),
+            slot(531, 10, 15, 15, F1M1, true),
+            slot(531, 5, 15, 15, F1M1, true),
+            slot(531, 5, 15, 15, F1M1, true),
+            slot(531, 4, 15, 15, F1M1, true),
+            slot(538, 4, 15, 15, MaleOnly, true),
+            slot(531, 1, 15, 15, F1M1, true),
+            slot(538, 1, 15, 15, MaleOnly, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: ShakingGrass,
+        location_key: "pinwheel_forest_interior",
+        slots: &[
+            slot(531, 20, 14, 14, F1M1, true),
+            slot(531, 20, 15, 15, F1M1, true),
+            slot(511, 10, 15, 15, F1M7, true),
+            slot(513, 10, 15, 15, F1M7, true),
+            slot(515, 10, 15, 15, F1M7, true),
+            slot(531, 10, 17, 17, F1M1, true),
+            slot(531, 5, 17, 17, F1M1, true),
+            slot(531, 5, 17, 17, F1M1, true),
+            slot(531, 4, 17, 17, F1M1, true),
+            slot(547, 4, 17, 17, F1M1, false),
+            slot(531, 1, 17, 17, F1M1, true),
+            slot(547, 1, 17, 17, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: ShakingGrass,
+        location_key: "cold_storage",
+        slots: &[
+            slot(531, 20, 20, 20, F1M1, true),
+            slot(531, 20, 20, 20, F1M1, true),
+            slot(531, 10, 21, 21, F1M1, true),
+            slot(531, 10, 21, 21, F1M1, true),
+            slot(531, 10, 22, 22, F1M1, true),
+            slot(531, 10, 23, 23, F1M1, true),
+            slot(531, 5, 23, 23, F1M1, true),
+            slot(531, 5, 23, 23, F1M1, true),
+            slot(573, 4, 23, 23, F3M1, true),
+            slot(508, 4, 23, 23, F1M1, false),
+            slot(573, 1, 23, 23, F3M1, true),
+            slot(508, 1, 23, 23, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: ShakingGrass,
+        location_key: "dragonspiral_tower_spring_exterior_south",
+        slots: &[
+            slot(531, 20, 30, 30, F1M1, true),
+            slot(531, 20, 30, 30, F1M1, true),
+            slot(587, 10, 31, 31, F1M1, true),
+            slot(531, 10, 31, 31, F1M1, true),
+            slot(531, 10, 32, 32, F1M1, true),
+            slot(531, 10, 33, 33, F1M1, true),
+            slot(531, 5, 33, 33, F1M1, true),
+            slot(531, 5, 33, 33, F1M1, true),
+            slot(531, 4, 33, 33, F1M1, true),
+            slot(521, 4, 33, 33, F1M1, false),
+            slot(531, 1, 33, 33, F1M1, true),
+            slot(521, 1, 33, 33, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: ShakingGrass,
+        location_key: "dragonspiral_tower_summer_exterior_south",
+        slots: &[
+            slot(531, 20, 30, 30, F1M1, true),
+            slot(531, 20, 30, 30, F1M1, true),
+            slot(587, 10, 31, 31, F1M1, true),
+            slot(531, 10, 31, 31, F1M1, true),
+            slot(531, 10, 32, 32, F1M1, true),
+            slot(531, 10, 33, 33, F1M1, true),
+            slot(531, 5, 33, 33, F1M1, true),
+            slot(531, 5, 33, 33, F1M1, true),
+            slot(531, 4, 33, 33, F1M1, true),
+            slot(521, 4, 33, 33, F1M1, false),
+            slot(531, 1, 33, 33, F1M1, true),
+            slot(521, 1, 33, 33, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: ShakingGrass,
+        location_key: "dragonspiral_tower_autumn_exterior_south",
+        slots: &[
+            slot(531, 20, 30, 30, F1M1, true),
+            slot(531, 20, 30, 30, F1M1, true),
+            slot(587, 10, 31, 31, F1M1, true),
+            slot(531, 10, 31, 31, F1M1, true),
+            slot(531, 10, 32, 32, F1M1, true),
+            slot(531, 10, 33, 33, F1M1, true),
+            slot(531, 5, 33, 33, F1M1, true),
+            slot(531, 5, 33, 33, F1M1, true),
+            slot(531, 4, 33, 33, F1M1, true),
+            slot(521, 4, 33, 33, F1M1, false),
+            slot(531, 1, 33, 33, F1M1, true),
+            slot(521, 1, 33, 33, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: ShakingGrass,
+        location_key: "dragonspiral_tower_winter_exterior_south",
+        slots: &[
+            slot(531, 20, 30, 30, F1M1, true),
+            slot(531, 20, 30, 30, F1M1, true),
+            slot(587, 10, 31, 31, F1M1, true),
+            slot(531, 10, 31, 31, F1M1, true),
+            slot(531, 10, 32, 32, F1M1, true),
+            slot(531, 10, 33, 33, F1M1, true),
+            slot(531, 5, 33, 33, F1M1, true),
+            slot(531, 5, 33, 33, F1M1, true),
+            slot(531, 4, 33, 33, F1M1, true),
+            slot(531, 4, 33, 33, F1M1, true),
+            slot(531, 1, 33, 33, F1M1, true),
+            slot(531, 1, 33, 33, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: ShakingGrass,
+        location_key: "giant_chasm_exterior",
+        slots: &[
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(587, 10, 48, 48, F1M1, true),
+            slot(531, 10, 48, 48, F1M1, true),
+            slot(531, 10, 49, 49, F1M1, true),
+            slot(531, 10, 50, 50, F1M1, true),
+            slot(531, 5, 50, 50, F1M1, true),
+            slot(531, 5, 50, 50, F1M1, true),
+            slot(169, 4, 50, 50, F1M1, false),
+            slot(465, 4, 50, 50, F1M1, false),
+            slot(169, 1, 50, 50, F1M1, false),
+            slot(465, 1, 50, 50, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: ShakingGrass,
+        location_key: "giant_chasm_crater_forest",
+        slots: &[
+            slot(531, 20, 52, 52, F1M1, true),
+            slot(531, 20, 52, 52, F1M1, true),
+            slot(531, 10, 53, 53, F1M1, true),
+            slot(531, 10, 53, 53, F1M1, true),
+            slot(531, 10, 54, 54, F1M1, true),
+            slot(531, 10, 55, 55, F1M1, true),
+            slot(531, 5, 55, 55, F1M1, true),
+            slot(473, 5, 55, 55, F1M1, false),
+            slot(36, 4, 55, 55, F3M1, true),
+            slot(376, 4, 55, 55, Genderless, true),
+            slot(36, 1, 55, 55, F3M1, true),
+            slot(376, 1, 55, 55, Genderless, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: ShakingGrass,
+        location_key: "p2_laboratory",
+        slots: &[
+            slot(531, 20, 28, 28, F1M1, true),
+            slot(531, 20, 28, 28, F1M1, true),
+            slot(531, 10, 29, 29, F1M1, true),
+            slot(531, 10, 29, 29, F1M1, true),
+            slot(531, 10, 30, 30, F1M1, true),
+            slot(531, 10, 30, 30, F1M1, true),
+            slot(531, 5, 31, 31, F1M1, true),
+            slot(531, 5, 31, 31, F1M1, true),
+            slot(531, 4, 31, 31, F1M1, true),
+            slot(508, 4, 31, 31, F1M1, false),
+            slot(531, 1, 31, 31, F1M1, true),
+            slot(508, 1, 31, 31, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: ShakingGrass,
+        location_key: "village_bridge",
+        slots: &[
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(587, 10, 48, 48, F1M1, true),
+            slot(531, 10, 48, 48, F1M1, true),
+            slot(531, 10, 49, 49, F1M1, true),
+            slot(531, 10, 50, 50, F1M1, true),
+            slot(531, 5, 50, 50, F1M1, true),
+            slot(531, 5, 50, 50, F1M1, true),
+            slot(531, 4, 50, 50, F1M1, true),
+            slot(531, 4, 50, 50, F1M1, true),
+            slot(531, 1, 50, 50, F1M1, true),
+            slot(531, 1, 50, 50, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: ShakingGrass,
+        location_key: "route_1",
+        slots: &[
+            slot(531, 20, 2, 2, F1M1, true),
+            slot(531, 20, 2, 2, F1M1, true),
+            slot(531, 10, 3, 3, F1M1, true),
+            slot(531, 10, 3, 3, F1M1, true),
+            slot(531, 10, 4, 4, F1M1, true),
+            slot(531, 10, 4, 4, F1M1, true),
+            slot(531, 5, 4, 4, F1M1, true),
+            slot(531, 5, 4, 4, F1M1, true),
+            slot(531, 4, 4, 4, F1M1, true),
+            slot(531, 4, 4, 4, F1M1, true),
+            slot(531, 1, 4, 4, F1M1, true),
+            slot(531, 1, 4, 4, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: ShakingGrass,
+        location_key: "route_2",
+        slots: &[
+            slot(531, 20, 4, 4, F1M1, true),
+            slot(531, 20, 4, 4, F1M1, true),
+            slot(531, 10, 5, 5, F1M1, true),
+            slot(531, 10, 5, 5, F1M1, true),
+            slot(531, 10, 6, 6, F1M1, true),
+            slot(531, 10, 7, 7, F1M1, true),
+            slot(531, 5, 7, 7, F1M1, true),
+            slot(531, 5, 7, 7, F1M1, true),
+            slot(531, 4, 7, 7, F1M1, true),
+            slot(531, 4, 7, 7, F1M1, true),
+            slot(531, 1, 7, 7, F1M1, true),
+            slot(531, 1, 7, 7, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: ShakingGrass,
+        location_key: "route_3",
+        slots: &[
+            slot(531, 20, 8, 8, F1M1, true),
+            slot(531, 20, 8, 8, F1M1, true),
+            slot(531, 10, 9, 9, F1M1, true),
+            slot(531, 10, 9, 9, F1M1, true),
+            slot(531, 10, 10, 10, F1M1, true),
+            slot(531, 10, 11, 11, F1M1, true),
+            slot(531, 5, 11, 11, F1M1, true),
+            slot(531, 5, 11, 11, F1M1, true),
+            slot(531, 4, 11, 11, F1M1, true),
+            slot(531, 4, 11, 11, F1M1, true),
+            slot(531, 1, 11, 11, F1M1, true),
+            slot(531, 1, 11, 11, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: ShakingGrass,
+        location_key: "route_5",
+        slots: &[
+            slot(531, 20, 19, 19, F1M1, true),
+            slot(531, 20, 19, 19, F1M1, true),
+            slot(587, 10, 20, 20, F1M1, true),
+            slot(531, 10, 20, 20, F1M1, true),
+            slot(531, 10, 21, 21, F1M1, true),
+            slot(531, 10, 22, 22, F1M1, true),
+            slot(531, 5, 22, 22, F1M1, true),
+            slot(531, 5, 22, 22, F1M1, true),
+            slot(573, 4, 22, 22, F3M1, true),
+            slot(531, 4, 22, 22, F1M1, true),
+            slot(573, 1, 22, 22, F3M1, true),
+            slot(531, 1, 22, 22, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: ShakingGrass,
+        location_key: "route_6_spring",
+        slots: &[
+            slot(531, 20, 22, 22, F1M1, true),
+            slot(531, 20, 23, 23, F1M1, true),
+            slot(587, 10, 23, 23, F1M1, true),
+            slot(587, 10, 25, 25, F1M1, true),
+            slot(531, 10, 24, 24, F1M1, true),
+            slot(531, 10, 25, 25, F1M1, true),
+            slot(531, 5, 25, 25, F1M1, true),
+            slot(531, 5, 25, 25, F1M1, true),
+            slot(521, 4, 25, 25, F1M1, false),
+            slot(542, 4, 25, 25, F1M1, true),
+            slot(521, 1, 25, 25, F1M1, false),
+            slot(542, 1, 25, 25, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: ShakingGrass,
+        location_key: "route_6_summer",
+        slots: &[
+            slot(531, 20, 22, 22, F1M1, true),
+            slot(531, 20, 23, 23, F1M1, true),
+            slot(587, 10, 23, 23, F1M1, true),
+            slot(587, 10, 25, 25, F1M1, true),
+            slot(531, 10, 24, 24, F1M1, true),
+            slot(531, 10, 25, 25, F1M1, true),
+            slot(531, 5, 25, 25, F1M1, true),
+            slot(531, 5, 25, 25, F1M1, true),
+            slot(521, 4, 25, 25, F1M1, false),
+            slot(542, 4, 25, 25, F1M1, true),
+            slot(521, 1, 25, 25, F1M1, false),
+            slot(542, 1, 25, 25, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: ShakingGrass,
+        location_key: "route_6_autumn",
+        slots: &[
+            slot(531, 20, 22, 22, F1M1, true),
+            slot(531, 20, 23, 23, F1M1, true),
+            slot(587, 10, 23, 23, F1M1, true),
+            slot(587, 10, 25, 25, F1M1, true),
+            slot(531, 10, 24, 24, F1M1, true),
+            slot(531, 10, 25, 25, F1M1, true),
+            slot(531, 5, 25, 25, F1M1, true),
+            slot(531, 5, 25, 25, F1M1, true),
+            slot(521, 4, 25, 25, F1M1, false),
+            slot(542, 4, 25, 25, F1M1, true),
+            slot(521, 1, 25, 25, F1M1, false),
+            slot(542, 1, 25, 25, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: ShakingGrass,
+        location_key: "route_6_winter",
+        slots: &[
+            slot(531, 20, 22, 22, F1M1, true),
+            slot(531, 20, 23, 23, F1M1, true),
+            slot(587, 10, 23, 23, F1M1, true),
+            slot(587, 10, 25, 25, F1M1, true),
+            slot(531, 10, 24, 24, F1M1, true),
+            slot(531, 10, 25, 25, F1M1, true),
+            slot(531, 5, 25, 25, F1M1, true),
+            slot(531, 5, 25, 25, F1M1, true),
+            slot(531, 4, 25, 25, F1M1, true),
+            slot(542, 4, 25, 25, F1M1, true),
+            slot(531, 1, 25, 25, F1M1, true),
+            slot(542, 1, 25, 25, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: ShakingGrass,
+        location_key: "route_7_spring",
+        slots: &[
+            slot(531, 20, 26, 26, F1M1, true),
+            slot(531, 20, 26, 26, F1M1, true),
+            slot(587, 10, 27, 27, F1M1, true),
+            slot(531, 10, 27, 27, F1M1, true),
+            slot(531, 10, 28, 28, F1M1, true),
+            slot(531, 10, 29, 29, F1M1, true),
+            slot(531, 5, 29, 29, F1M1, true),
+            slot(531, 5, 29, 29, F1M1, true),
+            slot(531, 4, 29, 29, F1M1, true),
+            slot(521, 4, 29, 29, F1M1, false),
+            slot(531, 1, 29, 29, F1M1, true),
+            slot(521, 1, 29, 29, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: ShakingGrass,
+        location_key: "route_7_summer",
+        slots: &[
+            slot(531, 20, 26, 26, F1M1, true),
+            slot(531, 20, 26, 26, F1M1, true),
+            slot(587, 10, 27, 27, F1M1, true),
+            slot(531, 10, 27, 27, F1M1, true),
+            slot(531, 10, 28, 28, F1M1, true),
+            slot(531, 10, 29, 29, F1M1, true),
+            slot(531, 5, 29, 29, F1M1, true),
+            slot(531, 5, 29, 29, F1M1, true),
+            slot(531, 4, 29, 29, F1M1, true),
+            slot(521, 4, 29, 29, F1M1, false),
+            slot(531, 1, 29, 29, F1M1, true),
+            slot(521, 1, 29, 29, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: ShakingGrass,
+        location_key: "route_7_autumn",
+        slots: &[
+            slot(531, 20, 26, 26, F1M1, true),
+            slot(531, 20, 26, 26, F1M1, true),
+            slot(587, 10, 27, 27, F1M1, true),
+            slot(531, 10, 27, 27, F1M1, true),
+            slot(531, 10, 28, 28, F1M1, true),
+            slot(531, 10, 29, 29, F1M1, true),
+            slot(531, 5, 29, 29, F1M1, true),
+            slot(531, 5, 29, 29, F1M1, true),
+            slot(531, 4, 29, 29, F1M1, true),
+            slot(521, 4, 29, 29, F1M1, false),
+            slot(531, 1, 29, 29, F1M1, true),
+            slot(521, 1, 29, 29, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: ShakingGrass,
+        location_key: "route_7_winter",
+        slots: &[
+            slot(531, 20, 26, 26, F1M1, true),
+            slot(531, 20, 26, 26, F1M1, true),
+            slot(587, 10, 27, 27, F1M1, true),
+            slot(531, 10, 27, 27, F1M1, true),
+            slot(531, 10, 28, 28, F1M1, true),
+            slot(531, 10, 29, 29, F1M1, true),
+            slot(531, 5, 29, 29, F1M1, true),
+            slot(531, 5, 29, 29, F1M1, true),
+            slot(531, 4, 29, 29, F1M1, true),
+            slot(531, 4, 29, 29, F1M1, true),
+            slot(531, 1, 29, 29, F1M1, true),
+            slot(531, 1, 29, 29, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: ShakingGrass,
+        location_key: "route_9",
+        slots: &[
+            slot(531, 20, 31, 31, F1M1, true),
+            slot(531, 20, 31, 31, F1M1, true),
+            slot(587, 10, 32, 32, F1M1, true),
+            slot(531, 10, 32, 32, F1M1, true),
+            slot(531, 10, 33, 33, F1M1, true),
+            slot(531, 10, 34, 34, F1M1, true),
+            slot(531, 5, 34, 34, F1M1, true),
+            slot(531, 5, 34, 34, F1M1, true),
+            slot(573, 4, 34, 34, F3M1, true),
+            slot(576, 4, 34, 34, F3M1, false),
+            slot(573, 1, 34, 34, F3M1, true),
+            slot(576, 1, 34, 34, F3M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: ShakingGrass,
+        location_key: "route_10",
+        slots: &[
+            slot(531, 20, 33, 33, F1M1, true),
+            slot(531, 20, 33, 33, F1M1, true),
+            slot(587, 10, 34, 34, F1M1, true),
+            slot(531, 10, 34, 34, F1M1, true),
+            slot(531, 10, 35, 35, F1M1, true),
+            slot(531, 10, 36, 36, F1M1, true),
+            slot(531, 5, 36, 36, F1M1, true),
+            slot(531, 5, 36, 36, F1M1, true),
+            slot(508, 4, 36, 36, F1M1, false),
+            slot(538, 4, 36, 36, MaleOnly, true),
+            slot(508, 1, 36, 36, F1M1, false),
+            slot(538, 1, 36, 36, MaleOnly, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: ShakingGrass,
+        location_key: "route_11",
+        slots: &[
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(587, 10, 48, 48, F1M1, true),
+            slot(531, 10, 48, 48, F1M1, true),
+            slot(531, 10, 49, 49, F1M1, true),
+            slot(531, 10, 50, 50, F1M1, true),
+            slot(531, 5, 50, 50, F1M1, true),
+            slot(531, 5, 50, 50, F1M1, true),
+            slot(472, 4, 50, 50, F1M1, false),
+            slot(531, 4, 50, 50, F1M1, true),
+            slot(472, 1, 50, 50, F1M1, false),
+            slot(531, 1, 50, 50, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: ShakingGrass,
+        location_key: "route_12",
+        slots: &[
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(587, 10, 48, 48, F1M1, true),
+            slot(531, 10, 48, 48, F1M1, true),
+            slot(531, 10, 49, 49, F1M1, true),
+            slot(531, 10, 50, 50, F1M1, true),
+            slot(521, 5, 50, 50, F1M1, false),
+            slot(15, 5, 50, 50, F1M1, true),
+            slot(416, 4, 50, 50, FemaleOnly, true),
+            slot(192, 4, 50, 50, F1M1, false),
+            slot(416, 1, 50, 50, FemaleOnly, true),
+            slot(192, 1, 50, 50, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: ShakingGrass,
+        location_key: "route_13",
+        slots: &[
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(587, 10, 48, 48, F1M1, true),
+            slot(531, 10, 48, 48, F1M1, true),
+            slot(531, 10, 49, 49, F1M1, true),
+            slot(531, 10, 50, 50, F1M1, true),
+            slot(531, 5, 50, 50, F1M1, true),
+            slot(531, 5, 50, 50, F1M1, true),
+            slot(169, 4, 50, 50, F1M1, false),
+            slot(465, 4, 50, 50, F1M1, false),
+            slot(169, 1, 50, 50, F1M1, false),
+            slot(465, 1, 50, 50, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: ShakingGrass,
+        location_key: "route_14",
+        slots: &[
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(587, 10, 48, 48, F1M1, true),
+            slot(531, 10, 48, 48, F1M1, true),
+            slot(531, 10, 49, 49, F1M1, true),
+            slot(531, 10, 50, 50, F1M1, true),
+            slot(531, 5, 50, 50, F1M1, true),
+            slot(531, 5, 50, 50, F1M1, true),
+            slot(40, 4, 50, 50, F3M1, false),
+            slot(531, 4, 50, 50, F1M1, true),
+            slot(40, 1, 50, 50, F3M1, false),
+            slot(531, 1, 50, 50, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: ShakingGrass,
+        location_key: "abundant_shrine",
+        slots: &[
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(587, 10, 48, 48, F1M1, true),
+            slot(531, 10, 48, 48, F1M1, true),
+            slot(531, 10, 49, 49, F1M1, true),
+            slot(531, 10, 50, 50, F1M1, true),
+            slot(430, 5, 50, 50, F1M1, false),
+            slot(547, 5, 50, 50, F1M1, false),
+            slot(531, 4, 50, 50, F1M1, true),
+            slot(38, 4, 50, 50, F3M1, true),
+            slot(531, 1, 50, 50, F1M1, true),
+            slot(38, 1, 50, 50, F3M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: ShakingGrass,
+        location_key: "route_15",
+        slots: &[
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(587, 10, 48, 48, F1M1, true),
+            slot(531, 10, 48, 48, F1M1, true),
+            slot(531, 10, 49, 49, F1M1, true),
+            slot(531, 10, 50, 50, F1M1, true),
+            slot(531, 5, 50, 50, F1M1, true),
+            slot(538, 5, 50, 50, MaleOnly, true),
+            slot(472, 4, 50, 50, F1M1, false),
+            slot(248, 4, 50, 50, F1M1, false),
+            slot(472, 1, 50, 50, F1M1, false),
+            slot(248, 1, 50, 50, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: ShakingGrass,
+        location_key: "route_16",
+        slots: &[
+            slot(531, 20, 19, 19, F1M1, true),
+            slot(531, 20, 19, 19, F1M1, true),
+            slot(587, 10, 20, 20, F1M1, true),
+            slot(531, 10, 20, 20, F1M1, true),
+            slot(531, 10, 21, 21, F1M1, true),
+            slot(531, 10, 22, 22, F1M1, true),
+            slot(531, 5, 22, 22, F1M1, true),
+            slot(531, 5, 22, 22, F1M1, true),
+            slot(573, 4, 22, 22, F3M1, true),
+            slot(531, 4, 22, 22, F1M1, true),
+            slot(573, 1, 22, 22, F3M1, true),
+            slot(531, 1, 22, 22, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: ShakingGrass,
+        location_key: "lostlorn_forest",
+        slots: &[
+            slot(531, 20, 19, 19, F1M1, true),
+            slot(531, 20, 20, 20, F1M1, true),
+            slot(587, 10, 20, 20, F1M1, true),
+            slot(511, 10, 20, 20, F1M7, true),
+            slot(513, 10, 20, 20, F1M7, true),
+            slot(515, 10, 20, 20, F1M7, true),
+            slot(531, 5, 21, 21, F1M1, true),
+            slot(521, 5, 22, 22, F1M1, false),
+            slot(542, 4, 22, 22, F1M1, true),
+            slot(547, 4, 22, 22, F1M1, false),
+            slot(542, 1, 22, 22, F1M1, true),
+            slot(547, 1, 22, 22, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: ShakingGrass,
+        location_key: "route_18",
+        slots: &[
+            slot(531, 20, 28, 28, F1M1, true),
+            slot(531, 20, 28, 28, F1M1, true),
+            slot(531, 10, 29, 29, F1M1, true),
+            slot(531, 10, 29, 29, F1M1, true),
+            slot(531, 10, 30, 30, F1M1, true),
+            slot(531, 10, 31, 31, F1M1, true),
+            slot(531, 5, 31, 31, F1M1, true),
+            slot(531, 5, 31, 31, F1M1, true),
+            slot(531, 4, 31, 31, F1M1, true),
+            slot(538, 4, 31, 31, MaleOnly, true),
+            slot(531, 1, 31, 31, F1M1, true),
+            slot(538, 1, 31, 31, MaleOnly, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: DustCloud,
+        location_key: "chargestone_cave_1f",
+        slots: &[
+            slot(529, 20, 24, 24, F1M1, false),
+            slot(529, 20, 24, 24, F1M1, false),
+            slot(529, 10, 25, 25, F1M1, false),
+            slot(529, 10, 25, 25, F1M1, false),
+            slot(529, 10, 26, 26, F1M1, false),
+            slot(529, 10, 26, 26, F1M1, false),
+            slot(529, 5, 27, 27, F1M1, false),
+            slot(529, 5, 27, 27, F1M1, false),
+            slot(529, 4, 27, 27, F1M1, false),
+            slot(529, 4, 27, 27, F1M1, false),
+            slot(529, 1, 27, 27, F1M1, false),
+            slot(529, 1, 27, 27, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: DustCloud,
+        location_key: "twist_mountain_spring",
+        slots: &[
+            slot(529, 20, 28, 28, F1M1, false),
+            slot(529, 20, 28, 28, F1M1, false),
+            slot(529, 10, 29, 29, F1M1, false),
+            slot(529, 10, 29, 29, F1M1, false),
+            slot(529, 10, 30, 30, F1M1, false),
+            slot(529, 10, 30, 30, F1M1, false),
+            slot(529, 5, 31, 31, F1M1, false),
+            slot(529, 5, 31, 31, F1M1, false),
+            slot(529, 4, 31, 31, F1M1, false),
+            slot(529, 4, 31, 31, F1M1, false),
+            slot(529, 1, 31, 31, F1M1, false),
+            slot(529, 1, 31, 31, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: DustCloud,
+        location_key: "twist_mountain_summer",
+        slots: &[
+            slot(529, 20, 28, 28, F1M1, false),
+            slot(529, 20, 28, 28, F1M1, false),
+            slot(529, 10, 29, 29, F1M1, false),
+            slot(529, 10, 29, 29, F1M1, false),
+            slot(529, 10, 30, 30, F1M1, false),
+            slot(529, 10, 30, 30, F1M1, false),
+            slot(529, 5, 31, 31, F1M1, false),
+            slot(529, 5, 31, 31, F1M1, false),
+            slot(529, 4, 31, 31, F1M1, false),
+            slot(529, 4, 31, 31, F1M1, false),
+            slot(529, 1, 31, 31, F1M1, false),
+            slot(529, 1, 31, 31, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: DustCloud,
+        location_key: "twist_mountain_autumn",
+        slots: &[
+            slot(529, 20, 28, 28, F1M1, false),
+            slot(529, 20, 28, 28, F1M1, false),
+            slot(529, 10, 29, 29, F1M1, false),
+            slot(529, 10, 29, 29, F1M1, false),
+            slot(529, 10, 30, 30, F1M1, false),
+            slot(529, 10, 30, 30, F1M1, false),
+            slot(529, 5, 31, 31, F1M1, false),
+            slot(529, 5, 31, 31, F1M1, false),
+            slot(529, 4, 31, 31, F1M1, false),
+            slot(529, 4, 31, 31, F1M1, false),
+            slot(529, 1, 31, 31, F1M1, false),
+            slot(529, 1, 31, 31, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: DustCloud,
+        location_key: "twist_mountain_winter",
+        slots: &[
+            slot(529, 20, 28, 28, F1M1, false),
+            slot(529, 20, 28, 28, F1M1, false),
+            slot(529, 10, 29, 29, F1M1, false),
+            slot(529, 10, 29, 29, F1M1, false),
+            slot(529, 10, 30, 30, F1M1, false),
+            slot(529, 10, 30, 30, F1M1, false),
+            slot(529, 5, 31, 31, F1M1, false),
+            slot(529, 5, 31, 31, F1M1, false),
+            slot(529, 4, 31, 31, F1M1, false),
+            slot(529, 4, 31, 31, F1M1, false),
+            slot(529, 1, 31, 31, F1M1, false),
+            slot(529, 1, 31, 31, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: DustCloud,
+        location_key: "victory_road_1f",
+        slots: &[
+            slot(530, 20, 37, 37, F1M1, false),
+            slot(530, 20, 37, 37, F1M1, false),
+            slot(530, 10, 38, 38, F1M1, false),
+            slot(530, 10, 38, 38, F1M1, false),
+            slot(530, 10, 39, 39, F1M1, false),
+            slot(530, 10, 39, 39, F1M1, false),
+            slot(530, 5, 40, 40, F1M1, false),
+            slot(530, 5, 40, 40, F1M1, false),
+            slot(530, 4, 40, 40, F1M1, false),
+            slot(530, 4, 40, 40, F1M1, false),
+            slot(530, 1, 40, 40, F1M1, false),
+            slot(530, 1, 40, 40, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: DustCloud,
+        location_key: "victory_road_2f",
+        slots: &[
+            slot(530, 20, 39, 39, F1M1, false),
+            slot(530, 20, 39, 39, F1M1, false),
+            slot(530, 10, 40, 40, F1M1, false),
+            slot(530, 10, 40, 40, F1M1, false),
+            slot(530, 10, 41, 41, F1M1, false),
+            slot(530, 10, 41, 41, F1M1, false),
+            slot(530, 5, 42, 42, F1M1, false),
+            slot(530, 5, 42, 42, F1M1, false),
+            slot(530, 4, 42, 42, F1M1, false),
+            slot(530, 4, 42, 42, F1M1, false),
+            slot(530, 1, 42, 42, F1M1, false),
+            slot(530, 1, 42, 42, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: DustCloud,
+        location_key: "trial_chamber",
+        slots: &[
+            slot(530, 20, 39, 39, F1M1, false),
+            slot(530, 20, 39, 39, F1M1, false),
+            slot(530, 10, 40, 40, F1M1, false),
+            slot(530, 10, 40, 40, F1M1, false),
+            slot(530, 10, 41, 41, F1M1, false),
+            slot(530, 10, 41, 41, F1M1, false),
+            slot(530, 5, 42, 42, F1M1, false),
+            slot(530, 5, 42, 42, F1M1, false),
+            slot(530, 4, 42, 42, F1M1, false),
+            slot(530, 4, 42, 42, F1M1, false),
+            slot(530, 1, 42, 42, F1M1, false),
+            slot(530, 1, 42, 42, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: DustCloud,
+        location_key: "giant_chasm",
+        slots: &[
+            slot(530, 20, 47, 47, F1M1, false),
+            slot(530, 20, 47, 47, F1M1, false),
+            slot(530, 10, 48, 48, F1M1, false),
+            slot(530, 10, 48, 48, F1M1, false),
+            slot(530, 10, 49, 49, F1M1, false),
+            slot(530, 10, 49, 49, F1M1, false),
+            slot(530, 5, 50, 50, F1M1, false),
+            slot(530, 5, 50, 50, F1M1, false),
+            slot(530, 4, 50, 50, F1M1, false),
+            slot(530, 4, 50, 50, F1M1, false),
+            slot(530, 1, 50, 50, F1M1, false),
+            slot(530, 1, 50, 50, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: DustCloud,
+        location_key: "wellspring_cave",
+        slots: &[
+            slot(529, 20, 10, 10, F1M1, false),
+            slot(529, 20, 10, 10, F1M1, false),
+            slot(529, 10, 11, 11, F1M1, false),
+            slot(529, 10, 11, 11, F1M1, false),
+            slot(529, 10, 12, 12, F1M1, false),
+            slot(529, 10, 12, 12, F1M1, false),
+            slot(529, 5, 13, 13, F1M1, false),
+            slot(529, 5, 13, 13, F1M1, false),
+            slot(529, 4, 13, 13, F1M1, false),
+            slot(529, 4, 13, 13, F1M1, false),
+            slot(529, 1, 13, 13, F1M1, false),
+            slot(529, 1, 13, 13, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: DustCloud,
+        location_key: "mistralton_cave",
+        slots: &[
+            slot(529, 20, 28, 28, F1M1, false),
+            slot(529, 20, 28, 28, F1M1, false),
+            slot(529, 10, 29, 29, F1M1, false),
+            slot(529, 10, 29, 29, F1M1, false),
+            slot(529, 10, 30, 30, F1M1, false),
+            slot(529, 10, 30, 30, F1M1, false),
+            slot(529, 5, 31, 31, F1M1, false),
+            slot(529, 5, 31, 31, F1M1, false),
+            slot(529, 4, 31, 31, F1M1, false),
+            slot(529, 4, 31, 31, F1M1, false),
+            slot(529, 1, 31, 31, F1M1, false),
+            slot(529, 1, 31, 31, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: DustCloud,
+        location_key: "guidance_chamber",
+        slots: &[
+            slot(529, 20, 28, 28, F1M1, false),
+            slot(529, 20, 28, 28, F1M1, false),
+            slot(529, 10, 29, 29, F1M1, false),
+            slot(529, 10, 29, 29, F1M1, false),
+            slot(529, 10, 30, 30, F1M1, false),
+            slot(529, 10, 30, 30, F1M1, false),
+            slot(529, 5, 31, 31, F1M1, false),
+            slot(529, 5, 31, 31, F1M1, false),
+            slot(529, 4, 31, 31, F1M1, false),
+            slot(529, 4, 31, 31, F1M1, false),
+            slot(529, 1, 31, 31, F1M1, false),
+            slot(529, 1, 31, 31, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: DustCloud,
+        location_key: "challengers_cave",
+        slots: &[
+            slot(530, 20, 47, 47, F1M1, false),
+            slot(530, 20, 47, 47, F1M1, false),
+            slot(530, 10, 48, 48, F1M1, false),
+            slot(530, 10, 48, 48, F1M1, false),
+            slot(530, 10, 49, 49, F1M1, false),
+            slot(530, 10, 49, 49, F1M1, false),
+            slot(530, 5, 50, 50, F1M1, false),
+            slot(530, 5, 50, 50, F1M1, false),
+            slot(530, 4, 50, 50, F1M1, false),
+            slot(530, 4, 50, 50, F1M1, false),
+            slot(530, 1, 50, 50, F1M1, false),
+            slot(530, 1, 50, 50, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: PokemonShadow,
+        location_key: "driftveil_drawbridge",
+        slots: &[
+            slot(580, 20, 22, 22, F1M1, false),
+            slot(580, 20, 22, 22, F1M1, false),
+            slot(580, 10, 23, 23, F1M1, false),
+            slot(580, 10, 23, 23, F1M1, false),
+            slot(580, 10, 24, 24, F1M1, false),
+            slot(580, 10, 24, 24, F1M1, false),
+            slot(580, 5, 25, 25, F1M1, false),
+            slot(580, 5, 25, 25, F1M1, false),
+            slot(580, 4, 25, 25, F1M1, false),
+            slot(580, 4, 25, 25, F1M1, false),
+            slot(580, 1, 25, 25, F1M1, false),
+            slot(580, 1, 25, 25, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: PokemonShadow,
+        location_key: "marvelous_bridge",
+        slots: &[
+            slot(581, 20, 47, 47, F1M1, false),
+            slot(581, 20, 47, 47, F1M1, false),
+            slot(581, 10, 48, 48, F1M1, false),
+            slot(581, 10, 48, 48, F1M1, false),
+            slot(581, 10, 49, 49, F1M1, false),
+            slot(581, 10, 49, 49, F1M1, false),
+            slot(581, 5, 50, 50, F1M1, false),
+            slot(581, 5, 50, 50, F1M1, false),
+            slot(581, 4, 50, 50, F1M1, false),
+            slot(581, 4, 50, 50, F1M1, false),
+            slot(581, 1, 50, 50, F1M1, false),
+            slot(581, 1, 50, 50, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "striaton_city",
+        slots: &[
+            slot(550, 60, 5, 15, F1M1, true),
+            slot(550, 30, 5, 15, F1M1, true),
+            slot(550, 5, 5, 15, F1M1, true),
+            slot(550, 4, 5, 15, F1M1, true),
+            slot(550, 1, 5, 15, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "driftveil_city",
+        slots: &[
+            slot(592, 60, 10, 25, F1M1, false),
+            slot(592, 30, 10, 25, F1M1, false),
+            slot(592, 5, 10, 25, F1M1, false),
+            slot(592, 4, 10, 25, F1M1, false),
+            slot(592, 1, 10, 25, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "icirrus_city_spring",
+        slots: &[
+            slot(618, 60, 15, 35, F1M1, true),
+            slot(618, 30, 15, 35, F1M1, true),
+            slot(618, 5, 15, 35, F1M1, true),
+            slot(618, 4, 15, 35, F1M1, true),
+            slot(618, 1, 15, 35, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "icirrus_city_summer",
+        slots: &[
+            slot(618, 60, 15, 35, F1M1, true),
+            slot(618, 30, 15, 35, F1M1, true),
+            slot(618, 5, 15, 35, F1M1, true),
+            slot(618, 4, 15, 35, F1M1, true),
+            slot(618, 1, 15, 35, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "icirrus_city_autumn",
+        slots: &[
+            slot(618, 60, 15, 35, F1M1, true),
+            slot(618, 30, 15, 35, F1M1, true),
+            slot(618, 5, 15, 35, F1M1, true),
+            slot(618, 4, 15, 35, F1M1, true),
+            slot(618, 1, 15, 35, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "icirrus_city_winter",
+        slots: &[
+            slot(618, 60, 15, 35, F1M1, true),
+            slot(618, 30, 15, 35, F1M1, true),
+            slot(618, 5, 15, 35, F1M1, true),
+            slot(618, 4, 15, 35, F1M1, true),
+            slot(618, 1, 15, 35, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "pinwheel_forest",
+        slots: &[
+            slot(550, 60, 5, 20, F1M1, true),
+            slot(550, 30, 5, 20, F1M1, true),
+            slot(550, 5, 5, 20, F1M1, true),
+            slot(550, 4, 5, 20, F1M1, true),
+            slot(550, 1, 5, 20, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "dragonspiral_tower_spring",
+        slots: &[
+            slot(550, 60, 15, 35, F1M1, true),
+            slot(550, 30, 15, 35, F1M1, true),
+            slot(550, 5, 15, 35, F1M1, true),
+            slot(550, 4, 15, 35, F1M1, true),
+            slot(550, 1, 15, 35, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "dragonspiral_tower_summer",
+        slots: &[
+            slot(550, 60, 15, 35, F1M1, true),
+            slot(550, 30, 15, 35, F1M1, true),
+            slot(550, 5, 15, 35, F1M1, true),
+            slot(550, 4, 15, 35, F1M1, true),
+            slot(550, 1, 15, 35, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "dragonspiral_tower_autumn",
+        slots: &[
+            slot(550, 60, 15, 35, F1M1, true),
+            slot(550, 30, 15, 35, F1M1, true),
+            slot(550, 5, 15, 35, F1M1, true),
+            slot(550, 4, 15, 35, F1M1, true),
+            slot(550, 1, 15, 35, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "dragonspiral_tower_winter",
+        slots: &[
+            slot(550, 60, 15, 35, F1M1, true),
+            slot(550, 30, 15, 35, F1M1, true),
+            slot(550, 5, 15, 35, F1M1, true),
+            slot(550, 4, 15, 35, F1M1, true),
+            slot(550, 1, 15, 35, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "victory_road",
+        slots: &[
+            slot(550, 60, 20, 45, F1M1, true),
+            slot(550, 30, 20, 45, F1M1, true),
+            slot(550, 5, 20, 45, F1M1, true),
+            slot(550, 4, 20, 45, F1M1, true),
+            slot(550, 1, 20, 45, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "giant_chasm",
+        slots: &[
+            slot(550, 60, 25, 55, F1M1, true),
+            slot(86, 30, 25, 55, F1M1, false),
+            slot(550, 5, 25, 55, F1M1, true),
+            slot(550, 4, 25, 55, F1M1, true),
+            slot(550, 1, 25, 55, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "p2_laboratory",
+        slots: &[
+            slot(592, 60, 5, 15, F1M1, false),
+            slot(592, 30, 5, 15, F1M1, false),
+            slot(592, 5, 5, 15, F1M1, false),
+            slot(592, 4, 5, 15, F1M1, false),
+            slot(592, 1, 5, 15, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "undella_bay_spring",
+        slots: &[
+            slot(278, 60, 25, 55, F1M1, false),
+            slot(458, 30, 25, 55, F1M1, false),
+            slot(279, 5, 25, 55, F1M1, false),
+            slot(279, 4, 25, 55, F1M1, false),
+            slot(279, 1, 25, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "undella_bay_summer",
+        slots: &[
+            slot(278, 60, 25, 55, F1M1, false),
+            slot(458, 30, 25, 55, F1M1, false),
+            slot(279, 5, 25, 55, F1M1, false),
+            slot(279, 4, 25, 55, F1M1, false),
+            slot(279, 1, 25, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "undella_bay_autumn",
+        slots: &[
+            slot(278, 60, 25, 55, F1M1, false),
+            slot(458, 30, 25, 55, F1M1, false),
+            slot(279, 5, 25, 55, F1M1, false),
+            slot(279, 4, 25, 55, F1M1, false),
+            slot(279, 1, 25, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "undella_bay_winter",
+        slots: &[
+            slot(278, 60, 25, 55, F1M1, false),
+            slot(363, 30, 25, 55, F1M1, false),
+            slot(279, 5, 25, 55, F1M1, false),
+            slot(279, 4, 25, 55, F1M1, false),
+            slot(279, 1, 25, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "village_bridge",
+        slots: &[
+            slot(550, 60, 25, 55, F1M1, true),
+            slot(550, 30, 25, 55, F1M1, true),
+            slot(550, 5, 25, 55, F1M1, true),
+            slot(550, 4, 25, 55, F1M1, true),
+            slot(550, 1, 25, 55, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "route_1",
+        slots: &[
+            slot(550, 60, 5, 15, F1M1, true),
+            slot(550, 30, 5, 15, F1M1, true),
+            slot(550, 5, 5, 15, F1M1, true),
+            slot(550, 4, 5, 15, F1M1, true),
+            slot(550, 1, 5, 15, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "route_3",
+        slots: &[
+            slot(550, 60, 5, 15, F1M1, true),
+            slot(550, 30, 5, 15, F1M1, true),
+            slot(550, 5, 5, 15, F1M1, true),
+            slot(550, 4, 5, 15, F1M1, true),
+            slot(550, 1, 5, 15, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "wellspring_cave",
+        slots: &[
+            slot(550, 60, 5, 15, F1M1, true),
+            slot(550, 30, 5, 15, F1M1, true),
+            slot(550, 5, 5, 15, F1M1, true),
+            slot(550, 4, 5, 15, F1M1, true),
+            slot(550, 1, 5, 15, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "route_4",
+        slots: &[
+            slot(592, 60, 5, 15, F1M1, false),
+            slot(592, 30, 5, 15, F1M1, false),
+            slot(592, 5, 5, 15, F1M1, false),
+            slot(592, 4, 5, 15, F1M1, false),
+            slot(592, 1, 5, 15, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "route_6_spring",
+        slots: &[
+            slot(550, 60, 10, 25, F1M1, true),
+            slot(550, 30, 10, 25, F1M1, true),
+            slot(550, 5, 10, 25, F1M1, true),
+            slot(550, 4, 10, 25, F1M1, true),
+            slot(550, 1, 10, 25, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "route_6_summer",
+        slots: &[
+            slot(550, 60, 10, 25, F1M1, true),
+            slot(550, 30, 10, 25, F1M1, true),
+            slot(550, 5, 10, 25, F1M1, true),
+            slot(550, 4, 10, 25, F1M1, true),
+            slot(550, 1, 10, 25, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "route_6_autumn",
+        slots: &[
+            slot(550, 60, 10, 25, F1M1, true),
+            slot(550, 30, 10, 25, F1M1, true),
+            slot(550, 5, 10, 25, F1M1, true),
+            slot(550, 4, 10, 25, F1M1, true),
+            slot(550, 1, 10, 25, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "route_6_winter",
+        slots: &[
+            slot(550, 60, 10, 25, F1M1, true),
+            slot(550, 30, 10, 25, F1M1, true),
+            slot(550, 5, 10, 25, F1M1, true),
+            slot(550, 4, 10, 25, F1M1, true),
+            slot(550, 1, 10, 25, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "route_8_spring",
+        slots: &[
+            slot(618, 60, 15, 35, F1M1, true),
+            slot(618, 30, 15, 35, F1M1, true),
+            slot(618, 5, 15, 35, F1M1, true),
+            slot(618, 4, 15, 35, F1M1, true),
+            slot(618, 1, 15, 35, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "route_8_summer",
+        slots: &[
+            slot(618, 60, 15, 35, F1M1, true),
+            slot(618, 30, 15, 35, F1M1, true),
+            slot(618, 5, 15, 35, F1M1, true),
+            slot(618, 4, 15, 35, F1M1, true),
+            slot(618, 1, 15, 35, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "route_8_autumn",
+        slots: &[
+            slot(618, 60, 15, 35, F1M1, true),
+            slot(618, 30, 15, 35, F1M1, true),
+            slot(618, 5, 15, 35, F1M1, true),
+            slot(618, 4, 15, 35, F1M1, true),
+            slot(618, 1, 15, 35, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "route_8_winter",
+        slots: &[
+            slot(618, 60, 15, 35, F1M1, true),
+            slot(618, 30, 15, 35, F1M1, true),
+            slot(618, 5, 15, 35, F1M1, true),
+            slot(618, 4, 15, 35, F1M1, true),
+            slot(618, 1, 15, 35, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "moor_of_icirrus_spring",
+        slots: &[
+            slot(618, 60, 15, 35, F1M1, true),
+            slot(618, 30, 15, 35, F1M1, true),
+            slot(618, 5, 15, 35, F1M1, true),
+            slot(618, 4, 15, 35, F1M1, true),
+            slot(618, 1, 15, 35, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "moor_of_icirrus_summer",
+        slots: &[
+            slot(618, 60, 15, 35, F1M1, true),
+            slot(618, 30, 15, 35, F1M1, true),
+            slot(618, 5, 15, 35, F1M1, true),
+            slot(618, 4, 15, 35, F1M1, true),
+            slot(618, 1, 15, 35, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "moor_of_icirrus_autumn",
+        slots: &[
+            slot(618, 60, 15, 35, F1M1, true),
+            slot(618, 30, 15, 35, F1M1, true),
+            slot(618, 5, 15, 35, F1M1, true),
+            slot(618, 4, 15, 35, F1M1, true),
+            slot(618, 1, 15, 35, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "moor_of_icirrus_winter",
+        slots: &[
+            slot(618, 60, 15, 35, F1M1, true),
+            slot(618, 30, 15, 35, F1M1, true),
+            slot(618, 5, 15, 35, F1M1, true),
+            slot(618, 4, 15, 35, F1M1, true),
+            slot(618, 1, 15, 35, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "challengers_cave",
+        slots: &[
+            slot(550, 60, 25, 55, F1M1, true),
+            slot(550, 30, 25, 55, F1M1, true),
+            slot(550, 5, 25, 55, F1M1, true),
+            slot(550, 4, 25, 55, F1M1, true),
+            slot(550, 1, 25, 55, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "route_11",
+        slots: &[
+            slot(550, 60, 25, 55, F1M1, true),
+            slot(418, 30, 25, 55, F1M1, true),
+            slot(550, 5, 25, 55, F1M1, true),
+            slot(550, 4, 25, 55, F1M1, true),
+            slot(550, 1, 25, 55, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "route_13",
+        slots: &[
+            slot(278, 60, 25, 55, F1M1, false),
+            slot(120, 30, 25, 55, Genderless, true),
+            slot(279, 5, 25, 55, F1M1, false),
+            slot(279, 4, 25, 55, F1M1, false),
+            slot(279, 1, 25, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "route_14",
+        slots: &[
+            slot(550, 60, 25, 55, F1M1, true),
+            slot(418, 30, 25, 55, F1M1, true),
+            slot(550, 5, 25, 55, F1M1, true),
+            slot(550, 4, 25, 55, F1M1, true),
+            slot(550, 1, 25, 55, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "abundant_shrine",
+        slots: &[
+            slot(550, 60, 25, 55, F1M1, true),
+            slot(79, 30, 25, 55, F1M1, true),
+            slot(550, 5, 25, 55, F1M1, true),
+            slot(550, 4, 25, 55, F1M1, true),
+            slot(550, 1, 25, 55, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "lostlorn_forest",
+        slots: &[
+            slot(550, 60, 5, 20, F1M1, true),
+            slot(550, 30, 5, 20, F1M1, true),
+            slot(550, 5, 5, 20, F1M1, true),
+            slot(550, 4, 5, 20, F1M1, true),
+            slot(550, 1, 5, 20, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "route_18",
+        slots: &[
+            slot(592, 60, 5, 15, F1M1, false),
+            slot(592, 30, 5, 15, F1M1, false),
+            slot(592, 5, 5, 15, F1M1, false),
+            slot(592, 4, 5, 15, F1M1, false),
+            slot(592, 1, 5, 15, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "undella_town",
+        slots: &[
+            slot(278, 60, 25, 55, F1M1, false),
+            slot(458, 30, 25, 55, F1M1, false),
+            slot(279, 5, 25, 55, F1M1, false),
+            slot(279, 4, 25, 55, F1M1, false),
+            slot(279, 1, 25, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Surfing,
+        location_key: "route_17",
+        slots: &[
+            slot(592, 60, 5, 15, F1M1, false),
+            slot(592, 30, 5, 15, F1M1, false),
+            slot(592, 5, 5, 15, F1M1, false),
+            slot(592, 4, 5, 15, F1M1, false),
+            slot(592, 1, 5, 15, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "striaton_city",
+        slots: &[
+            slot(550, 60, 5, 20, F1M1, true),
+            slot(550, 30, 5, 20, F1M1, true),
+            slot(550, 5, 5, 20, F1M1, true),
+            slot(550, 4, 5, 20, F1M1, true),
+            slot(550, 1, 5, 20, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "driftveil_city",
+        slots: &[
+            slot(594, 60, 10, 30, F1M1, false),
+            slot(594, 30, 10, 30, F1M1, false),
+            slot(594, 5, 10, 30, F1M1, false),
+            slot(593, 4, 10, 30, F1M1, false),
+            slot(593, 1, 10, 30, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "icirrus_city_spring",
+        slots: &[
+            slot(618, 60, 15, 40, F1M1, true),
+            slot(618, 30, 15, 40, F1M1, true),
+            slot(618, 5, 15, 40, F1M1, true),
+            slot(537, 4, 15, 40, F1M1, true),
+            slot(537, 1, 15, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "icirrus_city_summer",
+        slots: &[
+            slot(618, 60, 15, 40, F1M1, true),
+            slot(618, 30, 15, 40, F1M1, true),
+            slot(618, 5, 15, 40, F1M1, true),
+            slot(537, 4, 15, 40, F1M1, true),
+            slot(537, 1, 15, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "icirrus_city_autumn",
+        slots: &[
+            slot(618, 60, 15, 40, F1M1, true),
+            slot(618, 30, 15, 40, F1M1, true),
+            slot(618, 5, 15, 40, F1M1, true),
+            slot(537, 4, 15, 40, F1M1, true),
+            slot(537, 1, 15, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "icirrus_city_winter",
+        slots: &[
+            slot(618, 60, 15, 40, F1M1, true),
+            slot(618, 30, 15, 40, F1M1, true),
+            slot(618, 5, 15, 40, F1M1, true),
+            slot(537, 4, 15, 40, F1M1, true),
+            slot(537, 1, 15, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "pinwheel_forest",
+        slots: &[
+            slot(550, 60, 5, 20, F1M1, true),
+            slot(550, 30, 5, 20, F1M1, true),
+            slot(550, 5, 5, 20, F1M1, true),
+            slot(550, 4, 5, 20, F1M1, true),
+            slot(550, 1, 5, 20, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "dragonspiral_tower_spring",
+        slots: &[
+            slot(550, 60, 15, 40, F1M1, true),
+            slot(550, 30, 15, 40, F1M1, true),
+            slot(550, 5, 15, 40, F1M1, true),
+            slot(550, 4, 15, 40, F1M1, true),
+            slot(550, 1, 15, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "dragonspiral_tower_summer",
+        slots: &[
+            slot(550, 60, 15, 40, F1M1, true),
+            slot(550, 30, 15, 40, F1M1, true),
+            slot(550, 5, 15, 40, F1M1, true),
+            slot(550, 4, 15, 40, F1M1, true),
+            slot(550, 1, 15, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "dragonspiral_tower_autumn",
+        slots: &[
+            slot(550, 60, 15, 40, F1M1, true),
+            slot(550, 30, 15, 40, F1M1, true),
+            slot(550, 5, 15, 40, F1M1, true),
+            slot(550, 4, 15, 40, F1M1, true),
+            slot(550, 1, 15, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "dragonspiral_tower_winter",
+        slots: &[
+            slot(550, 60, 15, 40, F1M1, true),
+            slot(550, 30, 15, 40, F1M1, true),
+            slot(550, 5, 15, 40, F1M1, true),
+            slot(550, 4, 15, 40, F1M1, true),
+            slot(550, 1, 15, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "victory_road",
+        slots: &[
+            slot(550, 60, 20, 50, F1M1, true),
+            slot(550, 30, 20, 50, F1M1, true),
+            slot(550, 5, 20, 50, F1M1, true),
+            slot(550, 4, 20, 50, F1M1, true),
+            slot(550, 1, 20, 50, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "giant_chasm",
+        slots: &[
+            slot(86, 60, 25, 60, F1M1, false),
+            slot(550, 30, 25, 60, F1M1, true),
+            slot(87, 5, 35, 70, F1M1, false),
+            slot(87, 4, 35, 70, F1M1, false),
+            slot(87, 1, 35, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "p2_laboratory",
+        slots: &[
+            slot(594, 60, 5, 20, F1M1, false),
+            slot(594, 30, 5, 20, F1M1, false),
+            slot(594, 5, 5, 20, F1M1, false),
+            slot(593, 4, 5, 20, F1M1, false),
+            slot(593, 1, 5, 20, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "undella_bay_spring",
+        slots: &[
+            slot(320, 60, 25, 60, F1M1, false),
+            slot(320, 30, 25, 60, F1M1, false),
+            slot(226, 5, 35, 70, F1M1, false),
+            slot(321, 4, 35, 70, F1M1, false),
+            slot(321, 1, 35, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "undella_bay_summer",
+        slots: &[
+            slot(320, 60, 25, 60, F1M1, false),
+            slot(320, 30, 25, 60, F1M1, false),
+            slot(226, 5, 35, 70, F1M1, false),
+            slot(321, 4, 35, 70, F1M1, false),
+            slot(321, 1, 35, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "undella_bay_autumn",
+        slots: &[
+            slot(320, 60, 25, 60, F1M1, false),
+            slot(320, 30, 25, 60, F1M1, false),
+            slot(226, 5, 35, 70, F1M1, false),
+            slot(321, 4, 35, 70, F1M1, false),
+            slot(321, 1, 35, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "undella_bay_winter",
+        slots: &[
+            slot(320, 60, 25, 60, F1M1, false),
+            slot(364, 30, 25, 60, F1M1, false),
+            slot(321, 5, 35, 70, F1M1, false),
+            slot(365, 4, 35, 70, F1M1, false),
+            slot(365, 1, 35, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "village_bridge",
+        slots: &[
+            slot(550, 60, 25, 60, F1M1, true),
+            slot(550, 30, 25, 60, F1M1, true),
+            slot(550, 5, 35, 70, F1M1, true),
+            slot(131, 4, 35, 70, F1M1, false),
+            slot(131, 1, 35, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "route_1",
+        slots: &[
+            slot(550, 60, 5, 20, F1M1, true),
+            slot(550, 30, 5, 20, F1M1, true),
+            slot(550, 5, 5, 20, F1M1, true),
+            slot(550, 4, 5, 20, F1M1, true),
+            slot(550, 1, 5, 20, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "route_3",
+        slots: &[
+            slot(550, 60, 5, 20, F1M1, true),
+            slot(550, 30, 5, 20, F1M1, true),
+            slot(550, 5, 5, 20, F1M1, true),
+            slot(550, 4, 5, 20, F1M1, true),
+            slot(550, 1, 5, 20, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "wellspring_cave",
+        slots: &[
+            slot(550, 60, 5, 20, F1M1, true),
+            slot(550, 30, 5, 20, F1M1, true),
+            slot(550, 5, 5, 20, F1M1, true),
+            slot(550, 4, 5, 20, F1M1, true),
+            slot(550, 1, 5, 20, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "route_4",
+        slots: &[
+            slot(594, 60, 5, 20, F1M1, false),
+            slot(594, 30, 5, 20, F1M1, false),
+            slot(594, 5, 5, 20, F1M1, false),
+            slot(593, 4, 5, 20, F1M1, false),
+            slot(593, 1, 5, 20, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "route_6_spring",
+        slots: &[
+            slot(550, 60, 10, 30, F1M1, true),
+            slot(550, 30, 10, 30, F1M1, true),
+            slot(550, 5, 10, 30, F1M1, true),
+            slot(550, 4, 10, 30, F1M1, true),
+            slot(550, 1, 10, 30, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "route_6_summer",
+        slots: &[
+            slot(550, 60, 10, 30, F1M1, true),
+            slot(550, 30, 10, 30, F1M1, true),
+            slot(550, 5, 10, 30, F1M1, true),
+            slot(550, 4, 10, 30, F1M1, true),
+            slot(550, 1, 10, 30, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "route_6_autumn",
+        slots: &[
+            slot(550, 60, 10, 30, F1M1, true),
+            slot(550, 30, 10, 30, F1M1, true),
+            slot(550, 5, 10, 30, F1M1, true),
+            slot(550, 4, 10, 30, F1M1, true),
+            slot(550, 1, 10, 30, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "route_6_winter",
+        slots: &[
+            slot(550, 60, 10, 30, F1M1, true),
+            slot(550, 30, 10, 30, F1M1, true),
+            slot(550, 5, 10, 30, F1M1, true),
+            slot(550, 4, 10, 30, F1M1, true),
+            slot(550, 1, 10, 30, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "route_8_spring",
+        slots: &[
+            slot(618, 60, 15, 40, F1M1, true),
+            slot(618, 30, 15, 40, F1M1, true),
+            slot(618, 5, 15, 40, F1M1, true),
+            slot(537, 4, 15, 40, F1M1, true),
+            slot(537, 1, 15, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "route_8_summer",
+        slots: &[
+            slot(618, 60, 15, 40, F1M1, true),
+            slot(618, 30, 15, 40, F1M1, true),
+            slot(618, 5, 15, 40, F1M1, true),
+            slot(537, 4, 15, 40, F1M1, true),
+            slot(537, 1, 15, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "route_8_autumn",
+        slots: &[
+            slot(618, 60, 15, 40, F1M1, true),
+            slot(618, 30, 15, 40, F1M1, true),
+            slot(618, 5, 15, 40, F1M1, true),
+            slot(537, 4, 15, 40, F1M1, true),
+            slot(537, 1, 15, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "route_8_winter",
+        slots: &[
+            slot(618, 60, 15, 40, F1M1, true),
+            slot(618, 30, 15, 40, F1M1, true),
+            slot(618, 5, 15, 40, F1M1, true),
+            slot(537, 4, 15, 40, F1M1, true),
+            slot(537, 1, 15, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "moor_of_icirrus_spring",
+        slots: &[
+            slot(618, 60, 15, 40, F1M1, true),
+            slot(618, 30, 15, 40, F1M1, true),
+            slot(618, 5, 15, 40, F1M1, true),
+            slot(537, 4, 15, 40, F1M1, true),
+            slot(537, 1, 15, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "moor_of_icirrus_summer",
+        slots: &[
+            slot(618, 60, 15, 40, F1M1, true),
+            slot(618, 30, 15, 40, F1M1, true),
+            slot(618, 5, 15, 40, F1M1, true),
+            slot(537, 4, 15, 40, F1M1, true),
+            slot(537, 1, 15, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "moor_of_icirrus_autumn",
+        slots: &[
+            slot(618, 60, 15, 40, F1M1, true),
+            slot(618, 30, 15, 40, F1M1, true),
+            slot(618, 5, 15, 40, F1M1, true),
+            slot(537, 4, 15, 40, F1M1, true),
+            slot(537, 1, 15, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "moor_of_icirrus_winter",
+        slots: &[
+            slot(618, 60, 15, 40, F1M1, true),
+            slot(618, 30, 15, 40, F1M1, true),
+            slot(618, 5, 15, 40, F1M1, true),
+            slot(537, 4, 15, 40, F1M1, true),
+            slot(537, 1, 15, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "challengers_cave",
+        slots: &[
+            slot(550, 60, 25, 70, F1M1, true),
+            slot(550, 30, 25, 70, F1M1, true),
+            slot(550, 5, 25, 70, F1M1, true),
+            slot(550, 4, 25, 70, F1M1, true),
+            slot(550, 1, 25, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "route_11",
+        slots: &[
+            slot(418, 60, 25, 60, F1M1, true),
+            slot(550, 30, 25, 60, F1M1, true),
+            slot(419, 5, 35, 70, F1M1, true),
+            slot(419, 4, 35, 70, F1M1, true),
+            slot(419, 1, 35, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "route_13",
+        slots: &[
+            slot(120, 60, 25, 60, Genderless, true),
+            slot(222, 30, 25, 60, F3M1, true),
+            slot(121, 5, 35, 70, Genderless, true),
+            slot(121, 4, 35, 70, Genderless, true),
+            slot(121, 1, 35, 70, Genderless, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "route_14",
+        slots: &[
+            slot(418, 60, 25, 60, F1M1, true),
+            slot(550, 30, 25, 60, F1M1, true),
+            slot(419, 5, 35, 70, F1M1, true),
+            slot(419, 4, 35, 70, F1M1, true),
+            slot(419, 1, 35, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "abundant_shrine",
+        slots: &[
+            slot(79, 60, 25, 60, F1M1, true),
+            slot(550, 30, 25, 60, F1M1, true),
+            slot(80, 5, 35, 70, F1M1, true),
+            slot(199, 4, 35, 70, F1M1, true),
+            slot(199, 1, 35, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "lostlorn_forest",
+        slots: &[
+            slot(550, 60, 5, 20, F1M1, true),
+            slot(550, 30, 5, 20, F1M1, true),
+            slot(550, 5, 5, 20, F1M1, true),
+            slot(550, 4, 5, 20, F1M1, true),
+            slot(550, 1, 5, 20, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "route_18",
+        slots: &[
+            slot(594, 60, 5, 20, F1M1, false),
+            slot(594, 30, 5, 20, F1M1, false),
+            slot(594, 5, 5, 20, F1M1, false),
+            slot(593, 4, 5, 20, F1M1, false),
+            slot(593, 1, 5, 20, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "undella_town",
+        slots: &[
+            slot(320, 60, 25, 60, F1M1, false),
+            slot(222, 30, 25, 60, F3M1, true),
+            slot(226, 5, 35, 70, F1M1, false),
+            slot(226, 4, 35, 70, F1M1, false),
+            slot(226, 1, 35, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: SurfingBubble,
+        location_key: "route_17",
+        slots: &[
+            slot(594, 60, 5, 20, F1M1, false),
+            slot(594, 30, 5, 20, F1M1, false),
+            slot(594, 5, 5, 20, F1M1, false),
+            slot(593, 4, 5, 20, F1M1, false),
+            slot(593, 1, 5, 20, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "striaton_city",
+        slots: &[
+            slot(118, 60, 35, 55, F1M1, false),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(118, 5, 35, 55, F1M1, false),
+            slot(118, 4, 35, 55, F1M1, false),
+            slot(118, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "driftveil_city",
+        slots: &[
+            slot(98, 60, 35, 55, F1M1, false),
+            slot(170, 30, 35, 55, F1M1, true),
+            slot(98, 5, 35, 55, F1M1, false),
+            slot(370, 4, 35, 55, F3M1, true),
+            slot(370, 1, 35, 55, F3M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "icirrus_city_spring",
+        slots: &[
+            slot(339, 60, 35, 55, F1M1, false),
+            slot(618, 30, 35, 55, F1M1, true),
+            slot(339, 5, 35, 55, F1M1, false),
+            slot(339, 4, 35, 55, F1M1, false),
+            slot(339, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "icirrus_city_summer",
+        slots: &[
+            slot(339, 60, 35, 55, F1M1, false),
+            slot(618, 30, 35, 55, F1M1, true),
+            slot(339, 5, 35, 55, F1M1, false),
+            slot(339, 4, 35, 55, F1M1, false),
+            slot(339, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "icirrus_city_autumn",
+        slots: &[
+            slot(339, 60, 35, 55, F1M1, false),
+            slot(618, 30, 35, 55, F1M1, true),
+            slot(339, 5, 35, 55, F1M1, false),
+            slot(339, 4, 35, 55, F1M1, false),
+            slot(339, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "icirrus_city_winter",
+        slots: &[
+            slot(339, 60, 35, 55, F1M1, false),
+            slot(618, 30, 35, 55, F1M1, true),
+            slot(339, 5, 35, 55, F1M1, false),
+            slot(339, 4, 35, 55, F1M1, false),
+            slot(339, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "pinwheel_forest",
+        slots: &[
+            slot(118, 60, 35, 55, F1M1, false),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(118, 5, 35, 55, F1M1, false),
+            slot(118, 4, 35, 55, F1M1, false),
+            slot(118, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "dragonspiral_tower_spring",
+        slots: &[
+            slot(147, 60, 35, 55, F1M1, true),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(147, 5, 35, 55, F1M1, true),
+            slot(148, 4, 35, 55, F1M1, true),
+            slot(148, 1, 35, 55, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "dragonspiral_tower_summer",
+        slots: &[
+            slot(147, 60, 35, 55, F1M1, true),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(147, 5, 35, 55, F1M1, true),
+            slot(148, 4, 35, 55, F1M1, true),
+            slot(148, 1, 35, 55, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "dragonspiral_tower_autumn",
+        slots: &[
+            slot(147, 60, 35, 55, F1M1, true),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(147, 5, 35, 55, F1M1, true),
+            slot(148, 4, 35, 55, F1M1, true),
+            slot(148, 1, 35, 55, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "dragonspiral_tower_winter",
+        slots: &[
+            slot(147, 60, 35, 55, F1M1, true),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(147, 5, 35, 55, F1M1, true),
+            slot(148, 4, 35, 55, F1M1, true),
+            slot(148, 1, 35, 55, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "victory_road",
+        slots: &[
+            slot(60, 60, 35, 55, F1M1, false),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(61, 5, 35, 55, F1M1, true),
+            slot(60, 4, 35, 55, F1M1, false),
+            slot(60, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "giant_chasm",
+        slots: &[
+            slot(60, 60, 35, 55, F1M1, false),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(61, 5, 35, 55, F1M1, true),
+            slot(60, 4, 35, 55, F1M1, false),
+            slot(60, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "p2_laboratory",
+        slots: &[
+            slot(456, 60, 35, 55, F1M1, true),
+            slot(116, 30, 35, 55, F1M1, true),
+            slot(116, 5, 35, 55, F1M1, true),
+            slot(456, 4, 35, 55, F1M1, true),
+            slot(456, 1, 35, 55, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "undella_bay_spring",
+        slots: &[
+            slot(223, 60, 35, 55, F1M1, false),
+            slot(90, 30, 35, 55, F1M1, true),
+            slot(223, 5, 35, 55, F1M1, false),
+            slot(370, 4, 35, 55, F3M1, true),
+            slot(370, 1, 35, 55, F3M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "undella_bay_summer",
+        slots: &[
+            slot(223, 60, 35, 55, F1M1, false),
+            slot(90, 30, 35, 55, F1M1, true),
+            slot(223, 5, 35, 55, F1M1, false),
+            slot(370, 4, 35, 55, F3M1, true),
+            slot(370, 1, 35, 55, F3M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "undella_bay_autumn",
+        slots: &[
+            slot(223, 60, 35, 55, F1M1, false),
+            slot(90, 30, 35, 55, F1M1, true),
+            slot(223, 5, 35, 55, F1M1, false),
+            slot(370, 4, 35, 55, F3M1, true),
+            slot(370, 1, 35, 55, F3M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "undella_bay_winter",
+        slots: &[
+            slot(223, 60, 35, 55, F1M1, false),
+            slot(90, 30, 35, 55, F1M1, true),
+            slot(223, 5, 35, 55, F1M1, false),
+            slot(370, 4, 35, 55, F3M1, true),
+            slot(370, 1, 35, 55, F3M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "village_bridge",
+        slots: &[
+            slot(318, 60, 35, 55, F1M1, true),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(318, 5, 35, 55, F1M1, true),
+            slot(318, 4, 35, 55, F1M1, true),
+            slot(318, 1, 35, 55, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "route_1",
+        slots: &[
+            slot(550, 60, 35, 55, F1M1, true),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(550, 5, 35, 55, F1M1, true),
+            slot(349, 4, 35, 55, F1M1, false),
+            slot(349, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "route_3",
+        slots: &[
+            slot(118, 60, 35, 55, F1M1, false),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(118, 5, 35, 55, F1M1, false),
+            slot(118, 4, 35, 55, F1M1, false),
+            slot(118, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "wellspring_cave",
+        slots: &[
+            slot(60, 60, 35, 55, F1M1, false),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(61, 5, 35, 55, F1M1, true),
+            slot(60, 4, 35, 55, F1M1, false),
+            slot(60, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "route_4",
+        slots: &[
+            slot(98, 60, 35, 55, F1M1, false),
+            slot(366, 30, 35, 55, F1M1, true),
+            slot(98, 5, 35, 55, F1M1, false),
+            slot(370, 4, 35, 55, F3M1, true),
+            slot(370, 1, 35, 55, F3M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "route_6_spring",
+        slots: &[
+            slot(60, 60, 35, 55, F1M1, false),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(61, 5, 35, 55, F1M1, true),
+            slot(60, 4, 35, 55, F1M1, false),
+            slot(60, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "route_6_summer",
+        slots: &[
+            slot(60, 60, 35, 55, F1M1, false),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(61, 5, 35, 55, F1M1, true),
+            slot(60, 4, 35, 55, F1M1, false),
+            slot(60, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "route_6_autumn",
+        slots: &[
+            slot(60, 60, 35, 55, F1M1, false),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(61, 5, 35, 55, F1M1, true),
+            slot(60, 4, 35, 55, F1M1, false),
+            slot(60, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "route_6_winter",
+        slots: &[
+            slot(60, 60, 35, 55, F1M1, false),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(61, 5, 35, 55, F1M1, true),
+            slot(60, 4, 35, 55, F1M1, false),
+            slot(60, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "route_8_spring",
+        slots: &[
+            slot(339, 60, 35, 55, F1M1, false),
+            slot(618, 30, 35, 55, F1M1, true),
+            slot(339, 5, 35, 55, F1M1, false),
+            slot(339, 4, 35, 55, F1M1, false),
+            slot(339, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "route_8_summer",
+        slots: &[
+            slot(339, 60, 35, 55, F1M1, false),
+            slot(618, 30, 35, 55, F1M1, true),
+            slot(339, 5, 35, 55, F1M1, false),
+            slot(339, 4, 35, 55, F1M1, false),
+            slot(339, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "route_8_autumn",
+        slots: &[
+            slot(339, 60, 35, 55, F1M1, false),
+            slot(618, 30, 35, 55, F1M1, true),
+            slot(339, 5, 35, 55, F1M1, false),
+            slot(339, 4, 35, 55, F1M1, false),
+            slot(339, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "route_8_winter",
+        slots: &[
+            slot(339, 60, 35, 55, F1M1, false),
+            slot(618, 30, 35, 55, F1M1, true),
+            slot(339, 5, 35, 55, F1M1, false),
+            slot(339, 4, 35, 55, F1M1, false),
+            slot(339, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "moor_of_icirrus_spring",
+        slots: &[
+            slot(339, 60, 35, 55, F1M1, false),
+            slot(618, 30, 35, 55, F1M1, true),
+            slot(339, 5, 35, 55, F1M1, false),
+            slot(339, 4, 35, 55, F1M1, false),
+            slot(339, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "moor_of_icirrus_summer",
+        slots: &[
+            slot(339, 60, 35, 55, F1M1, false),
+            slot(618, 30, 35, 55, F1M1, true),
+            slot(339, 5, 35, 55, F1M1, false),
+            slot(339, 4, 35, 55, F1M1, false),
+            slot(339, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "moor_of_icirrus_autumn",
+        slots: &[
+            slot(339, 60, 35, 55, F1M1, false),
+            slot(618, 30, 35, 55, F1M1, true),
+            slot(339, 5, 35, 55, F1M1, false),
+            slot(339, 4, 35, 55, F1M1, false),
+            slot(339, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "moor_of_icirrus_winter",
+        slots: &[
+            slot(339, 60, 35, 55, F1M1, false),
+            slot(618, 30, 35, 55, F1M1, true),
+            slot(339, 5, 35, 55, F1M1, false),
+            slot(339, 4, 35, 55, F1M1, false),
+            slot(339, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "challengers_cave",
+        slots: &[
+            slot(60, 60, 35, 55, F1M1, false),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(61, 5, 35, 55, F1M1, true),
+            slot(60, 4, 35, 55, F1M1, false),
+            slot(60, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "route_11",
+        slots: &[
+            slot(118, 60, 35, 55, F1M1, false),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(118, 5, 35, 55, F1M1, false),
+            slot(118, 4, 35, 55, F1M1, false),
+            slot(118, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "route_13",
+        slots: &[
+            slot(98, 60, 35, 55, F1M1, false),
+            slot(90, 30, 35, 55, F1M1, true),
+            slot(98, 5, 35, 55, F1M1, false),
+            slot(370, 4, 35, 55, F3M1, true),
+            slot(370, 1, 35, 55, F3M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "route_14",
+        slots: &[
+            slot(118, 60, 35, 55, F1M1, false),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(118, 5, 35, 55, F1M1, false),
+            slot(118, 4, 35, 55, F1M1, false),
+            slot(118, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "abundant_shrine",
+        slots: &[
+            slot(118, 60, 35, 55, F1M1, false),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(118, 5, 35, 55, F1M1, false),
+            slot(118, 4, 35, 55, F1M1, false),
+            slot(118, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "lostlorn_forest",
+        slots: &[
+            slot(118, 60, 35, 55, F1M1, false),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(118, 5, 35, 55, F1M1, false),
+            slot(118, 4, 35, 55, F1M1, false),
+            slot(118, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "route_18",
+        slots: &[
+            slot(456, 60, 35, 55, F1M1, true),
+            slot(116, 30, 35, 55, F1M1, true),
+            slot(116, 5, 35, 55, F1M1, true),
+            slot(456, 4, 35, 55, F1M1, true),
+            slot(170, 1, 35, 55, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "undella_town",
+        slots: &[
+            slot(223, 60, 35, 55, F1M1, false),
+            slot(90, 30, 35, 55, F1M1, true),
+            slot(223, 5, 35, 55, F1M1, false),
+            slot(370, 4, 35, 55, F3M1, true),
+            slot(370, 1, 35, 55, F3M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: Fishing,
+        location_key: "route_17",
+        slots: &[
+            slot(456, 60, 35, 55, F1M1, true),
+            slot(116, 30, 35, 55, F1M1, true),
+            slot(116, 5, 35, 55, F1M1, true),
+            slot(456, 4, 35, 55, F1M1, true),
+            slot(456, 1, 35, 55, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "striaton_city",
+        slots: &[
+            slot(118, 60, 35, 60, F1M1, false),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(119, 5, 35, 70, F1M1, false),
+            slot(119, 4, 45, 70, F1M1, false),
+            slot(119, 1, 45, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "driftveil_city",
+        slots: &[
+            slot(170, 60, 35, 60, F1M1, true),
+            slot(370, 30, 35, 60, F3M1, true),
+            slot(99, 5, 35, 70, F1M1, false),
+            slot(171, 4, 45, 70, F1M1, true),
+            slot(171, 1, 45, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "icirrus_city_spring",
+        slots: &[
+            slot(339, 60, 35, 60, F1M1, false),
+            slot(618, 30, 35, 60, F1M1, true),
+            slot(340, 5, 35, 70, F1M1, false),
+            slot(340, 4, 45, 70, F1M1, false),
+            slot(340, 1, 45, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "icirrus_city_summer",
+        slots: &[
+            slot(339, 60, 35, 60, F1M1, false),
+            slot(618, 30, 35, 60, F1M1, true),
+            slot(340, 5, 35, 70, F1M1, false),
+            slot(340, 4, 45, 70, F1M1, false),
+            slot(340, 1, 45, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "icirrus_city_autumn",
+        slots: &[
+            slot(339, 60, 35, 60, F1M1, false),
+            slot(618, 30, 35, 60, F1M1, true),
+            slot(340, 5, 35, 70, F1M1, false),
+            slot(340, 4, 45, 70, F1M1, false),
+            slot(340, 1, 45, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "icirrus_city_winter",
+        slots: &[
+            slot(339, 60, 35, 60, F1M1, false),
+            slot(618, 30, 35, 60, F1M1, true),
+            slot(340, 5, 35, 70, F1M1, false),
+            slot(340, 4, 45, 70, F1M1, false),
+            slot(340, 1, 45, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "pinwheel_forest",
+        slots: &[
+            slot(118, 60, 35, 60, F1M1, false),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(119, 5, 35, 70, F1M1, false),
+            slot(119, 4, 45, 70, F1M1, false),
+            slot(119, 1, 45, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "dragonspiral_tower_spring",
+        slots: &[
+            slot(147, 60, 35, 60, F1M1, true),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(148, 5, 35, 70, F1M1, true),
+            slot(148, 4, 45, 70, F1M1, true),
+            slot(149, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "dragonspiral_tower_summer",
+        slots: &[
+            slot(147, 60, 35, 60, F1M1, true),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(148, 5, 35, 70, F1M1, true),
+            slot(148, 4, 45, 70, F1M1, true),
+            slot(149, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "dragonspiral_tower_autumn",
+        slots: &[
+            slot(147, 60, 35, 60, F1M1, true),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(148, 5, 35, 70, F1M1, true),
+            slot(148, 4, 45, 70, F1M1, true),
+            slot(149, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "dragonspiral_tower_winter",
+        slots: &[
+            slot(147, 60, 35, 60, F1M1, true),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(148, 5, 35, 70, F1M1, true),
+            slot(148, 4, 45, 70, F1M1, true),
+            slot(149, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "victory_road",
+        slots: &[
+            slot(61, 60, 35, 60, F1M1, true),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(61, 5, 35, 70, F1M1, true),
+            slot(62, 4, 45, 70, F1M1, true),
+            slot(62, 1, 45, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "giant_chasm",
+        slots: &[
+            slot(61, 60, 35, 60, F1M1, true),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(61, 5, 35, 70, F1M1, true),
+            slot(62, 4, 45, 70, F1M1, true),
+            slot(62, 1, 45, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "p2_laboratory",
+        slots: &[
+            slot(117, 60, 35, 60, F1M1, true),
+            slot(211, 30, 35, 60, F1M1, true),
+            slot(457, 5, 35, 70, F1M1, true),
+            slot(230, 4, 45, 70, F1M1, true),
+            slot(230, 1, 45, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "undella_bay_spring",
+        slots: &[
+            slot(90, 60, 35, 60, F1M1, true),
+            slot(370, 30, 35, 60, F3M1, true),
+            slot(224, 5, 35, 70, F1M1, false),
+            slot(91, 4, 45, 70, F1M1, true),
+            slot(91, 1, 45, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "undella_bay_summer",
+        slots: &[
+            slot(90, 60, 35, 60, F1M1, true),
+            slot(370, 30, 35, 60, F3M1, true),
+            slot(224, 5, 35, 70, F1M1, false),
+            slot(91, 4, 45, 70, F1M1, true),
+            slot(91, 1, 45, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "undella_bay_autumn",
+        slots: &[
+            slot(90, 60, 35, 60, F1M1, true),
+            slot(370, 30, 35, 60, F3M1, true),
+            slot(224, 5, 35, 70, F1M1, false),
+            slot(91, 4, 45, 70, F1M1, true),
+            slot(91, 1, 45, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "undella_bay_winter",
+        slots: &[
+            slot(90, 60, 35, 60, F1M1, true),
+            slot(370, 30, 35, 60, F3M1, true),
+            slot(224, 5, 35, 70, F1M1, false),
+            slot(91, 4, 45, 70, F1M1, true),
+            slot(91, 1, 45, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "village_bridge",
+        slots: &[
+            slot(318, 60, 35, 60, F1M1, true),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(319, 5, 35, 70, F1M1, true),
+            slot(319, 4, 45, 70, F1M1, true),
+            slot(319, 1, 45, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "route_1",
+        slots: &[
+            slot(349, 60, 35, 60, F1M1, false),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(550, 5, 35, 70, F1M1, true),
+            slot(350, 4, 45, 70, F1M1, false),
+            slot(350, 1, 45, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "route_3",
+        slots: &[
+            slot(118, 60, 35, 60, F1M1, false),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(119, 5, 35, 70, F1M1, false),
+            slot(119, 4, 45, 70, F1M1, false),
+            slot(119, 1, 45, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "wellspring_cave",
+        slots: &[
+            slot(61, 60, 35, 60, F1M1, true),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(61, 5, 35, 60, F1M1, true),
+            slot(62, 4, 35, 60, F1M1, true),
+            slot(62, 1, 35, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "route_4",
+        slots: &[
+            slot(369, 60, 35, 60, F1M7, true),
+            slot(370, 30, 35, 60, F3M1, true),
+            slot(99, 5, 35, 70, F1M1, false),
+            slot(367, 4, 45, 70, F1M1, true),
+            slot(367, 1, 45, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "route_6_spring",
+        slots: &[
+            slot(61, 60, 35, 60, F1M1, true),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(61, 5, 35, 70, F1M1, true),
+            slot(186, 4, 45, 70, F1M1, true),
+            slot(186, 1, 45, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "route_6_summer",
+        slots: &[
+            slot(61, 60, 35, 60, F1M1, true),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(61, 5, 35, 70, F1M1, true),
+            slot(186, 4, 45, 70, F1M1, true),
+            slot(186, 1, 45, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "route_6_autumn",
+        slots: &[
+            slot(61, 60, 35, 60, F1M1, true),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(61, 5, 35, 70, F1M1, true),
+            slot(186, 4, 45, 70, F1M1, true),
+            slot(186, 1, 45, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "route_6_winter",
+        slots: &[
+            slot(61, 60, 35, 60, F1M1, true),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(61, 5, 35, 70, F1M1, true),
+            slot(186, 4, 45, 70, F1M1, true),
+            slot(186, 1, 45, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "route_8_spring",
+        slots: &[
+            slot(339, 60, 35, 60, F1M1, false),
+            slot(618, 30, 35, 60, F1M1, true),
+            slot(340, 5, 35, 70, F1M1, false),
+            slot(340, 4, 45, 70, F1M1, false),
+            slot(340, 1, 45, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "route_8_summer",
+        slots: &[
+            slot(339, 60, 35, 60, F1M1, false),
+            slot(618, 30, 35, 60, F1M1, true),
+            slot(340, 5, 35, 70, F1M1, false),
+            slot(340, 4, 45, 70, F1M1, false),
+            slot(340, 1, 45, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "route_8_autumn",
+        slots: &[
+            slot(339, 60, 35, 60, F1M1, false),
+            slot(618, 30, 35, 60, F1M1, true),
+            slot(340, 5, 35, 70, F1M1, false),
+            slot(340, 4, 45, 70, F1M1, false),
+            slot(340, 1, 45, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "route_8_winter",
+        slots: &[
+            slot(339, 60, 35, 60, F1M1, false),
+            slot(618, 30, 35, 60, F1M1, true),
+            slot(340, 5, 35, 70, F1M1, false),
+            slot(340, 4, 45, 70, F1M1, false),
+            slot(340, 1, 45, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "moor_of_icirrus_spring",
+        slots: &[
+            slot(339, 60, 35, 60, F1M1, false),
+            slot(618, 30, 35, 60, F1M1, true),
+            slot(340, 5, 35, 70, F1M1, false),
+            slot(340, 4, 45, 70, F1M1, false),
+            slot(340, 1, 45, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "moor_of_icirrus_summer",
+        slots: &[
+            slot(339, 60, 35, 60, F1M1, false),
+            slot(618, 30, 35, 60, F1M1, true),
+            slot(340, 5, 35, 70, F1M1, false),
+            slot(340, 4, 45, 70, F1M1, false),
+            slot(340, 1, 45, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "moor_of_icirrus_autumn",
+        slots: &[
+            slot(339, 60, 35, 60, F1M1, false),
+            slot(618, 30, 35, 60, F1M1, true),
+            slot(340, 5, 35, 70, F1M1, false),
+            slot(340, 4, 45, 70, F1M1, false),
+            slot(340, 1, 45, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "moor_of_icirrus_winter",
+        slots: &[
+            slot(339, 60, 35, 60, F1M1, false),
+            slot(618, 30, 35, 60, F1M1, true),
+            slot(340, 5, 35, 70, F1M1, false),
+            slot(340, 4, 45, 70, F1M1, false),
+            slot(340, 1, 45, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "challengers_cave",
+        slots: &[
+            slot(61, 60, 35, 60, F1M1, true),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(61, 5, 35, 70, F1M1, true),
+            slot(62, 4, 45, 70, F1M1, true),
+            slot(62, 1, 45, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "route_11",
+        slots: &[
+            slot(118, 60, 35, 60, F1M1, false),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(119, 5, 35, 70, F1M1, false),
+            slot(119, 4, 45, 70, F1M1, false),
+            slot(119, 1, 45, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "route_13",
+        slots: &[
+            slot(90, 60, 35, 60, F1M1, true),
+            slot(370, 30, 35, 60, F3M1, true),
+            slot(99, 5, 35, 70, F1M1, false),
+            slot(91, 4, 45, 70, F1M1, true),
+            slot(91, 1, 45, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "route_14",
+        slots: &[
+            slot(118, 60, 35, 60, F1M1, false),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(119, 5, 35, 70, F1M1, false),
+            slot(119, 4, 45, 70, F1M1, false),
+            slot(119, 1, 45, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "abundant_shrine",
+        slots: &[
+            slot(118, 60, 35, 60, F1M1, false),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(119, 5, 35, 70, F1M1, false),
+            slot(119, 4, 45, 70, F1M1, false),
+            slot(119, 1, 45, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "lostlorn_forest",
+        slots: &[
+            slot(118, 60, 35, 60, F1M1, false),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(118, 5, 35, 70, F1M1, false),
+            slot(118, 4, 35, 70, F1M1, false),
+            slot(118, 1, 35, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "route_18",
+        slots: &[
+            slot(117, 60, 35, 60, F1M1, true),
+            slot(211, 30, 35, 60, F1M1, true),
+            slot(457, 5, 35, 70, F1M1, true),
+            slot(230, 4, 45, 70, F1M1, true),
+            slot(230, 1, 45, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "undella_town",
+        slots: &[
+            slot(90, 60, 35, 60, F1M1, true),
+            slot(370, 30, 35, 60, F3M1, true),
+            slot(224, 5, 35, 70, F1M1, false),
+            slot(91, 4, 45, 70, F1M1, true),
+            slot(91, 1, 45, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black,
+        encounter_type: FishingBubble,
+        location_key: "route_17",
+        slots: &[
+            slot(117, 60, 35, 60, F1M1, true),
+            slot(211, 30, 35, 60, F1M1, true),
+            slot(457, 5, 35, 70, F1M1, true),
+            slot(230, 4, 45, 70, F1M1, true),
+            slot(230, 1, 45, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "icirrus_city_spring",
+        slots: &[
+            slot(536, 20, 30, 30, F1M1, true),
+            slot(616, 20, 30, 30, F1M1, false),
+            slot(536, 10, 31, 31, F1M1, true),
+            slot(616, 10, 31, 31, F1M1, false),
+            slot(618, 10, 31, 31, F1M1, true),
+            slot(618, 10, 32, 32, F1M1, true),
+            slot(536, 5, 32, 32, F1M1, true),
+            slot(616, 5, 32, 32, F1M1, false),
+            slot(536, 4, 33, 33, F1M1, true),
+            slot(616, 4, 33, 33, F1M1, false),
+            slot(536, 1, 33, 33, F1M1, true),
+            slot(616, 1, 33, 33, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "icirrus_city_summer",
+        slots: &[
+            slot(536, 20, 30, 30, F1M1, true),
+            slot(616, 20, 30, 30, F1M1, false),
+            slot(536, 10, 31, 31, F1M1, true),
+            slot(616, 10, 31, 31, F1M1, false),
+            slot(618, 10, 31, 31, F1M1, true),
+            slot(618, 10, 32, 32, F1M1, true),
+            slot(536, 5, 32, 32, F1M1, true),
+            slot(616, 5, 32, 32, F1M1, false),
+            slot(536, 4, 33, 33, F1M1, true),
+            slot(616, 4, 33, 33, F1M1, false),
+            slot(536, 1, 33, 33, F1M1, true),
+            slot(616, 1, 33, 33, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "icirrus_city_autumn",
+        slots: &[
+            slot(536, 20, 30, 30, F1M1, true),
+            slot(616, 20, 30, 30, F1M1, false),
+            slot(536, 10, 31, 31, F1M1, true),
+            slot(616, 10, 31, 31, F1M1, false),
+            slot(618, 10, 31, 31, F1M1, true),
+            slot(618, 10, 32, 32, F1M1, true),
+            slot(536, 5, 32, 32, F1M1, true),
+            slot(616, 5, 32, 32, F1M1, false),
+            slot(536, 4, 33, 33, F1M1, true),
+            slot(616, 4, 33, 33, F1M1, false),
+            slot(536, 1, 33, 33, F1M1, true),
+            slot(616, 1, 33, 33, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "dreamyard",
+        slots: &[
+            slot(504, 20, 8, 8, F1M1, false),
+            slot(509, 20, 8, 8, F1M1, false),
+            slot(504, 10, 9, 9, F1M1, false),
+            slot(509, 10, 9, 9, F1M1, false),
+            slot(517, 10, 8, 8, F1M1, false),
+            slot(517, 10, 10, 10, F1M1, false),
+            slot(504, 5, 10, 10, F1M1, false),
+            slot(509, 5, 10, 10, F1M1, false),
+            slot(504, 4, 11, 11, F1M1, false),
+            slot(509, 4, 11, 11, F1M1, false),
+            slot(504, 1, 11, 11, F1M1, false),
+            slot(509, 1, 11, 11, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "pinwheel_forest_exterior",
+        slots: &[
+            slot(535, 20, 12, 12, F1M1, true),
+            slot(519, 20, 12, 12, F1M1, false),
+            slot(535, 10, 13, 13, F1M1, true),
+            slot(519, 10, 13, 13, F1M1, false),
+            slot(532, 10, 13, 13, F1M3, false),
+            slot(532, 10, 14, 14, F1M3, false),
+            slot(535, 5, 14, 14, F1M1, true),
+            slot(538, 5, 12, 12, MaleOnly, true),
+            slot(535, 4, 15, 15, F1M1, true),
+            slot(538, 4, 15, 15, MaleOnly, true),
+            slot(535, 1, 15, 15, F1M1, true),
+            slot(538, 1, 15, 15, MaleOnly, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "pinwheel_forest_interior",
+        slots: &[
+            slot(548, 20, 14, 14, FemaleOnly, false),
+            slot(540, 20, 14, 14, F1M1, true),
+            slot(548, 10, 15, 15, FemaleOnly, false),
+            slot(540, 10, 15, 15, F1M1, true),
+            slot(519, 10, 15, 15, F1M1, false),
+            slot(543, 10, 15, 15, F1M1, true),
+            slot(519, 5, 16, 16, F1M1, false),
+            slot(543, 5, 16, 16, F1M1, true),
+            slot(548, 4, 16, 16, FemaleOnly, false),
+            slot(540, 4, 16, 16, F1M1, true),
+            slot(548, 1, 17, 17, FemaleOnly, false),
+            slot(540, 1, 17, 17, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "desert_resort_exterior",
+        slots: &[
+            slot(551, 20, 19, 19, F1M1, false),
+            slot(554, 20, 19, 19, F1M1, true),
+            slot(551, 10, 20, 20, F1M1, false),
+            slot(554, 10, 20, 20, F1M1, true),
+            slot(556, 10, 20, 20, F1M1, true),
+            slot(559, 10, 20, 20, F1M1, true),
+            slot(551, 5, 21, 21, F1M1, false),
+            slot(557, 5, 20, 20, F1M1, true),
+            slot(551, 4, 22, 22, F1M1, false),
+            slot(557, 4, 22, 22, F1M1, true),
+            slot(551, 1, 22, 22, F1M1, false),
+            slot(557, 1, 22, 22, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "desert_resort_interior",
+        slots: &[
+            slot(551, 20, 19, 19, F1M1, false),
+            slot(554, 20, 19, 19, F1M1, true),
+            slot(551, 10, 20, 20, F1M1, false),
+            slot(554, 10, 20, 20, F1M1, true),
+            slot(556, 10, 20, 20, F1M1, true),
+            slot(561, 10, 20, 20, F1M1, false),
+            slot(551, 5, 21, 21, F1M1, false),
+            slot(557, 5, 20, 20, F1M1, true),
+            slot(551, 4, 22, 22, F1M1, false),
+            slot(557, 4, 22, 22, F1M1, true),
+            slot(551, 1, 22, 22, F1M1, false),
+            slot(557, 1, 22, 22, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "relic_castle_1f_b1f",
+        slots: &[
+            slot(551, 20, 19, 19, F1M1, false),
+            slot(562, 20, 19, 19, F1M1, true),
+            slot(551, 10, 20, 20, F1M1, false),
+            slot(562, 10, 20, 20, F1M1, true),
+            slot(551, 10, 21, 21, F1M1, false),
+            slot(562, 10, 21, 21, F1M1, true),
+            slot(551, 5, 21, 21, F1M1, false),
+            slot(562, 5, 21, 21, F1M1, true),
+            slot(551, 4, 22, 22, F1M1, false),
+            slot(562, 4, 22, 22, F1M1, true),
+            slot(551, 1, 22, 22, F1M1, false),
+            slot(562, 1, 22, 22, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "relic_castle_b2f_b6f",
+        slots: &[
+            slot(552, 20, 34, 34, F1M1, false),
+            slot(563, 20, 34, 34, F1M1, true),
+            slot(552, 10, 35, 35, F1M1, false),
+            slot(563, 10, 35, 35, F1M1, true),
+            slot(552, 10, 36, 36, F1M1, false),
+            slot(563, 10, 36, 36, F1M1, true),
+            slot(552, 5, 36, 36, F1M1, false),
+            slot(563, 5, 36, 36, F1M1, true),
+            slot(552, 4, 37, 37, F1M1, false),
+            slot(563, 4, 37, 37, F1M1, true),
+            slot(552, 1, 37, 37, F1M1, false),
+            slot(563, 1, 37, 37, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "relic_castle_lowest_floor",
+        slots: &[
+            slot(552, 20, 47, 47, F1M1, false),
+            slot(28, 20, 47, 47, F1M1, true),
+            slot(563, 10, 48, 48, F1M1, true),
+            slot(95, 10, 48, 48, F1M1, false),
+            slot(552, 10, 48, 48, F1M1, false),
+            slot(28, 10, 49, 49, F1M1, true),
+            slot(563, 5, 49, 49, F1M1, true),
+            slot(95, 5, 49, 49, F1M1, false),
+            slot(552, 4, 49, 49, F1M1, false),
+            slot(563, 4, 50, 50, F1M1, true),
+            slot(552, 1, 50, 50, F1M1, false),
+            slot(563, 1, 50, 50, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "relic_castle_small_room",
+        slots: &[
+            slot(344, 20, 47, 47, Genderless, false),
+            slot(344, 20, 47, 47, Genderless, false),
+            slot(344, 10, 48, 48, Genderless, false),
+            slot(344, 10, 48, 48, Genderless, false),
+            slot(344, 10, 49, 49, Genderless, false),
+            slot(344, 10, 49, 49, Genderless, false),
+            slot(344, 5, 50, 50, Genderless, false),
+            slot(344, 5, 50, 50, Genderless, false),
+            slot(344, 4, 50, 50, Genderless, false),
+            slot(344, 4, 50, 50, Genderless, false),
+            slot(344, 1, 50, 50, Genderless, false),
+            slot(344, 1, 50, 50, Genderless, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "cold_storage",
+        slots: &[
+            slot(507, 20, 21, 21, F1M1, false),
+            slot(572, 20, 20, 20, F3M1, true),
+            slot(507, 10, 22, 22, F1M1, false),
+            slot(532, 10, 21, 21, F1M3, false),
+            slot(582, 10, 20, 20, F1M1, false),
+            slot(582, 10, 21, 21, F1M1, false),
+            slot(532, 5, 22, 22, F1M3, false),
+            slot(582, 5, 22, 22, F1M1, false),
+            slot(507, 4, 23, 23, F1M1, false),
+            slot(582, 4, 23, 23, F1M1, false),
+            slot(507, 1, 23, 23, F1M1, false),
+            slot(582, 1, 23, 23, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "chargestone_cave_1f",
+        slots: &[
+            slot(595, 20, 24, 24, F1M1, false),
+            slot(599, 20, 25, 25, Genderless, false),
+            slot(595, 10, 25, 25, F1M1, false),
+            slot(525, 10, 24, 24, F1M1, true),
+            slot(597, 10, 24, 24, F1M1, true),
+            slot(597, 10, 25, 25, F1M1, true),
+            slot(595, 5, 26, 26, F1M1, false),
+            slot(599, 5, 26, 26, Genderless, false),
+            slot(595, 4, 27, 27, F1M1, false),
+            slot(599, 4, 27, 27, Genderless, false),
+            slot(602, 1, 27, 27, F1M1, false),
+            slot(602, 1, 27, 27, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "chargestone_cave_b1f",
+        slots: &[
+            slot(595, 20, 24, 24, F1M1, false),
+            slot(599, 20, 25, 25, Genderless, false),
+            slot(595, 10, 25, 25, F1M1, false),
+            slot(525, 10, 24, 24, F1M1, true),
+            slot(597, 10, 25, 25, F1M1, true),
+            slot(597, 10, 26, 26, F1M1, true),
+            slot(595, 5, 26, 26, F1M1, false),
+            slot(599, 5, 26, 26, Genderless, false),
+            slot(595, 4, 27, 27, F1M1, false),
+            slot(599, 4, 27, 27, Genderless, false),
+            slot(602, 1, 27, 27, F1M1, false),
+            slot(602, 1, 27, 27, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "chargestone_cave_b2f",
+        slots: &[
+            slot(595, 20, 24, 24, F1M1, false),
+            slot(599, 20, 25, 25, Genderless, false),
+            slot(595, 10, 25, 25, F1M1, false),
+            slot(525, 10, 24, 24, F1M1, true),
+            slot(597, 10, 26, 26, F1M1, true),
+            slot(597, 10, 27, 27, F1M1, true),
+            slot(595, 5, 26, 26, F1M1, false),
+            slot(599, 5, 26, 26, Genderless, false),
+            slot(602, 4, 27, 27, F1M1, false),
+            slot(602, 4, 27, 27, F1M1, false),
+            slot(595, 1, 27, 27, F1M1, false),
+            slot(599, 1, 27, 27, Genderless, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "twist_mountain_spring",
+        slots: &[
+            slot(525, 20, 28, 28, F1M1, true),
+            slot(533, 20, 28, 28, F1M3, false),
+            slot(525, 10, 29, 29, F1M1, true),
+            slot(533, 10, 30, 30, F1M3, false),
+            slot(525, 10, 30, 30, F1M1, true),
+            slot(613, 10, 28, 28, F1M1, true),
+            slot(525, 5, 30, 30, F1M1, true),
+            slot(527, 5, 29, 29, F1M1, false),
+            slot(525, 4, 31, 31, F1M1, true),
+            slot(527, 4, 30, 30, F1M1, false),
+            slot(615, 1, 31, 31, Genderless, true),
+            slot(527, 1, 31, 31, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "twist_mountain_summer",
+        slots: &[
+            slot(525, 20, 28, 28, F1M1, true),
+            slot(533, 20, 28, 28, F1M3, false),
+            slot(525, 10, 29, 29, F1M1, true),
+            slot(533, 10, 30, 30, F1M3, false),
+            slot(525, 10, 30, 30, F1M1, true),
+            slot(527, 10, 28, 28, F1M1, false),
+            slot(525, 5, 31, 31, F1M1, true),
+            slot(527, 5, 29, 29, F1M1, false),
+            slot(613, 4, 28, 28, F1M1, true),
+            slot(527, 4, 30, 30, F1M1, false),
+            slot(615, 1, 28, 28, Genderless, true),
+            slot(527, 1, 31, 31, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "twist_mountain_autumn",
+        slots: &[
+            slot(525, 20, 28, 28, F1M1, true),
+            slot(533, 20, 28, 28, F1M3, false),
+            slot(525, 10, 29, 29, F1M1, true),
+            slot(533, 10, 30, 30, F1M3, false),
+            slot(525, 10, 30, 30, F1M1, true),
+            slot(613, 10, 28, 28, F1M1, true),
+            slot(525, 5, 30, 30, F1M1, true),
+            slot(527, 5, 29, 29, F1M1, false),
+            slot(525, 4, 31, 31, F1M1, true),
+            slot(527, 4, 30, 30, F1M1, false),
+            slot(615, 1, 31, 31, Genderless, true),
+            slot(527, 1, 31, 31, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "twist_mountain_winter",
+        slots: &[
+            slot(613, 20, 28, 28, F1M1, true),
+            slot(525, 20, 29, 29, F1M1, true),
+            slot(533, 10, 29, 29, F1M3, false),
+            slot(613, 10, 29, 29, F1M1, true),
+            slot(525, 10, 30, 30, F1M1, true),
+            slot(613, 10, 30, 30, F1M1, true),
+            slot(613, 5, 31, 31, F1M1, true),
+            slot(527, 5, 28, 28, F1M1, false),
+            slot(615, 4, 31, 31, Genderless, true),
+            slot(527, 4, 29, 29, F1M1, false),
+            slot(615, 1, 31, 31, Genderless, true),
+            slot(527, 1, 30, 30, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "dragonspiral_tower_spring_exterior_south",
+        slots: &[
+            slot(520, 20, 30, 30, F1M1, false),
+            slot(585, 20, 31, 31, F1M1, false),
+            slot(619, 10, 30, 30, F1M1, false),
+            slot(585, 10, 32, 32, F1M1, false),
+            slot(619, 10, 31, 31, F1M1, false),
+            slot(520, 10, 32, 32, F1M1, false),
+            slot(619, 5, 32, 32, F1M1, false),
+            slot(621, 5, 31, 31, F1M1, true),
+            slot(619, 4, 33, 33, F1M1, false),
+            slot(621, 4, 32, 32, F1M1, true),
+            slot(619, 1, 33, 33, F1M1, false),
+            slot(621, 1, 33, 33, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "dragonspiral_tower_spring_exterior_northeast",
+        slots: &[
+            slot(520, 20, 31, 31, F1M1, false),
+            slot(585, 20, 30, 30, F1M1, false),
+            slot(619, 10, 30, 30, F1M1, false),
+            slot(585, 10, 32, 32, F1M1, false),
+            slot(619, 10, 31, 31, F1M1, false),
+            slot(520, 10, 32, 32, F1M1, false),
+            slot(619, 5, 32, 32, F1M1, false),
+            slot(621, 5, 31, 31, F1M1, true),
+            slot(619, 4, 33, 33, F1M1, false),
+            slot(621, 4, 32, 32, F1M1, true),
+            slot(619, 1, 33, 33, F1M1, false),
+            slot(621, 1, 33, 33, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "dragonspiral_tower_summer_exterior_south",
+        slots: &[
+            slot(520, 20, 30, 30, F1M1, false),
+            slot(585, 20, 31, 31, F1M1, false),
+            slot(619, 10, 30, 30, F1M1, false),
+            slot(585, 10, 32, 32, F1M1, false),
+            slot(619, 10, 31, 31, F1M1, false),
+            slot(520, 10, 32, 32, F1M1, false),
+            slot(619, 5, 32, 32, F1M1, false),
+            slot(621, 5, 31, 31, F1M1, true),
+            slot(619, 4, 33, 33, F1M1, false),
+            slot(621, 4, 32, 32, F1M1, true),
+            slot(619, 1, 33, 33, F1M1, false),
+            slot(621, 1, 33, 33, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "dragonspiral_tower_summer_exterior_northeast",
+        slots: &[
+            slot(520, 20, 31, 31, F1M1, false),
+            slot(585, 20, 30, 30, F1M1, false),
+            slot(619, 10, 30, 30, F1M1, false),
+            slot(585, 10, 32, 32, F1M1, false),
+            slot(619, 10, 31, 31, F1M1, false),
+            slot(520, 10, 32, 32, F1M1, false),
+            slot(619, 5, 32, 32, F1M1, false),
+            slot(621, 5, 31, 31, F1M1, true),
+            slot(619, 4, 33, 33, F1M1, false),
+            slot(621, 4, 32, 32, F1M1, true),
+            slot(619, 1, 33, 33, F1M1, false),
+            slot(621, 1, 33, 33, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "dragonspiral_tower_autumn_exterior_south",
+        slots: &[
+            slot(520, 20, 30, 30, F1M1, false),
+            slot(585, 20, 31, 31, F1M1, false),
+            slot(619, 10, 30, 30, F1M1, false),
+            slot(585, 10, 32, 32, F1M1, false),
+            slot(619, 10, 31, 31, F1M1, false),
+            slot(520, 10, 32, 32, F1M1, false),
+            slot(619, 5, 32, 32, F1M1, false),
+            slot(621, 5, 31, 31, F1M1, true),
+            slot(619, 4, 33, 33, F1M1, false),
+            slot(621, 4, 32, 32, F1M1, true),
+            slot(619, 1, 33, 33, F1M1, false),
+            slot(621, 1, 33, 33, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "dragonspiral_tower_autumn_exterior_northeast",
+        slots: &[
+            slot(520, 20, 31, 31, F1M1, false),
+            slot(585, 20, 30, 30, F1M1, false),
+            slot(619, 10, 30, 30, F1M1, false),
+            slot(585, 10, 32, 32, F1M1, false),
+            slot(619, 10, 31, 31, F1M1, false),
+            slot(520, 10, 32, 32, F1M1, false),
+            slot(619, 5, 32, 32, F1M1, false),
+            slot(621, 5, 31, 31, F1M1, true),
+            slot(619, 4, 33, 33, F1M1, false),
+            slot(621, 4, 32, 32, F1M1, true),
+            slot(619, 1, 33, 33, F1M1, false),
+            slot(621, 1, 33, 33, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "dragonspiral_tower_winter_exterior_south",
+        slots: &[
+            slot(582, 20, 31, 31, F1M1, false),
+            slot(585, 20, 30, 30, F1M1, false),
+            slot(619, 10, 30, 30, F1M1, false),
+            slot(585, 10, 32, 32, F1M1, false),
+            slot(619, 10, 31, 31, F1M1, false),
+            slot(613, 10, 33, 33, F1M1, true),
+            slot(619, 5, 32, 32, F1M1, false),
+            slot(582, 5, 31, 31, F1M1, false),
+            slot(619, 4, 33, 33, F1M1, false),
+            slot(582, 4, 32, 32, F1M1, false),
+            slot(619, 1, 33, 33, F1M1, false),
+            slot(582, 1, 33, 33, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "dragonspiral_tower_winter_exterior_northeast",
+        slots: &[
+            slot(582, 20, 31, 31, F1M1, false),
+            slot(585, 20, 30, 30, F1M1, false),
+            slot(619, 10, 30, 30, F1M1, false),
+            slot(585, 10, 32, 32, F1M1, false),
+            slot(619, 10, 31, 31, F1M1, false),
+            slot(613, 10, 33, 33, F1M1, true),
+            slot(619, 5, 32, 32, F1M1, false),
+            slot(582, 5, 32, 32, F1M1, false),
+            slot(619, 4, 33, 33, F1M1, false),
+            slot(582, 4, 32, 32, F1M1, false),
+            slot(619, 1, 33, 33, F1M1, false),
+            slot(582, 1, 33, 33, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "dragonspiral_tower_1f",
+        slots: &[
+            slot(622, 20, 30, 30, Genderless, true),
+            slot(619, 20, 33, 33, F1M1, false),
+            slot(622, 10, 31, 31, Genderless, true),
+            slot(621, 10, 30, 30, F1M1, true),
+            slot(622, 10, 32, 32, Genderless, true),
+            slot(621, 10, 31, 31, F1M1, true),
+            slot(622, 5, 32, 32, Genderless, true),
+            slot(621, 5, 32, 32, F1M1, true),
+            slot(622, 4, 33, 33, Genderless, true),
+            slot(621, 4, 33, 33, F1M1, true),
+            slot(622, 1, 33, 33, Genderless, true),
+            slot(621, 1, 33, 33, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "dragonspiral_tower_2f",
+        slots: &[
+            slot(622, 20, 30, 30, Genderless, true),
+            slot(622, 20, 30, 30, Genderless, true),
+            slot(622, 10, 31, 31, Genderless, true),
+            slot(622, 10, 31, 31, Genderless, true),
+            slot(622, 10, 32, 32, Genderless, true),
+            slot(622, 10, 32, 32, Genderless, true),
+            slot(622, 5, 32, 32, Genderless, true),
+            slot(622, 5, 32, 32, Genderless, true),
+            slot(622, 4, 33, 33, Genderless, true),
+            slot(622, 4, 33, 33, Genderless, true),
+            slot(622, 1, 33, 33, Genderless, true),
+            slot(622, 1, 33, 33, Genderless, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "victory_road_1f",
+        slots: &[
+            slot(627, 20, 38, 38, MaleOnly, false),
+            slot(631, 20, 37, 37, F1M1, false),
+            slot(627, 10, 40, 40, MaleOnly, false),
+            slot(631, 10, 38, 38, F1M1, false),
+            slot(619, 10, 38, 38, F1M1, false),
+            slot(631, 10, 39, 39, F1M1, false),
+            slot(619, 5, 40, 40, F1M1, false),
+            slot(611, 5, 40, 40, F1M1, false),
+            slot(627, 4, 37, 37, MaleOnly, false),
+            slot(631, 4, 40, 40, F1M1, false),
+            slot(627, 1, 39, 39, MaleOnly, false),
+            slot(631, 1, 40, 40, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "victory_road_2f",
+        slots: &[
+            slot(632, 20, 37, 37, F1M1, false),
+            slot(525, 20, 39, 39, F1M1, true),
+            slot(632, 10, 38, 38, F1M1, false),
+            slot(633, 10, 38, 38, F1M1, false),
+            slot(527, 10, 37, 37, F1M1, false),
+            slot(619, 10, 39, 39, F1M1, false),
+            slot(632, 5, 39, 39, F1M1, false),
+            slot(633, 5, 38, 38, F1M1, false),
+            slot(632, 4, 40, 40, F1M1, false),
+            slot(633, 4, 39, 39, F1M1, false),
+            slot(632, 1, 40, 40, F1M1, false),
+            slot(633, 1, 40, 40, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "victory_road_3f_4f_5f",
+        slots: &[
+            slot(632, 20, 37, 37, F1M1, false),
+            slot(525, 20, 37, 37, F1M1, true),
+            slot(632, 10, 38, 38, F1M1, false),
+            slot(525, 10, 39, 39, F1M1, true),
+            slot(527, 10, 37, 37, F1M1, false),
+            slot(619, 10, 39, 39, F1M1, false),
+            slot(632, 5, 39, 39, F1M1, false),
+            slot(527, 5, 38, 38, F1M1, false),
+            slot(632, 4, 40, 40, F1M1, false),
+            slot(527, 4, 39, 39, F1M1, false),
+            slot(632, 1, 40, 40, F1M1, false),
+            slot(527, 1, 40, 40, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "victory_road_6f_7f",
+        slots: &[
+            slot(632, 20, 39, 39, F1M1, false),
+            slot(525, 20, 39, 39, F1M1, true),
+            slot(632, 10, 40, 40, F1M1, false),
+            slot(525, 10, 41, 41, F1M1, true),
+            slot(527, 10, 39, 39, F1M1, false),
+            slot(619, 10, 41, 41, F1M1, false),
+            slot(632, 5, 41, 41, F1M1, false),
+            slot(527, 5, 40, 40, F1M1, false),
+            slot(632, 4, 42, 42, F1M1, false),
+            slot(527, 4, 41, 41, F1M1, false),
+            slot(632, 1, 42, 42, F1M1, false),
+            slot(527, 1, 42, 42, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "trial_chamber",
+        slots: &[
+            slot(632, 20, 39, 39, F1M1, false),
+            slot(525, 20, 39, 39, F1M1, true),
+            slot(632, 10, 40, 40, F1M1, false),
+            slot(525, 10, 41, 41, F1M1, true),
+            slot(527, 10, 39, 39, F1M1, false),
+            slot(619, 10, 41, 41, F1M1, false),
+            slot(632, 5, 41, 41, F1M1, false),
+            slot(527, 5, 40, 40, F1M1, false),
+            slot(632, 4, 42, 42, F1M1, false),
+            slot(527, 4, 41, 41, F1M1, false),
+            slot(632, 1, 42, 42, F1M1, false),
+            slot(527, 1, 42, 42, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "giant_chasm_exterior",
+        slots: &[
+            slot(114, 20, 47, 47, F1M1, false),
+            slot(277, 20, 47, 47, F1M1, true),
+            slot(42, 10, 48, 48, F1M1, false),
+            slot(337, 10, 48, 48, Genderless, true),
+            slot(338, 10, 48, 48, Genderless, true),
+            slot(426, 10, 49, 49, F1M1, false),
+            slot(359, 5, 48, 48, F1M1, false),
+            slot(359, 5, 50, 50, F1M1, false),
+            slot(114, 4, 49, 49, F1M1, false),
+            slot(42, 4, 50, 50, F1M1, false),
+            slot(114, 1, 49, 49, F1M1, false),
+            slot(42, 1, 50, 50, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "giant_chasm_cave",
+        slots: &[
+            slot(42, 20, 47, 47, F1M1, false),
+            slot(221, 20, 47, 47, F1M1, false),
+            slot(215, 10, 48, 48, F1M1, true),
+            slot(225, 10, 48, 48, F1M1, false),
+            slot(337, 10, 50, 50, Genderless, true),
+            slot(338, 10, 50, 50, Genderless, true),
+            slot(525, 5, 49, 49, F1M1, true),
+            slot(124, 5, 48, 48, FemaleOnly, true),
+            slot(221, 4, 49, 49, F1M1, false),
+            slot(124, 4, 50, 50, FemaleOnly, true),
+            slot(221, 1, 49, 49, F1M1, false),
+            slot(124, 1, 50, 50, FemaleOnly, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "giant_chasm_crater_forest",
+        slots: &[
+            slot(35, 20, 52, 52, F3M1, true),
+            slot(221, 20, 52, 52, F1M1, false),
+            slot(35, 10, 54, 54, F3M1, true),
+            slot(132, 10, 53, 53, Genderless, true),
+            slot(337, 10, 55, 55, Genderless, true),
+            slot(338, 10, 55, 55, Genderless, true),
+            slot(132, 5, 55, 55, Genderless, true),
+            slot(375, 5, 53, 53, Genderless, true),
+            slot(221, 4, 54, 54, F1M1, false),
+            slot(375, 4, 55, 55, Genderless, true),
+            slot(221, 1, 54, 54, F1M1, false),
+            slot(375, 1, 55, 55, Genderless, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "giant_chasm_cave_depths",
+        slots: &[
+            slot(42, 20, 57, 57, F1M1, false),
+            slot(221, 20, 57, 57, F1M1, false),
+            slot(215, 10, 58, 58, F1M1, true),
+            slot(225, 10, 58, 58, F1M1, false),
+            slot(337, 10, 60, 60, Genderless, true),
+            slot(338, 10, 60, 60, Genderless, true),
+            slot(525, 5, 59, 59, F1M1, true),
+            slot(124, 5, 58, 58, FemaleOnly, true),
+            slot(221, 4, 59, 59, F1M1, false),
+            slot(124, 4, 60, 60, FemaleOnly, true),
+            slot(221, 1, 59, 59, F1M1, false),
+            slot(124, 1, 60, 60, FemaleOnly, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "p2_laboratory",
+        slots: &[
+            slot(505, 20, 28, 28, F1M1, false),
+            slot(507, 20, 28, 28, F1M1, false),
+            slot(505, 10, 29, 29, F1M1, false),
+            slot(507, 10, 29, 29, F1M1, false),
+            slot(599, 10, 29, 29, Genderless, false),
+            slot(559, 10, 29, 29, F1M1, true),
+            slot(505, 5, 30, 30, F1M1, false),
+            slot(507, 5, 30, 30, F1M1, false),
+            slot(599, 4, 31, 31, Genderless, false),
+            slot(559, 4, 31, 31, F1M1, true),
+            slot(505, 1, 31, 31, F1M1, false),
+            slot(507, 1, 31, 31, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "village_bridge",
+        slots: &[
+            slot(55, 20, 47, 47, F1M1, false),
+            slot(400, 20, 47, 47, F1M1, true),
+            slot(335, 10, 48, 48, F1M1, true),
+            slot(336, 10, 48, 48, F1M1, false),
+            slot(627, 10, 48, 48, MaleOnly, false),
+            slot(627, 10, 50, 50, MaleOnly, false),
+            slot(55, 5, 49, 49, F1M1, false),
+            slot(400, 5, 49, 49, F1M1, true),
+            slot(335, 4, 50, 50, F1M1, true),
+            slot(336, 4, 50, 50, F1M1, false),
+            slot(335, 1, 50, 50, F1M1, true),
+            slot(336, 1, 50, 50, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "route_1",
+        slots: &[
+            slot(504, 20, 2, 2, F1M1, false),
+            slot(506, 20, 2, 2, F1M1, false),
+            slot(504, 10, 3, 3, F1M1, false),
+            slot(506, 10, 3, 3, F1M1, false),
+            slot(504, 10, 4, 4, F1M1, false),
+            slot(506, 10, 4, 4, F1M1, false),
+            slot(504, 5, 4, 4, F1M1, false),
+            slot(506, 5, 4, 4, F1M1, false),
+            slot(504, 4, 4, 4, F1M1, false),
+            slot(506, 4, 4, 4, F1M1, false),
+            slot(504, 1, 4, 4, F1M1, false),
+            slot(506, 1, 4, 4, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "route_2",
+        slots: &[
+            slot(504, 20, 4, 4, F1M1, false),
+            slot(506, 20, 4, 4, F1M1, false),
+            slot(504, 10, 5, 5, F1M1, false),
+            slot(506, 10, 5, 5, F1M1, false),
+            slot(509, 10, 4, 4, F1M1, false),
+            slot(509, 10, 5, 5, F1M1, false),
+            slot(504, 5, 6, 6, F1M1, false),
+            slot(506, 5, 6, 6, F1M1, false),
+            slot(504, 4, 7, 7, F1M1, false),
+            slot(506, 4, 7, 7, F1M1, false),
+            slot(504, 1, 7, 7, F1M1, false),
+            slot(506, 1, 7, 7, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "route_3",
+        slots: &[
+            slot(504, 20, 8, 8, F1M1, false),
+            slot(519, 20, 8, 8, F1M1, false),
+            slot(522, 10, 8, 8, F1M1, true),
+            slot(519, 10, 9, 9, F1M1, false),
+            slot(506, 10, 9, 9, F1M1, false),
+            slot(509, 10, 9, 9, F1M1, false),
+            slot(522, 5, 9, 9, F1M1, true),
+            slot(519, 5, 10, 10, F1M1, false),
+            slot(522, 4, 10, 10, F1M1, true),
+            slot(519, 4, 11, 11, F1M1, false),
+            slot(522, 1, 11, 11, F1M1, true),
+            slot(519, 1, 11, 11, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "wellspring_cave",
+        slots: &[
+            slot(524, 20, 10, 10, F1M1, true),
+            slot(527, 20, 10, 10, F1M1, false),
+            slot(524, 10, 11, 11, F1M1, true),
+            slot(527, 10, 11, 11, F1M1, false),
+            slot(524, 10, 11, 11, F1M1, true),
+            slot(527, 10, 11, 11, F1M1, false),
+            slot(524, 5, 12, 12, F1M1, true),
+            slot(527, 5, 12, 12, F1M1, false),
+            slot(524, 4, 13, 13, F1M1, true),
+            slot(527, 4, 13, 13, F1M1, false),
+            slot(524, 1, 13, 13, F1M1, true),
+            slot(527, 1, 13, 13, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "route_4",
+        slots: &[
+            slot(551, 20, 15, 15, F1M1, false),
+            slot(554, 20, 15, 15, F1M1, true),
+            slot(551, 10, 16, 16, F1M1, false),
+            slot(554, 10, 16, 16, F1M1, true),
+            slot(559, 10, 16, 16, F1M1, true),
+            slot(559, 10, 17, 17, F1M1, true),
+            slot(551, 5, 17, 17, F1M1, false),
+            slot(554, 5, 17, 17, F1M1, true),
+            slot(551, 4, 18, 18, F1M1, false),
+            slot(554, 4, 18, 18, F1M1, true),
+            slot(551, 1, 18, 18, F1M1, false),
+            slot(554, 1, 18, 18, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "route_5",
+        slots: &[
+            slot(577, 20, 19, 19, F1M1, false),
+            slot(572, 20, 19, 19, F3M1, true),
+            slot(568, 10, 19, 19, F1M1, true),
+            slot(568, 10, 21, 21, F1M1, true),
+            slot(510, 10, 20, 20, F1M1, false),
+            slot(510, 10, 22, 22, F1M1, false),
+            slot(572, 5, 20, 20, F3M1, true),
+            slot(577, 5, 20, 20, F1M1, false),
+            slot(572, 4, 21, 21, F3M1, true),
+            slot(577, 4, 21, 21, F1M1, false),
+            slot(572, 1, 22, 22, F3M1, true),
+            slot(577, 1, 22, 22, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "route_6_spring",
+        slots: &[
+            slot(585, 20, 22, 22, F1M1, false),
+            slot(588, 20, 22, 22, F1M1, false),
+            slot(520, 10, 23, 23, F1M1, false),
+            slot(590, 10, 23, 23, F1M1, true),
+            slot(585, 10, 23, 23, F1M1, false),
+            slot(541, 10, 23, 23, F1M1, true),
+            slot(585, 5, 24, 24, F1M1, false),
+            slot(588, 5, 24, 24, F1M1, false),
+            slot(520, 4, 25, 25, F1M1, false),
+            slot(590, 4, 25, 25, F1M1, true),
+            slot(520, 1, 25, 25, F1M1, false),
+            slot(590, 1, 25, 25, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "route_6_summer",
+        slots: &[
+            slot(585, 20, 22, 22, F1M1, false),
+            slot(588, 20, 22, 22, F1M1, false),
+            slot(520, 10, 23, 23, F1M1, false),
+            slot(590, 10, 23, 23, F1M1, true),
+            slot(585, 10, 23, 23, F1M1, false),
+            slot(541, 10, 23, 23, F1M1, true),
+            slot(585, 5, 24, 24, F1M1, false),
+            slot(588, 5, 24, 24, F1M1, false),
+            slot(520, 4, 25, 25, F1M1, false),
+            slot(590, 4, 25, 25, F1M1, true),
+            slot(520, 1, 25, 25, F1M1, false),
+            slot(590, 1, 25, 25, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "route_6_autumn",
+        slots: &[
+            slot(585, 20, 22, 22, F1M1, false),
+            slot(588, 20, 22, 22, F1M1, false),
+            slot(520, 10, 23, 23, F1M1, false),
+            slot(590, 10, 23, 23, F1M1, true),
+            slot(585, 10, 23, 23, F1M1, false),
+            slot(541, 10, 23, 23, F1M1, true),
+            slot(585, 5, 24, 24, F1M1, false),
+            slot(588, 5, 24, 24, F1M1, false),
+            slot(520, 4, 25, 25, F1M1, false),
+            slot(590, 4, 25, 25, F1M1, true),
+            slot(520, 1, 25, 25, F1M1, false),
+            slot(590, 1, 25, 25, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "route_6_winter",
+        slots: &[
+            slot(585, 20, 22, 22, F1M1, false),
+            slot(588, 20, 22, 22, F1M1, false),
+            slot(582, 10, 23, 23, F1M1, false),
+            slot(590, 10, 23, 23, F1M1, true),
+            slot(585, 10, 23, 23, F1M1, false),
+            slot(541, 10, 23, 23, F1M1, true),
+            slot(585, 5, 24, 24, F1M1, false),
+            slot(588, 5, 24, 24, F1M1, false),
+            slot(582, 4, 25, 25, F1M1, false),
+            slot(590, 4, 25, 25, F1M1, true),
+            slot(582, 1, 25, 25, F1M1, false),
+            slot(590, 1, 25, 25, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "mistralton_cave",
+        slots: &[
+            slot(525, 20, 28, 28, F1M1, true),
+            slot(527, 20, 28, 28, F1M1, false),
+            slot(525, 10, 29, 29, F1M1, true),
+            slot(527, 10, 30, 30, F1M1, false),
+            slot(525, 10, 30, 30, F1M1, true),
+            slot(610, 10, 30, 30, F1M1, false),
+            slot(525, 5, 30, 30, F1M1, true),
+            slot(610, 5, 31, 31, F1M1, false),
+            slot(525, 4, 31, 31, F1M1, true),
+            slot(610, 4, 31, 31, F1M1, false),
+            slot(525, 1, 31, 31, F1M1, true),
+            slot(610, 1, 31, 31, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "guidance_chamber",
+        slots: &[
+            slot(525, 20, 28, 28, F1M1, true),
+            slot(527, 20, 28, 28, F1M1, false),
+            slot(525, 10, 29, 29, F1M1, true),
+            slot(527, 10, 30, 30, F1M1, false),
+            slot(525, 10, 30, 30, F1M1, true),
+            slot(610, 10, 30, 30, F1M1, false),
+            slot(525, 5, 30, 30, F1M1, true),
+            slot(610, 5, 31, 31, F1M1, false),
+            slot(525, 4, 31, 31, F1M1, true),
+            slot(610, 4, 31, 31, F1M1, false),
+            slot(525, 1, 31, 31, F1M1, true),
+            slot(610, 1, 31, 31, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "route_7_spring",
+        slots: &[
+            slot(520, 20, 26, 26, F1M1, false),
+            slot(585, 20, 26, 26, F1M1, false),
+            slot(505, 10, 27, 27, F1M1, false),
+            slot(523, 10, 27, 27, F1M1, true),
+            slot(520, 10, 28, 28, F1M1, false),
+            slot(523, 10, 29, 29, F1M1, true),
+            slot(505, 5, 28, 28, F1M1, false),
+            slot(590, 5, 27, 27, F1M1, true),
+            slot(505, 4, 29, 29, F1M1, false),
+            slot(590, 4, 29, 29, F1M1, true),
+            slot(505, 1, 29, 29, F1M1, false),
+            slot(590, 1, 29, 29, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "route_7_summer",
+        slots: &[
+            slot(520, 20, 26, 26, F1M1, false),
+            slot(585, 20, 26, 26, F1M1, false),
+            slot(505, 10, 27, 27, F1M1, false),
+            slot(523, 10, 27, 27, F1M1, true),
+            slot(520, 10, 28, 28, F1M1, false),
+            slot(523, 10, 29, 29, F1M1, true),
+            slot(505, 5, 28, 28, F1M1, false),
+            slot(590, 5, 27, 27, F1M1, true),
+            slot(505, 4, 29, 29, F1M1, false),
+            slot(590, 4, 29, 29, F1M1, true),
+            slot(505, 1, 29, 29, F1M1, false),
+            slot(590, 1, 29, 29, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "route_7_autumn",
+        slots: &[
+            slot(520, 20, 26, 26, F1M1, false),
+            slot(585, 20, 26, 26, F1M1, false),
+            slot(505, 10, 27, 27, F1M1, false),
+            slot(523, 10, 27, 27, F1M1, true),
+            slot(520, 10, 28, 28, F1M1, false),
+            slot(523, 10, 29, 29, F1M1, true),
+            slot(505, 5, 28, 28, F1M1, false),
+            slot(590, 5, 27, 27, F1M1, true),
+            slot(505, 4, 29, 29, F1M1, false),
+            slot(590, 4, 29, 29, F1M1, true),
+            slot(505, 1, 29, 29, F1M1, false),
+            slot(590, 1, 29, 29, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "route_7_winter",
+        slots: &[
+            slot(613, 20, 26, 26, F1M1, true),
+            slot(585, 20, 26, 26, F1M1, false),
+            slot(505, 10, 27, 27, F1M1, false),
+            slot(523, 10, 27, 27, F1M1, true),
+            slot(613, 10, 28, 28, F1M1, true),
+            slot(523, 10, 29, 29, F1M1, true),
+            slot(505, 5, 28, 28, F1M1, false),
+            slot(590, 5, 27, 27, F1M1, true),
+            slot(505, 4, 29, 29, F1M1, false),
+            slot(590, 4, 29, 29, F1M1, true),
+            slot(505, 1, 29, 29, F1M1, false),
+            slot(590, 1, 29, 29, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "celestial_tower_2f",
+        slots: &[
+            slot(607, 20, 26, 26, F1M1, false),
+            slot(607, 20, 26, 26, F1M1, false),
+            slot(607, 10, 27, 27, F1M1, false),
+            slot(607, 10, 27, 27, F1M1, false),
+            slot(607, 10, 28, 28, F1M1, false),
+            slot(607, 10, 28, 28, F1M1, false),
+            slot(607, 5, 28, 28, F1M1, false),
+            slot(607, 5, 28, 28, F1M1, false),
+            slot(607, 4, 29, 29, F1M1, false),
+            slot(607, 4, 29, 29, F1M1, false),
+            slot(607, 1, 29, 29, F1M1, false),
+            slot(607, 1, 29, 29, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "celestial_tower_3f",
+        slots: &[
+            slot(607, 20, 26, 26, F1M1, false),
+            slot(607, 20, 26, 26, F1M1, false),
+            slot(607, 10, 27, 27, F1M1, false),
+            slot(607, 10, 27, 27, F1M1, false),
+            slot(607, 10, 28, 28, F1M1, false),
+            slot(605, 10, 26, 26, F1M1, false),
+            slot(607, 5, 28, 28, F1M1, false),
+            slot(607, 5, 28, 28, F1M1, false),
+            slot(607, 4, 29, 29, F1M1, false),
+            slot(605, 4, 27, 27, F1M1, false),
+            slot(607, 1, 29, 29, F1M1, false),
+            slot(605, 1, 28, 28, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "celestial_tower_4f",
+        slots: &[
+            slot(607, 20, 26, 26, F1M1, false),
+            slot(607, 20, 27, 27, F1M1, false),
+            slot(607, 10, 26, 26, F1M1, false),
+            slot(605, 10, 26, 26, F1M1, false),
+            slot(607, 10, 28, 28, F1M1, false),
+            slot(605, 10, 27, 27, F1M1, false),
+            slot(607, 5, 28, 28, F1M1, false),
+            slot(605, 5, 28, 28, F1M1, false),
+            slot(607, 4, 29, 29, F1M1, false),
+            slot(605, 4, 29, 29, F1M1, false),
+            slot(607, 1, 29, 29, F1M1, false),
+            slot(605, 1, 29, 29, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "celestial_tower_5f",
+        slots: &[
+            slot(607, 20, 26, 26, F1M1, false),
+            slot(605, 20, 26, 26, F1M1, false),
+            slot(607, 10, 27, 27, F1M1, false),
+            slot(605, 10, 27, 27, F1M1, false),
+            slot(607, 10, 28, 28, F1M1, false),
+            slot(605, 10, 28, 28, F1M1, false),
+            slot(607, 5, 28, 28, F1M1, false),
+            slot(605, 5, 28, 28, F1M1, false),
+            slot(607, 4, 29, 29, F1M1, false),
+            slot(605, 4, 29, 29, F1M1, false),
+            slot(607, 1, 29, 29, F1M1, false),
+            slot(605, 1, 29, 29, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "route_8_spring",
+        slots: &[
+            slot(536, 20, 30, 30, F1M1, true),
+            slot(616, 20, 30, 30, F1M1, false),
+            slot(536, 10, 31, 31, F1M1, true),
+            slot(616, 10, 31, 31, F1M1, false),
+            slot(618, 10, 31, 31, F1M1, true),
+            slot(618, 10, 32, 32, F1M1, true),
+            slot(536, 5, 32, 32, F1M1, true),
+            slot(616, 5, 32, 32, F1M1, false),
+            slot(536, 4, 33, 33, F1M1, true),
+            slot(616, 4, 33, 33, F1M1, false),
+            slot(536, 1, 33, 33, F1M1, true),
+            slot(616, 1, 33, 33, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "route_8_summer",
+        slots: &[
+            slot(536, 20, 30, 30, F1M1, true),
+            slot(616, 20, 30, 30, F1M1, false),
+            slot(536, 10, 31, 31, F1M1, true),
+            slot(616, 10, 31, 31, F1M1, false),
+            slot(618, 10, 31, 31, F1M1, true),
+            slot(618, 10, 32, 32, F1M1, true),
+            slot(536, 5, 32, 32, F1M1, true),
+            slot(616, 5, 32, 32, F1M1, false),
+            slot(536, 4, 33, 33, F1M1, true),
+            slot(616, 4, 33, 33, F1M1, false),
+            slot(536, 1, 33, 33, F1M1, true),
+            slot(616, 1, 33, 33, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "route_8_autumn",
+        slots: &[
+            slot(536, 20, 30, 30, F1M1, true),
+            slot(616, 20, 30, 30, F1M1, false),
+            slot(536, 10, 31, 31, F1M1, true),
+            slot(616, 10, 31, 31, F1M1, false),
+            slot(618, 10, 31, 31, F1M1, true),
+            slot(618, 10, 32, 32, F1M1, true),
+            slot(536, 5, 32, 32, F1M1, true),
+            slot(616, 5, 32, 32, F1M1, false),
+            slot(536, 4, 33, 33, F1M1, true),
+            slot(616, 4, 33, 33, F1M1, false),
+            slot(536, 1, 33, 33, F1M1, true),
+            slot(616, 1, 33, 33, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "moor_of_icirrus_spring",
+        slots: &[
+            slot(536, 20, 30, 30, F1M1, true),
+            slot(616, 20, 30, 30, F1M1, false),
+            slot(536, 10, 31, 31, F1M1, true),
+            slot(616, 10, 31, 31, F1M1, false),
+            slot(618, 10, 31, 31, F1M1, true),
+            slot(618, 10, 32, 32, F1M1, true),
+            slot(536, 5, 32, 32, F1M1, true),
+            slot(616, 5, 32, 32, F1M1, false),
+            slot(536, 4, 33, 33, F1M1, true),
+            slot(616, 4, 33, 33, F1M1, false),
+            slot(536, 1, 33, 33, F1M1, true),
+            slot(616, 1, 33, 33, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "moor_of_icirrus_summer",
+        slots: &[
+            slot(536, 20, 30, 30, F1M1, true),
+            slot(616, 20, 30, 30, F1M1, false),
+            slot(536, 10, 31, 31, F1M1, true),
+            slot(616, 10, 31, 31, F1M1, false),
+            slot(618, 10, 31, 31, F1M1, true),
+            slot(618, 10, 32, 32, F1M1, true),
+            slot(536, 5, 32, 32, F1M1, true),
+            slot(616, 5, 32, 32, F1M1, false),
+            slot(536, 4, 33, 33, F1M1, true),
+            slot(616, 4, 33, 33, F1M1, false),
+            slot(536, 1, 33, 33, F1M1, true),
+            slot(616, 1, 33, 33, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "moor_of_icirrus_autumn",
+        slots: &[
+            slot(536, 20, 30, 30, F1M1, true),
+            slot(616, 20, 30, 30, F1M1, false),
+            slot(536, 10, 31, 31, F1M1, true),
+            slot(616, 10, 31, 31, F1M1, false),
+            slot(618, 10, 31, 31, F1M1, true),
+            slot(618, 10, 32, 32, F1M1, true),
+            slot(536, 5, 32, 32, F1M1, true),
+            slot(616, 5, 32, 32, F1M1, false),
+            slot(536, 4, 33, 33, F1M1, true),
+            slot(616, 4, 33, 33, F1M1, false),
+            slot(536, 1, 33, 33, F1M1, true),
+            slot(616, 1, 33, 33, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "route_9",
+        slots: &[
+            slot(578, 20, 31, 31, F1M1, false),
+            slot(572, 20, 32, 32, F3M1, true),
+            slot(569, 10, 31, 31, F1M1, true),
+            slot(569, 10, 33, 33, F1M1, true),
+            slot(624, 10, 31, 31, F1M1, false),
+            slot(510, 10, 33, 33, F1M1, false),
+            slot(624, 5, 32, 32, F1M1, false),
+            slot(578, 5, 32, 32, F1M1, false),
+            slot(624, 4, 33, 33, F1M1, false),
+            slot(578, 4, 33, 33, F1M1, false),
+            slot(624, 1, 34, 34, F1M1, false),
+            slot(578, 1, 34, 34, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "challengers_cave_1f",
+        slots: &[
+            slot(525, 20, 48, 48, F1M1, true),
+            slot(527, 20, 47, 47, F1M1, false),
+            slot(75, 10, 47, 47, F1M1, true),
+            slot(75, 10, 49, 49, F1M1, true),
+            slot(302, 10, 49, 49, F1M1, false),
+            slot(108, 10, 49, 49, F1M1, true),
+            slot(303, 5, 49, 49, F1M1, true),
+            slot(108, 5, 50, 50, F1M1, true),
+            slot(303, 4, 49, 49, F1M1, true),
+            slot(108, 4, 50, 50, F1M1, true),
+            slot(303, 1, 49, 49, F1M1, true),
+            slot(108, 1, 49, 49, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "challengers_cave_b1f",
+        slots: &[
+            slot(525, 20, 48, 48, F1M1, true),
+            slot(527, 20, 47, 47, F1M1, false),
+            slot(75, 10, 47, 47, F1M1, true),
+            slot(75, 10, 49, 49, F1M1, true),
+            slot(302, 10, 49, 49, F1M1, false),
+            slot(108, 10, 49, 49, F1M1, true),
+            slot(303, 5, 49, 49, F1M1, true),
+            slot(108, 5, 50, 50, F1M1, true),
+            slot(303, 4, 49, 49, F1M1, true),
+            slot(447, 4, 50, 50, F1M7, false),
+            slot(303, 1, 49, 49, F1M1, true),
+            slot(447, 1, 49, 49, F1M7, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "challengers_cave_b2f",
+        slots: &[
+            slot(525, 20, 48, 48, F1M1, true),
+            slot(527, 20, 47, 47, F1M1, false),
+            slot(75, 10, 47, 47, F1M1, true),
+            slot(75, 10, 49, 49, F1M1, true),
+            slot(302, 10, 49, 49, F1M1, false),
+            slot(108, 10, 49, 49, F1M1, true),
+            slot(303, 5, 49, 49, F1M1, true),
+            slot(447, 5, 50, 50, F1M7, false),
+            slot(303, 4, 49, 49, F1M1, true),
+            slot(447, 4, 50, 50, F1M7, false),
+            slot(303, 1, 49, 49, F1M1, true),
+            slot(447, 1, 49, 49, F1M7, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "route_10",
+        slots: &[
+            slot(507, 20, 33, 33, F1M1, false),
+            slot(627, 20, 34, 34, MaleOnly, false),
+            slot(507, 10, 34, 34, F1M1, false),
+            slot(627, 10, 36, 36, MaleOnly, false),
+            slot(626, 10, 34, 34, F1M1, false),
+            slot(626, 10, 35, 35, F1M1, false),
+            slot(590, 5, 34, 34, F1M1, true),
+            slot(538, 5, 33, 33, MaleOnly, true),
+            slot(590, 4, 34, 34, F1M1, true),
+            slot(538, 4, 36, 36, MaleOnly, true),
+            slot(590, 1, 35, 35, F1M1, true),
+            slot(538, 1, 36, 36, MaleOnly, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "route_11",
+        slots: &[
+            slot(627, 20, 48, 48, MaleOnly, false),
+            slot(55, 20, 47, 47, F1M1, false),
+            slot(207, 10, 47, 47, F1M1, false),
+            slot(591, 10, 48, 48, F1M1, true),
+            slot(335, 10, 48, 48, F1M1, true),
+            slot(336, 10, 48, 48, F1M1, false),
+            slot(588, 5, 49, 49, F1M1, false),
+            slot(624, 5, 50, 50, F1M1, false),
+            slot(207, 4, 50, 50, F1M1, false),
+            slot(627, 4, 50, 50, MaleOnly, false),
+            slot(207, 1, 50, 50, F1M1, false),
+            slot(627, 1, 50, 50, MaleOnly, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "route_12",
+        slots: &[
+            slot(415, 20, 47, 47, F1M7, true),
+            slot(191, 20, 47, 47, F1M1, true),
+            slot(78, 10, 49, 49, F1M1, true),
+            slot(520, 10, 48, 48, F1M1, false),
+            slot(421, 10, 48, 48, F1M1, true),
+            slot(11, 10, 48, 48, F1M1, false),
+            slot(214, 5, 50, 50, F1M1, false),
+            slot(127, 5, 50, 50, F1M1, false),
+            slot(206, 4, 48, 48, F1M1, false),
+            slot(520, 4, 50, 50, F1M1, false),
+            slot(206, 1, 48, 48, F1M1, false),
+            slot(520, 1, 50, 50, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "route_13",
+        slots: &[
+            slot(114, 20, 47, 47, F1M1, false),
+            slot(277, 20, 47, 47, F1M1, true),
+            slot(42, 10, 48, 48, F1M1, false),
+            slot(337, 10, 48, 48, Genderless, true),
+            slot(338, 10, 48, 48, Genderless, true),
+            slot(426, 10, 49, 49, F1M1, false),
+            slot(359, 5, 48, 48, F1M1, false),
+            slot(359, 5, 50, 50, F1M1, false),
+            slot(114, 4, 49, 49, F1M1, false),
+            slot(42, 4, 50, 50, F1M1, false),
+            slot(114, 1, 49, 49, F1M1, false),
+            slot(42, 1, 50, 50, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "route_14",
+        slots: &[
+            slot(55, 20, 47, 47, F1M1, false),
+            slot(39, 20, 48, 48, F3M1, false),
+            slot(357, 10, 48, 48, F1M1, false),
+            slot(619, 10, 49, 49, F1M1, false),
+            slot(334, 10, 49, 49, F1M1, false),
+            slot(426, 10, 49, 49, F1M1, false),
+            slot(213, 5, 49, 49, F1M1, true),
+            slot(606, 5, 48, 48, F1M1, false),
+            slot(357, 4, 50, 50, F1M1, false),
+            slot(606, 4, 50, 50, F1M1, false),
+            slot(357, 1, 50, 50, F1M1, false),
+            slot(606, 1, 50, 50, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "abundant_shrine",
+        slots: &[
+            slot(37, 20, 47, 47, F3M1, true),
+            slot(358, 20, 47, 47, F1M1, true),
+            slot(548, 10, 48, 48, FemaleOnly, false),
+            slot(358, 10, 49, 49, F1M1, true),
+            slot(234, 10, 48, 48, F1M1, false),
+            slot(164, 10, 48, 48, F1M1, false),
+            slot(200, 5, 48, 48, F1M1, false),
+            slot(437, 5, 48, 48, Genderless, true),
+            slot(164, 4, 50, 50, F1M1, false),
+            slot(437, 4, 50, 50, Genderless, true),
+            slot(164, 1, 50, 50, F1M1, false),
+            slot(437, 1, 50, 50, Genderless, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "route_15",
+        slots: &[
+            slot(105, 20, 47, 47, F1M1, true),
+            slot(22, 20, 48, 48, F1M1, true),
+            slot(207, 10, 47, 47, F1M1, false),
+            slot(115, 10, 49, 49, FemaleOnly, false),
+            slot(22, 10, 50, 50, F1M1, true),
+            slot(538, 10, 48, 48, MaleOnly, true),
+            slot(247, 5, 48, 48, F1M1, false),
+            slot(538, 5, 49, 49, MaleOnly, true),
+            slot(247, 4, 50, 50, F1M1, false),
+            slot(207, 4, 49, 49, F1M1, false),
+            slot(247, 1, 50, 50, F1M1, false),
+            slot(207, 1, 49, 49, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "route_16",
+        slots: &[
+            slot(577, 20, 19, 19, F1M1, false),
+            slot(572, 20, 19, 19, F3M1, true),
+            slot(568, 10, 19, 19, F1M1, true),
+            slot(568, 10, 21, 21, F1M1, true),
+            slot(510, 10, 20, 20, F1M1, false),
+            slot(510, 10, 22, 22, F1M1, false),
+            slot(572, 5, 20, 20, F3M1, true),
+            slot(577, 5, 20, 20, F1M1, false),
+            slot(572, 4, 21, 21, F3M1, true),
+            slot(577, 4, 21, 21, F1M1, false),
+            slot(572, 1, 22, 22, F3M1, true),
+            slot(577, 1, 22, 22, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "lostlorn_forest",
+        slots: &[
+            slot(548, 20, 19, 19, FemaleOnly, false),
+            slot(541, 20, 20, 20, F1M1, true),
+            slot(548, 10, 20, 20, FemaleOnly, false),
+            slot(541, 10, 21, 21, F1M1, true),
+            slot(520, 10, 21, 21, F1M1, false),
+            slot(543, 10, 20, 20, F1M1, true),
+            slot(520, 5, 22, 22, F1M1, false),
+            slot(543, 5, 21, 21, F1M1, true),
+            slot(548, 4, 21, 21, FemaleOnly, false),
+            slot(541, 4, 22, 22, F1M1, true),
+            slot(548, 1, 22, 22, FemaleOnly, false),
+            slot(541, 1, 22, 22, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Normal,
+        location_key: "route_18",
+        slots: &[
+            slot(559, 20, 28, 28, F1M1, true),
+            slot(557, 20, 30, 30, F1M1, true),
+            slot(505, 10, 28, 28, F1M1, false),
+            slot(557, 10, 31, 31, F1M1, true),
+            slot(559, 10, 29, 29, F1M1, true),
+            slot(505, 10, 30, 30, F1M1, false),
+            slot(559, 5, 30, 30, F1M1, true),
+            slot(538, 5, 29, 29, MaleOnly, true),
+            slot(559, 4, 31, 31, F1M1, true),
+            slot(538, 4, 31, 31, MaleOnly, true),
+            slot(559, 1, 31, 31, F1M1, true),
+            slot(538, 1, 31, 31, MaleOnly, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: ShakingGrass,
+        location_key: "dreamyard",
+        slots: &[
+            slot(531, 20, 8, 8, F1M1, true),
+            slot(531, 20, 8, 8, F1M1, true),
+            slot(531, 10, 9, 9, F1M1, true),
+            slot(531, 10, 9, 9, F1M1, true),
+            slot(531, 10, 10, 10, F1M1, true),
+            slot(531, 10, 11, 11, F1M1, true),
+            slot(531, 5, 11, 11, F1M1, true),
+            slot(531, 5, 11, 11, F1M1, true),
+            slot(531, 4, 11, 11, F1M1, true),
+            slot(518, 4, 11, 11, F1M1, false),
+            slot(531, 1, 11, 11, F1M1, true),
+            slot(518, 1, 11, 11, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: ShakingGrass,
+        location_key: "pinwheel_forest_exterior",
+        slots: &[
+            slot(531, 20, 12, 12, F1M1, true),
+            slot(531, 20, 12, 12, F1M1, true),
+            slot(531, 10, 13, 13, F1M1, true),
+            slot(531, 10, 13, 13, F1M1, true),
+            slot(531, 10, 14, 14, F1M1, true),
+            slot(531, 10, 15, 15, F1M1, true),
+            slot(531, 5, 15, 15, F1M1, true),
+            slot(531, 5, 15, 15, F1M1, true),
+            slot(531, 4, 15, 15, F1M1, true),
+            slot(539, 4, 15, 15, MaleOnly, true),
+            slot(531, 1, 15, 15, F1M1, true),
+            slot(539, 1, 15, 15, MaleOnly, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: ShakingGrass,
+        location_key: "pinwheel_forest_interior",
+        slots: &[
+            slot(531, 20, 14, 14, F1M1, true),
+            slot(531, 20, 15, 15, F1M1, true),
+            slot(511, 10, 15, 15, F1M7, true),
+            slot(513, 10, 15, 15, F1M7, true),
+            slot(515, 10, 15, 15, F1M7, true),
+            slot(531, 10, 17, 17, F1M1, true),
+            slot(531, 5, 17, 17, F1M1, true),
+            slot(531, 5, 17, 17, F1M1, true),
+            slot(531, 4, 17, 17, F1M1, true),
+            slot(549, 4, 17, 17, FemaleOnly, false),
+            slot(531, 1, 17, 17, F1M1, true),
+            slot(549, 1, 17, 17, FemaleOnly, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: ShakingGrass,
+        location_key: "cold_storage",
+        slots: &[
+            slot(531, 20, 20, 20, F1M1, true),
+            slot(531, 20, 20, 20, F1M1, true),
+            slot(531, 10, 21, 21, F1M1, true),
+            slot(531, 10, 21, 21, F1M1, true),
+            slot(531, 10, 22, 22, F1M1, true),
+            slot(531, 10, 23, 23, F1M1, true),
+            slot(531, 5, 23, 23, F1M1, true),
+            slot(531, 5, 23, 23, F1M1, true),
+            slot(573, 4, 23, 23, F3M1, true),
+            slot(508, 4, 23, 23, F1M1, false),
+            slot(573, 1, 23, 23, F3M1, true),
+            slot(508, 1, 23, 23, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: ShakingGrass,
+        location_key: "dragonspiral_tower_spring_exterior_south",
+        slots: &[
+            slot(531, 20, 30, 30, F1M1, true),
+            slot(531, 20, 30, 30, F1M1, true),
+            slot(587, 10, 31, 31, F1M1, true),
+            slot(531, 10, 31, 31, F1M1, true),
+            slot(531, 10, 32, 32, F1M1, true),
+            slot(531, 10, 33, 33, F1M1, true),
+            slot(531, 5, 33, 33, F1M1, true),
+            slot(531, 5, 33, 33, F1M1, true),
+            slot(531, 4, 33, 33, F1M1, true),
+            slot(521, 4, 33, 33, F1M1, false),
+            slot(531, 1, 33, 33, F1M1, true),
+            slot(521, 1, 33, 33, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: ShakingGrass,
+        location_key: "dragonspiral_tower_summer_exterior_south",
+        slots: &[
+            slot(531, 20, 30, 30, F1M1, true),
+            slot(531, 20, 30, 30, F1M1, true),
+            slot(587, 10, 31, 31, F1M1, true),
+            slot(531, 10, 31, 31, F1M1, true),
+            slot(531, 10, 32, 32, F1M1, true),
+            slot(531, 10, 33, 33, F1M1, true),
+            slot(531, 5, 33, 33, F1M1, true),
+            slot(531, 5, 33, 33, F1M1, true),
+            slot(531, 4, 33, 33, F1M1, true),
+            slot(521, 4, 33, 33, F1M1, false),
+            slot(531, 1, 33, 33, F1M1, true),
+            slot(521, 1, 33, 33, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: ShakingGrass,
+        location_key: "dragonspiral_tower_autumn_exterior_south",
+        slots: &[
+            slot(531, 20, 30, 30, F1M1, true),
+            slot(531, 20, 30, 30, F1M1, true),
+            slot(587, 10, 31, 31, F1M1, true),
+            slot(531, 10, 31, 31, F1M1, true),
+            slot(531, 10, 32, 32, F1M1, true),
+            slot(531, 10, 33, 33, F1M1, true),
+            slot(531, 5, 33, 33, F1M1, true),
+            slot(531, 5, 33, 33, F1M1, true),
+            slot(531, 4, 33, 33, F1M1, true),
+            slot(521, 4, 33, 33, F1M1, false),
+            slot(531, 1, 33, 33, F1M1, true),
+            slot(521, 1, 33, 33, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: ShakingGrass,
+        location_key: "dragonspiral_tower_winter_exterior_south",
+        slots: &[
+            slot(531, 20, 30, 30, F1M1, true),
+            slot(531, 20, 30, 30, F1M1, true),
+            slot(587, 10, 31, 31, F1M1, true),
+            slot(531, 10, 31, 31, F1M1, true),
+            slot(531, 10, 32, 32, F1M1, true),
+            slot(531, 10, 33, 33, F1M1, true),
+            slot(531, 5, 33, 33, F1M1, true),
+            slot(531, 5, 33, 33, F1M1, true),
+            slot(531, 4, 33, 33, F1M1, true),
+            slot(531, 4, 33, 33, F1M1, true),
+            slot(531, 1, 33, 33, F1M1, true),
+            slot(531, 1, 33, 33, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: ShakingGrass,
+        location_key: "giant_chasm_exterior",
+        slots: &[
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(587, 10, 48, 48, F1M1, true),
+            slot(531, 10, 48, 48, F1M1, true),
+            slot(531, 10, 49, 49, F1M1, true),
+            slot(531, 10, 50, 50, F1M1, true),
+            slot(531, 5, 50, 50, F1M1, true),
+            slot(531, 5, 50, 50, F1M1, true),
+            slot(169, 4, 50, 50, F1M1, false),
+            slot(465, 4, 50, 50, F1M1, false),
+            slot(169, 1, 50, 50, F1M1, false),
+            slot(465, 1, 50, 50, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: ShakingGrass,
+        location_key: "giant_chasm_crater_forest",
+        slots: &[
+            slot(531, 20, 52, 52, F1M1, true),
+            slot(531, 20, 52, 52, F1M1, true),
+            slot(531, 10, 53, 53, F1M1, true),
+            slot(531, 10, 53, 53, F1M1, true),
+            slot(531, 10, 54, 54, F1M1, true),
+            slot(531, 10, 55, 55, F1M1, true),
+            slot(531, 5, 55, 55, F1M1, true),
+            slot(473, 5, 55, 55, F1M1, false),
+            slot(36, 4, 55, 55, F3M1, true),
+            slot(376, 4, 55, 55, Genderless, true),
+            slot(36, 1, 55, 55, F3M1, true),
+            slot(376, 1, 55, 55, Genderless, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: ShakingGrass,
+        location_key: "p2_laboratory",
+        slots: &[
+            slot(531, 20, 28, 28, F1M1, true),
+            slot(531, 20, 28, 28, F1M1, true),
+            slot(531, 10, 29, 29, F1M1, true),
+            slot(531, 10, 29, 29, F1M1, true),
+            slot(531, 10, 30, 30, F1M1, true),
+            slot(531, 10, 30, 30, F1M1, true),
+            slot(531, 5, 31, 31, F1M1, true),
+            slot(531, 5, 31, 31, F1M1, true),
+            slot(531, 4, 31, 31, F1M1, true),
+            slot(508, 4, 31, 31, F1M1, false),
+            slot(531, 1, 31, 31, F1M1, true),
+            slot(508, 1, 31, 31, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: ShakingGrass,
+        location_key: "village_bridge",
+        slots: &[
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(587, 10, 48, 48, F1M1, true),
+            slot(531, 10, 48, 48, F1M1, true),
+            slot(531, 10, 49, 49, F1M1, true),
+            slot(531, 10, 50, 50, F1M1, true),
+            slot(531, 5, 50, 50, F1M1, true),
+            slot(531, 5, 50, 50, F1M1, true),
+            slot(531, 4, 50, 50, F1M1, true),
+            slot(531, 4, 50, 50, F1M1, true),
+            slot(531, 1, 50, 50, F1M1, true),
+            slot(531, 1, 50, 50, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: ShakingGrass,
+        location_key: "route_1",
+        slots: &[
+            slot(531, 20, 2, 2, F1M1, true),
+            slot(531, 20, 2, 2, F1M1, true),
+            slot(531, 10, 3, 3, F1M1, true),
+            slot(531, 10, 3, 3, F1M1, true),
+            slot(531, 10, 4, 4, F1M1, true),
+            slot(531, 10, 4, 4, F1M1, true),
+            slot(531, 5, 4, 4, F1M1, true),
+            slot(531, 5, 4, 4, F1M1, true),
+            slot(531, 4, 4, 4, F1M1, true),
+            slot(531, 4, 4, 4, F1M1, true),
+            slot(531, 1, 4, 4, F1M1, true),
+            slot(531, 1, 4, 4, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: ShakingGrass,
+        location_key: "route_2",
+        slots: &[
+            slot(531, 20, 4, 4, F1M1, true),
+            slot(531, 20, 4, 4, F1M1, true),
+            slot(531, 10, 5, 5, F1M1, true),
+            slot(531, 10, 5, 5, F1M1, true),
+            slot(531, 10, 6, 6, F1M1, true),
+            slot(531, 10, 7, 7, F1M1, true),
+            slot(531, 5, 7, 7, F1M1, true),
+            slot(531, 5, 7, 7, F1M1, true),
+            slot(531, 4, 7, 7, F1M1, true),
+            slot(531, 4, 7, 7, F1M1, true),
+            slot(531, 1, 7, 7, F1M1, true),
+            slot(531, 1, 7, 7, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: ShakingGrass,
+        location_key: "route_3",
+        slots: &[
+            slot(531, 20, 8, 8, F1M1, true),
+            slot(531, 20, 8, 8, F1M1, true),
+            slot(531, 10, 9, 9, F1M1, true),
+            slot(531, 10, 9, 9, F1M1, true),
+            slot(531, 10, 10, 10, F1M1, true),
+            slot(531, 10, 11, 11, F1M1, true),
+            slot(531, 5, 11, 11, F1M1, true),
+            slot(531, 5, 11, 11, F1M1, true),
+            slot(531, 4, 11, 11, F1M1, true),
+            slot(531, 4, 11, 11, F1M1, true),
+            slot(531, 1, 11, 11, F1M1, true),
+            slot(531, 1, 11, 11, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: ShakingGrass,
+        location_key: "route_5",
+        slots: &[
+            slot(531, 20, 19, 19, F1M1, true),
+            slot(531, 20, 19, 19, F1M1, true),
+            slot(587, 10, 20, 20, F1M1, true),
+            slot(531, 10, 20, 20, F1M1, true),
+            slot(531, 10, 21, 21, F1M1, true),
+            slot(531, 10, 22, 22, F1M1, true),
+            slot(531, 5, 22, 22, F1M1, true),
+            slot(531, 5, 22, 22, F1M1, true),
+            slot(573, 4, 22, 22, F3M1, true),
+            slot(531, 4, 22, 22, F1M1, true),
+            slot(573, 1, 22, 22, F3M1, true),
+            slot(531, 1, 22, 22, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: ShakingGrass,
+        location_key: "route_6_spring",
+        slots: &[
+            slot(531, 20, 22, 22, F1M1, true),
+            slot(531, 20, 23, 23, F1M1, true),
+            slot(587, 10, 23, 23, F1M1, true),
+            slot(587, 10, 25, 25, F1M1, true),
+            slot(531, 10, 24, 24, F1M1, true),
+            slot(531, 10, 25, 25, F1M1, true),
+            slot(531, 5, 25, 25, F1M1, true),
+            slot(531, 5, 25, 25, F1M1, true),
+            slot(521, 4, 25, 25, F1M1, false),
+            slot(542, 4, 25, 25, F1M1, true),
+            slot(521, 1, 25, 25, F1M1, false),
+            slot(542, 1, 25, 25, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: ShakingGrass,
+        location_key: "route_6_summer",
+        slots: &[
+            slot(531, 20, 22, 22, F1M1, true),
+            slot(531, 20, 23, 23, F1M1, true),
+            slot(587, 10, 23, 23, F1M1, true),
+            slot(587, 10, 25, 25, F1M1, true),
+            slot(531, 10, 24, 24, F1M1, true),
+            slot(531, 10, 25, 25, F1M1, true),
+            slot(531, 5, 25, 25, F1M1, true),
+            slot(531, 5, 25, 25, F1M1, true),
+            slot(521, 4, 25, 25, F1M1, false),
+            slot(542, 4, 25, 25, F1M1, true),
+            slot(521, 1, 25, 25, F1M1, false),
+            slot(542, 1, 25, 25, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: ShakingGrass,
+        location_key: "route_6_autumn",
+        slots: &[
+            slot(531, 20, 22, 22, F1M1, true),
+            slot(531, 20, 23, 23, F1M1, true),
+            slot(587, 10, 23, 23, F1M1, true),
+            slot(587, 10, 25, 25, F1M1, true),
+            slot(531, 10, 24, 24, F1M1, true),
+            slot(531, 10, 25, 25, F1M1, true),
+            slot(531, 5, 25, 25, F1M1, true),
+            slot(531, 5, 25, 25, F1M1, true),
+            slot(521, 4, 25, 25, F1M1, false),
+            slot(542, 4, 25, 25, F1M1, true),
+            slot(521, 1, 25, 25, F1M1, false),
+            slot(542, 1, 25, 25, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: ShakingGrass,
+        location_key: "route_6_winter",
+        slots: &[
+            slot(531, 20, 22, 22, F1M1, true),
+            slot(531, 20, 23, 23, F1M1, true),
+            slot(587, 10, 23, 23, F1M1, true),
+            slot(587, 10, 25, 25, F1M1, true),
+            slot(531, 10, 24, 24, F1M1, true),
+            slot(531, 10, 25, 25, F1M1, true),
+            slot(531, 5, 25, 25, F1M1, true),
+            slot(531, 5, 25, 25, F1M1, true),
+            slot(531, 4, 25, 25, F1M1, true),
+            slot(542, 4, 25, 25, F1M1, true),
+            slot(531, 1, 25, 25, F1M1, true),
+            slot(542, 1, 25, 25, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: ShakingGrass,
+        location_key: "route_7_spring",
+        slots: &[
+            slot(531, 20, 26, 26, F1M1, true),
+            slot(531, 20, 26, 26, F1M1, true),
+            slot(587, 10, 27, 27, F1M1, true),
+            slot(531, 10, 27, 27, F1M1, true),
+            slot(531, 10, 28, 28, F1M1, true),
+            slot(531, 10, 29, 29, F1M1, true),
+            slot(531, 5, 29, 29, F1M1, true),
+            slot(531, 5, 29, 29, F1M1, true),
+            slot(531, 4, 29, 29, F1M1, true),
+            slot(521, 4, 29, 29, F1M1, false),
+            slot(531, 1, 29, 29, F1M1, true),
+            slot(521, 1, 29, 29, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: ShakingGrass,
+        location_key: "route_7_summer",
+        slots: &[
+            slot(531, 20, 26, 26, F1M1, true),
+            slot(531, 20, 26, 26, F1M1, true),
+            slot(587, 10, 27, 27, F1M1, true),
+            slot(531, 10, 27, 27, F1M1, true),
+            slot(531, 10, 28, 28, F1M1, true),
+            slot(531, 10, 29, 29, F1M1, true),
+            slot(531, 5, 29, 29, F1M1, true),
+            slot(531, 5, 29, 29, F1M1, true),
+            slot(531, 4, 29, 29, F1M1, true),
+            slot(521, 4, 29, 29, F1M1, false),
+            slot(531, 1, 29, 29, F1M1, true),
+            slot(521, 1, 29, 29, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: ShakingGrass,
+        location_key: "route_7_autumn",
+        slots: &[
+            slot(531, 20, 26, 26, F1M1, true),
+            slot(531, 20, 26, 26, F1M1, true),
+            slot(587, 10, 27, 27, F1M1, true),
+            slot(531, 10, 27, 27, F1M1, true),
+            slot(531, 10, 28, 28, F1M1, true),
+            slot(531, 10, 29, 29, F1M1, true),
+            slot(531, 5, 29, 29, F1M1, true),
+            slot(531, 5, 29, 29, F1M1, true),
+            slot(531, 4, 29, 29, F1M1, true),
+            slot(521, 4, 29, 29, F1M1, false),
+            slot(531, 1, 29, 29, F1M1, true),
+            slot(521, 1, 29, 29, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: ShakingGrass,
+        location_key: "route_7_winter",
+        slots: &[
+            slot(531, 20, 26, 26, F1M1, true),
+            slot(531, 20, 26, 26, F1M1, true),
+            slot(587, 10, 27, 27, F1M1, true),
+            slot(531, 10, 27, 27, F1M1, true),
+            slot(531, 10, 28, 28, F1M1, true),
+            slot(531, 10, 29, 29, F1M1, true),
+            slot(531, 5, 29, 29, F1M1, true),
+            slot(531, 5, 29, 29, F1M1, true),
+            slot(531, 4, 29, 29, F1M1, true),
+            slot(531, 4, 29, 29, F1M1, true),
+            slot(531, 1, 29, 29, F1M1, true),
+            slot(531, 1, 29, 29, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: ShakingGrass,
+        location_key: "route_9",
+        slots: &[
+            slot(531, 20, 31, 31, F1M1, true),
+            slot(531, 20, 31, 31, F1M1, true),
+            slot(587, 10, 32, 32, F1M1, true),
+            slot(531, 10, 32, 32, F1M1, true),
+            slot(531, 10, 33, 33, F1M1, true),
+            slot(531, 10, 34, 34, F1M1, true),
+            slot(531, 5, 34, 34, F1M1, true),
+            slot(531, 5, 34, 34, F1M1, true),
+            slot(573, 4, 34, 34, F3M1, true),
+            slot(579, 4, 34, 34, F1M1, false),
+            slot(573, 1, 34, 34, F3M1, true),
+            slot(579, 1, 34, 34, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: ShakingGrass,
+        location_key: "route_10",
+        slots: &[
+            slot(531, 20, 33, 33, F1M1, true),
+            slot(531, 20, 33, 33, F1M1, true),
+            slot(587, 10, 34, 34, F1M1, true),
+            slot(531, 10, 34, 34, F1M1, true),
+            slot(531, 10, 35, 35, F1M1, true),
+            slot(531, 10, 36, 36, F1M1, true),
+            slot(531, 5, 36, 36, F1M1, true),
+            slot(531, 5, 36, 36, F1M1, true),
+            slot(508, 4, 36, 36, F1M1, false),
+            slot(539, 4, 36, 36, MaleOnly, true),
+            slot(508, 1, 36, 36, F1M1, false),
+            slot(539, 1, 36, 36, MaleOnly, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: ShakingGrass,
+        location_key: "route_11",
+        slots: &[
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(587, 10, 48, 48, F1M1, true),
+            slot(531, 10, 48, 48, F1M1, true),
+            slot(531, 10, 49, 49, F1M1, true),
+            slot(531, 10, 50, 50, F1M1, true),
+            slot(531, 5, 50, 50, F1M1, true),
+            slot(531, 5, 50, 50, F1M1, true),
+            slot(472, 4, 50, 50, F1M1, false),
+            slot(531, 4, 50, 50, F1M1, true),
+            slot(472, 1, 50, 50, F1M1, false),
+            slot(531, 1, 50, 50, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: ShakingGrass,
+        location_key: "route_12",
+        slots: &[
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(587, 10, 48, 48, F1M1, true),
+            slot(531, 10, 48, 48, F1M1, true),
+            slot(531, 10, 49, 49, F1M1, true),
+            slot(531, 10, 50, 50, F1M1, true),
+            slot(521, 5, 50, 50, F1M1, false),
+            slot(12, 5, 50, 50, F1M1, true),
+            slot(416, 4, 50, 50, FemaleOnly, true),
+            slot(192, 4, 50, 50, F1M1, false),
+            slot(416, 1, 50, 50, FemaleOnly, true),
+            slot(192, 1, 50, 50, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: ShakingGrass,
+        location_key: "route_13",
+        slots: &[
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(587, 10, 48, 48, F1M1, true),
+            slot(531, 10, 48, 48, F1M1, true),
+            slot(531, 10, 49, 49, F1M1, true),
+            slot(531, 10, 50, 50, F1M1, true),
+            slot(531, 5, 50, 50, F1M1, true),
+            slot(531, 5, 50, 50, F1M1, true),
+            slot(169, 4, 50, 50, F1M1, false),
+            slot(465, 4, 50, 50, F1M1, false),
+            slot(169, 1, 50, 50, F1M1, false),
+            slot(465, 1, 50, 50, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: ShakingGrass,
+        location_key: "route_14",
+        slots: &[
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(587, 10, 48, 48, F1M1, true),
+            slot(531, 10, 48, 48, F1M1, true),
+            slot(531, 10, 49, 49, F1M1, true),
+            slot(531, 10, 50, 50, F1M1, true),
+            slot(531, 5, 50, 50, F1M1, true),
+            slot(531, 5, 50, 50, F1M1, true),
+            slot(40, 4, 50, 50, F3M1, false),
+            slot(531, 4, 50, 50, F1M1, true),
+            slot(40, 1, 50, 50, F3M1, false),
+            slot(531, 1, 50, 50, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: ShakingGrass,
+        location_key: "abundant_shrine",
+        slots: &[
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(587, 10, 48, 48, F1M1, true),
+            slot(531, 10, 48, 48, F1M1, true),
+            slot(531, 10, 49, 49, F1M1, true),
+            slot(531, 10, 50, 50, F1M1, true),
+            slot(429, 5, 50, 50, F1M1, false),
+            slot(549, 5, 50, 50, FemaleOnly, false),
+            slot(531, 4, 50, 50, F1M1, true),
+            slot(38, 4, 50, 50, F3M1, true),
+            slot(531, 1, 50, 50, F1M1, true),
+            slot(38, 1, 50, 50, F3M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: ShakingGrass,
+        location_key: "route_15",
+        slots: &[
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(587, 10, 48, 48, F1M1, true),
+            slot(531, 10, 48, 48, F1M1, true),
+            slot(531, 10, 49, 49, F1M1, true),
+            slot(531, 10, 50, 50, F1M1, true),
+            slot(531, 5, 50, 50, F1M1, true),
+            slot(539, 5, 50, 50, MaleOnly, true),
+            slot(472, 4, 50, 50, F1M1, false),
+            slot(248, 4, 50, 50, F1M1, false),
+            slot(472, 1, 50, 50, F1M1, false),
+            slot(248, 1, 50, 50, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: ShakingGrass,
+        location_key: "route_16",
+        slots: &[
+            slot(531, 20, 19, 19, F1M1, true),
+            slot(531, 20, 19, 19, F1M1, true),
+            slot(587, 10, 20, 20, F1M1, true),
+            slot(531, 10, 20, 20, F1M1, true),
+            slot(531, 10, 21, 21, F1M1, true),
+            slot(531, 10, 22, 22, F1M1, true),
+            slot(531, 5, 22, 22, F1M1, true),
+            slot(531, 5, 22, 22, F1M1, true),
+            slot(573, 4, 22, 22, F3M1, true),
+            slot(531, 4, 22, 22, F1M1, true),
+            slot(573, 1, 22, 22, F3M1, true),
+            slot(531, 1, 22, 22, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: ShakingGrass,
+        location_key: "lostlorn_forest",
+        slots: &[
+            slot(531, 20, 19, 19, F1M1, true),
+            slot(531, 20, 20, 20, F1M1, true),
+            slot(587, 10, 20, 20, F1M1, true),
+            slot(511, 10, 20, 20, F1M7, true),
+            slot(513, 10, 20, 20, F1M7, true),
+            slot(515, 10, 20, 20, F1M7, true),
+            slot(531, 5, 21, 21, F1M1, true),
+            slot(521, 5, 22, 22, F1M1, false),
+            slot(542, 4, 22, 22, F1M1, true),
+            slot(549, 4, 22, 22, FemaleOnly, false),
+            slot(542, 1, 22, 22, F1M1, true),
+            slot(549, 1, 22, 22, FemaleOnly, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: ShakingGrass,
+        location_key: "route_18",
+        slots: &[
+            slot(531, 20, 28, 28, F1M1, true),
+            slot(531, 20, 28, 28, F1M1, true),
+            slot(531, 10, 29, 29, F1M1, true),
+            slot(531, 10, 29, 29, F1M1, true),
+            slot(531, 10, 30, 30, F1M1, true),
+            slot(531, 10, 31, 31, F1M1, true),
+            slot(531, 5, 31, 31, F1M1, true),
+            slot(531, 5, 31, 31, F1M1, true),
+            slot(531, 4, 31, 31, F1M1, true),
+            slot(539, 4, 31, 31, MaleOnly, true),
+            slot(531, 1, 31, 31, F1M1, true),
+            slot(539, 1, 31, 31, MaleOnly, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: DustCloud,
+        location_key: "chargestone_cave_1f",
+        slots: &[
+            slot(529, 20, 24, 24, F1M1, false),
+            slot(529, 20, 24, 24, F1M1, false),
+            slot(529, 10, 25, 25, F1M1, false),
+            slot(529, 10, 25, 25, F1M1, false),
+            slot(529, 10, 26, 26, F1M1, false),
+            slot(529, 10, 26, 26, F1M1, false),
+            slot(529, 5, 27, 27, F1M1, false),
+            slot(529, 5, 27, 27, F1M1, false),
+            slot(529, 4, 27, 27, F1M1, false),
+            slot(529, 4, 27, 27, F1M1, false),
+            slot(529, 1, 27, 27, F1M1, false),
+            slot(529, 1, 27, 27, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: DustCloud,
+        location_key: "twist_mountain_spring",
+        slots: &[
+            slot(529, 20, 28, 28, F1M1, false),
+            slot(529, 20, 28, 28, F1M1, false),
+            slot(529, 10, 29, 29, F1M1, false),
+            slot(529, 10, 29, 29, F1M1, false),
+            slot(529, 10, 30, 30, F1M1, false),
+            slot(529, 10, 30, 30, F1M1, false),
+            slot(529, 5, 31, 31, F1M1, false),
+            slot(529, 5, 31, 31, F1M1, false),
+            slot(529, 4, 31, 31, F1M1, false),
+            slot(529, 4, 31, 31, F1M1, false),
+            slot(529, 1, 31, 31, F1M1, false),
+            slot(529, 1, 31, 31, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: DustCloud,
+        location_key: "twist_mountain_summer",
+        slots: &[
+            slot(529, 20, 28, 28, F1M1, false),
+            slot(529, 20, 28, 28, F1M1, false),
+            slot(529, 10, 29, 29, F1M1, false),
+            slot(529, 10, 29, 29, F1M1, false),
+            slot(529, 10, 30, 30, F1M1, false),
+            slot(529, 10, 30, 30, F1M1, false),
+            slot(529, 5, 31, 31, F1M1, false),
+            slot(529, 5, 31, 31, F1M1, false),
+            slot(529, 4, 31, 31, F1M1, false),
+            slot(529, 4, 31, 31, F1M1, false),
+            slot(529, 1, 31, 31, F1M1, false),
+            slot(529, 1, 31, 31, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: DustCloud,
+        location_key: "twist_mountain_autumn",
+        slots: &[
+            slot(529, 20, 28, 28, F1M1, false),
+            slot(529, 20, 28, 28, F1M1, false),
+            slot(529, 10, 29, 29, F1M1, false),
+            slot(529, 10, 29, 29, F1M1, false),
+            slot(529, 10, 30, 30, F1M1, false),
+            slot(529, 10, 30, 30, F1M1, false),
+            slot(529, 5, 31, 31, F1M1, false),
+            slot(529, 5, 31, 31, F1M1, false),
+            slot(529, 4, 31, 31, F1M1, false),
+            slot(529, 4, 31, 31, F1M1, false),
+            slot(529, 1, 31, 31, F1M1, false),
+            slot(529, 1, 31, 31, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: DustCloud,
+        location_key: "twist_mountain_winter",
+        slots: &[
+            slot(529, 20, 28, 28, F1M1, false),
+            slot(529, 20, 28, 28, F1M1, false),
+            slot(529, 10, 29, 29, F1M1, false),
+            slot(529, 10, 29, 29, F1M1, false),
+            slot(529, 10, 30, 30, F1M1, false),
+            slot(529, 10, 30, 30, F1M1, false),
+            slot(529, 5, 31, 31, F1M1, false),
+            slot(529, 5, 31, 31, F1M1, false),
+            slot(529, 4, 31, 31, F1M1, false),
+            slot(529, 4, 31, 31, F1M1, false),
+            slot(529, 1, 31, 31, F1M1, false),
+            slot(529, 1, 31, 31, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: DustCloud,
+        location_key: "victory_road_1f",
+        slots: &[
+            slot(530, 20, 37, 37, F1M1, false),
+            slot(530, 20, 37, 37, F1M1, false),
+            slot(530, 10, 38, 38, F1M1, false),
+            slot(530, 10, 38, 38, F1M1, false),
+            slot(530, 10, 39, 39, F1M1, false),
+            slot(530, 10, 39, 39, F1M1, false),
+            slot(530, 5, 40, 40, F1M1, false),
+            slot(530, 5, 40, 40, F1M1, false),
+            slot(530, 4, 40, 40, F1M1, false),
+            slot(530, 4, 40, 40, F1M1, false),
+            slot(530, 1, 40, 40, F1M1, false),
+            slot(530, 1, 40, 40, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: DustCloud,
+        location_key: "victory_road_2f",
+        slots: &[
+            slot(530, 20, 39, 39, F1M1, false),
+            slot(530, 20, 39, 39, F1M1, false),
+            slot(530, 10, 40, 40, F1M1, false),
+            slot(530, 10, 40, 40, F1M1, false),
+            slot(530, 10, 41, 41, F1M1, false),
+            slot(530, 10, 41, 41, F1M1, false),
+            slot(530, 5, 42, 42, F1M1, false),
+            slot(530, 5, 42, 42, F1M1, false),
+            slot(530, 4, 42, 42, F1M1, false),
+            slot(530, 4, 42, 42, F1M1, false),
+            slot(530, 1, 42, 42, F1M1, false),
+            slot(530, 1, 42, 42, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: DustCloud,
+        location_key: "trial_chamber",
+        slots: &[
+            slot(530, 20, 39, 39, F1M1, false),
+            slot(530, 20, 39, 39, F1M1, false),
+            slot(530, 10, 40, 40, F1M1, false),
+            slot(530, 10, 40, 40, F1M1, false),
+            slot(530, 10, 41, 41, F1M1, false),
+            slot(530, 10, 41, 41, F1M1, false),
+            slot(530, 5, 42, 42, F1M1, false),
+            slot(530, 5, 42, 42, F1M1, false),
+            slot(530, 4, 42, 42, F1M1, false),
+            slot(530, 4, 42, 42, F1M1, false),
+            slot(530, 1, 42, 42, F1M1, false),
+            slot(530, 1, 42, 42, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: DustCloud,
+        location_key: "giant_chasm",
+        slots: &[
+            slot(530, 20, 47, 47, F1M1, false),
+            slot(530, 20, 47, 47, F1M1, false),
+            slot(530, 10, 48, 48, F1M1, false),
+            slot(530, 10, 48, 48, F1M1, false),
+            slot(530, 10, 49, 49, F1M1, false),
+            slot(530, 10, 49, 49, F1M1, false),
+            slot(530, 5, 50, 50, F1M1, false),
+            slot(530, 5, 50, 50, F1M1, false),
+            slot(530, 4, 50, 50, F1M1, false),
+            slot(530, 4, 50, 50, F1M1, false),
+            slot(530, 1, 50, 50, F1M1, false),
+            slot(530, 1, 50, 50, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: DustCloud,
+        location_key: "wellspring_cave",
+        slots: &[
+            slot(529, 20, 10, 10, F1M1, false),
+            slot(529, 20, 10, 10, F1M1, false),
+            slot(529, 10, 11, 11, F1M1, false),
+            slot(529, 10, 11, 11, F1M1, false),
+            slot(529, 10, 12, 12, F1M1, false),
+            slot(529, 10, 12, 12, F1M1, false),
+            slot(529, 5, 13, 13, F1M1, false),
+            slot(529, 5, 13, 13, F1M1, false),
+            slot(529, 4, 13, 13, F1M1, false),
+            slot(529, 4, 13, 13, F1M1, false),
+            slot(529, 1, 13, 13, F1M1, false),
+            slot(529, 1, 13, 13, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: DustCloud,
+        location_key: "mistralton_cave",
+        slots: &[
+            slot(529, 20, 28, 28, F1M1, false),
+            slot(529, 20, 28, 28, F1M1, false),
+            slot(529, 10, 29, 29, F1M1, false),
+            slot(529, 10, 29, 29, F1M1, false),
+            slot(529, 10, 30, 30, F1M1, false),
+            slot(529, 10, 30, 30, F1M1, false),
+            slot(529, 5, 31, 31, F1M1, false),
+            slot(529, 5, 31, 31, F1M1, false),
+            slot(529, 4, 31, 31, F1M1, false),
+            slot(529, 4, 31, 31, F1M1, false),
+            slot(529, 1, 31, 31, F1M1, false),
+            slot(529, 1, 31, 31, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: DustCloud,
+        location_key: "guidance_chamber",
+        slots: &[
+            slot(529, 20, 28, 28, F1M1, false),
+            slot(529, 20, 28, 28, F1M1, false),
+            slot(529, 10, 29, 29, F1M1, false),
+            slot(529, 10, 29, 29, F1M1, false),
+            slot(529, 10, 30, 30, F1M1, false),
+            slot(529, 10, 30, 30, F1M1, false),
+            slot(529, 5, 31, 31, F1M1, false),
+            slot(529, 5, 31, 31, F1M1, false),
+            slot(529, 4, 31, 31, F1M1, false),
+            slot(529, 4, 31, 31, F1M1, false),
+            slot(529, 1, 31, 31, F1M1, false),
+            slot(529, 1, 31, 31, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: DustCloud,
+        location_key: "challengers_cave",
+        slots: &[
+            slot(530, 20, 47, 47, F1M1, false),
+            slot(530, 20, 47, 47, F1M1, false),
+            slot(530, 10, 48, 48, F1M1, false),
+            slot(530, 10, 48, 48, F1M1, false),
+            slot(530, 10, 49, 49, F1M1, false),
+            slot(530, 10, 49, 49, F1M1, false),
+            slot(530, 5, 50, 50, F1M1, false),
+            slot(530, 5, 50, 50, F1M1, false),
+            slot(530, 4, 50, 50, F1M1, false),
+            slot(530, 4, 50, 50, F1M1, false),
+            slot(530, 1, 50, 50, F1M1, false),
+            slot(530, 1, 50, 50, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: PokemonShadow,
+        location_key: "driftveil_drawbridge",
+        slots: &[
+            slot(580, 20, 22, 22, F1M1, false),
+            slot(580, 20, 22, 22, F1M1, false),
+            slot(580, 10, 23, 23, F1M1, false),
+            slot(580, 10, 23, 23, F1M1, false),
+            slot(580, 10, 24, 24, F1M1, false),
+            slot(580, 10, 24, 24, F1M1, false),
+            slot(580, 5, 25, 25, F1M1, false),
+            slot(580, 5, 25, 25, F1M1, false),
+            slot(580, 4, 25, 25, F1M1, false),
+            slot(580, 4, 25, 25, F1M1, false),
+            slot(580, 1, 25, 25, F1M1, false),
+            slot(580, 1, 25, 25, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: PokemonShadow,
+        location_key: "marvelous_bridge",
+        slots: &[
+            slot(581, 20, 47, 47, F1M1, false),
+            slot(581, 20, 47, 47, F1M1, false),
+            slot(581, 10, 48, 48, F1M1, false),
+            slot(581, 10, 48, 48, F1M1, false),
+            slot(581, 10, 49, 49, F1M1, false),
+            slot(581, 10, 49, 49, F1M1, false),
+            slot(581, 5, 50, 50, F1M1, false),
+            slot(581, 5, 50, 50, F1M1, false),
+            slot(581, 4, 50, 50, F1M1, false),
+            slot(581, 4, 50, 50, F1M1, false),
+            slot(581, 1, 50, 50, F1M1, false),
+            slot(581, 1, 50, 50, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "striaton_city",
+        slots: &[
+            slot(550, 60, 5, 15, F1M1, true),
+            slot(550, 30, 5, 15, F1M1, true),
+            slot(550, 5, 5, 15, F1M1, true),
+            slot(550, 4, 5, 15, F1M1, true),
+            slot(550, 1, 5, 15, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "driftveil_city",
+        slots: &[
+            slot(592, 60, 10, 25, F1M1, false),
+            slot(592, 30, 10, 25, F1M1, false),
+            slot(592, 5, 10, 25, F1M1, false),
+            slot(592, 4, 10, 25, F1M1, false),
+            slot(592, 1, 10, 25, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "icirrus_city_spring",
+        slots: &[
+            slot(618, 60, 15, 35, F1M1, true),
+            slot(618, 30, 15, 35, F1M1, true),
+            slot(618, 5, 15, 35, F1M1, true),
+            slot(618, 4, 15, 35, F1M1, true),
+            slot(618, 1, 15, 35, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "icirrus_city_summer",
+        slots: &[
+            slot(618, 60, 15, 35, F1M1, true),
+            slot(618, 30, 15, 35, F1M1, true),
+            slot(618, 5, 15, 35, F1M1, true),
+            slot(618, 4, 15, 35, F1M1, true),
+            slot(618, 1, 15, 35, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "icirrus_city_autumn",
+        slots: &[
+            slot(618, 60, 15, 35, F1M1, true),
+            slot(618, 30, 15, 35, F1M1, true),
+            slot(618, 5, 15, 35, F1M1, true),
+            slot(618, 4, 15, 35, F1M1, true),
+            slot(618, 1, 15, 35, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "icirrus_city_winter",
+        slots: &[
+            slot(618, 60, 15, 35, F1M1, true),
+            slot(618, 30, 15, 35, F1M1, true),
+            slot(618, 5, 15, 35, F1M1, true),
+            slot(618, 4, 15, 35, F1M1, true),
+            slot(618, 1, 15, 35, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "pinwheel_forest",
+        slots: &[
+            slot(550, 60, 5, 20, F1M1, true),
+            slot(550, 30, 5, 20, F1M1, true),
+            slot(550, 5, 5, 20, F1M1, true),
+            slot(550, 4, 5, 20, F1M1, true),
+            slot(550, 1, 5, 20, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "dragonspiral_tower_spring",
+        slots: &[
+            slot(550, 60, 15, 35, F1M1, true),
+            slot(550, 30, 15, 35, F1M1, true),
+            slot(550, 5, 15, 35, F1M1, true),
+            slot(550, 4, 15, 35, F1M1, true),
+            slot(550, 1, 15, 35, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "dragonspiral_tower_summer",
+        slots: &[
+            slot(550, 60, 15, 35, F1M1, true),
+            slot(550, 30, 15, 35, F1M1, true),
+            slot(550, 5, 15, 35, F1M1, true),
+            slot(550, 4, 15, 35, F1M1, true),
+            slot(550, 1, 15, 35, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "dragonspiral_tower_autumn",
+        slots: &[
+            slot(550, 60, 15, 35, F1M1, true),
+            slot(550, 30, 15, 35, F1M1, true),
+            slot(550, 5, 15, 35, F1M1, true),
+            slot(550, 4, 15, 35, F1M1, true),
+            slot(550, 1, 15, 35, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "dragonspiral_tower_winter",
+        slots: &[
+            slot(550, 60, 15, 35, F1M1, true),
+            slot(550, 30, 15, 35, F1M1, true),
+            slot(550, 5, 15, 35, F1M1, true),
+            slot(550, 4, 15, 35, F1M1, true),
+            slot(550, 1, 15, 35, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "victory_road",
+        slots: &[
+            slot(550, 60, 20, 45, F1M1, true),
+            slot(550, 30, 20, 45, F1M1, true),
+            slot(550, 5, 20, 45, F1M1, true),
+            slot(550, 4, 20, 45, F1M1, true),
+            slot(550, 1, 20, 45, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "giant_chasm",
+        slots: &[
+            slot(550, 60, 25, 55, F1M1, true),
+            slot(86, 30, 25, 55, F1M1, false),
+            slot(550, 5, 25, 55, F1M1, true),
+            slot(550, 4, 25, 55, F1M1, true),
+            slot(550, 1, 25, 55, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "p2_laboratory",
+        slots: &[
+            slot(592, 60, 5, 15, F1M1, false),
+            slot(592, 30, 5, 15, F1M1, false),
+            slot(592, 5, 5, 15, F1M1, false),
+            slot(592, 4, 5, 15, F1M1, false),
+            slot(592, 1, 5, 15, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "undella_bay_spring",
+        slots: &[
+            slot(278, 60, 25, 55, F1M1, false),
+            slot(458, 30, 25, 55, F1M1, false),
+            slot(279, 5, 25, 55, F1M1, false),
+            slot(279, 4, 25, 55, F1M1, false),
+            slot(279, 1, 25, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "undella_bay_summer",
+        slots: &[
+            slot(278, 60, 25, 55, F1M1, false),
+            slot(458, 30, 25, 55, F1M1, false),
+            slot(279, 5, 25, 55, F1M1, false),
+            slot(279, 4, 25, 55, F1M1, false),
+            slot(279, 1, 25, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "undella_bay_autumn",
+        slots: &[
+            slot(278, 60, 25, 55, F1M1, false),
+            slot(458, 30, 25, 55, F1M1, false),
+            slot(279, 5, 25, 55, F1M1, false),
+            slot(279, 4, 25, 55, F1M1, false),
+            slot(279, 1, 25, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "undella_bay_winter",
+        slots: &[
+            slot(278, 60, 25, 55, F1M1, false),
+            slot(363, 30, 25, 55, F1M1, false),
+            slot(279, 5, 25, 55, F1M1, false),
+            slot(279, 4, 25, 55, F1M1, false),
+            slot(279, 1, 25, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "village_bridge",
+        slots: &[
+            slot(550, 60, 25, 55, F1M1, true),
+            slot(550, 30, 25, 55, F1M1, true),
+            slot(550, 5, 25, 55, F1M1, true),
+            slot(550, 4, 25, 55, F1M1, true),
+            slot(550, 1, 25, 55, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "route_1",
+        slots: &[
+            slot(550, 60, 5, 15, F1M1, true),
+            slot(550, 30, 5, 15, F1M1, true),
+            slot(550, 5, 5, 15, F1M1, true),
+            slot(550, 4, 5, 15, F1M1, true),
+            slot(550, 1, 5, 15, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "route_3",
+        slots: &[
+            slot(550, 60, 5, 15, F1M1, true),
+            slot(550, 30, 5, 15, F1M1, true),
+            slot(550, 5, 5, 15, F1M1, true),
+            slot(550, 4, 5, 15, F1M1, true),
+            slot(550, 1, 5, 15, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "wellspring_cave",
+        slots: &[
+            slot(550, 60, 5, 15, F1M1, true),
+            slot(550, 30, 5, 15, F1M1, true),
+            slot(550, 5, 5, 15, F1M1, true),
+            slot(550, 4, 5, 15, F1M1, true),
+            slot(550, 1, 5, 15, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "route_4",
+        slots: &[
+            slot(592, 60, 5, 15, F1M1, false),
+            slot(592, 30, 5, 15, F1M1, false),
+            slot(592, 5, 5, 15, F1M1, false),
+            slot(592, 4, 5, 15, F1M1, false),
+            slot(592, 1, 5, 15, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "route_6_spring",
+        slots: &[
+            slot(550, 60, 10, 25, F1M1, true),
+            slot(550, 30, 10, 25, F1M1, true),
+            slot(550, 5, 10, 25, F1M1, true),
+            slot(550, 4, 10, 25, F1M1, true),
+            slot(550, 1, 10, 25, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "route_6_summer",
+        slots: &[
+            slot(550, 60, 10, 25, F1M1, true),
+            slot(550, 30, 10, 25, F1M1, true),
+            slot(550, 5, 10, 25, F1M1, true),
+            slot(550, 4, 10, 25, F1M1, true),
+            slot(550, 1, 10, 25, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "route_6_autumn",
+        slots: &[
+            slot(550, 60, 10, 25, F1M1, true),
+            slot(550, 30, 10, 25, F1M1, true),
+            slot(550, 5, 10, 25, F1M1, true),
+            slot(550, 4, 10, 25, F1M1, true),
+            slot(550, 1, 10, 25, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "route_6_winter",
+        slots: &[
+            slot(550, 60, 10, 25, F1M1, true),
+            slot(550, 30, 10, 25, F1M1, true),
+            slot(550, 5, 10, 25, F1M1, true),
+            slot(550, 4, 10, 25, F1M1, true),
+            slot(550, 1, 10, 25, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "route_8_spring",
+        slots: &[
+            slot(618, 60, 15, 35, F1M1, true),
+            slot(618, 30, 15, 35, F1M1, true),
+            slot(618, 5, 15, 35, F1M1, true),
+            slot(618, 4, 15, 35, F1M1, true),
+            slot(618, 1, 15, 35, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "route_8_summer",
+        slots: &[
+            slot(618, 60, 15, 35, F1M1, true),
+            slot(618, 30, 15, 35, F1M1, true),
+            slot(618, 5, 15, 35, F1M1, true),
+            slot(618, 4, 15, 35, F1M1, true),
+            slot(618, 1, 15, 35, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "route_8_autumn",
+        slots: &[
+            slot(618, 60, 15, 35, F1M1, true),
+            slot(618, 30, 15, 35, F1M1, true),
+            slot(618, 5, 15, 35, F1M1, true),
+            slot(618, 4, 15, 35, F1M1, true),
+            slot(618, 1, 15, 35, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "route_8_winter",
+        slots: &[
+            slot(618, 60, 15, 35, F1M1, true),
+            slot(618, 30, 15, 35, F1M1, true),
+            slot(618, 5, 15, 35, F1M1, true),
+            slot(618, 4, 15, 35, F1M1, true),
+            slot(618, 1, 15, 35, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "moor_of_icirrus_spring",
+        slots: &[
+            slot(618, 60, 15, 35, F1M1, true),
+            slot(618, 30, 15, 35, F1M1, true),
+            slot(618, 5, 15, 35, F1M1, true),
+            slot(618, 4, 15, 35, F1M1, true),
+            slot(618, 1, 15, 35, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "moor_of_icirrus_summer",
+        slots: &[
+            slot(618, 60, 15, 35, F1M1, true),
+            slot(618, 30, 15, 35, F1M1, true),
+            slot(618, 5, 15, 35, F1M1, true),
+            slot(618, 4, 15, 35, F1M1, true),
+            slot(618, 1, 15, 35, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "moor_of_icirrus_autumn",
+        slots: &[
+            slot(618, 60, 15, 35, F1M1, true),
+            slot(618, 30, 15, 35, F1M1, true),
+            slot(618, 5, 15, 35, F1M1, true),
+            slot(618, 4, 15, 35, F1M1, true),
+            slot(618, 1, 15, 35, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "moor_of_icirrus_winter",
+        slots: &[
+            slot(618, 60, 15, 35, F1M1, true),
+            slot(618, 30, 15, 35, F1M1, true),
+            slot(618, 5, 15, 35, F1M1, true),
+            slot(618, 4, 15, 35, F1M1, true),
+            slot(618, 1, 15, 35, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "challengers_cave",
+        slots: &[
+            slot(550, 60, 25, 55, F1M1, true),
+            slot(550, 30, 25, 55, F1M1, true),
+            slot(550, 5, 25, 55, F1M1, true),
+            slot(550, 4, 25, 55, F1M1, true),
+            slot(550, 1, 25, 55, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "route_11",
+        slots: &[
+            slot(550, 60, 25, 55, F1M1, true),
+            slot(418, 30, 25, 55, F1M1, true),
+            slot(550, 5, 25, 55, F1M1, true),
+            slot(550, 4, 25, 55, F1M1, true),
+            slot(550, 1, 25, 55, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "route_13",
+        slots: &[
+            slot(278, 60, 25, 55, F1M1, false),
+            slot(120, 30, 25, 55, Genderless, true),
+            slot(279, 5, 25, 55, F1M1, false),
+            slot(279, 4, 25, 55, F1M1, false),
+            slot(279, 1, 25, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "route_14",
+        slots: &[
+            slot(550, 60, 25, 55, F1M1, true),
+            slot(418, 30, 25, 55, F1M1, true),
+            slot(550, 5, 25, 55, F1M1, true),
+            slot(550, 4, 25, 55, F1M1, true),
+            slot(550, 1, 25, 55, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "abundant_shrine",
+        slots: &[
+            slot(550, 60, 25, 55, F1M1, true),
+            slot(79, 30, 25, 55, F1M1, true),
+            slot(550, 5, 25, 55, F1M1, true),
+            slot(550, 4, 25, 55, F1M1, true),
+            slot(550, 1, 25, 55, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "lostlorn_forest",
+        slots: &[
+            slot(550, 60, 5, 20, F1M1, true),
+            slot(550, 30, 5, 20, F1M1, true),
+            slot(550, 5, 5, 20, F1M1, true),
+            slot(550, 4, 5, 20, F1M1, true),
+            slot(550, 1, 5, 20, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "route_18",
+        slots: &[
+            slot(592, 60, 5, 15, F1M1, false),
+            slot(592, 30, 5, 15, F1M1, false),
+            slot(592, 5, 5, 15, F1M1, false),
+            slot(592, 4, 5, 15, F1M1, false),
+            slot(592, 1, 5, 15, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "undella_town",
+        slots: &[
+            slot(278, 60, 25, 55, F1M1, false),
+            slot(458, 30, 25, 55, F1M1, false),
+            slot(279, 5, 25, 55, F1M1, false),
+            slot(279, 4, 25, 55, F1M1, false),
+            slot(279, 1, 25, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Surfing,
+        location_key: "route_17",
+        slots: &[
+            slot(592, 60, 5, 15, F1M1, false),
+            slot(592, 30, 5, 15, F1M1, false),
+            slot(592, 5, 5, 15, F1M1, false),
+            slot(592, 4, 5, 15, F1M1, false),
+            slot(592, 1, 5, 15, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "striaton_city",
+        slots: &[
+            slot(550, 60, 5, 20, F1M1, true),
+            slot(550, 30, 5, 20, F1M1, true),
+            slot(550, 5, 5, 20, F1M1, true),
+            slot(550, 4, 5, 20, F1M1, true),
+            slot(550, 1, 5, 20, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "driftveil_city",
+        slots: &[
+            slot(594, 60, 10, 30, F1M1, false),
+            slot(594, 30, 10, 30, F1M1, false),
+            slot(594, 5, 10, 30, F1M1, false),
+            slot(593, 4, 10, 30, F1M1, false),
+            slot(593, 1, 10, 30, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "icirrus_city_spring",
+        slots: &[
+            slot(618, 60, 15, 40, F1M1, true),
+            slot(618, 30, 15, 40, F1M1, true),
+            slot(618, 5, 15, 40, F1M1, true),
+            slot(537, 4, 15, 40, F1M1, true),
+            slot(537, 1, 15, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "icirrus_city_summer",
+        slots: &[
+            slot(618, 60, 15, 40, F1M1, true),
+            slot(618, 30, 15, 40, F1M1, true),
+            slot(618, 5, 15, 40, F1M1, true),
+            slot(537, 4, 15, 40, F1M1, true),
+            slot(537, 1, 15, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "icirrus_city_autumn",
+        slots: &[
+            slot(618, 60, 15, 40, F1M1, true),
+            slot(618, 30, 15, 40, F1M1, true),
+            slot(618, 5, 15, 40, F1M1, true),
+            slot(537, 4, 15, 40, F1M1, true),
+            slot(537, 1, 15, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "icirrus_city_winter",
+        slots: &[
+            slot(618, 60, 15, 40, F1M1, true),
+            slot(618, 30, 15, 40, F1M1, true),
+            slot(618, 5, 15, 40, F1M1, true),
+            slot(537, 4, 15, 40, F1M1, true),
+            slot(537, 1, 15, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "pinwheel_forest",
+        slots: &[
+            slot(550, 60, 5, 20, F1M1, true),
+            slot(550, 30, 5, 20, F1M1, true),
+            slot(550, 5, 5, 20, F1M1, true),
+            slot(550, 4, 5, 20, F1M1, true),
+            slot(550, 1, 5, 20, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "dragonspiral_tower_spring",
+        slots: &[
+            slot(550, 60, 15, 40, F1M1, true),
+            slot(550, 30, 15, 40, F1M1, true),
+            slot(550, 5, 15, 40, F1M1, true),
+            slot(550, 4, 15, 40, F1M1, true),
+            slot(550, 1, 15, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "dragonspiral_tower_summer",
+        slots: &[
+            slot(550, 60, 15, 40, F1M1, true),
+            slot(550, 30, 15, 40, F1M1, true),
+            slot(550, 5, 15, 40, F1M1, true),
+            slot(550, 4, 15, 40, F1M1, true),
+            slot(550, 1, 15, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "dragonspiral_tower_autumn",
+        slots: &[
+            slot(550, 60, 15, 40, F1M1, true),
+            slot(550, 30, 15, 40, F1M1, true),
+            slot(550, 5, 15, 40, F1M1, true),
+            slot(550, 4, 15, 40, F1M1, true),
+            slot(550, 1, 15, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "dragonspiral_tower_winter",
+        slots: &[
+            slot(550, 60, 15, 40, F1M1, true),
+            slot(550, 30, 15, 40, F1M1, true),
+            slot(550, 5, 15, 40, F1M1, true),
+            slot(550, 4, 15, 40, F1M1, true),
+            slot(550, 1, 15, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "victory_road",
+        slots: &[
+            slot(550, 60, 20, 50, F1M1, true),
+            slot(550, 30, 20, 50, F1M1, true),
+            slot(550, 5, 20, 50, F1M1, true),
+            slot(550, 4, 20, 50, F1M1, true),
+            slot(550, 1, 20, 50, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "giant_chasm",
+        slots: &[
+            slot(86, 60, 25, 60, F1M1, false),
+            slot(550, 30, 25, 60, F1M1, true),
+            slot(87, 5, 35, 70, F1M1, false),
+            slot(87, 4, 35, 70, F1M1, false),
+            slot(87, 1, 35, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "p2_laboratory",
+        slots: &[
+            slot(594, 60, 5, 20, F1M1, false),
+            slot(594, 30, 5, 20, F1M1, false),
+            slot(594, 5, 5, 20, F1M1, false),
+            slot(593, 4, 5, 20, F1M1, false),
+            slot(593, 1, 5, 20, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "undella_bay_spring",
+        slots: &[
+            slot(320, 60, 25, 60, F1M1, false),
+            slot(320, 30, 25, 60, F1M1, false),
+            slot(226, 5, 35, 70, F1M1, false),
+            slot(321, 4, 35, 70, F1M1, false),
+            slot(321, 1, 35, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "undella_bay_summer",
+        slots: &[
+            slot(320, 60, 25, 60, F1M1, false),
+            slot(320, 30, 25, 60, F1M1, false),
+            slot(226, 5, 35, 70, F1M1, false),
+            slot(321, 4, 35, 70, F1M1, false),
+            slot(321, 1, 35, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "undella_bay_autumn",
+        slots: &[
+            slot(320, 60, 25, 60, F1M1, false),
+            slot(320, 30, 25, 60, F1M1, false),
+            slot(226, 5, 35, 70, F1M1, false),
+            slot(321, 4, 35, 70, F1M1, false),
+            slot(321, 1, 35, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "undella_bay_winter",
+        slots: &[
+            slot(320, 60, 25, 60, F1M1, false),
+            slot(364, 30, 25, 60, F1M1, false),
+            slot(321, 5, 35, 70, F1M1, false),
+            slot(365, 4, 35, 70, F1M1, false),
+            slot(365, 1, 35, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "village_bridge",
+        slots: &[
+            slot(550, 60, 25, 60, F1M1, true),
+            slot(550, 30, 25, 60, F1M1, true),
+            slot(550, 5, 35, 70, F1M1, true),
+            slot(131, 4, 35, 70, F1M1, false),
+            slot(131, 1, 35, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "route_1",
+        slots: &[
+            slot(550, 60, 5, 20, F1M1, true),
+            slot(550, 30, 5, 20, F1M1, true),
+            slot(550, 5, 5, 20, F1M1, true),
+            slot(550, 4, 5, 20, F1M1, true),
+            slot(550, 1, 5, 20, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "route_3",
+        slots: &[
+            slot(550, 60, 5, 20, F1M1, true),
+            slot(550, 30, 5, 20, F1M1, true),
+            slot(550, 5, 5, 20, F1M1, true),
+            slot(550, 4, 5, 20, F1M1, true),
+            slot(550, 1, 5, 20, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "wellspring_cave",
+        slots: &[
+            slot(550, 60, 5, 20, F1M1, true),
+            slot(550, 30, 5, 20, F1M1, true),
+            slot(550, 5, 5, 20, F1M1, true),
+            slot(550, 4, 5, 20, F1M1, true),
+            slot(550, 1, 5, 20, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "route_4",
+        slots: &[
+            slot(594, 60, 5, 20, F1M1, false),
+            slot(594, 30, 5, 20, F1M1, false),
+            slot(594, 5, 5, 20, F1M1, false),
+            slot(593, 4, 5, 20, F1M1, false),
+            slot(593, 1, 5, 20, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "route_6_spring",
+        slots: &[
+            slot(550, 60, 10, 30, F1M1, true),
+            slot(550, 30, 10, 30, F1M1, true),
+            slot(550, 5, 10, 30, F1M1, true),
+            slot(550, 4, 10, 30, F1M1, true),
+            slot(550, 1, 10, 30, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "route_6_summer",
+        slots: &[
+            slot(550, 60, 10, 30, F1M1, true),
+            slot(550, 30, 10, 30, F1M1, true),
+            slot(550, 5, 10, 30, F1M1, true),
+            slot(550, 4, 10, 30, F1M1, true),
+            slot(550, 1, 10, 30, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "route_6_autumn",
+        slots: &[
+            slot(550, 60, 10, 30, F1M1, true),
+            slot(550, 30, 10, 30, F1M1, true),
+            slot(550, 5, 10, 30, F1M1, true),
+            slot(550, 4, 10, 30, F1M1, true),
+            slot(550, 1, 10, 30, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "route_6_winter",
+        slots: &[
+            slot(550, 60, 10, 30, F1M1, true),
+            slot(550, 30, 10, 30, F1M1, true),
+            slot(550, 5, 10, 30, F1M1, true),
+            slot(550, 4, 10, 30, F1M1, true),
+            slot(550, 1, 10, 30, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "route_8_spring",
+        slots: &[
+            slot(618, 60, 15, 40, F1M1, true),
+            slot(618, 30, 15, 40, F1M1, true),
+            slot(618, 5, 15, 40, F1M1, true),
+            slot(537, 4, 15, 40, F1M1, true),
+            slot(537, 1, 15, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "route_8_summer",
+        slots: &[
+            slot(618, 60, 15, 40, F1M1, true),
+            slot(618, 30, 15, 40, F1M1, true),
+            slot(618, 5, 15, 40, F1M1, true),
+            slot(537, 4, 15, 40, F1M1, true),
+            slot(537, 1, 15, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "route_8_autumn",
+        slots: &[
+            slot(618, 60, 15, 40, F1M1, true),
+            slot(618, 30, 15, 40, F1M1, true),
+            slot(618, 5, 15, 40, F1M1, true),
+            slot(537, 4, 15, 40, F1M1, true),
+            slot(537, 1, 15, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "route_8_winter",
+        slots: &[
+            slot(618, 60, 15, 40, F1M1, true),
+            slot(618, 30, 15, 40, F1M1, true),
+            slot(618, 5, 15, 40, F1M1, true),
+            slot(537, 4, 15, 40, F1M1, true),
+            slot(537, 1, 15, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "moor_of_icirrus_spring",
+        slots: &[
+            slot(618, 60, 15, 40, F1M1, true),
+            slot(618, 30, 15, 40, F1M1, true),
+            slot(618, 5, 15, 40, F1M1, true),
+            slot(537, 4, 15, 40, F1M1, true),
+            slot(537, 1, 15, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "moor_of_icirrus_summer",
+        slots: &[
+            slot(618, 60, 15, 40, F1M1, true),
+            slot(618, 30, 15, 40, F1M1, true),
+            slot(618, 5, 15, 40, F1M1, true),
+            slot(537, 4, 15, 40, F1M1, true),
+            slot(537, 1, 15, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "moor_of_icirrus_autumn",
+        slots: &[
+            slot(618, 60, 15, 40, F1M1, true),
+            slot(618, 30, 15, 40, F1M1, true),
+            slot(618, 5, 15, 40, F1M1, true),
+            slot(537, 4, 15, 40, F1M1, true),
+            slot(537, 1, 15, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "moor_of_icirrus_winter",
+        slots: &[
+            slot(618, 60, 15, 40, F1M1, true),
+            slot(618, 30, 15, 40, F1M1, true),
+            slot(618, 5, 15, 40, F1M1, true),
+            slot(537, 4, 15, 40, F1M1, true),
+            slot(537, 1, 15, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "challengers_cave",
+        slots: &[
+            slot(550, 60, 25, 70, F1M1, true),
+            slot(550, 30, 25, 70, F1M1, true),
+            slot(550, 5, 25, 70, F1M1, true),
+            slot(550, 4, 25, 70, F1M1, true),
+            slot(550, 1, 25, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "route_11",
+        slots: &[
+            slot(418, 60, 25, 60, F1M1, true),
+            slot(550, 30, 25, 60, F1M1, true),
+            slot(419, 5, 35, 70, F1M1, true),
+            slot(419, 4, 35, 70, F1M1, true),
+            slot(419, 1, 35, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "route_13",
+        slots: &[
+            slot(120, 60, 25, 60, Genderless, true),
+            slot(222, 30, 25, 60, F3M1, true),
+            slot(121, 5, 35, 70, Genderless, true),
+            slot(121, 4, 35, 70, Genderless, true),
+            slot(121, 1, 35, 70, Genderless, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "route_14",
+        slots: &[
+            slot(418, 60, 25, 60, F1M1, true),
+            slot(550, 30, 25, 60, F1M1, true),
+            slot(419, 5, 35, 70, F1M1, true),
+            slot(419, 4, 35, 70, F1M1, true),
+            slot(419, 1, 35, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "abundant_shrine",
+        slots: &[
+            slot(79, 60, 25, 60, F1M1, true),
+            slot(550, 30, 25, 60, F1M1, true),
+            slot(80, 5, 35, 70, F1M1, true),
+            slot(199, 4, 35, 70, F1M1, true),
+            slot(199, 1, 35, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "lostlorn_forest",
+        slots: &[
+            slot(550, 60, 5, 20, F1M1, true),
+            slot(550, 30, 5, 20, F1M1, true),
+            slot(550, 5, 5, 20, F1M1, true),
+            slot(550, 4, 5, 20, F1M1, true),
+            slot(550, 1, 5, 20, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "route_18",
+        slots: &[
+            slot(594, 60, 5, 20, F1M1, false),
+            slot(594, 30, 5, 20, F1M1, false),
+            slot(594, 5, 5, 20, F1M1, false),
+            slot(593, 4, 5, 20, F1M1, false),
+            slot(593, 1, 5, 20, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "undella_town",
+        slots: &[
+            slot(320, 60, 25, 60, F1M1, false),
+            slot(222, 30, 25, 60, F3M1, true),
+            slot(226, 5, 35, 70, F1M1, false),
+            slot(226, 4, 35, 70, F1M1, false),
+            slot(226, 1, 35, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: SurfingBubble,
+        location_key: "route_17",
+        slots: &[
+            slot(594, 60, 5, 20, F1M1, false),
+            slot(594, 30, 5, 20, F1M1, false),
+            slot(594, 5, 5, 20, F1M1, false),
+            slot(593, 4, 5, 20, F1M1, false),
+            slot(593, 1, 5, 20, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "striaton_city",
+        slots: &[
+            slot(118, 60, 35, 55, F1M1, false),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(118, 5, 35, 55, F1M1, false),
+            slot(118, 4, 35, 55, F1M1, false),
+            slot(118, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "driftveil_city",
+        slots: &[
+            slot(98, 60, 35, 55, F1M1, false),
+            slot(170, 30, 35, 55, F1M1, true),
+            slot(98, 5, 35, 55, F1M1, false),
+            slot(370, 4, 35, 55, F3M1, true),
+            slot(370, 1, 35, 55, F3M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "icirrus_city_spring",
+        slots: &[
+            slot(339, 60, 35, 55, F1M1, false),
+            slot(618, 30, 35, 55, F1M1, true),
+            slot(339, 5, 35, 55, F1M1, false),
+            slot(339, 4, 35, 55, F1M1, false),
+            slot(339, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "icirrus_city_summer",
+        slots: &[
+            slot(339, 60, 35, 55, F1M1, false),
+            slot(618, 30, 35, 55, F1M1, true),
+            slot(339, 5, 35, 55, F1M1, false),
+            slot(339, 4, 35, 55, F1M1, false),
+            slot(339, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "icirrus_city_autumn",
+        slots: &[
+            slot(339, 60, 35, 55, F1M1, false),
+            slot(618, 30, 35, 55, F1M1, true),
+            slot(339, 5, 35, 55, F1M1, false),
+            slot(339, 4, 35, 55, F1M1, false),
+            slot(339, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "icirrus_city_winter",
+        slots: &[
+            slot(339, 60, 35, 55, F1M1, false),
+            slot(618, 30, 35, 55, F1M1, true),
+            slot(339, 5, 35, 55, F1M1, false),
+            slot(339, 4, 35, 55, F1M1, false),
+            slot(339, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "pinwheel_forest",
+        slots: &[
+            slot(118, 60, 35, 55, F1M1, false),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(118, 5, 35, 55, F1M1, false),
+            slot(118, 4, 35, 55, F1M1, false),
+            slot(118, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "dragonspiral_tower_spring",
+        slots: &[
+            slot(147, 60, 35, 55, F1M1, true),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(147, 5, 35, 55, F1M1, true),
+            slot(148, 4, 35, 55, F1M1, true),
+            slot(148, 1, 35, 55, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "dragonspiral_tower_summer",
+        slots: &[
+            slot(147, 60, 35, 55, F1M1, true),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(147, 5, 35, 55, F1M1, true),
+            slot(148, 4, 35, 55, F1M1, true),
+            slot(148, 1, 35, 55, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "dragonspiral_tower_autumn",
+        slots: &[
+            slot(147, 60, 35, 55, F1M1, true),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(147, 5, 35, 55, F1M1, true),
+            slot(148, 4, 35, 55, F1M1, true),
+            slot(148, 1, 35, 55, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "dragonspiral_tower_winter",
+        slots: &[
+            slot(147, 60, 35, 55, F1M1, true),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(147, 5, 35, 55, F1M1, true),
+            slot(148, 4, 35, 55, F1M1, true),
+            slot(148, 1, 35, 55, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "victory_road",
+        slots: &[
+            slot(60, 60, 35, 55, F1M1, false),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(61, 5, 35, 55, F1M1, true),
+            slot(60, 4, 35, 55, F1M1, false),
+            slot(60, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "giant_chasm",
+        slots: &[
+            slot(60, 60, 35, 55, F1M1, false),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(61, 5, 35, 55, F1M1, true),
+            slot(60, 4, 35, 55, F1M1, false),
+            slot(60, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "p2_laboratory",
+        slots: &[
+            slot(456, 60, 35, 55, F1M1, true),
+            slot(116, 30, 35, 55, F1M1, true),
+            slot(116, 5, 35, 55, F1M1, true),
+            slot(456, 4, 35, 55, F1M1, true),
+            slot(456, 1, 35, 55, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "undella_bay_spring",
+        slots: &[
+            slot(223, 60, 35, 55, F1M1, false),
+            slot(90, 30, 35, 55, F1M1, true),
+            slot(223, 5, 35, 55, F1M1, false),
+            slot(370, 4, 35, 55, F3M1, true),
+            slot(370, 1, 35, 55, F3M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "undella_bay_summer",
+        slots: &[
+            slot(223, 60, 35, 55, F1M1, false),
+            slot(90, 30, 35, 55, F1M1, true),
+            slot(223, 5, 35, 55, F1M1, false),
+            slot(370, 4, 35, 55, F3M1, true),
+            slot(370, 1, 35, 55, F3M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "undella_bay_autumn",
+        slots: &[
+            slot(223, 60, 35, 55, F1M1, false),
+            slot(90, 30, 35, 55, F1M1, true),
+            slot(223, 5, 35, 55, F1M1, false),
+            slot(370, 4, 35, 55, F3M1, true),
+            slot(370, 1, 35, 55, F3M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "undella_bay_winter",
+        slots: &[
+            slot(223, 60, 35, 55, F1M1, false),
+            slot(90, 30, 35, 55, F1M1, true),
+            slot(223, 5, 35, 55, F1M1, false),
+            slot(370, 4, 35, 55, F3M1, true),
+            slot(370, 1, 35, 55, F3M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "village_bridge",
+        slots: &[
+            slot(318, 60, 35, 55, F1M1, true),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(318, 5, 35, 55, F1M1, true),
+            slot(318, 4, 35, 55, F1M1, true),
+            slot(318, 1, 35, 55, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "route_1",
+        slots: &[
+            slot(550, 60, 35, 55, F1M1, true),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(550, 5, 35, 55, F1M1, true),
+            slot(349, 4, 35, 55, F1M1, false),
+            slot(349, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "route_3",
+        slots: &[
+            slot(118, 60, 35, 55, F1M1, false),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(118, 5, 35, 55, F1M1, false),
+            slot(118, 4, 35, 55, F1M1, false),
+            slot(118, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "wellspring_cave",
+        slots: &[
+            slot(60, 60, 35, 55, F1M1, false),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(61, 5, 35, 55, F1M1, true),
+            slot(60, 4, 35, 55, F1M1, false),
+            slot(60, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "route_4",
+        slots: &[
+            slot(98, 60, 35, 55, F1M1, false),
+            slot(366, 30, 35, 55, F1M1, true),
+            slot(98, 5, 35, 55, F1M1, false),
+            slot(370, 4, 35, 55, F3M1, true),
+            slot(370, 1, 35, 55, F3M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "route_6_spring",
+        slots: &[
+            slot(60, 60, 35, 55, F1M1, false),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(61, 5, 35, 55, F1M1, true),
+            slot(60, 4, 35, 55, F1M1, false),
+            slot(60, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "route_6_summer",
+        slots: &[
+            slot(60, 60, 35, 55, F1M1, false),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(61, 5, 35, 55, F1M1, true),
+            slot(60, 4, 35, 55, F1M1, false),
+            slot(60, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "route_6_autumn",
+        slots: &[
+            slot(60, 60, 35, 55, F1M1, false),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(61, 5, 35, 55, F1M1, true),
+            slot(60, 4, 35, 55, F1M1, false),
+            slot(60, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "route_6_winter",
+        slots: &[
+            slot(60, 60, 35, 55, F1M1, false),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(61, 5, 35, 55, F1M1, true),
+            slot(60, 4, 35, 55, F1M1, false),
+            slot(60, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "route_8_spring",
+        slots: &[
+            slot(339, 60, 35, 55, F1M1, false),
+            slot(618, 30, 35, 55, F1M1, true),
+            slot(339, 5, 35, 55, F1M1, false),
+            slot(339, 4, 35, 55, F1M1, false),
+            slot(339, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "route_8_summer",
+        slots: &[
+            slot(339, 60, 35, 55, F1M1, false),
+            slot(618, 30, 35, 55, F1M1, true),
+            slot(339, 5, 35, 55, F1M1, false),
+            slot(339, 4, 35, 55, F1M1, false),
+            slot(339, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "route_8_autumn",
+        slots: &[
+            slot(339, 60, 35, 55, F1M1, false),
+            slot(618, 30, 35, 55, F1M1, true),
+            slot(339, 5, 35, 55, F1M1, false),
+            slot(339, 4, 35, 55, F1M1, false),
+            slot(339, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "route_8_winter",
+        slots: &[
+            slot(339, 60, 35, 55, F1M1, false),
+            slot(618, 30, 35, 55, F1M1, true),
+            slot(339, 5, 35, 55, F1M1, false),
+            slot(339, 4, 35, 55, F1M1, false),
+            slot(339, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "moor_of_icirrus_spring",
+        slots: &[
+            slot(339, 60, 35, 55, F1M1, false),
+            slot(618, 30, 35, 55, F1M1, true),
+            slot(339, 5, 35, 55, F1M1, false),
+            slot(339, 4, 35, 55, F1M1, false),
+            slot(339, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "moor_of_icirrus_summer",
+        slots: &[
+            slot(339, 60, 35, 55, F1M1, false),
+            slot(618, 30, 35, 55, F1M1, true),
+            slot(339, 5, 35, 55, F1M1, false),
+            slot(339, 4, 35, 55, F1M1, false),
+            slot(339, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "moor_of_icirrus_autumn",
+        slots: &[
+            slot(339, 60, 35, 55, F1M1, false),
+            slot(618, 30, 35, 55, F1M1, true),
+            slot(339, 5, 35, 55, F1M1, false),
+            slot(339, 4, 35, 55, F1M1, false),
+            slot(339, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "moor_of_icirrus_winter",
+        slots: &[
+            slot(339, 60, 35, 55, F1M1, false),
+            slot(618, 30, 35, 55, F1M1, true),
+            slot(339, 5, 35, 55, F1M1, false),
+            slot(339, 4, 35, 55, F1M1, false),
+            slot(339, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "challengers_cave",
+        slots: &[
+            slot(60, 60, 35, 55, F1M1, false),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(61, 5, 35, 55, F1M1, true),
+            slot(60, 4, 35, 55, F1M1, false),
+            slot(60, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "route_11",
+        slots: &[
+            slot(118, 60, 35, 55, F1M1, false),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(118, 5, 35, 55, F1M1, false),
+            slot(118, 4, 35, 55, F1M1, false),
+            slot(118, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "route_13",
+        slots: &[
+            slot(98, 60, 35, 55, F1M1, false),
+            slot(90, 30, 35, 55, F1M1, true),
+            slot(98, 5, 35, 55, F1M1, false),
+            slot(370, 4, 35, 55, F3M1, true),
+            slot(370, 1, 35, 55, F3M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "route_14",
+        slots: &[
+            slot(118, 60, 35, 55, F1M1, false),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(118, 5, 35, 55, F1M1, false),
+            slot(118, 4, 35, 55, F1M1, false),
+            slot(118, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "abundant_shrine",
+        slots: &[
+            slot(118, 60, 35, 55, F1M1, false),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(118, 5, 35, 55, F1M1, false),
+            slot(118, 4, 35, 55, F1M1, false),
+            slot(118, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "lostlorn_forest",
+        slots: &[
+            slot(118, 60, 35, 55, F1M1, false),
+            slot(550, 30, 35, 55, F1M1, true),
+            slot(118, 5, 35, 55, F1M1, false),
+            slot(118, 4, 35, 55, F1M1, false),
+            slot(118, 1, 35, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "route_18",
+        slots: &[
+            slot(456, 60, 35, 55, F1M1, true),
+            slot(116, 30, 35, 55, F1M1, true),
+            slot(116, 5, 35, 55, F1M1, true),
+            slot(456, 4, 35, 55, F1M1, true),
+            slot(170, 1, 35, 55, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "undella_town",
+        slots: &[
+            slot(223, 60, 35, 55, F1M1, false),
+            slot(90, 30, 35, 55, F1M1, true),
+            slot(223, 5, 35, 55, F1M1, false),
+            slot(370, 4, 35, 55, F3M1, true),
+            slot(370, 1, 35, 55, F3M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: Fishing,
+        location_key: "route_17",
+        slots: &[
+            slot(456, 60, 35, 55, F1M1, true),
+            slot(116, 30, 35, 55, F1M1, true),
+            slot(116, 5, 35, 55, F1M1, true),
+            slot(456, 4, 35, 55, F1M1, true),
+            slot(456, 1, 35, 55, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "striaton_city",
+        slots: &[
+            slot(118, 60, 35, 60, F1M1, false),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(119, 5, 35, 70, F1M1, false),
+            slot(119, 4, 45, 70, F1M1, false),
+            slot(119, 1, 45, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "driftveil_city",
+        slots: &[
+            slot(170, 60, 35, 60, F1M1, true),
+            slot(370, 30, 35, 60, F3M1, true),
+            slot(99, 5, 35, 70, F1M1, false),
+            slot(171, 4, 45, 70, F1M1, true),
+            slot(171, 1, 45, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "icirrus_city_spring",
+        slots: &[
+            slot(339, 60, 35, 60, F1M1, false),
+            slot(618, 30, 35, 60, F1M1, true),
+            slot(340, 5, 35, 70, F1M1, false),
+            slot(340, 4, 45, 70, F1M1, false),
+            slot(340, 1, 45, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "icirrus_city_summer",
+        slots: &[
+            slot(339, 60, 35, 60, F1M1, false),
+            slot(618, 30, 35, 60, F1M1, true),
+            slot(340, 5, 35, 70, F1M1, false),
+            slot(340, 4, 45, 70, F1M1, false),
+            slot(340, 1, 45, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "icirrus_city_autumn",
+        slots: &[
+            slot(339, 60, 35, 60, F1M1, false),
+            slot(618, 30, 35, 60, F1M1, true),
+            slot(340, 5, 35, 70, F1M1, false),
+            slot(340, 4, 45, 70, F1M1, false),
+            slot(340, 1, 45, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "icirrus_city_winter",
+        slots: &[
+            slot(339, 60, 35, 60, F1M1, false),
+            slot(618, 30, 35, 60, F1M1, true),
+            slot(340, 5, 35, 70, F1M1, false),
+            slot(340, 4, 45, 70, F1M1, false),
+            slot(340, 1, 45, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "pinwheel_forest",
+        slots: &[
+            slot(118, 60, 35, 60, F1M1, false),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(119, 5, 35, 70, F1M1, false),
+            slot(119, 4, 45, 70, F1M1, false),
+            slot(119, 1, 45, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "dragonspiral_tower_spring",
+        slots: &[
+            slot(147, 60, 35, 60, F1M1, true),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(148, 5, 35, 70, F1M1, true),
+            slot(148, 4, 45, 70, F1M1, true),
+            slot(149, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "dragonspiral_tower_summer",
+        slots: &[
+            slot(147, 60, 35, 60, F1M1, true),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(148, 5, 35, 70, F1M1, true),
+            slot(148, 4, 45, 70, F1M1, true),
+            slot(149, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "dragonspiral_tower_autumn",
+        slots: &[
+            slot(147, 60, 35, 60, F1M1, true),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(148, 5, 35, 70, F1M1, true),
+            slot(148, 4, 45, 70, F1M1, true),
+            slot(149, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "dragonspiral_tower_winter",
+        slots: &[
+            slot(147, 60, 35, 60, F1M1, true),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(148, 5, 35, 70, F1M1, true),
+            slot(148, 4, 45, 70, F1M1, true),
+            slot(149, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "victory_road",
+        slots: &[
+            slot(61, 60, 35, 60, F1M1, true),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(61, 5, 35, 70, F1M1, true),
+            slot(62, 4, 45, 70, F1M1, true),
+            slot(62, 1, 45, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "giant_chasm",
+        slots: &[
+            slot(61, 60, 35, 60, F1M1, true),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(61, 5, 35, 70, F1M1, true),
+            slot(62, 4, 45, 70, F1M1, true),
+            slot(62, 1, 45, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "p2_laboratory",
+        slots: &[
+            slot(117, 60, 35, 60, F1M1, true),
+            slot(211, 30, 35, 60, F1M1, true),
+            slot(457, 5, 35, 70, F1M1, true),
+            slot(230, 4, 45, 70, F1M1, true),
+            slot(230, 1, 45, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "undella_bay_spring",
+        slots: &[
+            slot(90, 60, 35, 60, F1M1, true),
+            slot(370, 30, 35, 60, F3M1, true),
+            slot(224, 5, 35, 70, F1M1, false),
+            slot(91, 4, 45, 70, F1M1, true),
+            slot(91, 1, 45, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "undella_bay_summer",
+        slots: &[
+            slot(90, 60, 35, 60, F1M1, true),
+            slot(370, 30, 35, 60, F3M1, true),
+            slot(224, 5, 35, 70, F1M1, false),
+            slot(91, 4, 45, 70, F1M1, true),
+            slot(91, 1, 45, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "undella_bay_autumn",
+        slots: &[
+            slot(90, 60, 35, 60, F1M1, true),
+            slot(370, 30, 35, 60, F3M1, true),
+            slot(224, 5, 35, 70, F1M1, false),
+            slot(91, 4, 45, 70, F1M1, true),
+            slot(91, 1, 45, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "undella_bay_winter",
+        slots: &[
+            slot(90, 60, 35, 60, F1M1, true),
+            slot(370, 30, 35, 60, F3M1, true),
+            slot(224, 5, 35, 70, F1M1, false),
+            slot(91, 4, 45, 70, F1M1, true),
+            slot(91, 1, 45, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "village_bridge",
+        slots: &[
+            slot(318, 60, 35, 60, F1M1, true),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(319, 5, 35, 70, F1M1, true),
+            slot(319, 4, 45, 70, F1M1, true),
+            slot(319, 1, 45, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "route_1",
+        slots: &[
+            slot(349, 60, 35, 60, F1M1, false),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(550, 5, 35, 70, F1M1, true),
+            slot(350, 4, 45, 70, F1M1, false),
+            slot(350, 1, 45, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "route_3",
+        slots: &[
+            slot(118, 60, 35, 60, F1M1, false),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(119, 5, 35, 70, F1M1, false),
+            slot(119, 4, 45, 70, F1M1, false),
+            slot(119, 1, 45, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "wellspring_cave",
+        slots: &[
+            slot(61, 60, 35, 60, F1M1, true),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(61, 5, 35, 60, F1M1, true),
+            slot(62, 4, 35, 60, F1M1, true),
+            slot(62, 1, 35, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "route_4",
+        slots: &[
+            slot(369, 60, 35, 60, F1M7, true),
+            slot(370, 30, 35, 60, F3M1, true),
+            slot(99, 5, 35, 70, F1M1, false),
+            slot(368, 4, 45, 70, F1M1, true),
+            slot(368, 1, 45, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "route_6_spring",
+        slots: &[
+            slot(61, 60, 35, 60, F1M1, true),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(61, 5, 35, 70, F1M1, true),
+            slot(186, 4, 45, 70, F1M1, true),
+            slot(186, 1, 45, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "route_6_summer",
+        slots: &[
+            slot(61, 60, 35, 60, F1M1, true),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(61, 5, 35, 70, F1M1, true),
+            slot(186, 4, 45, 70, F1M1, true),
+            slot(186, 1, 45, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "route_6_autumn",
+        slots: &[
+            slot(61, 60, 35, 60, F1M1, true),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(61, 5, 35, 70, F1M1, true),
+            slot(186, 4, 45, 70, F1M1, true),
+            slot(186, 1, 45, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "route_6_winter",
+        slots: &[
+            slot(61, 60, 35, 60, F1M1, true),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(61, 5, 35, 70, F1M1, true),
+            slot(186, 4, 45, 70, F1M1, true),
+            slot(186, 1, 45, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "route_8_spring",
+        slots: &[
+            slot(339, 60, 35, 60, F1M1, false),
+            slot(618, 30, 35, 60, F1M1, true),
+            slot(340, 5, 35, 70, F1M1, false),
+            slot(340, 4, 45, 70, F1M1, false),
+            slot(340, 1, 45, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "route_8_summer",
+        slots: &[
+            slot(339, 60, 35, 60, F1M1, false),
+            slot(618, 30, 35, 60, F1M1, true),
+            slot(340, 5, 35, 70, F1M1, false),
+            slot(340, 4, 45, 70, F1M1, false),
+            slot(340, 1, 45, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "route_8_autumn",
+        slots: &[
+            slot(339, 60, 35, 60, F1M1, false),
+            slot(618, 30, 35, 60, F1M1, true),
+            slot(340, 5, 35, 70, F1M1, false),
+            slot(340, 4, 45, 70, F1M1, false),
+            slot(340, 1, 45, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "route_8_winter",
+        slots: &[
+            slot(339, 60, 35, 60, F1M1, false),
+            slot(618, 30, 35, 60, F1M1, true),
+            slot(340, 5, 35, 70, F1M1, false),
+            slot(340, 4, 45, 70, F1M1, false),
+            slot(340, 1, 45, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "moor_of_icirrus_spring",
+        slots: &[
+            slot(339, 60, 35, 60, F1M1, false),
+            slot(618, 30, 35, 60, F1M1, true),
+            slot(340, 5, 35, 70, F1M1, false),
+            slot(340, 4, 45, 70, F1M1, false),
+            slot(340, 1, 45, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "moor_of_icirrus_summer",
+        slots: &[
+            slot(339, 60, 35, 60, F1M1, false),
+            slot(618, 30, 35, 60, F1M1, true),
+            slot(340, 5, 35, 70, F1M1, false),
+            slot(340, 4, 45, 70, F1M1, false),
+            slot(340, 1, 45, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "moor_of_icirrus_autumn",
+        slots: &[
+            slot(339, 60, 35, 60, F1M1, false),
+            slot(618, 30, 35, 60, F1M1, true),
+            slot(340, 5, 35, 70, F1M1, false),
+            slot(340, 4, 45, 70, F1M1, false),
+            slot(340, 1, 45, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "moor_of_icirrus_winter",
+        slots: &[
+            slot(339, 60, 35, 60, F1M1, false),
+            slot(618, 30, 35, 60, F1M1, true),
+            slot(340, 5, 35, 70, F1M1, false),
+            slot(340, 4, 45, 70, F1M1, false),
+            slot(340, 1, 45, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "challengers_cave",
+        slots: &[
+            slot(61, 60, 35, 60, F1M1, true),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(61, 5, 35, 70, F1M1, true),
+            slot(62, 4, 45, 70, F1M1, true),
+            slot(62, 1, 45, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "route_11",
+        slots: &[
+            slot(118, 60, 35, 60, F1M1, false),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(119, 5, 35, 70, F1M1, false),
+            slot(119, 4, 45, 70, F1M1, false),
+            slot(119, 1, 45, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "route_13",
+        slots: &[
+            slot(90, 60, 35, 60, F1M1, true),
+            slot(370, 30, 35, 60, F3M1, true),
+            slot(99, 5, 35, 70, F1M1, false),
+            slot(91, 4, 45, 70, F1M1, true),
+            slot(91, 1, 45, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "route_14",
+        slots: &[
+            slot(118, 60, 35, 60, F1M1, false),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(119, 5, 35, 70, F1M1, false),
+            slot(119, 4, 45, 70, F1M1, false),
+            slot(119, 1, 45, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "abundant_shrine",
+        slots: &[
+            slot(118, 60, 35, 60, F1M1, false),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(119, 5, 35, 70, F1M1, false),
+            slot(119, 4, 45, 70, F1M1, false),
+            slot(119, 1, 45, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "lostlorn_forest",
+        slots: &[
+            slot(118, 60, 35, 60, F1M1, false),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(118, 5, 35, 70, F1M1, false),
+            slot(118, 4, 35, 70, F1M1, false),
+            slot(118, 1, 35, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "route_18",
+        slots: &[
+            slot(117, 60, 35, 60, F1M1, true),
+            slot(211, 30, 35, 60, F1M1, true),
+            slot(457, 5, 35, 70, F1M1, true),
+            slot(230, 4, 45, 70, F1M1, true),
+            slot(230, 1, 45, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "undella_town",
+        slots: &[
+            slot(90, 60, 35, 60, F1M1, true),
+            slot(370, 30, 35, 60, F3M1, true),
+            slot(224, 5, 35, 70, F1M1, false),
+            slot(91, 4, 45, 70, F1M1, true),
+            slot(91, 1, 45, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White,
+        encounter_type: FishingBubble,
+        location_key: "route_17",
+        slots: &[
+            slot(117, 60, 35, 60, F1M1, true),
+            slot(211, 30, 35, 60, F1M1, true),
+            slot(457, 5, 35, 70, F1M1, true),
+            slot(230, 4, 45, 70, F1M1, true),
+            slot(230, 1, 45, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "castelia_city",
+        slots: &[
+            slot(19, 20, 15, 15, F1M1, false),
+            slot(546, 20, 15, 15, F1M1, false),
+            slot(519, 10, 15, 15, F1M1, false),
+            slot(427, 10, 15, 15, F1M1, true),
+            slot(19, 10, 16, 16, F1M1, false),
+            slot(546, 10, 16, 16, F1M1, false),
+            slot(519, 5, 16, 16, F1M1, false),
+            slot(427, 5, 16, 16, F1M1, true),
+            slot(546, 4, 17, 17, F1M1, false),
+            slot(133, 4, 18, 18, F1M7, false),
+            slot(546, 1, 17, 17, F1M1, false),
+            slot(133, 1, 18, 18, F1M7, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "icirrus_city_spring",
+        slots: &[
+            slot(536, 20, 54, 54, F1M1, true),
+            slot(616, 20, 54, 54, F1M1, false),
+            slot(536, 10, 55, 55, F1M1, true),
+            slot(453, 10, 55, 55, F1M1, true),
+            slot(618, 10, 55, 55, F1M1, true),
+            slot(618, 10, 56, 56, F1M1, true),
+            slot(536, 5, 56, 56, F1M1, true),
+            slot(453, 5, 56, 56, F1M1, true),
+            slot(536, 4, 57, 57, F1M1, true),
+            slot(588, 4, 57, 57, F1M1, false),
+            slot(536, 1, 57, 57, F1M1, true),
+            slot(588, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "icirrus_city_summer",
+        slots: &[
+            slot(536, 20, 54, 54, F1M1, true),
+            slot(616, 20, 54, 54, F1M1, false),
+            slot(536, 10, 55, 55, F1M1, true),
+            slot(453, 10, 55, 55, F1M1, true),
+            slot(618, 10, 55, 55, F1M1, true),
+            slot(618, 10, 56, 56, F1M1, true),
+            slot(536, 5, 56, 56, F1M1, true),
+            slot(453, 5, 56, 56, F1M1, true),
+            slot(536, 4, 57, 57, F1M1, true),
+            slot(588, 4, 57, 57, F1M1, false),
+            slot(536, 1, 57, 57, F1M1, true),
+            slot(588, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "icirrus_city_autumn",
+        slots: &[
+            slot(536, 20, 54, 54, F1M1, true),
+            slot(616, 20, 54, 54, F1M1, false),
+            slot(536, 10, 55, 55, F1M1, true),
+            slot(453, 10, 55, 55, F1M1, true),
+            slot(618, 10, 55, 55, F1M1, true),
+            slot(618, 10, 56, 56, F1M1, true),
+            slot(536, 5, 56, 56, F1M1, true),
+            slot(453, 5, 56, 56, F1M1, true),
+            slot(536, 4, 57, 57, F1M1, true),
+            slot(588, 4, 57, 57, F1M1, false),
+            slot(536, 1, 57, 57, F1M1, true),
+            slot(588, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "dreamyard",
+        slots: &[
+            slot(505, 20, 56, 56, F1M1, false),
+            slot(510, 20, 56, 56, F1M1, false),
+            slot(20, 10, 57, 57, F1M1, false),
+            slot(39, 10, 57, 57, F3M1, false),
+            slot(517, 10, 57, 57, F1M1, false),
+            slot(517, 10, 58, 58, F1M1, false),
+            slot(42, 5, 57, 57, F1M1, false),
+            slot(42, 5, 58, 58, F1M1, false),
+            slot(505, 4, 59, 59, F1M1, false),
+            slot(510, 4, 59, 59, F1M1, false),
+            slot(505, 1, 59, 59, F1M1, false),
+            slot(510, 1, 59, 59, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "pinwheel_forest_exterior",
+        slots: &[
+            slot(536, 20, 54, 54, F1M1, true),
+            slot(533, 20, 54, 54, F1M3, false),
+            slot(454, 10, 55, 55, F1M1, true),
+            slot(193, 10, 55, 55, F1M1, true),
+            slot(533, 10, 55, 55, F1M3, false),
+            slot(533, 10, 56, 56, F1M3, false),
+            slot(539, 5, 55, 55, MaleOnly, true),
+            slot(539, 5, 55, 55, MaleOnly, true),
+            slot(536, 4, 57, 57, F1M1, true),
+            slot(539, 4, 57, 57, MaleOnly, true),
+            slot(536, 1, 57, 57, F1M1, true),
+            slot(539, 1, 57, 57, MaleOnly, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "pinwheel_forest_interior",
+        slots: &[
+            slot(546, 20, 54, 54, F1M1, false),
+            slot(541, 20, 54, 54, F1M1, true),
+            slot(546, 10, 55, 55, F1M1, false),
+            slot(288, 10, 55, 55, F1M1, false),
+            slot(546, 10, 56, 56, F1M1, false),
+            slot(544, 10, 55, 55, F1M1, true),
+            slot(288, 5, 57, 57, F1M1, false),
+            slot(544, 5, 57, 57, F1M1, true),
+            slot(546, 4, 57, 57, F1M1, false),
+            slot(541, 4, 56, 56, F1M1, true),
+            slot(546, 1, 57, 57, F1M1, false),
+            slot(541, 1, 56, 56, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "desert_resort_exterior",
+        slots: &[
+            slot(551, 20, 18, 18, F1M1, false),
+            slot(554, 20, 18, 18, F1M1, true),
+            slot(551, 10, 19, 19, F1M1, false),
+            slot(554, 10, 19, 19, F1M1, true),
+            slot(556, 10, 19, 19, F1M1, true),
+            slot(559, 10, 19, 19, F1M1, true),
+            slot(27, 5, 19, 19, F1M1, true),
+            slot(557, 5, 19, 19, F1M1, true),
+            slot(27, 4, 20, 20, F1M1, true),
+            slot(557, 4, 21, 21, F1M1, true),
+            slot(27, 1, 20, 20, F1M1, true),
+            slot(557, 1, 21, 21, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "desert_resort_interior",
+        slots: &[
+            slot(551, 20, 18, 18, F1M1, false),
+            slot(554, 20, 18, 18, F1M1, true),
+            slot(551, 10, 19, 19, F1M1, false),
+            slot(27, 10, 19, 19, F1M1, true),
+            slot(556, 10, 19, 19, F1M1, true),
+            slot(561, 10, 19, 19, F1M1, false),
+            slot(27, 5, 20, 20, F1M1, true),
+            slot(557, 5, 19, 19, F1M1, true),
+            slot(328, 4, 21, 21, F1M1, true),
+            slot(557, 4, 21, 21, F1M1, true),
+            slot(328, 1, 21, 21, F1M1, true),
+            slot(557, 1, 21, 21, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "relic_castle_1f_b1f",
+        slots: &[
+            slot(551, 20, 18, 18, F1M1, false),
+            slot(562, 20, 18, 18, F1M1, true),
+            slot(551, 10, 19, 19, F1M1, false),
+            slot(562, 10, 19, 19, F1M1, true),
+            slot(27, 10, 19, 19, F1M1, true),
+            slot(27, 10, 20, 20, F1M1, true),
+            slot(551, 5, 20, 20, F1M1, false),
+            slot(562, 5, 20, 20, F1M1, true),
+            slot(551, 4, 21, 21, F1M1, false),
+            slot(562, 4, 21, 21, F1M1, true),
+            slot(551, 1, 21, 21, F1M1, false),
+            slot(562, 1, 21, 21, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "relic_castle_lowest_floor",
+        slots: &[
+            slot(562, 20, 27, 27, F1M1, true),
+            slot(551, 20, 27, 27, F1M1, false),
+            slot(562, 10, 28, 28, F1M1, true),
+            slot(551, 10, 28, 28, F1M1, false),
+            slot(28, 10, 28, 28, F1M1, true),
+            slot(28, 10, 29, 29, F1M1, true),
+            slot(562, 5, 29, 29, F1M1, true),
+            slot(552, 5, 29, 29, F1M1, false),
+            slot(562, 4, 30, 30, F1M1, true),
+            slot(552, 4, 30, 30, F1M1, false),
+            slot(562, 1, 30, 30, F1M1, true),
+            slot(552, 1, 30, 30, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "relic_castle_small_room",
+        slots: &[
+            slot(343, 20, 27, 27, Genderless, false),
+            slot(343, 20, 27, 27, Genderless, false),
+            slot(343, 10, 28, 28, Genderless, false),
+            slot(343, 10, 28, 28, Genderless, false),
+            slot(343, 10, 28, 28, Genderless, false),
+            slot(343, 10, 28, 28, Genderless, false),
+            slot(343, 5, 29, 29, Genderless, false),
+            slot(343, 5, 29, 29, Genderless, false),
+            slot(343, 4, 30, 30, Genderless, false),
+            slot(343, 4, 30, 30, Genderless, false),
+            slot(343, 1, 30, 30, Genderless, false),
+            slot(343, 1, 30, 30, Genderless, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "chargestone_cave_1f",
+        slots: &[
+            slot(595, 20, 25, 25, F1M1, false),
+            slot(599, 20, 26, 26, Genderless, false),
+            slot(595, 10, 26, 26, F1M1, false),
+            slot(299, 10, 27, 27, F1M1, true),
+            slot(597, 10, 26, 26, F1M1, true),
+            slot(597, 10, 27, 27, F1M1, true),
+            slot(595, 5, 27, 27, F1M1, false),
+            slot(525, 5, 25, 25, F1M1, true),
+            slot(595, 4, 28, 28, F1M1, false),
+            slot(599, 4, 28, 28, Genderless, false),
+            slot(602, 1, 28, 28, F1M1, false),
+            slot(602, 1, 28, 28, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "chargestone_cave_b1f",
+        slots: &[
+            slot(595, 20, 28, 28, F1M1, false),
+            slot(599, 20, 29, 29, Genderless, false),
+            slot(595, 10, 29, 29, F1M1, false),
+            slot(299, 10, 30, 30, F1M1, true),
+            slot(597, 10, 29, 29, F1M1, true),
+            slot(597, 10, 30, 30, F1M1, true),
+            slot(595, 5, 30, 30, F1M1, false),
+            slot(525, 5, 28, 28, F1M1, true),
+            slot(595, 4, 31, 31, F1M1, false),
+            slot(599, 4, 31, 31, Genderless, false),
+            slot(602, 1, 31, 31, F1M1, false),
+            slot(602, 1, 31, 31, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "chargestone_cave_b2f",
+        slots: &[
+            slot(595, 20, 28, 28, F1M1, false),
+            slot(599, 20, 29, 29, Genderless, false),
+            slot(595, 10, 29, 29, F1M1, false),
+            slot(299, 10, 30, 30, F1M1, true),
+            slot(597, 10, 30, 30, F1M1, true),
+            slot(597, 10, 31, 31, F1M1, true),
+            slot(595, 5, 30, 30, F1M1, false),
+            slot(525, 5, 28, 28, F1M1, true),
+            slot(602, 4, 31, 31, F1M1, false),
+            slot(602, 4, 31, 31, F1M1, false),
+            slot(595, 1, 31, 31, F1M1, false),
+            slot(599, 1, 31, 31, Genderless, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "twist_mountain_spring",
+        slots: &[
+            slot(525, 20, 54, 54, F1M1, true),
+            slot(525, 20, 55, 55, F1M1, true),
+            slot(533, 10, 55, 55, F1M3, false),
+            slot(527, 10, 54, 54, F1M1, false),
+            slot(632, 10, 56, 56, F1M1, false),
+            slot(614, 10, 56, 56, F1M1, true),
+            slot(631, 5, 56, 56, F1M1, true),
+            slot(632, 5, 57, 57, F1M1, false),
+            slot(615, 4, 57, 57, Genderless, true),
+            slot(95, 4, 57, 57, F1M1, false),
+            slot(615, 1, 57, 57, Genderless, true),
+            slot(95, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "twist_mountain_summer",
+        slots: &[
+            slot(525, 20, 54, 54, F1M1, true),
+            slot(533, 20, 55, 55, F1M3, false),
+            slot(631, 10, 55, 55, F1M1, true),
+            slot(527, 10, 54, 54, F1M1, false),
+            slot(632, 10, 56, 56, F1M1, false),
+            slot(631, 10, 56, 56, F1M1, true),
+            slot(614, 5, 56, 56, F1M1, true),
+            slot(632, 5, 57, 57, F1M1, false),
+            slot(615, 4, 57, 57, Genderless, true),
+            slot(95, 4, 57, 57, F1M1, false),
+            slot(615, 1, 57, 57, Genderless, true),
+            slot(95, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "twist_mountain_autumn",
+        slots: &[
+            slot(525, 20, 54, 54, F1M1, true),
+            slot(525, 20, 55, 55, F1M1, true),
+            slot(533, 10, 55, 55, F1M3, false),
+            slot(527, 10, 54, 54, F1M1, false),
+            slot(632, 10, 56, 56, F1M1, false),
+            slot(614, 10, 56, 56, F1M1, true),
+            slot(631, 5, 56, 56, F1M1, true),
+            slot(632, 5, 57, 57, F1M1, false),
+            slot(615, 4, 57, 57, Genderless, true),
+            slot(95, 4, 57, 57, F1M1, false),
+            slot(615, 1, 57, 57, Genderless, true),
+            slot(95, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "twist_mountain_winter",
+        slots: &[
+            slot(614, 20, 54, 54, F1M1, true),
+            slot(525, 20, 55, 55, F1M1, true),
+            slot(533, 10, 55, 55, F1M3, false),
+            slot(527, 10, 54, 54, F1M1, false),
+            slot(632, 10, 56, 56, F1M1, false),
+            slot(615, 10, 56, 56, Genderless, true),
+            slot(631, 5, 56, 56, F1M1, true),
+            slot(632, 5, 57, 57, F1M1, false),
+            slot(615, 4, 57, 57, Genderless, true),
+            slot(95, 4, 57, 57, F1M1, false),
+            slot(615, 1, 57, 57, Genderless, true),
+            slot(95, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "dragonspiral_tower_spring_exterior_south",
+        slots: &[
+            slot(520, 20, 55, 55, F1M1, false),
+            slot(586, 20, 55, 55, F1M1, false),
+            slot(620, 10, 56, 56, F1M1, false),
+            slot(586, 10, 57, 57, F1M1, false),
+            slot(620, 10, 57, 57, F1M1, false),
+            slot(520, 10, 57, 57, F1M1, false),
+            slot(620, 5, 58, 58, F1M1, false),
+            slot(520, 5, 58, 58, F1M1, false),
+            slot(586, 4, 58, 58, F1M1, false),
+            slot(621, 4, 58, 58, F1M1, true),
+            slot(586, 1, 58, 58, F1M1, false),
+            slot(621, 1, 58, 58, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "dragonspiral_tower_summer_exterior_south",
+        slots: &[
+            slot(520, 20, 55, 55, F1M1, false),
+            slot(586, 20, 55, 55, F1M1, false),
+            slot(620, 10, 56, 56, F1M1, false),
+            slot(586, 10, 57, 57, F1M1, false),
+            slot(620, 10, 57, 57, F1M1, false),
+            slot(520, 10, 57, 57, F1M1, false),
+            slot(620, 5, 58, 58, F1M1, false),
+            slot(520, 5, 58, 58, F1M1, false),
+            slot(586, 4, 58, 58, F1M1, false),
+            slot(621, 4, 58, 58, F1M1, true),
+            slot(586, 1, 58, 58, F1M1, false),
+            slot(621, 1, 58, 58, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "dragonspiral_tower_autumn_exterior_south",
+        slots: &[
+            slot(520, 20, 55, 55, F1M1, false),
+            slot(586, 20, 55, 55, F1M1, false),
+            slot(620, 10, 56, 56, F1M1, false),
+            slot(586, 10, 57, 57, F1M1, false),
+            slot(620, 10, 57, 57, F1M1, false),
+            slot(520, 10, 57, 57, F1M1, false),
+            slot(620, 5, 58, 58, F1M1, false),
+            slot(520, 5, 58, 58, F1M1, false),
+            slot(586, 4, 58, 58, F1M1, false),
+            slot(621, 4, 58, 58, F1M1, true),
+            slot(586, 1, 58, 58, F1M1, false),
+            slot(621, 1, 58, 58, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "dragonspiral_tower_winter_exterior_south",
+        slots: &[
+            slot(583, 20, 55, 55, F1M1, false),
+            slot(586, 20, 55, 55, F1M1, false),
+            slot(620, 10, 56, 56, F1M1, false),
+            slot(586, 10, 57, 57, F1M1, false),
+            slot(620, 10, 57, 57, F1M1, false),
+            slot(614, 10, 57, 57, F1M1, true),
+            slot(620, 5, 58, 58, F1M1, false),
+            slot(583, 5, 57, 57, F1M1, false),
+            slot(620, 4, 58, 58, F1M1, false),
+            slot(614, 4, 58, 58, F1M1, true),
+            slot(620, 1, 58, 58, F1M1, false),
+            slot(614, 1, 58, 58, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "dragonspiral_tower_winter_exterior_northeast",
+        slots: &[
+            slot(583, 20, 55, 55, F1M1, false),
+            slot(586, 20, 55, 55, F1M1, false),
+            slot(620, 10, 56, 56, F1M1, false),
+            slot(586, 10, 57, 57, F1M1, false),
+            slot(620, 10, 57, 57, F1M1, false),
+            slot(614, 10, 57, 57, F1M1, true),
+            slot(620, 5, 58, 58, F1M1, false),
+            slot(583, 5, 57, 57, F1M1, false),
+            slot(614, 4, 58, 58, F1M1, true),
+            slot(621, 4, 58, 58, F1M1, true),
+            slot(614, 1, 58, 58, F1M1, true),
+            slot(621, 1, 58, 58, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "dragonspiral_tower_1f",
+        slots: &[
+            slot(623, 20, 55, 55, Genderless, true),
+            slot(620, 20, 55, 55, F1M1, false),
+            slot(623, 10, 56, 56, Genderless, true),
+            slot(621, 10, 56, 56, F1M1, true),
+            slot(623, 10, 57, 57, Genderless, true),
+            slot(621, 10, 57, 57, F1M1, true),
+            slot(623, 5, 58, 58, Genderless, true),
+            slot(621, 5, 58, 58, F1M1, true),
+            slot(623, 4, 58, 58, Genderless, true),
+            slot(621, 4, 58, 58, F1M1, true),
+            slot(623, 1, 58, 58, Genderless, true),
+            slot(621, 1, 58, 58, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "dragonspiral_tower_2f",
+        slots: &[
+            slot(623, 20, 55, 55, Genderless, true),
+            slot(623, 20, 55, 55, Genderless, true),
+            slot(623, 10, 56, 56, Genderless, true),
+            slot(623, 10, 56, 56, Genderless, true),
+            slot(623, 10, 57, 57, Genderless, true),
+            slot(623, 10, 57, 57, Genderless, true),
+            slot(623, 5, 58, 58, Genderless, true),
+            slot(623, 5, 58, 58, Genderless, true),
+            slot(623, 4, 58, 58, Genderless, true),
+            slot(623, 4, 58, 58, Genderless, true),
+            slot(623, 1, 58, 58, Genderless, true),
+            slot(623, 1, 58, 58, Genderless, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "victory_road_1f",
+        slots: &[
+            slot(525, 20, 47, 47, F1M1, true),
+            slot(525, 20, 47, 47, F1M1, true),
+            slot(525, 10, 48, 48, F1M1, true),
+            slot(525, 10, 48, 48, F1M1, true),
+            slot(525, 10, 49, 49, F1M1, true),
+            slot(525, 10, 49, 49, F1M1, true),
+            slot(525, 5, 50, 50, F1M1, true),
+            slot(525, 5, 50, 50, F1M1, true),
+            slot(525, 4, 50, 50, F1M1, true),
+            slot(525, 4, 50, 50, F1M1, true),
+            slot(525, 1, 50, 50, F1M1, true),
+            slot(525, 1, 50, 50, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "victory_road_2f",
+        slots: &[
+            slot(354, 20, 47, 47, F1M1, true),
+            slot(354, 20, 47, 47, F1M1, true),
+            slot(354, 10, 48, 48, F1M1, true),
+            slot(623, 10, 48, 48, Genderless, true),
+            slot(354, 10, 49, 49, F1M1, true),
+            slot(623, 10, 49, 49, Genderless, true),
+            slot(354, 5, 50, 50, F1M1, true),
+            slot(623, 5, 50, 50, Genderless, true),
+            slot(354, 4, 50, 50, F1M1, true),
+            slot(623, 4, 50, 50, Genderless, true),
+            slot(354, 1, 50, 50, F1M1, true),
+            slot(623, 1, 50, 50, Genderless, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "victory_road_3f_4f_5f",
+        slots: &[
+            slot(546, 20, 47, 47, F1M1, false),
+            slot(546, 20, 47, 47, F1M1, false),
+            slot(546, 10, 48, 48, F1M1, false),
+            slot(315, 10, 48, 48, F1M1, true),
+            slot(546, 10, 49, 49, F1M1, false),
+            slot(315, 10, 49, 49, F1M1, true),
+            slot(546, 5, 50, 50, F1M1, false),
+            slot(315, 5, 50, 50, F1M1, true),
+            slot(546, 4, 50, 50, F1M1, false),
+            slot(315, 4, 50, 50, F1M1, true),
+            slot(546, 1, 50, 50, F1M1, false),
+            slot(315, 1, 50, 50, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "victory_road_6f_7f",
+        slots: &[
+            slot(621, 20, 47, 47, F1M1, true),
+            slot(621, 20, 47, 47, F1M1, true),
+            slot(621, 10, 48, 48, F1M1, true),
+            slot(621, 10, 48, 48, F1M1, true),
+            slot(621, 10, 49, 49, F1M1, true),
+            slot(634, 10, 49, 49, F1M1, false),
+            slot(621, 5, 50, 50, F1M1, true),
+            slot(634, 5, 50, 50, F1M1, false),
+            slot(621, 4, 50, 50, F1M1, true),
+            slot(634, 4, 50, 50, F1M1, false),
+            slot(621, 1, 50, 50, F1M1, true),
+            slot(634, 1, 50, 50, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "giant_chasm_exterior",
+        slots: &[
+            slot(114, 20, 44, 44, F1M1, false),
+            slot(279, 20, 45, 45, F1M1, false),
+            slot(114, 10, 45, 45, F1M1, false),
+            slot(337, 10, 45, 45, Genderless, true),
+            slot(338, 10, 46, 46, Genderless, true),
+            slot(35, 10, 45, 45, F3M1, true),
+            slot(583, 5, 45, 45, F1M1, false),
+            slot(35, 5, 47, 47, F3M1, true),
+            slot(583, 4, 47, 47, F1M1, false),
+            slot(225, 4, 44, 44, F1M1, false),
+            slot(583, 1, 47, 47, F1M1, false),
+            slot(225, 1, 44, 44, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "giant_chasm_cave",
+        slots: &[
+            slot(215, 20, 44, 44, F1M1, true),
+            slot(221, 20, 44, 44, F1M1, false),
+            slot(583, 10, 45, 45, F1M1, false),
+            slot(35, 10, 44, 44, F3M1, true),
+            slot(337, 10, 46, 46, Genderless, true),
+            slot(338, 10, 46, 46, Genderless, true),
+            slot(35, 5, 46, 46, F3M1, true),
+            slot(583, 5, 47, 47, F1M1, false),
+            slot(221, 4, 46, 46, F1M1, false),
+            slot(225, 4, 47, 47, F1M1, false),
+            slot(221, 1, 46, 46, F1M1, false),
+            slot(225, 1, 47, 47, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "giant_chasm_crater_forest",
+        slots: &[
+            slot(221, 20, 44, 44, F1M1, false),
+            slot(35, 20, 45, 45, F3M1, true),
+            slot(221, 10, 46, 46, F1M1, false),
+            slot(132, 10, 45, 45, Genderless, true),
+            slot(337, 10, 46, 46, Genderless, true),
+            slot(338, 10, 46, 46, Genderless, true),
+            slot(132, 5, 47, 47, Genderless, true),
+            slot(375, 5, 45, 45, Genderless, true),
+            slot(225, 4, 44, 44, F1M1, false),
+            slot(375, 4, 47, 47, Genderless, true),
+            slot(225, 1, 44, 44, F1M1, false),
+            slot(375, 1, 47, 47, Genderless, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "castelia_sewers",
+        slots: &[
+            slot(19, 20, 14, 14, F1M1, false),
+            slot(41, 20, 14, 14, F1M1, false),
+            slot(19, 10, 15, 15, F1M1, false),
+            slot(41, 10, 15, 15, F1M1, false),
+            slot(19, 10, 16, 16, F1M1, false),
+            slot(41, 10, 16, 16, F1M1, false),
+            slot(88, 5, 15, 15, F1M1, true),
+            slot(88, 5, 17, 17, F1M1, true),
+            slot(19, 4, 17, 17, F1M1, false),
+            slot(41, 4, 17, 17, F1M1, false),
+            slot(19, 1, 17, 17, F1M1, false),
+            slot(41, 1, 17, 17, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "p2_laboratory",
+        slots: &[
+            slot(505, 20, 56, 56, F1M1, false),
+            slot(507, 20, 56, 56, F1M1, false),
+            slot(110, 10, 57, 57, F1M1, true),
+            slot(82, 10, 57, 57, Genderless, true),
+            slot(600, 10, 57, 57, Genderless, false),
+            slot(560, 10, 57, 57, F1M1, true),
+            slot(82, 5, 59, 59, Genderless, true),
+            slot(600, 5, 59, 59, Genderless, false),
+            slot(560, 4, 58, 58, F1M1, true),
+            slot(110, 4, 58, 58, F1M1, true),
+            slot(560, 1, 58, 58, F1M1, true),
+            slot(110, 1, 58, 58, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "floccesy_ranch",
+        slots: &[
+            slot(506, 20, 4, 4, F1M1, false),
+            slot(298, 20, 5, 5, F3M1, false),
+            slot(504, 10, 5, 5, F1M1, false),
+            slot(179, 10, 5, 5, F1M1, false),
+            slot(506, 10, 5, 5, F1M1, false),
+            slot(54, 10, 5, 5, F1M1, false),
+            slot(506, 5, 6, 6, F1M1, false),
+            slot(519, 5, 7, 7, F1M1, false),
+            slot(447, 4, 5, 5, F1M7, false),
+            slot(506, 4, 7, 7, F1M1, false),
+            slot(447, 1, 7, 7, F1M7, false),
+            slot(506, 1, 7, 7, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "virbank_complex_exterior",
+        slots: &[
+            slot(504, 20, 10, 10, F1M1, false),
+            slot(519, 20, 10, 10, F1M1, false),
+            slot(240, 10, 10, 10, F1M3, false),
+            slot(81, 10, 10, 10, Genderless, true),
+            slot(504, 10, 11, 11, F1M1, false),
+            slot(519, 10, 11, 11, F1M1, false),
+            slot(240, 5, 11, 11, F1M3, false),
+            slot(81, 5, 11, 11, Genderless, true),
+            slot(504, 4, 12, 12, F1M1, false),
+            slot(519, 4, 12, 12, F1M1, false),
+            slot(504, 1, 13, 13, F1M1, false),
+            slot(519, 1, 13, 13, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "virbank_complex_interior",
+        slots: &[
+            slot(240, 20, 10, 10, F1M3, false),
+            slot(81, 20, 10, 10, Genderless, true),
+            slot(504, 10, 10, 10, F1M1, false),
+            slot(109, 10, 10, 10, F1M1, true),
+            slot(58, 10, 11, 11, F1M3, true),
+            slot(58, 10, 13, 13, F1M3, true),
+            slot(240, 5, 12, 12, F1M3, false),
+            slot(81, 5, 12, 12, Genderless, true),
+            slot(504, 4, 12, 12, F1M1, false),
+            slot(109, 4, 12, 12, F1M1, true),
+            slot(504, 1, 13, 13, F1M1, false),
+            slot(109, 1, 13, 13, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "reversal_mountain_exterior",
+        slots: &[
+            slot(451, 20, 31, 31, F1M1, true),
+            slot(325, 20, 31, 31, F1M1, true),
+            slot(328, 10, 32, 32, F1M1, true),
+            slot(451, 10, 33, 33, F1M1, true),
+            slot(426, 10, 32, 32, F1M1, true),
+            slot(426, 10, 33, 33, F1M1, true),
+            slot(227, 5, 34, 34, F1M1, false),
+            slot(326, 5, 33, 33, F1M1, true),
+            slot(328, 4, 34, 34, F1M1, true),
+            slot(326, 4, 33, 33, F1M1, true),
+            slot(328, 1, 34, 34, F1M1, true),
+            slot(326, 1, 33, 33, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "reversal_mountain_interior_entrance",
+        slots: &[
+            slot(325, 20, 31, 31, F1M1, true),
+            slot(527, 20, 32, 32, F1M1, false),
+            slot(525, 10, 32, 32, F1M1, true),
+            slot(451, 10, 33, 33, F1M1, true),
+            slot(525, 10, 34, 34, F1M1, true),
+            slot(527, 10, 34, 34, F1M1, false),
+            slot(326, 5, 35, 35, F1M1, true),
+            slot(451, 5, 35, 35, F1M1, true),
+            slot(527, 4, 35, 35, F1M1, false),
+            slot(525, 4, 35, 35, F1M1, true),
+            slot(527, 1, 35, 35, F1M1, false),
+            slot(525, 1, 35, 35, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "reversal_mountain_interior_main",
+        slots: &[
+            slot(326, 20, 35, 35, F1M1, true),
+            slot(527, 20, 32, 32, F1M1, false),
+            slot(525, 10, 32, 32, F1M1, true),
+            slot(451, 10, 33, 33, F1M1, true),
+            slot(525, 10, 34, 34, F1M1, true),
+            slot(527, 10, 34, 34, F1M1, false),
+            slot(325, 5, 31, 31, F1M1, true),
+            slot(451, 5, 35, 35, F1M1, true),
+            slot(527, 4, 35, 35, F1M1, false),
+            slot(525, 4, 35, 35, F1M1, true),
+            slot(527, 1, 35, 35, F1M1, false),
+            slot(525, 1, 35, 35, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "strange_house_entrance_b1f",
+        slots: &[
+            slot(607, 20, 31, 31, F1M1, false),
+            slot(574, 20, 31, 31, F3M1, true),
+            slot(20, 10, 32, 32, F1M1, false),
+            slot(42, 10, 32, 32, F1M1, false),
+            slot(607, 10, 33, 33, F1M1, false),
+            slot(354, 10, 32, 32, F1M1, true),
+            slot(20, 5, 33, 33, F1M1, false),
+            slot(42, 5, 33, 33, F1M1, false),
+            slot(354, 4, 34, 34, F1M1, true),
+            slot(575, 4, 34, 34, F3M1, true),
+            slot(354, 1, 34, 34, F1M1, true),
+            slot(575, 1, 34, 34, F3M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "strange_house_small_room",
+        slots: &[
+            slot(607, 20, 31, 31, F1M1, false),
+            slot(574, 20, 31, 31, F3M1, true),
+            slot(20, 10, 32, 32, F1M1, false),
+            slot(42, 10, 32, 32, F1M1, false),
+            slot(575, 10, 33, 33, F3M1, true),
+            slot(354, 10, 32, 32, F1M1, true),
+            slot(20, 5, 33, 33, F1M1, false),
+            slot(42, 5, 33, 33, F1M1, false),
+            slot(354, 4, 34, 34, F1M1, true),
+            slot(575, 4, 34, 34, F3M1, true),
+            slot(354, 1, 34, 34, F1M1, true),
+            slot(575, 1, 34, 34, F3M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "relic_passage_south",
+        slots: &[
+            slot(524, 20, 16, 16, F1M1, true),
+            slot(527, 20, 16, 16, F1M1, false),
+            slot(524, 10, 17, 17, F1M1, true),
+            slot(527, 10, 17, 17, F1M1, false),
+            slot(532, 10, 17, 17, F1M3, false),
+            slot(532, 10, 18, 18, F1M3, false),
+            slot(19, 5, 17, 17, F1M1, false),
+            slot(19, 5, 17, 17, F1M1, false),
+            slot(524, 4, 18, 18, F1M1, true),
+            slot(95, 4, 18, 18, F1M1, false),
+            slot(527, 1, 18, 18, F1M1, false),
+            slot(95, 1, 18, 18, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "relic_passage_north",
+        slots: &[
+            slot(525, 20, 27, 27, F1M1, true),
+            slot(527, 20, 27, 27, F1M1, false),
+            slot(525, 10, 28, 28, F1M1, true),
+            slot(527, 10, 28, 28, F1M1, false),
+            slot(533, 10, 28, 28, F1M3, false),
+            slot(533, 10, 30, 30, F1M3, false),
+            slot(20, 5, 29, 29, F1M1, false),
+            slot(20, 5, 29, 29, F1M1, false),
+            slot(525, 4, 30, 30, F1M1, true),
+            slot(95, 4, 30, 30, F1M1, false),
+            slot(527, 1, 30, 30, F1M1, false),
+            slot(95, 1, 30, 30, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "relic_passage_central",
+        slots: &[
+            slot(525, 20, 27, 27, F1M1, true),
+            slot(527, 20, 27, 27, F1M1, false),
+            slot(525, 10, 28, 28, F1M1, true),
+            slot(527, 10, 28, 28, F1M1, false),
+            slot(533, 10, 28, 28, F1M3, false),
+            slot(533, 10, 30, 30, F1M3, false),
+            slot(525, 5, 29, 29, F1M1, true),
+            slot(527, 5, 29, 29, F1M1, false),
+            slot(525, 4, 30, 30, F1M1, true),
+            slot(95, 4, 30, 30, F1M1, false),
+            slot(527, 1, 30, 30, F1M1, false),
+            slot(95, 1, 30, 30, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "clay_tunnel",
+        slots: &[
+            slot(525, 20, 54, 54, F1M1, true),
+            slot(632, 20, 54, 54, F1M1, false),
+            slot(525, 10, 55, 55, F1M1, true),
+            slot(527, 10, 54, 54, F1M1, false),
+            slot(305, 10, 55, 55, F1M1, true),
+            slot(299, 10, 55, 55, F1M1, true),
+            slot(305, 5, 57, 57, F1M1, true),
+            slot(299, 5, 57, 57, F1M1, true),
+            slot(525, 4, 56, 56, F1M1, true),
+            slot(95, 4, 57, 57, F1M1, false),
+            slot(525, 1, 56, 56, F1M1, true),
+            slot(95, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "underground_ruins",
+        slots: &[
+            slot(525, 20, 54, 54, F1M1, true),
+            slot(632, 20, 54, 54, F1M1, false),
+            slot(525, 10, 55, 55, F1M1, true),
+            slot(527, 10, 54, 54, F1M1, false),
+            slot(305, 10, 55, 55, F1M1, true),
+            slot(299, 10, 55, 55, F1M1, true),
+            slot(305, 5, 57, 57, F1M1, true),
+            slot(299, 5, 57, 57, F1M1, true),
+            slot(525, 4, 56, 56, F1M1, true),
+            slot(95, 4, 57, 57, F1M1, false),
+            slot(525, 1, 56, 56, F1M1, true),
+            slot(95, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "rocky_mountain_chamber",
+        slots: &[
+            slot(525, 20, 54, 54, F1M1, true),
+            slot(632, 20, 54, 54, F1M1, false),
+            slot(525, 10, 55, 55, F1M1, true),
+            slot(527, 10, 54, 54, F1M1, false),
+            slot(305, 10, 55, 55, F1M1, true),
+            slot(299, 10, 55, 55, F1M1, true),
+            slot(305, 5, 57, 57, F1M1, true),
+            slot(299, 5, 57, 57, F1M1, true),
+            slot(525, 4, 56, 56, F1M1, true),
+            slot(95, 4, 57, 57, F1M1, false),
+            slot(525, 1, 56, 56, F1M1, true),
+            slot(95, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "iceberg_chamber",
+        slots: &[
+            slot(525, 20, 54, 54, F1M1, true),
+            slot(632, 20, 54, 54, F1M1, false),
+            slot(525, 10, 55, 55, F1M1, true),
+            slot(527, 10, 54, 54, F1M1, false),
+            slot(305, 10, 55, 55, F1M1, true),
+            slot(299, 10, 55, 55, F1M1, true),
+            slot(305, 5, 57, 57, F1M1, true),
+            slot(299, 5, 57, 57, F1M1, true),
+            slot(525, 4, 56, 56, F1M1, true),
+            slot(95, 4, 57, 57, F1M1, false),
+            slot(525, 1, 56, 56, F1M1, true),
+            slot(95, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "iron_chamber",
+        slots: &[
+            slot(525, 20, 54, 54, F1M1, true),
+            slot(632, 20, 54, 54, F1M1, false),
+            slot(525, 10, 55, 55, F1M1, true),
+            slot(527, 10, 54, 54, F1M1, false),
+            slot(305, 10, 55, 55, F1M1, true),
+            slot(299, 10, 55, 55, F1M1, true),
+            slot(305, 5, 57, 57, F1M1, true),
+            slot(299, 5, 57, 57, F1M1, true),
+            slot(525, 4, 56, 56, F1M1, true),
+            slot(95, 4, 57, 57, F1M1, false),
+            slot(525, 1, 56, 56, F1M1, true),
+            slot(95, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "seaside_cave_1f",
+        slots: &[
+            slot(527, 20, 34, 34, F1M1, false),
+            slot(55, 20, 34, 34, F1M1, false),
+            slot(86, 10, 35, 35, F1M1, false),
+            slot(525, 10, 35, 35, F1M1, true),
+            slot(527, 10, 35, 35, F1M1, false),
+            slot(55, 10, 35, 35, F1M1, false),
+            slot(602, 5, 37, 37, F1M1, false),
+            slot(525, 5, 37, 37, F1M1, true),
+            slot(55, 4, 36, 36, F1M1, false),
+            slot(527, 4, 36, 36, F1M1, false),
+            slot(55, 1, 36, 36, F1M1, false),
+            slot(527, 1, 36, 36, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "seaside_cave_b1f",
+        slots: &[
+            slot(527, 20, 39, 39, F1M1, false),
+            slot(525, 20, 39, 39, F1M1, true),
+            slot(55, 10, 40, 40, F1M1, false),
+            slot(525, 10, 40, 40, F1M1, true),
+            slot(527, 10, 40, 40, F1M1, false),
+            slot(55, 10, 40, 40, F1M1, false),
+            slot(603, 5, 42, 42, F1M1, false),
+            slot(525, 5, 42, 42, F1M1, true),
+            slot(213, 4, 41, 41, F1M1, true),
+            slot(527, 4, 41, 41, F1M1, false),
+            slot(213, 1, 41, 41, F1M1, true),
+            slot(527, 1, 41, 41, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "nature_preserve",
+        slots: &[
+            slot(274, 20, 56, 56, F1M1, false),
+            slot(334, 20, 56, 56, F1M1, false),
+            slot(274, 10, 57, 57, F1M1, false),
+            slot(55, 10, 57, 57, F1M1, false),
+            slot(164, 10, 58, 58, F1M1, false),
+            slot(203, 10, 58, 58, F1M1, true),
+            slot(611, 5, 57, 57, F1M1, false),
+            slot(203, 5, 59, 59, F1M1, true),
+            slot(611, 4, 59, 59, F1M1, false),
+            slot(352, 4, 57, 57, F1M1, true),
+            slot(611, 1, 59, 59, F1M1, false),
+            slot(352, 1, 59, 59, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "village_bridge",
+        slots: &[
+            slot(55, 20, 36, 36, F1M1, false),
+            slot(183, 20, 36, 36, F1M1, false),
+            slot(335, 10, 37, 37, F1M1, true),
+            slot(336, 10, 37, 37, F1M1, false),
+            slot(55, 10, 37, 37, F1M1, false),
+            slot(183, 10, 37, 37, F1M1, false),
+            slot(55, 5, 38, 38, F1M1, false),
+            slot(183, 5, 38, 38, F1M1, false),
+            slot(335, 4, 39, 39, F1M1, true),
+            slot(336, 4, 39, 39, F1M1, false),
+            slot(335, 1, 39, 39, F1M1, true),
+            slot(336, 1, 39, 39, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "route_1",
+        slots: &[
+            slot(505, 20, 56, 56, F1M1, false),
+            slot(507, 20, 56, 56, F1M1, false),
+            slot(39, 10, 57, 57, F3M1, false),
+            slot(507, 10, 57, 57, F1M1, false),
+            slot(505, 10, 58, 58, F1M1, false),
+            slot(507, 10, 58, 58, F1M1, false),
+            slot(505, 5, 59, 59, F1M1, false),
+            slot(507, 5, 59, 59, F1M1, false),
+            slot(505, 4, 59, 59, F1M1, false),
+            slot(507, 4, 59, 59, F1M1, false),
+            slot(505, 1, 59, 59, F1M1, false),
+            slot(507, 1, 59, 59, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "route_2",
+        slots: &[
+            slot(505, 20, 56, 56, F1M1, false),
+            slot(507, 20, 56, 56, F1M1, false),
+            slot(39, 10, 57, 57, F3M1, false),
+            slot(108, 10, 58, 58, F1M1, true),
+            slot(510, 10, 57, 57, F1M1, false),
+            slot(510, 10, 58, 58, F1M1, false),
+            slot(505, 5, 59, 59, F1M1, false),
+            slot(507, 5, 59, 59, F1M1, false),
+            slot(505, 4, 59, 59, F1M1, false),
+            slot(507, 4, 59, 59, F1M1, false),
+            slot(505, 1, 59, 59, F1M1, false),
+            slot(507, 1, 59, 59, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "route_3",
+        slots: &[
+            slot(505, 20, 55, 55, F1M1, false),
+            slot(520, 20, 55, 55, F1M1, false),
+            slot(523, 10, 56, 56, F1M1, true),
+            slot(193, 10, 56, 56, F1M1, true),
+            slot(507, 10, 57, 57, F1M1, false),
+            slot(509, 10, 57, 57, F1M1, false),
+            slot(523, 5, 58, 58, F1M1, true),
+            slot(520, 5, 57, 57, F1M1, false),
+            slot(523, 4, 58, 58, F1M1, true),
+            slot(520, 4, 57, 57, F1M1, false),
+            slot(523, 1, 58, 58, F1M1, true),
+            slot(520, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "wellspring_cave",
+        slots: &[
+            slot(525, 20, 55, 55, F1M1, true),
+            slot(527, 20, 55, 55, F1M1, false),
+            slot(525, 10, 56, 56, F1M1, true),
+            slot(527, 10, 56, 56, F1M1, false),
+            slot(525, 10, 57, 57, F1M1, true),
+            slot(527, 10, 57, 57, F1M1, false),
+            slot(525, 5, 58, 58, F1M1, true),
+            slot(527, 5, 58, 58, F1M1, false),
+            slot(525, 4, 58, 58, F1M1, true),
+            slot(527, 4, 58, 58, F1M1, false),
+            slot(525, 1, 58, 58, F1M1, true),
+            slot(527, 1, 58, 58, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "route_4",
+        slots: &[
+            slot(551, 20, 14, 14, F1M1, false),
+            slot(554, 20, 14, 14, F1M1, true),
+            slot(551, 10, 15, 15, F1M1, false),
+            slot(554, 10, 15, 15, F1M1, true),
+            slot(568, 10, 15, 15, F1M1, true),
+            slot(568, 10, 14, 14, F1M1, true),
+            slot(559, 5, 17, 17, F1M1, true),
+            slot(568, 5, 17, 17, F1M1, true),
+            slot(551, 4, 16, 16, F1M1, false),
+            slot(554, 4, 16, 16, F1M1, true),
+            slot(551, 1, 17, 17, F1M1, false),
+            slot(554, 1, 17, 17, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "route_5",
+        slots: &[
+            slot(574, 20, 21, 21, F3M1, true),
+            slot(572, 20, 21, 21, F3M1, true),
+            slot(568, 10, 21, 21, F1M1, true),
+            slot(568, 10, 23, 23, F1M1, true),
+            slot(510, 10, 22, 22, F1M1, false),
+            slot(510, 10, 24, 24, F1M1, false),
+            slot(572, 5, 22, 22, F3M1, true),
+            slot(574, 5, 22, 22, F3M1, true),
+            slot(572, 4, 23, 23, F3M1, true),
+            slot(574, 4, 23, 23, F3M1, true),
+            slot(572, 1, 24, 24, F3M1, true),
+            slot(574, 1, 24, 24, F3M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "route_6_spring",
+        slots: &[
+            slot(585, 20, 23, 23, F1M1, false),
+            slot(616, 20, 23, 23, F1M1, false),
+            slot(520, 10, 26, 26, F1M1, false),
+            slot(590, 10, 26, 26, F1M1, true),
+            slot(585, 10, 25, 25, F1M1, false),
+            slot(541, 10, 26, 26, F1M1, true),
+            slot(183, 5, 25, 25, F1M1, false),
+            slot(588, 5, 23, 23, F1M1, false),
+            slot(520, 4, 26, 26, F1M1, false),
+            slot(616, 4, 26, 26, F1M1, false),
+            slot(520, 1, 26, 26, F1M1, false),
+            slot(616, 1, 26, 26, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "route_6_summer",
+        slots: &[
+            slot(585, 20, 23, 23, F1M1, false),
+            slot(616, 20, 23, 23, F1M1, false),
+            slot(520, 10, 26, 26, F1M1, false),
+            slot(590, 10, 26, 26, F1M1, true),
+            slot(585, 10, 25, 25, F1M1, false),
+            slot(541, 10, 26, 26, F1M1, true),
+            slot(183, 5, 25, 25, F1M1, false),
+            slot(588, 5, 23, 23, F1M1, false),
+            slot(520, 4, 26, 26, F1M1, false),
+            slot(616, 4, 26, 26, F1M1, false),
+            slot(520, 1, 26, 26, F1M1, false),
+            slot(616, 1, 26, 26, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "route_6_autumn",
+        slots: &[
+            slot(585, 20, 23, 23, F1M1, false),
+            slot(616, 20, 23, 23, F1M1, false),
+            slot(520, 10, 26, 26, F1M1, false),
+            slot(590, 10, 26, 26, F1M1, true),
+            slot(585, 10, 25, 25, F1M1, false),
+            slot(541, 10, 26, 26, F1M1, true),
+            slot(183, 5, 25, 25, F1M1, false),
+            slot(588, 5, 23, 23, F1M1, false),
+            slot(520, 4, 26, 26, F1M1, false),
+            slot(616, 4, 26, 26, F1M1, false),
+            slot(520, 1, 26, 26, F1M1, false),
+            slot(616, 1, 26, 26, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "route_6_winter",
+        slots: &[
+            slot(585, 20, 23, 23, F1M1, false),
+            slot(616, 20, 23, 23, F1M1, false),
+            slot(520, 10, 26, 26, F1M1, false),
+            slot(590, 10, 26, 26, F1M1, true),
+            slot(585, 10, 25, 25, F1M1, false),
+            slot(541, 10, 26, 26, F1M1, true),
+            slot(183, 5, 25, 25, F1M1, false),
+            slot(588, 5, 23, 23, F1M1, false),
+            slot(520, 4, 26, 26, F1M1, false),
+            slot(616, 4, 26, 26, F1M1, false),
+            slot(520, 1, 26, 26, F1M1, false),
+            slot(616, 1, 26, 26, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "mistralton_cave",
+        slots: &[
+            slot(525, 20, 27, 27, F1M1, true),
+            slot(527, 20, 27, 27, F1M1, false),
+            slot(525, 10, 28, 28, F1M1, true),
+            slot(527, 10, 28, 28, F1M1, false),
+            slot(304, 10, 29, 29, F1M1, true),
+            slot(610, 10, 29, 29, F1M1, false),
+            slot(304, 5, 30, 30, F1M1, true),
+            slot(610, 5, 30, 30, F1M1, false),
+            slot(304, 4, 30, 30, F1M1, true),
+            slot(610, 4, 30, 30, F1M1, false),
+            slot(304, 1, 30, 30, F1M1, true),
+            slot(610, 1, 30, 30, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "guidance_chamber",
+        slots: &[
+            slot(525, 20, 27, 27, F1M1, true),
+            slot(527, 20, 27, 27, F1M1, false),
+            slot(525, 10, 28, 28, F1M1, true),
+            slot(527, 10, 28, 28, F1M1, false),
+            slot(304, 10, 29, 29, F1M1, true),
+            slot(610, 10, 29, 29, F1M1, false),
+            slot(304, 5, 30, 30, F1M1, true),
+            slot(610, 5, 30, 30, F1M1, false),
+            slot(304, 4, 30, 30, F1M1, true),
+            slot(610, 4, 30, 30, F1M1, false),
+            slot(304, 1, 30, 30, F1M1, true),
+            slot(610, 1, 30, 30, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "route_7_spring",
+        slots: &[
+            slot(520, 20, 30, 30, F1M1, false),
+            slot(585, 20, 30, 30, F1M1, false),
+            slot(505, 10, 31, 31, F1M1, false),
+            slot(523, 10, 31, 31, F1M1, true),
+            slot(520, 10, 32, 32, F1M1, false),
+            slot(523, 10, 33, 33, F1M1, true),
+            slot(335, 5, 32, 32, F1M1, true),
+            slot(336, 5, 32, 32, F1M1, false),
+            slot(505, 4, 33, 33, F1M1, false),
+            slot(590, 4, 33, 33, F1M1, true),
+            slot(505, 1, 33, 33, F1M1, false),
+            slot(590, 1, 33, 33, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "route_7_summer",
+        slots: &[
+            slot(520, 20, 30, 30, F1M1, false),
+            slot(585, 20, 30, 30, F1M1, false),
+            slot(505, 10, 31, 31, F1M1, false),
+            slot(523, 10, 31, 31, F1M1, true),
+            slot(520, 10, 32, 32, F1M1, false),
+            slot(523, 10, 33, 33, F1M1, true),
+            slot(335, 5, 32, 32, F1M1, true),
+            slot(336, 5, 32, 32, F1M1, false),
+            slot(505, 4, 33, 33, F1M1, false),
+            slot(590, 4, 33, 33, F1M1, true),
+            slot(505, 1, 33, 33, F1M1, false),
+            slot(590, 1, 33, 33, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "route_7_autumn",
+        slots: &[
+            slot(520, 20, 30, 30, F1M1, false),
+            slot(585, 20, 30, 30, F1M1, false),
+            slot(505, 10, 31, 31, F1M1, false),
+            slot(523, 10, 31, 31, F1M1, true),
+            slot(520, 10, 32, 32, F1M1, false),
+            slot(523, 10, 33, 33, F1M1, true),
+            slot(335, 5, 32, 32, F1M1, true),
+            slot(336, 5, 32, 32, F1M1, false),
+            slot(505, 4, 33, 33, F1M1, false),
+            slot(590, 4, 33, 33, F1M1, true),
+            slot(505, 1, 33, 33, F1M1, false),
+            slot(590, 1, 33, 33, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "route_7_winter",
+        slots: &[
+            slot(613, 20, 30, 30, F1M1, true),
+            slot(585, 20, 30, 30, F1M1, false),
+            slot(505, 10, 31, 31, F1M1, false),
+            slot(523, 10, 31, 31, F1M1, true),
+            slot(520, 10, 30, 30, F1M1, false),
+            slot(523, 10, 33, 33, F1M1, true),
+            slot(335, 5, 32, 32, F1M1, true),
+            slot(336, 5, 32, 32, F1M1, false),
+            slot(613, 4, 33, 33, F1M1, true),
+            slot(590, 4, 33, 33, F1M1, true),
+            slot(613, 1, 33, 33, F1M1, true),
+            slot(590, 1, 33, 33, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "celestial_tower_2f",
+        slots: &[
+            slot(607, 20, 27, 27, F1M1, false),
+            slot(607, 20, 27, 27, F1M1, false),
+            slot(607, 10, 28, 28, F1M1, false),
+            slot(607, 10, 28, 28, F1M1, false),
+            slot(607, 10, 29, 29, F1M1, false),
+            slot(607, 10, 29, 29, F1M1, false),
+            slot(607, 5, 30, 30, F1M1, false),
+            slot(607, 5, 30, 30, F1M1, false),
+            slot(607, 4, 30, 30, F1M1, false),
+            slot(607, 4, 30, 30, F1M1, false),
+            slot(607, 1, 30, 30, F1M1, false),
+            slot(607, 1, 30, 30, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "celestial_tower_3f",
+        slots: &[
+            slot(607, 20, 28, 28, F1M1, false),
+            slot(607, 20, 28, 28, F1M1, false),
+            slot(607, 10, 29, 29, F1M1, false),
+            slot(607, 10, 29, 29, F1M1, false),
+            slot(607, 10, 30, 30, F1M1, false),
+            slot(605, 10, 30, 30, F1M1, false),
+            slot(607, 5, 31, 31, F1M1, false),
+            slot(42, 5, 31, 31, F1M1, false),
+            slot(607, 4, 31, 31, F1M1, false),
+            slot(605, 4, 31, 31, F1M1, false),
+            slot(607, 1, 31, 31, F1M1, false),
+            slot(605, 1, 31, 31, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "celestial_tower_4f",
+        slots: &[
+            slot(607, 20, 29, 29, F1M1, false),
+            slot(607, 20, 29, 29, F1M1, false),
+            slot(607, 10, 30, 30, F1M1, false),
+            slot(605, 10, 30, 30, F1M1, false),
+            slot(607, 10, 31, 31, F1M1, false),
+            slot(605, 10, 31, 31, F1M1, false),
+            slot(42, 5, 32, 32, F1M1, false),
+            slot(42, 5, 32, 32, F1M1, false),
+            slot(607, 4, 32, 32, F1M1, false),
+            slot(605, 4, 32, 32, F1M1, false),
+            slot(607, 1, 32, 32, F1M1, false),
+            slot(605, 1, 32, 32, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "celestial_tower_5f",
+        slots: &[
+            slot(607, 20, 30, 30, F1M1, false),
+            slot(605, 20, 30, 30, F1M1, false),
+            slot(607, 10, 31, 31, F1M1, false),
+            slot(605, 10, 31, 31, F1M1, false),
+            slot(42, 10, 33, 33, F1M1, false),
+            slot(42, 10, 33, 33, F1M1, false),
+            slot(607, 5, 32, 32, F1M1, false),
+            slot(605, 5, 32, 32, F1M1, false),
+            slot(607, 4, 33, 33, F1M1, false),
+            slot(605, 4, 33, 33, F1M1, false),
+            slot(607, 1, 33, 33, F1M1, false),
+            slot(605, 1, 33, 33, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "route_8_spring",
+        slots: &[
+            slot(536, 20, 54, 54, F1M1, true),
+            slot(616, 20, 54, 54, F1M1, false),
+            slot(536, 10, 55, 55, F1M1, true),
+            slot(453, 10, 55, 55, F1M1, true),
+            slot(618, 10, 55, 55, F1M1, true),
+            slot(618, 10, 56, 56, F1M1, true),
+            slot(536, 5, 56, 56, F1M1, true),
+            slot(453, 5, 56, 56, F1M1, true),
+            slot(536, 4, 57, 57, F1M1, true),
+            slot(588, 4, 57, 57, F1M1, false),
+            slot(536, 1, 57, 57, F1M1, true),
+            slot(588, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "route_8_summer",
+        slots: &[
+            slot(536, 20, 54, 54, F1M1, true),
+            slot(616, 20, 54, 54, F1M1, false),
+            slot(536, 10, 55, 55, F1M1, true),
+            slot(453, 10, 55, 55, F1M1, true),
+            slot(618, 10, 55, 55, F1M1, true),
+            slot(618, 10, 56, 56, F1M1, true),
+            slot(536, 5, 56, 56, F1M1, true),
+            slot(453, 5, 56, 56, F1M1, true),
+            slot(536, 4, 57, 57, F1M1, true),
+            slot(588, 4, 57, 57, F1M1, false),
+            slot(536, 1, 57, 57, F1M1, true),
+            slot(588, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "route_8_autumn",
+        slots: &[
+            slot(536, 20, 54, 54, F1M1, true),
+            slot(616, 20, 54, 54, F1M1, false),
+            slot(536, 10, 55, 55, F1M1, true),
+            slot(453, 10, 55, 55, F1M1, true),
+            slot(618, 10, 55, 55, F1M1, true),
+            slot(618, 10, 56, 56, F1M1, true),
+            slot(536, 5, 56, 56, F1M1, true),
+            slot(453, 5, 56, 56, F1M1, true),
+            slot(536, 4, 57, 57, F1M1, true),
+            slot(588, 4, 57, 57, F1M1, false),
+            slot(536, 1, 57, 57, F1M1, true),
+            slot(588, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "moor_of_icirrus_spring",
+        slots: &[
+            slot(536, 20, 54, 54, F1M1, true),
+            slot(616, 20, 54, 54, F1M1, false),
+            slot(536, 10, 55, 55, F1M1, true),
+            slot(453, 10, 55, 55, F1M1, true),
+            slot(618, 10, 55, 55, F1M1, true),
+            slot(618, 10, 56, 56, F1M1, true),
+            slot(536, 5, 56, 56, F1M1, true),
+            slot(453, 5, 56, 56, F1M1, true),
+            slot(536, 4, 57, 57, F1M1, true),
+            slot(588, 4, 57, 57, F1M1, false),
+            slot(536, 1, 57, 57, F1M1, true),
+            slot(588, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "moor_of_icirrus_summer",
+        slots: &[
+            slot(536, 20, 54, 54, F1M1, true),
+            slot(616, 20, 54, 54, F1M1, false),
+            slot(536, 10, 55, 55, F1M1, true),
+            slot(453, 10, 55, 55, F1M1, true),
+            slot(618, 10, 55, 55, F1M1, true),
+            slot(618, 10, 56, 56, F1M1, true),
+            slot(536, 5, 56, 56, F1M1, true),
+            slot(453, 5, 56, 56, F1M1, true),
+            slot(536, 4, 57, 57, F1M1, true),
+            slot(588, 4, 57, 57, F1M1, false),
+            slot(536, 1, 57, 57, F1M1, true),
+            slot(588, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "moor_of_icirrus_autumn",
+        slots: &[
+            slot(536, 20, 54, 54, F1M1, true),
+            slot(616, 20, 54, 54, F1M1, false),
+            slot(536, 10, 55, 55, F1M1, true),
+            slot(453, 10, 55, 55, F1M1, true),
+            slot(618, 10, 55, 55, F1M1, true),
+            slot(618, 10, 56, 56, F1M1, true),
+            slot(536, 5, 56, 56, F1M1, true),
+            slot(453, 5, 56, 56, F1M1, true),
+            slot(536, 4, 57, 57, F1M1, true),
+            slot(588, 4, 57, 57, F1M1, false),
+            slot(536, 1, 57, 57, F1M1, true),
+            slot(588, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "route_9",
+        slots: &[
+            slot(575, 20, 37, 37, F3M1, true),
+            slot(572, 20, 37, 37, F3M1, true),
+            slot(569, 10, 38, 38, F1M1, true),
+            slot(572, 10, 39, 39, F3M1, true),
+            slot(624, 10, 38, 38, F1M1, false),
+            slot(510, 10, 40, 40, F1M1, false),
+            slot(624, 5, 40, 40, F1M1, false),
+            slot(89, 5, 40, 40, F1M1, true),
+            slot(575, 4, 39, 39, F3M1, true),
+            slot(569, 4, 40, 40, F1M1, true),
+            slot(575, 1, 39, 39, F3M1, true),
+            slot(569, 1, 40, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "route_11",
+        slots: &[
+            slot(616, 20, 36, 36, F1M1, false),
+            slot(55, 20, 36, 36, F1M1, false),
+            slot(207, 10, 37, 37, F1M1, false),
+            slot(183, 10, 36, 36, F1M1, false),
+            slot(335, 10, 38, 38, F1M1, true),
+            slot(336, 10, 38, 38, F1M1, false),
+            slot(591, 5, 37, 37, F1M1, true),
+            slot(588, 5, 36, 36, F1M1, false),
+            slot(207, 4, 39, 39, F1M1, false),
+            slot(616, 4, 39, 39, F1M1, false),
+            slot(207, 1, 39, 39, F1M1, false),
+            slot(616, 1, 39, 39, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "route_12",
+        slots: &[
+            slot(415, 20, 35, 35, F1M7, true),
+            slot(315, 20, 35, 35, F1M1, true),
+            slot(540, 10, 36, 36, F1M1, true),
+            slot(520, 10, 37, 37, F1M1, false),
+            slot(214, 10, 36, 36, F1M1, false),
+            slot(520, 10, 36, 36, F1M1, false),
+            slot(214, 5, 38, 38, F1M1, false),
+            slot(540, 5, 38, 38, F1M1, true),
+            slot(315, 4, 37, 37, F1M1, true),
+            slot(520, 4, 38, 38, F1M1, false),
+            slot(315, 1, 37, 37, F1M1, true),
+            slot(520, 1, 38, 38, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "route_13",
+        slots: &[
+            slot(114, 20, 34, 34, F1M1, false),
+            slot(279, 20, 34, 34, F1M1, false),
+            slot(426, 10, 34, 34, F1M1, true),
+            slot(337, 10, 36, 36, Genderless, true),
+            slot(338, 10, 36, 36, Genderless, true),
+            slot(359, 10, 35, 35, F1M1, false),
+            slot(426, 5, 35, 35, F1M1, true),
+            slot(359, 5, 37, 37, F1M1, false),
+            slot(114, 4, 36, 36, F1M1, false),
+            slot(279, 4, 36, 36, F1M1, false),
+            slot(114, 1, 37, 37, F1M1, false),
+            slot(279, 1, 37, 37, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "route_14",
+        slots: &[
+            slot(55, 20, 34, 34, F1M1, false),
+            slot(333, 20, 33, 33, F1M1, false),
+            slot(426, 10, 34, 34, F1M1, true),
+            slot(619, 10, 33, 33, F1M1, false),
+            slot(619, 10, 35, 35, F1M1, false),
+            slot(359, 10, 34, 34, F1M1, false),
+            slot(426, 5, 35, 35, F1M1, true),
+            slot(359, 5, 36, 36, F1M1, false),
+            slot(55, 4, 35, 35, F1M1, false),
+            slot(334, 4, 36, 36, F1M1, false),
+            slot(55, 1, 35, 35, F1M1, false),
+            slot(334, 1, 36, 36, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "abundant_shrine",
+        slots: &[
+            slot(333, 20, 33, 33, F1M1, false),
+            slot(546, 20, 33, 33, F1M1, false),
+            slot(37, 10, 34, 34, F3M1, true),
+            slot(436, 10, 32, 32, Genderless, true),
+            slot(183, 10, 34, 34, F1M1, false),
+            slot(55, 10, 34, 34, F1M1, false),
+            slot(546, 5, 35, 35, F1M1, false),
+            slot(436, 5, 32, 32, Genderless, true),
+            slot(546, 4, 35, 35, F1M1, false),
+            slot(334, 4, 36, 36, F1M1, false),
+            slot(546, 1, 35, 35, F1M1, false),
+            slot(334, 1, 36, 36, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "route_15",
+        slots: &[
+            slot(28, 20, 54, 54, F1M1, true),
+            slot(207, 20, 55, 55, F1M1, false),
+            slot(28, 10, 56, 56, F1M1, true),
+            slot(560, 10, 55, 55, F1M1, true),
+            slot(539, 10, 55, 55, MaleOnly, true),
+            slot(247, 10, 55, 55, F1M1, false),
+            slot(539, 5, 56, 56, MaleOnly, true),
+            slot(539, 5, 57, 57, MaleOnly, true),
+            slot(247, 4, 57, 57, F1M1, false),
+            slot(207, 4, 57, 57, F1M1, false),
+            slot(247, 1, 57, 57, F1M1, false),
+            slot(207, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "route_16",
+        slots: &[
+            slot(574, 20, 21, 21, F3M1, true),
+            slot(572, 20, 21, 21, F3M1, true),
+            slot(568, 10, 21, 21, F1M1, true),
+            slot(568, 10, 23, 23, F1M1, true),
+            slot(510, 10, 22, 22, F1M1, false),
+            slot(510, 10, 24, 24, F1M1, false),
+            slot(572, 5, 22, 22, F3M1, true),
+            slot(574, 5, 22, 22, F3M1, true),
+            slot(572, 4, 23, 23, F3M1, true),
+            slot(574, 4, 23, 23, F3M1, true),
+            slot(572, 1, 24, 24, F3M1, true),
+            slot(574, 1, 24, 24, F3M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "lostlorn_forest",
+        slots: &[
+            slot(546, 20, 21, 21, F1M1, false),
+            slot(541, 20, 21, 21, F1M1, true),
+            slot(415, 10, 22, 22, F1M7, true),
+            slot(543, 10, 21, 21, F1M1, true),
+            slot(315, 10, 23, 23, F1M1, true),
+            slot(543, 10, 21, 21, F1M1, true),
+            slot(315, 5, 24, 24, F1M1, true),
+            slot(214, 5, 24, 24, F1M1, false),
+            slot(546, 4, 23, 23, F1M1, false),
+            slot(541, 4, 24, 24, F1M1, true),
+            slot(546, 1, 24, 24, F1M1, false),
+            slot(541, 1, 24, 24, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "route_18",
+        slots: &[
+            slot(560, 20, 57, 57, F1M1, true),
+            slot(558, 20, 57, 57, F1M1, true),
+            slot(505, 10, 56, 56, F1M1, false),
+            slot(357, 10, 58, 58, F1M1, false),
+            slot(539, 10, 57, 57, MaleOnly, true),
+            slot(455, 10, 58, 58, F1M1, false),
+            slot(560, 5, 58, 58, F1M1, true),
+            slot(539, 5, 59, 59, MaleOnly, true),
+            slot(560, 4, 58, 58, F1M1, true),
+            slot(539, 4, 59, 59, MaleOnly, true),
+            slot(560, 1, 58, 58, F1M1, true),
+            slot(539, 1, 59, 59, MaleOnly, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "route_19",
+        slots: &[
+            slot(504, 20, 2, 2, F1M1, false),
+            slot(509, 20, 2, 2, F1M1, false),
+            slot(504, 10, 3, 3, F1M1, false),
+            slot(509, 10, 3, 3, F1M1, false),
+            slot(504, 10, 4, 4, F1M1, false),
+            slot(509, 10, 4, 4, F1M1, false),
+            slot(504, 5, 4, 4, F1M1, false),
+            slot(509, 5, 4, 4, F1M1, false),
+            slot(504, 4, 4, 4, F1M1, false),
+            slot(509, 4, 4, 4, F1M1, false),
+            slot(504, 1, 4, 4, F1M1, false),
+            slot(509, 1, 4, 4, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "route_20_spring",
+        slots: &[
+            slot(519, 20, 2, 2, F1M1, false),
+            slot(540, 20, 2, 2, F1M1, true),
+            slot(504, 10, 3, 3, F1M1, false),
+            slot(509, 10, 3, 3, F1M1, false),
+            slot(504, 10, 3, 3, F1M1, false),
+            slot(540, 10, 3, 3, F1M1, true),
+            slot(519, 5, 4, 4, F1M1, false),
+            slot(540, 5, 4, 4, F1M1, true),
+            slot(509, 4, 4, 4, F1M1, false),
+            slot(191, 4, 4, 4, F1M1, false),
+            slot(509, 1, 4, 4, F1M1, false),
+            slot(191, 1, 4, 4, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "route_20_summer",
+        slots: &[
+            slot(191, 20, 2, 2, F1M1, false),
+            slot(540, 20, 2, 2, F1M1, true),
+            slot(504, 10, 3, 3, F1M1, false),
+            slot(509, 10, 3, 3, F1M1, false),
+            slot(504, 10, 3, 3, F1M1, false),
+            slot(540, 10, 3, 3, F1M1, true),
+            slot(519, 5, 4, 4, F1M1, false),
+            slot(540, 5, 4, 4, F1M1, true),
+            slot(509, 4, 4, 4, F1M1, false),
+            slot(191, 4, 3, 3, F1M1, false),
+            slot(191, 1, 4, 4, F1M1, false),
+            slot(191, 1, 4, 4, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "route_20_autumn",
+        slots: &[
+            slot(519, 20, 2, 2, F1M1, false),
+            slot(540, 20, 2, 2, F1M1, true),
+            slot(504, 10, 3, 3, F1M1, false),
+            slot(509, 10, 3, 3, F1M1, false),
+            slot(504, 10, 3, 3, F1M1, false),
+            slot(540, 10, 3, 3, F1M1, true),
+            slot(519, 5, 4, 4, F1M1, false),
+            slot(540, 5, 4, 4, F1M1, true),
+            slot(509, 4, 4, 4, F1M1, false),
+            slot(191, 4, 4, 4, F1M1, false),
+            slot(509, 1, 4, 4, F1M1, false),
+            slot(191, 1, 4, 4, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "route_20_winter",
+        slots: &[
+            slot(519, 20, 2, 2, F1M1, false),
+            slot(540, 20, 2, 2, F1M1, true),
+            slot(504, 10, 3, 3, F1M1, false),
+            slot(509, 10, 3, 3, F1M1, false),
+            slot(504, 10, 3, 3, F1M1, false),
+            slot(540, 10, 3, 3, F1M1, true),
+            slot(519, 5, 4, 4, F1M1, false),
+            slot(540, 5, 4, 4, F1M1, true),
+            slot(509, 4, 4, 4, F1M1, false),
+            slot(191, 4, 4, 4, F1M1, false),
+            slot(509, 1, 4, 4, F1M1, false),
+            slot(191, 1, 4, 4, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "route_22",
+        slots: &[
+            slot(591, 20, 39, 39, F1M1, true),
+            slot(619, 20, 39, 39, F1M1, false),
+            slot(279, 10, 40, 40, F1M1, false),
+            slot(55, 10, 40, 40, F1M1, false),
+            slot(337, 10, 41, 41, Genderless, true),
+            slot(338, 10, 41, 41, Genderless, true),
+            slot(183, 5, 40, 40, F1M1, false),
+            slot(183, 5, 42, 42, F1M1, false),
+            slot(619, 4, 42, 42, F1M1, false),
+            slot(225, 4, 39, 39, F1M1, false),
+            slot(619, 1, 42, 42, F1M1, false),
+            slot(225, 1, 39, 39, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Normal,
+        location_key: "route_23",
+        slots: &[
+            slot(626, 20, 49, 49, F1M1, false),
+            slot(539, 20, 48, 48, MaleOnly, true),
+            slot(207, 10, 49, 49, F1M1, false),
+            slot(619, 10, 48, 48, F1M1, false),
+            slot(591, 10, 49, 49, F1M1, true),
+            slot(55, 10, 50, 50, F1M1, false),
+            slot(619, 5, 48, 48, F1M1, false),
+            slot(539, 5, 49, 49, MaleOnly, true),
+            slot(629, 4, 47, 47, FemaleOnly, false),
+            slot(626, 4, 51, 51, F1M1, false),
+            slot(629, 1, 47, 47, FemaleOnly, false),
+            slot(626, 1, 51, 51, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "castelia_city",
+        slots: &[
+            slot(531, 20, 15, 15, F1M1, true),
+            slot(531, 20, 15, 15, F1M1, true),
+            slot(531, 10, 16, 16, F1M1, true),
+            slot(531, 10, 16, 16, F1M1, true),
+            slot(531, 10, 17, 17, F1M1, true),
+            slot(531, 10, 17, 17, F1M1, true),
+            slot(531, 5, 18, 18, F1M1, true),
+            slot(531, 5, 18, 18, F1M1, true),
+            slot(428, 4, 18, 18, F1M1, true),
+            slot(547, 4, 18, 18, F1M1, false),
+            slot(428, 1, 18, 18, F1M1, true),
+            slot(547, 1, 18, 18, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "dreamyard",
+        slots: &[
+            slot(531, 20, 56, 56, F1M1, true),
+            slot(531, 20, 56, 56, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(206, 10, 57, 57, F1M1, false),
+            slot(531, 10, 58, 58, F1M1, true),
+            slot(531, 10, 58, 58, F1M1, true),
+            slot(531, 5, 59, 59, F1M1, true),
+            slot(40, 5, 59, 59, F3M1, false),
+            slot(169, 4, 59, 59, F1M1, false),
+            slot(518, 4, 59, 59, F1M1, false),
+            slot(169, 1, 59, 59, F1M1, false),
+            slot(518, 1, 59, 59, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "pinwheel_forest_exterior",
+        slots: &[
+            slot(531, 20, 54, 54, F1M1, true),
+            slot(531, 20, 54, 54, F1M1, true),
+            slot(531, 10, 55, 55, F1M1, true),
+            slot(531, 10, 55, 55, F1M1, true),
+            slot(531, 10, 56, 56, F1M1, true),
+            slot(531, 10, 56, 56, F1M1, true),
+            slot(469, 5, 57, 57, F1M1, true),
+            slot(537, 5, 57, 57, F1M1, true),
+            slot(531, 4, 57, 57, F1M1, true),
+            slot(538, 4, 57, 57, MaleOnly, true),
+            slot(531, 1, 57, 57, F1M1, true),
+            slot(538, 1, 57, 57, MaleOnly, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "pinwheel_forest_interior",
+        slots: &[
+            slot(531, 20, 54, 54, F1M1, true),
+            slot(531, 20, 64, 64, F1M1, true),
+            slot(511, 10, 55, 55, F1M7, true),
+            slot(513, 10, 55, 55, F1M7, true),
+            slot(515, 10, 55, 55, F1M7, true),
+            slot(531, 10, 56, 56, F1M1, true),
+            slot(531, 5, 57, 57, F1M1, true),
+            slot(545, 5, 57, 57, F1M1, true),
+            slot(289, 4, 57, 57, F1M1, false),
+            slot(547, 4, 57, 57, F1M1, false),
+            slot(289, 1, 57, 57, F1M1, false),
+            slot(547, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "dragonspiral_tower_spring_exterior_south",
+        slots: &[
+            slot(531, 20, 55, 55, F1M1, true),
+            slot(531, 20, 55, 55, F1M1, true),
+            slot(587, 10, 56, 56, F1M1, true),
+            slot(531, 10, 56, 56, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(531, 5, 58, 58, F1M1, true),
+            slot(531, 5, 58, 58, F1M1, true),
+            slot(531, 4, 58, 58, F1M1, true),
+            slot(521, 4, 58, 58, F1M1, false),
+            slot(531, 1, 58, 58, F1M1, true),
+            slot(521, 1, 58, 58, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "dragonspiral_tower_spring_exterior_northeast",
+        slots: &[
+            slot(531, 20, 55, 55, F1M1, true),
+            slot(531, 20, 55, 55, F1M1, true),
+            slot(587, 10, 56, 56, F1M1, true),
+            slot(531, 10, 56, 56, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(531, 5, 57, 57, F1M1, true),
+            slot(531, 5, 57, 57, F1M1, true),
+            slot(531, 4, 57, 57, F1M1, true),
+            slot(521, 4, 57, 57, F1M1, false),
+            slot(531, 1, 57, 57, F1M1, true),
+            slot(521, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "dragonspiral_tower_summer_exterior_south",
+        slots: &[
+            slot(531, 20, 55, 55, F1M1, true),
+            slot(531, 20, 55, 55, F1M1, true),
+            slot(587, 10, 56, 56, F1M1, true),
+            slot(531, 10, 56, 56, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(531, 5, 58, 58, F1M1, true),
+            slot(531, 5, 58, 58, F1M1, true),
+            slot(531, 4, 58, 58, F1M1, true),
+            slot(521, 4, 58, 58, F1M1, false),
+            slot(531, 1, 58, 58, F1M1, true),
+            slot(521, 1, 58, 58, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "dragonspiral_tower_summer_exterior_northeast",
+        slots: &[
+            slot(531, 20, 55, 55, F1M1, true),
+            slot(531, 20, 55, 55, F1M1, true),
+            slot(587, 10, 56, 56, F1M1, true),
+            slot(531, 10, 56, 56, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(531, 5, 57, 57, F1M1, true),
+            slot(531, 5, 57, 57, F1M1, true),
+            slot(531, 4, 57, 57, F1M1, true),
+            slot(521, 4, 57, 57, F1M1, false),
+            slot(531, 1, 57, 57, F1M1, true),
+            slot(521, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "dragonspiral_tower_autumn_exterior_south",
+        slots: &[
+            slot(531, 20, 55, 55, F1M1, true),
+            slot(531, 20, 55, 55, F1M1, true),
+            slot(587, 10, 56, 56, F1M1, true),
+            slot(531, 10, 56, 56, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(531, 5, 58, 58, F1M1, true),
+            slot(531, 5, 58, 58, F1M1, true),
+            slot(531, 4, 58, 58, F1M1, true),
+            slot(521, 4, 58, 58, F1M1, false),
+            slot(531, 1, 58, 58, F1M1, true),
+            slot(521, 1, 58, 58, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "dragonspiral_tower_autumn_exterior_northeast",
+        slots: &[
+            slot(531, 20, 55, 55, F1M1, true),
+            slot(531, 20, 55, 55, F1M1, true),
+            slot(587, 10, 56, 56, F1M1, true),
+            slot(531, 10, 56, 56, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(531, 5, 57, 57, F1M1, true),
+            slot(531, 5, 57, 57, F1M1, true),
+            slot(531, 4, 57, 57, F1M1, true),
+            slot(521, 4, 57, 57, F1M1, false),
+            slot(531, 1, 57, 57, F1M1, true),
+            slot(521, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "dragonspiral_tower_winter_exterior_south",
+        slots: &[
+            slot(531, 20, 55, 55, F1M1, true),
+            slot(531, 20, 55, 55, F1M1, true),
+            slot(587, 10, 56, 56, F1M1, true),
+            slot(531, 10, 56, 56, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(531, 5, 58, 58, F1M1, true),
+            slot(531, 5, 58, 58, F1M1, true),
+            slot(531, 4, 58, 58, F1M1, true),
+            slot(584, 4, 58, 58, F1M1, false),
+            slot(531, 1, 58, 58, F1M1, true),
+            slot(584, 1, 58, 58, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "dragonspiral_tower_winter_exterior_northeast",
+        slots: &[
+            slot(531, 20, 55, 55, F1M1, true),
+            slot(531, 20, 55, 55, F1M1, true),
+            slot(587, 10, 56, 56, F1M1, true),
+            slot(531, 10, 56, 56, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(531, 5, 57, 57, F1M1, true),
+            slot(531, 5, 57, 57, F1M1, true),
+            slot(531, 4, 57, 57, F1M1, true),
+            slot(584, 4, 57, 57, F1M1, false),
+            slot(531, 1, 57, 57, F1M1, true),
+            slot(584, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "giant_chasm_exterior",
+        slots: &[
+            slot(531, 20, 44, 44, F1M1, true),
+            slot(531, 20, 44, 44, F1M1, true),
+            slot(531, 10, 45, 45, F1M1, true),
+            slot(531, 10, 45, 45, F1M1, true),
+            slot(531, 10, 46, 46, F1M1, true),
+            slot(531, 10, 46, 46, F1M1, true),
+            slot(531, 5, 47, 47, F1M1, true),
+            slot(36, 5, 47, 47, F3M1, true),
+            slot(584, 4, 47, 47, F1M1, false),
+            slot(465, 4, 47, 47, F1M1, false),
+            slot(584, 1, 47, 47, F1M1, false),
+            slot(465, 1, 47, 47, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "giant_chasm_crater_forest",
+        slots: &[
+            slot(531, 20, 44, 44, F1M1, true),
+            slot(531, 20, 44, 44, F1M1, true),
+            slot(531, 10, 45, 45, F1M1, true),
+            slot(531, 10, 45, 45, F1M1, true),
+            slot(531, 10, 46, 46, F1M1, true),
+            slot(531, 10, 46, 46, F1M1, true),
+            slot(531, 5, 47, 47, F1M1, true),
+            slot(473, 5, 47, 47, F1M1, false),
+            slot(36, 4, 47, 47, F3M1, true),
+            slot(376, 4, 47, 47, Genderless, true),
+            slot(36, 1, 47, 47, F3M1, true),
+            slot(376, 1, 47, 47, Genderless, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "p2_laboratory",
+        slots: &[
+            slot(531, 20, 56, 56, F1M1, true),
+            slot(531, 20, 56, 56, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(531, 10, 58, 58, F1M1, true),
+            slot(531, 10, 58, 58, F1M1, true),
+            slot(531, 5, 59, 59, F1M1, true),
+            slot(462, 5, 59, 59, Genderless, true),
+            slot(601, 4, 59, 59, Genderless, false),
+            slot(508, 4, 59, 59, F1M1, false),
+            slot(601, 1, 59, 59, Genderless, false),
+            slot(508, 1, 59, 59, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "floccesy_ranch",
+        slots: &[
+            slot(531, 20, 4, 4, F1M1, true),
+            slot(531, 20, 4, 4, F1M1, true),
+            slot(531, 10, 5, 5, F1M1, true),
+            slot(206, 10, 5, 5, F1M1, false),
+            slot(531, 10, 6, 6, F1M1, true),
+            slot(531, 10, 6, 6, F1M1, true),
+            slot(531, 5, 7, 7, F1M1, true),
+            slot(531, 5, 7, 7, F1M1, true),
+            slot(531, 4, 7, 7, F1M1, true),
+            slot(531, 4, 7, 7, F1M1, true),
+            slot(531, 1, 7, 7, F1M1, true),
+            slot(531, 1, 7, 7, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "virbank_complex",
+        slots: &[
+            slot(531, 20, 10, 10, F1M1, true),
+            slot(531, 20, 10, 10, F1M1, true),
+            slot(531, 10, 11, 11, F1M1, true),
+            slot(531, 10, 11, 11, F1M1, true),
+            slot(531, 10, 12, 12, F1M1, true),
+            slot(531, 10, 12, 12, F1M1, true),
+            slot(531, 5, 13, 13, F1M1, true),
+            slot(531, 5, 13, 13, F1M1, true),
+            slot(531, 4, 13, 13, F1M1, true),
+            slot(531, 4, 13, 13, F1M1, true),
+            slot(531, 1, 13, 13, F1M1, true),
+            slot(531, 1, 13, 13, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "reversal_mountain",
+        slots: &[
+            slot(531, 20, 31, 31, F1M1, true),
+            slot(531, 20, 31, 31, F1M1, true),
+            slot(531, 10, 32, 32, F1M1, true),
+            slot(531, 10, 32, 32, F1M1, true),
+            slot(531, 10, 33, 33, F1M1, true),
+            slot(531, 10, 33, 33, F1M1, true),
+            slot(531, 5, 34, 34, F1M1, true),
+            slot(531, 5, 34, 34, F1M1, true),
+            slot(531, 4, 34, 34, F1M1, true),
+            slot(531, 4, 34, 34, F1M1, true),
+            slot(531, 1, 34, 34, F1M1, true),
+            slot(531, 1, 34, 34, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "victory_road_1f",
+        slots: &[
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(531, 10, 48, 48, F1M1, true),
+            slot(531, 10, 48, 48, F1M1, true),
+            slot(206, 10, 49, 49, F1M1, false),
+            slot(531, 10, 49, 49, F1M1, true),
+            slot(531, 5, 50, 50, F1M1, true),
+            slot(531, 5, 50, 50, F1M1, true),
+            slot(547, 4, 50, 50, F1M1, false),
+            slot(407, 4, 50, 50, F1M1, true),
+            slot(547, 1, 50, 50, F1M1, false),
+            slot(407, 1, 50, 50, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "victory_road_2f",
+        slots: &[
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(531, 10, 48, 48, F1M1, true),
+            slot(531, 10, 48, 48, F1M1, true),
+            slot(206, 10, 49, 49, F1M1, false),
+            slot(531, 10, 49, 49, F1M1, true),
+            slot(531, 5, 50, 50, F1M1, true),
+            slot(531, 5, 50, 50, F1M1, true),
+            slot(531, 4, 50, 50, F1M1, true),
+            slot(521, 4, 50, 50, F1M1, false),
+            slot(531, 1, 50, 50, F1M1, true),
+            slot(521, 1, 50, 50, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "victory_road_3f_4f_5f",
+        slots: &[
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(531, 10, 48, 48, F1M1, true),
+            slot(531, 10, 48, 48, F1M1, true),
+            slot(531, 10, 49, 49, F1M1, true),
+            slot(531, 10, 49, 49, F1M1, true),
+            slot(531, 5, 50, 50, F1M1, true),
+            slot(531, 5, 50, 50, F1M1, true),
+            slot(531, 4, 50, 50, F1M1, true),
+            slot(539, 4, 50, 50, MaleOnly, true),
+            slot(531, 1, 50, 50, F1M1, true),
+            slot(539, 1, 50, 50, MaleOnly, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "nature_preserve",
+        slots: &[
+            slot(531, 20, 56, 56, F1M1, true),
+            slot(531, 20, 56, 56, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(206, 10, 57, 57, F1M1, false),
+            slot(531, 10, 58, 58, F1M1, true),
+            slot(531, 10, 58, 58, F1M1, true),
+            slot(531, 5, 59, 59, F1M1, true),
+            slot(531, 5, 59, 59, F1M1, true),
+            slot(275, 4, 59, 59, F1M1, false),
+            slot(531, 4, 59, 59, F1M1, true),
+            slot(275, 1, 59, 59, F1M1, false),
+            slot(531, 1, 59, 59, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "village_bridge",
+        slots: &[
+            slot(531, 20, 36, 36, F1M1, true),
+            slot(531, 20, 36, 36, F1M1, true),
+            slot(587, 10, 37, 37, F1M1, true),
+            slot(206, 10, 37, 37, F1M1, false),
+            slot(531, 10, 38, 38, F1M1, true),
+            slot(531, 10, 38, 38, F1M1, true),
+            slot(531, 5, 39, 39, F1M1, true),
+            slot(531, 5, 39, 39, F1M1, true),
+            slot(531, 4, 39, 39, F1M1, true),
+            slot(184, 4, 39, 39, F1M1, false),
+            slot(531, 1, 39, 39, F1M1, true),
+            slot(184, 1, 39, 39, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "route_1",
+        slots: &[
+            slot(531, 20, 56, 56, F1M1, true),
+            slot(531, 20, 56, 56, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(206, 10, 57, 57, F1M1, false),
+            slot(531, 10, 58, 58, F1M1, true),
+            slot(531, 10, 58, 58, F1M1, true),
+            slot(531, 5, 59, 59, F1M1, true),
+            slot(531, 5, 59, 59, F1M1, true),
+            slot(508, 4, 59, 59, F1M1, false),
+            slot(40, 4, 59, 59, F3M1, false),
+            slot(508, 1, 59, 59, F1M1, false),
+            slot(40, 1, 59, 59, F3M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "route_2",
+        slots: &[
+            slot(531, 20, 56, 56, F1M1, true),
+            slot(531, 20, 56, 56, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(206, 10, 57, 57, F1M1, false),
+            slot(531, 10, 58, 58, F1M1, true),
+            slot(531, 10, 58, 58, F1M1, true),
+            slot(531, 5, 59, 59, F1M1, true),
+            slot(463, 5, 59, 59, F1M1, true),
+            slot(508, 4, 59, 59, F1M1, false),
+            slot(40, 4, 59, 59, F3M1, false),
+            slot(508, 1, 59, 59, F1M1, false),
+            slot(40, 1, 59, 59, F3M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "route_3",
+        slots: &[
+            slot(531, 20, 55, 55, F1M1, true),
+            slot(531, 20, 55, 55, F1M1, true),
+            slot(531, 10, 56, 56, F1M1, true),
+            slot(531, 10, 56, 56, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(531, 5, 58, 58, F1M1, true),
+            slot(469, 5, 58, 58, F1M1, true),
+            slot(521, 4, 58, 58, F1M1, false),
+            slot(508, 4, 58, 58, F1M1, false),
+            slot(521, 1, 58, 58, F1M1, false),
+            slot(508, 1, 58, 58, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "route_5",
+        slots: &[
+            slot(531, 20, 21, 21, F1M1, true),
+            slot(531, 20, 21, 21, F1M1, true),
+            slot(587, 10, 22, 22, F1M1, true),
+            slot(531, 10, 22, 22, F1M1, true),
+            slot(531, 10, 23, 23, F1M1, true),
+            slot(531, 10, 23, 23, F1M1, true),
+            slot(531, 5, 24, 24, F1M1, true),
+            slot(531, 5, 24, 24, F1M1, true),
+            slot(573, 4, 24, 24, F3M1, true),
+            slot(531, 4, 24, 24, F1M1, true),
+            slot(573, 1, 24, 24, F3M1, true),
+            slot(531, 1, 24, 24, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "route_6_spring",
+        slots: &[
+            slot(531, 20, 23, 23, F1M1, true),
+            slot(531, 20, 24, 24, F1M1, true),
+            slot(587, 10, 24, 24, F1M1, true),
+            slot(587, 10, 25, 25, F1M1, true),
+            slot(531, 10, 25, 25, F1M1, true),
+            slot(206, 10, 25, 25, F1M1, false),
+            slot(184, 5, 26, 26, F1M1, false),
+            slot(351, 5, 26, 26, F1M1, true),
+            slot(521, 4, 26, 26, F1M1, false),
+            slot(542, 4, 26, 26, F1M1, true),
+            slot(521, 1, 26, 26, F1M1, false),
+            slot(542, 1, 26, 26, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "route_6_summer",
+        slots: &[
+            slot(531, 20, 23, 23, F1M1, true),
+            slot(531, 20, 24, 24, F1M1, true),
+            slot(587, 10, 24, 24, F1M1, true),
+            slot(587, 10, 25, 25, F1M1, true),
+            slot(531, 10, 25, 25, F1M1, true),
+            slot(206, 10, 25, 25, F1M1, false),
+            slot(184, 5, 26, 26, F1M1, false),
+            slot(351, 5, 26, 26, F1M1, true),
+            slot(521, 4, 26, 26, F1M1, false),
+            slot(542, 4, 26, 26, F1M1, true),
+            slot(521, 1, 26, 26, F1M1, false),
+            slot(542, 1, 26, 26, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "route_6_autumn",
+        slots: &[
+            slot(531, 20, 23, 23, F1M1, true),
+            slot(531, 20, 24, 24, F1M1, true),
+            slot(587, 10, 24, 24, F1M1, true),
+            slot(587, 10, 25, 25, F1M1, true),
+            slot(531, 10, 25, 25, F1M1, true),
+            slot(206, 10, 25, 25, F1M1, false),
+            slot(184, 5, 26, 26, F1M1, false),
+            slot(351, 5, 26, 26, F1M1, true),
+            slot(521, 4, 26, 26, F1M1, false),
+            slot(542, 4, 26, 26, F1M1, true),
+            slot(521, 1, 26, 26, F1M1, false),
+            slot(542, 1, 26, 26, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "route_6_winter",
+        slots: &[
+            slot(531, 20, 23, 23, F1M1, true),
+            slot(531, 20, 24, 24, F1M1, true),
+            slot(587, 10, 24, 24, F1M1, true),
+            slot(587, 10, 25, 25, F1M1, true),
+            slot(531, 10, 25, 25, F1M1, true),
+            slot(206, 10, 25, 25, F1M1, false),
+            slot(184, 5, 26, 26, F1M1, false),
+            slot(351, 5, 26, 26, F1M1, true),
+            slot(521, 4, 26, 26, F1M1, false),
+            slot(542, 4, 26, 26, F1M1, true),
+            slot(521, 1, 26, 26, F1M1, false),
+            slot(542, 1, 26, 26, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "route_7_spring",
+        slots: &[
+            slot(531, 20, 30, 30, F1M1, true),
+            slot(531, 20, 30, 30, F1M1, true),
+            slot(587, 10, 31, 31, F1M1, true),
+            slot(531, 10, 31, 31, F1M1, true),
+            slot(531, 10, 32, 32, F1M1, true),
+            slot(531, 10, 32, 32, F1M1, true),
+            slot(531, 5, 33, 33, F1M1, true),
+            slot(531, 5, 33, 33, F1M1, true),
+            slot(531, 4, 33, 33, F1M1, true),
+            slot(521, 4, 33, 33, F1M1, false),
+            slot(531, 1, 33, 33, F1M1, true),
+            slot(521, 1, 33, 33, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "route_7_summer",
+        slots: &[
+            slot(531, 20, 30, 30, F1M1, true),
+            slot(531, 20, 30, 30, F1M1, true),
+            slot(587, 10, 31, 31, F1M1, true),
+            slot(531, 10, 31, 31, F1M1, true),
+            slot(531, 10, 32, 32, F1M1, true),
+            slot(531, 10, 32, 32, F1M1, true),
+            slot(531, 5, 33, 33, F1M1, true),
+            slot(531, 5, 33, 33, F1M1, true),
+            slot(531, 4, 33, 33, F1M1, true),
+            slot(521, 4, 33, 33, F1M1, false),
+            slot(531, 1, 33, 33, F1M1, true),
+            slot(521, 1, 33, 33, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "route_7_autumn",
+        slots: &[
+            slot(531, 20, 30, 30, F1M1, true),
+            slot(531, 20, 30, 30, F1M1, true),
+            slot(587, 10, 31, 31, F1M1, true),
+            slot(531, 10, 31, 31, F1M1, true),
+            slot(531, 10, 32, 32, F1M1, true),
+            slot(531, 10, 32, 32, F1M1, true),
+            slot(531, 5, 33, 33, F1M1, true),
+            slot(531, 5, 33, 33, F1M1, true),
+            slot(531, 4, 33, 33, F1M1, true),
+            slot(521, 4, 33, 33, F1M1, false),
+            slot(531, 1, 33, 33, F1M1, true),
+            slot(521, 1, 33, 33, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "route_7_winter",
+        slots: &[
+            slot(531, 20, 30, 30, F1M1, true),
+            slot(531, 20, 30, 30, F1M1, true),
+            slot(587, 10, 31, 31, F1M1, true),
+            slot(531, 10, 31, 31, F1M1, true),
+            slot(531, 10, 32, 32, F1M1, true),
+            slot(531, 10, 32, 32, F1M1, true),
+            slot(531, 5, 33, 33, F1M1, true),
+            slot(531, 5, 33, 33, F1M1, true),
+            slot(531, 4, 33, 33, F1M1, true),
+            slot(521, 4, 33, 33, F1M1, false),
+            slot(531, 1, 33, 33, F1M1, true),
+            slot(521, 1, 33, 33, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "route_9",
+        slots: &[
+            slot(531, 20, 37, 37, F1M1, true),
+            slot(531, 20, 37, 37, F1M1, true),
+            slot(587, 10, 38, 38, F1M1, true),
+            slot(531, 10, 38, 38, F1M1, true),
+            slot(531, 10, 39, 39, F1M1, true),
+            slot(531, 10, 39, 39, F1M1, true),
+            slot(531, 5, 40, 40, F1M1, true),
+            slot(531, 5, 40, 40, F1M1, true),
+            slot(573, 4, 40, 40, F3M1, true),
+            slot(576, 4, 40, 40, F3M1, true),
+            slot(573, 1, 40, 40, F3M1, true),
+            slot(576, 1, 40, 40, F3M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "route_11",
+        slots: &[
+            slot(531, 20, 36, 36, F1M1, true),
+            slot(531, 20, 36, 36, F1M1, true),
+            slot(587, 10, 37, 37, F1M1, true),
+            slot(531, 10, 37, 37, F1M1, true),
+            slot(531, 10, 38, 38, F1M1, true),
+            slot(531, 10, 38, 38, F1M1, true),
+            slot(531, 5, 39, 39, F1M1, true),
+            slot(531, 5, 39, 39, F1M1, true),
+            slot(472, 4, 39, 39, F1M1, false),
+            slot(184, 4, 39, 39, F1M1, false),
+            slot(472, 1, 39, 39, F1M1, false),
+            slot(184, 1, 39, 39, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "route_12",
+        slots: &[
+            slot(531, 20, 35, 35, F1M1, true),
+            slot(531, 20, 35, 35, F1M1, true),
+            slot(587, 10, 36, 36, F1M1, true),
+            slot(206, 10, 36, 36, F1M1, false),
+            slot(531, 10, 37, 37, F1M1, true),
+            slot(531, 10, 37, 37, F1M1, true),
+            slot(521, 5, 38, 38, F1M1, false),
+            slot(542, 5, 38, 38, F1M1, true),
+            slot(416, 4, 38, 38, FemaleOnly, true),
+            slot(407, 4, 38, 38, F1M1, true),
+            slot(416, 1, 38, 38, FemaleOnly, true),
+            slot(407, 1, 38, 38, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "route_13",
+        slots: &[
+            slot(531, 20, 34, 34, F1M1, true),
+            slot(531, 20, 34, 34, F1M1, true),
+            slot(587, 10, 35, 35, F1M1, true),
+            slot(531, 10, 35, 35, F1M1, true),
+            slot(531, 10, 36, 36, F1M1, true),
+            slot(531, 10, 36, 36, F1M1, true),
+            slot(531, 5, 37, 37, F1M1, true),
+            slot(531, 5, 37, 37, F1M1, true),
+            slot(531, 4, 37, 37, F1M1, true),
+            slot(465, 4, 37, 37, F1M1, false),
+            slot(531, 1, 37, 37, F1M1, true),
+            slot(465, 1, 37, 37, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "route_14",
+        slots: &[
+            slot(531, 20, 33, 33, F1M1, true),
+            slot(531, 20, 33, 33, F1M1, true),
+            slot(587, 10, 34, 34, F1M1, true),
+            slot(531, 10, 34, 34, F1M1, true),
+            slot(531, 10, 35, 35, F1M1, true),
+            slot(531, 10, 35, 35, F1M1, true),
+            slot(531, 5, 36, 36, F1M1, true),
+            slot(531, 5, 36, 36, F1M1, true),
+            slot(531, 4, 36, 36, F1M1, true),
+            slot(531, 4, 36, 36, F1M1, true),
+            slot(531, 1, 36, 36, F1M1, true),
+            slot(531, 1, 36, 36, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "abundant_shrine",
+        slots: &[
+            slot(531, 20, 33, 33, F1M1, true),
+            slot(531, 20, 33, 33, F1M1, true),
+            slot(587, 10, 34, 34, F1M1, true),
+            slot(531, 10, 34, 34, F1M1, true),
+            slot(531, 10, 35, 35, F1M1, true),
+            slot(531, 10, 35, 35, F1M1, true),
+            slot(531, 5, 36, 36, F1M1, true),
+            slot(531, 5, 36, 36, F1M1, true),
+            slot(547, 4, 36, 36, F1M1, false),
+            slot(38, 4, 36, 36, F3M1, true),
+            slot(547, 1, 36, 36, F1M1, false),
+            slot(38, 1, 36, 36, F3M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "route_15",
+        slots: &[
+            slot(531, 20, 54, 54, F1M1, true),
+            slot(531, 20, 54, 54, F1M1, true),
+            slot(587, 10, 55, 55, F1M1, true),
+            slot(531, 10, 55, 55, F1M1, true),
+            slot(531, 10, 56, 56, F1M1, true),
+            slot(531, 10, 56, 56, F1M1, true),
+            slot(531, 5, 57, 57, F1M1, true),
+            slot(538, 5, 57, 57, MaleOnly, true),
+            slot(472, 4, 57, 57, F1M1, false),
+            slot(248, 4, 57, 57, F1M1, false),
+            slot(472, 1, 57, 57, F1M1, false),
+            slot(248, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "route_16",
+        slots: &[
+            slot(531, 20, 21, 21, F1M1, true),
+            slot(531, 20, 21, 21, F1M1, true),
+            slot(587, 10, 22, 22, F1M1, true),
+            slot(531, 10, 22, 22, F1M1, true),
+            slot(531, 10, 23, 23, F1M1, true),
+            slot(531, 10, 23, 23, F1M1, true),
+            slot(531, 5, 24, 24, F1M1, true),
+            slot(531, 5, 24, 24, F1M1, true),
+            slot(573, 4, 24, 24, F3M1, true),
+            slot(531, 4, 24, 24, F1M1, true),
+            slot(573, 1, 24, 24, F3M1, true),
+            slot(531, 1, 24, 24, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "lostlorn_forest",
+        slots: &[
+            slot(531, 20, 21, 21, F1M1, true),
+            slot(531, 20, 23, 23, F1M1, true),
+            slot(587, 10, 22, 22, F1M1, true),
+            slot(511, 10, 22, 22, F1M7, true),
+            slot(513, 10, 22, 22, F1M7, true),
+            slot(515, 10, 22, 22, F1M7, true),
+            slot(416, 5, 24, 24, FemaleOnly, true),
+            slot(407, 5, 24, 24, F1M1, true),
+            slot(542, 4, 24, 24, F1M1, true),
+            slot(547, 4, 24, 24, F1M1, false),
+            slot(542, 1, 24, 24, F1M1, true),
+            slot(547, 1, 24, 24, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "route_18",
+        slots: &[
+            slot(531, 20, 56, 56, F1M1, true),
+            slot(531, 20, 56, 56, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(206, 10, 57, 57, F1M1, false),
+            slot(531, 10, 58, 58, F1M1, true),
+            slot(531, 10, 58, 58, F1M1, true),
+            slot(531, 5, 59, 59, F1M1, true),
+            slot(531, 5, 59, 59, F1M1, true),
+            slot(531, 4, 59, 59, F1M1, true),
+            slot(538, 4, 59, 59, MaleOnly, true),
+            slot(531, 1, 59, 59, F1M1, true),
+            slot(538, 1, 59, 59, MaleOnly, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "route_19",
+        slots: &[
+            slot(504, 20, 2, 2, F1M1, false),
+            slot(504, 20, 2, 2, F1M1, false),
+            slot(504, 10, 3, 3, F1M1, false),
+            slot(504, 10, 3, 3, F1M1, false),
+            slot(504, 10, 4, 4, F1M1, false),
+            slot(504, 10, 4, 4, F1M1, false),
+            slot(504, 5, 4, 4, F1M1, false),
+            slot(504, 5, 4, 4, F1M1, false),
+            slot(504, 4, 4, 4, F1M1, false),
+            slot(504, 4, 4, 4, F1M1, false),
+            slot(504, 1, 4, 4, F1M1, false),
+            slot(504, 1, 4, 4, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "route_20_spring",
+        slots: &[
+            slot(531, 20, 2, 2, F1M1, true),
+            slot(531, 20, 2, 2, F1M1, true),
+            slot(531, 10, 3, 3, F1M1, true),
+            slot(206, 10, 3, 3, F1M1, false),
+            slot(531, 10, 4, 4, F1M1, true),
+            slot(531, 10, 4, 4, F1M1, true),
+            slot(531, 5, 4, 4, F1M1, true),
+            slot(531, 5, 4, 4, F1M1, true),
+            slot(531, 4, 4, 4, F1M1, true),
+            slot(531, 4, 4, 4, F1M1, true),
+            slot(531, 1, 4, 4, F1M1, true),
+            slot(531, 1, 4, 4, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "route_20_summer",
+        slots: &[
+            slot(531, 20, 2, 2, F1M1, true),
+            slot(531, 20, 2, 2, F1M1, true),
+            slot(531, 10, 3, 3, F1M1, true),
+            slot(206, 10, 3, 3, F1M1, false),
+            slot(531, 10, 4, 4, F1M1, true),
+            slot(531, 10, 4, 4, F1M1, true),
+            slot(531, 5, 4, 4, F1M1, true),
+            slot(531, 5, 4, 4, F1M1, true),
+            slot(531, 4, 4, 4, F1M1, true),
+            slot(531, 4, 4, 4, F1M1, true),
+            slot(531, 1, 4, 4, F1M1, true),
+            slot(531, 1, 4, 4, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "route_20_autumn",
+        slots: &[
+            slot(531, 20, 2, 2, F1M1, true),
+            slot(531, 20, 2, 2, F1M1, true),
+            slot(531, 10, 3, 3, F1M1, true),
+            slot(206, 10, 3, 3, F1M1, false),
+            slot(531, 10, 4, 4, F1M1, true),
+            slot(531, 10, 4, 4, F1M1, true),
+            slot(531, 5, 4, 4, F1M1, true),
+            slot(531, 5, 4, 4, F1M1, true),
+            slot(531, 4, 4, 4, F1M1, true),
+            slot(531, 4, 4, 4, F1M1, true),
+            slot(531, 1, 4, 4, F1M1, true),
+            slot(531, 1, 4, 4, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "route_20_winter",
+        slots: &[
+            slot(531, 20, 2, 2, F1M1, true),
+            slot(531, 20, 2, 2, F1M1, true),
+            slot(531, 10, 3, 3, F1M1, true),
+            slot(206, 10, 3, 3, F1M1, false),
+            slot(531, 10, 4, 4, F1M1, true),
+            slot(531, 10, 4, 4, F1M1, true),
+            slot(531, 5, 4, 4, F1M1, true),
+            slot(531, 5, 4, 4, F1M1, true),
+            slot(531, 4, 4, 4, F1M1, true),
+            slot(531, 4, 4, 4, F1M1, true),
+            slot(531, 1, 4, 4, F1M1, true),
+            slot(531, 1, 4, 4, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "route_22",
+        slots: &[
+            slot(531, 20, 39, 39, F1M1, true),
+            slot(531, 20, 39, 39, F1M1, true),
+            slot(587, 10, 40, 40, F1M1, true),
+            slot(531, 10, 40, 40, F1M1, true),
+            slot(531, 10, 41, 41, F1M1, true),
+            slot(531, 10, 41, 41, F1M1, true),
+            slot(531, 5, 42, 42, F1M1, true),
+            slot(531, 5, 42, 42, F1M1, true),
+            slot(531, 4, 42, 42, F1M1, true),
+            slot(184, 4, 42, 42, F1M1, false),
+            slot(531, 1, 42, 42, F1M1, true),
+            slot(184, 1, 42, 42, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: ShakingGrass,
+        location_key: "route_23",
+        slots: &[
+            slot(531, 20, 48, 48, F1M1, true),
+            slot(531, 20, 48, 48, F1M1, true),
+            slot(587, 10, 49, 49, F1M1, true),
+            slot(531, 10, 49, 49, F1M1, true),
+            slot(531, 10, 50, 50, F1M1, true),
+            slot(531, 10, 50, 50, F1M1, true),
+            slot(531, 5, 51, 51, F1M1, true),
+            slot(531, 5, 51, 51, F1M1, true),
+            slot(472, 4, 51, 51, F1M1, false),
+            slot(538, 4, 51, 51, MaleOnly, true),
+            slot(472, 1, 51, 51, F1M1, false),
+            slot(538, 1, 51, 51, MaleOnly, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: DustCloud,
+        location_key: "chargestone_cave_1f",
+        slots: &[
+            slot(529, 20, 25, 25, F1M1, false),
+            slot(529, 20, 25, 25, F1M1, false),
+            slot(529, 10, 26, 26, F1M1, false),
+            slot(529, 10, 26, 26, F1M1, false),
+            slot(529, 10, 27, 27, F1M1, false),
+            slot(529, 10, 27, 27, F1M1, false),
+            slot(529, 5, 28, 28, F1M1, false),
+            slot(529, 5, 28, 28, F1M1, false),
+            slot(529, 4, 28, 28, F1M1, false),
+            slot(529, 4, 28, 28, F1M1, false),
+            slot(529, 1, 28, 28, F1M1, false),
+            slot(529, 1, 28, 28, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: DustCloud,
+        location_key: "chargestone_cave_b1f",
+        slots: &[
+            slot(529, 20, 28, 28, F1M1, false),
+            slot(529, 20, 28, 28, F1M1, false),
+            slot(529, 10, 29, 29, F1M1, false),
+            slot(529, 10, 29, 29, F1M1, false),
+            slot(529, 10, 30, 30, F1M1, false),
+            slot(529, 10, 30, 30, F1M1, false),
+            slot(529, 5, 31, 31, F1M1, false),
+            slot(529, 5, 31, 31, F1M1, false),
+            slot(529, 4, 31, 31, F1M1, false),
+            slot(529, 4, 31, 31, F1M1, false),
+            slot(529, 1, 31, 31, F1M1, false),
+            slot(529, 1, 31, 31, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: DustCloud,
+        location_key: "twist_mountain_spring",
+        slots: &[
+            slot(530, 20, 54, 54, F1M1, false),
+            slot(95, 20, 54, 54, F1M1, false),
+            slot(530, 10, 55, 55, F1M1, false),
+            slot(530, 10, 55, 55, F1M1, false),
+            slot(530, 10, 56, 56, F1M1, false),
+            slot(530, 10, 56, 56, F1M1, false),
+            slot(530, 5, 57, 57, F1M1, false),
+            slot(530, 5, 57, 57, F1M1, false),
+            slot(530, 4, 57, 57, F1M1, false),
+            slot(208, 4, 57, 57, F1M1, true),
+            slot(530, 1, 57, 57, F1M1, false),
+            slot(208, 1, 57, 57, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: DustCloud,
+        location_key: "twist_mountain_summer",
+        slots: &[
+            slot(530, 20, 54, 54, F1M1, false),
+            slot(95, 20, 54, 54, F1M1, false),
+            slot(530, 10, 55, 55, F1M1, false),
+            slot(530, 10, 55, 55, F1M1, false),
+            slot(530, 10, 56, 56, F1M1, false),
+            slot(530, 10, 56, 56, F1M1, false),
+            slot(530, 5, 57, 57, F1M1, false),
+            slot(530, 5, 57, 57, F1M1, false),
+            slot(530, 4, 57, 57, F1M1, false),
+            slot(208, 4, 57, 57, F1M1, true),
+            slot(530, 1, 57, 57, F1M1, false),
+            slot(208, 1, 57, 57, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: DustCloud,
+        location_key: "twist_mountain_autumn",
+        slots: &[
+            slot(530, 20, 54, 54, F1M1, false),
+            slot(95, 20, 54, 54, F1M1, false),
+            slot(530, 10, 55, 55, F1M1, false),
+            slot(530, 10, 55, 55, F1M1, false),
+            slot(530, 10, 56, 56, F1M1, false),
+            slot(530, 10, 56, 56, F1M1, false),
+            slot(530, 5, 57, 57, F1M1, false),
+            slot(530, 5, 57, 57, F1M1, false),
+            slot(530, 4, 57, 57, F1M1, false),
+            slot(208, 4, 57, 57, F1M1, true),
+            slot(530, 1, 57, 57, F1M1, false),
+            slot(208, 1, 57, 57, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: DustCloud,
+        location_key: "twist_mountain_winter",
+        slots: &[
+            slot(530, 20, 54, 54, F1M1, false),
+            slot(95, 20, 54, 54, F1M1, false),
+            slot(530, 10, 55, 55, F1M1, false),
+            slot(530, 10, 55, 55, F1M1, false),
+            slot(530, 10, 56, 56, F1M1, false),
+            slot(530, 10, 56, 56, F1M1, false),
+            slot(530, 5, 57, 57, F1M1, false),
+            slot(530, 5, 57, 57, F1M1, false),
+            slot(530, 4, 57, 57, F1M1, false),
+            slot(208, 4, 57, 57, F1M1, true),
+            slot(530, 1, 57, 57, F1M1, false),
+            slot(208, 1, 57, 57, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: DustCloud,
+        location_key: "victory_road_1f",
+        slots: &[
+            slot(530, 20, 47, 47, F1M1, false),
+            slot(530, 20, 47, 47, F1M1, false),
+            slot(530, 10, 48, 48, F1M1, false),
+            slot(530, 10, 48, 48, F1M1, false),
+            slot(530, 10, 49, 49, F1M1, false),
+            slot(530, 10, 49, 49, F1M1, false),
+            slot(530, 5, 50, 50, F1M1, false),
+            slot(530, 5, 50, 50, F1M1, false),
+            slot(530, 4, 50, 50, F1M1, false),
+            slot(530, 4, 50, 50, F1M1, false),
+            slot(530, 1, 50, 50, F1M1, false),
+            slot(530, 1, 50, 50, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: DustCloud,
+        location_key: "victory_road_2f",
+        slots: &[
+            slot(530, 20, 41, 41, F1M1, false),
+            slot(95, 20, 41, 41, F1M1, false),
+            slot(530, 10, 42, 42, F1M1, false),
+            slot(530, 10, 42, 42, F1M1, false),
+            slot(530, 10, 43, 43, F1M1, false),
+            slot(530, 10, 43, 43, F1M1, false),
+            slot(530, 5, 44, 44, F1M1, false),
+            slot(530, 5, 44, 44, F1M1, false),
+            slot(530, 4, 44, 44, F1M1, false),
+            slot(530, 4, 44, 44, F1M1, false),
+            slot(530, 1, 44, 44, F1M1, false),
+            slot(530, 1, 44, 44, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: DustCloud,
+        location_key: "victory_road_3f_4f_5f",
+        slots: &[
+            slot(530, 20, 47, 47, F1M1, false),
+            slot(95, 20, 47, 47, F1M1, false),
+            slot(530, 10, 48, 48, F1M1, false),
+            slot(530, 10, 48, 48, F1M1, false),
+            slot(530, 10, 49, 49, F1M1, false),
+            slot(530, 10, 49, 49, F1M1, false),
+            slot(530, 5, 50, 50, F1M1, false),
+            slot(530, 5, 50, 50, F1M1, false),
+            slot(530, 4, 50, 50, F1M1, false),
+            slot(530, 4, 50, 50, F1M1, false),
+            slot(530, 1, 50, 50, F1M1, false),
+            slot(530, 1, 50, 50, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: DustCloud,
+        location_key: "giant_chasm",
+        slots: &[
+            slot(530, 20, 44, 44, F1M1, false),
+            slot(530, 20, 44, 44, F1M1, false),
+            slot(530, 10, 45, 45, F1M1, false),
+            slot(530, 10, 45, 45, F1M1, false),
+            slot(530, 10, 46, 46, F1M1, false),
+            slot(530, 10, 46, 46, F1M1, false),
+            slot(530, 5, 47, 47, F1M1, false),
+            slot(530, 5, 47, 47, F1M1, false),
+            slot(530, 4, 47, 47, F1M1, false),
+            slot(530, 4, 47, 47, F1M1, false),
+            slot(530, 1, 47, 47, F1M1, false),
+            slot(530, 1, 47, 47, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: DustCloud,
+        location_key: "reversal_mountain",
+        slots: &[
+            slot(530, 20, 32, 32, F1M1, false),
+            slot(530, 20, 32, 32, F1M1, false),
+            slot(530, 10, 33, 33, F1M1, false),
+            slot(530, 10, 33, 33, F1M1, false),
+            slot(530, 10, 34, 34, F1M1, false),
+            slot(530, 10, 34, 34, F1M1, false),
+            slot(530, 5, 35, 35, F1M1, false),
+            slot(530, 5, 35, 35, F1M1, false),
+            slot(530, 4, 35, 35, F1M1, false),
+            slot(530, 4, 35, 35, F1M1, false),
+            slot(530, 1, 35, 35, F1M1, false),
+            slot(530, 1, 35, 35, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: DustCloud,
+        location_key: "relic_passage_south",
+        slots: &[
+            slot(529, 20, 16, 16, F1M1, false),
+            slot(95, 20, 16, 16, F1M1, false),
+            slot(529, 10, 17, 17, F1M1, false),
+            slot(529, 10, 17, 17, F1M1, false),
+            slot(529, 10, 18, 18, F1M1, false),
+            slot(529, 10, 18, 18, F1M1, false),
+            slot(529, 5, 19, 19, F1M1, false),
+            slot(529, 5, 19, 19, F1M1, false),
+            slot(529, 4, 19, 19, F1M1, false),
+            slot(529, 4, 19, 19, F1M1, false),
+            slot(529, 1, 19, 19, F1M1, false),
+            slot(529, 1, 19, 19, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: DustCloud,
+        location_key: "relic_passage_north",
+        slots: &[
+            slot(529, 20, 27, 27, F1M1, false),
+            slot(95, 20, 27, 27, F1M1, false),
+            slot(529, 10, 28, 28, F1M1, false),
+            slot(529, 10, 28, 28, F1M1, false),
+            slot(529, 10, 29, 29, F1M1, false),
+            slot(529, 10, 29, 29, F1M1, false),
+            slot(529, 5, 30, 30, F1M1, false),
+            slot(529, 5, 30, 30, F1M1, false),
+            slot(529, 4, 30, 30, F1M1, false),
+            slot(529, 4, 30, 30, F1M1, false),
+            slot(529, 1, 30, 30, F1M1, false),
+            slot(529, 1, 30, 30, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: DustCloud,
+        location_key: "clay_tunnel",
+        slots: &[
+            slot(530, 20, 54, 54, F1M1, false),
+            slot(95, 20, 54, 54, F1M1, false),
+            slot(530, 10, 55, 55, F1M1, false),
+            slot(530, 10, 55, 55, F1M1, false),
+            slot(530, 10, 56, 56, F1M1, false),
+            slot(530, 10, 56, 56, F1M1, false),
+            slot(530, 5, 57, 57, F1M1, false),
+            slot(530, 5, 57, 57, F1M1, false),
+            slot(530, 4, 57, 57, F1M1, false),
+            slot(208, 4, 57, 57, F1M1, true),
+            slot(530, 1, 57, 57, F1M1, false),
+            slot(208, 1, 57, 57, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: DustCloud,
+        location_key: "underground_ruins",
+        slots: &[
+            slot(530, 20, 54, 54, F1M1, false),
+            slot(95, 20, 54, 54, F1M1, false),
+            slot(530, 10, 55, 55, F1M1, false),
+            slot(530, 10, 55, 55, F1M1, false),
+            slot(530, 10, 56, 56, F1M1, false),
+            slot(530, 10, 56, 56, F1M1, false),
+            slot(530, 5, 57, 57, F1M1, false),
+            slot(530, 5, 57, 57, F1M1, false),
+            slot(530, 4, 57, 57, F1M1, false),
+            slot(208, 4, 57, 57, F1M1, true),
+            slot(530, 1, 57, 57, F1M1, false),
+            slot(208, 1, 57, 57, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: DustCloud,
+        location_key: "rocky_mountain_chamber",
+        slots: &[
+            slot(530, 20, 54, 54, F1M1, false),
+            slot(95, 20, 54, 54, F1M1, false),
+            slot(530, 10, 55, 55, F1M1, false),
+            slot(530, 10, 55, 55, F1M1, false),
+            slot(530, 10, 56, 56, F1M1, false),
+            slot(530, 10, 56, 56, F1M1, false),
+            slot(530, 5, 57, 57, F1M1, false),
+            slot(530, 5, 57, 57, F1M1, false),
+            slot(530, 4, 57, 57, F1M1, false),
+            slot(208, 4, 57, 57, F1M1, true),
+            slot(530, 1, 57, 57, F1M1, false),
+            slot(208, 1, 57, 57, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: DustCloud,
+        location_key: "iceberg_chamber",
+        slots: &[
+            slot(530, 20, 54, 54, F1M1, false),
+            slot(95, 20, 54, 54, F1M1, false),
+            slot(530, 10, 55, 55, F1M1, false),
+            slot(530, 10, 55, 55, F1M1, false),
+            slot(530, 10, 56, 56, F1M1, false),
+            slot(530, 10, 56, 56, F1M1, false),
+            slot(530, 5, 57, 57, F1M1, false),
+            slot(530, 5, 57, 57, F1M1, false),
+            slot(530, 4, 57, 57, F1M1, false),
+            slot(208, 4, 57, 57, F1M1, true),
+            slot(530, 1, 57, 57, F1M1, false),
+            slot(208, 1, 57, 57, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: DustCloud,
+        location_key: "iron_chamber",
+        slots: &[
+            slot(530, 20, 54, 54, F1M1, false),
+            slot(95, 20, 54, 54, F1M1, false),
+            slot(530, 10, 55, 55, F1M1, false),
+            slot(530, 10, 55, 55, F1M1, false),
+            slot(530, 10, 56, 56, F1M1, false),
+            slot(530, 10, 56, 56, F1M1, false),
+            slot(530, 5, 57, 57, F1M1, false),
+            slot(530, 5, 57, 57, F1M1, false),
+            slot(530, 4, 57, 57, F1M1, false),
+            slot(208, 4, 57, 57, F1M1, true),
+            slot(530, 1, 57, 57, F1M1, false),
+            slot(208, 1, 57, 57, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: DustCloud,
+        location_key: "seaside_cave_1f",
+        slots: &[
+            slot(530, 20, 34, 34, F1M1, false),
+            slot(530, 20, 34, 34, F1M1, false),
+            slot(530, 10, 35, 35, F1M1, false),
+            slot(530, 10, 35, 35, F1M1, false),
+            slot(530, 10, 36, 36, F1M1, false),
+            slot(530, 10, 36, 36, F1M1, false),
+            slot(530, 5, 37, 37, F1M1, false),
+            slot(530, 5, 37, 37, F1M1, false),
+            slot(530, 4, 37, 37, F1M1, false),
+            slot(530, 4, 37, 37, F1M1, false),
+            slot(530, 1, 37, 37, F1M1, false),
+            slot(530, 1, 37, 37, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: DustCloud,
+        location_key: "seaside_cave_b1f",
+        slots: &[
+            slot(530, 20, 39, 39, F1M1, false),
+            slot(530, 20, 39, 39, F1M1, false),
+            slot(530, 10, 40, 40, F1M1, false),
+            slot(530, 10, 40, 40, F1M1, false),
+            slot(530, 10, 41, 41, F1M1, false),
+            slot(530, 10, 41, 41, F1M1, false),
+            slot(530, 5, 42, 42, F1M1, false),
+            slot(530, 5, 42, 42, F1M1, false),
+            slot(530, 4, 42, 42, F1M1, false),
+            slot(530, 4, 42, 42, F1M1, false),
+            slot(530, 1, 42, 42, F1M1, false),
+            slot(530, 1, 42, 42, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: DustCloud,
+        location_key: "wellspring_cave",
+        slots: &[
+            slot(530, 20, 55, 55, F1M1, false),
+            slot(530, 20, 55, 55, F1M1, false),
+            slot(530, 10, 56, 56, F1M1, false),
+            slot(530, 10, 56, 56, F1M1, false),
+            slot(530, 10, 57, 57, F1M1, false),
+            slot(530, 10, 57, 57, F1M1, false),
+            slot(530, 5, 58, 58, F1M1, false),
+            slot(530, 5, 58, 58, F1M1, false),
+            slot(530, 4, 58, 58, F1M1, false),
+            slot(530, 4, 58, 58, F1M1, false),
+            slot(530, 1, 58, 58, F1M1, false),
+            slot(530, 1, 58, 58, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: DustCloud,
+        location_key: "mistralton_cave",
+        slots: &[
+            slot(529, 20, 27, 27, F1M1, false),
+            slot(529, 20, 27, 27, F1M1, false),
+            slot(529, 10, 28, 28, F1M1, false),
+            slot(529, 10, 28, 28, F1M1, false),
+            slot(529, 10, 29, 29, F1M1, false),
+            slot(529, 10, 29, 29, F1M1, false),
+            slot(529, 5, 30, 30, F1M1, false),
+            slot(529, 5, 30, 30, F1M1, false),
+            slot(529, 4, 30, 30, F1M1, false),
+            slot(529, 4, 30, 30, F1M1, false),
+            slot(529, 1, 30, 30, F1M1, false),
+            slot(529, 1, 30, 30, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: DustCloud,
+        location_key: "guidance_chamber",
+        slots: &[
+            slot(529, 20, 27, 27, F1M1, false),
+            slot(529, 20, 27, 27, F1M1, false),
+            slot(529, 10, 28, 28, F1M1, false),
+            slot(529, 10, 28, 28, F1M1, false),
+            slot(529, 10, 29, 29, F1M1, false),
+            slot(529, 10, 29, 29, F1M1, false),
+            slot(529, 5, 30, 30, F1M1, false),
+            slot(529, 5, 30, 30, F1M1, false),
+            slot(529, 4, 30, 30, F1M1, false),
+            slot(529, 4, 30, 30, F1M1, false),
+            slot(529, 1, 30, 30, F1M1, false),
+            slot(529, 1, 30, 30, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: PokemonShadow,
+        location_key: "driftveil_drawbridge",
+        slots: &[
+            slot(580, 20, 23, 23, F1M1, false),
+            slot(580, 20, 23, 23, F1M1, false),
+            slot(580, 10, 24, 24, F1M1, false),
+            slot(580, 10, 24, 24, F1M1, false),
+            slot(580, 10, 25, 25, F1M1, false),
+            slot(580, 10, 25, 25, F1M1, false),
+            slot(580, 5, 26, 26, F1M1, false),
+            slot(580, 5, 25, 25, F1M1, false),
+            slot(580, 4, 26, 26, F1M1, false),
+            slot(580, 4, 26, 26, F1M1, false),
+            slot(580, 1, 26, 26, F1M1, false),
+            slot(580, 1, 26, 26, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: PokemonShadow,
+        location_key: "marvelous_bridge",
+        slots: &[
+            slot(581, 20, 54, 54, F1M1, false),
+            slot(581, 20, 54, 54, F1M1, false),
+            slot(581, 10, 55, 55, F1M1, false),
+            slot(581, 10, 55, 55, F1M1, false),
+            slot(581, 10, 56, 56, F1M1, false),
+            slot(581, 10, 56, 56, F1M1, false),
+            slot(581, 5, 57, 57, F1M1, false),
+            slot(581, 5, 57, 57, F1M1, false),
+            slot(581, 4, 57, 57, F1M1, false),
+            slot(581, 4, 57, 57, F1M1, false),
+            slot(581, 1, 57, 57, F1M1, false),
+            slot(581, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "striaton_city",
+        slots: &[
+            slot(550, 60, 45, 60, F1M1, true),
+            slot(341, 30, 45, 60, F1M1, false),
+            slot(550, 5, 50, 60, F1M1, true),
+            slot(550, 4, 50, 60, F1M1, true),
+            slot(550, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "icirrus_city_spring",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(618, 5, 60, 60, F1M1, true),
+            slot(618, 4, 60, 60, F1M1, true),
+            slot(618, 1, 60, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "icirrus_city_summer",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(618, 5, 60, 60, F1M1, true),
+            slot(618, 4, 60, 60, F1M1, true),
+            slot(618, 1, 60, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "icirrus_city_autumn",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(618, 5, 60, 60, F1M1, true),
+            slot(618, 4, 60, 60, F1M1, true),
+            slot(618, 1, 60, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "icirrus_city_winter",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(618, 5, 50, 60, F1M1, true),
+            slot(618, 4, 50, 60, F1M1, true),
+            slot(618, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "aspertia_city",
+        slots: &[
+            slot(550, 60, 5, 15, F1M1, true),
+            slot(550, 30, 5, 15, F1M1, true),
+            slot(550, 5, 5, 15, F1M1, true),
+            slot(550, 4, 5, 15, F1M1, true),
+            slot(550, 1, 5, 15, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "virbank_city",
+        slots: &[
+            slot(592, 60, 5, 15, F1M1, false),
+            slot(592, 30, 5, 15, F1M1, false),
+            slot(592, 5, 5, 15, F1M1, false),
+            slot(592, 4, 5, 15, F1M1, false),
+            slot(592, 1, 5, 15, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "humilau_city",
+        slots: &[
+            slot(592, 60, 30, 45, F1M1, false),
+            slot(120, 30, 30, 45, Genderless, true),
+            slot(550, 5, 35, 45, F1M1, true),
+            slot(592, 4, 35, 45, F1M1, false),
+            slot(592, 1, 35, 45, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "pinwheel_forest",
+        slots: &[
+            slot(550, 60, 45, 60, F1M1, true),
+            slot(183, 30, 45, 60, F1M1, false),
+            slot(550, 5, 50, 60, F1M1, true),
+            slot(550, 4, 50, 60, F1M1, true),
+            slot(550, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "dragonspiral_tower_spring",
+        slots: &[
+            slot(550, 60, 45, 60, F1M1, true),
+            slot(550, 30, 45, 60, F1M1, true),
+            slot(550, 5, 50, 60, F1M1, true),
+            slot(550, 4, 50, 60, F1M1, true),
+            slot(550, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "dragonspiral_tower_summer",
+        slots: &[
+            slot(550, 60, 45, 60, F1M1, true),
+            slot(550, 30, 45, 60, F1M1, true),
+            slot(550, 5, 50, 60, F1M1, true),
+            slot(550, 4, 50, 60, F1M1, true),
+            slot(550, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "dragonspiral_tower_autumn",
+        slots: &[
+            slot(550, 60, 45, 60, F1M1, true),
+            slot(550, 30, 45, 60, F1M1, true),
+            slot(550, 5, 50, 60, F1M1, true),
+            slot(550, 4, 50, 60, F1M1, true),
+            slot(550, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "dragonspiral_tower_winter",
+        slots: &[
+            slot(550, 60, 45, 60, F1M1, true),
+            slot(550, 30, 45, 60, F1M1, true),
+            slot(550, 5, 50, 60, F1M1, true),
+            slot(550, 4, 50, 60, F1M1, true),
+            slot(550, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "giant_chasm",
+        slots: &[
+            slot(550, 60, 35, 50, F1M1, true),
+            slot(86, 30, 35, 50, F1M1, false),
+            slot(550, 5, 40, 50, F1M1, true),
+            slot(550, 4, 40, 50, F1M1, true),
+            slot(550, 1, 40, 50, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "castelia_sewers",
+        slots: &[
+            slot(88, 60, 5, 15, F1M1, true),
+            slot(88, 30, 5, 15, F1M1, true),
+            slot(88, 5, 5, 15, F1M1, true),
+            slot(88, 4, 5, 15, F1M1, true),
+            slot(88, 1, 5, 15, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "p2_laboratory",
+        slots: &[
+            slot(592, 60, 45, 60, F1M1, false),
+            slot(592, 30, 45, 60, F1M1, false),
+            slot(592, 5, 50, 60, F1M1, false),
+            slot(592, 4, 50, 60, F1M1, false),
+            slot(592, 1, 50, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "undella_bay_spring",
+        slots: &[
+            slot(592, 60, 25, 40, F1M1, false),
+            slot(458, 30, 25, 40, F1M1, false),
+            slot(223, 5, 30, 40, F1M1, false),
+            slot(592, 4, 30, 40, F1M1, false),
+            slot(592, 1, 30, 40, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "undella_bay_summer",
+        slots: &[
+            slot(592, 60, 25, 40, F1M1, false),
+            slot(458, 30, 25, 40, F1M1, false),
+            slot(223, 5, 30, 40, F1M1, false),
+            slot(592, 4, 30, 40, F1M1, false),
+            slot(592, 1, 30, 40, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "undella_bay_autumn",
+        slots: &[
+            slot(592, 60, 25, 40, F1M1, false),
+            slot(458, 30, 25, 40, F1M1, false),
+            slot(223, 5, 30, 40, F1M1, false),
+            slot(592, 4, 30, 40, F1M1, false),
+            slot(592, 1, 30, 40, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "undella_bay_winter",
+        slots: &[
+            slot(592, 60, 25, 40, F1M1, false),
+            slot(363, 30, 25, 40, F1M1, false),
+            slot(223, 5, 30, 40, F1M1, false),
+            slot(592, 4, 30, 40, F1M1, false),
+            slot(592, 1, 30, 40, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "floccesy_ranch",
+        slots: &[
+            slot(550, 60, 5, 15, F1M1, true),
+            slot(298, 30, 5, 15, F3M1, false),
+            slot(550, 5, 5, 15, F1M1, true),
+            slot(550, 4, 5, 15, F1M1, true),
+            slot(550, 1, 5, 15, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "virbank_complex",
+        slots: &[
+            slot(592, 60, 5, 15, F1M1, false),
+            slot(592, 30, 5, 15, F1M1, false),
+            slot(592, 5, 5, 15, F1M1, false),
+            slot(592, 4, 5, 15, F1M1, false),
+            slot(592, 1, 5, 15, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "victory_road",
+        slots: &[
+            slot(550, 60, 35, 50, F1M1, true),
+            slot(183, 30, 35, 50, F1M1, false),
+            slot(550, 5, 40, 50, F1M1, true),
+            slot(550, 4, 40, 50, F1M1, true),
+            slot(550, 1, 40, 50, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "relic_passage",
+        slots: &[
+            slot(550, 60, 10, 30, F1M1, true),
+            slot(183, 30, 10, 30, F1M1, false),
+            slot(550, 5, 10, 30, F1M1, true),
+            slot(550, 4, 10, 30, F1M1, true),
+            slot(550, 1, 10, 30, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "clay_tunnel",
+        slots: &[
+            slot(550, 60, 45, 60, F1M1, true),
+            slot(550, 30, 45, 60, F1M1, true),
+            slot(550, 5, 50, 60, F1M1, true),
+            slot(550, 4, 50, 60, F1M1, true),
+            slot(550, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "seaside_cave",
+        slots: &[
+            slot(592, 60, 25, 40, F1M1, false),
+            slot(86, 30, 25, 40, F1M1, false),
+            slot(592, 5, 30, 40, F1M1, false),
+            slot(592, 4, 30, 40, F1M1, false),
+            slot(592, 1, 30, 40, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "nature_preserve",
+        slots: &[
+            slot(550, 60, 45, 60, F1M1, true),
+            slot(418, 30, 45, 60, F1M1, false),
+            slot(550, 5, 50, 60, F1M1, true),
+            slot(550, 4, 50, 60, F1M1, true),
+            slot(550, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "village_bridge",
+        slots: &[
+            slot(550, 60, 25, 40, F1M1, true),
+            slot(183, 30, 25, 40, F1M1, false),
+            slot(550, 5, 30, 40, F1M1, true),
+            slot(550, 4, 30, 40, F1M1, true),
+            slot(550, 1, 30, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "route_1",
+        slots: &[
+            slot(550, 60, 45, 60, F1M1, true),
+            slot(550, 30, 45, 60, F1M1, true),
+            slot(550, 5, 50, 60, F1M1, true),
+            slot(550, 4, 50, 60, F1M1, true),
+            slot(550, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "route_3",
+        slots: &[
+            slot(550, 60, 45, 60, F1M1, true),
+            slot(341, 30, 45, 60, F1M1, false),
+            slot(550, 5, 50, 60, F1M1, true),
+            slot(550, 4, 50, 60, F1M1, true),
+            slot(550, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "wellspring_cave",
+        slots: &[
+            slot(550, 60, 45, 60, F1M1, true),
+            slot(550, 30, 45, 60, F1M1, true),
+            slot(550, 5, 50, 60, F1M1, true),
+            slot(550, 4, 50, 60, F1M1, true),
+            slot(550, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "route_4",
+        slots: &[
+            slot(592, 60, 5, 15, F1M1, false),
+            slot(592, 30, 5, 15, F1M1, false),
+            slot(592, 5, 5, 15, F1M1, false),
+            slot(592, 4, 5, 15, F1M1, false),
+            slot(592, 1, 5, 15, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "route_6_spring",
+        slots: &[
+            slot(550, 60, 10, 25, F1M1, true),
+            slot(183, 30, 10, 25, F1M1, false),
+            slot(550, 5, 10, 25, F1M1, true),
+            slot(550, 4, 10, 25, F1M1, true),
+            slot(550, 1, 10, 25, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "route_6_summer",
+        slots: &[
+            slot(550, 60, 10, 25, F1M1, true),
+            slot(183, 30, 10, 25, F1M1, false),
+            slot(550, 5, 10, 25, F1M1, true),
+            slot(550, 4, 10, 25, F1M1, true),
+            slot(550, 1, 10, 25, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "route_6_autumn",
+        slots: &[
+            slot(550, 60, 10, 25, F1M1, true),
+            slot(183, 30, 10, 25, F1M1, false),
+            slot(550, 5, 10, 25, F1M1, true),
+            slot(550, 4, 10, 25, F1M1, true),
+            slot(550, 1, 10, 25, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "route_6_winter",
+        slots: &[
+            slot(550, 60, 10, 25, F1M1, true),
+            slot(183, 30, 10, 25, F1M1, false),
+            slot(550, 5, 10, 25, F1M1, true),
+            slot(550, 4, 10, 25, F1M1, true),
+            slot(550, 1, 10, 25, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "route_8_spring",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(536, 30, 45, 60, F1M1, true),
+            slot(618, 5, 60, 60, F1M1, true),
+            slot(618, 4, 60, 60, F1M1, true),
+            slot(618, 1, 60, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "route_8_summer",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(536, 30, 45, 60, F1M1, true),
+            slot(618, 5, 50, 60, F1M1, true),
+            slot(618, 4, 50, 60, F1M1, true),
+            slot(618, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "route_8_autumn",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(536, 30, 45, 60, F1M1, true),
+            slot(618, 5, 50, 60, F1M1, true),
+            slot(618, 4, 50, 60, F1M1, true),
+            slot(618, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "route_8_winter",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(536, 30, 45, 60, F1M1, true),
+            slot(618, 5, 50, 60, F1M1, true),
+            slot(618, 4, 50, 60, F1M1, true),
+            slot(618, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "moor_of_icirrus_spring",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(618, 5, 60, 60, F1M1, true),
+            slot(618, 4, 60, 60, F1M1, true),
+            slot(618, 1, 60, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "moor_of_icirrus_summer",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(618, 5, 60, 60, F1M1, true),
+            slot(618, 4, 60, 60, F1M1, true),
+            slot(618, 1, 60, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "moor_of_icirrus_autumn",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(618, 5, 60, 60, F1M1, true),
+            slot(618, 4, 60, 60, F1M1, true),
+            slot(618, 1, 60, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "moor_of_icirrus_winter",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(618, 5, 50, 60, F1M1, true),
+            slot(618, 4, 50, 60, F1M1, true),
+            slot(618, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "route_11",
+        slots: &[
+            slot(550, 60, 25, 40, F1M1, true),
+            slot(418, 30, 25, 40, F1M1, false),
+            slot(550, 5, 30, 40, F1M1, true),
+            slot(550, 4, 30, 40, F1M1, true),
+            slot(550, 1, 30, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "route_13",
+        slots: &[
+            slot(592, 60, 25, 40, F1M1, false),
+            slot(120, 30, 25, 40, Genderless, true),
+            slot(550, 5, 30, 40, F1M1, true),
+            slot(592, 4, 30, 40, F1M1, false),
+            slot(592, 1, 30, 40, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "route_14",
+        slots: &[
+            slot(550, 60, 15, 35, F1M1, true),
+            slot(418, 30, 15, 35, F1M1, false),
+            slot(550, 5, 15, 35, F1M1, true),
+            slot(550, 4, 15, 35, F1M1, true),
+            slot(550, 1, 15, 35, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "abundant_shrine",
+        slots: &[
+            slot(550, 60, 25, 40, F1M1, true),
+            slot(183, 30, 25, 40, F1M1, false),
+            slot(550, 5, 30, 40, F1M1, true),
+            slot(550, 4, 30, 40, F1M1, true),
+            slot(550, 1, 30, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "lostlorn_forest",
+        slots: &[
+            slot(550, 60, 10, 25, F1M1, true),
+            slot(418, 30, 10, 25, F1M1, false),
+            slot(550, 5, 10, 25, F1M1, true),
+            slot(550, 4, 10, 25, F1M1, true),
+            slot(550, 1, 10, 25, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "route_18",
+        slots: &[
+            slot(592, 60, 45, 60, F1M1, false),
+            slot(592, 30, 45, 60, F1M1, false),
+            slot(592, 5, 50, 60, F1M1, false),
+            slot(592, 4, 50, 60, F1M1, false),
+            slot(592, 1, 50, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "route_19",
+        slots: &[
+            slot(550, 60, 5, 15, F1M1, true),
+            slot(550, 30, 5, 15, F1M1, true),
+            slot(550, 5, 5, 15, F1M1, true),
+            slot(550, 4, 5, 15, F1M1, true),
+            slot(550, 1, 5, 15, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "route_20_spring",
+        slots: &[
+            slot(550, 60, 5, 15, F1M1, true),
+            slot(298, 30, 5, 15, F3M1, false),
+            slot(550, 5, 5, 15, F1M1, true),
+            slot(550, 4, 5, 15, F1M1, true),
+            slot(550, 1, 5, 15, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "route_20_summer",
+        slots: &[
+            slot(550, 60, 5, 15, F1M1, true),
+            slot(298, 30, 5, 15, F3M1, false),
+            slot(550, 5, 5, 15, F1M1, true),
+            slot(550, 4, 5, 15, F1M1, true),
+            slot(550, 1, 5, 15, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "route_20_autumn",
+        slots: &[
+            slot(550, 60, 5, 15, F1M1, true),
+            slot(298, 30, 5, 15, F3M1, false),
+            slot(550, 5, 5, 15, F1M1, true),
+            slot(550, 4, 5, 15, F1M1, true),
+            slot(550, 1, 5, 15, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "route_20_winter",
+        slots: &[
+            slot(550, 60, 5, 15, F1M1, true),
+            slot(298, 30, 5, 15, F3M1, false),
+            slot(550, 5, 5, 15, F1M1, true),
+            slot(550, 4, 5, 15, F1M1, true),
+            slot(550, 1, 5, 15, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "route_22",
+        slots: &[
+            slot(550, 60, 15, 40, F1M1, true),
+            slot(183, 30, 15, 40, F1M1, false),
+            slot(550, 5, 15, 40, F1M1, true),
+            slot(550, 4, 15, 40, F1M1, true),
+            slot(550, 1, 15, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "route_23",
+        slots: &[
+            slot(550, 60, 40, 55, F1M1, true),
+            slot(418, 30, 40, 55, F1M1, false),
+            slot(550, 5, 45, 55, F1M1, true),
+            slot(550, 4, 45, 55, F1M1, true),
+            slot(550, 1, 45, 55, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "undella_town",
+        slots: &[
+            slot(592, 60, 25, 40, F1M1, false),
+            slot(120, 30, 25, 40, Genderless, true),
+            slot(550, 5, 30, 40, F1M1, true),
+            slot(592, 4, 30, 40, F1M1, false),
+            slot(592, 1, 30, 40, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "route_17",
+        slots: &[
+            slot(592, 60, 45, 60, F1M1, false),
+            slot(592, 30, 45, 60, F1M1, false),
+            slot(592, 5, 50, 60, F1M1, false),
+            slot(592, 4, 50, 60, F1M1, false),
+            slot(592, 1, 50, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Surfing,
+        location_key: "route_21",
+        slots: &[
+            slot(592, 60, 30, 45, F1M1, false),
+            slot(458, 30, 30, 45, F1M1, false),
+            slot(223, 5, 35, 45, F1M1, false),
+            slot(592, 4, 35, 45, F1M1, false),
+            slot(592, 1, 35, 45, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "striaton_city",
+        slots: &[
+            slot(550, 60, 45, 60, F1M1, true),
+            slot(550, 30, 45, 60, F1M1, true),
+            slot(342, 5, 50, 60, F1M1, false),
+            slot(342, 4, 50, 60, F1M1, false),
+            slot(342, 1, 50, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "icirrus_city_spring",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(618, 5, 60, 60, F1M1, true),
+            slot(537, 4, 60, 60, F1M1, true),
+            slot(537, 1, 60, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "icirrus_city_summer",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(618, 5, 60, 60, F1M1, true),
+            slot(537, 4, 60, 60, F1M1, true),
+            slot(537, 1, 60, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "icirrus_city_autumn",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(618, 5, 60, 60, F1M1, true),
+            slot(537, 4, 60, 60, F1M1, true),
+            slot(537, 1, 60, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "icirrus_city_winter",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(618, 5, 50, 60, F1M1, true),
+            slot(537, 4, 50, 60, F1M1, true),
+            slot(537, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "aspertia_city",
+        slots: &[
+            slot(550, 60, 5, 15, F1M1, true),
+            slot(550, 30, 5, 15, F1M1, true),
+            slot(550, 5, 5, 15, F1M1, true),
+            slot(550, 4, 5, 15, F1M1, true),
+            slot(550, 1, 5, 15, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "virbank_city",
+        slots: &[
+            slot(594, 60, 5, 15, F1M1, false),
+            slot(594, 30, 5, 15, F1M1, false),
+            slot(594, 5, 5, 15, F1M1, false),
+            slot(593, 4, 5, 15, F1M1, false),
+            slot(593, 1, 5, 15, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "humilau_city",
+        slots: &[
+            slot(593, 60, 35, 45, F1M1, false),
+            slot(222, 30, 35, 45, F3M1, true),
+            slot(550, 5, 35, 45, F1M1, true),
+            slot(121, 4, 35, 45, Genderless, true),
+            slot(121, 1, 35, 45, Genderless, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "pinwheel_forest",
+        slots: &[
+            slot(183, 60, 45, 60, F1M1, false),
+            slot(550, 30, 45, 60, F1M1, true),
+            slot(550, 5, 50, 60, F1M1, true),
+            slot(184, 4, 50, 60, F1M1, false),
+            slot(184, 1, 50, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "dragonspiral_tower_spring",
+        slots: &[
+            slot(550, 60, 45, 60, F1M1, true),
+            slot(550, 30, 45, 60, F1M1, true),
+            slot(550, 5, 50, 60, F1M1, true),
+            slot(550, 4, 50, 60, F1M1, true),
+            slot(550, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "dragonspiral_tower_summer",
+        slots: &[
+            slot(550, 60, 45, 60, F1M1, true),
+            slot(550, 30, 45, 60, F1M1, true),
+            slot(550, 5, 50, 60, F1M1, true),
+            slot(550, 4, 50, 60, F1M1, true),
+            slot(550, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "dragonspiral_tower_autumn",
+        slots: &[
+            slot(550, 60, 45, 60, F1M1, true),
+            slot(550, 30, 45, 60, F1M1, true),
+            slot(550, 5, 50, 60, F1M1, true),
+            slot(550, 4, 50, 60, F1M1, true),
+            slot(550, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "dragonspiral_tower_winter",
+        slots: &[
+            slot(550, 60, 45, 60, F1M1, true),
+            slot(550, 30, 45, 60, F1M1, true),
+            slot(550, 5, 50, 60, F1M1, true),
+            slot(550, 4, 50, 60, F1M1, true),
+            slot(550, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "giant_chasm",
+        slots: &[
+            slot(86, 60, 35, 50, F1M1, false),
+            slot(550, 30, 35, 50, F1M1, true),
+            slot(87, 5, 40, 50, F1M1, false),
+            slot(87, 4, 40, 50, F1M1, false),
+            slot(87, 1, 40, 50, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "castelia_sewers",
+        slots: &[
+            slot(88, 60, 5, 20, F1M1, true),
+            slot(88, 30, 5, 20, F1M1, true),
+            slot(88, 5, 5, 20, F1M1, true),
+            slot(89, 4, 5, 20, F1M1, true),
+            slot(89, 1, 5, 20, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "p2_laboratory",
+        slots: &[
+            slot(594, 60, 45, 60, F1M1, false),
+            slot(594, 30, 45, 60, F1M1, false),
+            slot(594, 5, 50, 60, F1M1, false),
+            slot(593, 4, 50, 60, F1M1, false),
+            slot(593, 1, 50, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "undella_bay_spring",
+        slots: &[
+            slot(593, 60, 25, 40, F1M1, false),
+            slot(320, 30, 25, 40, F1M1, false),
+            slot(226, 5, 30, 40, F1M1, false),
+            slot(321, 4, 30, 40, F1M1, false),
+            slot(321, 1, 30, 40, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "undella_bay_summer",
+        slots: &[
+            slot(593, 60, 25, 40, F1M1, false),
+            slot(320, 30, 25, 40, F1M1, false),
+            slot(226, 5, 30, 40, F1M1, false),
+            slot(321, 4, 30, 40, F1M1, false),
+            slot(321, 1, 30, 40, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "undella_bay_autumn",
+        slots: &[
+            slot(593, 60, 25, 40, F1M1, false),
+            slot(320, 30, 25, 40, F1M1, false),
+            slot(226, 5, 30, 40, F1M1, false),
+            slot(321, 4, 30, 40, F1M1, false),
+            slot(321, 1, 30, 40, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "undella_bay_winter",
+        slots: &[
+            slot(320, 60, 25, 40, F1M1, false),
+            slot(364, 30, 25, 40, F1M1, false),
+            slot(365, 5, 30, 40, F1M1, false),
+            slot(321, 4, 30, 40, F1M1, false),
+            slot(321, 1, 30, 40, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "floccesy_ranch",
+        slots: &[
+            slot(550, 60, 5, 15, F1M1, true),
+            slot(183, 30, 5, 15, F1M1, false),
+            slot(550, 5, 5, 15, F1M1, true),
+            slot(184, 4, 5, 15, F1M1, false),
+            slot(184, 1, 5, 15, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "virbank_complex",
+        slots: &[
+            slot(594, 60, 5, 15, F1M1, false),
+            slot(594, 30, 5, 15, F1M1, false),
+            slot(594, 5, 5, 15, F1M1, false),
+            slot(593, 4, 5, 15, F1M1, false),
+            slot(593, 1, 5, 15, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "victory_road",
+        slots: &[
+            slot(183, 60, 35, 50, F1M1, false),
+            slot(550, 30, 35, 50, F1M1, true),
+            slot(550, 5, 40, 50, F1M1, true),
+            slot(184, 4, 40, 50, F1M1, false),
+            slot(184, 1, 40, 50, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "relic_passage",
+        slots: &[
+            slot(183, 60, 10, 30, F1M1, false),
+            slot(550, 30, 10, 30, F1M1, true),
+            slot(550, 5, 10, 30, F1M1, true),
+            slot(184, 4, 10, 30, F1M1, false),
+            slot(184, 1, 10, 30, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "clay_tunnel",
+        slots: &[
+            slot(550, 60, 45, 60, F1M1, true),
+            slot(550, 30, 45, 60, F1M1, true),
+            slot(550, 5, 50, 60, F1M1, true),
+            slot(550, 4, 50, 60, F1M1, true),
+            slot(550, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "seaside_cave",
+        slots: &[
+            slot(86, 60, 25, 40, F1M1, false),
+            slot(593, 30, 25, 40, F1M1, false),
+            slot(87, 5, 30, 40, F1M1, false),
+            slot(87, 4, 30, 40, F1M1, false),
+            slot(87, 1, 30, 40, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "nature_preserve",
+        slots: &[
+            slot(418, 60, 45, 60, F1M1, false),
+            slot(550, 30, 45, 60, F1M1, true),
+            slot(550, 5, 50, 60, F1M1, true),
+            slot(419, 4, 50, 60, F1M1, false),
+            slot(419, 1, 50, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "village_bridge",
+        slots: &[
+            slot(183, 60, 25, 40, F1M1, false),
+            slot(550, 30, 25, 40, F1M1, true),
+            slot(184, 5, 30, 40, F1M1, false),
+            slot(131, 4, 30, 40, F1M1, false),
+            slot(131, 1, 30, 40, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "route_1",
+        slots: &[
+            slot(550, 60, 45, 60, F1M1, true),
+            slot(550, 30, 45, 60, F1M1, true),
+            slot(550, 5, 50, 60, F1M1, true),
+            slot(550, 4, 50, 60, F1M1, true),
+            slot(550, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "route_3",
+        slots: &[
+            slot(341, 60, 45, 60, F1M1, false),
+            slot(550, 30, 45, 60, F1M1, true),
+            slot(342, 5, 50, 60, F1M1, false),
+            slot(342, 4, 50, 60, F1M1, false),
+            slot(342, 1, 50, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "wellspring_cave",
+        slots: &[
+            slot(550, 60, 45, 60, F1M1, true),
+            slot(550, 30, 45, 60, F1M1, true),
+            slot(550, 5, 50, 60, F1M1, true),
+            slot(550, 4, 50, 60, F1M1, true),
+            slot(550, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "route_4",
+        slots: &[
+            slot(594, 60, 5, 20, F1M1, false),
+            slot(594, 30, 5, 20, F1M1, false),
+            slot(594, 5, 5, 20, F1M1, false),
+            slot(593, 4, 5, 20, F1M1, false),
+            slot(593, 1, 5, 20, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "route_6_spring",
+        slots: &[
+            slot(183, 60, 10, 30, F1M1, false),
+            slot(550, 30, 10, 30, F1M1, true),
+            slot(550, 5, 10, 30, F1M1, true),
+            slot(184, 4, 10, 30, F1M1, false),
+            slot(184, 1, 10, 30, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "route_6_summer",
+        slots: &[
+            slot(183, 60, 10, 30, F1M1, false),
+            slot(550, 30, 10, 30, F1M1, true),
+            slot(550, 5, 10, 30, F1M1, true),
+            slot(184, 4, 10, 30, F1M1, false),
+            slot(184, 1, 10, 30, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "route_6_autumn",
+        slots: &[
+            slot(183, 60, 10, 30, F1M1, false),
+            slot(550, 30, 10, 30, F1M1, true),
+            slot(550, 5, 10, 30, F1M1, true),
+            slot(184, 4, 10, 30, F1M1, false),
+            slot(184, 1, 10, 30, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "route_6_winter",
+        slots: &[
+            slot(183, 60, 10, 30, F1M1, false),
+            slot(550, 30, 10, 30, F1M1, true),
+            slot(550, 5, 10, 30, F1M1, true),
+            slot(184, 4, 10, 30, F1M1, false),
+            slot(184, 1, 10, 30, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "route_8_spring",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(536, 30, 45, 60, F1M1, true),
+            slot(618, 5, 60, 60, F1M1, true),
+            slot(537, 4, 60, 60, F1M1, true),
+            slot(537, 1, 60, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "route_8_summer",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(536, 30, 45, 60, F1M1, true),
+            slot(618, 5, 50, 60, F1M1, true),
+            slot(537, 4, 50, 60, F1M1, true),
+            slot(537, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "route_8_autumn",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(536, 30, 45, 60, F1M1, true),
+            slot(618, 5, 50, 60, F1M1, true),
+            slot(537, 4, 50, 60, F1M1, true),
+            slot(537, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "route_8_winter",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(536, 30, 45, 60, F1M1, true),
+            slot(618, 5, 50, 60, F1M1, true),
+            slot(537, 4, 50, 60, F1M1, true),
+            slot(537, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "moor_of_icirrus_spring",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(618, 5, 60, 60, F1M1, true),
+            slot(537, 4, 60, 60, F1M1, true),
+            slot(537, 1, 60, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "moor_of_icirrus_summer",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(618, 5, 60, 60, F1M1, true),
+            slot(537, 4, 60, 60, F1M1, true),
+            slot(537, 1, 60, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "moor_of_icirrus_autumn",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(618, 5, 60, 60, F1M1, true),
+            slot(537, 4, 60, 60, F1M1, true),
+            slot(537, 1, 60, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "moor_of_icirrus_winter",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(618, 5, 50, 60, F1M1, true),
+            slot(537, 4, 50, 60, F1M1, true),
+            slot(537, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "route_11",
+        slots: &[
+            slot(418, 60, 25, 40, F1M1, false),
+            slot(550, 30, 25, 40, F1M1, true),
+            slot(419, 5, 30, 40, F1M1, false),
+            slot(419, 4, 30, 40, F1M1, false),
+            slot(419, 1, 30, 40, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "route_13",
+        slots: &[
+            slot(120, 60, 25, 40, Genderless, true),
+            slot(593, 30, 25, 40, F1M1, false),
+            slot(550, 5, 30, 40, F1M1, true),
+            slot(121, 4, 30, 40, Genderless, true),
+            slot(121, 1, 30, 40, Genderless, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "route_14",
+        slots: &[
+            slot(418, 60, 15, 40, F1M1, false),
+            slot(550, 30, 15, 40, F1M1, true),
+            slot(419, 5, 25, 40, F1M1, false),
+            slot(419, 4, 25, 40, F1M1, false),
+            slot(419, 1, 25, 40, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "abundant_shrine",
+        slots: &[
+            slot(183, 60, 25, 40, F1M1, false),
+            slot(550, 30, 25, 40, F1M1, true),
+            slot(550, 5, 30, 40, F1M1, true),
+            slot(184, 4, 30, 40, F1M1, false),
+            slot(184, 1, 30, 40, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "lostlorn_forest",
+        slots: &[
+            slot(418, 60, 10, 30, F1M1, false),
+            slot(550, 30, 10, 30, F1M1, true),
+            slot(419, 5, 10, 30, F1M1, false),
+            slot(419, 4, 10, 30, F1M1, false),
+            slot(419, 1, 10, 30, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "route_18",
+        slots: &[
+            slot(594, 60, 45, 60, F1M1, false),
+            slot(594, 30, 45, 60, F1M1, false),
+            slot(594, 5, 50, 60, F1M1, false),
+            slot(593, 4, 50, 60, F1M1, false),
+            slot(593, 1, 50, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "route_19",
+        slots: &[
+            slot(550, 60, 5, 15, F1M1, true),
+            slot(550, 30, 5, 15, F1M1, true),
+            slot(550, 5, 5, 15, F1M1, true),
+            slot(550, 4, 5, 15, F1M1, true),
+            slot(550, 1, 5, 15, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "route_20_spring",
+        slots: &[
+            slot(550, 60, 5, 15, F1M1, true),
+            slot(183, 30, 5, 15, F1M1, false),
+            slot(550, 5, 5, 15, F1M1, true),
+            slot(184, 4, 5, 15, F1M1, false),
+            slot(184, 1, 5, 15, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "route_20_summer",
+        slots: &[
+            slot(550, 60, 5, 15, F1M1, true),
+            slot(183, 30, 5, 15, F1M1, false),
+            slot(550, 5, 5, 15, F1M1, true),
+            slot(184, 4, 5, 15, F1M1, false),
+            slot(184, 1, 5, 15, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "route_20_autumn",
+        slots: &[
+            slot(550, 60, 5, 15, F1M1, true),
+            slot(183, 30, 5, 15, F1M1, false),
+            slot(550, 5, 5, 15, F1M1, true),
+            slot(184, 4, 5, 15, F1M1, false),
+            slot(184, 1, 5, 15, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "route_20_winter",
+        slots: &[
+            slot(550, 60, 5, 15, F1M1, true),
+            slot(183, 30, 5, 15, F1M1, false),
+            slot(550, 5, 5, 15, F1M1, true),
+            slot(184, 4, 5, 15, F1M1, false),
+            slot(184, 1, 5, 15, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "route_22",
+        slots: &[
+            slot(183, 60, 15, 40, F1M1, false),
+            slot(550, 30, 15, 40, F1M1, true),
+            slot(550, 5, 25, 45, F1M1, true),
+            slot(184, 4, 25, 45, F1M1, false),
+            slot(184, 1, 25, 45, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "route_23",
+        slots: &[
+            slot(418, 60, 40, 55, F1M1, false),
+            slot(550, 30, 40, 55, F1M1, true),
+            slot(419, 5, 45, 55, F1M1, false),
+            slot(419, 4, 45, 55, F1M1, false),
+            slot(419, 1, 45, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "undella_town",
+        slots: &[
+            slot(120, 60, 25, 40, Genderless, true),
+            slot(593, 30, 25, 40, F1M1, false),
+            slot(550, 5, 30, 40, F1M1, true),
+            slot(121, 4, 30, 40, Genderless, true),
+            slot(121, 1, 30, 40, Genderless, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "route_17",
+        slots: &[
+            slot(594, 60, 45, 60, F1M1, false),
+            slot(594, 30, 45, 60, F1M1, false),
+            slot(594, 5, 50, 60, F1M1, false),
+            slot(593, 4, 50, 60, F1M1, false),
+            slot(593, 1, 50, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: SurfingBubble,
+        location_key: "route_21",
+        slots: &[
+            slot(593, 60, 30, 45, F1M1, false),
+            slot(594, 30, 30, 45, F1M1, false),
+            slot(226, 5, 35, 45, F1M1, false),
+            slot(594, 4, 35, 45, F1M1, false),
+            slot(594, 1, 35, 45, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "striaton_city",
+        slots: &[
+            slot(118, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(118, 5, 50, 70, F1M1, false),
+            slot(118, 4, 50, 70, F1M1, false),
+            slot(118, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "icirrus_city_spring",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(339, 5, 60, 60, F1M1, false),
+            slot(339, 4, 60, 60, F1M1, false),
+            slot(339, 1, 60, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "icirrus_city_summer",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(339, 5, 60, 60, F1M1, false),
+            slot(339, 4, 60, 60, F1M1, false),
+            slot(339, 1, 60, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "icirrus_city_autumn",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(339, 5, 60, 60, F1M1, false),
+            slot(339, 4, 60, 60, F1M1, false),
+            slot(339, 1, 60, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "icirrus_city_winter",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(339, 5, 50, 60, F1M1, false),
+            slot(339, 4, 50, 60, F1M1, false),
+            slot(339, 1, 50, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "aspertia_city",
+        slots: &[
+            slot(118, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(118, 5, 50, 70, F1M1, false),
+            slot(118, 4, 50, 70, F1M1, false),
+            slot(118, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "virbank_city",
+        slots: &[
+            slot(456, 60, 40, 60, F1M1, true),
+            slot(98, 30, 40, 60, F1M1, false),
+            slot(456, 5, 50, 70, F1M1, true),
+            slot(211, 4, 50, 70, F1M1, true),
+            slot(211, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "humilau_city",
+        slots: &[
+            slot(370, 60, 40, 60, F3M1, true),
+            slot(90, 30, 40, 60, F1M1, true),
+            slot(370, 5, 50, 70, F3M1, true),
+            slot(370, 4, 50, 70, F3M1, true),
+            slot(370, 1, 50, 70, F3M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "pinwheel_forest",
+        slots: &[
+            slot(118, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(118, 5, 50, 70, F1M1, false),
+            slot(118, 4, 50, 70, F1M1, false),
+            slot(118, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "dragonspiral_tower_spring",
+        slots: &[
+            slot(147, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(147, 5, 50, 70, F1M1, true),
+            slot(147, 4, 50, 70, F1M1, true),
+            slot(147, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "dragonspiral_tower_summer",
+        slots: &[
+            slot(147, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(147, 5, 50, 70, F1M1, true),
+            slot(147, 4, 50, 70, F1M1, true),
+            slot(147, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "dragonspiral_tower_autumn",
+        slots: &[
+            slot(147, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(147, 5, 50, 70, F1M1, true),
+            slot(147, 4, 50, 70, F1M1, true),
+            slot(147, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "dragonspiral_tower_winter",
+        slots: &[
+            slot(147, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(147, 5, 50, 70, F1M1, true),
+            slot(147, 4, 50, 70, F1M1, true),
+            slot(147, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "giant_chasm",
+        slots: &[
+            slot(60, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(60, 5, 50, 70, F1M1, false),
+            slot(60, 4, 50, 70, F1M1, false),
+            slot(60, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "castelia_sewers",
+        slots: &[
+            slot(88, 60, 40, 60, F1M1, true),
+            slot(88, 30, 40, 60, F1M1, true),
+            slot(88, 5, 50, 70, F1M1, true),
+            slot(88, 4, 50, 70, F1M1, true),
+            slot(88, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "p2_laboratory",
+        slots: &[
+            slot(456, 60, 35, 55, F1M1, true),
+            slot(116, 30, 35, 55, F1M1, true),
+            slot(116, 5, 35, 55, F1M1, true),
+            slot(456, 4, 35, 55, F1M1, true),
+            slot(456, 1, 35, 55, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "undella_bay_spring",
+        slots: &[
+            slot(170, 60, 40, 60, F1M1, true),
+            slot(223, 30, 40, 60, F1M1, false),
+            slot(170, 5, 50, 70, F1M1, true),
+            slot(170, 4, 50, 70, F1M1, true),
+            slot(170, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "undella_bay_summer",
+        slots: &[
+            slot(170, 60, 40, 60, F1M1, true),
+            slot(223, 30, 40, 60, F1M1, false),
+            slot(170, 5, 50, 70, F1M1, true),
+            slot(170, 4, 50, 70, F1M1, true),
+            slot(170, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "undella_bay_autumn",
+        slots: &[
+            slot(170, 60, 40, 60, F1M1, true),
+            slot(223, 30, 40, 60, F1M1, false),
+            slot(170, 5, 50, 70, F1M1, true),
+            slot(170, 4, 50, 70, F1M1, true),
+            slot(170, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "undella_bay_winter",
+        slots: &[
+            slot(170, 60, 40, 60, F1M1, true),
+            slot(223, 30, 40, 60, F1M1, false),
+            slot(170, 5, 50, 70, F1M1, true),
+            slot(170, 4, 50, 70, F1M1, true),
+            slot(170, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "floccesy_ranch",
+        slots: &[
+            slot(60, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(60, 5, 50, 70, F1M1, false),
+            slot(60, 4, 50, 70, F1M1, false),
+            slot(60, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "virbank_complex",
+        slots: &[
+            slot(456, 60, 40, 60, F1M1, true),
+            slot(98, 30, 40, 60, F1M1, false),
+            slot(456, 5, 50, 70, F1M1, true),
+            slot(211, 4, 50, 70, F1M1, true),
+            slot(211, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "victory_road",
+        slots: &[
+            slot(60, 60, 40, 55, F1M1, false),
+            slot(550, 30, 40, 55, F1M1, true),
+            slot(60, 5, 50, 55, F1M1, false),
+            slot(60, 4, 50, 55, F1M1, false),
+            slot(60, 1, 50, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "relic_passage",
+        slots: &[
+            slot(60, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(60, 5, 50, 70, F1M1, false),
+            slot(60, 4, 50, 70, F1M1, false),
+            slot(60, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "clay_tunnel",
+        slots: &[
+            slot(60, 60, 50, 60, F1M1, false),
+            slot(550, 30, 50, 60, F1M1, true),
+            slot(60, 5, 60, 70, F1M1, false),
+            slot(60, 4, 60, 70, F1M1, false),
+            slot(60, 1, 60, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "seaside_cave",
+        slots: &[
+            slot(370, 60, 40, 60, F3M1, true),
+            slot(90, 30, 40, 60, F1M1, true),
+            slot(370, 5, 50, 70, F3M1, true),
+            slot(370, 4, 50, 70, F3M1, true),
+            slot(370, 1, 50, 70, F3M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "nature_preserve",
+        slots: &[
+            slot(129, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(129, 5, 50, 70, F1M1, false),
+            slot(129, 4, 50, 70, F1M1, false),
+            slot(129, 1, 1, 100, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "village_bridge",
+        slots: &[
+            slot(318, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(318, 5, 50, 70, F1M1, true),
+            slot(318, 4, 50, 70, F1M1, true),
+            slot(318, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "route_1",
+        slots: &[
+            slot(550, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(550, 5, 50, 70, F1M1, true),
+            slot(349, 4, 50, 70, F1M1, false),
+            slot(349, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "route_3",
+        slots: &[
+            slot(118, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(118, 5, 50, 70, F1M1, false),
+            slot(118, 4, 50, 70, F1M1, false),
+            slot(118, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "wellspring_cave",
+        slots: &[
+            slot(60, 60, 50, 60, F1M1, false),
+            slot(550, 30, 50, 60, F1M1, true),
+            slot(60, 5, 60, 70, F1M1, false),
+            slot(60, 4, 60, 70, F1M1, false),
+            slot(60, 1, 60, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "route_4",
+        slots: &[
+            slot(456, 60, 40, 60, F1M1, true),
+            slot(366, 30, 40, 60, F1M1, true),
+            slot(456, 5, 50, 70, F1M1, true),
+            slot(211, 4, 50, 70, F1M1, true),
+            slot(211, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "route_6_spring",
+        slots: &[
+            slot(60, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(60, 5, 50, 70, F1M1, false),
+            slot(60, 4, 50, 70, F1M1, false),
+            slot(60, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "route_6_summer",
+        slots: &[
+            slot(60, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(60, 5, 50, 70, F1M1, false),
+            slot(60, 4, 50, 70, F1M1, false),
+            slot(60, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "route_6_autumn",
+        slots: &[
+            slot(60, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(60, 5, 50, 70, F1M1, false),
+            slot(60, 4, 50, 70, F1M1, false),
+            slot(60, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "route_6_winter",
+        slots: &[
+            slot(60, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(60, 5, 50, 70, F1M1, false),
+            slot(60, 4, 50, 70, F1M1, false),
+            slot(60, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "route_8_spring",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(339, 5, 60, 60, F1M1, false),
+            slot(339, 4, 60, 60, F1M1, false),
+            slot(339, 1, 60, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "route_8_summer",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(339, 5, 50, 60, F1M1, false),
+            slot(339, 4, 50, 60, F1M1, false),
+            slot(339, 1, 50, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "route_8_autumn",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(339, 5, 50, 60, F1M1, false),
+            slot(339, 4, 50, 60, F1M1, false),
+            slot(339, 1, 50, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "route_8_winter",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(339, 5, 50, 60, F1M1, false),
+            slot(339, 4, 50, 60, F1M1, false),
+            slot(339, 1, 50, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "moor_of_icirrus_spring",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(339, 5, 60, 60, F1M1, false),
+            slot(339, 4, 60, 60, F1M1, false),
+            slot(339, 1, 60, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "moor_of_icirrus_summer",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(339, 5, 60, 60, F1M1, false),
+            slot(339, 4, 60, 60, F1M1, false),
+            slot(339, 1, 60, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "moor_of_icirrus_autumn",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(339, 5, 60, 60, F1M1, false),
+            slot(339, 4, 60, 60, F1M1, false),
+            slot(339, 1, 60, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "moor_of_icirrus_winter",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(339, 5, 50, 60, F1M1, false),
+            slot(339, 4, 50, 60, F1M1, false),
+            slot(339, 1, 50, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "route_11",
+        slots: &[
+            slot(118, 60, 40, 50, F1M1, false),
+            slot(550, 30, 40, 50, F1M1, true),
+            slot(118, 5, 50, 60, F1M1, false),
+            slot(118, 4, 50, 60, F1M1, false),
+            slot(118, 1, 50, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "route_13",
+        slots: &[
+            slot(370, 60, 40, 60, F3M1, true),
+            slot(90, 30, 40, 60, F1M1, true),
+            slot(370, 5, 50, 70, F3M1, true),
+            slot(370, 4, 50, 70, F3M1, true),
+            slot(370, 1, 50, 70, F3M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "route_14",
+        slots: &[
+            slot(118, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(118, 5, 50, 70, F1M1, false),
+            slot(118, 4, 50, 70, F1M1, false),
+            slot(118, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "abundant_shrine",
+        slots: &[
+            slot(118, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(118, 5, 50, 70, F1M1, false),
+            slot(118, 4, 50, 70, F1M1, false),
+            slot(118, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "lostlorn_forest",
+        slots: &[
+            slot(60, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(60, 5, 50, 70, F1M1, false),
+            slot(60, 4, 50, 70, F1M1, false),
+            slot(60, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "route_18",
+        slots: &[
+            slot(456, 60, 50, 60, F1M1, true),
+            slot(116, 30, 50, 60, F1M1, true),
+            slot(456, 5, 60, 70, F1M1, true),
+            slot(222, 4, 60, 70, F3M1, true),
+            slot(222, 1, 60, 70, F3M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "route_19",
+        slots: &[
+            slot(60, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(60, 5, 50, 70, F1M1, false),
+            slot(60, 4, 50, 70, F1M1, false),
+            slot(60, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "route_20_spring",
+        slots: &[
+            slot(60, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(60, 5, 50, 70, F1M1, false),
+            slot(60, 4, 50, 70, F1M1, false),
+            slot(60, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "route_20_summer",
+        slots: &[
+            slot(60, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(60, 5, 50, 70, F1M1, false),
+            slot(60, 4, 50, 70, F1M1, false),
+            slot(60, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "route_20_autumn",
+        slots: &[
+            slot(60, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(60, 5, 50, 70, F1M1, false),
+            slot(60, 4, 50, 70, F1M1, false),
+            slot(60, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "route_20_winter",
+        slots: &[
+            slot(60, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(60, 5, 50, 70, F1M1, false),
+            slot(60, 4, 50, 70, F1M1, false),
+            slot(60, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "route_22",
+        slots: &[
+            slot(118, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(118, 5, 50, 70, F1M1, false),
+            slot(118, 4, 50, 70, F1M1, false),
+            slot(118, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "route_23",
+        slots: &[
+            slot(60, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(60, 5, 50, 70, F1M1, false),
+            slot(60, 4, 50, 70, F1M1, false),
+            slot(60, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "undella_town",
+        slots: &[
+            slot(370, 60, 40, 60, F3M1, true),
+            slot(90, 30, 40, 60, F1M1, true),
+            slot(370, 5, 50, 70, F3M1, true),
+            slot(370, 4, 50, 70, F3M1, true),
+            slot(370, 1, 50, 70, F3M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "route_17",
+        slots: &[
+            slot(456, 60, 50, 60, F1M1, true),
+            slot(116, 30, 50, 60, F1M1, true),
+            slot(456, 5, 60, 70, F1M1, true),
+            slot(456, 4, 60, 70, F1M1, true),
+            slot(456, 1, 60, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: Fishing,
+        location_key: "route_21",
+        slots: &[
+            slot(370, 60, 40, 60, F3M1, true),
+            slot(223, 30, 40, 60, F1M1, false),
+            slot(370, 5, 50, 70, F3M1, true),
+            slot(370, 4, 50, 70, F3M1, true),
+            slot(370, 1, 50, 70, F3M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "striaton_city",
+        slots: &[
+            slot(118, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(119, 5, 50, 70, F1M1, false),
+            slot(119, 4, 50, 70, F1M1, false),
+            slot(119, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "icirrus_city_spring",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(340, 5, 60, 60, F1M1, false),
+            slot(340, 4, 60, 60, F1M1, false),
+            slot(340, 1, 60, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "icirrus_city_summer",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(340, 5, 60, 60, F1M1, false),
+            slot(340, 4, 60, 60, F1M1, false),
+            slot(340, 1, 60, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "icirrus_city_autumn",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(340, 5, 60, 60, F1M1, false),
+            slot(340, 4, 60, 60, F1M1, false),
+            slot(340, 1, 60, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "icirrus_city_winter",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(340, 5, 50, 60, F1M1, false),
+            slot(340, 4, 50, 60, F1M1, false),
+            slot(340, 1, 50, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "aspertia_city",
+        slots: &[
+            slot(118, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(119, 5, 50, 70, F1M1, false),
+            slot(119, 4, 50, 70, F1M1, false),
+            slot(119, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "virbank_city",
+        slots: &[
+            slot(98, 60, 40, 60, F1M1, false),
+            slot(457, 30, 40, 60, F1M1, true),
+            slot(99, 5, 50, 70, F1M1, false),
+            slot(99, 4, 50, 70, F1M1, false),
+            slot(99, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "humilau_city",
+        slots: &[
+            slot(90, 60, 40, 60, F1M1, true),
+            slot(370, 30, 40, 60, F3M1, true),
+            slot(90, 5, 50, 70, F1M1, true),
+            slot(91, 4, 50, 70, F1M1, true),
+            slot(91, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "pinwheel_forest",
+        slots: &[
+            slot(118, 60, 35, 60, F1M1, false),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(119, 5, 35, 70, F1M1, false),
+            slot(119, 4, 45, 70, F1M1, false),
+            slot(119, 1, 45, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "dragonspiral_tower_spring",
+        slots: &[
+            slot(147, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(148, 5, 50, 70, F1M1, true),
+            slot(148, 4, 50, 70, F1M1, true),
+            slot(149, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "dragonspiral_tower_summer",
+        slots: &[
+            slot(147, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(148, 5, 50, 70, F1M1, true),
+            slot(148, 4, 50, 70, F1M1, true),
+            slot(149, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "dragonspiral_tower_autumn",
+        slots: &[
+            slot(147, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(148, 5, 50, 70, F1M1, true),
+            slot(148, 4, 50, 70, F1M1, true),
+            slot(149, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "dragonspiral_tower_winter",
+        slots: &[
+            slot(147, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(148, 5, 50, 70, F1M1, true),
+            slot(148, 4, 50, 70, F1M1, true),
+            slot(149, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "giant_chasm",
+        slots: &[
+            slot(61, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(61, 5, 50, 70, F1M1, true),
+            slot(62, 4, 50, 70, F1M1, true),
+            slot(62, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "castelia_sewers",
+        slots: &[
+            slot(88, 60, 40, 60, F1M1, true),
+            slot(88, 30, 40, 60, F1M1, true),
+            slot(89, 5, 50, 70, F1M1, true),
+            slot(89, 4, 50, 70, F1M1, true),
+            slot(89, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "p2_laboratory",
+        slots: &[
+            slot(117, 60, 35, 60, F1M1, true),
+            slot(211, 30, 35, 60, F1M1, true),
+            slot(457, 5, 35, 70, F1M1, true),
+            slot(230, 4, 45, 70, F1M1, true),
+            slot(230, 1, 45, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "undella_bay_spring",
+        slots: &[
+            slot(223, 60, 40, 60, F1M1, false),
+            slot(170, 30, 40, 60, F1M1, true),
+            slot(171, 5, 50, 70, F1M1, true),
+            slot(224, 4, 50, 70, F1M1, false),
+            slot(224, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "undella_bay_summer",
+        slots: &[
+            slot(223, 60, 40, 60, F1M1, false),
+            slot(170, 30, 40, 60, F1M1, true),
+            slot(171, 5, 50, 70, F1M1, true),
+            slot(224, 4, 50, 70, F1M1, false),
+            slot(224, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "undella_bay_autumn",
+        slots: &[
+            slot(223, 60, 40, 60, F1M1, false),
+            slot(170, 30, 40, 60, F1M1, true),
+            slot(171, 5, 50, 70, F1M1, true),
+            slot(224, 4, 50, 70, F1M1, false),
+            slot(224, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "undella_bay_winter",
+        slots: &[
+            slot(223, 60, 40, 60, F1M1, false),
+            slot(170, 30, 40, 60, F1M1, true),
+            slot(171, 5, 50, 70, F1M1, true),
+            slot(224, 4, 50, 70, F1M1, false),
+            slot(224, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "floccesy_ranch",
+        slots: &[
+            slot(61, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(61, 5, 50, 70, F1M1, true),
+            slot(186, 4, 50, 70, F1M1, true),
+            slot(186, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "virbank_complex",
+        slots: &[
+            slot(98, 60, 40, 60, F1M1, false),
+            slot(457, 30, 40, 60, F1M1, true),
+            slot(99, 5, 50, 70, F1M1, false),
+            slot(99, 4, 50, 70, F1M1, false),
+            slot(99, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "victory_road",
+        slots: &[
+            slot(61, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(61, 5, 50, 60, F1M1, true),
+            slot(62, 4, 50, 60, F1M1, true),
+            slot(62, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "relic_passage",
+        slots: &[
+            slot(61, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(61, 5, 50, 70, F1M1, true),
+            slot(62, 4, 50, 70, F1M1, true),
+            slot(62, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "clay_tunnel",
+        slots: &[
+            slot(61, 60, 50, 60, F1M1, true),
+            slot(550, 30, 50, 60, F1M1, true),
+            slot(61, 5, 60, 60, F1M1, true),
+            slot(62, 4, 60, 60, F1M1, true),
+            slot(62, 1, 60, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "seaside_cave",
+        slots: &[
+            slot(90, 60, 40, 60, F1M1, true),
+            slot(370, 30, 40, 60, F3M1, true),
+            slot(90, 5, 50, 70, F1M1, true),
+            slot(91, 4, 50, 70, F1M1, true),
+            slot(91, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "nature_preserve",
+        slots: &[
+            slot(129, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(130, 5, 50, 70, F1M1, false),
+            slot(130, 4, 50, 70, F1M1, false),
+            slot(130, 1, 1, 100, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "village_bridge",
+        slots: &[
+            slot(318, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(319, 5, 50, 70, F1M1, true),
+            slot(319, 4, 50, 70, F1M1, true),
+            slot(319, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "route_1",
+        slots: &[
+            slot(349, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(349, 5, 50, 70, F1M1, false),
+            slot(350, 4, 50, 70, F1M1, false),
+            slot(350, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "route_3",
+        slots: &[
+            slot(118, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(119, 5, 50, 70, F1M1, false),
+            slot(119, 4, 50, 70, F1M1, false),
+            slot(119, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "wellspring_cave",
+        slots: &[
+            slot(61, 60, 50, 60, F1M1, true),
+            slot(550, 30, 50, 60, F1M1, true),
+            slot(61, 5, 60, 60, F1M1, true),
+            slot(62, 4, 60, 60, F1M1, true),
+            slot(62, 1, 60, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "route_4",
+        slots: &[
+            slot(457, 60, 40, 60, F1M1, true),
+            slot(211, 30, 40, 60, F1M1, true),
+            slot(369, 5, 50, 70, F1M7, true),
+            slot(367, 4, 50, 70, F1M1, true),
+            slot(367, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "route_6_spring",
+        slots: &[
+            slot(61, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(61, 5, 50, 70, F1M1, true),
+            slot(186, 4, 50, 70, F1M1, true),
+            slot(186, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "route_6_summer",
+        slots: &[
+            slot(61, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(61, 5, 50, 70, F1M1, true),
+            slot(186, 4, 50, 70, F1M1, true),
+            slot(186, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "route_6_autumn",
+        slots: &[
+            slot(61, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(61, 5, 50, 70, F1M1, true),
+            slot(186, 4, 50, 70, F1M1, true),
+            slot(186, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "route_6_winter",
+        slots: &[
+            slot(61, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(61, 5, 50, 70, F1M1, true),
+            slot(186, 4, 50, 70, F1M1, true),
+            slot(186, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "route_8_spring",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(340, 5, 60, 60, F1M1, false),
+            slot(340, 4, 60, 60, F1M1, false),
+            slot(340, 1, 60, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "route_8_summer",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(340, 5, 50, 60, F1M1, false),
+            slot(340, 4, 50, 60, F1M1, false),
+            slot(340, 1, 50, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "route_8_autumn",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(340, 5, 50, 60, F1M1, false),
+            slot(340, 4, 50, 60, F1M1, false),
+            slot(340, 1, 50, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "route_8_winter",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(340, 5, 50, 60, F1M1, false),
+            slot(340, 4, 50, 60, F1M1, false),
+            slot(340, 1, 50, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "moor_of_icirrus_spring",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(340, 5, 60, 60, F1M1, false),
+            slot(340, 4, 60, 60, F1M1, false),
+            slot(340, 1, 60, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "moor_of_icirrus_summer",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(340, 5, 60, 60, F1M1, false),
+            slot(340, 4, 60, 60, F1M1, false),
+            slot(340, 1, 60, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "moor_of_icirrus_autumn",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(340, 5, 60, 60, F1M1, false),
+            slot(340, 4, 60, 60, F1M1, false),
+            slot(340, 1, 60, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "moor_of_icirrus_winter",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(340, 5, 50, 60, F1M1, false),
+            slot(340, 4, 50, 60, F1M1, false),
+            slot(340, 1, 50, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "route_11",
+        slots: &[
+            slot(118, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(119, 5, 50, 70, F1M1, false),
+            slot(119, 4, 50, 70, F1M1, false),
+            slot(119, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "route_13",
+        slots: &[
+            slot(90, 60, 40, 60, F1M1, true),
+            slot(370, 30, 40, 60, F3M1, true),
+            slot(90, 5, 50, 70, F1M1, true),
+            slot(91, 4, 50, 70, F1M1, true),
+            slot(91, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "route_14",
+        slots: &[
+            slot(118, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(119, 5, 50, 70, F1M1, false),
+            slot(119, 4, 50, 70, F1M1, false),
+            slot(119, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "abundant_shrine",
+        slots: &[
+            slot(118, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(119, 5, 50, 70, F1M1, false),
+            slot(119, 4, 50, 70, F1M1, false),
+            slot(119, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "lostlorn_forest",
+        slots: &[
+            slot(61, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(61, 5, 50, 70, F1M1, true),
+            slot(186, 4, 50, 70, F1M1, true),
+            slot(186, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "route_18",
+        slots: &[
+            slot(116, 60, 50, 60, F1M1, true),
+            slot(457, 30, 50, 60, F1M1, true),
+            slot(117, 5, 60, 70, F1M1, true),
+            slot(230, 4, 60, 70, F1M1, true),
+            slot(230, 1, 60, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "route_19",
+        slots: &[
+            slot(61, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(61, 5, 50, 70, F1M1, true),
+            slot(186, 4, 50, 70, F1M1, true),
+            slot(186, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "route_20_spring",
+        slots: &[
+            slot(61, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(61, 5, 50, 70, F1M1, true),
+            slot(186, 4, 50, 70, F1M1, true),
+            slot(186, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "route_20_summer",
+        slots: &[
+            slot(61, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(61, 5, 50, 70, F1M1, true),
+            slot(186, 4, 50, 70, F1M1, true),
+            slot(186, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "route_20_autumn",
+        slots: &[
+            slot(61, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(61, 5, 50, 70, F1M1, true),
+            slot(186, 4, 50, 70, F1M1, true),
+            slot(186, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "route_20_winter",
+        slots: &[
+            slot(61, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(61, 5, 50, 70, F1M1, true),
+            slot(186, 4, 50, 70, F1M1, true),
+            slot(186, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "route_22",
+        slots: &[
+            slot(118, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(119, 5, 50, 70, F1M1, false),
+            slot(119, 4, 50, 70, F1M1, false),
+            slot(119, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "route_23",
+        slots: &[
+            slot(61, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(61, 5, 50, 70, F1M1, true),
+            slot(62, 4, 50, 70, F1M1, true),
+            slot(62, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "undella_town",
+        slots: &[
+            slot(90, 60, 40, 60, F1M1, true),
+            slot(370, 30, 40, 60, F3M1, true),
+            slot(90, 5, 50, 70, F1M1, true),
+            slot(91, 4, 50, 70, F1M1, true),
+            slot(91, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "route_17",
+        slots: &[
+            slot(116, 60, 50, 60, F1M1, true),
+            slot(457, 30, 50, 60, F1M1, true),
+            slot(117, 5, 60, 70, F1M1, true),
+            slot(230, 4, 60, 70, F1M1, true),
+            slot(230, 1, 60, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: Black2,
+        encounter_type: FishingBubble,
+        location_key: "route_21",
+        slots: &[
+            slot(223, 60, 40, 60, F1M1, false),
+            slot(370, 30, 40, 60, F3M1, true),
+            slot(223, 5, 50, 70, F1M1, false),
+            slot(224, 4, 50, 70, F1M1, false),
+            slot(224, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "castelia_city",
+        slots: &[
+            slot(19, 20, 15, 15, F1M1, false),
+            slot(548, 20, 15, 15, FemaleOnly, false),
+            slot(519, 10, 15, 15, F1M1, false),
+            slot(300, 10, 15, 15, F3M1, true),
+            slot(19, 10, 16, 16, F1M1, false),
+            slot(548, 10, 16, 16, FemaleOnly, false),
+            slot(519, 5, 16, 16, F1M1, false),
+            slot(300, 5, 16, 16, F3M1, true),
+            slot(548, 4, 17, 17, FemaleOnly, false),
+            slot(133, 4, 18, 18, F1M7, false),
+            slot(548, 1, 17, 17, FemaleOnly, false),
+            slot(133, 1, 18, 18, F1M7, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "icirrus_city_spring",
+        slots: &[
+            slot(536, 20, 54, 54, F1M1, true),
+            slot(588, 20, 54, 54, F1M1, false),
+            slot(536, 10, 55, 55, F1M1, true),
+            slot(453, 10, 55, 55, F1M1, true),
+            slot(618, 10, 55, 55, F1M1, true),
+            slot(618, 10, 56, 56, F1M1, true),
+            slot(536, 5, 56, 56, F1M1, true),
+            slot(453, 5, 56, 56, F1M1, true),
+            slot(536, 4, 57, 57, F1M1, true),
+            slot(616, 4, 57, 57, F1M1, false),
+            slot(536, 1, 57, 57, F1M1, true),
+            slot(616, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "icirrus_city_summer",
+        slots: &[
+            slot(536, 20, 54, 54, F1M1, true),
+            slot(588, 20, 54, 54, F1M1, false),
+            slot(536, 10, 55, 55, F1M1, true),
+            slot(453, 10, 55, 55, F1M1, true),
+            slot(618, 10, 55, 55, F1M1, true),
+            slot(618, 10, 56, 56, F1M1, true),
+            slot(536, 5, 56, 56, F1M1, true),
+            slot(453, 5, 56, 56, F1M1, true),
+            slot(536, 4, 57, 57, F1M1, true),
+            slot(616, 4, 57, 57, F1M1, false),
+            slot(536, 1, 57, 57, F1M1, true),
+            slot(616, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "icirrus_city_autumn",
+        slots: &[
+            slot(536, 20, 54, 54, F1M1, true),
+            slot(588, 20, 54, 54, F1M1, false),
+            slot(536, 10, 55, 55, F1M1, true),
+            slot(453, 10, 55, 55, F1M1, true),
+            slot(618, 10, 55, 55, F1M1, true),
+            slot(618, 10, 56, 56, F1M1, true),
+            slot(536, 5, 56, 56, F1M1, true),
+            slot(453, 5, 56, 56, F1M1, true),
+            slot(536, 4, 57, 57, F1M1, true),
+            slot(616, 4, 57, 57, F1M1, false),
+            slot(536, 1, 57, 57, F1M1, true),
+            slot(616, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "dreamyard",
+        slots: &[
+            slot(505, 20, 56, 56, F1M1, false),
+            slot(510, 20, 56, 56, F1M1, false),
+            slot(20, 10, 57, 57, F1M1, false),
+            slot(39, 10, 57, 57, F3M1, false),
+            slot(517, 10, 57, 57, F1M1, false),
+            slot(517, 10, 58, 58, F1M1, false),
+            slot(42, 5, 57, 57, F1M1, false),
+            slot(42, 5, 58, 58, F1M1, false),
+            slot(505, 4, 59, 59, F1M1, false),
+            slot(510, 4, 59, 59, F1M1, false),
+            slot(505, 1, 59, 59, F1M1, false),
+            slot(510, 1, 59, 59, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "pinwheel_forest_exterior",
+        slots: &[
+            slot(536, 20, 54, 54, F1M1, true),
+            slot(533, 20, 54, 54, F1M3, false),
+            slot(454, 10, 55, 55, F1M1, true),
+            slot(193, 10, 55, 55, F1M1, true),
+            slot(533, 10, 55, 55, F1M3, false),
+            slot(533, 10, 56, 56, F1M3, false),
+            slot(538, 5, 55, 55, MaleOnly, true),
+            slot(538, 5, 55, 55, MaleOnly, true),
+            slot(536, 4, 57, 57, F1M1, true),
+            slot(538, 4, 57, 57, MaleOnly, true),
+            slot(536, 1, 57, 57, F1M1, true),
+            slot(538, 1, 57, 57, MaleOnly, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "pinwheel_forest_interior",
+        slots: &[
+            slot(548, 20, 54, 54, FemaleOnly, false),
+            slot(541, 20, 54, 54, F1M1, true),
+            slot(548, 10, 55, 55, FemaleOnly, false),
+            slot(288, 10, 55, 55, F1M1, false),
+            slot(548, 10, 56, 56, FemaleOnly, false),
+            slot(544, 10, 55, 55, F1M1, true),
+            slot(288, 5, 57, 57, F1M1, false),
+            slot(544, 5, 57, 57, F1M1, true),
+            slot(548, 4, 57, 57, FemaleOnly, false),
+            slot(541, 4, 56, 56, F1M1, true),
+            slot(548, 1, 57, 57, FemaleOnly, false),
+            slot(541, 1, 56, 56, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "desert_resort_exterior",
+        slots: &[
+            slot(551, 20, 18, 18, F1M1, false),
+            slot(554, 20, 18, 18, F1M1, true),
+            slot(551, 10, 19, 19, F1M1, false),
+            slot(554, 10, 19, 19, F1M1, true),
+            slot(556, 10, 19, 19, F1M1, true),
+            slot(559, 10, 19, 19, F1M1, true),
+            slot(27, 5, 19, 19, F1M1, true),
+            slot(557, 5, 19, 19, F1M1, true),
+            slot(27, 4, 20, 20, F1M1, true),
+            slot(557, 4, 21, 21, F1M1, true),
+            slot(27, 1, 20, 20, F1M1, true),
+            slot(557, 1, 21, 21, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "desert_resort_interior",
+        slots: &[
+            slot(551, 20, 18, 18, F1M1, false),
+            slot(554, 20, 18, 18, F1M1, true),
+            slot(551, 10, 19, 19, F1M1, false),
+            slot(27, 10, 19, 19, F1M1, true),
+            slot(556, 10, 19, 19, F1M1, true),
+            slot(561, 10, 19, 19, F1M1, false),
+            slot(27, 5, 20, 20, F1M1, true),
+            slot(557, 5, 19, 19, F1M1, true),
+            slot(328, 4, 21, 21, F1M1, true),
+            slot(557, 4, 21, 21, F1M1, true),
+            slot(328, 1, 21, 21, F1M1, true),
+            slot(557, 1, 21, 21, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "relic_castle_1f_b1f",
+        slots: &[
+            slot(551, 20, 18, 18, F1M1, false),
+            slot(562, 20, 18, 18, F1M1, true),
+            slot(551, 10, 19, 19, F1M1, false),
+            slot(562, 10, 19, 19, F1M1, true),
+            slot(27, 10, 19, 19, F1M1, true),
+            slot(27, 10, 20, 20, F1M1, true),
+            slot(551, 5, 20, 20, F1M1, false),
+            slot(562, 5, 20, 20, F1M1, true),
+            slot(551, 4, 21, 21, F1M1, false),
+            slot(562, 4, 21, 21, F1M1, true),
+            slot(551, 1, 21, 21, F1M1, false),
+            slot(562, 1, 21, 21, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "relic_castle_lowest_floor",
+        slots: &[
+            slot(562, 20, 27, 27, F1M1, true),
+            slot(551, 20, 27, 27, F1M1, false),
+            slot(562, 10, 28, 28, F1M1, true),
+            slot(551, 10, 28, 28, F1M1, false),
+            slot(28, 10, 28, 28, F1M1, true),
+            slot(28, 10, 29, 29, F1M1, true),
+            slot(562, 5, 29, 29, F1M1, true),
+            slot(552, 5, 29, 29, F1M1, false),
+            slot(562, 4, 30, 30, F1M1, true),
+            slot(552, 4, 30, 30, F1M1, false),
+            slot(562, 1, 30, 30, F1M1, true),
+            slot(552, 1, 30, 30, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "relic_castle_small_room",
+        slots: &[
+            slot(343, 20, 27, 27, Genderless, false),
+            slot(343, 20, 27, 27, Genderless, false),
+            slot(343, 10, 28, 28, Genderless, false),
+            slot(343, 10, 28, 28, Genderless, false),
+            slot(343, 10, 28, 28, Genderless, false),
+            slot(343, 10, 28, 28, Genderless, false),
+            slot(343, 5, 29, 29, Genderless, false),
+            slot(343, 5, 29, 29, Genderless, false),
+            slot(343, 4, 30, 30, Genderless, false),
+            slot(343, 4, 30, 30, Genderless, false),
+            slot(343, 1, 30, 30, Genderless, false),
+            slot(343, 1, 30, 30, Genderless, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "chargestone_cave_1f",
+        slots: &[
+            slot(595, 20, 25, 25, F1M1, false),
+            slot(599, 20, 26, 26, Genderless, false),
+            slot(595, 10, 26, 26, F1M1, false),
+            slot(299, 10, 27, 27, F1M1, true),
+            slot(597, 10, 26, 26, F1M1, true),
+            slot(597, 10, 27, 27, F1M1, true),
+            slot(595, 5, 27, 27, F1M1, false),
+            slot(525, 5, 25, 25, F1M1, true),
+            slot(595, 4, 28, 28, F1M1, false),
+            slot(599, 4, 28, 28, Genderless, false),
+            slot(602, 1, 28, 28, F1M1, false),
+            slot(602, 1, 28, 28, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "chargestone_cave_b1f",
+        slots: &[
+            slot(595, 20, 28, 28, F1M1, false),
+            slot(599, 20, 29, 29, Genderless, false),
+            slot(595, 10, 29, 29, F1M1, false),
+            slot(299, 10, 30, 30, F1M1, true),
+            slot(597, 10, 29, 29, F1M1, true),
+            slot(597, 10, 30, 30, F1M1, true),
+            slot(595, 5, 30, 30, F1M1, false),
+            slot(525, 5, 28, 28, F1M1, true),
+            slot(595, 4, 31, 31, F1M1, false),
+            slot(599, 4, 31, 31, Genderless, false),
+            slot(602, 1, 31, 31, F1M1, false),
+            slot(602, 1, 31, 31, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "chargestone_cave_b2f",
+        slots: &[
+            slot(595, 20, 28, 28, F1M1, false),
+            slot(599, 20, 29, 29, Genderless, false),
+            slot(595, 10, 29, 29, F1M1, false),
+            slot(299, 10, 30, 30, F1M1, true),
+            slot(597, 10, 30, 30, F1M1, true),
+            slot(597, 10, 31, 31, F1M1, true),
+            slot(595, 5, 30, 30, F1M1, false),
+            slot(525, 5, 28, 28, F1M1, true),
+            slot(602, 4, 31, 31, F1M1, false),
+            slot(602, 4, 31, 31, F1M1, false),
+            slot(595, 1, 31, 31, F1M1, false),
+            slot(599, 1, 31, 31, Genderless, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "twist_mountain_spring",
+        slots: &[
+            slot(525, 20, 54, 54, F1M1, true),
+            slot(525, 20, 55, 55, F1M1, true),
+            slot(533, 10, 55, 55, F1M3, false),
+            slot(527, 10, 54, 54, F1M1, false),
+            slot(632, 10, 56, 56, F1M1, false),
+            slot(614, 10, 56, 56, F1M1, true),
+            slot(631, 5, 56, 56, F1M1, true),
+            slot(632, 5, 57, 57, F1M1, false),
+            slot(615, 4, 57, 57, Genderless, true),
+            slot(95, 4, 57, 57, F1M1, false),
+            slot(615, 1, 57, 57, Genderless, true),
+            slot(95, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "twist_mountain_summer",
+        slots: &[
+            slot(525, 20, 54, 54, F1M1, true),
+            slot(533, 20, 55, 55, F1M3, false),
+            slot(631, 10, 55, 55, F1M1, true),
+            slot(527, 10, 54, 54, F1M1, false),
+            slot(632, 10, 56, 56, F1M1, false),
+            slot(631, 10, 56, 56, F1M1, true),
+            slot(614, 5, 56, 56, F1M1, true),
+            slot(632, 5, 57, 57, F1M1, false),
+            slot(615, 4, 57, 57, Genderless, true),
+            slot(95, 4, 57, 57, F1M1, false),
+            slot(615, 1, 57, 57, Genderless, true),
+            slot(95, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "twist_mountain_autumn",
+        slots: &[
+            slot(525, 20, 54, 54, F1M1, true),
+            slot(525, 20, 55, 55, F1M1, true),
+            slot(533, 10, 55, 55, F1M3, false),
+            slot(527, 10, 54, 54, F1M1, false),
+            slot(632, 10, 56, 56, F1M1, false),
+            slot(614, 10, 56, 56, F1M1, true),
+            slot(631, 5, 56, 56, F1M1, true),
+            slot(632, 5, 57, 57, F1M1, false),
+            slot(615, 4, 57, 57, Genderless, true),
+            slot(95, 4, 57, 57, F1M1, false),
+            slot(615, 1, 57, 57, Genderless, true),
+            slot(95, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "twist_mountain_winter",
+        slots: &[
+            slot(614, 20, 54, 54, F1M1, true),
+            slot(525, 20, 55, 55, F1M1, true),
+            slot(533, 10, 55, 55, F1M3, false),
+            slot(527, 10, 54, 54, F1M1, false),
+            slot(632, 10, 56, 56, F1M1, false),
+            slot(615, 10, 56, 56, Genderless, true),
+            slot(631, 5, 56, 56, F1M1, true),
+            slot(632, 5, 57, 57, F1M1, false),
+            slot(615, 4, 57, 57, Genderless, true),
+            slot(95, 4, 57, 57, F1M1, false),
+            slot(615, 1, 57, 57, Genderless, true),
+            slot(95, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "dragonspiral_tower_spring_exterior_south",
+        slots: &[
+            slot(520, 20, 55, 55, F1M1, false),
+            slot(586, 20, 55, 55, F1M1, false),
+            slot(620, 10, 56, 56, F1M1, false),
+            slot(586, 10, 57, 57, F1M1, false),
+            slot(620, 10, 57, 57, F1M1, false),
+            slot(520, 10, 57, 57, F1M1, false),
+            slot(620, 5, 58, 58, F1M1, false),
+            slot(520, 5, 58, 58, F1M1, false),
+            slot(586, 4, 58, 58, F1M1, false),
+            slot(621, 4, 58, 58, F1M1, true),
+            slot(586, 1, 58, 58, F1M1, false),
+            slot(621, 1, 58, 58, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "dragonspiral_tower_summer_exterior_south",
+        slots: &[
+            slot(520, 20, 55, 55, F1M1, false),
+            slot(586, 20, 55, 55, F1M1, false),
+            slot(620, 10, 56, 56, F1M1, false),
+            slot(586, 10, 57, 57, F1M1, false),
+            slot(620, 10, 57, 57, F1M1, false),
+            slot(520, 10, 57, 57, F1M1, false),
+            slot(620, 5, 58, 58, F1M1, false),
+            slot(520, 5, 58, 58, F1M1, false),
+            slot(586, 4, 58, 58, F1M1, false),
+            slot(621, 4, 58, 58, F1M1, true),
+            slot(586, 1, 58, 58, F1M1, false),
+            slot(621, 1, 58, 58, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "dragonspiral_tower_autumn_exterior_south",
+        slots: &[
+            slot(520, 20, 55, 55, F1M1, false),
+            slot(586, 20, 55, 55, F1M1, false),
+            slot(620, 10, 56, 56, F1M1, false),
+            slot(586, 10, 57, 57, F1M1, false),
+            slot(620, 10, 57, 57, F1M1, false),
+            slot(520, 10, 57, 57, F1M1, false),
+            slot(620, 5, 58, 58, F1M1, false),
+            slot(520, 5, 58, 58, F1M1, false),
+            slot(586, 4, 58, 58, F1M1, false),
+            slot(621, 4, 58, 58, F1M1, true),
+            slot(586, 1, 58, 58, F1M1, false),
+            slot(621, 1, 58, 58, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "dragonspiral_tower_winter_exterior_south",
+        slots: &[
+            slot(583, 20, 55, 55, F1M1, false),
+            slot(586, 20, 55, 55, F1M1, false),
+            slot(620, 10, 56, 56, F1M1, false),
+            slot(586, 10, 57, 57, F1M1, false),
+            slot(620, 10, 57, 57, F1M1, false),
+            slot(614, 10, 57, 57, F1M1, true),
+            slot(620, 5, 58, 58, F1M1, false),
+            slot(583, 5, 57, 57, F1M1, false),
+            slot(620, 4, 58, 58, F1M1, false),
+            slot(614, 4, 58, 58, F1M1, true),
+            slot(620, 1, 58, 58, F1M1, false),
+            slot(614, 1, 58, 58, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "dragonspiral_tower_winter_exterior_northeast",
+        slots: &[
+            slot(583, 20, 55, 55, F1M1, false),
+            slot(586, 20, 55, 55, F1M1, false),
+            slot(620, 10, 56, 56, F1M1, false),
+            slot(586, 10, 57, 57, F1M1, false),
+            slot(620, 10, 57, 57, F1M1, false),
+            slot(614, 10, 57, 57, F1M1, true),
+            slot(620, 5, 58, 58, F1M1, false),
+            slot(583, 5, 57, 57, F1M1, false),
+            slot(614, 4, 58, 58, F1M1, true),
+            slot(621, 4, 58, 58, F1M1, true),
+            slot(614, 1, 58, 58, F1M1, true),
+            slot(621, 1, 58, 58, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "dragonspiral_tower_1f",
+        slots: &[
+            slot(623, 20, 55, 55, Genderless, true),
+            slot(620, 20, 55, 55, F1M1, false),
+            slot(623, 10, 56, 56, Genderless, true),
+            slot(621, 10, 56, 56, F1M1, true),
+            slot(623, 10, 57, 57, Genderless, true),
+            slot(621, 10, 57, 57, F1M1, true),
+            slot(623, 5, 58, 58, Genderless, true),
+            slot(621, 5, 58, 58, F1M1, true),
+            slot(623, 4, 58, 58, Genderless, true),
+            slot(621, 4, 58, 58, F1M1, true),
+            slot(623, 1, 58, 58, Genderless, true),
+            slot(621, 1, 58, 58, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "dragonspiral_tower_2f",
+        slots: &[
+            slot(623, 20, 55, 55, Genderless, true),
+            slot(623, 20, 55, 55, Genderless, true),
+            slot(623, 10, 56, 56, Genderless, true),
+            slot(623, 10, 56, 56, Genderless, true),
+            slot(623, 10, 57, 57, Genderless, true),
+            slot(623, 10, 57, 57, Genderless, true),
+            slot(623, 5, 58, 58, Genderless, true),
+            slot(623, 5, 58, 58, Genderless, true),
+            slot(623, 4, 58, 58, Genderless, true),
+            slot(623, 4, 58, 58, Genderless, true),
+            slot(623, 1, 58, 58, Genderless, true),
+            slot(623, 1, 58, 58, Genderless, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "victory_road_1f",
+        slots: &[
+            slot(525, 20, 47, 47, F1M1, true),
+            slot(525, 20, 47, 47, F1M1, true),
+            slot(525, 10, 48, 48, F1M1, true),
+            slot(525, 10, 48, 48, F1M1, true),
+            slot(525, 10, 49, 49, F1M1, true),
+            slot(525, 10, 49, 49, F1M1, true),
+            slot(525, 5, 50, 50, F1M1, true),
+            slot(525, 5, 50, 50, F1M1, true),
+            slot(525, 4, 50, 50, F1M1, true),
+            slot(525, 4, 50, 50, F1M1, true),
+            slot(525, 1, 50, 50, F1M1, true),
+            slot(525, 1, 50, 50, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "victory_road_2f",
+        slots: &[
+            slot(354, 20, 47, 47, F1M1, true),
+            slot(354, 20, 47, 47, F1M1, true),
+            slot(354, 10, 48, 48, F1M1, true),
+            slot(623, 10, 48, 48, Genderless, true),
+            slot(354, 10, 49, 49, F1M1, true),
+            slot(623, 10, 49, 49, Genderless, true),
+            slot(354, 5, 50, 50, F1M1, true),
+            slot(623, 5, 50, 50, Genderless, true),
+            slot(354, 4, 50, 50, F1M1, true),
+            slot(623, 4, 50, 50, Genderless, true),
+            slot(354, 1, 50, 50, F1M1, true),
+            slot(623, 1, 50, 50, Genderless, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "victory_road_3f_4f_5f",
+        slots: &[
+            slot(548, 20, 47, 47, FemaleOnly, false),
+            slot(548, 20, 47, 47, FemaleOnly, false),
+            slot(548, 10, 48, 48, FemaleOnly, false),
+            slot(315, 10, 48, 48, F1M1, true),
+            slot(548, 10, 49, 49, FemaleOnly, false),
+            slot(315, 10, 49, 49, F1M1, true),
+            slot(548, 5, 50, 50, FemaleOnly, false),
+            slot(315, 5, 50, 50, F1M1, true),
+            slot(548, 4, 50, 50, FemaleOnly, false),
+            slot(315, 4, 50, 50, F1M1, true),
+            slot(548, 1, 50, 50, FemaleOnly, false),
+            slot(315, 1, 50, 50, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "victory_road_6f_7f",
+        slots: &[
+            slot(621, 20, 47, 47, F1M1, true),
+            slot(621, 20, 47, 47, F1M1, true),
+            slot(621, 10, 48, 48, F1M1, true),
+            slot(621, 10, 48, 48, F1M1, true),
+            slot(621, 10, 49, 49, F1M1, true),
+            slot(634, 10, 49, 49, F1M1, false),
+            slot(621, 5, 50, 50, F1M1, true),
+            slot(634, 5, 50, 50, F1M1, false),
+            slot(621, 4, 50, 50, F1M1, true),
+            slot(634, 4, 50, 50, F1M1, false),
+            slot(621, 1, 50, 50, F1M1, true),
+            slot(634, 1, 50, 50, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "giant_chasm_exterior",
+        slots: &[
+            slot(114, 20, 44, 44, F1M1, false),
+            slot(279, 20, 45, 45, F1M1, false),
+            slot(114, 10, 45, 45, F1M1, false),
+            slot(337, 10, 45, 45, Genderless, true),
+            slot(338, 10, 46, 46, Genderless, true),
+            slot(35, 10, 45, 45, F3M1, true),
+            slot(583, 5, 45, 45, F1M1, false),
+            slot(35, 5, 47, 47, F3M1, true),
+            slot(583, 4, 47, 47, F1M1, false),
+            slot(225, 4, 44, 44, F1M1, false),
+            slot(583, 1, 47, 47, F1M1, false),
+            slot(225, 1, 44, 44, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "giant_chasm_cave",
+        slots: &[
+            slot(215, 20, 44, 44, F1M1, true),
+            slot(221, 20, 44, 44, F1M1, false),
+            slot(583, 10, 45, 45, F1M1, false),
+            slot(35, 10, 44, 44, F3M1, true),
+            slot(337, 10, 46, 46, Genderless, true),
+            slot(338, 10, 46, 46, Genderless, true),
+            slot(35, 5, 46, 46, F3M1, true),
+            slot(583, 5, 47, 47, F1M1, false),
+            slot(221, 4, 46, 46, F1M1, false),
+            slot(225, 4, 47, 47, F1M1, false),
+            slot(221, 1, 46, 46, F1M1, false),
+            slot(225, 1, 47, 47, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "giant_chasm_crater_forest",
+        slots: &[
+            slot(221, 20, 44, 44, F1M1, false),
+            slot(35, 20, 45, 45, F3M1, true),
+            slot(221, 10, 46, 46, F1M1, false),
+            slot(132, 10, 45, 45, Genderless, true),
+            slot(337, 10, 46, 46, Genderless, true),
+            slot(338, 10, 46, 46, Genderless, true),
+            slot(132, 5, 47, 47, Genderless, true),
+            slot(375, 5, 45, 45, Genderless, true),
+            slot(225, 4, 44, 44, F1M1, false),
+            slot(375, 4, 47, 47, Genderless, true),
+            slot(225, 1, 44, 44, F1M1, false),
+            slot(375, 1, 47, 47, Genderless, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "castelia_sewers",
+        slots: &[
+            slot(19, 20, 14, 14, F1M1, false),
+            slot(41, 20, 14, 14, F1M1, false),
+            slot(19, 10, 15, 15, F1M1, false),
+            slot(41, 10, 15, 15, F1M1, false),
+            slot(19, 10, 16, 16, F1M1, false),
+            slot(41, 10, 16, 16, F1M1, false),
+            slot(88, 5, 15, 15, F1M1, true),
+            slot(88, 5, 17, 17, F1M1, true),
+            slot(19, 4, 17, 17, F1M1, false),
+            slot(41, 4, 17, 17, F1M1, false),
+            slot(19, 1, 17, 17, F1M1, false),
+            slot(41, 1, 17, 17, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "p2_laboratory",
+        slots: &[
+            slot(505, 20, 56, 56, F1M1, false),
+            slot(507, 20, 56, 56, F1M1, false),
+            slot(110, 10, 57, 57, F1M1, true),
+            slot(82, 10, 57, 57, Genderless, true),
+            slot(600, 10, 57, 57, Genderless, false),
+            slot(560, 10, 57, 57, F1M1, true),
+            slot(82, 5, 59, 59, Genderless, true),
+            slot(600, 5, 59, 59, Genderless, false),
+            slot(560, 4, 58, 58, F1M1, true),
+            slot(110, 4, 58, 58, F1M1, true),
+            slot(560, 1, 58, 58, F1M1, true),
+            slot(110, 1, 58, 58, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "floccesy_ranch",
+        slots: &[
+            slot(506, 20, 4, 4, F1M1, false),
+            slot(298, 20, 5, 5, F3M1, false),
+            slot(504, 10, 5, 5, F1M1, false),
+            slot(179, 10, 5, 5, F1M1, false),
+            slot(506, 10, 5, 5, F1M1, false),
+            slot(54, 10, 5, 5, F1M1, false),
+            slot(506, 5, 6, 6, F1M1, false),
+            slot(519, 5, 7, 7, F1M1, false),
+            slot(447, 4, 5, 5, F1M7, false),
+            slot(506, 4, 7, 7, F1M1, false),
+            slot(447, 1, 7, 7, F1M7, false),
+            slot(506, 1, 7, 7, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "virbank_complex_exterior",
+        slots: &[
+            slot(504, 20, 10, 10, F1M1, false),
+            slot(519, 20, 10, 10, F1M1, false),
+            slot(239, 10, 10, 10, F1M3, false),
+            slot(81, 10, 10, 10, Genderless, true),
+            slot(504, 10, 11, 11, F1M1, false),
+            slot(519, 10, 11, 11, F1M1, false),
+            slot(239, 5, 11, 11, F1M3, false),
+            slot(81, 5, 11, 11, Genderless, true),
+            slot(504, 4, 12, 12, F1M1, false),
+            slot(519, 4, 12, 12, F1M1, false),
+            slot(504, 1, 13, 13, F1M1, false),
+            slot(519, 1, 13, 13, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "virbank_complex_interior",
+        slots: &[
+            slot(239, 20, 10, 10, F1M3, false),
+            slot(81, 20, 10, 10, Genderless, true),
+            slot(504, 10, 10, 10, F1M1, false),
+            slot(109, 10, 10, 10, F1M1, true),
+            slot(58, 10, 11, 11, F1M3, true),
+            slot(58, 10, 13, 13, F1M3, true),
+            slot(239, 5, 12, 12, F1M3, false),
+            slot(81, 5, 12, 12, Genderless, true),
+            slot(504, 4, 12, 12, F1M1, false),
+            slot(109, 4, 12, 12, F1M1, true),
+            slot(504, 1, 13, 13, F1M1, false),
+            slot(109, 1, 13, 13, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "reversal_mountain_exterior",
+        slots: &[
+            slot(451, 20, 31, 31, F1M1, true),
+            slot(322, 20, 31, 31, F1M1, true),
+            slot(328, 10, 32, 32, F1M1, true),
+            slot(451, 10, 33, 33, F1M1, true),
+            slot(426, 10, 32, 32, F1M1, true),
+            slot(426, 10, 33, 33, F1M1, true),
+            slot(227, 5, 34, 34, F1M1, false),
+            slot(323, 5, 33, 33, F1M1, true),
+            slot(328, 4, 34, 34, F1M1, true),
+            slot(323, 4, 33, 33, F1M1, true),
+            slot(328, 1, 34, 34, F1M1, true),
+            slot(323, 1, 33, 33, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "reversal_mountain_interior_entrance",
+        slots: &[
+            slot(322, 20, 31, 31, F1M1, true),
+            slot(527, 20, 32, 32, F1M1, false),
+            slot(525, 10, 32, 32, F1M1, true),
+            slot(451, 10, 33, 33, F1M1, true),
+            slot(525, 10, 34, 34, F1M1, true),
+            slot(527, 10, 34, 34, F1M1, false),
+            slot(323, 5, 35, 35, F1M1, true),
+            slot(451, 5, 35, 35, F1M1, true),
+            slot(527, 4, 35, 35, F1M1, false),
+            slot(525, 4, 35, 35, F1M1, true),
+            slot(527, 1, 35, 35, F1M1, false),
+            slot(525, 1, 35, 35, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "reversal_mountain_interior_main",
+        slots: &[
+            slot(323, 20, 35, 35, F1M1, true),
+            slot(527, 20, 32, 32, F1M1, false),
+            slot(525, 10, 32, 32, F1M1, true),
+            slot(451, 10, 33, 33, F1M1, true),
+            slot(525, 10, 34, 34, F1M1, true),
+            slot(527, 10, 34, 34, F1M1, false),
+            slot(322, 5, 31, 31, F1M1, true),
+            slot(451, 5, 35, 35, F1M1, true),
+            slot(527, 4, 35, 35, F1M1, false),
+            slot(525, 4, 35, 35, F1M1, true),
+            slot(527, 1, 35, 35, F1M1, false),
+            slot(525, 1, 35, 35, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "strange_house_entrance_b1f",
+        slots: &[
+            slot(607, 20, 31, 31, F1M1, false),
+            slot(577, 20, 31, 31, F1M1, true),
+            slot(20, 10, 32, 32, F1M1, false),
+            slot(42, 10, 32, 32, F1M1, false),
+            slot(607, 10, 33, 33, F1M1, false),
+            slot(354, 10, 32, 32, F1M1, true),
+            slot(20, 5, 33, 33, F1M1, false),
+            slot(42, 5, 33, 33, F1M1, false),
+            slot(354, 4, 34, 34, F1M1, true),
+            slot(578, 4, 34, 34, F1M1, true),
+            slot(354, 1, 34, 34, F1M1, true),
+            slot(578, 1, 34, 34, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "strange_house_small_room",
+        slots: &[
+            slot(607, 20, 31, 31, F1M1, false),
+            slot(577, 20, 31, 31, F1M1, true),
+            slot(20, 10, 32, 32, F1M1, false),
+            slot(42, 10, 32, 32, F1M1, false),
+            slot(578, 10, 33, 33, F1M1, true),
+            slot(354, 10, 32, 32, F1M1, true),
+            slot(20, 5, 33, 33, F1M1, false),
+            slot(42, 5, 33, 33, F1M1, false),
+            slot(354, 4, 34, 34, F1M1, true),
+            slot(578, 4, 34, 34, F1M1, true),
+            slot(354, 1, 34, 34, F1M1, true),
+            slot(578, 1, 34, 34, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "relic_passage_south",
+        slots: &[
+            slot(524, 20, 16, 16, F1M1, true),
+            slot(527, 20, 16, 16, F1M1, false),
+            slot(524, 10, 17, 17, F1M1, true),
+            slot(527, 10, 17, 17, F1M1, false),
+            slot(532, 10, 17, 17, F1M3, false),
+            slot(532, 10, 18, 18, F1M3, false),
+            slot(19, 5, 17, 17, F1M1, false),
+            slot(19, 5, 17, 17, F1M1, false),
+            slot(524, 4, 18, 18, F1M1, true),
+            slot(95, 4, 18, 18, F1M1, false),
+            slot(527, 1, 18, 18, F1M1, false),
+            slot(95, 1, 18, 18, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "relic_passage_north",
+        slots: &[
+            slot(525, 20, 27, 27, F1M1, true),
+            slot(527, 20, 27, 27, F1M1, false),
+            slot(525, 10, 28, 28, F1M1, true),
+            slot(527, 10, 28, 28, F1M1, false),
+            slot(533, 10, 28, 28, F1M3, false),
+            slot(533, 10, 30, 30, F1M3, false),
+            slot(20, 5, 29, 29, F1M1, false),
+            slot(20, 5, 29, 29, F1M1, false),
+            slot(525, 4, 30, 30, F1M1, true),
+            slot(95, 4, 30, 30, F1M1, false),
+            slot(527, 1, 30, 30, F1M1, false),
+            slot(95, 1, 30, 30, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "relic_passage_central",
+        slots: &[
+            slot(525, 20, 27, 27, F1M1, true),
+            slot(527, 20, 27, 27, F1M1, false),
+            slot(525, 10, 28, 28, F1M1, true),
+            slot(527, 10, 28, 28, F1M1, false),
+            slot(533, 10, 28, 28, F1M3, false),
+            slot(533, 10, 30, 30, F1M3, false),
+            slot(525, 5, 29, 29, F1M1, true),
+            slot(527, 5, 29, 29, F1M1, false),
+            slot(525, 4, 30, 30, F1M1, true),
+            slot(95, 4, 30, 30, F1M1, false),
+            slot(527, 1, 30, 30, F1M1, false),
+            slot(95, 1, 30, 30, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "clay_tunnel",
+        slots: &[
+            slot(525, 20, 54, 54, F1M1, true),
+            slot(632, 20, 54, 54, F1M1, false),
+            slot(525, 10, 55, 55, F1M1, true),
+            slot(527, 10, 54, 54, F1M1, false),
+            slot(305, 10, 55, 55, F1M1, true),
+            slot(299, 10, 55, 55, F1M1, true),
+            slot(305, 5, 57, 57, F1M1, true),
+            slot(299, 5, 57, 57, F1M1, true),
+            slot(525, 4, 56, 56, F1M1, true),
+            slot(95, 4, 57, 57, F1M1, false),
+            slot(525, 1, 56, 56, F1M1, true),
+            slot(95, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "underground_ruins",
+        slots: &[
+            slot(525, 20, 54, 54, F1M1, true),
+            slot(632, 20, 54, 54, F1M1, false),
+            slot(525, 10, 55, 55, F1M1, true),
+            slot(527, 10, 54, 54, F1M1, false),
+            slot(305, 10, 55, 55, F1M1, true),
+            slot(299, 10, 55, 55, F1M1, true),
+            slot(305, 5, 57, 57, F1M1, true),
+            slot(299, 5, 57, 57, F1M1, true),
+            slot(525, 4, 56, 56, F1M1, true),
+            slot(95, 4, 57, 57, F1M1, false),
+            slot(525, 1, 56, 56, F1M1, true),
+            slot(95, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "rocky_mountain_chamber",
+        slots: &[
+            slot(525, 20, 54, 54, F1M1, true),
+            slot(632, 20, 54, 54, F1M1, false),
+            slot(525, 10, 55, 55, F1M1, true),
+            slot(527, 10, 54, 54, F1M1, false),
+            slot(305, 10, 55, 55, F1M1, true),
+            slot(299, 10, 55, 55, F1M1, true),
+            slot(305, 5, 57, 57, F1M1, true),
+            slot(299, 5, 57, 57, F1M1, true),
+            slot(525, 4, 56, 56, F1M1, true),
+            slot(95, 4, 57, 57, F1M1, false),
+            slot(525, 1, 56, 56, F1M1, true),
+            slot(95, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "iceberg_chamber",
+        slots: &[
+            slot(525, 20, 54, 54, F1M1, true),
+            slot(632, 20, 54, 54, F1M1, false),
+            slot(525, 10, 55, 55, F1M1, true),
+            slot(527, 10, 54, 54, F1M1, false),
+            slot(305, 10, 55, 55, F1M1, true),
+            slot(299, 10, 55, 55, F1M1, true),
+            slot(305, 5, 57, 57, F1M1, true),
+            slot(299, 5, 57, 57, F1M1, true),
+            slot(525, 4, 56, 56, F1M1, true),
+            slot(95, 4, 57, 57, F1M1, false),
+            slot(525, 1, 56, 56, F1M1, true),
+            slot(95, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "iron_chamber",
+        slots: &[
+            slot(525, 20, 54, 54, F1M1, true),
+            slot(632, 20, 54, 54, F1M1, false),
+            slot(525, 10, 55, 55, F1M1, true),
+            slot(527, 10, 54, 54, F1M1, false),
+            slot(305, 10, 55, 55, F1M1, true),
+            slot(299, 10, 55, 55, F1M1, true),
+            slot(305, 5, 57, 57, F1M1, true),
+            slot(299, 5, 57, 57, F1M1, true),
+            slot(525, 4, 56, 56, F1M1, true),
+            slot(95, 4, 57, 57, F1M1, false),
+            slot(525, 1, 56, 56, F1M1, true),
+            slot(95, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "seaside_cave_1f",
+        slots: &[
+            slot(527, 20, 34, 34, F1M1, false),
+            slot(55, 20, 34, 34, F1M1, false),
+            slot(86, 10, 35, 35, F1M1, false),
+            slot(525, 10, 35, 35, F1M1, true),
+            slot(527, 10, 35, 35, F1M1, false),
+            slot(55, 10, 35, 35, F1M1, false),
+            slot(602, 5, 37, 37, F1M1, false),
+            slot(525, 5, 37, 37, F1M1, true),
+            slot(55, 4, 36, 36, F1M1, false),
+            slot(527, 4, 36, 36, F1M1, false),
+            slot(55, 1, 36, 36, F1M1, false),
+            slot(527, 1, 36, 36, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "seaside_cave_b1f",
+        slots: &[
+            slot(527, 20, 39, 39, F1M1, false),
+            slot(525, 20, 39, 39, F1M1, true),
+            slot(55, 10, 40, 40, F1M1, false),
+            slot(525, 10, 40, 40, F1M1, true),
+            slot(527, 10, 40, 40, F1M1, false),
+            slot(55, 10, 40, 40, F1M1, false),
+            slot(603, 5, 42, 42, F1M1, false),
+            slot(525, 5, 42, 42, F1M1, true),
+            slot(213, 4, 41, 41, F1M1, true),
+            slot(527, 4, 41, 41, F1M1, false),
+            slot(213, 1, 41, 41, F1M1, true),
+            slot(527, 1, 41, 41, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "nature_preserve",
+        slots: &[
+            slot(274, 20, 56, 56, F1M1, false),
+            slot(334, 20, 56, 56, F1M1, false),
+            slot(274, 10, 57, 57, F1M1, false),
+            slot(55, 10, 57, 57, F1M1, false),
+            slot(164, 10, 58, 58, F1M1, false),
+            slot(203, 10, 58, 58, F1M1, true),
+            slot(611, 5, 57, 57, F1M1, false),
+            slot(203, 5, 59, 59, F1M1, true),
+            slot(611, 4, 59, 59, F1M1, false),
+            slot(352, 4, 57, 57, F1M1, true),
+            slot(611, 1, 59, 59, F1M1, false),
+            slot(352, 1, 59, 59, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "village_bridge",
+        slots: &[
+            slot(55, 20, 36, 36, F1M1, false),
+            slot(183, 20, 36, 36, F1M1, false),
+            slot(335, 10, 37, 37, F1M1, true),
+            slot(336, 10, 37, 37, F1M1, false),
+            slot(55, 10, 37, 37, F1M1, false),
+            slot(183, 10, 37, 37, F1M1, false),
+            slot(55, 5, 38, 38, F1M1, false),
+            slot(183, 5, 38, 38, F1M1, false),
+            slot(335, 4, 39, 39, F1M1, true),
+            slot(336, 4, 39, 39, F1M1, false),
+            slot(335, 1, 39, 39, F1M1, true),
+            slot(336, 1, 39, 39, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "route_1",
+        slots: &[
+            slot(505, 20, 56, 56, F1M1, false),
+            slot(507, 20, 56, 56, F1M1, false),
+            slot(39, 10, 57, 57, F3M1, false),
+            slot(507, 10, 57, 57, F1M1, false),
+            slot(505, 10, 58, 58, F1M1, false),
+            slot(507, 10, 58, 58, F1M1, false),
+            slot(505, 5, 59, 59, F1M1, false),
+            slot(507, 5, 59, 59, F1M1, false),
+            slot(505, 4, 59, 59, F1M1, false),
+            slot(507, 4, 59, 59, F1M1, false),
+            slot(505, 1, 59, 59, F1M1, false),
+            slot(507, 1, 59, 59, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "route_2",
+        slots: &[
+            slot(505, 20, 56, 56, F1M1, false),
+            slot(507, 20, 56, 56, F1M1, false),
+            slot(39, 10, 57, 57, F3M1, false),
+            slot(108, 10, 58, 58, F1M1, true),
+            slot(510, 10, 57, 57, F1M1, false),
+            slot(510, 10, 58, 58, F1M1, false),
+            slot(505, 5, 59, 59, F1M1, false),
+            slot(507, 5, 59, 59, F1M1, false),
+            slot(505, 4, 59, 59, F1M1, false),
+            slot(507, 4, 59, 59, F1M1, false),
+            slot(505, 1, 59, 59, F1M1, false),
+            slot(507, 1, 59, 59, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "route_3",
+        slots: &[
+            slot(505, 20, 55, 55, F1M1, false),
+            slot(520, 20, 55, 55, F1M1, false),
+            slot(523, 10, 56, 56, F1M1, true),
+            slot(193, 10, 56, 56, F1M1, true),
+            slot(507, 10, 57, 57, F1M1, false),
+            slot(509, 10, 57, 57, F1M1, false),
+            slot(523, 5, 58, 58, F1M1, true),
+            slot(520, 5, 57, 57, F1M1, false),
+            slot(523, 4, 58, 58, F1M1, true),
+            slot(520, 4, 57, 57, F1M1, false),
+            slot(523, 1, 58, 58, F1M1, true),
+            slot(520, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "wellspring_cave",
+        slots: &[
+            slot(525, 20, 55, 55, F1M1, true),
+            slot(527, 20, 55, 55, F1M1, false),
+            slot(525, 10, 56, 56, F1M1, true),
+            slot(527, 10, 56, 56, F1M1, false),
+            slot(525, 10, 57, 57, F1M1, true),
+            slot(527, 10, 57, 57, F1M1, false),
+            slot(525, 5, 58, 58, F1M1, true),
+            slot(527, 5, 58, 58, F1M1, false),
+            slot(525, 4, 58, 58, F1M1, true),
+            slot(527, 4, 58, 58, F1M1, false),
+            slot(525, 1, 58, 58, F1M1, true),
+            slot(527, 1, 58, 58, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "route_4",
+        slots: &[
+            slot(551, 20, 14, 14, F1M1, false),
+            slot(554, 20, 14, 14, F1M1, true),
+            slot(551, 10, 15, 15, F1M1, false),
+            slot(554, 10, 15, 15, F1M1, true),
+            slot(572, 10, 15, 15, F3M1, true),
+            slot(572, 10, 14, 14, F3M1, true),
+            slot(559, 5, 17, 17, F1M1, true),
+            slot(572, 5, 17, 17, F3M1, true),
+            slot(551, 4, 16, 16, F1M1, false),
+            slot(554, 4, 16, 16, F1M1, true),
+            slot(551, 1, 17, 17, F1M1, false),
+            slot(554, 1, 17, 17, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "route_5",
+        slots: &[
+            slot(577, 20, 21, 21, F1M1, true),
+            slot(572, 20, 21, 21, F3M1, true),
+            slot(568, 10, 21, 21, F1M1, true),
+            slot(572, 10, 23, 23, F3M1, true),
+            slot(510, 10, 22, 22, F1M1, false),
+            slot(510, 10, 24, 24, F1M1, false),
+            slot(568, 5, 22, 22, F1M1, true),
+            slot(577, 5, 22, 22, F1M1, true),
+            slot(568, 4, 23, 23, F1M1, true),
+            slot(577, 4, 23, 23, F1M1, true),
+            slot(568, 1, 24, 24, F1M1, true),
+            slot(577, 1, 24, 24, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "route_6_spring",
+        slots: &[
+            slot(585, 20, 23, 23, F1M1, false),
+            slot(588, 20, 23, 23, F1M1, false),
+            slot(520, 10, 26, 26, F1M1, false),
+            slot(590, 10, 26, 26, F1M1, true),
+            slot(585, 10, 25, 25, F1M1, false),
+            slot(541, 10, 26, 26, F1M1, true),
+            slot(183, 5, 25, 25, F1M1, false),
+            slot(616, 5, 23, 23, F1M1, false),
+            slot(520, 4, 26, 26, F1M1, false),
+            slot(588, 4, 26, 26, F1M1, false),
+            slot(520, 1, 26, 26, F1M1, false),
+            slot(588, 1, 26, 26, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "route_6_summer",
+        slots: &[
+            slot(585, 20, 23, 23, F1M1, false),
+            slot(588, 20, 23, 23, F1M1, false),
+            slot(520, 10, 26, 26, F1M1, false),
+            slot(590, 10, 26, 26, F1M1, true),
+            slot(585, 10, 25, 25, F1M1, false),
+            slot(541, 10, 26, 26, F1M1, true),
+            slot(183, 5, 25, 25, F1M1, false),
+            slot(616, 5, 23, 23, F1M1, false),
+            slot(520, 4, 26, 26, F1M1, false),
+            slot(588, 4, 26, 26, F1M1, false),
+            slot(520, 1, 26, 26, F1M1, false),
+            slot(588, 1, 26, 26, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "route_6_autumn",
+        slots: &[
+            slot(585, 20, 23, 23, F1M1, false),
+            slot(588, 20, 23, 23, F1M1, false),
+            slot(520, 10, 26, 26, F1M1, false),
+            slot(590, 10, 26, 26, F1M1, true),
+            slot(585, 10, 25, 25, F1M1, false),
+            slot(541, 10, 26, 26, F1M1, true),
+            slot(183, 5, 25, 25, F1M1, false),
+            slot(616, 5, 23, 23, F1M1, false),
+            slot(520, 4, 26, 26, F1M1, false),
+            slot(588, 4, 26, 26, F1M1, false),
+            slot(520, 1, 26, 26, F1M1, false),
+            slot(588, 1, 26, 26, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "route_6_winter",
+        slots: &[
+            slot(585, 20, 23, 23, F1M1, false),
+            slot(588, 20, 23, 23, F1M1, false),
+            slot(520, 10, 26, 26, F1M1, false),
+            slot(590, 10, 26, 26, F1M1, true),
+            slot(585, 10, 25, 25, F1M1, false),
+            slot(541, 10, 26, 26, F1M1, true),
+            slot(183, 5, 25, 25, F1M1, false),
+            slot(616, 5, 23, 23, F1M1, false),
+            slot(520, 4, 26, 26, F1M1, false),
+            slot(588, 4, 26, 26, F1M1, false),
+            slot(520, 1, 26, 26, F1M1, false),
+            slot(588, 1, 26, 26, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "mistralton_cave",
+        slots: &[
+            slot(525, 20, 27, 27, F1M1, true),
+            slot(527, 20, 27, 27, F1M1, false),
+            slot(525, 10, 28, 28, F1M1, true),
+            slot(527, 10, 28, 28, F1M1, false),
+            slot(304, 10, 29, 29, F1M1, true),
+            slot(610, 10, 29, 29, F1M1, false),
+            slot(304, 5, 30, 30, F1M1, true),
+            slot(610, 5, 30, 30, F1M1, false),
+            slot(304, 4, 30, 30, F1M1, true),
+            slot(610, 4, 30, 30, F1M1, false),
+            slot(304, 1, 30, 30, F1M1, true),
+            slot(610, 1, 30, 30, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "guidance_chamber",
+        slots: &[
+            slot(525, 20, 27, 27, F1M1, true),
+            slot(527, 20, 27, 27, F1M1, false),
+            slot(525, 10, 28, 28, F1M1, true),
+            slot(527, 10, 28, 28, F1M1, false),
+            slot(304, 10, 29, 29, F1M1, true),
+            slot(610, 10, 29, 29, F1M1, false),
+            slot(304, 5, 30, 30, F1M1, true),
+            slot(610, 5, 30, 30, F1M1, false),
+            slot(304, 4, 30, 30, F1M1, true),
+            slot(610, 4, 30, 30, F1M1, false),
+            slot(304, 1, 30, 30, F1M1, true),
+            slot(610, 1, 30, 30, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "route_7_spring",
+        slots: &[
+            slot(520, 20, 30, 30, F1M1, false),
+            slot(585, 20, 30, 30, F1M1, false),
+            slot(505, 10, 31, 31, F1M1, false),
+            slot(523, 10, 31, 31, F1M1, true),
+            slot(520, 10, 32, 32, F1M1, false),
+            slot(523, 10, 33, 33, F1M1, true),
+            slot(335, 5, 32, 32, F1M1, true),
+            slot(336, 5, 32, 32, F1M1, false),
+            slot(505, 4, 33, 33, F1M1, false),
+            slot(590, 4, 33, 33, F1M1, true),
+            slot(505, 1, 33, 33, F1M1, false),
+            slot(590, 1, 33, 33, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "route_7_summer",
+        slots: &[
+            slot(520, 20, 30, 30, F1M1, false),
+            slot(585, 20, 30, 30, F1M1, false),
+            slot(505, 10, 31, 31, F1M1, false),
+            slot(523, 10, 31, 31, F1M1, true),
+            slot(520, 10, 32, 32, F1M1, false),
+            slot(523, 10, 33, 33, F1M1, true),
+            slot(335, 5, 32, 32, F1M1, true),
+            slot(336, 5, 32, 32, F1M1, false),
+            slot(505, 4, 33, 33, F1M1, false),
+            slot(590, 4, 33, 33, F1M1, true),
+            slot(505, 1, 33, 33, F1M1, false),
+            slot(590, 1, 33, 33, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "route_7_autumn",
+        slots: &[
+            slot(520, 20, 30, 30, F1M1, false),
+            slot(585, 20, 30, 30, F1M1, false),
+            slot(505, 10, 31, 31, F1M1, false),
+            slot(523, 10, 31, 31, F1M1, true),
+            slot(520, 10, 32, 32, F1M1, false),
+            slot(523, 10, 33, 33, F1M1, true),
+            slot(335, 5, 32, 32, F1M1, true),
+            slot(336, 5, 32, 32, F1M1, false),
+            slot(505, 4, 33, 33, F1M1, false),
+            slot(590, 4, 33, 33, F1M1, true),
+            slot(505, 1, 33, 33, F1M1, false),
+            slot(590, 1, 33, 33, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "route_7_winter",
+        slots: &[
+            slot(613, 20, 30, 30, F1M1, true),
+            slot(585, 20, 30, 30, F1M1, false),
+            slot(505, 10, 31, 31, F1M1, false),
+            slot(523, 10, 31, 31, F1M1, true),
+            slot(520, 10, 30, 30, F1M1, false),
+            slot(523, 10, 33, 33, F1M1, true),
+            slot(335, 5, 32, 32, F1M1, true),
+            slot(336, 5, 32, 32, F1M1, false),
+            slot(613, 4, 33, 33, F1M1, true),
+            slot(590, 4, 33, 33, F1M1, true),
+            slot(613, 1, 33, 33, F1M1, true),
+            slot(590, 1, 33, 33, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "celestial_tower_2f",
+        slots: &[
+            slot(607, 20, 27, 27, F1M1, false),
+            slot(607, 20, 27, 27, F1M1, false),
+            slot(607, 10, 28, 28, F1M1, false),
+            slot(607, 10, 28, 28, F1M1, false),
+            slot(607, 10, 29, 29, F1M1, false),
+            slot(607, 10, 29, 29, F1M1, false),
+            slot(607, 5, 30, 30, F1M1, false),
+            slot(607, 5, 30, 30, F1M1, false),
+            slot(607, 4, 30, 30, F1M1, false),
+            slot(607, 4, 30, 30, F1M1, false),
+            slot(607, 1, 30, 30, F1M1, false),
+            slot(607, 1, 30, 30, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "celestial_tower_3f",
+        slots: &[
+            slot(607, 20, 28, 28, F1M1, false),
+            slot(607, 20, 28, 28, F1M1, false),
+            slot(607, 10, 29, 29, F1M1, false),
+            slot(607, 10, 29, 29, F1M1, false),
+            slot(607, 10, 30, 30, F1M1, false),
+            slot(605, 10, 30, 30, F1M1, false),
+            slot(607, 5, 31, 31, F1M1, false),
+            slot(42, 5, 31, 31, F1M1, false),
+            slot(607, 4, 31, 31, F1M1, false),
+            slot(605, 4, 31, 31, F1M1, false),
+            slot(607, 1, 31, 31, F1M1, false),
+            slot(605, 1, 31, 31, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "celestial_tower_4f",
+        slots: &[
+            slot(607, 20, 29, 29, F1M1, false),
+            slot(607, 20, 29, 29, F1M1, false),
+            slot(607, 10, 30, 30, F1M1, false),
+            slot(605, 10, 30, 30, F1M1, false),
+            slot(607, 10, 31, 31, F1M1, false),
+            slot(605, 10, 31, 31, F1M1, false),
+            slot(42, 5, 32, 32, F1M1, false),
+            slot(42, 5, 32, 32, F1M1, false),
+            slot(607, 4, 32, 32, F1M1, false),
+            slot(605, 4, 32, 32, F1M1, false),
+            slot(607, 1, 32, 32, F1M1, false),
+            slot(605, 1, 32, 32, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "celestial_tower_5f",
+        slots: &[
+            slot(607, 20, 30, 30, F1M1, false),
+            slot(605, 20, 30, 30, F1M1, false),
+            slot(607, 10, 31, 31, F1M1, false),
+            slot(605, 10, 31, 31, F1M1, false),
+            slot(42, 10, 33, 33, F1M1, false),
+            slot(42, 10, 33, 33, F1M1, false),
+            slot(607, 5, 32, 32, F1M1, false),
+            slot(605, 5, 32, 32, F1M1, false),
+            slot(607, 4, 33, 33, F1M1, false),
+            slot(605, 4, 33, 33, F1M1, false),
+            slot(607, 1, 33, 33, F1M1, false),
+            slot(605, 1, 33, 33, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "route_8_spring",
+        slots: &[
+            slot(536, 20, 54, 54, F1M1, true),
+            slot(588, 20, 54, 54, F1M1, false),
+            slot(536, 10, 55, 55, F1M1, true),
+            slot(453, 10, 55, 55, F1M1, true),
+            slot(618, 10, 55, 55, F1M1, true),
+            slot(618, 10, 56, 56, F1M1, true),
+            slot(536, 5, 56, 56, F1M1, true),
+            slot(453, 5, 56, 56, F1M1, true),
+            slot(536, 4, 57, 57, F1M1, true),
+            slot(616, 4, 57, 57, F1M1, false),
+            slot(536, 1, 57, 57, F1M1, true),
+            slot(616, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "route_8_summer",
+        slots: &[
+            slot(536, 20, 54, 54, F1M1, true),
+            slot(588, 20, 54, 54, F1M1, false),
+            slot(536, 10, 55, 55, F1M1, true),
+            slot(453, 10, 55, 55, F1M1, true),
+            slot(618, 10, 55, 55, F1M1, true),
+            slot(618, 10, 56, 56, F1M1, true),
+            slot(536, 5, 56, 56, F1M1, true),
+            slot(453, 5, 56, 56, F1M1, true),
+            slot(536, 4, 57, 57, F1M1, true),
+            slot(616, 4, 57, 57, F1M1, false),
+            slot(536, 1, 57, 57, F1M1, true),
+            slot(616, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "route_8_autumn",
+        slots: &[
+            slot(536, 20, 54, 54, F1M1, true),
+            slot(588, 20, 54, 54, F1M1, false),
+            slot(536, 10, 55, 55, F1M1, true),
+            slot(453, 10, 55, 55, F1M1, true),
+            slot(618, 10, 55, 55, F1M1, true),
+            slot(618, 10, 56, 56, F1M1, true),
+            slot(536, 5, 56, 56, F1M1, true),
+            slot(453, 5, 56, 56, F1M1, true),
+            slot(536, 4, 57, 57, F1M1, true),
+            slot(616, 4, 57, 57, F1M1, false),
+            slot(536, 1, 57, 57, F1M1, true),
+            slot(616, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "moor_of_icirrus_spring",
+        slots: &[
+            slot(536, 20, 54, 54, F1M1, true),
+            slot(588, 20, 54, 54, F1M1, false),
+            slot(536, 10, 55, 55, F1M1, true),
+            slot(453, 10, 55, 55, F1M1, true),
+            slot(618, 10, 55, 55, F1M1, true),
+            slot(618, 10, 56, 56, F1M1, true),
+            slot(536, 5, 56, 56, F1M1, true),
+            slot(453, 5, 56, 56, F1M1, true),
+            slot(536, 4, 57, 57, F1M1, true),
+            slot(616, 4, 57, 57, F1M1, false),
+            slot(536, 1, 57, 57, F1M1, true),
+            slot(616, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "moor_of_icirrus_summer",
+        slots: &[
+            slot(536, 20, 54, 54, F1M1, true),
+            slot(588, 20, 54, 54, F1M1, false),
+            slot(536, 10, 55, 55, F1M1, true),
+            slot(453, 10, 55, 55, F1M1, true),
+            slot(618, 10, 55, 55, F1M1, true),
+            slot(618, 10, 56, 56, F1M1, true),
+            slot(536, 5, 56, 56, F1M1, true),
+            slot(453, 5, 56, 56, F1M1, true),
+            slot(536, 4, 57, 57, F1M1, true),
+            slot(616, 4, 57, 57, F1M1, false),
+            slot(536, 1, 57, 57, F1M1, true),
+            slot(616, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "moor_of_icirrus_autumn",
+        slots: &[
+            slot(536, 20, 54, 54, F1M1, true),
+            slot(588, 20, 54, 54, F1M1, false),
+            slot(536, 10, 55, 55, F1M1, true),
+            slot(453, 10, 55, 55, F1M1, true),
+            slot(618, 10, 55, 55, F1M1, true),
+            slot(618, 10, 56, 56, F1M1, true),
+            slot(536, 5, 56, 56, F1M1, true),
+            slot(453, 5, 56, 56, F1M1, true),
+            slot(536, 4, 57, 57, F1M1, true),
+            slot(616, 4, 57, 57, F1M1, false),
+            slot(536, 1, 57, 57, F1M1, true),
+            slot(616, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "route_9",
+        slots: &[
+            slot(578, 20, 37, 37, F1M1, true),
+            slot(572, 20, 37, 37, F3M1, true),
+            slot(569, 10, 38, 38, F1M1, true),
+            slot(572, 10, 39, 39, F3M1, true),
+            slot(624, 10, 38, 38, F1M1, false),
+            slot(510, 10, 40, 40, F1M1, false),
+            slot(624, 5, 40, 40, F1M1, false),
+            slot(89, 5, 40, 40, F1M1, true),
+            slot(578, 4, 39, 39, F1M1, true),
+            slot(569, 4, 40, 40, F1M1, true),
+            slot(578, 1, 39, 39, F1M1, true),
+            slot(569, 1, 40, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "route_11",
+        slots: &[
+            slot(588, 20, 36, 36, F1M1, false),
+            slot(55, 20, 36, 36, F1M1, false),
+            slot(207, 10, 37, 37, F1M1, false),
+            slot(183, 10, 36, 36, F1M1, false),
+            slot(335, 10, 38, 38, F1M1, true),
+            slot(336, 10, 38, 38, F1M1, false),
+            slot(591, 5, 37, 37, F1M1, true),
+            slot(616, 5, 36, 36, F1M1, false),
+            slot(207, 4, 39, 39, F1M1, false),
+            slot(588, 4, 39, 39, F1M1, false),
+            slot(207, 1, 39, 39, F1M1, false),
+            slot(588, 1, 39, 39, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "route_12",
+        slots: &[
+            slot(415, 20, 35, 35, F1M7, true),
+            slot(315, 20, 35, 35, F1M1, true),
+            slot(540, 10, 36, 36, F1M1, true),
+            slot(520, 10, 37, 37, F1M1, false),
+            slot(127, 10, 36, 36, F1M1, false),
+            slot(520, 10, 36, 36, F1M1, false),
+            slot(127, 5, 38, 38, F1M1, false),
+            slot(540, 5, 38, 38, F1M1, true),
+            slot(315, 4, 37, 37, F1M1, true),
+            slot(520, 4, 38, 38, F1M1, false),
+            slot(315, 1, 37, 37, F1M1, true),
+            slot(520, 1, 38, 38, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "route_13",
+        slots: &[
+            slot(114, 20, 34, 34, F1M1, false),
+            slot(279, 20, 34, 34, F1M1, false),
+            slot(426, 10, 34, 34, F1M1, true),
+            slot(337, 10, 36, 36, Genderless, true),
+            slot(338, 10, 36, 36, Genderless, true),
+            slot(359, 10, 35, 35, F1M1, false),
+            slot(426, 5, 35, 35, F1M1, true),
+            slot(359, 5, 37, 37, F1M1, false),
+            slot(114, 4, 36, 36, F1M1, false),
+            slot(279, 4, 36, 36, F1M1, false),
+            slot(114, 1, 37, 37, F1M1, false),
+            slot(279, 1, 37, 37, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "route_14",
+        slots: &[
+            slot(55, 20, 34, 34, F1M1, false),
+            slot(333, 20, 33, 33, F1M1, false),
+            slot(426, 10, 34, 34, F1M1, true),
+            slot(619, 10, 33, 33, F1M1, false),
+            slot(619, 10, 35, 35, F1M1, false),
+            slot(359, 10, 34, 34, F1M1, false),
+            slot(426, 5, 35, 35, F1M1, true),
+            slot(359, 5, 36, 36, F1M1, false),
+            slot(55, 4, 35, 35, F1M1, false),
+            slot(334, 4, 36, 36, F1M1, false),
+            slot(55, 1, 35, 35, F1M1, false),
+            slot(334, 1, 36, 36, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "abundant_shrine",
+        slots: &[
+            slot(333, 20, 33, 33, F1M1, false),
+            slot(548, 20, 33, 33, FemaleOnly, false),
+            slot(37, 10, 34, 34, F3M1, true),
+            slot(436, 10, 32, 32, Genderless, true),
+            slot(183, 10, 34, 34, F1M1, false),
+            slot(55, 10, 34, 34, F1M1, false),
+            slot(548, 5, 35, 35, FemaleOnly, false),
+            slot(436, 5, 32, 32, Genderless, true),
+            slot(548, 4, 35, 35, FemaleOnly, false),
+            slot(334, 4, 36, 36, F1M1, false),
+            slot(548, 1, 35, 35, FemaleOnly, false),
+            slot(334, 1, 36, 36, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "route_15",
+        slots: &[
+            slot(28, 20, 54, 54, F1M1, true),
+            slot(207, 20, 55, 55, F1M1, false),
+            slot(28, 10, 56, 56, F1M1, true),
+            slot(560, 10, 55, 55, F1M1, true),
+            slot(538, 10, 55, 55, MaleOnly, true),
+            slot(247, 10, 55, 55, F1M1, false),
+            slot(538, 5, 56, 56, MaleOnly, true),
+            slot(538, 5, 57, 57, MaleOnly, true),
+            slot(247, 4, 57, 57, F1M1, false),
+            slot(207, 4, 57, 57, F1M1, false),
+            slot(247, 1, 57, 57, F1M1, false),
+            slot(207, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "route_16",
+        slots: &[
+            slot(577, 20, 21, 21, F1M1, true),
+            slot(572, 20, 21, 21, F3M1, true),
+            slot(568, 10, 21, 21, F1M1, true),
+            slot(572, 10, 23, 23, F3M1, true),
+            slot(510, 10, 22, 22, F1M1, false),
+            slot(510, 10, 24, 24, F1M1, false),
+            slot(568, 5, 22, 22, F1M1, true),
+            slot(577, 5, 22, 22, F1M1, true),
+            slot(568, 4, 23, 23, F1M1, true),
+            slot(577, 4, 23, 23, F1M1, true),
+            slot(568, 1, 24, 24, F1M1, true),
+            slot(577, 1, 24, 24, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "lostlorn_forest",
+        slots: &[
+            slot(548, 20, 21, 21, FemaleOnly, false),
+            slot(541, 20, 21, 21, F1M1, true),
+            slot(415, 10, 22, 22, F1M7, true),
+            slot(543, 10, 21, 21, F1M1, true),
+            slot(315, 10, 23, 23, F1M1, true),
+            slot(543, 10, 21, 21, F1M1, true),
+            slot(315, 5, 24, 24, F1M1, true),
+            slot(127, 5, 24, 24, F1M1, false),
+            slot(548, 4, 23, 23, FemaleOnly, false),
+            slot(541, 4, 24, 24, F1M1, true),
+            slot(548, 1, 24, 24, FemaleOnly, false),
+            slot(541, 1, 24, 24, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "route_18",
+        slots: &[
+            slot(560, 20, 57, 57, F1M1, true),
+            slot(558, 20, 57, 57, F1M1, true),
+            slot(505, 10, 56, 56, F1M1, false),
+            slot(357, 10, 58, 58, F1M1, false),
+            slot(538, 10, 57, 57, MaleOnly, true),
+            slot(455, 10, 58, 58, F1M1, false),
+            slot(560, 5, 58, 58, F1M1, true),
+            slot(538, 5, 59, 59, MaleOnly, true),
+            slot(560, 4, 58, 58, F1M1, true),
+            slot(538, 4, 59, 59, MaleOnly, true),
+            slot(560, 1, 58, 58, F1M1, true),
+            slot(538, 1, 59, 59, MaleOnly, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "route_19",
+        slots: &[
+            slot(504, 20, 2, 2, F1M1, false),
+            slot(509, 20, 2, 2, F1M1, false),
+            slot(504, 10, 3, 3, F1M1, false),
+            slot(509, 10, 3, 3, F1M1, false),
+            slot(504, 10, 4, 4, F1M1, false),
+            slot(509, 10, 4, 4, F1M1, false),
+            slot(504, 5, 4, 4, F1M1, false),
+            slot(509, 5, 4, 4, F1M1, false),
+            slot(504, 4, 4, 4, F1M1, false),
+            slot(509, 4, 4, 4, F1M1, false),
+            slot(504, 1, 4, 4, F1M1, false),
+            slot(509, 1, 4, 4, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "route_20_spring",
+        slots: &[
+            slot(519, 20, 2, 2, F1M1, false),
+            slot(540, 20, 2, 2, F1M1, true),
+            slot(504, 10, 3, 3, F1M1, false),
+            slot(509, 10, 3, 3, F1M1, false),
+            slot(504, 10, 3, 3, F1M1, false),
+            slot(540, 10, 3, 3, F1M1, true),
+            slot(519, 5, 4, 4, F1M1, false),
+            slot(540, 5, 4, 4, F1M1, true),
+            slot(509, 4, 4, 4, F1M1, false),
+            slot(191, 4, 4, 4, F1M1, false),
+            slot(509, 1, 4, 4, F1M1, false),
+            slot(191, 1, 4, 4, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "route_20_summer",
+        slots: &[
+            slot(191, 20, 2, 2, F1M1, false),
+            slot(540, 20, 2, 2, F1M1, true),
+            slot(504, 10, 3, 3, F1M1, false),
+            slot(509, 10, 3, 3, F1M1, false),
+            slot(504, 10, 3, 3, F1M1, false),
+            slot(540, 10, 3, 3, F1M1, true),
+            slot(519, 5, 4, 4, F1M1, false),
+            slot(540, 5, 4, 4, F1M1, true),
+            slot(509, 4, 4, 4, F1M1, false),
+            slot(191, 4, 3, 3, F1M1, false),
+            slot(191, 1, 4, 4, F1M1, false),
+            slot(191, 1, 4, 4, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "route_20_autumn",
+        slots: &[
+            slot(519, 20, 2, 2, F1M1, false),
+            slot(540, 20, 2, 2, F1M1, true),
+            slot(504, 10, 3, 3, F1M1, false),
+            slot(509, 10, 3, 3, F1M1, false),
+            slot(504, 10, 3, 3, F1M1, false),
+            slot(540, 10, 3, 3, F1M1, true),
+            slot(519, 5, 4, 4, F1M1, false),
+            slot(540, 5, 4, 4, F1M1, true),
+            slot(509, 4, 4, 4, F1M1, false),
+            slot(191, 4, 4, 4, F1M1, false),
+            slot(509, 1, 4, 4, F1M1, false),
+            slot(191, 1, 4, 4, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "route_20_winter",
+        slots: &[
+            slot(519, 20, 2, 2, F1M1, false),
+            slot(540, 20, 2, 2, F1M1, true),
+            slot(504, 10, 3, 3, F1M1, false),
+            slot(509, 10, 3, 3, F1M1, false),
+            slot(504, 10, 3, 3, F1M1, false),
+            slot(540, 10, 3, 3, F1M1, true),
+            slot(519, 5, 4, 4, F1M1, false),
+            slot(540, 5, 4, 4, F1M1, true),
+            slot(509, 4, 4, 4, F1M1, false),
+            slot(191, 4, 4, 4, F1M1, false),
+            slot(509, 1, 4, 4, F1M1, false),
+            slot(191, 1, 4, 4, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "route_22",
+        slots: &[
+            slot(591, 20, 39, 39, F1M1, true),
+            slot(619, 20, 39, 39, F1M1, false),
+            slot(279, 10, 40, 40, F1M1, false),
+            slot(55, 10, 40, 40, F1M1, false),
+            slot(337, 10, 41, 41, Genderless, true),
+            slot(338, 10, 41, 41, Genderless, true),
+            slot(183, 5, 40, 40, F1M1, false),
+            slot(183, 5, 42, 42, F1M1, false),
+            slot(619, 4, 42, 42, F1M1, false),
+            slot(225, 4, 39, 39, F1M1, false),
+            slot(619, 1, 42, 42, F1M1, false),
+            slot(225, 1, 39, 39, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Normal,
+        location_key: "route_23",
+        slots: &[
+            slot(626, 20, 49, 49, F1M1, false),
+            slot(538, 20, 48, 48, MaleOnly, true),
+            slot(207, 10, 49, 49, F1M1, false),
+            slot(619, 10, 48, 48, F1M1, false),
+            slot(591, 10, 49, 49, F1M1, true),
+            slot(55, 10, 50, 50, F1M1, false),
+            slot(619, 5, 48, 48, F1M1, false),
+            slot(538, 5, 49, 49, MaleOnly, true),
+            slot(627, 4, 47, 47, MaleOnly, false),
+            slot(626, 4, 51, 51, F1M1, false),
+            slot(627, 1, 47, 47, MaleOnly, false),
+            slot(626, 1, 51, 51, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "castelia_city",
+        slots: &[
+            slot(531, 20, 15, 15, F1M1, true),
+            slot(531, 20, 15, 15, F1M1, true),
+            slot(531, 10, 16, 16, F1M1, true),
+            slot(531, 10, 16, 16, F1M1, true),
+            slot(531, 10, 17, 17, F1M1, true),
+            slot(531, 10, 17, 17, F1M1, true),
+            slot(531, 5, 18, 18, F1M1, true),
+            slot(531, 5, 18, 18, F1M1, true),
+            slot(301, 4, 18, 18, F3M1, true),
+            slot(549, 4, 18, 18, FemaleOnly, false),
+            slot(301, 1, 18, 18, F3M1, true),
+            slot(549, 1, 18, 18, FemaleOnly, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "dreamyard",
+        slots: &[
+            slot(531, 20, 56, 56, F1M1, true),
+            slot(531, 20, 56, 56, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(206, 10, 57, 57, F1M1, false),
+            slot(531, 10, 58, 58, F1M1, true),
+            slot(531, 10, 58, 58, F1M1, true),
+            slot(531, 5, 59, 59, F1M1, true),
+            slot(40, 5, 59, 59, F3M1, false),
+            slot(169, 4, 59, 59, F1M1, false),
+            slot(518, 4, 59, 59, F1M1, false),
+            slot(169, 1, 59, 59, F1M1, false),
+            slot(518, 1, 59, 59, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "pinwheel_forest_exterior",
+        slots: &[
+            slot(531, 20, 54, 54, F1M1, true),
+            slot(531, 20, 54, 54, F1M1, true),
+            slot(531, 10, 55, 55, F1M1, true),
+            slot(531, 10, 55, 55, F1M1, true),
+            slot(531, 10, 56, 56, F1M1, true),
+            slot(531, 10, 56, 56, F1M1, true),
+            slot(469, 5, 57, 57, F1M1, true),
+            slot(537, 5, 57, 57, F1M1, true),
+            slot(531, 4, 57, 57, F1M1, true),
+            slot(539, 4, 57, 57, MaleOnly, true),
+            slot(531, 1, 57, 57, F1M1, true),
+            slot(539, 1, 57, 57, MaleOnly, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "pinwheel_forest_interior",
+        slots: &[
+            slot(531, 20, 54, 54, F1M1, true),
+            slot(531, 20, 54, 54, F1M1, true),
+            slot(511, 10, 55, 55, F1M7, true),
+            slot(513, 10, 55, 55, F1M7, true),
+            slot(515, 10, 55, 55, F1M7, true),
+            slot(531, 10, 56, 56, F1M1, true),
+            slot(531, 5, 57, 57, F1M1, true),
+            slot(545, 5, 57, 57, F1M1, true),
+            slot(289, 4, 57, 57, F1M1, false),
+            slot(549, 4, 57, 57, FemaleOnly, false),
+            slot(289, 1, 57, 57, F1M1, false),
+            slot(549, 1, 57, 57, FemaleOnly, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "dragonspiral_tower_spring_exterior_south",
+        slots: &[
+            slot(531, 20, 55, 55, F1M1, true),
+            slot(531, 20, 55, 55, F1M1, true),
+            slot(587, 10, 56, 56, F1M1, true),
+            slot(531, 10, 56, 56, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(531, 5, 58, 58, F1M1, true),
+            slot(531, 5, 58, 58, F1M1, true),
+            slot(531, 4, 58, 58, F1M1, true),
+            slot(521, 4, 58, 58, F1M1, false),
+            slot(531, 1, 58, 58, F1M1, true),
+            slot(521, 1, 58, 58, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "dragonspiral_tower_spring_exterior_northeast",
+        slots: &[
+            slot(531, 20, 55, 55, F1M1, true),
+            slot(531, 20, 55, 55, F1M1, true),
+            slot(587, 10, 56, 56, F1M1, true),
+            slot(531, 10, 56, 56, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(531, 5, 57, 57, F1M1, true),
+            slot(531, 5, 57, 57, F1M1, true),
+            slot(531, 4, 57, 57, F1M1, true),
+            slot(521, 4, 57, 57, F1M1, false),
+            slot(531, 1, 57, 57, F1M1, true),
+            slot(521, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "dragonspiral_tower_summer_exterior_south",
+        slots: &[
+            slot(531, 20, 55, 55, F1M1, true),
+            slot(531, 20, 55, 55, F1M1, true),
+            slot(587, 10, 56, 56, F1M1, true),
+            slot(531, 10, 56, 56, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(531, 5, 58, 58, F1M1, true),
+            slot(531, 5, 58, 58, F1M1, true),
+            slot(531, 4, 58, 58, F1M1, true),
+            slot(521, 4, 58, 58, F1M1, false),
+            slot(531, 1, 58, 58, F1M1, true),
+            slot(521, 1, 58, 58, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "dragonspiral_tower_summer_exterior_northeast",
+        slots: &[
+            slot(531, 20, 55, 55, F1M1, true),
+            slot(531, 20, 55, 55, F1M1, true),
+            slot(587, 10, 56, 56, F1M1, true),
+            slot(531, 10, 56, 56, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(531, 5, 57, 57, F1M1, true),
+            slot(531, 5, 57, 57, F1M1, true),
+            slot(531, 4, 57, 57, F1M1, true),
+            slot(521, 4, 57, 57, F1M1, false),
+            slot(531, 1, 57, 57, F1M1, true),
+            slot(521, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "dragonspiral_tower_autumn_exterior_south",
+        slots: &[
+            slot(531, 20, 55, 55, F1M1, true),
+            slot(531, 20, 55, 55, F1M1, true),
+            slot(587, 10, 56, 56, F1M1, true),
+            slot(531, 10, 56, 56, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(531, 5, 58, 58, F1M1, true),
+            slot(531, 5, 58, 58, F1M1, true),
+            slot(531, 4, 58, 58, F1M1, true),
+            slot(521, 4, 58, 58, F1M1, false),
+            slot(531, 1, 58, 58, F1M1, true),
+            slot(521, 1, 58, 58, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "dragonspiral_tower_autumn_exterior_northeast",
+        slots: &[
+            slot(531, 20, 55, 55, F1M1, true),
+            slot(531, 20, 55, 55, F1M1, true),
+            slot(587, 10, 56, 56, F1M1, true),
+            slot(531, 10, 56, 56, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(531, 5, 57, 57, F1M1, true),
+            slot(531, 5, 57, 57, F1M1, true),
+            slot(531, 4, 57, 57, F1M1, true),
+            slot(521, 4, 57, 57, F1M1, false),
+            slot(531, 1, 57, 57, F1M1, true),
+            slot(521, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "dragonspiral_tower_winter_exterior_south",
+        slots: &[
+            slot(531, 20, 55, 55, F1M1, true),
+            slot(531, 20, 55, 55, F1M1, true),
+            slot(587, 10, 56, 56, F1M1, true),
+            slot(531, 10, 56, 56, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(531, 5, 58, 58, F1M1, true),
+            slot(531, 5, 58, 58, F1M1, true),
+            slot(531, 4, 58, 58, F1M1, true),
+            slot(584, 4, 58, 58, F1M1, false),
+            slot(531, 1, 58, 58, F1M1, true),
+            slot(584, 1, 58, 58, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "dragonspiral_tower_winter_exterior_northeast",
+        slots: &[
+            slot(531, 20, 55, 55, F1M1, true),
+            slot(531, 20, 55, 55, F1M1, true),
+            slot(587, 10, 56, 56, F1M1, true),
+            slot(531, 10, 56, 56, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(531, 5, 57, 57, F1M1, true),
+            slot(531, 5, 57, 57, F1M1, true),
+            slot(531, 4, 57, 57, F1M1, true),
+            slot(584, 4, 57, 57, F1M1, false),
+            slot(531, 1, 57, 57, F1M1, true),
+            slot(584, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "giant_chasm_exterior",
+        slots: &[
+            slot(531, 20, 44, 44, F1M1, true),
+            slot(531, 20, 44, 44, F1M1, true),
+            slot(531, 10, 45, 45, F1M1, true),
+            slot(531, 10, 45, 45, F1M1, true),
+            slot(531, 10, 46, 46, F1M1, true),
+            slot(531, 10, 46, 46, F1M1, true),
+            slot(531, 5, 47, 47, F1M1, true),
+            slot(36, 5, 47, 47, F3M1, true),
+            slot(584, 4, 47, 47, F1M1, false),
+            slot(465, 4, 47, 47, F1M1, false),
+            slot(584, 1, 47, 47, F1M1, false),
+            slot(465, 1, 47, 47, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "giant_chasm_crater_forest",
+        slots: &[
+            slot(531, 20, 44, 44, F1M1, true),
+            slot(531, 20, 44, 44, F1M1, true),
+            slot(531, 10, 45, 45, F1M1, true),
+            slot(531, 10, 45, 45, F1M1, true),
+            slot(531, 10, 46, 46, F1M1, true),
+            slot(531, 10, 46, 46, F1M1, true),
+            slot(531, 5, 47, 47, F1M1, true),
+            slot(473, 5, 47, 47, F1M1, false),
+            slot(36, 4, 47, 47, F3M1, true),
+            slot(376, 4, 47, 47, Genderless, true),
+            slot(36, 1, 47, 47, F3M1, true),
+            slot(376, 1, 47, 47, Genderless, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "p2_laboratory",
+        slots: &[
+            slot(531, 20, 56, 56, F1M1, true),
+            slot(531, 20, 56, 56, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(531, 10, 58, 58, F1M1, true),
+            slot(531, 10, 58, 58, F1M1, true),
+            slot(531, 5, 59, 59, F1M1, true),
+            slot(462, 5, 59, 59, Genderless, true),
+            slot(601, 4, 59, 59, Genderless, false),
+            slot(508, 4, 59, 59, F1M1, false),
+            slot(601, 1, 59, 59, Genderless, false),
+            slot(508, 1, 59, 59, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "floccesy_ranch",
+        slots: &[
+            slot(531, 20, 4, 4, F1M1, true),
+            slot(531, 20, 4, 4, F1M1, true),
+            slot(531, 10, 5, 5, F1M1, true),
+            slot(206, 10, 5, 5, F1M1, false),
+            slot(531, 10, 6, 6, F1M1, true),
+            slot(531, 10, 6, 6, F1M1, true),
+            slot(531, 5, 7, 7, F1M1, true),
+            slot(531, 5, 7, 7, F1M1, true),
+            slot(531, 4, 7, 7, F1M1, true),
+            slot(531, 4, 7, 7, F1M1, true),
+            slot(531, 1, 7, 7, F1M1, true),
+            slot(531, 1, 7, 7, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "virbank_complex",
+        slots: &[
+            slot(531, 20, 10, 10, F1M1, true),
+            slot(531, 20, 10, 10, F1M1, true),
+            slot(531, 10, 11, 11, F1M1, true),
+            slot(531, 10, 11, 11, F1M1, true),
+            slot(531, 10, 12, 12, F1M1, true),
+            slot(531, 10, 12, 12, F1M1, true),
+            slot(531, 5, 13, 13, F1M1, true),
+            slot(531, 5, 13, 13, F1M1, true),
+            slot(531, 4, 13, 13, F1M1, true),
+            slot(531, 4, 13, 13, F1M1, true),
+            slot(531, 1, 13, 13, F1M1, true),
+            slot(531, 1, 13, 13, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "reversal_mountain",
+        slots: &[
+            slot(531, 20, 31, 31, F1M1, true),
+            slot(531, 20, 31, 31, F1M1, true),
+            slot(531, 10, 32, 32, F1M1, true),
+            slot(531, 10, 32, 32, F1M1, true),
+            slot(531, 10, 33, 33, F1M1, true),
+            slot(531, 10, 33, 33, F1M1, true),
+            slot(531, 5, 34, 34, F1M1, true),
+            slot(531, 5, 34, 34, F1M1, true),
+            slot(531, 4, 34, 34, F1M1, true),
+            slot(531, 4, 34, 34, F1M1, true),
+            slot(531, 1, 34, 34, F1M1, true),
+            slot(531, 1, 34, 34, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "victory_road_1f",
+        slots: &[
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(531, 10, 48, 48, F1M1, true),
+            slot(531, 10, 48, 48, F1M1, true),
+            slot(206, 10, 49, 49, F1M1, false),
+            slot(531, 10, 49, 49, F1M1, true),
+            slot(531, 5, 50, 50, F1M1, true),
+            slot(531, 5, 50, 50, F1M1, true),
+            slot(549, 4, 50, 50, FemaleOnly, false),
+            slot(407, 4, 50, 50, F1M1, true),
+            slot(549, 1, 50, 50, FemaleOnly, false),
+            slot(407, 1, 50, 50, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "victory_road_2f",
+        slots: &[
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(531, 10, 48, 48, F1M1, true),
+            slot(531, 10, 48, 48, F1M1, true),
+            slot(206, 10, 49, 49, F1M1, false),
+            slot(531, 10, 49, 49, F1M1, true),
+            slot(531, 5, 50, 50, F1M1, true),
+            slot(531, 5, 50, 50, F1M1, true),
+            slot(531, 4, 50, 50, F1M1, true),
+            slot(521, 4, 50, 50, F1M1, false),
+            slot(531, 1, 50, 50, F1M1, true),
+            slot(521, 1, 50, 50, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "victory_road_3f_4f_5f",
+        slots: &[
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(531, 20, 47, 47, F1M1, true),
+            slot(531, 10, 48, 48, F1M1, true),
+            slot(531, 10, 48, 48, F1M1, true),
+            slot(531, 10, 49, 49, F1M1, true),
+            slot(531, 10, 49, 49, F1M1, true),
+            slot(531, 5, 50, 50, F1M1, true),
+            slot(531, 5, 50, 50, F1M1, true),
+            slot(531, 4, 50, 50, F1M1, true),
+            slot(539, 4, 50, 50, MaleOnly, true),
+            slot(531, 1, 50, 50, F1M1, true),
+            slot(539, 1, 50, 50, MaleOnly, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "nature_preserve",
+        slots: &[
+            slot(531, 20, 56, 56, F1M1, true),
+            slot(531, 20, 56, 56, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(206, 10, 57, 57, F1M1, false),
+            slot(531, 10, 58, 58, F1M1, true),
+            slot(531, 10, 58, 58, F1M1, true),
+            slot(531, 5, 59, 59, F1M1, true),
+            slot(531, 5, 59, 59, F1M1, true),
+            slot(275, 4, 59, 59, F1M1, false),
+            slot(531, 4, 59, 59, F1M1, true),
+            slot(275, 1, 59, 59, F1M1, false),
+            slot(531, 1, 59, 59, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "village_bridge",
+        slots: &[
+            slot(531, 20, 36, 36, F1M1, true),
+            slot(531, 20, 36, 36, F1M1, true),
+            slot(587, 10, 37, 37, F1M1, true),
+            slot(206, 10, 37, 37, F1M1, false),
+            slot(531, 10, 38, 38, F1M1, true),
+            slot(531, 10, 38, 38, F1M1, true),
+            slot(531, 5, 39, 39, F1M1, true),
+            slot(531, 5, 39, 39, F1M1, true),
+            slot(531, 4, 39, 39, F1M1, true),
+            slot(184, 4, 39, 39, F1M1, false),
+            slot(531, 1, 39, 39, F1M1, true),
+            slot(184, 1, 39, 39, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "route_1",
+        slots: &[
+            slot(531, 20, 56, 56, F1M1, true),
+            slot(531, 20, 56, 56, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(206, 10, 57, 57, F1M1, false),
+            slot(531, 10, 58, 58, F1M1, true),
+            slot(531, 10, 58, 58, F1M1, true),
+            slot(531, 5, 59, 59, F1M1, true),
+            slot(531, 5, 59, 59, F1M1, true),
+            slot(508, 4, 59, 59, F1M1, false),
+            slot(40, 4, 59, 59, F3M1, false),
+            slot(508, 1, 59, 59, F1M1, false),
+            slot(40, 1, 59, 59, F3M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "route_2",
+        slots: &[
+            slot(531, 20, 56, 56, F1M1, true),
+            slot(531, 20, 56, 56, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(206, 10, 57, 57, F1M1, false),
+            slot(531, 10, 58, 58, F1M1, true),
+            slot(531, 10, 58, 58, F1M1, true),
+            slot(531, 5, 59, 59, F1M1, true),
+            slot(463, 5, 59, 59, F1M1, true),
+            slot(508, 4, 59, 59, F1M1, false),
+            slot(40, 4, 59, 59, F3M1, false),
+            slot(508, 1, 59, 59, F1M1, false),
+            slot(40, 1, 59, 59, F3M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "route_3",
+        slots: &[
+            slot(531, 20, 55, 55, F1M1, true),
+            slot(531, 20, 55, 55, F1M1, true),
+            slot(531, 10, 56, 56, F1M1, true),
+            slot(531, 10, 56, 56, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(531, 5, 58, 58, F1M1, true),
+            slot(469, 5, 58, 58, F1M1, true),
+            slot(521, 4, 58, 58, F1M1, false),
+            slot(508, 4, 58, 58, F1M1, false),
+            slot(521, 1, 58, 58, F1M1, false),
+            slot(508, 1, 58, 58, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "route_5",
+        slots: &[
+            slot(531, 20, 21, 21, F1M1, true),
+            slot(531, 20, 21, 21, F1M1, true),
+            slot(587, 10, 22, 22, F1M1, true),
+            slot(531, 10, 22, 22, F1M1, true),
+            slot(531, 10, 23, 23, F1M1, true),
+            slot(531, 10, 23, 23, F1M1, true),
+            slot(531, 5, 24, 24, F1M1, true),
+            slot(531, 5, 24, 24, F1M1, true),
+            slot(573, 4, 24, 24, F3M1, true),
+            slot(531, 4, 24, 24, F1M1, true),
+            slot(573, 1, 24, 24, F3M1, true),
+            slot(531, 1, 24, 24, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "route_6_spring",
+        slots: &[
+            slot(531, 20, 23, 23, F1M1, true),
+            slot(531, 20, 24, 24, F1M1, true),
+            slot(587, 10, 24, 24, F1M1, true),
+            slot(587, 10, 25, 25, F1M1, true),
+            slot(531, 10, 25, 25, F1M1, true),
+            slot(206, 10, 25, 25, F1M1, false),
+            slot(184, 5, 26, 26, F1M1, false),
+            slot(351, 5, 26, 26, F1M1, true),
+            slot(521, 4, 26, 26, F1M1, false),
+            slot(542, 4, 26, 26, F1M1, true),
+            slot(521, 1, 26, 26, F1M1, false),
+            slot(542, 1, 26, 26, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "route_6_summer",
+        slots: &[
+            slot(531, 20, 23, 23, F1M1, true),
+            slot(531, 20, 24, 24, F1M1, true),
+            slot(587, 10, 24, 24, F1M1, true),
+            slot(587, 10, 25, 25, F1M1, true),
+            slot(531, 10, 25, 25, F1M1, true),
+            slot(206, 10, 25, 25, F1M1, false),
+            slot(184, 5, 26, 26, F1M1, false),
+            slot(351, 5, 26, 26, F1M1, true),
+            slot(521, 4, 26, 26, F1M1, false),
+            slot(542, 4, 26, 26, F1M1, true),
+            slot(521, 1, 26, 26, F1M1, false),
+            slot(542, 1, 26, 26, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "route_6_autumn",
+        slots: &[
+            slot(531, 20, 23, 23, F1M1, true),
+            slot(531, 20, 24, 24, F1M1, true),
+            slot(587, 10, 24, 24, F1M1, true),
+            slot(587, 10, 25, 25, F1M1, true),
+            slot(531, 10, 25, 25, F1M1, true),
+            slot(206, 10, 25, 25, F1M1, false),
+            slot(184, 5, 26, 26, F1M1, false),
+            slot(351, 5, 26, 26, F1M1, true),
+            slot(521, 4, 26, 26, F1M1, false),
+            slot(542, 4, 26, 26, F1M1, true),
+            slot(521, 1, 26, 26, F1M1, false),
+            slot(542, 1, 26, 26, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "route_6_winter",
+        slots: &[
+            slot(531, 20, 23, 23, F1M1, true),
+            slot(531, 20, 24, 24, F1M1, true),
+            slot(587, 10, 24, 24, F1M1, true),
+            slot(587, 10, 25, 25, F1M1, true),
+            slot(531, 10, 25, 25, F1M1, true),
+            slot(206, 10, 25, 25, F1M1, false),
+            slot(184, 5, 26, 26, F1M1, false),
+            slot(351, 5, 26, 26, F1M1, true),
+            slot(521, 4, 26, 26, F1M1, false),
+            slot(542, 4, 26, 26, F1M1, true),
+            slot(521, 1, 26, 26, F1M1, false),
+            slot(542, 1, 26, 26, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "route_7_spring",
+        slots: &[
+            slot(531, 20, 30, 30, F1M1, true),
+            slot(531, 20, 30, 30, F1M1, true),
+            slot(587, 10, 31, 31, F1M1, true),
+            slot(531, 10, 31, 31, F1M1, true),
+            slot(531, 10, 32, 32, F1M1, true),
+            slot(531, 10, 32, 32, F1M1, true),
+            slot(531, 5, 33, 33, F1M1, true),
+            slot(531, 5, 33, 33, F1M1, true),
+            slot(531, 4, 33, 33, F1M1, true),
+            slot(521, 4, 33, 33, F1M1, false),
+            slot(531, 1, 33, 33, F1M1, true),
+            slot(521, 1, 33, 33, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "route_7_summer",
+        slots: &[
+            slot(531, 20, 30, 30, F1M1, true),
+            slot(531, 20, 30, 30, F1M1, true),
+            slot(587, 10, 31, 31, F1M1, true),
+            slot(531, 10, 31, 31, F1M1, true),
+            slot(531, 10, 32, 32, F1M1, true),
+            slot(531, 10, 32, 32, F1M1, true),
+            slot(531, 5, 33, 33, F1M1, true),
+            slot(531, 5, 33, 33, F1M1, true),
+            slot(531, 4, 33, 33, F1M1, true),
+            slot(521, 4, 33, 33, F1M1, false),
+            slot(531, 1, 33, 33, F1M1, true),
+            slot(521, 1, 33, 33, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "route_7_autumn",
+        slots: &[
+            slot(531, 20, 30, 30, F1M1, true),
+            slot(531, 20, 30, 30, F1M1, true),
+            slot(587, 10, 31, 31, F1M1, true),
+            slot(531, 10, 31, 31, F1M1, true),
+            slot(531, 10, 32, 32, F1M1, true),
+            slot(531, 10, 32, 32, F1M1, true),
+            slot(531, 5, 33, 33, F1M1, true),
+            slot(531, 5, 33, 33, F1M1, true),
+            slot(531, 4, 33, 33, F1M1, true),
+            slot(521, 4, 33, 33, F1M1, false),
+            slot(531, 1, 33, 33, F1M1, true),
+            slot(521, 1, 33, 33, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "route_7_winter",
+        slots: &[
+            slot(531, 20, 30, 30, F1M1, true),
+            slot(531, 20, 30, 30, F1M1, true),
+            slot(587, 10, 31, 31, F1M1, true),
+            slot(531, 10, 31, 31, F1M1, true),
+            slot(531, 10, 32, 32, F1M1, true),
+            slot(531, 10, 32, 32, F1M1, true),
+            slot(531, 5, 33, 33, F1M1, true),
+            slot(531, 5, 33, 33, F1M1, true),
+            slot(531, 4, 33, 33, F1M1, true),
+            slot(521, 4, 33, 33, F1M1, false),
+            slot(531, 1, 33, 33, F1M1, true),
+            slot(521, 1, 33, 33, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "route_9",
+        slots: &[
+            slot(531, 20, 37, 37, F1M1, true),
+            slot(531, 20, 37, 37, F1M1, true),
+            slot(587, 10, 38, 38, F1M1, true),
+            slot(531, 10, 38, 38, F1M1, true),
+            slot(531, 10, 39, 39, F1M1, true),
+            slot(531, 10, 39, 39, F1M1, true),
+            slot(531, 5, 40, 40, F1M1, true),
+            slot(531, 5, 40, 40, F1M1, true),
+            slot(573, 4, 40, 40, F3M1, true),
+            slot(579, 4, 40, 40, F1M1, true),
+            slot(573, 1, 40, 40, F3M1, true),
+            slot(579, 1, 40, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "route_11",
+        slots: &[
+            slot(531, 20, 36, 36, F1M1, true),
+            slot(531, 20, 36, 36, F1M1, true),
+            slot(587, 10, 37, 37, F1M1, true),
+            slot(531, 10, 37, 37, F1M1, true),
+            slot(531, 10, 38, 38, F1M1, true),
+            slot(531, 10, 38, 38, F1M1, true),
+            slot(531, 5, 39, 39, F1M1, true),
+            slot(531, 5, 39, 39, F1M1, true),
+            slot(472, 4, 39, 39, F1M1, false),
+            slot(184, 4, 39, 39, F1M1, false),
+            slot(472, 1, 39, 39, F1M1, false),
+            slot(184, 1, 39, 39, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "route_12",
+        slots: &[
+            slot(531, 20, 35, 35, F1M1, true),
+            slot(531, 20, 35, 35, F1M1, true),
+            slot(587, 10, 36, 36, F1M1, true),
+            slot(206, 10, 36, 36, F1M1, false),
+            slot(531, 10, 37, 37, F1M1, true),
+            slot(531, 10, 37, 37, F1M1, true),
+            slot(521, 5, 38, 38, F1M1, false),
+            slot(542, 5, 38, 38, F1M1, true),
+            slot(416, 4, 38, 38, FemaleOnly, true),
+            slot(407, 4, 38, 38, F1M1, true),
+            slot(416, 1, 38, 38, FemaleOnly, true),
+            slot(407, 1, 38, 38, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "route_13",
+        slots: &[
+            slot(531, 20, 34, 34, F1M1, true),
+            slot(531, 20, 34, 34, F1M1, true),
+            slot(587, 10, 35, 35, F1M1, true),
+            slot(531, 10, 35, 35, F1M1, true),
+            slot(531, 10, 36, 36, F1M1, true),
+            slot(531, 10, 36, 36, F1M1, true),
+            slot(531, 5, 37, 37, F1M1, true),
+            slot(531, 5, 37, 37, F1M1, true),
+            slot(531, 4, 37, 37, F1M1, true),
+            slot(465, 4, 37, 37, F1M1, false),
+            slot(531, 1, 37, 37, F1M1, true),
+            slot(465, 1, 37, 37, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "route_14",
+        slots: &[
+            slot(531, 20, 33, 33, F1M1, true),
+            slot(531, 20, 33, 33, F1M1, true),
+            slot(587, 10, 34, 34, F1M1, true),
+            slot(531, 10, 34, 34, F1M1, true),
+            slot(531, 10, 35, 35, F1M1, true),
+            slot(531, 10, 35, 35, F1M1, true),
+            slot(531, 5, 36, 36, F1M1, true),
+            slot(531, 5, 36, 36, F1M1, true),
+            slot(531, 4, 36, 36, F1M1, true),
+            slot(531, 4, 36, 36, F1M1, true),
+            slot(531, 1, 36, 36, F1M1, true),
+            slot(531, 1, 36, 36, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "abundant_shrine",
+        slots: &[
+            slot(531, 20, 33, 33, F1M1, true),
+            slot(531, 20, 33, 33, F1M1, true),
+            slot(587, 10, 34, 34, F1M1, true),
+            slot(531, 10, 34, 34, F1M1, true),
+            slot(531, 10, 35, 35, F1M1, true),
+            slot(531, 10, 35, 35, F1M1, true),
+            slot(531, 5, 36, 36, F1M1, true),
+            slot(184, 5, 36, 36, F1M1, false),
+            slot(549, 4, 36, 36, FemaleOnly, false),
+            slot(38, 4, 36, 36, F3M1, true),
+            slot(549, 1, 36, 36, FemaleOnly, false),
+            slot(38, 1, 36, 36, F3M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "route_15",
+        slots: &[
+            slot(531, 20, 54, 54, F1M1, true),
+            slot(531, 20, 54, 54, F1M1, true),
+            slot(587, 10, 55, 55, F1M1, true),
+            slot(531, 10, 55, 55, F1M1, true),
+            slot(531, 10, 56, 56, F1M1, true),
+            slot(531, 10, 56, 56, F1M1, true),
+            slot(531, 5, 57, 57, F1M1, true),
+            slot(539, 5, 57, 57, MaleOnly, true),
+            slot(472, 4, 57, 57, F1M1, false),
+            slot(248, 4, 57, 57, F1M1, false),
+            slot(472, 1, 57, 57, F1M1, false),
+            slot(248, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "route_16",
+        slots: &[
+            slot(531, 20, 21, 21, F1M1, true),
+            slot(531, 20, 21, 21, F1M1, true),
+            slot(587, 10, 22, 22, F1M1, true),
+            slot(531, 10, 22, 22, F1M1, true),
+            slot(531, 10, 23, 23, F1M1, true),
+            slot(531, 10, 23, 23, F1M1, true),
+            slot(531, 5, 24, 24, F1M1, true),
+            slot(531, 5, 24, 24, F1M1, true),
+            slot(573, 4, 24, 24, F3M1, true),
+            slot(531, 4, 24, 24, F1M1, true),
+            slot(573, 1, 24, 24, F3M1, true),
+            slot(531, 1, 24, 24, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "lostlorn_forest",
+        slots: &[
+            slot(531, 20, 21, 21, F1M1, true),
+            slot(531, 20, 23, 23, F1M1, true),
+            slot(587, 10, 22, 22, F1M1, true),
+            slot(511, 10, 22, 22, F1M7, true),
+            slot(513, 10, 22, 22, F1M7, true),
+            slot(515, 10, 22, 22, F1M7, true),
+            slot(416, 5, 24, 24, FemaleOnly, true),
+            slot(407, 5, 24, 24, F1M1, true),
+            slot(542, 4, 24, 24, F1M1, true),
+            slot(549, 4, 24, 24, FemaleOnly, false),
+            slot(542, 1, 24, 24, F1M1, true),
+            slot(549, 1, 24, 24, FemaleOnly, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "route_18",
+        slots: &[
+            slot(531, 20, 56, 56, F1M1, true),
+            slot(531, 20, 56, 56, F1M1, true),
+            slot(531, 10, 57, 57, F1M1, true),
+            slot(206, 10, 57, 57, F1M1, false),
+            slot(531, 10, 58, 58, F1M1, true),
+            slot(531, 10, 58, 58, F1M1, true),
+            slot(531, 5, 59, 59, F1M1, true),
+            slot(531, 5, 59, 59, F1M1, true),
+            slot(531, 4, 59, 59, F1M1, true),
+            slot(539, 4, 59, 59, MaleOnly, true),
+            slot(531, 1, 59, 59, F1M1, true),
+            slot(539, 1, 59, 59, MaleOnly, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "route_19",
+        slots: &[
+            slot(504, 20, 2, 2, F1M1, false),
+            slot(504, 20, 2, 2, F1M1, false),
+            slot(504, 10, 3, 3, F1M1, false),
+            slot(504, 10, 3, 3, F1M1, false),
+            slot(504, 10, 4, 4, F1M1, false),
+            slot(504, 10, 4, 4, F1M1, false),
+            slot(504, 5, 4, 4, F1M1, false),
+            slot(504, 5, 4, 4, F1M1, false),
+            slot(504, 4, 4, 4, F1M1, false),
+            slot(504, 4, 4, 4, F1M1, false),
+            slot(504, 1, 4, 4, F1M1, false),
+            slot(504, 1, 4, 4, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "route_20_spring",
+        slots: &[
+            slot(531, 20, 2, 2, F1M1, true),
+            slot(531, 20, 2, 2, F1M1, true),
+            slot(531, 10, 3, 3, F1M1, true),
+            slot(206, 10, 3, 3, F1M1, false),
+            slot(531, 10, 4, 4, F1M1, true),
+            slot(531, 10, 4, 4, F1M1, true),
+            slot(531, 5, 4, 4, F1M1, true),
+            slot(531, 5, 4, 4, F1M1, true),
+            slot(531, 4, 4, 4, F1M1, true),
+            slot(531, 4, 4, 4, F1M1, true),
+            slot(531, 1, 4, 4, F1M1, true),
+            slot(531, 1, 4, 4, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "route_20_summer",
+        slots: &[
+            slot(531, 20, 2, 2, F1M1, true),
+            slot(531, 20, 2, 2, F1M1, true),
+            slot(531, 10, 3, 3, F1M1, true),
+            slot(206, 10, 3, 3, F1M1, false),
+            slot(531, 10, 4, 4, F1M1, true),
+            slot(531, 10, 4, 4, F1M1, true),
+            slot(531, 5, 4, 4, F1M1, true),
+            slot(531, 5, 4, 4, F1M1, true),
+            slot(531, 4, 4, 4, F1M1, true),
+            slot(531, 4, 4, 4, F1M1, true),
+            slot(531, 1, 4, 4, F1M1, true),
+            slot(531, 1, 4, 4, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "route_20_autumn",
+        slots: &[
+            slot(531, 20, 2, 2, F1M1, true),
+            slot(531, 20, 2, 2, F1M1, true),
+            slot(531, 10, 3, 3, F1M1, true),
+            slot(206, 10, 3, 3, F1M1, false),
+            slot(531, 10, 4, 4, F1M1, true),
+            slot(531, 10, 4, 4, F1M1, true),
+            slot(531, 5, 4, 4, F1M1, true),
+            slot(531, 5, 4, 4, F1M1, true),
+            slot(531, 4, 4, 4, F1M1, true),
+            slot(531, 4, 4, 4, F1M1, true),
+            slot(531, 1, 4, 4, F1M1, true),
+            slot(531, 1, 4, 4, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "route_20_winter",
+        slots: &[
+            slot(531, 20, 2, 2, F1M1, true),
+            slot(531, 20, 2, 2, F1M1, true),
+            slot(531, 10, 3, 3, F1M1, true),
+            slot(206, 10, 3, 3, F1M1, false),
+            slot(531, 10, 4, 4, F1M1, true),
+            slot(531, 10, 4, 4, F1M1, true),
+            slot(531, 5, 4, 4, F1M1, true),
+            slot(531, 5, 4, 4, F1M1, true),
+            slot(531, 4, 4, 4, F1M1, true),
+            slot(531, 4, 4, 4, F1M1, true),
+            slot(531, 1, 4, 4, F1M1, true),
+            slot(531, 1, 4, 4, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "route_22",
+        slots: &[
+            slot(531, 20, 39, 39, F1M1, true),
+            slot(531, 20, 39, 39, F1M1, true),
+            slot(587, 10, 40, 40, F1M1, true),
+            slot(531, 10, 40, 40, F1M1, true),
+            slot(531, 10, 41, 41, F1M1, true),
+            slot(531, 10, 41, 41, F1M1, true),
+            slot(531, 5, 42, 42, F1M1, true),
+            slot(531, 5, 42, 42, F1M1, true),
+            slot(531, 4, 42, 42, F1M1, true),
+            slot(184, 4, 42, 42, F1M1, false),
+            slot(531, 1, 42, 42, F1M1, true),
+            slot(184, 1, 42, 42, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: ShakingGrass,
+        location_key: "route_23",
+        slots: &[
+            slot(531, 20, 48, 48, F1M1, true),
+            slot(531, 20, 48, 48, F1M1, true),
+            slot(587, 10, 49, 49, F1M1, true),
+            slot(531, 10, 49, 49, F1M1, true),
+            slot(531, 10, 50, 50, F1M1, true),
+            slot(531, 10, 50, 50, F1M1, true),
+            slot(531, 5, 51, 51, F1M1, true),
+            slot(531, 5, 51, 51, F1M1, true),
+            slot(472, 4, 51, 51, F1M1, false),
+            slot(539, 4, 51, 51, MaleOnly, true),
+            slot(472, 1, 51, 51, F1M1, false),
+            slot(539, 1, 51, 51, MaleOnly, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: DustCloud,
+        location_key: "chargestone_cave_1f",
+        slots: &[
+            slot(529, 20, 25, 25, F1M1, false),
+            slot(529, 20, 25, 25, F1M1, false),
+            slot(529, 10, 26, 26, F1M1, false),
+            slot(529, 10, 26, 26, F1M1, false),
+            slot(529, 10, 27, 27, F1M1, false),
+            slot(529, 10, 27, 27, F1M1, false),
+            slot(529, 5, 28, 28, F1M1, false),
+            slot(529, 5, 28, 28, F1M1, false),
+            slot(529, 4, 28, 28, F1M1, false),
+            slot(529, 4, 28, 28, F1M1, false),
+            slot(529, 1, 28, 28, F1M1, false),
+            slot(529, 1, 28, 28, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: DustCloud,
+        location_key: "chargestone_cave_b1f",
+        slots: &[
+            slot(529, 20, 28, 28, F1M1, false),
+            slot(529, 20, 28, 28, F1M1, false),
+            slot(529, 10, 29, 29, F1M1, false),
+            slot(529, 10, 29, 29, F1M1, false),
+            slot(529, 10, 30, 30, F1M1, false),
+            slot(529, 10, 30, 30, F1M1, false),
+            slot(529, 5, 31, 31, F1M1, false),
+            slot(529, 5, 31, 31, F1M1, false),
+            slot(529, 4, 31, 31, F1M1, false),
+            slot(529, 4, 31, 31, F1M1, false),
+            slot(529, 1, 31, 31, F1M1, false),
+            slot(529, 1, 31, 31, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: DustCloud,
+        location_key: "twist_mountain_spring",
+        slots: &[
+            slot(530, 20, 54, 54, F1M1, false),
+            slot(95, 20, 54, 54, F1M1, false),
+            slot(530, 10, 55, 55, F1M1, false),
+            slot(530, 10, 55, 55, F1M1, false),
+            slot(530, 10, 56, 56, F1M1, false),
+            slot(530, 10, 56, 56, F1M1, false),
+            slot(530, 5, 57, 57, F1M1, false),
+            slot(530, 5, 57, 57, F1M1, false),
+            slot(530, 4, 57, 57, F1M1, false),
+            slot(208, 4, 57, 57, F1M1, true),
+            slot(530, 1, 57, 57, F1M1, false),
+            slot(208, 1, 57, 57, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: DustCloud,
+        location_key: "twist_mountain_summer",
+        slots: &[
+            slot(530, 20, 54, 54, F1M1, false),
+            slot(95, 20, 54, 54, F1M1, false),
+            slot(530, 10, 55, 55, F1M1, false),
+            slot(530, 10, 55, 55, F1M1, false),
+            slot(530, 10, 56, 56, F1M1, false),
+            slot(530, 10, 56, 56, F1M1, false),
+            slot(530, 5, 57, 57, F1M1, false),
+            slot(530, 5, 57, 57, F1M1, false),
+            slot(530, 4, 57, 57, F1M1, false),
+            slot(208, 4, 57, 57, F1M1, true),
+            slot(530, 1, 57, 57, F1M1, false),
+            slot(208, 1, 57, 57, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: DustCloud,
+        location_key: "twist_mountain_autumn",
+        slots: &[
+            slot(530, 20, 54, 54, F1M1, false),
+            slot(95, 20, 54, 54, F1M1, false),
+            slot(530, 10, 55, 55, F1M1, false),
+            slot(530, 10, 55, 55, F1M1, false),
+            slot(530, 10, 56, 56, F1M1, false),
+            slot(530, 10, 56, 56, F1M1, false),
+            slot(530, 5, 57, 57, F1M1, false),
+            slot(530, 5, 57, 57, F1M1, false),
+            slot(530, 4, 57, 57, F1M1, false),
+            slot(208, 4, 57, 57, F1M1, true),
+            slot(530, 1, 57, 57, F1M1, false),
+            slot(208, 1, 57, 57, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: DustCloud,
+        location_key: "twist_mountain_winter",
+        slots: &[
+            slot(530, 20, 54, 54, F1M1, false),
+            slot(95, 20, 54, 54, F1M1, false),
+            slot(530, 10, 55, 55, F1M1, false),
+            slot(530, 10, 55, 55, F1M1, false),
+            slot(530, 10, 56, 56, F1M1, false),
+            slot(530, 10, 56, 56, F1M1, false),
+            slot(530, 5, 57, 57, F1M1, false),
+            slot(530, 5, 57, 57, F1M1, false),
+            slot(530, 4, 57, 57, F1M1, false),
+            slot(208, 4, 57, 57, F1M1, true),
+            slot(530, 1, 57, 57, F1M1, false),
+            slot(208, 1, 57, 57, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: DustCloud,
+        location_key: "victory_road_1f",
+        slots: &[
+            slot(530, 20, 47, 47, F1M1, false),
+            slot(530, 20, 47, 47, F1M1, false),
+            slot(530, 10, 48, 48, F1M1, false),
+            slot(530, 10, 48, 48, F1M1, false),
+            slot(530, 10, 49, 49, F1M1, false),
+            slot(530, 10, 49, 49, F1M1, false),
+            slot(530, 5, 50, 50, F1M1, false),
+            slot(530, 5, 50, 50, F1M1, false),
+            slot(530, 4, 50, 50, F1M1, false),
+            slot(530, 4, 50, 50, F1M1, false),
+            slot(530, 1, 50, 50, F1M1, false),
+            slot(530, 1, 50, 50, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: DustCloud,
+        location_key: "victory_road_2f",
+        slots: &[
+            slot(530, 20, 41, 41, F1M1, false),
+            slot(95, 20, 41, 41, F1M1, false),
+            slot(530, 10, 42, 42, F1M1, false),
+            slot(530, 10, 42, 42, F1M1, false),
+            slot(530, 10, 43, 43, F1M1, false),
+            slot(530, 10, 43, 43, F1M1, false),
+            slot(530, 5, 44, 44, F1M1, false),
+            slot(530, 5, 44, 44, F1M1, false),
+            slot(530, 4, 44, 44, F1M1, false),
+            slot(530, 4, 44, 44, F1M1, false),
+            slot(530, 1, 44, 44, F1M1, false),
+            slot(530, 1, 44, 44, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: DustCloud,
+        location_key: "victory_road_3f_4f_5f",
+        slots: &[
+            slot(530, 20, 47, 47, F1M1, false),
+            slot(95, 20, 47, 47, F1M1, false),
+            slot(530, 10, 48, 48, F1M1, false),
+            slot(530, 10, 48, 48, F1M1, false),
+            slot(530, 10, 49, 49, F1M1, false),
+            slot(530, 10, 49, 49, F1M1, false),
+            slot(530, 5, 50, 50, F1M1, false),
+            slot(530, 5, 50, 50, F1M1, false),
+            slot(530, 4, 50, 50, F1M1, false),
+            slot(530, 4, 50, 50, F1M1, false),
+            slot(530, 1, 50, 50, F1M1, false),
+            slot(530, 1, 50, 50, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: DustCloud,
+        location_key: "giant_chasm",
+        slots: &[
+            slot(530, 20, 44, 44, F1M1, false),
+            slot(530, 20, 44, 44, F1M1, false),
+            slot(530, 10, 45, 45, F1M1, false),
+            slot(530, 10, 45, 45, F1M1, false),
+            slot(530, 10, 46, 46, F1M1, false),
+            slot(530, 10, 46, 46, F1M1, false),
+            slot(530, 5, 47, 47, F1M1, false),
+            slot(530, 5, 47, 47, F1M1, false),
+            slot(530, 4, 47, 47, F1M1, false),
+            slot(530, 4, 47, 47, F1M1, false),
+            slot(530, 1, 47, 47, F1M1, false),
+            slot(530, 1, 47, 47, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: DustCloud,
+        location_key: "reversal_mountain",
+        slots: &[
+            slot(530, 20, 32, 32, F1M1, false),
+            slot(530, 20, 32, 32, F1M1, false),
+            slot(530, 10, 33, 33, F1M1, false),
+            slot(530, 10, 33, 33, F1M1, false),
+            slot(530, 10, 34, 34, F1M1, false),
+            slot(530, 10, 34, 34, F1M1, false),
+            slot(530, 5, 35, 35, F1M1, false),
+            slot(530, 5, 35, 35, F1M1, false),
+            slot(530, 4, 35, 35, F1M1, false),
+            slot(530, 4, 35, 35, F1M1, false),
+            slot(530, 1, 35, 35, F1M1, false),
+            slot(530, 1, 35, 35, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: DustCloud,
+        location_key: "relic_passage_south",
+        slots: &[
+            slot(529, 20, 16, 16, F1M1, false),
+            slot(95, 20, 16, 16, F1M1, false),
+            slot(529, 10, 17, 17, F1M1, false),
+            slot(529, 10, 17, 17, F1M1, false),
+            slot(529, 10, 18, 18, F1M1, false),
+            slot(529, 10, 18, 18, F1M1, false),
+            slot(529, 5, 19, 19, F1M1, false),
+            slot(529, 5, 19, 19, F1M1, false),
+            slot(529, 4, 19, 19, F1M1, false),
+            slot(529, 4, 19, 19, F1M1, false),
+            slot(529, 1, 19, 19, F1M1, false),
+            slot(529, 1, 19, 19, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: DustCloud,
+        location_key: "relic_passage_north",
+        slots: &[
+            slot(529, 20, 27, 27, F1M1, false),
+            slot(95, 20, 27, 27, F1M1, false),
+            slot(529, 10, 28, 28, F1M1, false),
+            slot(529, 10, 28, 28, F1M1, false),
+            slot(529, 10, 29, 29, F1M1, false),
+            slot(529, 10, 29, 29, F1M1, false),
+            slot(529, 5, 30, 30, F1M1, false),
+            slot(529, 5, 30, 30, F1M1, false),
+            slot(529, 4, 30, 30, F1M1, false),
+            slot(529, 4, 30, 30, F1M1, false),
+            slot(529, 1, 30, 30, F1M1, false),
+            slot(529, 1, 30, 30, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: DustCloud,
+        location_key: "clay_tunnel",
+        slots: &[
+            slot(530, 20, 54, 54, F1M1, false),
+            slot(95, 20, 54, 54, F1M1, false),
+            slot(530, 10, 55, 55, F1M1, false),
+            slot(530, 10, 55, 55, F1M1, false),
+            slot(530, 10, 56, 56, F1M1, false),
+            slot(530, 10, 56, 56, F1M1, false),
+            slot(530, 5, 57, 57, F1M1, false),
+            slot(530, 5, 57, 57, F1M1, false),
+            slot(530, 4, 57, 57, F1M1, false),
+            slot(208, 4, 57, 57, F1M1, true),
+            slot(530, 1, 57, 57, F1M1, false),
+            slot(208, 1, 57, 57, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: DustCloud,
+        location_key: "underground_ruins",
+        slots: &[
+            slot(530, 20, 54, 54, F1M1, false),
+            slot(95, 20, 54, 54, F1M1, false),
+            slot(530, 10, 55, 55, F1M1, false),
+            slot(530, 10, 55, 55, F1M1, false),
+            slot(530, 10, 56, 56, F1M1, false),
+            slot(530, 10, 56, 56, F1M1, false),
+            slot(530, 5, 57, 57, F1M1, false),
+            slot(530, 5, 57, 57, F1M1, false),
+            slot(530, 4, 57, 57, F1M1, false),
+            slot(208, 4, 57, 57, F1M1, true),
+            slot(530, 1, 57, 57, F1M1, false),
+            slot(208, 1, 57, 57, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: DustCloud,
+        location_key: "rocky_mountain_chamber",
+        slots: &[
+            slot(530, 20, 54, 54, F1M1, false),
+            slot(95, 20, 54, 54, F1M1, false),
+            slot(530, 10, 55, 55, F1M1, false),
+            slot(530, 10, 55, 55, F1M1, false),
+            slot(530, 10, 56, 56, F1M1, false),
+            slot(530, 10, 56, 56, F1M1, false),
+            slot(530, 5, 57, 57, F1M1, false),
+            slot(530, 5, 57, 57, F1M1, false),
+            slot(530, 4, 57, 57, F1M1, false),
+            slot(208, 4, 57, 57, F1M1, true),
+            slot(530, 1, 57, 57, F1M1, false),
+            slot(208, 1, 57, 57, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: DustCloud,
+        location_key: "iceberg_chamber",
+        slots: &[
+            slot(530, 20, 54, 54, F1M1, false),
+            slot(95, 20, 54, 54, F1M1, false),
+            slot(530, 10, 55, 55, F1M1, false),
+            slot(530, 10, 55, 55, F1M1, false),
+            slot(530, 10, 56, 56, F1M1, false),
+            slot(530, 10, 56, 56, F1M1, false),
+            slot(530, 5, 57, 57, F1M1, false),
+            slot(530, 5, 57, 57, F1M1, false),
+            slot(530, 4, 57, 57, F1M1, false),
+            slot(208, 4, 57, 57, F1M1, true),
+            slot(530, 1, 57, 57, F1M1, false),
+            slot(208, 1, 57, 57, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: DustCloud,
+        location_key: "iron_chamber",
+        slots: &[
+            slot(530, 20, 54, 54, F1M1, false),
+            slot(95, 20, 54, 54, F1M1, false),
+            slot(530, 10, 55, 55, F1M1, false),
+            slot(530, 10, 55, 55, F1M1, false),
+            slot(530, 10, 56, 56, F1M1, false),
+            slot(530, 10, 56, 56, F1M1, false),
+            slot(530, 5, 57, 57, F1M1, false),
+            slot(530, 5, 57, 57, F1M1, false),
+            slot(530, 4, 57, 57, F1M1, false),
+            slot(208, 4, 57, 57, F1M1, true),
+            slot(530, 1, 57, 57, F1M1, false),
+            slot(208, 1, 57, 57, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: DustCloud,
+        location_key: "seaside_cave_1f",
+        slots: &[
+            slot(530, 20, 34, 34, F1M1, false),
+            slot(530, 20, 34, 34, F1M1, false),
+            slot(530, 10, 35, 35, F1M1, false),
+            slot(530, 10, 35, 35, F1M1, false),
+            slot(530, 10, 36, 36, F1M1, false),
+            slot(530, 10, 36, 36, F1M1, false),
+            slot(530, 5, 37, 37, F1M1, false),
+            slot(530, 5, 37, 37, F1M1, false),
+            slot(530, 4, 37, 37, F1M1, false),
+            slot(530, 4, 37, 37, F1M1, false),
+            slot(530, 1, 37, 37, F1M1, false),
+            slot(530, 1, 37, 37, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: DustCloud,
+        location_key: "seaside_cave_b1f",
+        slots: &[
+            slot(530, 20, 39, 39, F1M1, false),
+            slot(530, 20, 39, 39, F1M1, false),
+            slot(530, 10, 40, 40, F1M1, false),
+            slot(530, 10, 40, 40, F1M1, false),
+            slot(530, 10, 41, 41, F1M1, false),
+            slot(530, 10, 41, 41, F1M1, false),
+            slot(530, 5, 42, 42, F1M1, false),
+            slot(530, 5, 42, 42, F1M1, false),
+            slot(530, 4, 42, 42, F1M1, false),
+            slot(530, 4, 42, 42, F1M1, false),
+            slot(530, 1, 42, 42, F1M1, false),
+            slot(530, 1, 42, 42, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: DustCloud,
+        location_key: "wellspring_cave",
+        slots: &[
+            slot(530, 20, 55, 55, F1M1, false),
+            slot(530, 20, 55, 55, F1M1, false),
+            slot(530, 10, 56, 56, F1M1, false),
+            slot(530, 10, 56, 56, F1M1, false),
+            slot(530, 10, 57, 57, F1M1, false),
+            slot(530, 10, 57, 57, F1M1, false),
+            slot(530, 5, 58, 58, F1M1, false),
+            slot(530, 5, 58, 58, F1M1, false),
+            slot(530, 4, 58, 58, F1M1, false),
+            slot(530, 4, 58, 58, F1M1, false),
+            slot(530, 1, 58, 58, F1M1, false),
+            slot(530, 1, 58, 58, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: DustCloud,
+        location_key: "mistralton_cave",
+        slots: &[
+            slot(529, 20, 27, 27, F1M1, false),
+            slot(529, 20, 27, 27, F1M1, false),
+            slot(529, 10, 28, 28, F1M1, false),
+            slot(529, 10, 28, 28, F1M1, false),
+            slot(529, 10, 29, 29, F1M1, false),
+            slot(529, 10, 29, 29, F1M1, false),
+            slot(529, 5, 30, 30, F1M1, false),
+            slot(529, 5, 30, 30, F1M1, false),
+            slot(529, 4, 30, 30, F1M1, false),
+            slot(529, 4, 30, 30, F1M1, false),
+            slot(529, 1, 30, 30, F1M1, false),
+            slot(529, 1, 30, 30, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: DustCloud,
+        location_key: "guidance_chamber",
+        slots: &[
+            slot(529, 20, 27, 27, F1M1, false),
+            slot(529, 20, 27, 27, F1M1, false),
+            slot(529, 10, 28, 28, F1M1, false),
+            slot(529, 10, 28, 28, F1M1, false),
+            slot(529, 10, 29, 29, F1M1, false),
+            slot(529, 10, 29, 29, F1M1, false),
+            slot(529, 5, 30, 30, F1M1, false),
+            slot(529, 5, 30, 30, F1M1, false),
+            slot(529, 4, 30, 30, F1M1, false),
+            slot(529, 4, 30, 30, F1M1, false),
+            slot(529, 1, 30, 30, F1M1, false),
+            slot(529, 1, 30, 30, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: PokemonShadow,
+        location_key: "driftveil_drawbridge",
+        slots: &[
+            slot(580, 20, 23, 23, F1M1, false),
+            slot(580, 20, 23, 23, F1M1, false),
+            slot(580, 10, 24, 24, F1M1, false),
+            slot(580, 10, 24, 24, F1M1, false),
+            slot(580, 10, 25, 25, F1M1, false),
+            slot(580, 10, 25, 25, F1M1, false),
+            slot(580, 5, 26, 26, F1M1, false),
+            slot(580, 5, 25, 25, F1M1, false),
+            slot(580, 4, 26, 26, F1M1, false),
+            slot(580, 4, 26, 26, F1M1, false),
+            slot(580, 1, 26, 26, F1M1, false),
+            slot(580, 1, 26, 26, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: PokemonShadow,
+        location_key: "marvelous_bridge",
+        slots: &[
+            slot(581, 20, 54, 54, F1M1, false),
+            slot(581, 20, 54, 54, F1M1, false),
+            slot(581, 10, 55, 55, F1M1, false),
+            slot(581, 10, 55, 55, F1M1, false),
+            slot(581, 10, 56, 56, F1M1, false),
+            slot(581, 10, 56, 56, F1M1, false),
+            slot(581, 5, 57, 57, F1M1, false),
+            slot(581, 5, 57, 57, F1M1, false),
+            slot(581, 4, 57, 57, F1M1, false),
+            slot(581, 4, 57, 57, F1M1, false),
+            slot(581, 1, 57, 57, F1M1, false),
+            slot(581, 1, 57, 57, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "striaton_city",
+        slots: &[
+            slot(550, 60, 45, 60, F1M1, true),
+            slot(341, 30, 45, 60, F1M1, false),
+            slot(550, 5, 50, 60, F1M1, true),
+            slot(550, 4, 50, 60, F1M1, true),
+            slot(550, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "icirrus_city_spring",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(618, 5, 60, 60, F1M1, true),
+            slot(618, 4, 60, 60, F1M1, true),
+            slot(618, 1, 60, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "icirrus_city_summer",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(618, 5, 60, 60, F1M1, true),
+            slot(618, 4, 60, 60, F1M1, true),
+            slot(618, 1, 60, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "icirrus_city_autumn",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(618, 5, 60, 60, F1M1, true),
+            slot(618, 4, 60, 60, F1M1, true),
+            slot(618, 1, 60, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "icirrus_city_winter",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(618, 5, 50, 60, F1M1, true),
+            slot(618, 4, 50, 60, F1M1, true),
+            slot(618, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "aspertia_city",
+        slots: &[
+            slot(550, 60, 5, 15, F1M1, true),
+            slot(550, 30, 5, 15, F1M1, true),
+            slot(550, 5, 5, 15, F1M1, true),
+            slot(550, 4, 5, 15, F1M1, true),
+            slot(550, 1, 5, 15, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "virbank_city",
+        slots: &[
+            slot(592, 60, 5, 15, F1M1, false),
+            slot(592, 30, 5, 15, F1M1, false),
+            slot(592, 5, 5, 15, F1M1, false),
+            slot(592, 4, 5, 15, F1M1, false),
+            slot(592, 1, 5, 15, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "humilau_city",
+        slots: &[
+            slot(592, 60, 30, 45, F1M1, false),
+            slot(120, 30, 30, 45, Genderless, true),
+            slot(550, 5, 35, 45, F1M1, true),
+            slot(592, 4, 35, 45, F1M1, false),
+            slot(592, 1, 35, 45, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "pinwheel_forest",
+        slots: &[
+            slot(550, 60, 45, 60, F1M1, true),
+            slot(183, 30, 45, 60, F1M1, false),
+            slot(550, 5, 50, 60, F1M1, true),
+            slot(550, 4, 50, 60, F1M1, true),
+            slot(550, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "dragonspiral_tower_spring",
+        slots: &[
+            slot(550, 60, 45, 60, F1M1, true),
+            slot(550, 30, 45, 60, F1M1, true),
+            slot(550, 5, 50, 60, F1M1, true),
+            slot(550, 4, 50, 60, F1M1, true),
+            slot(550, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "dragonspiral_tower_summer",
+        slots: &[
+            slot(550, 60, 45, 60, F1M1, true),
+            slot(550, 30, 45, 60, F1M1, true),
+            slot(550, 5, 50, 60, F1M1, true),
+            slot(550, 4, 50, 60, F1M1, true),
+            slot(550, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "dragonspiral_tower_autumn",
+        slots: &[
+            slot(550, 60, 45, 60, F1M1, true),
+            slot(550, 30, 45, 60, F1M1, true),
+            slot(550, 5, 50, 60, F1M1, true),
+            slot(550, 4, 50, 60, F1M1, true),
+            slot(550, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "dragonspiral_tower_winter",
+        slots: &[
+            slot(550, 60, 45, 60, F1M1, true),
+            slot(550, 30, 45, 60, F1M1, true),
+            slot(550, 5, 50, 60, F1M1, true),
+            slot(550, 4, 50, 60, F1M1, true),
+            slot(550, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "giant_chasm",
+        slots: &[
+            slot(550, 60, 35, 50, F1M1, true),
+            slot(86, 30, 35, 50, F1M1, false),
+            slot(550, 5, 40, 50, F1M1, true),
+            slot(550, 4, 40, 50, F1M1, true),
+            slot(550, 1, 40, 50, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "castelia_sewers",
+        slots: &[
+            slot(88, 60, 5, 15, F1M1, true),
+            slot(88, 30, 5, 15, F1M1, true),
+            slot(88, 5, 5, 15, F1M1, true),
+            slot(88, 4, 5, 15, F1M1, true),
+            slot(88, 1, 5, 15, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "p2_laboratory",
+        slots: &[
+            slot(592, 60, 45, 60, F1M1, false),
+            slot(592, 30, 45, 60, F1M1, false),
+            slot(592, 5, 50, 60, F1M1, false),
+            slot(592, 4, 50, 60, F1M1, false),
+            slot(592, 1, 50, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "undella_bay_spring",
+        slots: &[
+            slot(592, 60, 25, 40, F1M1, false),
+            slot(458, 30, 25, 40, F1M1, false),
+            slot(223, 5, 30, 40, F1M1, false),
+            slot(592, 4, 30, 40, F1M1, false),
+            slot(592, 1, 30, 40, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "undella_bay_summer",
+        slots: &[
+            slot(592, 60, 25, 40, F1M1, false),
+            slot(458, 30, 25, 40, F1M1, false),
+            slot(223, 5, 30, 40, F1M1, false),
+            slot(592, 4, 30, 40, F1M1, false),
+            slot(592, 1, 30, 40, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "undella_bay_autumn",
+        slots: &[
+            slot(592, 60, 25, 40, F1M1, false),
+            slot(458, 30, 25, 40, F1M1, false),
+            slot(223, 5, 30, 40, F1M1, false),
+            slot(592, 4, 30, 40, F1M1, false),
+            slot(592, 1, 30, 40, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "undella_bay_winter",
+        slots: &[
+            slot(592, 60, 25, 40, F1M1, false),
+            slot(363, 30, 25, 40, F1M1, false),
+            slot(223, 5, 30, 40, F1M1, false),
+            slot(592, 4, 30, 40, F1M1, false),
+            slot(592, 1, 30, 40, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "floccesy_ranch",
+        slots: &[
+            slot(550, 60, 5, 15, F1M1, true),
+            slot(298, 30, 5, 15, F3M1, false),
+            slot(550, 5, 5, 15, F1M1, true),
+            slot(550, 4, 5, 15, F1M1, true),
+            slot(550, 1, 5, 15, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "virbank_complex",
+        slots: &[
+            slot(592, 60, 5, 15, F1M1, false),
+            slot(592, 30, 5, 15, F1M1, false),
+            slot(592, 5, 5, 15, F1M1, false),
+            slot(592, 4, 5, 15, F1M1, false),
+            slot(592, 1, 5, 15, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "victory_road",
+        slots: &[
+            slot(550, 60, 35, 50, F1M1, true),
+            slot(183, 30, 35, 50, F1M1, false),
+            slot(550, 5, 40, 50, F1M1, true),
+            slot(550, 4, 40, 50, F1M1, true),
+            slot(550, 1, 40, 50, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "relic_passage",
+        slots: &[
+            slot(550, 60, 10, 30, F1M1, true),
+            slot(183, 30, 10, 30, F1M1, false),
+            slot(550, 5, 10, 30, F1M1, true),
+            slot(550, 4, 10, 30, F1M1, true),
+            slot(550, 1, 10, 30, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "clay_tunnel",
+        slots: &[
+            slot(550, 60, 45, 60, F1M1, true),
+            slot(550, 30, 45, 60, F1M1, true),
+            slot(550, 5, 50, 60, F1M1, true),
+            slot(550, 4, 50, 60, F1M1, true),
+            slot(550, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "seaside_cave",
+        slots: &[
+            slot(592, 60, 25, 40, F1M1, false),
+            slot(86, 30, 25, 40, F1M1, false),
+            slot(592, 5, 30, 40, F1M1, false),
+            slot(592, 4, 30, 40, F1M1, false),
+            slot(592, 1, 30, 40, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "nature_preserve",
+        slots: &[
+            slot(550, 60, 45, 60, F1M1, true),
+            slot(418, 30, 45, 60, F1M1, false),
+            slot(550, 5, 50, 60, F1M1, true),
+            slot(550, 4, 50, 60, F1M1, true),
+            slot(550, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "village_bridge",
+        slots: &[
+            slot(550, 60, 25, 40, F1M1, true),
+            slot(183, 30, 25, 40, F1M1, false),
+            slot(550, 5, 30, 40, F1M1, true),
+            slot(550, 4, 30, 40, F1M1, true),
+            slot(550, 1, 30, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "route_1",
+        slots: &[
+            slot(550, 60, 45, 60, F1M1, true),
+            slot(550, 30, 45, 60, F1M1, true),
+            slot(550, 5, 50, 60, F1M1, true),
+            slot(550, 4, 50, 60, F1M1, true),
+            slot(550, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "route_3",
+        slots: &[
+            slot(550, 60, 45, 60, F1M1, true),
+            slot(341, 30, 45, 60, F1M1, false),
+            slot(550, 5, 50, 60, F1M1, true),
+            slot(550, 4, 50, 60, F1M1, true),
+            slot(550, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "wellspring_cave",
+        slots: &[
+            slot(550, 60, 45, 60, F1M1, true),
+            slot(550, 30, 45, 60, F1M1, true),
+            slot(550, 5, 50, 60, F1M1, true),
+            slot(550, 4, 50, 60, F1M1, true),
+            slot(550, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "route_4",
+        slots: &[
+            slot(592, 60, 5, 15, F1M1, false),
+            slot(592, 30, 5, 15, F1M1, false),
+            slot(592, 5, 5, 15, F1M1, false),
+            slot(592, 4, 5, 15, F1M1, false),
+            slot(592, 1, 5, 15, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "route_6_spring",
+        slots: &[
+            slot(550, 60, 10, 30, F1M1, true),
+            slot(183, 30, 10, 30, F1M1, false),
+            slot(550, 5, 10, 30, F1M1, true),
+            slot(550, 4, 10, 30, F1M1, true),
+            slot(550, 1, 10, 30, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "route_6_summer",
+        slots: &[
+            slot(550, 60, 10, 30, F1M1, true),
+            slot(183, 30, 10, 30, F1M1, false),
+            slot(550, 5, 10, 30, F1M1, true),
+            slot(550, 4, 10, 30, F1M1, true),
+            slot(550, 1, 10, 30, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "route_6_autumn",
+        slots: &[
+            slot(550, 60, 10, 30, F1M1, true),
+            slot(183, 30, 10, 30, F1M1, false),
+            slot(550, 5, 10, 30, F1M1, true),
+            slot(550, 4, 10, 30, F1M1, true),
+            slot(550, 1, 10, 30, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "route_6_winter",
+        slots: &[
+            slot(550, 60, 10, 30, F1M1, true),
+            slot(183, 30, 10, 30, F1M1, false),
+            slot(550, 5, 10, 30, F1M1, true),
+            slot(550, 4, 10, 30, F1M1, true),
+            slot(550, 1, 10, 30, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "route_8_spring",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(536, 30, 45, 60, F1M1, true),
+            slot(618, 5, 60, 60, F1M1, true),
+            slot(618, 4, 60, 60, F1M1, true),
+            slot(618, 1, 60, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "route_8_summer",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(536, 30, 45, 60, F1M1, true),
+            slot(618, 5, 50, 60, F1M1, true),
+            slot(618, 4, 50, 60, F1M1, true),
+            slot(618, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "route_8_autumn",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(536, 30, 45, 60, F1M1, true),
+            slot(618, 5, 50, 60, F1M1, true),
+            slot(618, 4, 50, 60, F1M1, true),
+            slot(618, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "route_8_winter",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(536, 30, 45, 60, F1M1, true),
+            slot(618, 5, 50, 60, F1M1, true),
+            slot(618, 4, 50, 60, F1M1, true),
+            slot(618, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "moor_of_icirrus_spring",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(618, 5, 60, 60, F1M1, true),
+            slot(618, 4, 60, 60, F1M1, true),
+            slot(618, 1, 60, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "moor_of_icirrus_summer",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(618, 5, 60, 60, F1M1, true),
+            slot(618, 4, 60, 60, F1M1, true),
+            slot(618, 1, 60, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "moor_of_icirrus_autumn",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(618, 5, 60, 60, F1M1, true),
+            slot(618, 4, 60, 60, F1M1, true),
+            slot(618, 1, 60, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "moor_of_icirrus_winter",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(618, 5, 50, 60, F1M1, true),
+            slot(618, 4, 50, 60, F1M1, true),
+            slot(618, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "route_11",
+        slots: &[
+            slot(550, 60, 25, 40, F1M1, true),
+            slot(418, 30, 25, 40, F1M1, false),
+            slot(550, 5, 30, 40, F1M1, true),
+            slot(550, 4, 30, 40, F1M1, true),
+            slot(550, 1, 30, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "route_13",
+        slots: &[
+            slot(592, 60, 25, 40, F1M1, false),
+            slot(120, 30, 25, 40, Genderless, true),
+            slot(550, 5, 30, 40, F1M1, true),
+            slot(592, 4, 30, 40, F1M1, false),
+            slot(592, 1, 30, 40, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "route_14",
+        slots: &[
+            slot(550, 60, 25, 40, F1M1, true),
+            slot(418, 30, 25, 40, F1M1, false),
+            slot(550, 5, 30, 40, F1M1, true),
+            slot(550, 4, 30, 40, F1M1, true),
+            slot(550, 1, 30, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "abundant_shrine",
+        slots: &[
+            slot(550, 60, 25, 40, F1M1, true),
+            slot(183, 30, 25, 40, F1M1, false),
+            slot(550, 5, 30, 40, F1M1, true),
+            slot(550, 4, 30, 40, F1M1, true),
+            slot(550, 1, 30, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "lostlorn_forest",
+        slots: &[
+            slot(550, 60, 10, 25, F1M1, true),
+            slot(418, 30, 10, 25, F1M1, false),
+            slot(550, 5, 10, 25, F1M1, true),
+            slot(550, 4, 10, 25, F1M1, true),
+            slot(550, 1, 10, 25, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "route_18",
+        slots: &[
+            slot(592, 60, 45, 60, F1M1, false),
+            slot(592, 30, 45, 60, F1M1, false),
+            slot(592, 5, 50, 60, F1M1, false),
+            slot(592, 4, 50, 60, F1M1, false),
+            slot(592, 1, 50, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "route_19",
+        slots: &[
+            slot(550, 60, 5, 15, F1M1, true),
+            slot(550, 30, 5, 15, F1M1, true),
+            slot(550, 5, 5, 15, F1M1, true),
+            slot(550, 4, 5, 15, F1M1, true),
+            slot(550, 1, 5, 15, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "route_20_spring",
+        slots: &[
+            slot(550, 60, 5, 15, F1M1, true),
+            slot(298, 30, 5, 15, F3M1, false),
+            slot(550, 5, 5, 15, F1M1, true),
+            slot(550, 4, 5, 15, F1M1, true),
+            slot(550, 1, 5, 15, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "route_20_summer",
+        slots: &[
+            slot(550, 60, 5, 15, F1M1, true),
+            slot(298, 30, 5, 15, F3M1, false),
+            slot(550, 5, 5, 15, F1M1, true),
+            slot(550, 4, 5, 15, F1M1, true),
+            slot(550, 1, 5, 15, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "route_20_autumn",
+        slots: &[
+            slot(550, 60, 5, 15, F1M1, true),
+            slot(298, 30, 5, 15, F3M1, false),
+            slot(550, 5, 5, 15, F1M1, true),
+            slot(550, 4, 5, 15, F1M1, true),
+            slot(550, 1, 5, 15, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "route_20_winter",
+        slots: &[
+            slot(550, 60, 5, 15, F1M1, true),
+            slot(298, 30, 5, 15, F3M1, false),
+            slot(550, 5, 5, 15, F1M1, true),
+            slot(550, 4, 5, 15, F1M1, true),
+            slot(550, 1, 5, 15, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "route_22",
+        slots: &[
+            slot(550, 60, 15, 40, F1M1, true),
+            slot(183, 30, 15, 40, F1M1, false),
+            slot(550, 5, 15, 40, F1M1, true),
+            slot(550, 4, 15, 40, F1M1, true),
+            slot(550, 1, 15, 40, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "route_23",
+        slots: &[
+            slot(550, 60, 40, 55, F1M1, true),
+            slot(418, 30, 40, 55, F1M1, false),
+            slot(550, 5, 45, 55, F1M1, true),
+            slot(550, 4, 45, 55, F1M1, true),
+            slot(550, 1, 45, 55, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "undella_town",
+        slots: &[
+            slot(592, 60, 25, 40, F1M1, false),
+            slot(120, 30, 25, 40, Genderless, true),
+            slot(550, 5, 30, 40, F1M1, true),
+            slot(592, 4, 30, 40, F1M1, false),
+            slot(592, 1, 30, 40, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "route_17",
+        slots: &[
+            slot(592, 60, 45, 60, F1M1, false),
+            slot(592, 30, 45, 60, F1M1, false),
+            slot(592, 5, 50, 60, F1M1, false),
+            slot(592, 4, 50, 60, F1M1, false),
+            slot(592, 1, 50, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Surfing,
+        location_key: "route_21",
+        slots: &[
+            slot(592, 60, 30, 45, F1M1, false),
+            slot(458, 30, 30, 45, F1M1, false),
+            slot(223, 5, 35, 45, F1M1, false),
+            slot(592, 4, 35, 45, F1M1, false),
+            slot(592, 1, 35, 45, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "striaton_city",
+        slots: &[
+            slot(550, 60, 45, 60, F1M1, true),
+            slot(550, 30, 45, 60, F1M1, true),
+            slot(342, 5, 50, 60, F1M1, false),
+            slot(342, 4, 50, 60, F1M1, false),
+            slot(342, 1, 50, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "icirrus_city_spring",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(618, 5, 60, 60, F1M1, true),
+            slot(537, 4, 60, 60, F1M1, true),
+            slot(537, 1, 60, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "icirrus_city_summer",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(618, 5, 60, 60, F1M1, true),
+            slot(537, 4, 60, 60, F1M1, true),
+            slot(537, 1, 60, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "icirrus_city_autumn",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(618, 5, 60, 60, F1M1, true),
+            slot(537, 4, 60, 60, F1M1, true),
+            slot(537, 1, 60, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "icirrus_city_winter",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(618, 5, 50, 60, F1M1, true),
+            slot(537, 4, 50, 60, F1M1, true),
+            slot(537, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "aspertia_city",
+        slots: &[
+            slot(550, 60, 5, 15, F1M1, true),
+            slot(550, 30, 5, 15, F1M1, true),
+            slot(550, 5, 5, 15, F1M1, true),
+            slot(550, 4, 5, 15, F1M1, true),
+            slot(550, 1, 5, 15, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "virbank_city",
+        slots: &[
+            slot(594, 60, 5, 15, F1M1, false),
+            slot(594, 30, 5, 15, F1M1, false),
+            slot(594, 5, 5, 15, F1M1, false),
+            slot(593, 4, 5, 15, F1M1, false),
+            slot(593, 1, 5, 15, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "humilau_city",
+        slots: &[
+            slot(593, 60, 35, 45, F1M1, false),
+            slot(222, 30, 35, 45, F3M1, true),
+            slot(550, 5, 35, 45, F1M1, true),
+            slot(121, 4, 35, 45, Genderless, true),
+            slot(121, 1, 35, 45, Genderless, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "pinwheel_forest",
+        slots: &[
+            slot(183, 60, 45, 60, F1M1, false),
+            slot(550, 30, 45, 60, F1M1, true),
+            slot(550, 5, 50, 60, F1M1, true),
+            slot(184, 4, 50, 60, F1M1, false),
+            slot(184, 1, 50, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "dragonspiral_tower_spring",
+        slots: &[
+            slot(550, 60, 45, 60, F1M1, true),
+            slot(550, 30, 45, 60, F1M1, true),
+            slot(550, 5, 50, 60, F1M1, true),
+            slot(550, 4, 50, 60, F1M1, true),
+            slot(550, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "dragonspiral_tower_summer",
+        slots: &[
+            slot(550, 60, 45, 60, F1M1, true),
+            slot(550, 30, 45, 60, F1M1, true),
+            slot(550, 5, 50, 60, F1M1, true),
+            slot(550, 4, 50, 60, F1M1, true),
+            slot(550, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "dragonspiral_tower_autumn",
+        slots: &[
+            slot(550, 60, 45, 60, F1M1, true),
+            slot(550, 30, 45, 60, F1M1, true),
+            slot(550, 5, 50, 60, F1M1, true),
+            slot(550, 4, 50, 60, F1M1, true),
+            slot(550, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "dragonspiral_tower_winter",
+        slots: &[
+            slot(550, 60, 45, 60, F1M1, true),
+            slot(550, 30, 45, 60, F1M1, true),
+            slot(550, 5, 50, 60, F1M1, true),
+            slot(550, 4, 50, 60, F1M1, true),
+            slot(550, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "giant_chasm",
+        slots: &[
+            slot(86, 60, 35, 50, F1M1, false),
+            slot(550, 30, 35, 50, F1M1, true),
+            slot(87, 5, 40, 50, F1M1, false),
+            slot(87, 4, 40, 50, F1M1, false),
+            slot(87, 1, 40, 50, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "castelia_sewers",
+        slots: &[
+            slot(88, 60, 5, 20, F1M1, true),
+            slot(88, 30, 5, 20, F1M1, true),
+            slot(88, 5, 5, 20, F1M1, true),
+            slot(89, 4, 5, 20, F1M1, true),
+            slot(89, 1, 5, 20, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "p2_laboratory",
+        slots: &[
+            slot(594, 60, 45, 60, F1M1, false),
+            slot(594, 30, 45, 60, F1M1, false),
+            slot(594, 5, 50, 60, F1M1, false),
+            slot(593, 4, 50, 60, F1M1, false),
+            slot(593, 1, 50, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "undella_bay_spring",
+        slots: &[
+            slot(593, 60, 25, 40, F1M1, false),
+            slot(320, 30, 25, 40, F1M1, false),
+            slot(226, 5, 30, 40, F1M1, false),
+            slot(321, 4, 30, 40, F1M1, false),
+            slot(321, 1, 30, 40, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "undella_bay_summer",
+        slots: &[
+            slot(593, 60, 25, 40, F1M1, false),
+            slot(320, 30, 25, 40, F1M1, false),
+            slot(226, 5, 30, 40, F1M1, false),
+            slot(321, 4, 30, 40, F1M1, false),
+            slot(321, 1, 30, 40, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "undella_bay_autumn",
+        slots: &[
+            slot(593, 60, 25, 40, F1M1, false),
+            slot(320, 30, 25, 40, F1M1, false),
+            slot(226, 5, 30, 40, F1M1, false),
+            slot(321, 4, 30, 40, F1M1, false),
+            slot(321, 1, 30, 40, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "undella_bay_winter",
+        slots: &[
+            slot(320, 60, 25, 40, F1M1, false),
+            slot(364, 30, 25, 40, F1M1, false),
+            slot(365, 5, 30, 40, F1M1, false),
+            slot(321, 4, 30, 40, F1M1, false),
+            slot(321, 1, 30, 40, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "floccesy_ranch",
+        slots: &[
+            slot(550, 60, 5, 15, F1M1, true),
+            slot(183, 30, 5, 15, F1M1, false),
+            slot(550, 5, 5, 15, F1M1, true),
+            slot(184, 4, 5, 15, F1M1, false),
+            slot(184, 1, 5, 15, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "virbank_complex",
+        slots: &[
+            slot(594, 60, 5, 15, F1M1, false),
+            slot(594, 30, 5, 15, F1M1, false),
+            slot(594, 5, 5, 15, F1M1, false),
+            slot(593, 4, 5, 15, F1M1, false),
+            slot(593, 1, 5, 15, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "victory_road",
+        slots: &[
+            slot(183, 60, 35, 50, F1M1, false),
+            slot(550, 30, 35, 50, F1M1, true),
+            slot(550, 5, 40, 50, F1M1, true),
+            slot(184, 4, 40, 50, F1M1, false),
+            slot(184, 1, 40, 50, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "relic_passage",
+        slots: &[
+            slot(183, 60, 10, 30, F1M1, false),
+            slot(550, 30, 10, 30, F1M1, true),
+            slot(550, 5, 10, 30, F1M1, true),
+            slot(184, 4, 10, 30, F1M1, false),
+            slot(184, 1, 10, 30, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "clay_tunnel",
+        slots: &[
+            slot(550, 60, 45, 60, F1M1, true),
+            slot(550, 30, 45, 60, F1M1, true),
+            slot(550, 5, 50, 60, F1M1, true),
+            slot(550, 4, 50, 60, F1M1, true),
+            slot(550, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "seaside_cave",
+        slots: &[
+            slot(86, 60, 25, 40, F1M1, false),
+            slot(593, 30, 25, 40, F1M1, false),
+            slot(87, 5, 30, 40, F1M1, false),
+            slot(87, 4, 30, 40, F1M1, false),
+            slot(87, 1, 30, 40, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "nature_preserve",
+        slots: &[
+            slot(418, 60, 45, 60, F1M1, false),
+            slot(550, 30, 45, 60, F1M1, true),
+            slot(550, 5, 50, 60, F1M1, true),
+            slot(419, 4, 50, 60, F1M1, false),
+            slot(419, 1, 50, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "village_bridge",
+        slots: &[
+            slot(183, 60, 25, 40, F1M1, false),
+            slot(550, 30, 25, 40, F1M1, true),
+            slot(184, 5, 30, 40, F1M1, false),
+            slot(131, 4, 30, 40, F1M1, false),
+            slot(131, 1, 30, 40, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "route_1",
+        slots: &[
+            slot(550, 60, 45, 60, F1M1, true),
+            slot(550, 30, 45, 60, F1M1, true),
+            slot(550, 5, 50, 60, F1M1, true),
+            slot(550, 4, 50, 60, F1M1, true),
+            slot(550, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "route_3",
+        slots: &[
+            slot(341, 60, 45, 60, F1M1, false),
+            slot(550, 30, 45, 60, F1M1, true),
+            slot(342, 5, 50, 60, F1M1, false),
+            slot(342, 4, 50, 60, F1M1, false),
+            slot(342, 1, 50, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "wellspring_cave",
+        slots: &[
+            slot(550, 60, 45, 60, F1M1, true),
+            slot(550, 30, 45, 60, F1M1, true),
+            slot(550, 5, 50, 60, F1M1, true),
+            slot(550, 4, 50, 60, F1M1, true),
+            slot(550, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "route_4",
+        slots: &[
+            slot(594, 60, 5, 20, F1M1, false),
+            slot(594, 30, 5, 20, F1M1, false),
+            slot(594, 5, 5, 20, F1M1, false),
+            slot(593, 4, 5, 20, F1M1, false),
+            slot(593, 1, 5, 20, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "route_6_spring",
+        slots: &[
+            slot(183, 60, 10, 35, F1M1, false),
+            slot(550, 30, 10, 35, F1M1, true),
+            slot(550, 5, 10, 35, F1M1, true),
+            slot(184, 4, 10, 35, F1M1, false),
+            slot(184, 1, 10, 35, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "route_6_summer",
+        slots: &[
+            slot(183, 60, 10, 35, F1M1, false),
+            slot(550, 30, 10, 35, F1M1, true),
+            slot(550, 5, 10, 35, F1M1, true),
+            slot(184, 4, 10, 35, F1M1, false),
+            slot(184, 1, 10, 35, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "route_6_autumn",
+        slots: &[
+            slot(183, 60, 10, 35, F1M1, false),
+            slot(550, 30, 10, 35, F1M1, true),
+            slot(550, 5, 10, 35, F1M1, true),
+            slot(184, 4, 10, 35, F1M1, false),
+            slot(184, 1, 10, 35, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "route_6_winter",
+        slots: &[
+            slot(183, 60, 10, 35, F1M1, false),
+            slot(550, 30, 10, 35, F1M1, true),
+            slot(550, 5, 10, 35, F1M1, true),
+            slot(184, 4, 10, 35, F1M1, false),
+            slot(184, 1, 10, 35, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "route_8_spring",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(536, 30, 45, 60, F1M1, true),
+            slot(618, 5, 60, 60, F1M1, true),
+            slot(537, 4, 60, 60, F1M1, true),
+            slot(537, 1, 60, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "route_8_summer",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(536, 30, 45, 60, F1M1, true),
+            slot(618, 5, 50, 60, F1M1, true),
+            slot(537, 4, 50, 60, F1M1, true),
+            slot(537, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "route_8_autumn",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(536, 30, 45, 60, F1M1, true),
+            slot(618, 5, 50, 60, F1M1, true),
+            slot(537, 4, 50, 60, F1M1, true),
+            slot(537, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "route_8_winter",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(536, 30, 45, 60, F1M1, true),
+            slot(618, 5, 50, 60, F1M1, true),
+            slot(537, 4, 50, 60, F1M1, true),
+            slot(537, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "moor_of_icirrus_spring",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(618, 5, 60, 60, F1M1, true),
+            slot(537, 4, 60, 60, F1M1, true),
+            slot(537, 1, 60, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "moor_of_icirrus_summer",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(618, 5, 60, 60, F1M1, true),
+            slot(537, 4, 60, 60, F1M1, true),
+            slot(537, 1, 60, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "moor_of_icirrus_autumn",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(618, 5, 60, 60, F1M1, true),
+            slot(537, 4, 60, 60, F1M1, true),
+            slot(537, 1, 60, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "moor_of_icirrus_winter",
+        slots: &[
+            slot(618, 60, 45, 60, F1M1, true),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(618, 5, 50, 60, F1M1, true),
+            slot(537, 4, 50, 60, F1M1, true),
+            slot(537, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "route_11",
+        slots: &[
+            slot(418, 60, 25, 40, F1M1, false),
+            slot(550, 30, 25, 40, F1M1, true),
+            slot(419, 5, 30, 40, F1M1, false),
+            slot(419, 4, 30, 40, F1M1, false),
+            slot(419, 1, 30, 40, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "route_13",
+        slots: &[
+            slot(120, 60, 25, 40, Genderless, true),
+            slot(593, 30, 25, 40, F1M1, false),
+            slot(550, 5, 30, 40, F1M1, true),
+            slot(121, 4, 30, 40, Genderless, true),
+            slot(121, 1, 30, 40, Genderless, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "route_14",
+        slots: &[
+            slot(418, 60, 25, 40, F1M1, false),
+            slot(550, 30, 25, 40, F1M1, true),
+            slot(419, 5, 30, 40, F1M1, false),
+            slot(419, 4, 30, 40, F1M1, false),
+            slot(419, 1, 30, 40, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "abundant_shrine",
+        slots: &[
+            slot(183, 60, 25, 40, F1M1, false),
+            slot(550, 30, 25, 40, F1M1, true),
+            slot(550, 5, 30, 40, F1M1, true),
+            slot(184, 4, 30, 40, F1M1, false),
+            slot(184, 1, 30, 40, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "lostlorn_forest",
+        slots: &[
+            slot(418, 60, 10, 30, F1M1, false),
+            slot(550, 30, 10, 30, F1M1, true),
+            slot(419, 5, 10, 30, F1M1, false),
+            slot(419, 4, 10, 30, F1M1, false),
+            slot(419, 1, 10, 30, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "route_18",
+        slots: &[
+            slot(594, 60, 45, 60, F1M1, false),
+            slot(594, 30, 45, 60, F1M1, false),
+            slot(594, 5, 50, 60, F1M1, false),
+            slot(593, 4, 50, 60, F1M1, false),
+            slot(593, 1, 50, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "route_19",
+        slots: &[
+            slot(550, 60, 5, 15, F1M1, true),
+            slot(550, 30, 5, 15, F1M1, true),
+            slot(550, 5, 5, 15, F1M1, true),
+            slot(550, 4, 5, 15, F1M1, true),
+            slot(550, 1, 5, 15, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "route_20_spring",
+        slots: &[
+            slot(550, 60, 5, 15, F1M1, true),
+            slot(183, 30, 5, 15, F1M1, false),
+            slot(550, 5, 5, 15, F1M1, true),
+            slot(184, 4, 5, 15, F1M1, false),
+            slot(184, 1, 5, 15, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "route_20_summer",
+        slots: &[
+            slot(550, 60, 5, 15, F1M1, true),
+            slot(183, 30, 5, 15, F1M1, false),
+            slot(550, 5, 5, 15, F1M1, true),
+            slot(184, 4, 5, 15, F1M1, false),
+            slot(184, 1, 5, 15, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "route_20_autumn",
+        slots: &[
+            slot(550, 60, 5, 15, F1M1, true),
+            slot(183, 30, 5, 15, F1M1, false),
+            slot(550, 5, 5, 15, F1M1, true),
+            slot(184, 4, 5, 15, F1M1, false),
+            slot(184, 1, 5, 15, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "route_20_winter",
+        slots: &[
+            slot(550, 60, 5, 15, F1M1, true),
+            slot(183, 30, 5, 15, F1M1, false),
+            slot(550, 5, 5, 15, F1M1, true),
+            slot(184, 4, 5, 15, F1M1, false),
+            slot(184, 1, 5, 15, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "route_22",
+        slots: &[
+            slot(183, 60, 15, 40, F1M1, false),
+            slot(550, 30, 15, 40, F1M1, true),
+            slot(550, 5, 25, 45, F1M1, true),
+            slot(184, 4, 25, 45, F1M1, false),
+            slot(184, 1, 25, 45, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "route_23",
+        slots: &[
+            slot(418, 60, 40, 55, F1M1, false),
+            slot(550, 30, 40, 55, F1M1, true),
+            slot(419, 5, 45, 55, F1M1, false),
+            slot(419, 4, 45, 55, F1M1, false),
+            slot(419, 1, 45, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "undella_town",
+        slots: &[
+            slot(120, 60, 25, 40, Genderless, true),
+            slot(593, 30, 25, 40, F1M1, false),
+            slot(550, 5, 30, 40, F1M1, true),
+            slot(121, 4, 30, 40, Genderless, true),
+            slot(121, 1, 30, 40, Genderless, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "route_17",
+        slots: &[
+            slot(594, 60, 45, 60, F1M1, false),
+            slot(594, 30, 45, 60, F1M1, false),
+            slot(594, 5, 50, 60, F1M1, false),
+            slot(593, 4, 50, 60, F1M1, false),
+            slot(593, 1, 50, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: SurfingBubble,
+        location_key: "route_21",
+        slots: &[
+            slot(593, 60, 30, 45, F1M1, false),
+            slot(594, 30, 30, 45, F1M1, false),
+            slot(226, 5, 35, 45, F1M1, false),
+            slot(594, 4, 35, 45, F1M1, false),
+            slot(594, 1, 35, 45, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "striaton_city",
+        slots: &[
+            slot(118, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(118, 5, 50, 70, F1M1, false),
+            slot(118, 4, 50, 70, F1M1, false),
+            slot(118, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "icirrus_city_spring",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(339, 5, 60, 60, F1M1, false),
+            slot(339, 4, 60, 60, F1M1, false),
+            slot(339, 1, 60, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "icirrus_city_summer",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(339, 5, 60, 60, F1M1, false),
+            slot(339, 4, 60, 60, F1M1, false),
+            slot(339, 1, 60, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "icirrus_city_autumn",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(339, 5, 60, 60, F1M1, false),
+            slot(339, 4, 60, 60, F1M1, false),
+            slot(339, 1, 60, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "icirrus_city_winter",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(339, 5, 50, 60, F1M1, false),
+            slot(339, 4, 50, 60, F1M1, false),
+            slot(339, 1, 50, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "aspertia_city",
+        slots: &[
+            slot(118, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(118, 5, 50, 70, F1M1, false),
+            slot(118, 4, 50, 70, F1M1, false),
+            slot(118, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "virbank_city",
+        slots: &[
+            slot(456, 60, 40, 60, F1M1, true),
+            slot(98, 30, 40, 60, F1M1, false),
+            slot(456, 5, 50, 70, F1M1, true),
+            slot(211, 4, 50, 70, F1M1, true),
+            slot(211, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "humilau_city",
+        slots: &[
+            slot(370, 60, 40, 60, F3M1, true),
+            slot(90, 30, 40, 60, F1M1, true),
+            slot(370, 5, 50, 70, F3M1, true),
+            slot(370, 4, 50, 70, F3M1, true),
+            slot(370, 1, 50, 70, F3M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "pinwheel_forest",
+        slots: &[
+            slot(118, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(118, 5, 50, 70, F1M1, false),
+            slot(118, 4, 50, 70, F1M1, false),
+            slot(118, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "dragonspiral_tower_spring",
+        slots: &[
+            slot(147, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(147, 5, 50, 70, F1M1, true),
+            slot(147, 4, 50, 70, F1M1, true),
+            slot(147, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "dragonspiral_tower_summer",
+        slots: &[
+            slot(147, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(147, 5, 50, 70, F1M1, true),
+            slot(147, 4, 50, 70, F1M1, true),
+            slot(147, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "dragonspiral_tower_autumn",
+        slots: &[
+            slot(147, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(147, 5, 50, 70, F1M1, true),
+            slot(147, 4, 50, 70, F1M1, true),
+            slot(147, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "dragonspiral_tower_winter",
+        slots: &[
+            slot(147, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(147, 5, 50, 70, F1M1, true),
+            slot(147, 4, 50, 70, F1M1, true),
+            slot(147, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "giant_chasm",
+        slots: &[
+            slot(60, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(60, 5, 50, 70, F1M1, false),
+            slot(60, 4, 50, 70, F1M1, false),
+            slot(60, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "castelia_sewers",
+        slots: &[
+            slot(88, 60, 40, 60, F1M1, true),
+            slot(88, 30, 40, 60, F1M1, true),
+            slot(88, 5, 50, 70, F1M1, true),
+            slot(88, 4, 50, 70, F1M1, true),
+            slot(88, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "p2_laboratory",
+        slots: &[
+            slot(456, 60, 35, 55, F1M1, true),
+            slot(116, 30, 35, 55, F1M1, true),
+            slot(116, 5, 35, 55, F1M1, true),
+            slot(456, 4, 35, 55, F1M1, true),
+            slot(456, 1, 35, 55, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "undella_bay_spring",
+        slots: &[
+            slot(170, 60, 40, 60, F1M1, true),
+            slot(223, 30, 40, 60, F1M1, false),
+            slot(170, 5, 50, 70, F1M1, true),
+            slot(170, 4, 50, 70, F1M1, true),
+            slot(170, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "undella_bay_summer",
+        slots: &[
+            slot(170, 60, 40, 60, F1M1, true),
+            slot(223, 30, 40, 60, F1M1, false),
+            slot(170, 5, 50, 70, F1M1, true),
+            slot(170, 4, 50, 70, F1M1, true),
+            slot(170, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "undella_bay_autumn",
+        slots: &[
+            slot(170, 60, 40, 60, F1M1, true),
+            slot(223, 30, 40, 60, F1M1, false),
+            slot(170, 5, 50, 70, F1M1, true),
+            slot(170, 4, 50, 70, F1M1, true),
+            slot(170, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "undella_bay_winter",
+        slots: &[
+            slot(170, 60, 40, 60, F1M1, true),
+            slot(223, 30, 40, 60, F1M1, false),
+            slot(170, 5, 50, 70, F1M1, true),
+            slot(170, 4, 50, 70, F1M1, true),
+            slot(170, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "floccesy_ranch",
+        slots: &[
+            slot(60, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(60, 5, 50, 70, F1M1, false),
+            slot(60, 4, 50, 70, F1M1, false),
+            slot(60, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "virbank_complex",
+        slots: &[
+            slot(456, 60, 40, 60, F1M1, true),
+            slot(98, 30, 40, 60, F1M1, false),
+            slot(456, 5, 50, 70, F1M1, true),
+            slot(211, 4, 50, 70, F1M1, true),
+            slot(211, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "victory_road",
+        slots: &[
+            slot(60, 60, 40, 55, F1M1, false),
+            slot(550, 30, 40, 55, F1M1, true),
+            slot(60, 5, 50, 55, F1M1, false),
+            slot(60, 4, 50, 55, F1M1, false),
+            slot(60, 1, 50, 55, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "relic_passage",
+        slots: &[
+            slot(60, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(60, 5, 50, 70, F1M1, false),
+            slot(60, 4, 50, 70, F1M1, false),
+            slot(60, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "clay_tunnel",
+        slots: &[
+            slot(60, 60, 50, 60, F1M1, false),
+            slot(550, 30, 50, 60, F1M1, true),
+            slot(60, 5, 60, 70, F1M1, false),
+            slot(60, 4, 60, 70, F1M1, false),
+            slot(60, 1, 60, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "seaside_cave",
+        slots: &[
+            slot(370, 60, 40, 60, F3M1, true),
+            slot(90, 30, 40, 60, F1M1, true),
+            slot(370, 5, 50, 70, F3M1, true),
+            slot(370, 4, 50, 70, F3M1, true),
+            slot(370, 1, 50, 70, F3M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "nature_preserve",
+        slots: &[
+            slot(129, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(129, 5, 50, 70, F1M1, false),
+            slot(129, 4, 50, 70, F1M1, false),
+            slot(129, 1, 1, 100, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "village_bridge",
+        slots: &[
+            slot(318, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(318, 5, 50, 70, F1M1, true),
+            slot(318, 4, 50, 70, F1M1, true),
+            slot(318, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "route_1",
+        slots: &[
+            slot(550, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(550, 5, 50, 70, F1M1, true),
+            slot(349, 4, 50, 70, F1M1, false),
+            slot(349, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "route_3",
+        slots: &[
+            slot(118, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(118, 5, 50, 70, F1M1, false),
+            slot(118, 4, 50, 70, F1M1, false),
+            slot(118, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "wellspring_cave",
+        slots: &[
+            slot(60, 60, 50, 60, F1M1, false),
+            slot(550, 30, 50, 60, F1M1, true),
+            slot(60, 5, 60, 70, F1M1, false),
+            slot(60, 4, 60, 70, F1M1, false),
+            slot(60, 1, 60, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "route_4",
+        slots: &[
+            slot(456, 60, 40, 60, F1M1, true),
+            slot(366, 30, 40, 60, F1M1, true),
+            slot(456, 5, 50, 70, F1M1, true),
+            slot(211, 4, 50, 70, F1M1, true),
+            slot(211, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "route_6_spring",
+        slots: &[
+            slot(60, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(60, 5, 50, 70, F1M1, false),
+            slot(60, 4, 50, 70, F1M1, false),
+            slot(60, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "route_6_summer",
+        slots: &[
+            slot(60, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(60, 5, 50, 70, F1M1, false),
+            slot(60, 4, 50, 70, F1M1, false),
+            slot(60, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "route_6_autumn",
+        slots: &[
+            slot(60, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(60, 5, 50, 70, F1M1, false),
+            slot(60, 4, 50, 70, F1M1, false),
+            slot(60, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "route_6_winter",
+        slots: &[
+            slot(60, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(60, 5, 50, 70, F1M1, false),
+            slot(60, 4, 50, 70, F1M1, false),
+            slot(60, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "route_8_spring",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(339, 5, 60, 60, F1M1, false),
+            slot(339, 4, 60, 60, F1M1, false),
+            slot(339, 1, 60, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "route_8_summer",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(339, 5, 50, 60, F1M1, false),
+            slot(339, 4, 50, 60, F1M1, false),
+            slot(339, 1, 50, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "route_8_autumn",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(339, 5, 50, 60, F1M1, false),
+            slot(339, 4, 50, 60, F1M1, false),
+            slot(339, 1, 50, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "route_8_winter",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(339, 5, 50, 60, F1M1, false),
+            slot(339, 4, 50, 60, F1M1, false),
+            slot(339, 1, 50, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "moor_of_icirrus_spring",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(339, 5, 60, 60, F1M1, false),
+            slot(339, 4, 60, 60, F1M1, false),
+            slot(339, 1, 60, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "moor_of_icirrus_summer",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(339, 5, 60, 60, F1M1, false),
+            slot(339, 4, 60, 60, F1M1, false),
+            slot(339, 1, 60, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "moor_of_icirrus_autumn",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(339, 5, 60, 60, F1M1, false),
+            slot(339, 4, 60, 60, F1M1, false),
+            slot(339, 1, 60, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "moor_of_icirrus_winter",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(339, 5, 50, 60, F1M1, false),
+            slot(339, 4, 50, 60, F1M1, false),
+            slot(339, 1, 50, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "route_11",
+        slots: &[
+            slot(118, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(118, 5, 50, 70, F1M1, false),
+            slot(118, 4, 50, 70, F1M1, false),
+            slot(118, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "route_13",
+        slots: &[
+            slot(370, 60, 40, 60, F3M1, true),
+            slot(90, 30, 40, 60, F1M1, true),
+            slot(370, 5, 50, 70, F3M1, true),
+            slot(370, 4, 50, 70, F3M1, true),
+            slot(370, 1, 50, 70, F3M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "route_14",
+        slots: &[
+            slot(118, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(118, 5, 50, 70, F1M1, false),
+            slot(118, 4, 50, 70, F1M1, false),
+            slot(118, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "abundant_shrine",
+        slots: &[
+            slot(118, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(118, 5, 50, 70, F1M1, false),
+            slot(118, 4, 50, 70, F1M1, false),
+            slot(118, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "lostlorn_forest",
+        slots: &[
+            slot(60, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(60, 5, 50, 70, F1M1, false),
+            slot(60, 4, 50, 70, F1M1, false),
+            slot(60, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "route_18",
+        slots: &[
+            slot(456, 60, 50, 60, F1M1, true),
+            slot(116, 30, 50, 60, F1M1, true),
+            slot(456, 5, 60, 70, F1M1, true),
+            slot(222, 4, 60, 70, F3M1, true),
+            slot(222, 1, 60, 70, F3M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "route_19",
+        slots: &[
+            slot(60, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(60, 5, 50, 70, F1M1, false),
+            slot(60, 4, 50, 70, F1M1, false),
+            slot(60, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "route_20_spring",
+        slots: &[
+            slot(60, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(60, 5, 50, 70, F1M1, false),
+            slot(60, 4, 50, 70, F1M1, false),
+            slot(60, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "route_20_summer",
+        slots: &[
+            slot(60, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(60, 5, 50, 70, F1M1, false),
+            slot(60, 4, 50, 70, F1M1, false),
+            slot(60, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "route_20_autumn",
+        slots: &[
+            slot(60, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(60, 5, 50, 70, F1M1, false),
+            slot(60, 4, 50, 70, F1M1, false),
+            slot(60, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "route_20_winter",
+        slots: &[
+            slot(60, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(60, 5, 50, 70, F1M1, false),
+            slot(60, 4, 50, 70, F1M1, false),
+            slot(60, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "route_22",
+        slots: &[
+            slot(118, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(118, 5, 50, 70, F1M1, false),
+            slot(118, 4, 50, 70, F1M1, false),
+            slot(118, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "route_23",
+        slots: &[
+            slot(60, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(60, 5, 50, 70, F1M1, false),
+            slot(60, 4, 50, 70, F1M1, false),
+            slot(60, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "undella_town",
+        slots: &[
+            slot(370, 60, 40, 60, F3M1, true),
+            slot(90, 30, 40, 60, F1M1, true),
+            slot(370, 5, 50, 70, F3M1, true),
+            slot(370, 4, 50, 70, F3M1, true),
+            slot(370, 1, 50, 70, F3M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "route_17",
+        slots: &[
+            slot(456, 60, 50, 60, F1M1, true),
+            slot(116, 30, 50, 60, F1M1, true),
+            slot(456, 5, 60, 70, F1M1, true),
+            slot(456, 4, 60, 70, F1M1, true),
+            slot(456, 1, 60, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: Fishing,
+        location_key: "route_21",
+        slots: &[
+            slot(370, 60, 40, 60, F3M1, true),
+            slot(223, 30, 40, 60, F1M1, false),
+            slot(370, 5, 50, 70, F3M1, true),
+            slot(370, 4, 50, 70, F3M1, true),
+            slot(370, 1, 50, 70, F3M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "striaton_city",
+        slots: &[
+            slot(118, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(119, 5, 50, 70, F1M1, false),
+            slot(119, 4, 50, 70, F1M1, false),
+            slot(119, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "icirrus_city_spring",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(340, 5, 60, 60, F1M1, false),
+            slot(340, 4, 60, 60, F1M1, false),
+            slot(340, 1, 60, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "icirrus_city_summer",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(340, 5, 60, 60, F1M1, false),
+            slot(340, 4, 60, 60, F1M1, false),
+            slot(340, 1, 60, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "icirrus_city_autumn",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(340, 5, 60, 60, F1M1, false),
+            slot(340, 4, 60, 60, F1M1, false),
+            slot(340, 1, 60, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "icirrus_city_winter",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(340, 5, 50, 60, F1M1, false),
+            slot(340, 4, 50, 60, F1M1, false),
+            slot(340, 1, 50, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "aspertia_city",
+        slots: &[
+            slot(118, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(119, 5, 50, 70, F1M1, false),
+            slot(119, 4, 50, 70, F1M1, false),
+            slot(119, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "virbank_city",
+        slots: &[
+            slot(98, 60, 40, 60, F1M1, false),
+            slot(457, 30, 40, 60, F1M1, true),
+            slot(99, 5, 50, 70, F1M1, false),
+            slot(99, 4, 50, 70, F1M1, false),
+            slot(99, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "humilau_city",
+        slots: &[
+            slot(90, 60, 40, 60, F1M1, true),
+            slot(370, 30, 40, 60, F3M1, true),
+            slot(90, 5, 50, 70, F1M1, true),
+            slot(91, 4, 50, 70, F1M1, true),
+            slot(91, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "pinwheel_forest",
+        slots: &[
+            slot(118, 60, 35, 60, F1M1, false),
+            slot(550, 30, 35, 60, F1M1, true),
+            slot(119, 5, 35, 70, F1M1, false),
+            slot(119, 4, 45, 70, F1M1, false),
+            slot(119, 1, 45, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "dragonspiral_tower_spring",
+        slots: &[
+            slot(147, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(148, 5, 50, 70, F1M1, true),
+            slot(148, 4, 50, 70, F1M1, true),
+            slot(149, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "dragonspiral_tower_summer",
+        slots: &[
+            slot(147, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(148, 5, 50, 70, F1M1, true),
+            slot(148, 4, 50, 70, F1M1, true),
+            slot(149, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "dragonspiral_tower_autumn",
+        slots: &[
+            slot(147, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(148, 5, 50, 70, F1M1, true),
+            slot(148, 4, 50, 70, F1M1, true),
+            slot(149, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "dragonspiral_tower_winter",
+        slots: &[
+            slot(147, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(148, 5, 50, 70, F1M1, true),
+            slot(148, 4, 50, 70, F1M1, true),
+            slot(149, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "giant_chasm",
+        slots: &[
+            slot(61, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(61, 5, 50, 70, F1M1, true),
+            slot(62, 4, 50, 70, F1M1, true),
+            slot(62, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "castelia_sewers",
+        slots: &[
+            slot(88, 60, 40, 60, F1M1, true),
+            slot(88, 30, 40, 60, F1M1, true),
+            slot(89, 5, 50, 70, F1M1, true),
+            slot(89, 4, 50, 70, F1M1, true),
+            slot(89, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "p2_laboratory",
+        slots: &[
+            slot(117, 60, 35, 60, F1M1, true),
+            slot(211, 30, 35, 60, F1M1, true),
+            slot(457, 5, 35, 70, F1M1, true),
+            slot(230, 4, 45, 70, F1M1, true),
+            slot(230, 1, 45, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "undella_bay_spring",
+        slots: &[
+            slot(223, 60, 40, 60, F1M1, false),
+            slot(170, 30, 40, 60, F1M1, true),
+            slot(171, 5, 50, 70, F1M1, true),
+            slot(224, 4, 50, 70, F1M1, false),
+            slot(224, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "undella_bay_summer",
+        slots: &[
+            slot(223, 60, 40, 60, F1M1, false),
+            slot(170, 30, 40, 60, F1M1, true),
+            slot(171, 5, 50, 70, F1M1, true),
+            slot(224, 4, 50, 70, F1M1, false),
+            slot(224, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "undella_bay_autumn",
+        slots: &[
+            slot(223, 60, 40, 60, F1M1, false),
+            slot(170, 30, 40, 60, F1M1, true),
+            slot(171, 5, 50, 70, F1M1, true),
+            slot(224, 4, 50, 70, F1M1, false),
+            slot(224, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "undella_bay_winter",
+        slots: &[
+            slot(223, 60, 40, 60, F1M1, false),
+            slot(170, 30, 40, 60, F1M1, true),
+            slot(171, 5, 50, 70, F1M1, true),
+            slot(224, 4, 50, 70, F1M1, false),
+            slot(224, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "floccesy_ranch",
+        slots: &[
+            slot(61, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(61, 5, 50, 70, F1M1, true),
+            slot(186, 4, 50, 70, F1M1, true),
+            slot(186, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "virbank_complex",
+        slots: &[
+            slot(98, 60, 40, 60, F1M1, false),
+            slot(457, 30, 40, 60, F1M1, true),
+            slot(99, 5, 50, 70, F1M1, false),
+            slot(99, 4, 50, 70, F1M1, false),
+            slot(99, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "victory_road",
+        slots: &[
+            slot(61, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(61, 5, 50, 60, F1M1, true),
+            slot(62, 4, 50, 60, F1M1, true),
+            slot(62, 1, 50, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "relic_passage",
+        slots: &[
+            slot(61, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(61, 5, 50, 70, F1M1, true),
+            slot(62, 4, 50, 70, F1M1, true),
+            slot(62, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "clay_tunnel",
+        slots: &[
+            slot(61, 60, 50, 60, F1M1, true),
+            slot(550, 30, 50, 60, F1M1, true),
+            slot(61, 5, 60, 60, F1M1, true),
+            slot(62, 4, 60, 60, F1M1, true),
+            slot(62, 1, 60, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "seaside_cave",
+        slots: &[
+            slot(90, 60, 40, 60, F1M1, true),
+            slot(370, 30, 40, 60, F3M1, true),
+            slot(90, 5, 50, 70, F1M1, true),
+            slot(91, 4, 50, 70, F1M1, true),
+            slot(91, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "nature_preserve",
+        slots: &[
+            slot(129, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(130, 5, 50, 70, F1M1, false),
+            slot(130, 4, 50, 70, F1M1, false),
+            slot(130, 1, 1, 100, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "village_bridge",
+        slots: &[
+            slot(318, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(319, 5, 50, 70, F1M1, true),
+            slot(319, 4, 50, 70, F1M1, true),
+            slot(319, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "route_1",
+        slots: &[
+            slot(349, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(349, 5, 50, 70, F1M1, false),
+            slot(350, 4, 50, 70, F1M1, false),
+            slot(350, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "route_3",
+        slots: &[
+            slot(118, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(119, 5, 50, 70, F1M1, false),
+            slot(119, 4, 50, 70, F1M1, false),
+            slot(119, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "wellspring_cave",
+        slots: &[
+            slot(61, 60, 50, 60, F1M1, true),
+            slot(550, 30, 50, 60, F1M1, true),
+            slot(61, 5, 60, 60, F1M1, true),
+            slot(62, 4, 60, 60, F1M1, true),
+            slot(62, 1, 60, 60, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "route_4",
+        slots: &[
+            slot(457, 60, 40, 60, F1M1, true),
+            slot(211, 30, 40, 60, F1M1, true),
+            slot(369, 5, 50, 70, F1M7, true),
+            slot(368, 4, 50, 70, F1M1, true),
+            slot(368, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "route_6_spring",
+        slots: &[
+            slot(61, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(61, 5, 50, 70, F1M1, true),
+            slot(186, 4, 50, 70, F1M1, true),
+            slot(186, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "route_6_summer",
+        slots: &[
+            slot(61, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(61, 5, 50, 70, F1M1, true),
+            slot(186, 4, 50, 70, F1M1, true),
+            slot(186, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "route_6_autumn",
+        slots: &[
+            slot(61, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(61, 5, 50, 70, F1M1, true),
+            slot(186, 4, 50, 70, F1M1, true),
+            slot(186, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "route_6_winter",
+        slots: &[
+            slot(61, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(61, 5, 50, 70, F1M1, true),
+            slot(186, 4, 50, 70, F1M1, true),
+            slot(186, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "route_8_spring",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(340, 5, 60, 60, F1M1, false),
+            slot(340, 4, 60, 60, F1M1, false),
+            slot(340, 1, 60, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "route_8_summer",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(340, 5, 50, 60, F1M1, false),
+            slot(340, 4, 50, 60, F1M1, false),
+            slot(340, 1, 50, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "route_8_autumn",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(340, 5, 50, 60, F1M1, false),
+            slot(340, 4, 50, 60, F1M1, false),
+            slot(340, 1, 50, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "route_8_winter",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(340, 5, 50, 60, F1M1, false),
+            slot(340, 4, 50, 60, F1M1, false),
+            slot(340, 1, 50, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "moor_of_icirrus_spring",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(340, 5, 60, 60, F1M1, false),
+            slot(340, 4, 60, 60, F1M1, false),
+            slot(340, 1, 60, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "moor_of_icirrus_summer",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(340, 5, 60, 60, F1M1, false),
+            slot(340, 4, 60, 60, F1M1, false),
+            slot(340, 1, 60, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "moor_of_icirrus_autumn",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(340, 5, 60, 60, F1M1, false),
+            slot(340, 4, 60, 60, F1M1, false),
+            slot(340, 1, 60, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "moor_of_icirrus_winter",
+        slots: &[
+            slot(339, 60, 45, 60, F1M1, false),
+            slot(618, 30, 45, 60, F1M1, true),
+            slot(340, 5, 50, 60, F1M1, false),
+            slot(340, 4, 50, 60, F1M1, false),
+            slot(340, 1, 50, 60, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "route_11",
+        slots: &[
+            slot(118, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(119, 5, 50, 70, F1M1, false),
+            slot(119, 4, 50, 70, F1M1, false),
+            slot(119, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "route_13",
+        slots: &[
+            slot(90, 60, 40, 60, F1M1, true),
+            slot(370, 30, 40, 60, F3M1, true),
+            slot(90, 5, 50, 70, F1M1, true),
+            slot(91, 4, 50, 70, F1M1, true),
+            slot(91, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "route_14",
+        slots: &[
+            slot(118, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(119, 5, 50, 70, F1M1, false),
+            slot(119, 4, 50, 70, F1M1, false),
+            slot(119, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "abundant_shrine",
+        slots: &[
+            slot(118, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(119, 5, 50, 70, F1M1, false),
+            slot(119, 4, 50, 70, F1M1, false),
+            slot(119, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "lostlorn_forest",
+        slots: &[
+            slot(61, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(61, 5, 50, 70, F1M1, true),
+            slot(186, 4, 50, 70, F1M1, true),
+            slot(186, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "route_18",
+        slots: &[
+            slot(116, 60, 50, 60, F1M1, true),
+            slot(457, 30, 50, 60, F1M1, true),
+            slot(117, 5, 60, 70, F1M1, true),
+            slot(230, 4, 60, 70, F1M1, true),
+            slot(230, 1, 60, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "route_19",
+        slots: &[
+            slot(61, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(61, 5, 50, 70, F1M1, true),
+            slot(186, 4, 50, 70, F1M1, true),
+            slot(186, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "route_20_spring",
+        slots: &[
+            slot(61, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(61, 5, 50, 70, F1M1, true),
+            slot(186, 4, 50, 70, F1M1, true),
+            slot(186, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "route_20_summer",
+        slots: &[
+            slot(61, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(61, 5, 50, 70, F1M1, true),
+            slot(186, 4, 50, 70, F1M1, true),
+            slot(186, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "route_20_autumn",
+        slots: &[
+            slot(61, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(61, 5, 50, 70, F1M1, true),
+            slot(186, 4, 50, 70, F1M1, true),
+            slot(186, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "route_20_winter",
+        slots: &[
+            slot(61, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(61, 5, 50, 70, F1M1, true),
+            slot(186, 4, 50, 70, F1M1, true),
+            slot(186, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "route_22",
+        slots: &[
+            slot(118, 60, 40, 60, F1M1, false),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(119, 5, 50, 70, F1M1, false),
+            slot(119, 4, 50, 70, F1M1, false),
+            slot(119, 1, 50, 70, F1M1, false),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "route_23",
+        slots: &[
+            slot(61, 60, 40, 60, F1M1, true),
+            slot(550, 30, 40, 60, F1M1, true),
+            slot(61, 5, 50, 70, F1M1, true),
+            slot(62, 4, 50, 70, F1M1, true),
+            slot(62, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "undella_town",
+        slots: &[
+            slot(90, 60, 40, 60, F1M1, true),
+            slot(370, 30, 40, 60, F3M1, true),
+            slot(90, 5, 50, 70, F1M1, true),
+            slot(91, 4, 50, 70, F1M1, true),
+            slot(91, 1, 50, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "route_17",
+        slots: &[
+            slot(116, 60, 50, 60, F1M1, true),
+            slot(457, 30, 50, 60, F1M1, true),
+            slot(117, 5, 60, 70, F1M1, true),
+            slot(230, 4, 60, 70, F1M1, true),
+            slot(230, 1, 60, 70, F1M1, true),
+        ],
+    },
+    EncounterLocationEntry {
+        version: White2,
+        encounter_type: FishingBubble,
+        location_key: "route_21",
+        slots: &[
+            slot(223, 60, 40, 60, F1M1, false),
+            slot(370, 30, 40, 60, F3M1, true),
+            slot(223, 5, 50, 70, F1M1, false),
+            slot(224, 4, 50, 70, F1M1, false),
+            slot(224, 1, 50, 70, F1M1, false),
+        ],
+    },
+];
+
+/// ロケーションキーの正規化で除去するダッシュ類・ピリオド
+const DASHES_DOTS: [char; 8] = [
+    '\u{2010}', '\u{2011}', '\u{2012}', '\u{2013}', '\u{2014}', '\u{2212}', '-', '.',
+];
+
+/// ロケーションキーを正規化
+///
+/// 前後の空白を除去し、空白 (全角空白を含む)・ダッシュ類・ピリオドを取り除く。
+/// `src/data/encounters/loader.ts` の `normalizeLocationKey` と同一の規則。
+pub fn normalize_location_key(location: &str) -> String {
+    location
+        .trim()
+        .chars()
+        .filter(|c| !c.is_whitespace() && !DASHES_DOTS.contains(c))
+        .collect()
+}
+
+/// エンカウントテーブルのエントリを取得
+///
+/// # Returns
+/// 該当するエントリ。存在しない場合は `None`。
+pub fn get_encounter_entry(
+    version: RomVersion,
+    location: &str,
+    encounter_type: EncounterType,
+) -> Option<&'static EncounterLocationEntry> {
+    let key = normalize_location_key(location);
+    ENCOUNTER_TABLE.iter().find(|e| {
+        e.version == version && e.encounter_type == encounter_type && e.location_key == key
+    })
+}
+
+/// 指定ロケーションのエンカウントスロットを取得
+///
+/// # Returns
+/// スロット設定 (出現率テーブルのスロット順)。存在しない場合は `None`。
+pub fn get_encounter_slots(
+    version: RomVersion,
+    location: &str,
+    encounter_type: EncounterType,
+) -> Option<Vec<EncounterSlotConfig>> {
+    get_encounter_entry(version, location, encounter_type)
+        .map(|e| e.slots.iter().map(|s| s.to_config()).collect())
+}
+
+/// 季節で出現ポケモンが変わるロケーションの季節別スロットを取得
+///
+/// `{location}_spring` / `_summer` / `_autumn` / `_winter` の各エントリを束ねる。
+/// エントリのない季節 (冬の積雪でエンカウントなし等) は空。
+///
+/// # Returns
+/// 季節別スロット。いずれの季節のエントリも存在しない場合は `None`。
+pub fn get_seasonal_encounter_slots(
+    version: RomVersion,
+    location: &str,
+    encounter_type: EncounterType,
+) -> Option<SeasonalSlots> {
+    let key = normalize_location_key(location);
+    let slots = |season: &str| {
+        get_encounter_slots(version, &format!("{key}_{season}"), encounter_type).unwrap_or_default()
+    };
+    let seasonal = SeasonalSlots {
+        spring: slots("spring"),
+        summer: slots("summer"),
+        autumn: slots("autumn"),
+        winter: slots("winter"),
+    };
+    let found = seasonal.iter().any(|s| !s.is_empty());
+    found.then_some(seasonal)
+}
+
+/// 指定バージョン・エンカウント種別で利用可能なロケーションキー一覧を取得
+pub fn list_encounter_locations(
+    version: RomVersion,
+    encounter_type: EncounterType,
+) -> Vec<&'static str> {
+    ENCOUNTER_TABLE
+        .iter()
+        .filter(|e| e.version == version && e.encounter_type == encounter_type)
+        .map(|e| e.location_key)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NORMAL_RATES: [u8; 12] = [20, 20, 10, 10, 10, 10, 5, 5, 4, 4, 1, 1];
+    const WATER_RATES: [u8; 5] = [60, 30, 5, 4, 1];
+
+    #[test]
+    fn test_slot_rates_match_encounter_type() {
+        for entry in &ENCOUNTER_TABLE {
+            let rates: Vec<u8> = entry.slots.iter().map(|s| s.rate).collect();
+            let expected: &[u8] = match entry.encounter_type {
+                Surfing | SurfingBubble | Fishing | FishingBubble => &WATER_RATES,
+                _ => &NORMAL_RATES,
+            };
+            assert_eq!(rates, expected, "{}", entry.location_key);
+        }
+    }
+
+    #[test]
+    fn test_get_encounter_slots_known_location() {
+        let slots = get_encounter_slots(Black, "icirrus_city_spring", Normal).unwrap();
+        assert_eq!(slots.len(), 12);
+        assert_eq!(slots[0].species_id, 536);
+        assert_eq!(slots[0].level_min, 30);
+        assert_eq!(slots[0].level_max, 30);
+        assert!(slots[0].has_held_item);
+        assert!(!slots[1].has_held_item);
+        assert!(slots.iter().all(|s| !s.shiny_locked));
+    }
+
+    #[test]
+    fn test_get_encounter_slots_normalizes_key() {
+        assert!(get_encounter_slots(Black, " icirrus_city_spring\u{3000}", Normal).is_some());
+        assert!(get_encounter_slots(Black, "unknown_location", Normal).is_none());
+    }
+
+    #[test]
+    fn test_get_seasonal_encounter_slots() {
+        let seasonal = get_seasonal_encounter_slots(Black, "icirrus_city", Normal).unwrap();
+        assert_eq!(
+            seasonal.spring,
+            get_encounter_slots(Black, "icirrus_city_spring", Normal).unwrap()
+        );
+        // 冬は積雪によりエンカウントなし
+        assert!(seasonal.winter.is_empty());
+        let seasonal = get_seasonal_encounter_slots(Black, "icirrus_city", Surfing).unwrap();
+        assert_eq!(seasonal.winter.len(), 5);
+        // 季節変化のないロケーション
+        assert!(get_seasonal_encounter_slots(Black, "icirrus_city_spring", Normal).is_none());
+    }
+
+    #[test]
+    fn test_list_encounter_locations() {
+        let locations = list_encounter_locations(Black, Normal);
+        assert!(locations.contains(&"icirrus_city_spring"));
+        assert!(!list_encounter_locations(White2, Fishing).is_empty());
+    }
+}
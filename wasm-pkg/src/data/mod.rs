@@ -4,15 +4,27 @@
 //!
 //! このモジュールのファイルは自動生成されています。
 //! 生成コマンド: node scripts/generate-species-data.js
+//! エンカウントテーブル: node scripts/generate-encounter-data.js
 
 pub mod abilities;
+pub mod breeding;
+pub mod encounters;
 pub mod items;
 pub mod names;
 pub mod species;
+pub mod static_encounters;
 pub mod stats;
+pub mod timer0_defaults;
 
 pub use abilities::get_ability_name;
+pub use breeding::resolve_egg_species;
+pub use encounters::{
+    EncounterLocationEntry, EncounterSlotEntry, get_encounter_entry, get_encounter_slots,
+    get_seasonal_encounter_slots, list_encounter_locations, normalize_location_key,
+};
 pub use items::{HeldItemEntry, get_held_item_entry, get_held_item_name, get_item_name};
 pub use names::{get_nature_name, get_species_name};
 pub use species::{BaseStats, SpeciesEntry, get_species_entry};
-pub use stats::{Stats, calculate_stats};
+pub use static_encounters::{StaticEncounterEntry, get_static_encounter, list_static_encounters};
+pub use stats::{Stats, calculate_stats, derive_iv_ranges};
+pub use timer0_defaults::default_ranges;
@@ -3,7 +3,7 @@
 //! このファイルは自動生成されています。直接編集しないでください。
 //! 生成コマンド: node scripts/generate-species-data.js
 
-use crate::types::GenderRatio;
+use crate::types::{EggGroup, GenderRatio, PokemonType};
 
 /// 種族値
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -21,10 +21,30 @@ pub struct BaseStats {
 pub struct SpeciesEntry {
     pub base_stats: BaseStats,
     pub gender_ratio: GenderRatio,
+    /// タイプ: [タイプ1, タイプ2] (単タイプは同じタイプを2回格納)
+    pub types: [PokemonType; 2],
+    /// タマゴグループ: [グループ1, グループ2] (単一グループは同じグループを2回格納)
+    pub egg_groups: [EggGroup; 2],
+    /// 進化前の種族 ID (0 = なし)
+    pub evolves_from: u16,
     /// 特性ID: [通常1, 通常2, 夢] (0 = なし)
     pub ability_ids: [u8; 3],
 }
 
+impl SpeciesEntry {
+    /// 指定タイプを持つか判定
+    #[inline]
+    pub fn has_type(&self, pokemon_type: PokemonType) -> bool {
+        self.types[0] == pokemon_type || self.types[1] == pokemon_type
+    }
+
+    /// 指定タマゴグループに属するか判定
+    #[inline]
+    pub fn has_egg_group(&self, egg_group: EggGroup) -> bool {
+        self.egg_groups[0] == egg_group || self.egg_groups[1] == egg_group
+    }
+}
+
 /// 種族テーブル (649件)
 pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
     // #001 Bulbasaur
@@ -38,6 +58,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 45,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Grass, PokemonType::Poison],
+        egg_groups: [EggGroup::Monster, EggGroup::Grass],
+        evolves_from: 0,
         ability_ids: [1, 0, 2],
     },
     // #002 Ivysaur
@@ -51,6 +74,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 60,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Grass, PokemonType::Poison],
+        egg_groups: [EggGroup::Monster, EggGroup::Grass],
+        evolves_from: 1,
         ability_ids: [1, 0, 2],
     },
     // #003 Venusaur
@@ -64,6 +90,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 80,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Grass, PokemonType::Poison],
+        egg_groups: [EggGroup::Monster, EggGroup::Grass],
+        evolves_from: 2,
         ability_ids: [1, 0, 2],
     },
     // #004 Charmander
@@ -77,6 +106,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 65,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Fire, PokemonType::Fire],
+        egg_groups: [EggGroup::Monster, EggGroup::Dragon],
+        evolves_from: 0,
         ability_ids: [3, 0, 4],
     },
     // #005 Charmeleon
@@ -90,6 +122,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 80,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Fire, PokemonType::Fire],
+        egg_groups: [EggGroup::Monster, EggGroup::Dragon],
+        evolves_from: 4,
         ability_ids: [3, 0, 4],
     },
     // #006 Charizard
@@ -103,6 +138,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 100,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Fire, PokemonType::Flying],
+        egg_groups: [EggGroup::Monster, EggGroup::Dragon],
+        evolves_from: 5,
         ability_ids: [3, 0, 4],
     },
     // #007 Squirtle
@@ -116,6 +154,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 43,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Monster, EggGroup::Water1],
+        evolves_from: 0,
         ability_ids: [5, 0, 6],
     },
     // #008 Wartortle
@@ -129,6 +170,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 58,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Monster, EggGroup::Water1],
+        evolves_from: 7,
         ability_ids: [5, 0, 6],
     },
     // #009 Blastoise
@@ -142,6 +186,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 78,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Monster, EggGroup::Water1],
+        evolves_from: 8,
         ability_ids: [5, 0, 6],
     },
     // #010 Caterpie
@@ -155,6 +202,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 45,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Bug],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 0,
         ability_ids: [7, 0, 8],
     },
     // #011 Metapod
@@ -168,6 +218,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 30,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Bug],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 10,
         ability_ids: [9, 0, 0],
     },
     // #012 Butterfree
@@ -181,6 +234,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 70,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Flying],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 11,
         ability_ids: [10, 0, 11],
     },
     // #013 Weedle
@@ -194,6 +250,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 50,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Poison],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 0,
         ability_ids: [7, 0, 8],
     },
     // #014 Kakuna
@@ -207,6 +266,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 35,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Poison],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 13,
         ability_ids: [9, 0, 0],
     },
     // #015 Beedrill
@@ -220,6 +282,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 75,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Poison],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 14,
         ability_ids: [12, 0, 13],
     },
     // #016 Pidgey
@@ -233,6 +298,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 56,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Flying],
+        egg_groups: [EggGroup::Flying, EggGroup::Flying],
+        evolves_from: 0,
         ability_ids: [14, 15, 16],
     },
     // #017 Pidgeotto
@@ -246,6 +314,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 71,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Flying],
+        egg_groups: [EggGroup::Flying, EggGroup::Flying],
+        evolves_from: 16,
         ability_ids: [14, 15, 16],
     },
     // #018 Pidgeot
@@ -259,6 +330,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 91,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Flying],
+        egg_groups: [EggGroup::Flying, EggGroup::Flying],
+        evolves_from: 17,
         ability_ids: [14, 15, 16],
     },
     // #019 Rattata
@@ -272,6 +346,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 72,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [8, 17, 18],
     },
     // #020 Raticate
@@ -285,6 +362,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 97,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 19,
         ability_ids: [8, 17, 18],
     },
     // #021 Spearow
@@ -298,6 +378,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 70,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Flying],
+        egg_groups: [EggGroup::Flying, EggGroup::Flying],
+        evolves_from: 0,
         ability_ids: [14, 0, 13],
     },
     // #022 Fearow
@@ -311,6 +394,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 100,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Flying],
+        egg_groups: [EggGroup::Flying, EggGroup::Flying],
+        evolves_from: 21,
         ability_ids: [14, 0, 13],
     },
     // #023 Ekans
@@ -324,6 +410,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 55,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Poison, PokemonType::Poison],
+        egg_groups: [EggGroup::Field, EggGroup::Dragon],
+        evolves_from: 0,
         ability_ids: [19, 9, 20],
     },
     // #024 Arbok
@@ -337,6 +426,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 80,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Poison, PokemonType::Poison],
+        egg_groups: [EggGroup::Field, EggGroup::Dragon],
+        evolves_from: 23,
         ability_ids: [19, 9, 20],
     },
     // #025 Pikachu
@@ -350,6 +442,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 90,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Electric, PokemonType::Electric],
+        egg_groups: [EggGroup::Field, EggGroup::Fairy],
+        evolves_from: 172,
         ability_ids: [21, 0, 22],
     },
     // #026 Raichu
@@ -363,6 +458,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 100,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Electric, PokemonType::Electric],
+        egg_groups: [EggGroup::Field, EggGroup::Fairy],
+        evolves_from: 25,
         ability_ids: [21, 0, 22],
     },
     // #027 Sandshrew
@@ -376,6 +474,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 40,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ground, PokemonType::Ground],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [23, 0, 24],
     },
     // #028 Sandslash
@@ -389,6 +490,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 65,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ground, PokemonType::Ground],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 27,
         ability_ids: [23, 0, 24],
     },
     // #029 Nidoran♀
@@ -402,6 +506,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 41,
         },
         gender_ratio: GenderRatio::FemaleOnly,
+        types: [PokemonType::Poison, PokemonType::Poison],
+        egg_groups: [EggGroup::Monster, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [25, 26, 18],
     },
     // #030 Nidorina
@@ -415,6 +522,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 56,
         },
         gender_ratio: GenderRatio::FemaleOnly,
+        types: [PokemonType::Poison, PokemonType::Poison],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 29,
         ability_ids: [25, 26, 18],
     },
     // #031 Nidoqueen
@@ -428,6 +538,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 76,
         },
         gender_ratio: GenderRatio::FemaleOnly,
+        types: [PokemonType::Poison, PokemonType::Ground],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 30,
         ability_ids: [25, 26, 27],
     },
     // #032 Nidoran♂
@@ -441,6 +554,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 50,
         },
         gender_ratio: GenderRatio::MaleOnly,
+        types: [PokemonType::Poison, PokemonType::Poison],
+        egg_groups: [EggGroup::Monster, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [25, 26, 18],
     },
     // #033 Nidorino
@@ -454,6 +570,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 65,
         },
         gender_ratio: GenderRatio::MaleOnly,
+        types: [PokemonType::Poison, PokemonType::Poison],
+        egg_groups: [EggGroup::Monster, EggGroup::Field],
+        evolves_from: 32,
         ability_ids: [25, 26, 18],
     },
     // #034 Nidoking
@@ -467,6 +586,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 85,
         },
         gender_ratio: GenderRatio::MaleOnly,
+        types: [PokemonType::Poison, PokemonType::Ground],
+        egg_groups: [EggGroup::Monster, EggGroup::Field],
+        evolves_from: 33,
         ability_ids: [25, 26, 27],
     },
     // #035 Clefairy
@@ -480,6 +602,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 35,
         },
         gender_ratio: GenderRatio::F3M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Fairy, EggGroup::Fairy],
+        evolves_from: 173,
         ability_ids: [28, 29, 30],
     },
     // #036 Clefable
@@ -493,6 +618,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 60,
         },
         gender_ratio: GenderRatio::F3M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Fairy, EggGroup::Fairy],
+        evolves_from: 35,
         ability_ids: [28, 29, 31],
     },
     // #037 Vulpix
@@ -506,6 +634,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 65,
         },
         gender_ratio: GenderRatio::F3M1,
+        types: [PokemonType::Fire, PokemonType::Fire],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [32, 0, 33],
     },
     // #038 Ninetales
@@ -519,6 +650,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 100,
         },
         gender_ratio: GenderRatio::F3M1,
+        types: [PokemonType::Fire, PokemonType::Fire],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 37,
         ability_ids: [32, 0, 33],
     },
     // #039 Jigglypuff
@@ -532,6 +666,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 20,
         },
         gender_ratio: GenderRatio::F3M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Fairy, EggGroup::Fairy],
+        evolves_from: 174,
         ability_ids: [28, 0, 30],
     },
     // #040 Wigglytuff
@@ -545,6 +682,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 45,
         },
         gender_ratio: GenderRatio::F3M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Fairy, EggGroup::Fairy],
+        evolves_from: 39,
         ability_ids: [28, 0, 34],
     },
     // #041 Zubat
@@ -558,6 +698,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 55,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Poison, PokemonType::Flying],
+        egg_groups: [EggGroup::Flying, EggGroup::Flying],
+        evolves_from: 0,
         ability_ids: [35, 0, 36],
     },
     // #042 Golbat
@@ -571,6 +714,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 90,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Poison, PokemonType::Flying],
+        egg_groups: [EggGroup::Flying, EggGroup::Flying],
+        evolves_from: 41,
         ability_ids: [35, 0, 36],
     },
     // #043 Oddish
@@ -584,6 +730,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 30,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Grass, PokemonType::Poison],
+        egg_groups: [EggGroup::Grass, EggGroup::Grass],
+        evolves_from: 0,
         ability_ids: [2, 0, 8],
     },
     // #044 Gloom
@@ -597,6 +746,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 40,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Grass, PokemonType::Poison],
+        egg_groups: [EggGroup::Grass, EggGroup::Grass],
+        evolves_from: 43,
         ability_ids: [2, 0, 37],
     },
     // #045 Vileplume
@@ -610,6 +762,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 50,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Grass, PokemonType::Poison],
+        egg_groups: [EggGroup::Grass, EggGroup::Grass],
+        evolves_from: 44,
         ability_ids: [2, 0, 38],
     },
     // #046 Paras
@@ -623,6 +778,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 25,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Grass],
+        egg_groups: [EggGroup::Bug, EggGroup::Grass],
+        evolves_from: 0,
         ability_ids: [38, 39, 40],
     },
     // #047 Parasect
@@ -636,6 +794,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 30,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Grass],
+        egg_groups: [EggGroup::Bug, EggGroup::Grass],
+        evolves_from: 46,
         ability_ids: [38, 39, 40],
     },
     // #048 Venonat
@@ -649,6 +810,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 45,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Poison],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 0,
         ability_ids: [10, 11, 8],
     },
     // #049 Venomoth
@@ -662,6 +826,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 90,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Poison],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 48,
         ability_ids: [7, 11, 41],
     },
     // #050 Diglett
@@ -675,6 +842,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 95,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ground, PokemonType::Ground],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [23, 42, 43],
     },
     // #051 Dugtrio
@@ -688,6 +858,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 120,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ground, PokemonType::Ground],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 50,
         ability_ids: [23, 42, 43],
     },
     // #052 Meowth
@@ -701,6 +874,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 90,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [44, 45, 20],
     },
     // #053 Persian
@@ -714,6 +890,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 115,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 52,
         ability_ids: [46, 45, 20],
     },
     // #054 Psyduck
@@ -727,6 +906,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 55,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Water1, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [40, 47, 48],
     },
     // #055 Golduck
@@ -740,6 +922,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 85,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Water1, EggGroup::Field],
+        evolves_from: 54,
         ability_ids: [40, 47, 48],
     },
     // #056 Mankey
@@ -753,6 +938,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 70,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Fighting, PokemonType::Fighting],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [49, 50, 51],
     },
     // #057 Primeape
@@ -766,6 +954,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 95,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Fighting, PokemonType::Fighting],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 56,
         ability_ids: [49, 50, 51],
     },
     // #058 Growlithe
@@ -779,6 +970,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 60,
         },
         gender_ratio: GenderRatio::F1M3,
+        types: [PokemonType::Fire, PokemonType::Fire],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [19, 32, 52],
     },
     // #059 Arcanine
@@ -792,6 +986,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 95,
         },
         gender_ratio: GenderRatio::F1M3,
+        types: [PokemonType::Fire, PokemonType::Fire],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 58,
         ability_ids: [19, 32, 52],
     },
     // #060 Poliwag
@@ -805,6 +1002,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 90,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Water1, EggGroup::Water1],
+        evolves_from: 0,
         ability_ids: [53, 40, 48],
     },
     // #061 Poliwhirl
@@ -818,6 +1018,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 90,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Water1, EggGroup::Water1],
+        evolves_from: 60,
         ability_ids: [53, 40, 48],
     },
     // #062 Poliwrath
@@ -831,6 +1034,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 70,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Fighting],
+        egg_groups: [EggGroup::Water1, EggGroup::Water1],
+        evolves_from: 61,
         ability_ids: [53, 40, 48],
     },
     // #063 Abra
@@ -844,6 +1050,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 90,
         },
         gender_ratio: GenderRatio::F1M3,
+        types: [PokemonType::Psychic, PokemonType::Psychic],
+        egg_groups: [EggGroup::HumanLike, EggGroup::HumanLike],
+        evolves_from: 0,
         ability_ids: [54, 35, 29],
     },
     // #064 Kadabra
@@ -857,6 +1066,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 105,
         },
         gender_ratio: GenderRatio::F1M3,
+        types: [PokemonType::Psychic, PokemonType::Psychic],
+        egg_groups: [EggGroup::HumanLike, EggGroup::HumanLike],
+        evolves_from: 63,
         ability_ids: [54, 35, 29],
     },
     // #065 Alakazam
@@ -870,6 +1082,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 120,
         },
         gender_ratio: GenderRatio::F1M3,
+        types: [PokemonType::Psychic, PokemonType::Psychic],
+        egg_groups: [EggGroup::HumanLike, EggGroup::HumanLike],
+        evolves_from: 64,
         ability_ids: [54, 35, 29],
     },
     // #066 Machop
@@ -883,6 +1098,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 35,
         },
         gender_ratio: GenderRatio::F1M3,
+        types: [PokemonType::Fighting, PokemonType::Fighting],
+        egg_groups: [EggGroup::HumanLike, EggGroup::HumanLike],
+        evolves_from: 0,
         ability_ids: [17, 55, 56],
     },
     // #067 Machoke
@@ -896,6 +1114,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 45,
         },
         gender_ratio: GenderRatio::F1M3,
+        types: [PokemonType::Fighting, PokemonType::Fighting],
+        egg_groups: [EggGroup::HumanLike, EggGroup::HumanLike],
+        evolves_from: 66,
         ability_ids: [17, 55, 56],
     },
     // #068 Machamp
@@ -909,6 +1130,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 55,
         },
         gender_ratio: GenderRatio::F1M3,
+        types: [PokemonType::Fighting, PokemonType::Fighting],
+        egg_groups: [EggGroup::HumanLike, EggGroup::HumanLike],
+        evolves_from: 67,
         ability_ids: [17, 55, 56],
     },
     // #069 Bellsprout
@@ -922,6 +1146,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 40,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Grass, PokemonType::Poison],
+        egg_groups: [EggGroup::Grass, EggGroup::Grass],
+        evolves_from: 0,
         ability_ids: [2, 0, 57],
     },
     // #070 Weepinbell
@@ -935,6 +1162,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 55,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Grass, PokemonType::Poison],
+        egg_groups: [EggGroup::Grass, EggGroup::Grass],
+        evolves_from: 69,
         ability_ids: [2, 0, 57],
     },
     // #071 Victreebel
@@ -948,6 +1178,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 70,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Grass, PokemonType::Poison],
+        egg_groups: [EggGroup::Grass, EggGroup::Grass],
+        evolves_from: 70,
         ability_ids: [2, 0, 57],
     },
     // #072 Tentacool
@@ -961,6 +1194,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 70,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Poison],
+        egg_groups: [EggGroup::Water3, EggGroup::Water3],
+        evolves_from: 0,
         ability_ids: [58, 59, 6],
     },
     // #073 Tentacruel
@@ -974,6 +1210,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 100,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Poison],
+        egg_groups: [EggGroup::Water3, EggGroup::Water3],
+        evolves_from: 72,
         ability_ids: [58, 59, 6],
     },
     // #074 Geodude
@@ -987,6 +1226,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 20,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Rock, PokemonType::Ground],
+        egg_groups: [EggGroup::Mineral, EggGroup::Mineral],
+        evolves_from: 0,
         ability_ids: [60, 61, 23],
     },
     // #075 Graveler
@@ -1000,6 +1242,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 35,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Rock, PokemonType::Ground],
+        egg_groups: [EggGroup::Mineral, EggGroup::Mineral],
+        evolves_from: 74,
         ability_ids: [60, 61, 23],
     },
     // #076 Golem
@@ -1013,6 +1258,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 45,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Rock, PokemonType::Ground],
+        egg_groups: [EggGroup::Mineral, EggGroup::Mineral],
+        evolves_from: 75,
         ability_ids: [60, 61, 23],
     },
     // #077 Ponyta
@@ -1026,6 +1274,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 90,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Fire, PokemonType::Fire],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [8, 32, 62],
     },
     // #078 Rapidash
@@ -1039,6 +1290,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 105,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Fire, PokemonType::Fire],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 77,
         ability_ids: [8, 32, 62],
     },
     // #079 Slowpoke
@@ -1052,6 +1306,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 15,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Psychic],
+        egg_groups: [EggGroup::Monster, EggGroup::Water1],
+        evolves_from: 0,
         ability_ids: [63, 64, 65],
     },
     // #080 Slowbro
@@ -1065,6 +1322,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 30,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Psychic],
+        egg_groups: [EggGroup::Monster, EggGroup::Water1],
+        evolves_from: 79,
         ability_ids: [63, 64, 65],
     },
     // #081 Magnemite
@@ -1078,6 +1338,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 45,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Electric, PokemonType::Steel],
+        egg_groups: [EggGroup::Mineral, EggGroup::Mineral],
+        evolves_from: 0,
         ability_ids: [66, 61, 67],
     },
     // #082 Magneton
@@ -1091,6 +1354,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 70,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Electric, PokemonType::Steel],
+        egg_groups: [EggGroup::Mineral, EggGroup::Mineral],
+        evolves_from: 81,
         ability_ids: [66, 61, 67],
     },
     // #083 Farfetch’d
@@ -1104,6 +1370,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 60,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Flying],
+        egg_groups: [EggGroup::Flying, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [14, 35, 51],
     },
     // #084 Doduo
@@ -1117,6 +1386,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 75,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Flying],
+        egg_groups: [EggGroup::Flying, EggGroup::Flying],
+        evolves_from: 0,
         ability_ids: [8, 68, 15],
     },
     // #085 Dodrio
@@ -1130,6 +1402,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 100,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Flying],
+        egg_groups: [EggGroup::Flying, EggGroup::Flying],
+        evolves_from: 84,
         ability_ids: [8, 68, 15],
     },
     // #086 Seel
@@ -1143,6 +1418,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 45,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Water1, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [69, 70, 71],
     },
     // #087 Dewgong
@@ -1156,6 +1434,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 70,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Ice],
+        egg_groups: [EggGroup::Water1, EggGroup::Field],
+        evolves_from: 86,
         ability_ids: [69, 70, 71],
     },
     // #088 Grimer
@@ -1169,6 +1450,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 25,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Poison, PokemonType::Poison],
+        egg_groups: [EggGroup::Amorphous, EggGroup::Amorphous],
+        evolves_from: 0,
         ability_ids: [37, 72, 73],
     },
     // #089 Muk
@@ -1182,6 +1466,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 50,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Poison, PokemonType::Poison],
+        egg_groups: [EggGroup::Amorphous, EggGroup::Amorphous],
+        evolves_from: 88,
         ability_ids: [37, 72, 73],
     },
     // #090 Shellder
@@ -1195,6 +1482,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 40,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Water3, EggGroup::Water3],
+        evolves_from: 0,
         ability_ids: [74, 75, 76],
     },
     // #091 Cloyster
@@ -1208,6 +1498,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 70,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Ice],
+        egg_groups: [EggGroup::Water3, EggGroup::Water3],
+        evolves_from: 90,
         ability_ids: [74, 75, 76],
     },
     // #092 Gastly
@@ -1221,6 +1514,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 80,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ghost, PokemonType::Poison],
+        egg_groups: [EggGroup::Amorphous, EggGroup::Amorphous],
+        evolves_from: 0,
         ability_ids: [77, 0, 0],
     },
     // #093 Haunter
@@ -1234,6 +1530,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 95,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ghost, PokemonType::Poison],
+        egg_groups: [EggGroup::Amorphous, EggGroup::Amorphous],
+        evolves_from: 92,
         ability_ids: [77, 0, 0],
     },
     // #094 Gengar
@@ -1247,6 +1546,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 110,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ghost, PokemonType::Poison],
+        egg_groups: [EggGroup::Amorphous, EggGroup::Amorphous],
+        evolves_from: 93,
         ability_ids: [77, 0, 0],
     },
     // #095 Onix
@@ -1260,6 +1562,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 70,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Rock, PokemonType::Ground],
+        egg_groups: [EggGroup::Mineral, EggGroup::Mineral],
+        evolves_from: 0,
         ability_ids: [60, 61, 78],
     },
     // #096 Drowzee
@@ -1273,6 +1578,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 42,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Psychic, PokemonType::Psychic],
+        egg_groups: [EggGroup::HumanLike, EggGroup::HumanLike],
+        evolves_from: 0,
         ability_ids: [79, 80, 35],
     },
     // #097 Hypno
@@ -1286,6 +1594,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 67,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Psychic, PokemonType::Psychic],
+        egg_groups: [EggGroup::HumanLike, EggGroup::HumanLike],
+        evolves_from: 96,
         ability_ids: [79, 80, 35],
     },
     // #098 Krabby
@@ -1299,6 +1610,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 50,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Water3, EggGroup::Water3],
+        evolves_from: 0,
         ability_ids: [81, 74, 27],
     },
     // #099 Kingler
@@ -1312,6 +1626,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 75,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Water3, EggGroup::Water3],
+        evolves_from: 98,
         ability_ids: [81, 74, 27],
     },
     // #100 Voltorb
@@ -1325,6 +1642,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 100,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Electric, PokemonType::Electric],
+        egg_groups: [EggGroup::Mineral, EggGroup::Mineral],
+        evolves_from: 0,
         ability_ids: [82, 21, 83],
     },
     // #101 Electrode
@@ -1338,6 +1658,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 140,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Electric, PokemonType::Electric],
+        egg_groups: [EggGroup::Mineral, EggGroup::Mineral],
+        evolves_from: 100,
         ability_ids: [82, 21, 83],
     },
     // #102 Exeggcute
@@ -1351,6 +1674,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 40,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Grass, PokemonType::Psychic],
+        egg_groups: [EggGroup::Grass, EggGroup::Grass],
+        evolves_from: 0,
         ability_ids: [2, 0, 84],
     },
     // #103 Exeggutor
@@ -1364,6 +1690,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 55,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Grass, PokemonType::Psychic],
+        egg_groups: [EggGroup::Grass, EggGroup::Grass],
+        evolves_from: 102,
         ability_ids: [2, 0, 84],
     },
     // #104 Cubone
@@ -1377,6 +1706,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 35,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ground, PokemonType::Ground],
+        egg_groups: [EggGroup::Monster, EggGroup::Monster],
+        evolves_from: 0,
         ability_ids: [60, 22, 85],
     },
     // #105 Marowak
@@ -1390,6 +1722,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 45,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ground, PokemonType::Ground],
+        egg_groups: [EggGroup::Monster, EggGroup::Monster],
+        evolves_from: 104,
         ability_ids: [60, 22, 85],
     },
     // #106 Hitmonlee
@@ -1403,6 +1738,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 87,
         },
         gender_ratio: GenderRatio::MaleOnly,
+        types: [PokemonType::Fighting, PokemonType::Fighting],
+        egg_groups: [EggGroup::HumanLike, EggGroup::HumanLike],
+        evolves_from: 236,
         ability_ids: [46, 86, 87],
     },
     // #107 Hitmonchan
@@ -1416,6 +1754,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 76,
         },
         gender_ratio: GenderRatio::MaleOnly,
+        types: [PokemonType::Fighting, PokemonType::Fighting],
+        egg_groups: [EggGroup::HumanLike, EggGroup::HumanLike],
+        evolves_from: 236,
         ability_ids: [14, 88, 35],
     },
     // #108 Lickitung
@@ -1429,6 +1770,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 30,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Monster, EggGroup::Monster],
+        evolves_from: 0,
         ability_ids: [64, 63, 47],
     },
     // #109 Koffing
@@ -1442,6 +1786,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 35,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Poison, PokemonType::Poison],
+        egg_groups: [EggGroup::Amorphous, EggGroup::Amorphous],
+        evolves_from: 0,
         ability_ids: [77, 0, 37],
     },
     // #110 Weezing
@@ -1455,6 +1802,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 60,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Poison, PokemonType::Poison],
+        egg_groups: [EggGroup::Amorphous, EggGroup::Amorphous],
+        evolves_from: 109,
         ability_ids: [77, 0, 37],
     },
     // #111 Rhyhorn
@@ -1468,6 +1818,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 25,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ground, PokemonType::Rock],
+        egg_groups: [EggGroup::Monster, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [22, 60, 86],
     },
     // #112 Rhydon
@@ -1481,6 +1834,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 40,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ground, PokemonType::Rock],
+        egg_groups: [EggGroup::Monster, EggGroup::Field],
+        evolves_from: 111,
         ability_ids: [22, 60, 86],
     },
     // #113 Chansey
@@ -1494,6 +1850,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 50,
         },
         gender_ratio: GenderRatio::FemaleOnly,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Fairy, EggGroup::Fairy],
+        evolves_from: 440,
         ability_ids: [89, 90, 91],
     },
     // #114 Tangela
@@ -1507,6 +1866,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 60,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Grass, PokemonType::Grass],
+        egg_groups: [EggGroup::Grass, EggGroup::Grass],
+        evolves_from: 0,
         ability_ids: [2, 92, 65],
     },
     // #115 Kangaskhan
@@ -1520,6 +1882,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 90,
         },
         gender_ratio: GenderRatio::FemaleOnly,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Monster, EggGroup::Monster],
+        evolves_from: 0,
         ability_ids: [68, 93, 35],
     },
     // #116 Horsea
@@ -1533,6 +1898,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 60,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Water1, EggGroup::Dragon],
+        evolves_from: 0,
         ability_ids: [48, 13, 40],
     },
     // #117 Seadra
@@ -1546,6 +1914,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 85,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Water1, EggGroup::Dragon],
+        evolves_from: 116,
         ability_ids: [25, 13, 40],
     },
     // #118 Goldeen
@@ -1559,6 +1930,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 63,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Water2, EggGroup::Water2],
+        evolves_from: 0,
         ability_ids: [48, 94, 22],
     },
     // #119 Seaking
@@ -1572,6 +1946,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 68,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Water2, EggGroup::Water2],
+        evolves_from: 118,
         ability_ids: [48, 94, 22],
     },
     // #120 Staryu
@@ -1585,6 +1962,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 85,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Water3, EggGroup::Water3],
+        evolves_from: 0,
         ability_ids: [95, 89, 67],
     },
     // #121 Starmie
@@ -1598,6 +1978,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 115,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Water, PokemonType::Psychic],
+        egg_groups: [EggGroup::Water3, EggGroup::Water3],
+        evolves_from: 120,
         ability_ids: [95, 89, 67],
     },
     // #122 Mr. Mime
@@ -1611,6 +1994,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 90,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Psychic, PokemonType::Psychic],
+        egg_groups: [EggGroup::HumanLike, EggGroup::HumanLike],
+        evolves_from: 439,
         ability_ids: [82, 96, 45],
     },
     // #123 Scyther
@@ -1624,6 +2010,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 105,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Flying],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 0,
         ability_ids: [12, 45, 56],
     },
     // #124 Jynx
@@ -1637,6 +2026,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 95,
         },
         gender_ratio: GenderRatio::FemaleOnly,
+        types: [PokemonType::Ice, PokemonType::Psychic],
+        egg_groups: [EggGroup::HumanLike, EggGroup::HumanLike],
+        evolves_from: 238,
         ability_ids: [63, 80, 39],
     },
     // #125 Electabuzz
@@ -1650,6 +2042,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 105,
         },
         gender_ratio: GenderRatio::F1M3,
+        types: [PokemonType::Electric, PokemonType::Electric],
+        egg_groups: [EggGroup::HumanLike, EggGroup::HumanLike],
+        evolves_from: 239,
         ability_ids: [21, 0, 49],
     },
     // #126 Magmar
@@ -1663,6 +2058,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 93,
         },
         gender_ratio: GenderRatio::F1M3,
+        types: [PokemonType::Fire, PokemonType::Fire],
+        egg_groups: [EggGroup::HumanLike, EggGroup::HumanLike],
+        evolves_from: 240,
         ability_ids: [62, 0, 49],
     },
     // #127 Pinsir
@@ -1676,6 +2074,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 85,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Bug],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 0,
         ability_ids: [81, 97, 98],
     },
     // #128 Tauros
@@ -1689,6 +2090,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 110,
         },
         gender_ratio: GenderRatio::MaleOnly,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [19, 50, 27],
     },
     // #129 Magikarp
@@ -1702,6 +2106,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 80,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Water2, EggGroup::Dragon],
+        evolves_from: 0,
         ability_ids: [48, 0, 99],
     },
     // #130 Gyarados
@@ -1715,6 +2122,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 81,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Flying],
+        egg_groups: [EggGroup::Water2, EggGroup::Dragon],
+        evolves_from: 129,
         ability_ids: [19, 0, 98],
     },
     // #131 Lapras
@@ -1728,6 +2138,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 60,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Ice],
+        egg_groups: [EggGroup::Monster, EggGroup::Water1],
+        evolves_from: 0,
         ability_ids: [53, 74, 70],
     },
     // #132 Ditto
@@ -1741,6 +2154,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 48,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Ditto, EggGroup::Ditto],
+        evolves_from: 0,
         ability_ids: [46, 0, 100],
     },
     // #133 Eevee
@@ -1754,6 +2170,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 55,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [8, 101, 102],
     },
     // #134 Vaporeon
@@ -1767,6 +2186,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 65,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 133,
         ability_ids: [53, 0, 70],
     },
     // #135 Jolteon
@@ -1780,6 +2202,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 130,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Electric, PokemonType::Electric],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 133,
         ability_ids: [103, 0, 104],
     },
     // #136 Flareon
@@ -1793,6 +2218,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 65,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Fire, PokemonType::Fire],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 133,
         ability_ids: [32, 0, 17],
     },
     // #137 Porygon
@@ -1806,6 +2234,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 40,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Mineral, EggGroup::Mineral],
+        evolves_from: 0,
         ability_ids: [105, 106, 67],
     },
     // #138 Omanyte
@@ -1819,6 +2250,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 35,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Rock, PokemonType::Water],
+        egg_groups: [EggGroup::Water1, EggGroup::Water3],
+        evolves_from: 0,
         ability_ids: [48, 74, 78],
     },
     // #139 Omastar
@@ -1832,6 +2266,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 55,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Rock, PokemonType::Water],
+        egg_groups: [EggGroup::Water1, EggGroup::Water3],
+        evolves_from: 138,
         ability_ids: [48, 74, 78],
     },
     // #140 Kabuto
@@ -1845,6 +2282,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 55,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Rock, PokemonType::Water],
+        egg_groups: [EggGroup::Water1, EggGroup::Water3],
+        evolves_from: 0,
         ability_ids: [48, 85, 78],
     },
     // #141 Kabutops
@@ -1858,6 +2298,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 80,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Rock, PokemonType::Water],
+        egg_groups: [EggGroup::Water1, EggGroup::Water3],
+        evolves_from: 140,
         ability_ids: [48, 85, 78],
     },
     // #142 Aerodactyl
@@ -1871,6 +2314,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 130,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Rock, PokemonType::Flying],
+        egg_groups: [EggGroup::Flying, EggGroup::Flying],
+        evolves_from: 0,
         ability_ids: [60, 107, 20],
     },
     // #143 Snorlax
@@ -1884,6 +2330,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 30,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Monster, EggGroup::Monster],
+        evolves_from: 446,
         ability_ids: [108, 69, 57],
     },
     // #144 Articuno
@@ -1897,6 +2346,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 85,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Ice, PokemonType::Flying],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [107, 0, 109],
     },
     // #145 Zapdos
@@ -1910,6 +2362,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 100,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Electric, PokemonType::Flying],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [107, 0, 22],
     },
     // #146 Moltres
@@ -1923,6 +2378,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 90,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Fire, PokemonType::Flying],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [107, 0, 62],
     },
     // #147 Dratini
@@ -1936,6 +2394,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 50,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Dragon, PokemonType::Dragon],
+        egg_groups: [EggGroup::Water1, EggGroup::Dragon],
+        evolves_from: 0,
         ability_ids: [9, 0, 110],
     },
     // #148 Dragonair
@@ -1949,6 +2410,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 70,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Dragon, PokemonType::Dragon],
+        egg_groups: [EggGroup::Water1, EggGroup::Dragon],
+        evolves_from: 147,
         ability_ids: [9, 0, 110],
     },
     // #149 Dragonite
@@ -1962,6 +2426,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 80,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Dragon, PokemonType::Flying],
+        egg_groups: [EggGroup::Water1, EggGroup::Dragon],
+        evolves_from: 148,
         ability_ids: [35, 0, 111],
     },
     // #150 Mewtwo
@@ -1975,6 +2442,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 130,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Psychic, PokemonType::Psychic],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [107, 0, 20],
     },
     // #151 Mew
@@ -1988,6 +2458,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 100,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Psychic, PokemonType::Psychic],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [54, 0, 0],
     },
     // #152 Chikorita
@@ -2001,6 +2474,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 45,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Grass, PokemonType::Grass],
+        egg_groups: [EggGroup::Monster, EggGroup::Grass],
+        evolves_from: 0,
         ability_ids: [1, 0, 92],
     },
     // #153 Bayleef
@@ -2014,6 +2490,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 60,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Grass, PokemonType::Grass],
+        egg_groups: [EggGroup::Monster, EggGroup::Grass],
+        evolves_from: 152,
         ability_ids: [1, 0, 92],
     },
     // #154 Meganium
@@ -2027,6 +2506,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 80,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Grass, PokemonType::Grass],
+        egg_groups: [EggGroup::Monster, EggGroup::Grass],
+        evolves_from: 153,
         ability_ids: [1, 0, 92],
     },
     // #155 Cyndaquil
@@ -2040,6 +2522,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 65,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Fire, PokemonType::Fire],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [3, 0, 32],
     },
     // #156 Quilava
@@ -2053,6 +2538,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 80,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Fire, PokemonType::Fire],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 155,
         ability_ids: [3, 0, 32],
     },
     // #157 Typhlosion
@@ -2066,6 +2554,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 100,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Fire, PokemonType::Fire],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 156,
         ability_ids: [3, 0, 32],
     },
     // #158 Totodile
@@ -2079,6 +2570,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 43,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Monster, EggGroup::Water1],
+        evolves_from: 0,
         ability_ids: [5, 0, 27],
     },
     // #159 Croconaw
@@ -2092,6 +2586,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 58,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Monster, EggGroup::Water1],
+        evolves_from: 158,
         ability_ids: [5, 0, 27],
     },
     // #160 Feraligatr
@@ -2105,6 +2602,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 78,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Monster, EggGroup::Water1],
+        evolves_from: 159,
         ability_ids: [5, 0, 27],
     },
     // #161 Sentret
@@ -2118,6 +2618,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 20,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [8, 14, 34],
     },
     // #162 Furret
@@ -2131,6 +2634,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 90,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 161,
         ability_ids: [8, 14, 34],
     },
     // #163 Hoothoot
@@ -2144,6 +2650,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 50,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Flying],
+        egg_groups: [EggGroup::Flying, EggGroup::Flying],
+        evolves_from: 0,
         ability_ids: [79, 14, 11],
     },
     // #164 Noctowl
@@ -2157,6 +2666,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 70,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Flying],
+        egg_groups: [EggGroup::Flying, EggGroup::Flying],
+        evolves_from: 163,
         ability_ids: [79, 14, 11],
     },
     // #165 Ledyba
@@ -2170,6 +2682,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 55,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Flying],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 0,
         ability_ids: [12, 68, 99],
     },
     // #166 Ledian
@@ -2183,6 +2698,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 85,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Flying],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 165,
         ability_ids: [12, 68, 88],
     },
     // #167 Spinarak
@@ -2196,6 +2714,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 30,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Poison],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 0,
         ability_ids: [12, 79, 13],
     },
     // #168 Ariados
@@ -2209,6 +2730,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 40,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Poison],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 167,
         ability_ids: [12, 79, 13],
     },
     // #169 Crobat
@@ -2222,6 +2746,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 130,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Poison, PokemonType::Flying],
+        egg_groups: [EggGroup::Flying, EggGroup::Flying],
+        evolves_from: 42,
         ability_ids: [35, 0, 36],
     },
     // #170 Chinchou
@@ -2235,6 +2762,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 67,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Electric],
+        egg_groups: [EggGroup::Water2, EggGroup::Water2],
+        evolves_from: 0,
         ability_ids: [103, 95, 53],
     },
     // #171 Lanturn
@@ -2248,6 +2778,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 67,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Electric],
+        egg_groups: [EggGroup::Water2, EggGroup::Water2],
+        evolves_from: 170,
         ability_ids: [103, 95, 53],
     },
     // #172 Pichu
@@ -2261,6 +2794,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 60,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Electric, PokemonType::Electric],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [21, 0, 22],
     },
     // #173 Cleffa
@@ -2274,6 +2810,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 15,
         },
         gender_ratio: GenderRatio::F3M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [28, 29, 30],
     },
     // #174 Igglybuff
@@ -2287,6 +2826,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 15,
         },
         gender_ratio: GenderRatio::F3M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [28, 0, 30],
     },
     // #175 Togepi
@@ -2300,6 +2842,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 20,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [18, 90, 112],
     },
     // #176 Togetic
@@ -2313,6 +2858,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 40,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Normal, PokemonType::Flying],
+        egg_groups: [EggGroup::Flying, EggGroup::Fairy],
+        evolves_from: 175,
         ability_ids: [18, 90, 112],
     },
     // #177 Natu
@@ -2326,6 +2874,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 70,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Psychic, PokemonType::Flying],
+        egg_groups: [EggGroup::Flying, EggGroup::Flying],
+        evolves_from: 0,
         ability_ids: [54, 68, 113],
     },
     // #178 Xatu
@@ -2339,6 +2890,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 95,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Psychic, PokemonType::Flying],
+        egg_groups: [EggGroup::Flying, EggGroup::Flying],
+        evolves_from: 177,
         ability_ids: [54, 68, 113],
     },
     // #179 Mareep
@@ -2352,6 +2906,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 35,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Electric, PokemonType::Electric],
+        egg_groups: [EggGroup::Monster, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [21, 0, 114],
     },
     // #180 Flaaffy
@@ -2365,6 +2922,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 45,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Electric, PokemonType::Electric],
+        egg_groups: [EggGroup::Monster, EggGroup::Field],
+        evolves_from: 179,
         ability_ids: [21, 0, 114],
     },
     // #181 Ampharos
@@ -2378,6 +2938,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 55,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Electric, PokemonType::Electric],
+        egg_groups: [EggGroup::Monster, EggGroup::Field],
+        evolves_from: 180,
         ability_ids: [21, 0, 114],
     },
     // #182 Bellossom
@@ -2391,6 +2954,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 50,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Grass, PokemonType::Grass],
+        egg_groups: [EggGroup::Grass, EggGroup::Grass],
+        evolves_from: 44,
         ability_ids: [2, 0, 91],
     },
     // #183 Marill
@@ -2404,6 +2970,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 40,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Water1, EggGroup::Fairy],
+        evolves_from: 298,
         ability_ids: [69, 115, 116],
     },
     // #184 Azumarill
@@ -2417,6 +2986,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 50,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Water1, EggGroup::Fairy],
+        evolves_from: 183,
         ability_ids: [69, 115, 116],
     },
     // #185 Sudowoodo
@@ -2430,6 +3002,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 30,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Rock, PokemonType::Rock],
+        egg_groups: [EggGroup::Mineral, EggGroup::Mineral],
+        evolves_from: 438,
         ability_ids: [61, 60, 99],
     },
     // #186 Politoed
@@ -2443,6 +3018,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 70,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Water1, EggGroup::Water1],
+        evolves_from: 61,
         ability_ids: [53, 40, 117],
     },
     // #187 Hoppip
@@ -2456,6 +3034,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 50,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Grass, PokemonType::Flying],
+        egg_groups: [EggGroup::Fairy, EggGroup::Grass],
+        evolves_from: 0,
         ability_ids: [2, 92, 36],
     },
     // #188 Skiploom
@@ -2469,6 +3050,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 80,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Grass, PokemonType::Flying],
+        egg_groups: [EggGroup::Fairy, EggGroup::Grass],
+        evolves_from: 187,
         ability_ids: [2, 92, 36],
     },
     // #189 Jumpluff
@@ -2482,6 +3066,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 110,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Grass, PokemonType::Flying],
+        egg_groups: [EggGroup::Fairy, EggGroup::Grass],
+        evolves_from: 188,
         ability_ids: [2, 92, 36],
     },
     // #190 Aipom
@@ -2495,6 +3082,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 85,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [8, 44, 75],
     },
     // #191 Sunkern
@@ -2508,6 +3098,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 30,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Grass, PokemonType::Grass],
+        egg_groups: [EggGroup::Grass, EggGroup::Grass],
+        evolves_from: 0,
         ability_ids: [2, 4, 68],
     },
     // #192 Sunflora
@@ -2521,6 +3114,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 30,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Grass, PokemonType::Grass],
+        egg_groups: [EggGroup::Grass, EggGroup::Grass],
+        evolves_from: 191,
         ability_ids: [2, 4, 68],
     },
     // #193 Yanma
@@ -2534,6 +3130,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 95,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Flying],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 0,
         ability_ids: [118, 10, 34],
     },
     // #194 Wooper
@@ -2547,6 +3146,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 15,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Ground],
+        egg_groups: [EggGroup::Water1, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [40, 53, 31],
     },
     // #195 Quagsire
@@ -2560,6 +3162,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 35,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Ground],
+        egg_groups: [EggGroup::Water1, EggGroup::Field],
+        evolves_from: 194,
         ability_ids: [40, 53, 31],
     },
     // #196 Espeon
@@ -2573,6 +3178,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 110,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Psychic, PokemonType::Psychic],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 133,
         ability_ids: [54, 0, 113],
     },
     // #197 Umbreon
@@ -2586,6 +3194,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 65,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Dark, PokemonType::Dark],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 133,
         ability_ids: [54, 0, 35],
     },
     // #198 Murkrow
@@ -2599,6 +3210,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 91,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Dark, PokemonType::Flying],
+        egg_groups: [EggGroup::Flying, EggGroup::Flying],
+        evolves_from: 0,
         ability_ids: [79, 112, 119],
     },
     // #199 Slowking
@@ -2612,6 +3226,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 30,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Psychic],
+        egg_groups: [EggGroup::Monster, EggGroup::Water1],
+        evolves_from: 79,
         ability_ids: [63, 64, 65],
     },
     // #200 Misdreavus
@@ -2625,6 +3242,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 85,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ghost, PokemonType::Ghost],
+        egg_groups: [EggGroup::Amorphous, EggGroup::Amorphous],
+        evolves_from: 0,
         ability_ids: [77, 0, 0],
     },
     // #201 Unown
@@ -2638,6 +3258,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 48,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Psychic, PokemonType::Psychic],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [77, 0, 0],
     },
     // #202 Wobbuffet
@@ -2651,6 +3274,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 33,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Psychic, PokemonType::Psychic],
+        egg_groups: [EggGroup::Amorphous, EggGroup::Amorphous],
+        evolves_from: 360,
         ability_ids: [120, 0, 121],
     },
     // #203 Girafarig
@@ -2664,6 +3290,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 85,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Psychic],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [35, 68, 116],
     },
     // #204 Pineco
@@ -2677,6 +3306,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 15,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Bug],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 0,
         ability_ids: [61, 0, 76],
     },
     // #205 Forretress
@@ -2690,6 +3322,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 40,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Steel],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 204,
         ability_ids: [61, 0, 76],
     },
     // #206 Dunsparce
@@ -2703,6 +3338,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 45,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [90, 8, 99],
     },
     // #207 Gligar
@@ -2716,6 +3354,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 85,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ground, PokemonType::Flying],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 0,
         ability_ids: [81, 23, 108],
     },
     // #208 Steelix
@@ -2729,6 +3370,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 30,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Steel, PokemonType::Ground],
+        egg_groups: [EggGroup::Mineral, EggGroup::Mineral],
+        evolves_from: 95,
         ability_ids: [60, 61, 27],
     },
     // #209 Snubbull
@@ -2742,6 +3386,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 30,
         },
         gender_ratio: GenderRatio::F3M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Field, EggGroup::Fairy],
+        evolves_from: 0,
         ability_ids: [19, 8, 99],
     },
     // #210 Granbull
@@ -2755,6 +3402,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 45,
         },
         gender_ratio: GenderRatio::F3M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Field, EggGroup::Fairy],
+        evolves_from: 209,
         ability_ids: [19, 104, 99],
     },
     // #211 Qwilfish
@@ -2768,6 +3418,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 85,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Poison],
+        egg_groups: [EggGroup::Water2, EggGroup::Water2],
+        evolves_from: 0,
         ability_ids: [25, 48, 19],
     },
     // #212 Scizor
@@ -2781,6 +3434,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 65,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Steel],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 123,
         ability_ids: [12, 45, 122],
     },
     // #213 Shuckle
@@ -2794,6 +3450,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 5,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Rock],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 0,
         ability_ids: [61, 57, 123],
     },
     // #214 Heracross
@@ -2807,6 +3466,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 85,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Fighting],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 0,
         ability_ids: [12, 17, 98],
     },
     // #215 Sneasel
@@ -2820,6 +3482,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 115,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Dark, PokemonType::Ice],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [35, 14, 124],
     },
     // #216 Teddiursa
@@ -2833,6 +3498,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 40,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [44, 104, 125],
     },
     // #217 Ursaring
@@ -2846,6 +3514,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 55,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 216,
         ability_ids: [17, 104, 20],
     },
     // #218 Slugma
@@ -2859,6 +3530,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 20,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Fire, PokemonType::Fire],
+        egg_groups: [EggGroup::Amorphous, EggGroup::Amorphous],
+        evolves_from: 0,
         ability_ids: [126, 62, 78],
     },
     // #219 Magcargo
@@ -2872,6 +3546,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 30,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Fire, PokemonType::Rock],
+        egg_groups: [EggGroup::Amorphous, EggGroup::Amorphous],
+        evolves_from: 218,
         ability_ids: [126, 62, 78],
     },
     // #220 Swinub
@@ -2885,6 +3562,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 50,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ice, PokemonType::Ground],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [63, 109, 69],
     },
     // #221 Piloswine
@@ -2898,6 +3578,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 50,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ice, PokemonType::Ground],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 220,
         ability_ids: [63, 109, 69],
     },
     // #222 Corsola
@@ -2911,6 +3594,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 35,
         },
         gender_ratio: GenderRatio::F3M1,
+        types: [PokemonType::Water, PokemonType::Rock],
+        egg_groups: [EggGroup::Water1, EggGroup::Water3],
+        evolves_from: 0,
         ability_ids: [18, 89, 65],
     },
     // #223 Remoraid
@@ -2924,6 +3610,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 65,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Water1, EggGroup::Water2],
+        evolves_from: 0,
         ability_ids: [18, 13, 127],
     },
     // #224 Octillery
@@ -2937,6 +3626,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 45,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Water1, EggGroup::Water2],
+        evolves_from: 223,
         ability_ids: [128, 13, 127],
     },
     // #225 Delibird
@@ -2950,6 +3642,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 75,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ice, PokemonType::Flying],
+        egg_groups: [EggGroup::Water1, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [49, 18, 79],
     },
     // #226 Mantine
@@ -2963,6 +3658,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 70,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Flying],
+        egg_groups: [EggGroup::Water1, EggGroup::Water1],
+        evolves_from: 458,
         ability_ids: [48, 53, 94],
     },
     // #227 Skarmory
@@ -2976,6 +3674,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 70,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Steel, PokemonType::Flying],
+        egg_groups: [EggGroup::Flying, EggGroup::Flying],
+        evolves_from: 0,
         ability_ids: [14, 61, 78],
     },
     // #228 Houndour
@@ -2989,6 +3690,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 65,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Dark, PokemonType::Fire],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [68, 32, 20],
     },
     // #229 Houndoom
@@ -3002,6 +3706,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 95,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Dark, PokemonType::Fire],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 228,
         ability_ids: [68, 32, 20],
     },
     // #230 Kingdra
@@ -3015,6 +3722,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 85,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Dragon],
+        egg_groups: [EggGroup::Water1, EggGroup::Dragon],
+        evolves_from: 117,
         ability_ids: [48, 13, 40],
     },
     // #231 Phanpy
@@ -3028,6 +3738,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 40,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ground, PokemonType::Ground],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [44, 0, 23],
     },
     // #232 Donphan
@@ -3041,6 +3754,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 50,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ground, PokemonType::Ground],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 231,
         ability_ids: [61, 0, 23],
     },
     // #233 Porygon2
@@ -3054,6 +3770,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 60,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Mineral, EggGroup::Mineral],
+        evolves_from: 137,
         ability_ids: [105, 106, 67],
     },
     // #234 Stantler
@@ -3067,6 +3786,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 85,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [19, 34, 116],
     },
     // #235 Smeargle
@@ -3080,6 +3802,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 75,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [64, 45, 127],
     },
     // #236 Tyrogue
@@ -3093,6 +3818,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 35,
         },
         gender_ratio: GenderRatio::MaleOnly,
+        types: [PokemonType::Fighting, PokemonType::Fighting],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [17, 56, 49],
     },
     // #237 Hitmontop
@@ -3106,6 +3834,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 70,
         },
         gender_ratio: GenderRatio::MaleOnly,
+        types: [PokemonType::Fighting, PokemonType::Fighting],
+        egg_groups: [EggGroup::HumanLike, EggGroup::HumanLike],
+        evolves_from: 236,
         ability_ids: [19, 45, 56],
     },
     // #238 Smoochum
@@ -3119,6 +3850,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 65,
         },
         gender_ratio: GenderRatio::FemaleOnly,
+        types: [PokemonType::Ice, PokemonType::Psychic],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [63, 80, 70],
     },
     // #239 Elekid
@@ -3132,6 +3866,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 95,
         },
         gender_ratio: GenderRatio::F1M3,
+        types: [PokemonType::Electric, PokemonType::Electric],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [21, 0, 49],
     },
     // #240 Magby
@@ -3145,6 +3882,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 83,
         },
         gender_ratio: GenderRatio::F1M3,
+        types: [PokemonType::Fire, PokemonType::Fire],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [62, 0, 49],
     },
     // #241 Miltank
@@ -3158,6 +3898,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 100,
         },
         gender_ratio: GenderRatio::FemaleOnly,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [69, 93, 116],
     },
     // #242 Blissey
@@ -3171,6 +3914,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 55,
         },
         gender_ratio: GenderRatio::FemaleOnly,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Fairy, EggGroup::Fairy],
+        evolves_from: 113,
         ability_ids: [89, 90, 91],
     },
     // #243 Raikou
@@ -3184,6 +3930,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 115,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Electric, PokemonType::Electric],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [107, 0, 103],
     },
     // #244 Entei
@@ -3197,6 +3946,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 100,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Fire, PokemonType::Fire],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [107, 0, 32],
     },
     // #245 Suicune
@@ -3210,6 +3962,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 85,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [107, 0, 53],
     },
     // #246 Larvitar
@@ -3223,6 +3978,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 41,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Rock, PokemonType::Ground],
+        egg_groups: [EggGroup::Monster, EggGroup::Monster],
+        evolves_from: 0,
         ability_ids: [17, 0, 23],
     },
     // #247 Pupitar
@@ -3236,6 +3994,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 51,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Rock, PokemonType::Ground],
+        egg_groups: [EggGroup::Monster, EggGroup::Monster],
+        evolves_from: 246,
         ability_ids: [9, 0, 0],
     },
     // #248 Tyranitar
@@ -3249,6 +4010,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 61,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Rock, PokemonType::Dark],
+        egg_groups: [EggGroup::Monster, EggGroup::Monster],
+        evolves_from: 247,
         ability_ids: [129, 0, 20],
     },
     // #249 Lugia
@@ -3262,6 +4026,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 110,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Psychic, PokemonType::Flying],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [107, 0, 111],
     },
     // #250 Ho-Oh
@@ -3275,6 +4042,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 90,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Fire, PokemonType::Flying],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [107, 0, 65],
     },
     // #251 Celebi
@@ -3288,6 +4058,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 100,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Psychic, PokemonType::Grass],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [89, 0, 0],
     },
     // #252 Treecko
@@ -3301,6 +4074,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 70,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Grass, PokemonType::Grass],
+        egg_groups: [EggGroup::Monster, EggGroup::Dragon],
+        evolves_from: 0,
         ability_ids: [1, 0, 87],
     },
     // #253 Grovyle
@@ -3314,6 +4090,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 95,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Grass, PokemonType::Grass],
+        egg_groups: [EggGroup::Monster, EggGroup::Dragon],
+        evolves_from: 252,
         ability_ids: [1, 0, 87],
     },
     // #254 Sceptile
@@ -3327,6 +4106,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 120,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Grass, PokemonType::Grass],
+        egg_groups: [EggGroup::Monster, EggGroup::Dragon],
+        evolves_from: 253,
         ability_ids: [1, 0, 87],
     },
     // #255 Torchic
@@ -3340,6 +4122,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 45,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Fire, PokemonType::Fire],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [3, 0, 118],
     },
     // #256 Combusken
@@ -3353,6 +4138,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 55,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Fire, PokemonType::Fighting],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 255,
         ability_ids: [3, 0, 118],
     },
     // #257 Blaziken
@@ -3366,6 +4154,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 80,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Fire, PokemonType::Fighting],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 256,
         ability_ids: [3, 0, 118],
     },
     // #258 Mudkip
@@ -3379,6 +4170,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 40,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Monster, EggGroup::Water1],
+        evolves_from: 0,
         ability_ids: [5, 0, 40],
     },
     // #259 Marshtomp
@@ -3392,6 +4186,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 50,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Water, PokemonType::Ground],
+        egg_groups: [EggGroup::Monster, EggGroup::Water1],
+        evolves_from: 258,
         ability_ids: [5, 0, 40],
     },
     // #260 Swampert
@@ -3405,6 +4202,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 60,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Water, PokemonType::Ground],
+        egg_groups: [EggGroup::Monster, EggGroup::Water1],
+        evolves_from: 259,
         ability_ids: [5, 0, 40],
     },
     // #261 Poochyena
@@ -3418,6 +4218,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 35,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Dark, PokemonType::Dark],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [8, 104, 99],
     },
     // #262 Mightyena
@@ -3431,6 +4234,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 70,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Dark, PokemonType::Dark],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 261,
         ability_ids: [19, 104, 98],
     },
     // #263 Zigzagoon
@@ -3444,6 +4250,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 60,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [44, 57, 104],
     },
     // #264 Linoone
@@ -3457,6 +4266,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 100,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 263,
         ability_ids: [44, 57, 104],
     },
     // #265 Wurmple
@@ -3470,6 +4282,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 20,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Bug],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 0,
         ability_ids: [7, 0, 8],
     },
     // #266 Silcoon
@@ -3483,6 +4298,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 15,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Bug],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 265,
         ability_ids: [9, 0, 0],
     },
     // #267 Beautifly
@@ -3496,6 +4314,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 65,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Flying],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 266,
         ability_ids: [12, 0, 26],
     },
     // #268 Cascoon
@@ -3509,6 +4330,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 15,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Bug],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 265,
         ability_ids: [9, 0, 0],
     },
     // #269 Dustox
@@ -3522,6 +4346,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 65,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Poison],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 268,
         ability_ids: [7, 0, 10],
     },
     // #270 Lotad
@@ -3535,6 +4362,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 30,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Grass],
+        egg_groups: [EggGroup::Water1, EggGroup::Grass],
+        evolves_from: 0,
         ability_ids: [48, 6, 64],
     },
     // #271 Lombre
@@ -3548,6 +4378,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 50,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Grass],
+        egg_groups: [EggGroup::Water1, EggGroup::Grass],
+        evolves_from: 270,
         ability_ids: [48, 6, 64],
     },
     // #272 Ludicolo
@@ -3561,6 +4394,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 70,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Grass],
+        egg_groups: [EggGroup::Water1, EggGroup::Grass],
+        evolves_from: 271,
         ability_ids: [48, 6, 64],
     },
     // #273 Seedot
@@ -3574,6 +4410,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 30,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Grass, PokemonType::Grass],
+        egg_groups: [EggGroup::Field, EggGroup::Grass],
+        evolves_from: 0,
         ability_ids: [2, 68, 124],
     },
     // #274 Nuzleaf
@@ -3587,6 +4426,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 60,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Grass, PokemonType::Dark],
+        egg_groups: [EggGroup::Field, EggGroup::Grass],
+        evolves_from: 273,
         ability_ids: [2, 68, 124],
     },
     // #275 Shiftry
@@ -3600,6 +4442,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 80,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Grass, PokemonType::Dark],
+        egg_groups: [EggGroup::Field, EggGroup::Grass],
+        evolves_from: 274,
         ability_ids: [2, 68, 124],
     },
     // #276 Taillow
@@ -3613,6 +4458,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 85,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Flying],
+        egg_groups: [EggGroup::Flying, EggGroup::Flying],
+        evolves_from: 0,
         ability_ids: [17, 0, 93],
     },
     // #277 Swellow
@@ -3626,6 +4474,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 125,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Flying],
+        egg_groups: [EggGroup::Flying, EggGroup::Flying],
+        evolves_from: 276,
         ability_ids: [17, 0, 93],
     },
     // #278 Wingull
@@ -3639,6 +4490,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 85,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Flying],
+        egg_groups: [EggGroup::Water1, EggGroup::Flying],
+        evolves_from: 0,
         ability_ids: [14, 0, 6],
     },
     // #279 Pelipper
@@ -3652,6 +4506,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 65,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Flying],
+        egg_groups: [EggGroup::Water1, EggGroup::Flying],
+        evolves_from: 278,
         ability_ids: [14, 0, 6],
     },
     // #280 Ralts
@@ -3665,6 +4522,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 40,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Psychic, PokemonType::Psychic],
+        egg_groups: [EggGroup::Amorphous, EggGroup::Amorphous],
+        evolves_from: 0,
         ability_ids: [54, 105, 121],
     },
     // #281 Kirlia
@@ -3678,6 +4538,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 50,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Psychic, PokemonType::Psychic],
+        egg_groups: [EggGroup::Amorphous, EggGroup::Amorphous],
+        evolves_from: 280,
         ability_ids: [54, 105, 121],
     },
     // #282 Gardevoir
@@ -3691,6 +4554,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 80,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Psychic, PokemonType::Psychic],
+        egg_groups: [EggGroup::Amorphous, EggGroup::Amorphous],
+        evolves_from: 281,
         ability_ids: [54, 105, 121],
     },
     // #283 Surskit
@@ -3704,6 +4570,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 65,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Water],
+        egg_groups: [EggGroup::Water1, EggGroup::Bug],
+        evolves_from: 0,
         ability_ids: [48, 0, 6],
     },
     // #284 Masquerain
@@ -3717,6 +4586,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 60,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Flying],
+        egg_groups: [EggGroup::Water1, EggGroup::Bug],
+        evolves_from: 283,
         ability_ids: [19, 0, 20],
     },
     // #285 Shroomish
@@ -3730,6 +4602,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 35,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Grass, PokemonType::Grass],
+        egg_groups: [EggGroup::Fairy, EggGroup::Grass],
+        evolves_from: 0,
         ability_ids: [38, 130, 104],
     },
     // #286 Breloom
@@ -3743,6 +4618,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 70,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Grass, PokemonType::Fighting],
+        egg_groups: [EggGroup::Fairy, EggGroup::Grass],
+        evolves_from: 285,
         ability_ids: [38, 130, 45],
     },
     // #287 Slakoth
@@ -3756,6 +4634,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 30,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [131, 0, 0],
     },
     // #288 Vigoroth
@@ -3769,6 +4650,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 90,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 287,
         ability_ids: [49, 0, 0],
     },
     // #289 Slaking
@@ -3782,6 +4666,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 100,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 288,
         ability_ids: [131, 0, 0],
     },
     // #290 Nincada
@@ -3795,6 +4682,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 40,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Ground],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 0,
         ability_ids: [10, 0, 8],
     },
     // #291 Ninjask
@@ -3808,6 +4698,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 160,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Flying],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 290,
         ability_ids: [118, 0, 36],
     },
     // #292 Shedinja
@@ -3821,6 +4714,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 40,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Bug, PokemonType::Ghost],
+        egg_groups: [EggGroup::Mineral, EggGroup::Mineral],
+        evolves_from: 290,
         ability_ids: [132, 0, 0],
     },
     // #293 Whismur
@@ -3834,6 +4730,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 28,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Monster, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [82, 0, 99],
     },
     // #294 Loudred
@@ -3847,6 +4746,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 48,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Monster, EggGroup::Field],
+        evolves_from: 293,
         ability_ids: [82, 0, 93],
     },
     // #295 Exploud
@@ -3860,6 +4762,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 68,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Monster, EggGroup::Field],
+        evolves_from: 294,
         ability_ids: [82, 0, 93],
     },
     // #296 Makuhita
@@ -3873,6 +4778,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 25,
         },
         gender_ratio: GenderRatio::F1M3,
+        types: [PokemonType::Fighting, PokemonType::Fighting],
+        egg_groups: [EggGroup::HumanLike, EggGroup::HumanLike],
+        evolves_from: 0,
         ability_ids: [69, 17, 27],
     },
     // #297 Hariyama
@@ -3886,6 +4794,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 50,
         },
         gender_ratio: GenderRatio::F1M3,
+        types: [PokemonType::Fighting, PokemonType::Fighting],
+        egg_groups: [EggGroup::HumanLike, EggGroup::HumanLike],
+        evolves_from: 296,
         ability_ids: [69, 17, 27],
     },
     // #298 Azurill
@@ -3899,6 +4810,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 20,
         },
         gender_ratio: GenderRatio::F3M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [69, 115, 116],
     },
     // #299 Nosepass
@@ -3912,6 +4826,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 30,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Rock, PokemonType::Rock],
+        egg_groups: [EggGroup::Mineral, EggGroup::Mineral],
+        evolves_from: 0,
         ability_ids: [61, 66, 43],
     },
     // #300 Skitty
@@ -3925,6 +4842,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 50,
         },
         gender_ratio: GenderRatio::F3M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Field, EggGroup::Fairy],
+        evolves_from: 0,
         ability_ids: [28, 133, 41],
     },
     // #301 Delcatty
@@ -3938,6 +4858,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 70,
         },
         gender_ratio: GenderRatio::F3M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Field, EggGroup::Fairy],
+        evolves_from: 300,
         ability_ids: [28, 133, 41],
     },
     // #302 Sableye
@@ -3951,6 +4874,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 50,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Dark, PokemonType::Ghost],
+        egg_groups: [EggGroup::HumanLike, EggGroup::HumanLike],
+        evolves_from: 0,
         ability_ids: [14, 134, 119],
     },
     // #303 Mawile
@@ -3964,6 +4890,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 50,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Steel, PokemonType::Steel],
+        egg_groups: [EggGroup::Field, EggGroup::Fairy],
+        evolves_from: 0,
         ability_ids: [81, 19, 27],
     },
     // #304 Aron
@@ -3977,6 +4906,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 30,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Steel, PokemonType::Rock],
+        egg_groups: [EggGroup::Monster, EggGroup::Monster],
+        evolves_from: 0,
         ability_ids: [61, 60, 135],
     },
     // #305 Lairon
@@ -3990,6 +4922,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 40,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Steel, PokemonType::Rock],
+        egg_groups: [EggGroup::Monster, EggGroup::Monster],
+        evolves_from: 304,
         ability_ids: [61, 60, 135],
     },
     // #306 Aggron
@@ -4003,6 +4938,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 50,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Steel, PokemonType::Rock],
+        egg_groups: [EggGroup::Monster, EggGroup::Monster],
+        evolves_from: 305,
         ability_ids: [61, 60, 135],
     },
     // #307 Meditite
@@ -4016,6 +4954,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 60,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Fighting, PokemonType::Psychic],
+        egg_groups: [EggGroup::HumanLike, EggGroup::HumanLike],
+        evolves_from: 0,
         ability_ids: [136, 0, 121],
     },
     // #308 Medicham
@@ -4029,6 +4970,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 80,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Fighting, PokemonType::Psychic],
+        egg_groups: [EggGroup::HumanLike, EggGroup::HumanLike],
+        evolves_from: 307,
         ability_ids: [136, 0, 121],
     },
     // #309 Electrike
@@ -4042,6 +4986,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 65,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Electric, PokemonType::Electric],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [21, 22, 137],
     },
     // #310 Manectric
@@ -4055,6 +5002,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 105,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Electric, PokemonType::Electric],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 309,
         ability_ids: [21, 22, 137],
     },
     // #311 Plusle
@@ -4068,6 +5018,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 95,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Electric, PokemonType::Electric],
+        egg_groups: [EggGroup::Fairy, EggGroup::Fairy],
+        evolves_from: 0,
         ability_ids: [114, 0, 0],
     },
     // #312 Minun
@@ -4081,6 +5034,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 95,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Electric, PokemonType::Electric],
+        egg_groups: [EggGroup::Fairy, EggGroup::Fairy],
+        evolves_from: 0,
         ability_ids: [137, 0, 0],
     },
     // #313 Volbeat
@@ -4094,6 +5050,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 85,
         },
         gender_ratio: GenderRatio::MaleOnly,
+        types: [PokemonType::Bug, PokemonType::Bug],
+        egg_groups: [EggGroup::Bug, EggGroup::HumanLike],
+        evolves_from: 0,
         ability_ids: [95, 12, 119],
     },
     // #314 Illumise
@@ -4107,6 +5066,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 85,
         },
         gender_ratio: GenderRatio::FemaleOnly,
+        types: [PokemonType::Bug, PokemonType::Bug],
+        egg_groups: [EggGroup::Bug, EggGroup::HumanLike],
+        evolves_from: 0,
         ability_ids: [63, 11, 119],
     },
     // #315 Roselia
@@ -4120,6 +5082,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 65,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Grass, PokemonType::Poison],
+        egg_groups: [EggGroup::Fairy, EggGroup::Grass],
+        evolves_from: 406,
         ability_ids: [89, 25, 92],
     },
     // #316 Gulpin
@@ -4133,6 +5098,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 40,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Poison, PokemonType::Poison],
+        egg_groups: [EggGroup::Amorphous, EggGroup::Amorphous],
+        evolves_from: 0,
         ability_ids: [59, 72, 57],
     },
     // #317 Swalot
@@ -4146,6 +5114,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 55,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Poison, PokemonType::Poison],
+        egg_groups: [EggGroup::Amorphous, EggGroup::Amorphous],
+        evolves_from: 316,
         ability_ids: [59, 72, 57],
     },
     // #318 Carvanha
@@ -4159,6 +5130,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 65,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Dark],
+        egg_groups: [EggGroup::Water2, EggGroup::Water2],
+        evolves_from: 0,
         ability_ids: [138, 0, 118],
     },
     // #319 Sharpedo
@@ -4172,6 +5146,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 95,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Dark],
+        egg_groups: [EggGroup::Water2, EggGroup::Water2],
+        evolves_from: 318,
         ability_ids: [138, 0, 118],
     },
     // #320 Wailmer
@@ -4185,6 +5162,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 60,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Field, EggGroup::Water2],
+        evolves_from: 0,
         ability_ids: [94, 63, 107],
     },
     // #321 Wailord
@@ -4198,6 +5178,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 60,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Field, EggGroup::Water2],
+        evolves_from: 320,
         ability_ids: [94, 63, 107],
     },
     // #322 Numel
@@ -4211,6 +5194,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 35,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Fire, PokemonType::Ground],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [63, 139, 64],
     },
     // #323 Camerupt
@@ -4224,6 +5210,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 40,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Fire, PokemonType::Ground],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 322,
         ability_ids: [126, 140, 50],
     },
     // #324 Torkoal
@@ -4237,6 +5226,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 20,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Fire, PokemonType::Fire],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [141, 0, 74],
     },
     // #325 Spoink
@@ -4250,6 +5242,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 60,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Psychic, PokemonType::Psychic],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [69, 64, 57],
     },
     // #326 Grumpig
@@ -4263,6 +5258,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 80,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Psychic, PokemonType::Psychic],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 325,
         ability_ids: [69, 64, 57],
     },
     // #327 Spinda
@@ -4276,6 +5274,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 60,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Field, EggGroup::HumanLike],
+        evolves_from: 0,
         ability_ids: [64, 15, 123],
     },
     // #328 Trapinch
@@ -4289,6 +5290,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 10,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ground, PokemonType::Ground],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 0,
         ability_ids: [81, 42, 27],
     },
     // #329 Vibrava
@@ -4302,6 +5306,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 70,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ground, PokemonType::Dragon],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 328,
         ability_ids: [77, 0, 0],
     },
     // #330 Flygon
@@ -4315,6 +5322,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 100,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ground, PokemonType::Dragon],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 329,
         ability_ids: [77, 0, 0],
     },
     // #331 Cacnea
@@ -4328,6 +5338,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 35,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Grass, PokemonType::Grass],
+        egg_groups: [EggGroup::Grass, EggGroup::HumanLike],
+        evolves_from: 0,
         ability_ids: [23, 0, 53],
     },
     // #332 Cacturne
@@ -4341,6 +5354,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 55,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Grass, PokemonType::Dark],
+        egg_groups: [EggGroup::Grass, EggGroup::HumanLike],
+        evolves_from: 331,
         ability_ids: [23, 0, 53],
     },
     // #333 Swablu
@@ -4354,6 +5370,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 50,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Flying],
+        egg_groups: [EggGroup::Flying, EggGroup::Dragon],
+        evolves_from: 0,
         ability_ids: [89, 0, 47],
     },
     // #334 Altaria
@@ -4367,6 +5386,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 80,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Dragon, PokemonType::Flying],
+        egg_groups: [EggGroup::Flying, EggGroup::Dragon],
+        evolves_from: 333,
         ability_ids: [89, 0, 47],
     },
     // #335 Zangoose
@@ -4380,6 +5402,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 90,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [108, 0, 142],
     },
     // #336 Seviper
@@ -4393,6 +5418,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 65,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Poison, PokemonType::Poison],
+        egg_groups: [EggGroup::Field, EggGroup::Dragon],
+        evolves_from: 0,
         ability_ids: [9, 0, 36],
     },
     // #337 Lunatone
@@ -4406,6 +5434,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 70,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Rock, PokemonType::Psychic],
+        egg_groups: [EggGroup::Mineral, EggGroup::Mineral],
+        evolves_from: 0,
         ability_ids: [77, 0, 0],
     },
     // #338 Solrock
@@ -4419,6 +5450,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 70,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Rock, PokemonType::Psychic],
+        egg_groups: [EggGroup::Mineral, EggGroup::Mineral],
+        evolves_from: 0,
         ability_ids: [77, 0, 0],
     },
     // #339 Barboach
@@ -4432,6 +5466,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 60,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Ground],
+        egg_groups: [EggGroup::Water2, EggGroup::Water2],
+        evolves_from: 0,
         ability_ids: [63, 102, 70],
     },
     // #340 Whiscash
@@ -4445,6 +5482,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 60,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Ground],
+        egg_groups: [EggGroup::Water2, EggGroup::Water2],
+        evolves_from: 339,
         ability_ids: [63, 102, 70],
     },
     // #341 Corphish
@@ -4458,6 +5498,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 35,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Water1, EggGroup::Water3],
+        evolves_from: 0,
         ability_ids: [81, 74, 101],
     },
     // #342 Crawdaunt
@@ -4471,6 +5514,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 55,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Dark],
+        egg_groups: [EggGroup::Water1, EggGroup::Water3],
+        evolves_from: 341,
         ability_ids: [81, 74, 101],
     },
     // #343 Baltoy
@@ -4484,6 +5530,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 55,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Ground, PokemonType::Psychic],
+        egg_groups: [EggGroup::Mineral, EggGroup::Mineral],
+        evolves_from: 0,
         ability_ids: [77, 0, 0],
     },
     // #344 Claydol
@@ -4497,6 +5546,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 75,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Ground, PokemonType::Psychic],
+        egg_groups: [EggGroup::Mineral, EggGroup::Mineral],
+        evolves_from: 343,
         ability_ids: [77, 0, 0],
     },
     // #345 Lileep
@@ -4510,6 +5562,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 23,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Rock, PokemonType::Grass],
+        egg_groups: [EggGroup::Water3, EggGroup::Water3],
+        evolves_from: 0,
         ability_ids: [128, 0, 143],
     },
     // #346 Cradily
@@ -4523,6 +5578,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 43,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Rock, PokemonType::Grass],
+        egg_groups: [EggGroup::Water3, EggGroup::Water3],
+        evolves_from: 345,
         ability_ids: [128, 0, 143],
     },
     // #347 Anorith
@@ -4536,6 +5594,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 75,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Rock, PokemonType::Bug],
+        egg_groups: [EggGroup::Water3, EggGroup::Water3],
+        evolves_from: 0,
         ability_ids: [85, 0, 48],
     },
     // #348 Armaldo
@@ -4549,6 +5610,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 45,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Rock, PokemonType::Bug],
+        egg_groups: [EggGroup::Water3, EggGroup::Water3],
+        evolves_from: 347,
         ability_ids: [85, 0, 48],
     },
     // #349 Feebas
@@ -4562,6 +5626,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 80,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Water1, EggGroup::Dragon],
+        evolves_from: 0,
         ability_ids: [48, 0, 101],
     },
     // #350 Milotic
@@ -4575,6 +5642,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 81,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Water1, EggGroup::Dragon],
+        evolves_from: 349,
         ability_ids: [110, 0, 28],
     },
     // #351 Castform
@@ -4588,6 +5658,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 70,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Fairy, EggGroup::Amorphous],
+        evolves_from: 0,
         ability_ids: [144, 0, 0],
     },
     // #352 Kecleon
@@ -4601,6 +5674,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 40,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [145, 0, 0],
     },
     // #353 Shuppet
@@ -4614,6 +5690,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 45,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ghost, PokemonType::Ghost],
+        egg_groups: [EggGroup::Amorphous, EggGroup::Amorphous],
+        evolves_from: 0,
         ability_ids: [79, 34, 146],
     },
     // #354 Banette
@@ -4627,6 +5706,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 65,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ghost, PokemonType::Ghost],
+        egg_groups: [EggGroup::Amorphous, EggGroup::Amorphous],
+        evolves_from: 353,
         ability_ids: [79, 34, 146],
     },
     // #355 Duskull
@@ -4640,6 +5722,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 25,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ghost, PokemonType::Ghost],
+        egg_groups: [EggGroup::Amorphous, EggGroup::Amorphous],
+        evolves_from: 0,
         ability_ids: [77, 0, 0],
     },
     // #356 Dusclops
@@ -4653,6 +5738,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 25,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ghost, PokemonType::Ghost],
+        egg_groups: [EggGroup::Amorphous, EggGroup::Amorphous],
+        evolves_from: 355,
         ability_ids: [107, 0, 0],
     },
     // #357 Tropius
@@ -4666,6 +5754,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 51,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Grass, PokemonType::Flying],
+        egg_groups: [EggGroup::Monster, EggGroup::Grass],
+        evolves_from: 0,
         ability_ids: [2, 4, 84],
     },
     // #358 Chimecho
@@ -4679,6 +5770,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 65,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Psychic, PokemonType::Psychic],
+        egg_groups: [EggGroup::Amorphous, EggGroup::Amorphous],
+        evolves_from: 433,
         ability_ids: [77, 0, 0],
     },
     // #359 Absol
@@ -4692,6 +5786,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 75,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Dark, PokemonType::Dark],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [107, 112, 52],
     },
     // #360 Wynaut
@@ -4705,6 +5802,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 23,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Psychic, PokemonType::Psychic],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [120, 0, 121],
     },
     // #361 Snorunt
@@ -4718,6 +5818,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 50,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ice, PokemonType::Ice],
+        egg_groups: [EggGroup::Fairy, EggGroup::Mineral],
+        evolves_from: 0,
         ability_ids: [35, 71, 127],
     },
     // #362 Glalie
@@ -4731,6 +5834,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 80,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ice, PokemonType::Ice],
+        egg_groups: [EggGroup::Fairy, EggGroup::Mineral],
+        evolves_from: 361,
         ability_ids: [35, 71, 127],
     },
     // #363 Spheal
@@ -4744,6 +5850,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 25,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ice, PokemonType::Water],
+        egg_groups: [EggGroup::Water1, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [69, 71, 63],
     },
     // #364 Sealeo
@@ -4757,6 +5866,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 45,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ice, PokemonType::Water],
+        egg_groups: [EggGroup::Water1, EggGroup::Field],
+        evolves_from: 363,
         ability_ids: [69, 71, 63],
     },
     // #365 Walrein
@@ -4770,6 +5882,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 65,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ice, PokemonType::Water],
+        egg_groups: [EggGroup::Water1, EggGroup::Field],
+        evolves_from: 364,
         ability_ids: [69, 71, 63],
     },
     // #366 Clamperl
@@ -4783,6 +5898,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 32,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Water1, EggGroup::Water1],
+        evolves_from: 0,
         ability_ids: [74, 0, 99],
     },
     // #367 Huntail
@@ -4796,6 +5914,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 52,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Water1, EggGroup::Water1],
+        evolves_from: 366,
         ability_ids: [48, 0, 94],
     },
     // #368 Gorebyss
@@ -4809,6 +5930,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 52,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Water1, EggGroup::Water1],
+        evolves_from: 366,
         ability_ids: [48, 0, 70],
     },
     // #369 Relicanth
@@ -4822,6 +5946,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 55,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Water, PokemonType::Rock],
+        egg_groups: [EggGroup::Water1, EggGroup::Water2],
+        evolves_from: 0,
         ability_ids: [48, 60, 61],
     },
     // #370 Luvdisc
@@ -4835,6 +5962,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 97,
         },
         gender_ratio: GenderRatio::F3M1,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Water2, EggGroup::Water2],
+        evolves_from: 0,
         ability_ids: [48, 0, 70],
     },
     // #371 Bagon
@@ -4848,6 +5978,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 50,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Dragon, PokemonType::Dragon],
+        egg_groups: [EggGroup::Dragon, EggGroup::Dragon],
+        evolves_from: 0,
         ability_ids: [60, 0, 27],
     },
     // #372 Shelgon
@@ -4861,6 +5994,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 50,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Dragon, PokemonType::Dragon],
+        egg_groups: [EggGroup::Dragon, EggGroup::Dragon],
+        evolves_from: 371,
         ability_ids: [60, 0, 76],
     },
     // #373 Salamence
@@ -4874,6 +6010,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 100,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Dragon, PokemonType::Flying],
+        egg_groups: [EggGroup::Dragon, EggGroup::Dragon],
+        evolves_from: 372,
         ability_ids: [19, 0, 98],
     },
     // #374 Beldum
@@ -4887,6 +6026,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 30,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Steel, PokemonType::Psychic],
+        egg_groups: [EggGroup::Mineral, EggGroup::Mineral],
+        evolves_from: 0,
         ability_ids: [58, 0, 122],
     },
     // #375 Metang
@@ -4900,6 +6042,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 50,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Steel, PokemonType::Psychic],
+        egg_groups: [EggGroup::Mineral, EggGroup::Mineral],
+        evolves_from: 374,
         ability_ids: [58, 0, 122],
     },
     // #376 Metagross
@@ -4913,6 +6058,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 70,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Steel, PokemonType::Psychic],
+        egg_groups: [EggGroup::Mineral, EggGroup::Mineral],
+        evolves_from: 375,
         ability_ids: [58, 0, 122],
     },
     // #377 Regirock
@@ -4926,6 +6074,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 50,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Rock, PokemonType::Rock],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [58, 0, 61],
     },
     // #378 Regice
@@ -4939,6 +6090,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 50,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Ice, PokemonType::Ice],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [58, 0, 71],
     },
     // #379 Registeel
@@ -4952,6 +6106,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 50,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Steel, PokemonType::Steel],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [58, 0, 122],
     },
     // #380 Latias
@@ -4965,6 +6122,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 110,
         },
         gender_ratio: GenderRatio::FemaleOnly,
+        types: [PokemonType::Dragon, PokemonType::Psychic],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [77, 0, 0],
     },
     // #381 Latios
@@ -4978,6 +6138,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 110,
         },
         gender_ratio: GenderRatio::MaleOnly,
+        types: [PokemonType::Dragon, PokemonType::Psychic],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [77, 0, 0],
     },
     // #382 Kyogre
@@ -4991,6 +6154,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 90,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [117, 0, 0],
     },
     // #383 Groudon
@@ -5004,6 +6170,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 90,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Ground, PokemonType::Ground],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [33, 0, 0],
     },
     // #384 Rayquaza
@@ -5017,6 +6186,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 95,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Dragon, PokemonType::Flying],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [147, 0, 0],
     },
     // #385 Jirachi
@@ -5030,6 +6202,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 100,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Steel, PokemonType::Psychic],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [90, 0, 0],
     },
     // #386 Deoxys
@@ -5043,6 +6218,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 150,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Psychic, PokemonType::Psychic],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [107, 0, 0],
     },
     // #387 Turtwig
@@ -5056,6 +6234,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 31,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Grass, PokemonType::Grass],
+        egg_groups: [EggGroup::Monster, EggGroup::Grass],
+        evolves_from: 0,
         ability_ids: [1, 0, 74],
     },
     // #388 Grotle
@@ -5069,6 +6250,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 36,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Grass, PokemonType::Grass],
+        egg_groups: [EggGroup::Monster, EggGroup::Grass],
+        evolves_from: 387,
         ability_ids: [1, 0, 74],
     },
     // #389 Torterra
@@ -5082,6 +6266,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 56,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Grass, PokemonType::Ground],
+        egg_groups: [EggGroup::Monster, EggGroup::Grass],
+        evolves_from: 388,
         ability_ids: [1, 0, 74],
     },
     // #390 Chimchar
@@ -5095,6 +6282,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 61,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Fire, PokemonType::Fire],
+        egg_groups: [EggGroup::Field, EggGroup::HumanLike],
+        evolves_from: 0,
         ability_ids: [3, 0, 88],
     },
     // #391 Monferno
@@ -5108,6 +6298,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 81,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Fire, PokemonType::Fighting],
+        egg_groups: [EggGroup::Field, EggGroup::HumanLike],
+        evolves_from: 390,
         ability_ids: [3, 0, 88],
     },
     // #392 Infernape
@@ -5121,6 +6314,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 108,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Fire, PokemonType::Fighting],
+        egg_groups: [EggGroup::Field, EggGroup::HumanLike],
+        evolves_from: 391,
         ability_ids: [3, 0, 88],
     },
     // #393 Piplup
@@ -5134,6 +6330,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 40,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Water1, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [5, 0, 51],
     },
     // #394 Prinplup
@@ -5147,6 +6346,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 50,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Water1, EggGroup::Field],
+        evolves_from: 393,
         ability_ids: [5, 0, 51],
     },
     // #395 Empoleon
@@ -5160,6 +6362,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 60,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Water, PokemonType::Steel],
+        egg_groups: [EggGroup::Water1, EggGroup::Field],
+        evolves_from: 394,
         ability_ids: [5, 0, 51],
     },
     // #396 Starly
@@ -5173,6 +6378,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 60,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Flying],
+        egg_groups: [EggGroup::Flying, EggGroup::Flying],
+        evolves_from: 0,
         ability_ids: [14, 0, 0],
     },
     // #397 Staravia
@@ -5186,6 +6394,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 80,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Flying],
+        egg_groups: [EggGroup::Flying, EggGroup::Flying],
+        evolves_from: 396,
         ability_ids: [19, 0, 86],
     },
     // #398 Staraptor
@@ -5199,6 +6410,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 100,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Flying],
+        egg_groups: [EggGroup::Flying, EggGroup::Flying],
+        evolves_from: 397,
         ability_ids: [19, 0, 86],
     },
     // #399 Bidoof
@@ -5212,6 +6426,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 31,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Water1, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [139, 31, 127],
     },
     // #400 Bibarel
@@ -5225,6 +6442,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 71,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Water],
+        egg_groups: [EggGroup::Water1, EggGroup::Field],
+        evolves_from: 399,
         ability_ids: [139, 31, 127],
     },
     // #401 Kricketot
@@ -5238,6 +6458,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 25,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Bug],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 0,
         ability_ids: [9, 0, 8],
     },
     // #402 Kricketune
@@ -5251,6 +6474,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 65,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Bug],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 401,
         ability_ids: [12, 0, 45],
     },
     // #403 Shinx
@@ -5264,6 +6490,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 45,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Electric, PokemonType::Electric],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [26, 19, 17],
     },
     // #404 Luxio
@@ -5277,6 +6506,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 60,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Electric, PokemonType::Electric],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 403,
         ability_ids: [26, 19, 17],
     },
     // #405 Luxray
@@ -5290,6 +6522,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 70,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Electric, PokemonType::Electric],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 404,
         ability_ids: [26, 19, 17],
     },
     // #406 Budew
@@ -5303,6 +6538,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 55,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Grass, PokemonType::Poison],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [89, 25, 92],
     },
     // #407 Roserade
@@ -5316,6 +6554,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 90,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Grass, PokemonType::Poison],
+        egg_groups: [EggGroup::Fairy, EggGroup::Grass],
+        evolves_from: 315,
         ability_ids: [89, 25, 45],
     },
     // #408 Cranidos
@@ -5329,6 +6570,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 58,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Rock, PokemonType::Rock],
+        egg_groups: [EggGroup::Monster, EggGroup::Monster],
+        evolves_from: 0,
         ability_ids: [97, 0, 27],
     },
     // #409 Rampardos
@@ -5342,6 +6586,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 58,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Rock, PokemonType::Rock],
+        egg_groups: [EggGroup::Monster, EggGroup::Monster],
+        evolves_from: 408,
         ability_ids: [97, 0, 27],
     },
     // #410 Shieldon
@@ -5355,6 +6602,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 30,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Rock, PokemonType::Steel],
+        egg_groups: [EggGroup::Monster, EggGroup::Monster],
+        evolves_from: 0,
         ability_ids: [61, 0, 82],
     },
     // #411 Bastiodon
@@ -5368,6 +6618,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 30,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Rock, PokemonType::Steel],
+        egg_groups: [EggGroup::Monster, EggGroup::Monster],
+        evolves_from: 410,
         ability_ids: [61, 0, 82],
     },
     // #412 Burmy
@@ -5381,6 +6634,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 36,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Bug],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 0,
         ability_ids: [9, 0, 76],
     },
     // #413 Wormadam
@@ -5394,6 +6650,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 36,
         },
         gender_ratio: GenderRatio::FemaleOnly,
+        types: [PokemonType::Bug, PokemonType::Grass],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 412,
         ability_ids: [102, 0, 76],
     },
     // #414 Mothim
@@ -5407,6 +6666,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 66,
         },
         gender_ratio: GenderRatio::MaleOnly,
+        types: [PokemonType::Bug, PokemonType::Flying],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 412,
         ability_ids: [12, 0, 11],
     },
     // #415 Combee
@@ -5420,6 +6682,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 70,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Bug, PokemonType::Flying],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 0,
         ability_ids: [125, 0, 18],
     },
     // #416 Vespiquen
@@ -5433,6 +6698,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 40,
         },
         gender_ratio: GenderRatio::FemaleOnly,
+        types: [PokemonType::Bug, PokemonType::Flying],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 415,
         ability_ids: [107, 0, 20],
     },
     // #417 Pachirisu
@@ -5446,6 +6714,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 95,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Electric, PokemonType::Electric],
+        egg_groups: [EggGroup::Field, EggGroup::Fairy],
+        evolves_from: 0,
         ability_ids: [8, 44, 103],
     },
     // #418 Buizel
@@ -5459,6 +6730,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 85,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Water1, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [48, 0, 94],
     },
     // #419 Floatzel
@@ -5472,6 +6746,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 115,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Water1, EggGroup::Field],
+        evolves_from: 418,
         ability_ids: [48, 0, 94],
     },
     // #420 Cherubi
@@ -5485,6 +6762,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 35,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Grass, PokemonType::Grass],
+        egg_groups: [EggGroup::Fairy, EggGroup::Grass],
+        evolves_from: 0,
         ability_ids: [2, 0, 0],
     },
     // #421 Cherrim
@@ -5498,6 +6778,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 85,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Grass, PokemonType::Grass],
+        egg_groups: [EggGroup::Fairy, EggGroup::Grass],
+        evolves_from: 420,
         ability_ids: [148, 0, 0],
     },
     // #422 Shellos
@@ -5511,6 +6794,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 34,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Water1, EggGroup::Amorphous],
+        evolves_from: 0,
         ability_ids: [72, 143, 43],
     },
     // #423 Gastrodon
@@ -5524,6 +6810,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 39,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Ground],
+        egg_groups: [EggGroup::Water1, EggGroup::Amorphous],
+        evolves_from: 422,
         ability_ids: [72, 143, 43],
     },
     // #424 Ambipom
@@ -5537,6 +6826,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 115,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 190,
         ability_ids: [45, 44, 75],
     },
     // #425 Drifloon
@@ -5550,6 +6842,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 70,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ghost, PokemonType::Flying],
+        egg_groups: [EggGroup::Amorphous, EggGroup::Amorphous],
+        evolves_from: 0,
         ability_ids: [83, 87, 149],
     },
     // #426 Drifblim
@@ -5563,6 +6858,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 80,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ghost, PokemonType::Flying],
+        egg_groups: [EggGroup::Amorphous, EggGroup::Amorphous],
+        evolves_from: 425,
         ability_ids: [83, 87, 149],
     },
     // #427 Buneary
@@ -5576,6 +6874,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 85,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Field, EggGroup::HumanLike],
+        evolves_from: 0,
         ability_ids: [8, 150, 46],
     },
     // #428 Lopunny
@@ -5589,6 +6890,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 105,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Field, EggGroup::HumanLike],
+        evolves_from: 427,
         ability_ids: [28, 150, 46],
     },
     // #429 Mismagius
@@ -5602,6 +6906,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 105,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ghost, PokemonType::Ghost],
+        egg_groups: [EggGroup::Amorphous, EggGroup::Amorphous],
+        evolves_from: 200,
         ability_ids: [77, 0, 0],
     },
     // #430 Honchkrow
@@ -5615,6 +6922,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 71,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Dark, PokemonType::Flying],
+        egg_groups: [EggGroup::Flying, EggGroup::Flying],
+        evolves_from: 198,
         ability_ids: [79, 112, 98],
     },
     // #431 Glameow
@@ -5628,6 +6938,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 85,
         },
         gender_ratio: GenderRatio::F3M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [46, 64, 14],
     },
     // #432 Purugly
@@ -5641,6 +6954,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 112,
         },
         gender_ratio: GenderRatio::F3M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 431,
         ability_ids: [69, 64, 51],
     },
     // #433 Chingling
@@ -5654,6 +6970,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 45,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Psychic, PokemonType::Psychic],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [77, 0, 0],
     },
     // #434 Stunky
@@ -5667,6 +6986,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 74,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Poison, PokemonType::Dark],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [37, 83, 14],
     },
     // #435 Skuntank
@@ -5680,6 +7002,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 84,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Poison, PokemonType::Dark],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 434,
         ability_ids: [37, 83, 14],
     },
     // #436 Bronzor
@@ -5693,6 +7018,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 23,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Steel, PokemonType::Psychic],
+        egg_groups: [EggGroup::Mineral, EggGroup::Mineral],
+        evolves_from: 0,
         ability_ids: [77, 151, 135],
     },
     // #437 Bronzong
@@ -5706,6 +7034,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 33,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Steel, PokemonType::Psychic],
+        egg_groups: [EggGroup::Mineral, EggGroup::Mineral],
+        evolves_from: 436,
         ability_ids: [77, 151, 135],
     },
     // #438 Bonsly
@@ -5719,6 +7050,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 10,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Rock, PokemonType::Rock],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [61, 60, 99],
     },
     // #439 Mime Jr.
@@ -5732,6 +7066,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 60,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Psychic, PokemonType::Psychic],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [82, 96, 45],
     },
     // #440 Happiny
@@ -5745,6 +7082,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 30,
         },
         gender_ratio: GenderRatio::FemaleOnly,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [89, 90, 30],
     },
     // #441 Chatot
@@ -5758,6 +7098,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 91,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Flying],
+        egg_groups: [EggGroup::Flying, EggGroup::Flying],
+        evolves_from: 0,
         ability_ids: [14, 15, 16],
     },
     // #442 Spiritomb
@@ -5771,6 +7114,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 35,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ghost, PokemonType::Dark],
+        egg_groups: [EggGroup::Amorphous, EggGroup::Amorphous],
+        evolves_from: 0,
         ability_ids: [107, 0, 36],
     },
     // #443 Gible
@@ -5784,6 +7130,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 42,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Dragon, PokemonType::Ground],
+        egg_groups: [EggGroup::Monster, EggGroup::Dragon],
+        evolves_from: 0,
         ability_ids: [23, 0, 138],
     },
     // #444 Gabite
@@ -5797,6 +7146,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 82,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Dragon, PokemonType::Ground],
+        egg_groups: [EggGroup::Monster, EggGroup::Dragon],
+        evolves_from: 443,
         ability_ids: [23, 0, 138],
     },
     // #445 Garchomp
@@ -5810,6 +7162,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 102,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Dragon, PokemonType::Ground],
+        egg_groups: [EggGroup::Monster, EggGroup::Dragon],
+        evolves_from: 444,
         ability_ids: [23, 0, 138],
     },
     // #446 Munchlax
@@ -5823,6 +7178,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 5,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [44, 69, 57],
     },
     // #447 Riolu
@@ -5836,6 +7194,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 60,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Fighting, PokemonType::Fighting],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [56, 35, 119],
     },
     // #448 Lucario
@@ -5849,6 +7210,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 90,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Fighting, PokemonType::Steel],
+        egg_groups: [EggGroup::Field, EggGroup::HumanLike],
+        evolves_from: 447,
         ability_ids: [56, 35, 52],
     },
     // #449 Hippopotas
@@ -5862,6 +7226,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 32,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ground, PokemonType::Ground],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [129, 0, 43],
     },
     // #450 Hippowdon
@@ -5875,6 +7242,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 47,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ground, PokemonType::Ground],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 449,
         ability_ids: [129, 0, 43],
     },
     // #451 Skorupi
@@ -5888,6 +7258,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 65,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Poison, PokemonType::Bug],
+        egg_groups: [EggGroup::Bug, EggGroup::Water3],
+        evolves_from: 0,
         ability_ids: [85, 13, 14],
     },
     // #452 Drapion
@@ -5901,6 +7274,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 95,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Poison, PokemonType::Dark],
+        egg_groups: [EggGroup::Bug, EggGroup::Water3],
+        evolves_from: 451,
         ability_ids: [85, 13, 14],
     },
     // #453 Croagunk
@@ -5914,6 +7290,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 50,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Poison, PokemonType::Fighting],
+        egg_groups: [EggGroup::HumanLike, EggGroup::HumanLike],
+        evolves_from: 0,
         ability_ids: [102, 39, 73],
     },
     // #454 Toxicroak
@@ -5927,6 +7306,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 85,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Poison, PokemonType::Fighting],
+        egg_groups: [EggGroup::HumanLike, EggGroup::HumanLike],
+        evolves_from: 453,
         ability_ids: [102, 39, 73],
     },
     // #455 Carnivine
@@ -5940,6 +7322,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 46,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Grass, PokemonType::Grass],
+        egg_groups: [EggGroup::Grass, EggGroup::Grass],
+        evolves_from: 0,
         ability_ids: [77, 0, 0],
     },
     // #456 Finneon
@@ -5953,6 +7338,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 66,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Water2, EggGroup::Water2],
+        evolves_from: 0,
         ability_ids: [48, 143, 94],
     },
     // #457 Lumineon
@@ -5966,6 +7354,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 91,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Water2, EggGroup::Water2],
+        evolves_from: 456,
         ability_ids: [48, 143, 94],
     },
     // #458 Mantyke
@@ -5979,6 +7370,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 50,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Flying],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [48, 53, 94],
     },
     // #459 Snover
@@ -5992,6 +7386,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 40,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Grass, PokemonType::Ice],
+        egg_groups: [EggGroup::Monster, EggGroup::Grass],
+        evolves_from: 0,
         ability_ids: [152, 0, 82],
     },
     // #460 Abomasnow
@@ -6005,6 +7402,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 60,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Grass, PokemonType::Ice],
+        egg_groups: [EggGroup::Monster, EggGroup::Grass],
+        evolves_from: 459,
         ability_ids: [152, 0, 82],
     },
     // #461 Weavile
@@ -6018,6 +7418,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 125,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Dark, PokemonType::Ice],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 215,
         ability_ids: [107, 0, 124],
     },
     // #462 Magnezone
@@ -6031,6 +7434,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 60,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Electric, PokemonType::Steel],
+        egg_groups: [EggGroup::Mineral, EggGroup::Mineral],
+        evolves_from: 82,
         ability_ids: [66, 61, 67],
     },
     // #463 Lickilicky
@@ -6044,6 +7450,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 50,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Monster, EggGroup::Monster],
+        evolves_from: 108,
         ability_ids: [64, 63, 47],
     },
     // #464 Rhyperior
@@ -6057,6 +7466,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 40,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ground, PokemonType::Rock],
+        egg_groups: [EggGroup::Monster, EggGroup::Field],
+        evolves_from: 112,
         ability_ids: [22, 140, 86],
     },
     // #465 Tangrowth
@@ -6070,6 +7482,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 50,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Grass, PokemonType::Grass],
+        egg_groups: [EggGroup::Grass, EggGroup::Grass],
+        evolves_from: 114,
         ability_ids: [2, 92, 65],
     },
     // #466 Electivire
@@ -6083,6 +7498,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 95,
         },
         gender_ratio: GenderRatio::F1M3,
+        types: [PokemonType::Electric, PokemonType::Electric],
+        egg_groups: [EggGroup::HumanLike, EggGroup::HumanLike],
+        evolves_from: 125,
         ability_ids: [153, 0, 49],
     },
     // #467 Magmortar
@@ -6096,6 +7514,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 83,
         },
         gender_ratio: GenderRatio::F1M3,
+        types: [PokemonType::Fire, PokemonType::Fire],
+        egg_groups: [EggGroup::HumanLike, EggGroup::HumanLike],
+        evolves_from: 126,
         ability_ids: [62, 0, 49],
     },
     // #468 Togekiss
@@ -6109,6 +7530,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 80,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Normal, PokemonType::Flying],
+        egg_groups: [EggGroup::Flying, EggGroup::Fairy],
+        evolves_from: 176,
         ability_ids: [18, 90, 112],
     },
     // #469 Yanmega
@@ -6122,6 +7546,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 95,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Flying],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 193,
         ability_ids: [118, 11, 34],
     },
     // #470 Leafeon
@@ -6135,6 +7562,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 95,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Grass, PokemonType::Grass],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 133,
         ability_ids: [92, 0, 2],
     },
     // #471 Glaceon
@@ -6148,6 +7578,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 65,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Ice, PokemonType::Ice],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 133,
         ability_ids: [109, 0, 71],
     },
     // #472 Gliscor
@@ -6161,6 +7594,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 95,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ground, PokemonType::Flying],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 207,
         ability_ids: [81, 23, 130],
     },
     // #473 Mamoswine
@@ -6174,6 +7610,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 80,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ice, PokemonType::Ground],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 221,
         ability_ids: [63, 109, 69],
     },
     // #474 Porygon-Z
@@ -6187,6 +7626,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 90,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Mineral, EggGroup::Mineral],
+        evolves_from: 233,
         ability_ids: [101, 106, 67],
     },
     // #475 Gallade
@@ -6200,6 +7642,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 80,
         },
         gender_ratio: GenderRatio::MaleOnly,
+        types: [PokemonType::Psychic, PokemonType::Fighting],
+        egg_groups: [EggGroup::Amorphous, EggGroup::Amorphous],
+        evolves_from: 281,
         ability_ids: [56, 0, 52],
     },
     // #476 Probopass
@@ -6213,6 +7658,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 40,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Rock, PokemonType::Steel],
+        egg_groups: [EggGroup::Mineral, EggGroup::Mineral],
+        evolves_from: 299,
         ability_ids: [61, 66, 43],
     },
     // #477 Dusknoir
@@ -6226,6 +7674,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 45,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ghost, PokemonType::Ghost],
+        egg_groups: [EggGroup::Amorphous, EggGroup::Amorphous],
+        evolves_from: 356,
         ability_ids: [107, 0, 0],
     },
     // #478 Froslass
@@ -6239,6 +7690,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 110,
         },
         gender_ratio: GenderRatio::FemaleOnly,
+        types: [PokemonType::Ice, PokemonType::Ghost],
+        egg_groups: [EggGroup::Fairy, EggGroup::Mineral],
+        evolves_from: 361,
         ability_ids: [109, 0, 146],
     },
     // #479 Rotom
@@ -6252,6 +7706,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 91,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Electric, PokemonType::Ghost],
+        egg_groups: [EggGroup::Amorphous, EggGroup::Amorphous],
+        evolves_from: 0,
         ability_ids: [77, 0, 0],
     },
     // #480 Uxie
@@ -6265,6 +7722,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 95,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Psychic, PokemonType::Psychic],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [77, 0, 0],
     },
     // #481 Mesprit
@@ -6278,6 +7738,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 80,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Psychic, PokemonType::Psychic],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [77, 0, 0],
     },
     // #482 Azelf
@@ -6291,6 +7754,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 115,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Psychic, PokemonType::Psychic],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [77, 0, 0],
     },
     // #483 Dialga
@@ -6304,6 +7770,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 90,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Steel, PokemonType::Dragon],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [107, 0, 121],
     },
     // #484 Palkia
@@ -6317,6 +7786,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 100,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Water, PokemonType::Dragon],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [107, 0, 121],
     },
     // #485 Heatran
@@ -6330,6 +7802,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 77,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Fire, PokemonType::Steel],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [32, 0, 62],
     },
     // #486 Regigigas
@@ -6343,6 +7818,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 100,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [154, 0, 0],
     },
     // #487 Giratina
@@ -6356,6 +7834,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 90,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Ghost, PokemonType::Dragon],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [107, 0, 121],
     },
     // #488 Cresselia
@@ -6369,6 +7850,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 85,
         },
         gender_ratio: GenderRatio::FemaleOnly,
+        types: [PokemonType::Psychic, PokemonType::Psychic],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [77, 0, 0],
     },
     // #489 Phione
@@ -6382,6 +7866,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 80,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Water1, EggGroup::Fairy],
+        evolves_from: 0,
         ability_ids: [70, 0, 0],
     },
     // #490 Manaphy
@@ -6395,6 +7882,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 100,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Water1, EggGroup::Fairy],
+        evolves_from: 0,
         ability_ids: [70, 0, 0],
     },
     // #491 Darkrai
@@ -6408,6 +7898,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 125,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Dark, PokemonType::Dark],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [155, 0, 0],
     },
     // #492 Shaymin
@@ -6421,6 +7914,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 100,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Grass, PokemonType::Grass],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [89, 0, 0],
     },
     // #493 Arceus
@@ -6434,6 +7930,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 120,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [156, 0, 0],
     },
     // #494 Victini
@@ -6447,6 +7946,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 100,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Psychic, PokemonType::Fire],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [157, 0, 0],
     },
     // #495 Snivy
@@ -6460,6 +7962,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 63,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Grass, PokemonType::Grass],
+        egg_groups: [EggGroup::Field, EggGroup::Grass],
+        evolves_from: 0,
         ability_ids: [1, 0, 123],
     },
     // #496 Servine
@@ -6473,6 +7978,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 83,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Grass, PokemonType::Grass],
+        egg_groups: [EggGroup::Field, EggGroup::Grass],
+        evolves_from: 495,
         ability_ids: [1, 0, 123],
     },
     // #497 Serperior
@@ -6486,6 +7994,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 113,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Grass, PokemonType::Grass],
+        egg_groups: [EggGroup::Field, EggGroup::Grass],
+        evolves_from: 496,
         ability_ids: [1, 0, 123],
     },
     // #498 Tepig
@@ -6499,6 +8010,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 45,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Fire, PokemonType::Fire],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [3, 0, 69],
     },
     // #499 Pignite
@@ -6512,6 +8026,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 55,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Fire, PokemonType::Fighting],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 498,
         ability_ids: [3, 0, 69],
     },
     // #500 Emboar
@@ -6525,6 +8042,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 65,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Fire, PokemonType::Fighting],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 499,
         ability_ids: [3, 0, 86],
     },
     // #501 Oshawott
@@ -6538,6 +8058,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 45,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [5, 0, 74],
     },
     // #502 Dewott
@@ -6551,6 +8074,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 60,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 501,
         ability_ids: [5, 0, 74],
     },
     // #503 Samurott
@@ -6564,6 +8090,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 70,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 502,
         ability_ids: [5, 0, 74],
     },
     // #504 Patrat
@@ -6577,6 +8106,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 42,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [8, 14, 67],
     },
     // #505 Watchog
@@ -6590,6 +8122,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 77,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 504,
         ability_ids: [95, 14, 67],
     },
     // #506 Lillipup
@@ -6603,6 +8138,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 55,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [49, 44, 8],
     },
     // #507 Herdier
@@ -6616,6 +8154,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 60,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 506,
         ability_ids: [19, 24, 93],
     },
     // #508 Stoutland
@@ -6629,6 +8170,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 80,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 507,
         ability_ids: [19, 24, 93],
     },
     // #509 Purrloin
@@ -6642,6 +8186,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 66,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Dark, PokemonType::Dark],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [46, 87, 119],
     },
     // #510 Liepard
@@ -6655,6 +8202,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 106,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Dark, PokemonType::Dark],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 509,
         ability_ids: [46, 87, 119],
     },
     // #511 Pansage
@@ -6668,6 +8218,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 64,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Grass, PokemonType::Grass],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [57, 0, 1],
     },
     // #512 Simisage
@@ -6681,6 +8234,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 101,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Grass, PokemonType::Grass],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 511,
         ability_ids: [57, 0, 1],
     },
     // #513 Pansear
@@ -6694,6 +8250,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 64,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Fire, PokemonType::Fire],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [57, 0, 3],
     },
     // #514 Simisear
@@ -6707,6 +8266,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 101,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Fire, PokemonType::Fire],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 513,
         ability_ids: [57, 0, 3],
     },
     // #515 Panpour
@@ -6720,6 +8282,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 64,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [57, 0, 5],
     },
     // #516 Simipour
@@ -6733,6 +8298,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 101,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 515,
         ability_ids: [57, 0, 5],
     },
     // #517 Munna
@@ -6746,6 +8314,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 24,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Psychic, PokemonType::Psychic],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [80, 54, 121],
     },
     // #518 Musharna
@@ -6759,6 +8330,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 29,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Psychic, PokemonType::Psychic],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 517,
         ability_ids: [80, 54, 121],
     },
     // #519 Pidove
@@ -6772,6 +8346,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 43,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Flying],
+        egg_groups: [EggGroup::Flying, EggGroup::Flying],
+        evolves_from: 0,
         ability_ids: [16, 112, 26],
     },
     // #520 Tranquill
@@ -6785,6 +8362,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 65,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Flying],
+        egg_groups: [EggGroup::Flying, EggGroup::Flying],
+        evolves_from: 519,
         ability_ids: [16, 112, 26],
     },
     // #521 Unfezant
@@ -6798,6 +8378,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 93,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Flying],
+        egg_groups: [EggGroup::Flying, EggGroup::Flying],
+        evolves_from: 520,
         ability_ids: [16, 112, 26],
     },
     // #522 Blitzle
@@ -6811,6 +8394,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 76,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Electric, PokemonType::Electric],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [22, 153, 116],
     },
     // #523 Zebstrika
@@ -6824,6 +8410,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 116,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Electric, PokemonType::Electric],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 522,
         ability_ids: [22, 153, 116],
     },
     // #524 Roggenrola
@@ -6837,6 +8426,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 15,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Rock, PokemonType::Rock],
+        egg_groups: [EggGroup::Mineral, EggGroup::Mineral],
+        evolves_from: 0,
         ability_ids: [61, 0, 43],
     },
     // #525 Boldore
@@ -6850,6 +8442,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 20,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Rock, PokemonType::Rock],
+        egg_groups: [EggGroup::Mineral, EggGroup::Mineral],
+        evolves_from: 524,
         ability_ids: [61, 0, 43],
     },
     // #526 Gigalith
@@ -6863,6 +8458,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 25,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Rock, PokemonType::Rock],
+        egg_groups: [EggGroup::Mineral, EggGroup::Mineral],
+        evolves_from: 525,
         ability_ids: [61, 0, 43],
     },
     // #527 Woobat
@@ -6876,6 +8474,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 72,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Psychic, PokemonType::Flying],
+        egg_groups: [EggGroup::Field, EggGroup::Flying],
+        evolves_from: 0,
         ability_ids: [31, 150, 139],
     },
     // #528 Swoobat
@@ -6889,6 +8490,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 114,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Psychic, PokemonType::Flying],
+        egg_groups: [EggGroup::Field, EggGroup::Flying],
+        evolves_from: 527,
         ability_ids: [31, 150, 139],
     },
     // #529 Drilbur
@@ -6902,6 +8506,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 68,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ground, PokemonType::Ground],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [24, 43, 97],
     },
     // #530 Excadrill
@@ -6915,6 +8522,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 88,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ground, PokemonType::Steel],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 529,
         ability_ids: [24, 43, 97],
     },
     // #531 Audino
@@ -6928,6 +8538,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 50,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Fairy, EggGroup::Fairy],
+        evolves_from: 0,
         ability_ids: [91, 65, 150],
     },
     // #532 Timburr
@@ -6941,6 +8554,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 35,
         },
         gender_ratio: GenderRatio::F1M3,
+        types: [PokemonType::Fighting, PokemonType::Fighting],
+        egg_groups: [EggGroup::HumanLike, EggGroup::HumanLike],
+        evolves_from: 0,
         ability_ids: [17, 27, 88],
     },
     // #533 Gurdurr
@@ -6954,6 +8570,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 40,
         },
         gender_ratio: GenderRatio::F1M3,
+        types: [PokemonType::Fighting, PokemonType::Fighting],
+        egg_groups: [EggGroup::HumanLike, EggGroup::HumanLike],
+        evolves_from: 532,
         ability_ids: [17, 27, 88],
     },
     // #534 Conkeldurr
@@ -6967,6 +8586,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 45,
         },
         gender_ratio: GenderRatio::F1M3,
+        types: [PokemonType::Fighting, PokemonType::Fighting],
+        egg_groups: [EggGroup::HumanLike, EggGroup::HumanLike],
+        evolves_from: 533,
         ability_ids: [17, 27, 88],
     },
     // #535 Tympole
@@ -6980,6 +8602,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 64,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Water1, EggGroup::Water1],
+        evolves_from: 0,
         ability_ids: [48, 70, 53],
     },
     // #536 Palpitoad
@@ -6993,6 +8618,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 69,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Ground],
+        egg_groups: [EggGroup::Water1, EggGroup::Water1],
+        evolves_from: 535,
         ability_ids: [48, 70, 53],
     },
     // #537 Seismitoad
@@ -7006,6 +8634,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 74,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Ground],
+        egg_groups: [EggGroup::Water1, EggGroup::Water1],
+        evolves_from: 536,
         ability_ids: [48, 73, 53],
     },
     // #538 Throh
@@ -7019,6 +8650,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 45,
         },
         gender_ratio: GenderRatio::MaleOnly,
+        types: [PokemonType::Fighting, PokemonType::Fighting],
+        egg_groups: [EggGroup::HumanLike, EggGroup::HumanLike],
+        evolves_from: 0,
         ability_ids: [17, 35, 97],
     },
     // #539 Sawk
@@ -7032,6 +8666,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 85,
         },
         gender_ratio: GenderRatio::MaleOnly,
+        types: [PokemonType::Fighting, PokemonType::Fighting],
+        egg_groups: [EggGroup::HumanLike, EggGroup::HumanLike],
+        evolves_from: 0,
         ability_ids: [61, 35, 97],
     },
     // #540 Sewaddle
@@ -7045,6 +8682,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 42,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Grass],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 0,
         ability_ids: [12, 2, 76],
     },
     // #541 Swadloon
@@ -7058,6 +8698,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 42,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Grass],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 540,
         ability_ids: [92, 2, 76],
     },
     // #542 Leavanny
@@ -7071,6 +8714,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 92,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Grass],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 541,
         ability_ids: [12, 2, 76],
     },
     // #543 Venipede
@@ -7084,6 +8730,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 57,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Poison],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 0,
         ability_ids: [25, 12, 104],
     },
     // #544 Whirlipede
@@ -7097,6 +8746,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 47,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Poison],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 543,
         ability_ids: [25, 12, 104],
     },
     // #545 Scolipede
@@ -7110,6 +8762,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 112,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Poison],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 544,
         ability_ids: [25, 12, 104],
     },
     // #546 Cottonee
@@ -7123,6 +8778,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 66,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Grass, PokemonType::Grass],
+        egg_groups: [EggGroup::Fairy, EggGroup::Grass],
+        evolves_from: 0,
         ability_ids: [119, 36, 2],
     },
     // #547 Whimsicott
@@ -7136,6 +8794,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 116,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Grass, PokemonType::Grass],
+        egg_groups: [EggGroup::Fairy, EggGroup::Grass],
+        evolves_from: 546,
         ability_ids: [119, 36, 2],
     },
     // #548 Petilil
@@ -7149,6 +8810,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 30,
         },
         gender_ratio: GenderRatio::FemaleOnly,
+        types: [PokemonType::Grass, PokemonType::Grass],
+        egg_groups: [EggGroup::Grass, EggGroup::Grass],
+        evolves_from: 0,
         ability_ids: [2, 64, 92],
     },
     // #549 Lilligant
@@ -7162,6 +8826,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 90,
         },
         gender_ratio: GenderRatio::FemaleOnly,
+        types: [PokemonType::Grass, PokemonType::Grass],
+        egg_groups: [EggGroup::Grass, EggGroup::Grass],
+        evolves_from: 548,
         ability_ids: [2, 64, 92],
     },
     // #550 Basculin
@@ -7175,6 +8842,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 98,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Water2, EggGroup::Water2],
+        evolves_from: 0,
         ability_ids: [86, 101, 97],
     },
     // #551 Sandile
@@ -7188,6 +8858,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 65,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ground, PokemonType::Dark],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [19, 98, 50],
     },
     // #552 Krokorok
@@ -7201,6 +8874,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 74,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ground, PokemonType::Dark],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 551,
         ability_ids: [19, 98, 50],
     },
     // #553 Krookodile
@@ -7214,6 +8890,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 92,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ground, PokemonType::Dark],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 552,
         ability_ids: [19, 98, 50],
     },
     // #554 Darumaka
@@ -7227,6 +8906,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 50,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Fire, PokemonType::Fire],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [18, 0, 35],
     },
     // #555 Darmanitan
@@ -7240,6 +8922,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 95,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Fire, PokemonType::Fire],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 554,
         ability_ids: [27, 0, 158],
     },
     // #556 Maractus
@@ -7253,6 +8938,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 60,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Grass, PokemonType::Grass],
+        egg_groups: [EggGroup::Grass, EggGroup::Grass],
+        evolves_from: 0,
         ability_ids: [53, 2, 143],
     },
     // #557 Dwebble
@@ -7266,6 +8954,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 55,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Rock],
+        egg_groups: [EggGroup::Bug, EggGroup::Mineral],
+        evolves_from: 0,
         ability_ids: [61, 74, 78],
     },
     // #558 Crustle
@@ -7279,6 +8970,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 45,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Rock],
+        egg_groups: [EggGroup::Bug, EggGroup::Mineral],
+        evolves_from: 557,
         ability_ids: [61, 74, 78],
     },
     // #559 Scraggy
@@ -7292,6 +8986,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 48,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Dark, PokemonType::Fighting],
+        egg_groups: [EggGroup::Field, EggGroup::Dragon],
+        evolves_from: 0,
         ability_ids: [9, 98, 19],
     },
     // #560 Scrafty
@@ -7305,6 +9002,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 58,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Dark, PokemonType::Fighting],
+        egg_groups: [EggGroup::Field, EggGroup::Dragon],
+        evolves_from: 559,
         ability_ids: [9, 98, 19],
     },
     // #561 Sigilyph
@@ -7318,6 +9018,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 97,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Psychic, PokemonType::Flying],
+        egg_groups: [EggGroup::Flying, EggGroup::Flying],
+        evolves_from: 0,
         ability_ids: [41, 29, 11],
     },
     // #562 Yamask
@@ -7331,6 +9034,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 30,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ghost, PokemonType::Ghost],
+        egg_groups: [EggGroup::Mineral, EggGroup::Amorphous],
+        evolves_from: 0,
         ability_ids: [159, 0, 0],
     },
     // #563 Cofagrigus
@@ -7344,6 +9050,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 30,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ghost, PokemonType::Ghost],
+        egg_groups: [EggGroup::Mineral, EggGroup::Amorphous],
+        evolves_from: 562,
         ability_ids: [159, 0, 0],
     },
     // #564 Tirtouga
@@ -7357,6 +9066,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 22,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Water, PokemonType::Rock],
+        egg_groups: [EggGroup::Water1, EggGroup::Water3],
+        evolves_from: 0,
         ability_ids: [140, 61, 48],
     },
     // #565 Carracosta
@@ -7370,6 +9082,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 32,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Water, PokemonType::Rock],
+        egg_groups: [EggGroup::Water1, EggGroup::Water3],
+        evolves_from: 564,
         ability_ids: [140, 61, 48],
     },
     // #566 Archen
@@ -7383,6 +9098,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 70,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Rock, PokemonType::Flying],
+        egg_groups: [EggGroup::Flying, EggGroup::Water3],
+        evolves_from: 0,
         ability_ids: [160, 0, 0],
     },
     // #567 Archeops
@@ -7396,6 +9114,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 110,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Rock, PokemonType::Flying],
+        egg_groups: [EggGroup::Flying, EggGroup::Water3],
+        evolves_from: 566,
         ability_ids: [160, 0, 0],
     },
     // #568 Trubbish
@@ -7409,6 +9130,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 65,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Poison, PokemonType::Poison],
+        egg_groups: [EggGroup::Mineral, EggGroup::Mineral],
+        evolves_from: 0,
         ability_ids: [37, 72, 83],
     },
     // #569 Garbodor
@@ -7422,6 +9146,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 75,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Poison, PokemonType::Poison],
+        egg_groups: [EggGroup::Mineral, EggGroup::Mineral],
+        evolves_from: 568,
         ability_ids: [37, 78, 83],
     },
     // #570 Zorua
@@ -7435,6 +9162,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 65,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Dark, PokemonType::Dark],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [161, 0, 0],
     },
     // #571 Zoroark
@@ -7448,6 +9178,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 105,
         },
         gender_ratio: GenderRatio::F1M7,
+        types: [PokemonType::Dark, PokemonType::Dark],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 570,
         ability_ids: [161, 0, 0],
     },
     // #572 Minccino
@@ -7461,6 +9194,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 75,
         },
         gender_ratio: GenderRatio::F3M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [28, 45, 75],
     },
     // #573 Cinccino
@@ -7474,6 +9210,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 115,
         },
         gender_ratio: GenderRatio::F3M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 572,
         ability_ids: [28, 45, 75],
     },
     // #574 Gothita
@@ -7487,6 +9226,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 45,
         },
         gender_ratio: GenderRatio::F3M1,
+        types: [PokemonType::Psychic, PokemonType::Psychic],
+        egg_groups: [EggGroup::HumanLike, EggGroup::HumanLike],
+        evolves_from: 0,
         ability_ids: [34, 0, 120],
     },
     // #575 Gothorita
@@ -7500,6 +9242,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 55,
         },
         gender_ratio: GenderRatio::F3M1,
+        types: [PokemonType::Psychic, PokemonType::Psychic],
+        egg_groups: [EggGroup::HumanLike, EggGroup::HumanLike],
+        evolves_from: 574,
         ability_ids: [34, 0, 120],
     },
     // #576 Gothitelle
@@ -7513,6 +9258,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 65,
         },
         gender_ratio: GenderRatio::F3M1,
+        types: [PokemonType::Psychic, PokemonType::Psychic],
+        egg_groups: [EggGroup::HumanLike, EggGroup::HumanLike],
+        evolves_from: 575,
         ability_ids: [34, 0, 120],
     },
     // #577 Solosis
@@ -7526,6 +9274,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 20,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Psychic, PokemonType::Psychic],
+        egg_groups: [EggGroup::Amorphous, EggGroup::Amorphous],
+        evolves_from: 0,
         ability_ids: [76, 29, 65],
     },
     // #578 Duosion
@@ -7539,6 +9290,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 30,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Psychic, PokemonType::Psychic],
+        egg_groups: [EggGroup::Amorphous, EggGroup::Amorphous],
+        evolves_from: 577,
         ability_ids: [76, 29, 65],
     },
     // #579 Reuniclus
@@ -7552,6 +9306,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 30,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Psychic, PokemonType::Psychic],
+        egg_groups: [EggGroup::Amorphous, EggGroup::Amorphous],
+        evolves_from: 578,
         ability_ids: [76, 29, 65],
     },
     // #580 Ducklett
@@ -7565,6 +9322,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 55,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Flying],
+        egg_groups: [EggGroup::Water1, EggGroup::Flying],
+        evolves_from: 0,
         ability_ids: [14, 16, 70],
     },
     // #581 Swanna
@@ -7578,6 +9338,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 98,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Flying],
+        egg_groups: [EggGroup::Water1, EggGroup::Flying],
+        evolves_from: 580,
         ability_ids: [14, 16, 70],
     },
     // #582 Vanillite
@@ -7591,6 +9354,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 44,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ice, PokemonType::Ice],
+        egg_groups: [EggGroup::Mineral, EggGroup::Mineral],
+        evolves_from: 0,
         ability_ids: [71, 0, 78],
     },
     // #583 Vanillish
@@ -7604,6 +9370,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 59,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ice, PokemonType::Ice],
+        egg_groups: [EggGroup::Mineral, EggGroup::Mineral],
+        evolves_from: 582,
         ability_ids: [71, 0, 78],
     },
     // #584 Vanilluxe
@@ -7617,6 +9386,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 79,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ice, PokemonType::Ice],
+        egg_groups: [EggGroup::Mineral, EggGroup::Mineral],
+        evolves_from: 583,
         ability_ids: [71, 0, 78],
     },
     // #585 Deerling
@@ -7630,6 +9402,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 75,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Grass],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [2, 116, 90],
     },
     // #586 Sawsbuck
@@ -7643,6 +9418,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 95,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Grass],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 585,
         ability_ids: [2, 116, 90],
     },
     // #587 Emolga
@@ -7656,6 +9434,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 103,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Electric, PokemonType::Flying],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [21, 0, 153],
     },
     // #588 Karrablast
@@ -7669,6 +9450,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 60,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Bug],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 0,
         ability_ids: [12, 9, 55],
     },
     // #589 Escavalier
@@ -7682,6 +9466,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 20,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Steel],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 588,
         ability_ids: [12, 74, 76],
     },
     // #590 Foongus
@@ -7695,6 +9482,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 15,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Grass, PokemonType::Poison],
+        egg_groups: [EggGroup::Grass, EggGroup::Grass],
+        evolves_from: 0,
         ability_ids: [38, 0, 65],
     },
     // #591 Amoonguss
@@ -7708,6 +9498,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 30,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Grass, PokemonType::Poison],
+        egg_groups: [EggGroup::Grass, EggGroup::Grass],
+        evolves_from: 590,
         ability_ids: [38, 0, 65],
     },
     // #592 Frillish
@@ -7721,6 +9514,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 40,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Ghost],
+        egg_groups: [EggGroup::Amorphous, EggGroup::Amorphous],
+        evolves_from: 0,
         ability_ids: [53, 146, 40],
     },
     // #593 Jellicent
@@ -7734,6 +9530,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 60,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Ghost],
+        egg_groups: [EggGroup::Amorphous, EggGroup::Amorphous],
+        evolves_from: 592,
         ability_ids: [53, 146, 40],
     },
     // #594 Alomomola
@@ -7747,6 +9546,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 65,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Water, PokemonType::Water],
+        egg_groups: [EggGroup::Water1, EggGroup::Water2],
+        evolves_from: 0,
         ability_ids: [91, 70, 65],
     },
     // #595 Joltik
@@ -7760,6 +9562,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 65,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Electric],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 0,
         ability_ids: [10, 20, 12],
     },
     // #596 Galvantula
@@ -7773,6 +9578,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 108,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Electric],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 595,
         ability_ids: [10, 20, 12],
     },
     // #597 Ferroseed
@@ -7786,6 +9594,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 10,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Grass, PokemonType::Steel],
+        egg_groups: [EggGroup::Grass, EggGroup::Mineral],
+        evolves_from: 0,
         ability_ids: [162, 0, 0],
     },
     // #598 Ferrothorn
@@ -7799,6 +9610,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 20,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Grass, PokemonType::Steel],
+        egg_groups: [EggGroup::Grass, EggGroup::Mineral],
+        evolves_from: 597,
         ability_ids: [162, 0, 0],
     },
     // #599 Klink
@@ -7812,6 +9626,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 30,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Steel, PokemonType::Steel],
+        egg_groups: [EggGroup::Mineral, EggGroup::Mineral],
+        evolves_from: 0,
         ability_ids: [114, 137, 58],
     },
     // #600 Klang
@@ -7825,6 +9642,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 50,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Steel, PokemonType::Steel],
+        egg_groups: [EggGroup::Mineral, EggGroup::Mineral],
+        evolves_from: 599,
         ability_ids: [114, 137, 58],
     },
     // #601 Klinklang
@@ -7838,6 +9658,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 90,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Steel, PokemonType::Steel],
+        egg_groups: [EggGroup::Mineral, EggGroup::Mineral],
+        evolves_from: 600,
         ability_ids: [114, 137, 58],
     },
     // #602 Tynamo
@@ -7851,6 +9674,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 60,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Electric, PokemonType::Electric],
+        egg_groups: [EggGroup::Amorphous, EggGroup::Amorphous],
+        evolves_from: 0,
         ability_ids: [77, 0, 0],
     },
     // #603 Eelektrik
@@ -7864,6 +9690,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 40,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Electric, PokemonType::Electric],
+        egg_groups: [EggGroup::Amorphous, EggGroup::Amorphous],
+        evolves_from: 602,
         ability_ids: [77, 0, 0],
     },
     // #604 Eelektross
@@ -7877,6 +9706,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 50,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Electric, PokemonType::Electric],
+        egg_groups: [EggGroup::Amorphous, EggGroup::Amorphous],
+        evolves_from: 603,
         ability_ids: [77, 0, 0],
     },
     // #605 Elgyem
@@ -7890,6 +9722,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 30,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Psychic, PokemonType::Psychic],
+        egg_groups: [EggGroup::HumanLike, EggGroup::HumanLike],
+        evolves_from: 0,
         ability_ids: [121, 54, 67],
     },
     // #606 Beheeyem
@@ -7903,6 +9738,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 40,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Psychic, PokemonType::Psychic],
+        egg_groups: [EggGroup::HumanLike, EggGroup::HumanLike],
+        evolves_from: 605,
         ability_ids: [121, 54, 67],
     },
     // #607 Litwick
@@ -7916,6 +9754,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 20,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ghost, PokemonType::Fire],
+        egg_groups: [EggGroup::Amorphous, EggGroup::Amorphous],
+        evolves_from: 0,
         ability_ids: [32, 62, 120],
     },
     // #608 Lampent
@@ -7929,6 +9770,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 55,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ghost, PokemonType::Fire],
+        egg_groups: [EggGroup::Amorphous, EggGroup::Amorphous],
+        evolves_from: 607,
         ability_ids: [32, 62, 120],
     },
     // #609 Chandelure
@@ -7942,6 +9786,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 80,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ghost, PokemonType::Fire],
+        egg_groups: [EggGroup::Amorphous, EggGroup::Amorphous],
+        evolves_from: 608,
         ability_ids: [32, 62, 120],
     },
     // #610 Axew
@@ -7955,6 +9802,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 57,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Dragon, PokemonType::Dragon],
+        egg_groups: [EggGroup::Monster, EggGroup::Dragon],
+        evolves_from: 0,
         ability_ids: [26, 97, 20],
     },
     // #611 Fraxure
@@ -7968,6 +9818,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 67,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Dragon, PokemonType::Dragon],
+        egg_groups: [EggGroup::Monster, EggGroup::Dragon],
+        evolves_from: 610,
         ability_ids: [26, 97, 20],
     },
     // #612 Haxorus
@@ -7981,6 +9834,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 97,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Dragon, PokemonType::Dragon],
+        egg_groups: [EggGroup::Monster, EggGroup::Dragon],
+        evolves_from: 611,
         ability_ids: [26, 97, 20],
     },
     // #613 Cubchoo
@@ -7994,6 +9850,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 40,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ice, PokemonType::Ice],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [109, 0, 99],
     },
     // #614 Beartic
@@ -8007,6 +9866,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 50,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ice, PokemonType::Ice],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 613,
         ability_ids: [109, 0, 48],
     },
     // #615 Cryogonal
@@ -8020,6 +9882,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 105,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Ice, PokemonType::Ice],
+        egg_groups: [EggGroup::Mineral, EggGroup::Mineral],
+        evolves_from: 0,
         ability_ids: [77, 0, 0],
     },
     // #616 Shelmet
@@ -8033,6 +9898,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 25,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Bug],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 0,
         ability_ids: [70, 74, 76],
     },
     // #617 Accelgor
@@ -8046,6 +9914,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 145,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Bug],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 616,
         ability_ids: [70, 72, 87],
     },
     // #618 Stunfisk
@@ -8059,6 +9930,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 32,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Ground, PokemonType::Electric],
+        egg_groups: [EggGroup::Water1, EggGroup::Amorphous],
+        evolves_from: 0,
         ability_ids: [21, 46, 23],
     },
     // #619 Mienfoo
@@ -8072,6 +9946,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 65,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Fighting, PokemonType::Fighting],
+        egg_groups: [EggGroup::Field, EggGroup::HumanLike],
+        evolves_from: 0,
         ability_ids: [35, 65, 86],
     },
     // #620 Mienshao
@@ -8085,6 +9962,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 105,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Fighting, PokemonType::Fighting],
+        egg_groups: [EggGroup::Field, EggGroup::HumanLike],
+        evolves_from: 619,
         ability_ids: [35, 65, 86],
     },
     // #621 Druddigon
@@ -8098,6 +9978,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 48,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Dragon, PokemonType::Dragon],
+        egg_groups: [EggGroup::Monster, EggGroup::Dragon],
+        evolves_from: 0,
         ability_ids: [138, 27, 97],
     },
     // #622 Golett
@@ -8111,6 +9994,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 35,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Ground, PokemonType::Ghost],
+        egg_groups: [EggGroup::Mineral, EggGroup::Mineral],
+        evolves_from: 0,
         ability_ids: [88, 150, 55],
     },
     // #623 Golurk
@@ -8124,6 +10010,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 55,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Ground, PokemonType::Ghost],
+        egg_groups: [EggGroup::Mineral, EggGroup::Mineral],
+        evolves_from: 622,
         ability_ids: [88, 150, 55],
     },
     // #624 Pawniard
@@ -8137,6 +10026,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 60,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Dark, PokemonType::Steel],
+        egg_groups: [EggGroup::HumanLike, EggGroup::HumanLike],
+        evolves_from: 0,
         ability_ids: [51, 35, 107],
     },
     // #625 Bisharp
@@ -8150,6 +10042,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 70,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Dark, PokemonType::Steel],
+        egg_groups: [EggGroup::HumanLike, EggGroup::HumanLike],
+        evolves_from: 624,
         ability_ids: [51, 35, 107],
     },
     // #626 Bouffalant
@@ -8163,6 +10058,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 55,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Normal, PokemonType::Normal],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [86, 116, 82],
     },
     // #627 Rufflet
@@ -8176,6 +10074,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 60,
         },
         gender_ratio: GenderRatio::MaleOnly,
+        types: [PokemonType::Normal, PokemonType::Flying],
+        egg_groups: [EggGroup::Flying, EggGroup::Flying],
+        evolves_from: 0,
         ability_ids: [14, 27, 18],
     },
     // #628 Braviary
@@ -8189,6 +10090,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 80,
         },
         gender_ratio: GenderRatio::MaleOnly,
+        types: [PokemonType::Normal, PokemonType::Flying],
+        egg_groups: [EggGroup::Flying, EggGroup::Flying],
+        evolves_from: 627,
         ability_ids: [14, 27, 51],
     },
     // #629 Vullaby
@@ -8202,6 +10106,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 60,
         },
         gender_ratio: GenderRatio::FemaleOnly,
+        types: [PokemonType::Dark, PokemonType::Flying],
+        egg_groups: [EggGroup::Flying, EggGroup::Flying],
+        evolves_from: 0,
         ability_ids: [16, 76, 78],
     },
     // #630 Mandibuzz
@@ -8215,6 +10122,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 80,
         },
         gender_ratio: GenderRatio::FemaleOnly,
+        types: [PokemonType::Dark, PokemonType::Flying],
+        egg_groups: [EggGroup::Flying, EggGroup::Flying],
+        evolves_from: 629,
         ability_ids: [16, 76, 78],
     },
     // #631 Heatmor
@@ -8228,6 +10138,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 65,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Fire, PokemonType::Fire],
+        egg_groups: [EggGroup::Field, EggGroup::Field],
+        evolves_from: 0,
         ability_ids: [57, 32, 141],
     },
     // #632 Durant
@@ -8241,6 +10154,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 109,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Steel],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 0,
         ability_ids: [12, 18, 131],
     },
     // #633 Deino
@@ -8254,6 +10170,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 38,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Dark, PokemonType::Dragon],
+        egg_groups: [EggGroup::Dragon, EggGroup::Dragon],
+        evolves_from: 0,
         ability_ids: [18, 0, 0],
     },
     // #634 Zweilous
@@ -8267,6 +10186,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 58,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Dark, PokemonType::Dragon],
+        egg_groups: [EggGroup::Dragon, EggGroup::Dragon],
+        evolves_from: 633,
         ability_ids: [18, 0, 0],
     },
     // #635 Hydreigon
@@ -8280,6 +10202,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 98,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Dark, PokemonType::Dragon],
+        egg_groups: [EggGroup::Dragon, EggGroup::Dragon],
+        evolves_from: 634,
         ability_ids: [77, 0, 0],
     },
     // #636 Larvesta
@@ -8293,6 +10218,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 60,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Fire],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 0,
         ability_ids: [62, 0, 12],
     },
     // #637 Volcarona
@@ -8306,6 +10234,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 100,
         },
         gender_ratio: GenderRatio::F1M1,
+        types: [PokemonType::Bug, PokemonType::Fire],
+        egg_groups: [EggGroup::Bug, EggGroup::Bug],
+        evolves_from: 636,
         ability_ids: [62, 0, 12],
     },
     // #638 Cobalion
@@ -8319,6 +10250,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 108,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Steel, PokemonType::Fighting],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [52, 0, 0],
     },
     // #639 Terrakion
@@ -8332,6 +10266,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 108,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Rock, PokemonType::Fighting],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [52, 0, 0],
     },
     // #640 Virizion
@@ -8345,6 +10282,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 108,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Grass, PokemonType::Fighting],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [52, 0, 0],
     },
     // #641 Tornadus
@@ -8358,6 +10298,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 111,
         },
         gender_ratio: GenderRatio::MaleOnly,
+        types: [PokemonType::Flying, PokemonType::Flying],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [119, 0, 51],
     },
     // #642 Thundurus
@@ -8371,6 +10314,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 111,
         },
         gender_ratio: GenderRatio::MaleOnly,
+        types: [PokemonType::Electric, PokemonType::Flying],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [119, 0, 51],
     },
     // #643 Reshiram
@@ -8384,6 +10330,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 90,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Dragon, PokemonType::Fire],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [163, 0, 0],
     },
     // #644 Zekrom
@@ -8397,6 +10346,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 90,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Dragon, PokemonType::Electric],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [164, 0, 0],
     },
     // #645 Landorus
@@ -8410,6 +10362,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 101,
         },
         gender_ratio: GenderRatio::MaleOnly,
+        types: [PokemonType::Ground, PokemonType::Flying],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [43, 0, 27],
     },
     // #646 Kyurem
@@ -8423,6 +10378,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 95,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Dragon, PokemonType::Ice],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [107, 0, 0],
     },
     // #647 Keldeo
@@ -8436,6 +10394,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 108,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Water, PokemonType::Fighting],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [52, 0, 0],
     },
     // #648 Meloetta
@@ -8449,6 +10410,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 90,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Normal, PokemonType::Psychic],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [90, 0, 0],
     },
     // #649 Genesect
@@ -8462,6 +10426,9 @@ pub static SPECIES_TABLE: [SpeciesEntry; 649] = [
             speed: 99,
         },
         gender_ratio: GenderRatio::Genderless,
+        types: [PokemonType::Bug, PokemonType::Steel],
+        egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+        evolves_from: 0,
         ability_ids: [106, 0, 0],
     },
 ];
@@ -8511,6 +10478,45 @@ mod tests {
         assert_eq!(get_species_entry(488).base_stats.special_defense, 130);
     }
 
+    #[test]
+    fn test_get_species_entry_types() {
+        // 複合タイプ: レアコイル (でんき/はがね)
+        let magneton = get_species_entry(82);
+        assert_eq!(magneton.types, [PokemonType::Electric, PokemonType::Steel]);
+        assert!(magneton.has_type(PokemonType::Steel));
+        assert!(magneton.has_type(PokemonType::Electric));
+        // 単タイプ: ピカチュウ (でんき)
+        let pikachu = get_species_entry(25);
+        assert_eq!(
+            pikachu.types,
+            [PokemonType::Electric, PokemonType::Electric]
+        );
+        assert!(!pikachu.has_type(PokemonType::Steel));
+        // 第5世代: フェアリータイプ導入前 (マリル: みず)
+        assert_eq!(
+            get_species_entry(183).types,
+            [PokemonType::Water, PokemonType::Water]
+        );
+    }
+
+    #[test]
+    fn test_get_species_entry_breeding_data() {
+        // 複合グループ: フシギダネ (かいじゅう/しょくぶつ)
+        let bulbasaur = get_species_entry(1);
+        assert_eq!(bulbasaur.egg_groups, [EggGroup::Monster, EggGroup::Grass]);
+        assert!(bulbasaur.has_egg_group(EggGroup::Grass));
+        assert_eq!(bulbasaur.evolves_from, 0);
+        // 進化前: ピカチュウ ← ピチュー (タマゴみはっけん)
+        assert_eq!(get_species_entry(25).evolves_from, 172);
+        assert!(get_species_entry(172).has_egg_group(EggGroup::Undiscovered));
+        // 分岐進化: エーフィ ← イーブイ
+        assert_eq!(get_species_entry(196).evolves_from, 133);
+        assert_eq!(
+            get_species_entry(132).egg_groups,
+            [EggGroup::Ditto, EggGroup::Ditto]
+        );
+    }
+
     #[test]
     fn test_get_species_entry_out_of_range() {
         // 0 と 650 はインデックス0にフォールバック
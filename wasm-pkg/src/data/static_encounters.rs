@@ -0,0 +1,1004 @@
+//! 固定・ギフトエンカウントカタログ
+//!
+//! このファイルは自動生成されています。直接編集しないでください。
+//! 生成コマンド: node scripts/generate-encounter-data.js
+//!
+//! `StaticEvent` / `HiddenGrotto` は元データ未収集のため未収録。
+
+use crate::types::{
+    EncounterMethod, EncounterModifiers, EncounterSlotConfig, EncounterType, GenderRatio,
+    LeadAbilityEffect, PokemonGenerationParams, RomVersion, TrainerInfo,
+};
+
+use EncounterType::{Roamer, StaticFossil, StaticStarter, StaticSymbol};
+use GenderRatio::{F1M1, F1M7, FemaleOnly, Genderless, MaleOnly};
+use RomVersion::{Black, Black2, White, White2};
+
+/// 固定・ギフトエンカウントエントリ
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StaticEncounterEntry {
+    pub version: RomVersion,
+    pub encounter_type: EncounterType,
+    /// エンカウント ID (バージョン内で一意)
+    pub id: &'static str,
+    /// ポケモン種族 ID
+    pub species_id: u16,
+    /// レベル
+    pub level: u8,
+    /// 性別比
+    pub gender_ratio: GenderRatio,
+    /// 色違いロック
+    pub shiny_locked: bool,
+    /// 所持アイテムあり
+    pub has_held_item: bool,
+}
+
+impl StaticEncounterEntry {
+    /// 生成パラメータ用のスロット設定に変換
+    pub const fn to_slot_config(self) -> EncounterSlotConfig {
+        EncounterSlotConfig {
+            species_id: self.species_id,
+            level_min: self.level,
+            level_max: self.level,
+            gender_ratio: self.gender_ratio,
+            has_held_item: self.has_held_item,
+            shiny_locked: self.shiny_locked,
+        }
+    }
+
+    /// ポケモン生成パラメータに変換
+    ///
+    /// エンカウント方法は `Stationary` 固定。
+    pub fn to_generation_params(
+        self,
+        trainer: TrainerInfo,
+        lead_ability: LeadAbilityEffect,
+    ) -> PokemonGenerationParams {
+        PokemonGenerationParams {
+            trainer,
+            encounter_type: self.encounter_type,
+            encounter_method: EncounterMethod::Stationary,
+            lead_ability,
+            slots: vec![self.to_slot_config()],
+            seasonal_slots: None,
+            encounter_modifiers: EncounterModifiers::default(),
+        }
+    }
+}
+
+/// 固定・ギフトエンカウントカタログ (82件)
+pub static STATIC_ENCOUNTER_TABLE: [StaticEncounterEntry; 82] = [
+    StaticEncounterEntry {
+        version: Black,
+        encounter_type: StaticSymbol,
+        id: "reshiram-n-castle",
+        species_id: 643,
+        level: 50,
+        gender_ratio: Genderless,
+        shiny_locked: true,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: Black,
+        encounter_type: StaticSymbol,
+        id: "cobalion-guidance-chamber",
+        species_id: 638,
+        level: 42,
+        gender_ratio: Genderless,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: Black,
+        encounter_type: StaticSymbol,
+        id: "terrakion-trial-chamber",
+        species_id: 639,
+        level: 42,
+        gender_ratio: Genderless,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: Black,
+        encounter_type: StaticSymbol,
+        id: "virizion-rumination-field",
+        species_id: 640,
+        level: 42,
+        gender_ratio: Genderless,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: Black,
+        encounter_type: StaticSymbol,
+        id: "kyurem-giant-chasm",
+        species_id: 646,
+        level: 75,
+        gender_ratio: Genderless,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: Black,
+        encounter_type: StaticSymbol,
+        id: "landorus-abundant-shrine",
+        species_id: 645,
+        level: 70,
+        gender_ratio: MaleOnly,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: Black,
+        encounter_type: StaticSymbol,
+        id: "volcarona-relic-castle",
+        species_id: 637,
+        level: 70,
+        gender_ratio: F1M1,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: Black,
+        encounter_type: StaticSymbol,
+        id: "musharna-dreamyard",
+        species_id: 518,
+        level: 50,
+        gender_ratio: F1M1,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: Black,
+        encounter_type: StaticStarter,
+        id: "snivy-starter",
+        species_id: 495,
+        level: 5,
+        gender_ratio: F1M7,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: Black,
+        encounter_type: StaticStarter,
+        id: "tepig-starter",
+        species_id: 498,
+        level: 5,
+        gender_ratio: F1M7,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: Black,
+        encounter_type: StaticStarter,
+        id: "oshawott-starter",
+        species_id: 501,
+        level: 5,
+        gender_ratio: F1M7,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: Black,
+        encounter_type: StaticFossil,
+        id: "tirtouga-cover-fossil",
+        species_id: 564,
+        level: 25,
+        gender_ratio: F1M7,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: Black,
+        encounter_type: StaticFossil,
+        id: "archen-plume-fossil",
+        species_id: 566,
+        level: 25,
+        gender_ratio: F1M7,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: Black,
+        encounter_type: Roamer,
+        id: "tornadus-roamer",
+        species_id: 641,
+        level: 40,
+        gender_ratio: MaleOnly,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: White,
+        encounter_type: StaticSymbol,
+        id: "zekrom-n-castle",
+        species_id: 644,
+        level: 50,
+        gender_ratio: Genderless,
+        shiny_locked: true,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: White,
+        encounter_type: StaticSymbol,
+        id: "cobalion-guidance-chamber",
+        species_id: 638,
+        level: 42,
+        gender_ratio: Genderless,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: White,
+        encounter_type: StaticSymbol,
+        id: "terrakion-trial-chamber",
+        species_id: 639,
+        level: 42,
+        gender_ratio: Genderless,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: White,
+        encounter_type: StaticSymbol,
+        id: "virizion-rumination-field",
+        species_id: 640,
+        level: 42,
+        gender_ratio: Genderless,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: White,
+        encounter_type: StaticSymbol,
+        id: "kyurem-giant-chasm",
+        species_id: 646,
+        level: 75,
+        gender_ratio: Genderless,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: White,
+        encounter_type: StaticSymbol,
+        id: "landorus-abundant-shrine",
+        species_id: 645,
+        level: 70,
+        gender_ratio: MaleOnly,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: White,
+        encounter_type: StaticSymbol,
+        id: "volcarona-relic-castle",
+        species_id: 637,
+        level: 70,
+        gender_ratio: F1M1,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: White,
+        encounter_type: StaticSymbol,
+        id: "musharna-dreamyard",
+        species_id: 518,
+        level: 50,
+        gender_ratio: F1M1,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: White,
+        encounter_type: StaticStarter,
+        id: "snivy-starter",
+        species_id: 495,
+        level: 5,
+        gender_ratio: F1M7,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: White,
+        encounter_type: StaticStarter,
+        id: "tepig-starter",
+        species_id: 498,
+        level: 5,
+        gender_ratio: F1M7,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: White,
+        encounter_type: StaticStarter,
+        id: "oshawott-starter",
+        species_id: 501,
+        level: 5,
+        gender_ratio: F1M7,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: White,
+        encounter_type: StaticFossil,
+        id: "tirtouga-cover-fossil",
+        species_id: 564,
+        level: 25,
+        gender_ratio: F1M7,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: White,
+        encounter_type: StaticFossil,
+        id: "archen-plume-fossil",
+        species_id: 566,
+        level: 25,
+        gender_ratio: F1M7,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: White,
+        encounter_type: Roamer,
+        id: "thundurus-roamer",
+        species_id: 642,
+        level: 40,
+        gender_ratio: MaleOnly,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: Black2,
+        encounter_type: StaticSymbol,
+        id: "zekrom-dragonspiral-tower",
+        species_id: 644,
+        level: 70,
+        gender_ratio: Genderless,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: Black2,
+        encounter_type: StaticSymbol,
+        id: "cobalion-route-13",
+        species_id: 638,
+        level: 45,
+        gender_ratio: Genderless,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: Black2,
+        encounter_type: StaticSymbol,
+        id: "terrakion-route-22",
+        species_id: 639,
+        level: 45,
+        gender_ratio: Genderless,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: Black2,
+        encounter_type: StaticSymbol,
+        id: "virizion-route-11",
+        species_id: 640,
+        level: 45,
+        gender_ratio: Genderless,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: Black2,
+        encounter_type: StaticSymbol,
+        id: "kyurem-giant-chasm",
+        species_id: 646,
+        level: 70,
+        gender_ratio: Genderless,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: Black2,
+        encounter_type: StaticSymbol,
+        id: "latios-dreamyard",
+        species_id: 381,
+        level: 68,
+        gender_ratio: MaleOnly,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: Black2,
+        encounter_type: StaticSymbol,
+        id: "uxie-nacrene-city",
+        species_id: 480,
+        level: 65,
+        gender_ratio: Genderless,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: Black2,
+        encounter_type: StaticSymbol,
+        id: "mesprit-celestial-tower",
+        species_id: 481,
+        level: 65,
+        gender_ratio: Genderless,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: Black2,
+        encounter_type: StaticSymbol,
+        id: "azelf-route-23",
+        species_id: 482,
+        level: 65,
+        gender_ratio: Genderless,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: Black2,
+        encounter_type: StaticSymbol,
+        id: "heatran-reversal-mountain",
+        species_id: 485,
+        level: 68,
+        gender_ratio: F1M1,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: Black2,
+        encounter_type: StaticSymbol,
+        id: "cresselia-marvelous-bridge",
+        species_id: 488,
+        level: 68,
+        gender_ratio: FemaleOnly,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: Black2,
+        encounter_type: StaticSymbol,
+        id: "regirock-rock-peak-chamber",
+        species_id: 377,
+        level: 65,
+        gender_ratio: Genderless,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: Black2,
+        encounter_type: StaticSymbol,
+        id: "registeel-iron-chamber",
+        species_id: 379,
+        level: 65,
+        gender_ratio: Genderless,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: Black2,
+        encounter_type: StaticSymbol,
+        id: "regigigas-twist-mountain",
+        species_id: 486,
+        level: 68,
+        gender_ratio: Genderless,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: Black2,
+        encounter_type: StaticSymbol,
+        id: "volcarona-relic-castle",
+        species_id: 637,
+        level: 35,
+        gender_ratio: F1M1,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: Black2,
+        encounter_type: StaticStarter,
+        id: "snivy-starter",
+        species_id: 495,
+        level: 5,
+        gender_ratio: F1M7,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: Black2,
+        encounter_type: StaticStarter,
+        id: "tepig-starter",
+        species_id: 498,
+        level: 5,
+        gender_ratio: F1M7,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: Black2,
+        encounter_type: StaticStarter,
+        id: "oshawott-starter",
+        species_id: 501,
+        level: 5,
+        gender_ratio: F1M7,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: Black2,
+        encounter_type: StaticFossil,
+        id: "tirtouga-cover-fossil",
+        species_id: 564,
+        level: 25,
+        gender_ratio: F1M7,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: Black2,
+        encounter_type: StaticFossil,
+        id: "archen-plume-fossil",
+        species_id: 566,
+        level: 25,
+        gender_ratio: F1M7,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: Black2,
+        encounter_type: StaticFossil,
+        id: "kabuto-dome-fossil",
+        species_id: 140,
+        level: 25,
+        gender_ratio: F1M7,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: Black2,
+        encounter_type: StaticFossil,
+        id: "omanyte-helix-fossil",
+        species_id: 138,
+        level: 25,
+        gender_ratio: F1M7,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: Black2,
+        encounter_type: StaticFossil,
+        id: "aerodactyl-old-amber",
+        species_id: 142,
+        level: 25,
+        gender_ratio: F1M7,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: Black2,
+        encounter_type: StaticFossil,
+        id: "lileep-root-fossil",
+        species_id: 345,
+        level: 25,
+        gender_ratio: F1M7,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: Black2,
+        encounter_type: StaticFossil,
+        id: "anorith-claw-fossil",
+        species_id: 347,
+        level: 25,
+        gender_ratio: F1M7,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: Black2,
+        encounter_type: StaticFossil,
+        id: "cranidos-skull-fossil",
+        species_id: 408,
+        level: 25,
+        gender_ratio: F1M7,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: Black2,
+        encounter_type: StaticFossil,
+        id: "shieldon-armor-fossil",
+        species_id: 410,
+        level: 25,
+        gender_ratio: F1M7,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: White2,
+        encounter_type: StaticSymbol,
+        id: "reshiram-dragonspiral-tower",
+        species_id: 643,
+        level: 70,
+        gender_ratio: Genderless,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: White2,
+        encounter_type: StaticSymbol,
+        id: "cobalion-route-13",
+        species_id: 638,
+        level: 45,
+        gender_ratio: Genderless,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: White2,
+        encounter_type: StaticSymbol,
+        id: "terrakion-route-22",
+        species_id: 639,
+        level: 45,
+        gender_ratio: Genderless,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: White2,
+        encounter_type: StaticSymbol,
+        id: "virizion-route-11",
+        species_id: 640,
+        level: 45,
+        gender_ratio: Genderless,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: White2,
+        encounter_type: StaticSymbol,
+        id: "kyurem-giant-chasm",
+        species_id: 646,
+        level: 70,
+        gender_ratio: Genderless,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: White2,
+        encounter_type: StaticSymbol,
+        id: "latias-dreamyard",
+        species_id: 380,
+        level: 68,
+        gender_ratio: FemaleOnly,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: White2,
+        encounter_type: StaticSymbol,
+        id: "uxie-nacrene-city",
+        species_id: 480,
+        level: 65,
+        gender_ratio: Genderless,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: White2,
+        encounter_type: StaticSymbol,
+        id: "mesprit-celestial-tower",
+        species_id: 481,
+        level: 65,
+        gender_ratio: Genderless,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: White2,
+        encounter_type: StaticSymbol,
+        id: "azelf-route-23",
+        species_id: 482,
+        level: 65,
+        gender_ratio: Genderless,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: White2,
+        encounter_type: StaticSymbol,
+        id: "heatran-reversal-mountain",
+        species_id: 485,
+        level: 68,
+        gender_ratio: F1M1,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: White2,
+        encounter_type: StaticSymbol,
+        id: "cresselia-marvelous-bridge",
+        species_id: 488,
+        level: 68,
+        gender_ratio: FemaleOnly,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: White2,
+        encounter_type: StaticSymbol,
+        id: "regice-iceberg-chamber",
+        species_id: 378,
+        level: 65,
+        gender_ratio: Genderless,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: White2,
+        encounter_type: StaticSymbol,
+        id: "registeel-iron-chamber",
+        species_id: 379,
+        level: 65,
+        gender_ratio: Genderless,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: White2,
+        encounter_type: StaticSymbol,
+        id: "regigigas-twist-mountain",
+        species_id: 486,
+        level: 68,
+        gender_ratio: Genderless,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: White2,
+        encounter_type: StaticSymbol,
+        id: "volcarona-relic-castle",
+        species_id: 637,
+        level: 35,
+        gender_ratio: F1M1,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: White2,
+        encounter_type: StaticStarter,
+        id: "snivy-starter",
+        species_id: 495,
+        level: 5,
+        gender_ratio: F1M7,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: White2,
+        encounter_type: StaticStarter,
+        id: "tepig-starter",
+        species_id: 498,
+        level: 5,
+        gender_ratio: F1M7,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: White2,
+        encounter_type: StaticStarter,
+        id: "oshawott-starter",
+        species_id: 501,
+        level: 5,
+        gender_ratio: F1M7,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: White2,
+        encounter_type: StaticFossil,
+        id: "tirtouga-cover-fossil",
+        species_id: 564,
+        level: 25,
+        gender_ratio: F1M7,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: White2,
+        encounter_type: StaticFossil,
+        id: "archen-plume-fossil",
+        species_id: 566,
+        level: 25,
+        gender_ratio: F1M7,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: White2,
+        encounter_type: StaticFossil,
+        id: "kabuto-dome-fossil",
+        species_id: 140,
+        level: 25,
+        gender_ratio: F1M7,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: White2,
+        encounter_type: StaticFossil,
+        id: "omanyte-helix-fossil",
+        species_id: 138,
+        level: 25,
+        gender_ratio: F1M7,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: White2,
+        encounter_type: StaticFossil,
+        id: "aerodactyl-old-amber",
+        species_id: 142,
+        level: 25,
+        gender_ratio: F1M7,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: White2,
+        encounter_type: StaticFossil,
+        id: "lileep-root-fossil",
+        species_id: 345,
+        level: 25,
+        gender_ratio: F1M7,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: White2,
+        encounter_type: StaticFossil,
+        id: "anorith-claw-fossil",
+        species_id: 347,
+        level: 25,
+        gender_ratio: F1M7,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: White2,
+        encounter_type: StaticFossil,
+        id: "cranidos-skull-fossil",
+        species_id: 408,
+        level: 25,
+        gender_ratio: F1M7,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+    StaticEncounterEntry {
+        version: White2,
+        encounter_type: StaticFossil,
+        id: "shieldon-armor-fossil",
+        species_id: 410,
+        level: 25,
+        gender_ratio: F1M7,
+        shiny_locked: false,
+        has_held_item: false,
+    },
+];
+
+/// 固定・ギフトエンカウントエントリを取得
+///
+/// # Returns
+/// 該当するエントリ。存在しない場合は `None`。
+pub fn get_static_encounter(
+    version: RomVersion,
+    id: &str,
+) -> Option<&'static StaticEncounterEntry> {
+    STATIC_ENCOUNTER_TABLE
+        .iter()
+        .find(|e| e.version == version && e.id == id)
+}
+
+/// 指定バージョン・エンカウント種別の固定・ギフトエンカウント一覧を取得
+pub fn list_static_encounters(
+    version: RomVersion,
+    encounter_type: EncounterType,
+) -> Vec<&'static StaticEncounterEntry> {
+    STATIC_ENCOUNTER_TABLE
+        .iter()
+        .filter(|e| e.version == version && e.encounter_type == encounter_type)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::lcg::Lcg64;
+    use crate::data::get_species_entry;
+    use crate::generation::flows::generator::{is_static_encounter, validate_encounter_slots};
+    use crate::generation::flows::pokemon::generate_static_pokemon;
+    use crate::types::{
+        GameStartConfig, GenerationConfig, LcgSeed, MemoryLinkState, SavePresence, ShinyCharmState,
+        ShinyType, StartMode,
+    };
+
+    fn make_config(version: RomVersion) -> GenerationConfig {
+        GenerationConfig {
+            version,
+            game_start: GameStartConfig {
+                start_mode: StartMode::Continue,
+                save: SavePresence::WithSave,
+                memory_link: MemoryLinkState::Disabled,
+                shiny_charm: ShinyCharmState::NotObtained,
+            },
+            user_offset: 0,
+            max_advance: 100,
+        }
+    }
+
+    #[test]
+    fn test_catalog_entries_valid() {
+        for entry in &STATIC_ENCOUNTER_TABLE {
+            assert!(is_static_encounter(entry.encounter_type), "{}", entry.id);
+            assert!((1..=649).contains(&entry.species_id), "{}", entry.id);
+            assert!((1..=100).contains(&entry.level), "{}", entry.id);
+            assert_eq!(
+                entry.gender_ratio,
+                get_species_entry(entry.species_id).gender_ratio,
+                "{}",
+                entry.id
+            );
+            let params =
+                entry.to_generation_params(TrainerInfo { tid: 0, sid: 0 }, LeadAbilityEffect::None);
+            assert!(validate_encounter_slots(&params).is_ok(), "{}", entry.id);
+        }
+    }
+
+    #[test]
+    fn test_catalog_ids_unique_per_version() {
+        for (i, a) in STATIC_ENCOUNTER_TABLE.iter().enumerate() {
+            assert!(
+                STATIC_ENCOUNTER_TABLE[i + 1..]
+                    .iter()
+                    .all(|b| a.version != b.version || a.id != b.id),
+                "{}",
+                a.id
+            );
+        }
+    }
+
+    #[test]
+    fn test_generate_from_catalog_entry() {
+        let entry = get_static_encounter(Black, "reshiram-n-castle").unwrap();
+        assert_eq!(entry.encounter_type, StaticSymbol);
+        assert_eq!(entry.species_id, 643);
+        assert!(entry.shiny_locked);
+
+        let trainer = TrainerInfo {
+            tid: 12345,
+            sid: 54321,
+        };
+        let params = entry.to_generation_params(trainer, LeadAbilityEffect::None);
+        let config = make_config(Black);
+        for seed in [0x0000_0000_0000_0000, 0x1234_5678_9ABC_DEF0] {
+            let mut lcg = Lcg64::new(LcgSeed::new(seed));
+            let raw = generate_static_pokemon(&mut lcg, &params, &params.slots[0], &config);
+            assert_eq!(raw.species_id, 643);
+            assert_eq!(raw.level, 50);
+            // 色違いロック
+            assert_eq!(raw.shiny_type, ShinyType::None);
+        }
+    }
+
+    #[test]
+    fn test_list_static_encounters() {
+        assert!(!list_static_encounters(Black2, StaticStarter).is_empty());
+        // BW2 に徘徊ポケモンはいない
+        assert!(list_static_encounters(White2, Roamer).is_empty());
+        assert!(get_static_encounter(White, "reshiram-n-castle").is_none());
+    }
+}
@@ -81,6 +81,50 @@ pub fn calculate_stats(base: BaseStats, ivs: Ivs, nature: Nature, level: u8) ->
     }
 }
 
+/// 実ステータスから個体値の範囲を逆算
+///
+/// 各ステータスについて、観測値と一致する個体値 (0-31) の最小値・最大値を求める。
+/// ステータスは個体値に対して単調非減少のため、一致する個体値は連続した範囲となる。
+///
+/// # Arguments
+/// * `base` - 種族値
+/// * `stats` - 観測した実ステータス (`None` の箇所は `(0, 31)`)
+/// * `nature` - 性格
+/// * `level` - レベル
+///
+/// # Returns
+/// 順序 `[HP, Atk, Def, SpA, SpD, Spe]` の個体値範囲。
+/// いずれかのステータスに一致する個体値が存在しない場合は `None`。
+pub fn derive_iv_ranges(
+    base: BaseStats,
+    stats: &Stats,
+    nature: Nature,
+    level: u8,
+) -> Option<[(u8, u8); 6]> {
+    let observed = stats.to_array();
+    let mut ranges: [Option<(u8, u8)>; 6] = [None; 6];
+
+    for iv in 0..=31u8 {
+        let calculated = calculate_stats(base, Ivs::uniform(iv), nature, level);
+        for (range, (actual, expected)) in ranges
+            .iter_mut()
+            .zip(calculated.to_array().into_iter().zip(observed))
+        {
+            if expected.is_some() && actual == expected {
+                *range = Some(range.map_or((iv, iv), |(min, _)| (min, iv)));
+            }
+        }
+    }
+
+    let mut result = [(0, 31); 6];
+    for (i, expected) in observed.iter().enumerate() {
+        if expected.is_some() {
+            result[i] = ranges[i]?;
+        }
+    }
+    Some(result)
+}
+
 /// HP ステータスを計算
 ///
 /// HP = floor((2 * base + iv) * level / 100) + level + 10
@@ -229,4 +273,51 @@ mod tests {
         assert!(stats.attack.is_none());
         assert!(stats.special_defense.is_none());
     }
+
+    #[test]
+    fn test_derive_iv_ranges() {
+        // ピカチュウ Lv.50 がんばりや
+        let base = BaseStats {
+            hp: 35,
+            attack: 55,
+            defense: 40,
+            special_attack: 50,
+            special_defense: 50,
+            speed: 90,
+        };
+        let stats = Stats {
+            hp: Some(110),
+            attack: Some(75),
+            defense: None,
+            special_attack: Some(70),
+            special_defense: Some(70),
+            speed: Some(110),
+        };
+
+        let ranges = derive_iv_ranges(base, &stats, Nature::Hardy, 50).unwrap();
+
+        // Lv.50 では個体値 2 つごとに実数値が 1 変化する
+        assert_eq!(ranges[0], (30, 31));
+        assert_eq!(ranges[1], (30, 31));
+        // 不明なステータスは全範囲
+        assert_eq!(ranges[2], (0, 31));
+        assert_eq!(ranges[5], (30, 31));
+    }
+
+    #[test]
+    fn test_derive_iv_ranges_impossible() {
+        let base = BaseStats {
+            hp: 35,
+            attack: 55,
+            defense: 40,
+            special_attack: 50,
+            special_defense: 50,
+            speed: 90,
+        };
+        let stats = Stats {
+            hp: Some(999),
+            ..Stats::UNKNOWN
+        };
+        assert!(derive_iv_ranges(base, &stats, Nature::Hardy, 50).is_none());
+    }
 }
@@ -0,0 +1,202 @@
+//! `Timer0` / `VCount` 既定範囲テーブル
+//!
+//! ハードウェア群 × ROM バージョン × リージョンごとの既定 `Timer0` / `VCount` 範囲。
+//! `src/data/timer0-vcount-defaults.ts` と同一の内容を保持する。
+
+use crate::types::{DsConfig, Hardware, RomRegion, RomVersion, Timer0VCountRange};
+
+use HardwareGroup::{DsLite, Dsi};
+use RomRegion::{Fra, Ger, Ita, Jpn, Kor, Spa, Usa};
+use RomVersion::{Black, Black2, White, White2};
+
+/// ハードウェア群
+///
+/// DS / DS Lite と `DSi` / 3DS で `Timer0` / `VCount` 範囲が異なる。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum HardwareGroup {
+    DsLite,
+    Dsi,
+}
+
+impl HardwareGroup {
+    const fn from_hardware(hardware: Hardware) -> Self {
+        match hardware {
+            Hardware::Ds | Hardware::DsLite => Self::DsLite,
+            Hardware::Dsi | Hardware::N3ds => Self::Dsi,
+        }
+    }
+}
+
+/// 既定範囲テーブルのエントリ
+struct DefaultRangeEntry {
+    group: HardwareGroup,
+    version: RomVersion,
+    region: RomRegion,
+    ranges: &'static [Timer0VCountRange],
+}
+
+const fn entry(
+    group: HardwareGroup,
+    version: RomVersion,
+    region: RomRegion,
+    ranges: &'static [Timer0VCountRange],
+) -> DefaultRangeEntry {
+    DefaultRangeEntry {
+        group,
+        version,
+        region,
+        ranges,
+    }
+}
+
+const fn range(timer0_min: u16, timer0_max: u16, vcount: u8) -> Timer0VCountRange {
+    Timer0VCountRange::timer0_range(timer0_min, timer0_max, vcount)
+}
+
+/// 既定範囲テーブル
+///
+/// 未収集の組み合わせはエントリなし。
+static DEFAULT_RANGE_TABLE: [DefaultRangeEntry; 32] = [
+    // === DS/DSLite 群: 28 パターン全確定 ===
+
+    // -- Black (DS/DSLite) --
+    entry(DsLite, Black, Jpn, &[range(0x0C79, 0x0C7A, 0x60)]),
+    entry(DsLite, Black, Kor, &[range(0x0C84, 0x0C85, 0x60)]),
+    entry(DsLite, Black, Usa, &[range(0x0C7B, 0x0C7C, 0x60)]),
+    entry(DsLite, Black, Ger, &[range(0x0C77, 0x0C78, 0x5F)]),
+    entry(DsLite, Black, Fra, &[range(0x0C73, 0x0C74, 0x5F)]),
+    entry(DsLite, Black, Spa, &[range(0x0C86, 0x0C87, 0x60)]),
+    entry(DsLite, Black, Ita, &[range(0x0C6A, 0x0C6B, 0x5F)]),
+    // -- White (DS/DSLite) --
+    entry(DsLite, White, Jpn, &[range(0x0C67, 0x0C69, 0x5F)]),
+    entry(DsLite, White, Kor, &[range(0x0C7B, 0x0C7C, 0x60)]),
+    entry(DsLite, White, Usa, &[range(0x0C7E, 0x0C80, 0x60)]),
+    entry(DsLite, White, Ger, &[range(0x0C7A, 0x0C7B, 0x60)]),
+    entry(DsLite, White, Fra, &[range(0x0C6E, 0x0C6F, 0x5F)]),
+    entry(DsLite, White, Spa, &[range(0x0C70, 0x0C71, 0x5F)]),
+    entry(DsLite, White, Ita, &[range(0x0C7B, 0x0C7C, 0x60)]),
+    // -- Black2 (DS/DSLite) --
+    entry(DsLite, Black2, Jpn, &[range(0x1102, 0x1108, 0x82)]),
+    entry(DsLite, Black2, Kor, &[range(0x10EF, 0x10F4, 0x82)]),
+    entry(DsLite, Black2, Usa, &[range(0x1102, 0x1108, 0x82)]),
+    entry(
+        DsLite,
+        Black2,
+        Ger,
+        &[range(0x10E5, 0x10E8, 0x81), range(0x10E9, 0x10EC, 0x82)],
+    ),
+    entry(DsLite, Black2, Fra, &[range(0x10F4, 0x10F8, 0x82)]),
+    entry(DsLite, Black2, Spa, &[range(0x1101, 0x1106, 0x82)]),
+    entry(
+        DsLite,
+        Black2,
+        Ita,
+        &[range(0x1107, 0x1109, 0x82), range(0x1109, 0x110D, 0x83)],
+    ),
+    // -- White2 (DS/DSLite) --
+    entry(DsLite, White2, Jpn, &[range(0x10F5, 0x10FB, 0x82)]),
+    entry(DsLite, White2, Kor, &[range(0x10E4, 0x10E9, 0x81)]),
+    entry(DsLite, White2, Usa, &[range(0x10F2, 0x10F6, 0x82)]),
+    entry(DsLite, White2, Ger, &[range(0x10E5, 0x10ED, 0x82)]),
+    entry(DsLite, White2, Fra, &[range(0x10EC, 0x10F0, 0x82)]),
+    entry(DsLite, White2, Spa, &[range(0x10EF, 0x10F4, 0x82)]),
+    entry(DsLite, White2, Ita, &[range(0x10FF, 0x1104, 0x82)]),
+    // === DSi/3DS 群: JPN 4 パターン確定 ===
+    entry(Dsi, Black, Jpn, &[range(0x1237, 0x1238, 0x8C)]),
+    entry(Dsi, White, Jpn, &[range(0x1232, 0x1234, 0x8C)]),
+    entry(Dsi, Black2, Jpn, &[range(0x150D, 0x1514, 0xA2)]),
+    entry(Dsi, White2, Jpn, &[range(0x18AF, 0x18B3, 0xBE)]),
+    // DSi/3DS JPN 以外: 未収集 (エントリなし)
+];
+
+/// DS 設定に対応する既定の `Timer0` / `VCount` 範囲を取得
+///
+/// # Returns
+/// 既定範囲。未収集の組み合わせの場合は `None`。
+pub fn default_ranges(ds: &DsConfig) -> Option<Vec<Timer0VCountRange>> {
+    let group = HardwareGroup::from_hardware(ds.hardware);
+    DEFAULT_RANGE_TABLE
+        .iter()
+        .find(|e| e.group == group && e.version == ds.version && e.region == ds.region)
+        .map(|e| e.ranges.to_vec())
+}
+
+/// `auto_ranges` 指定に応じて `Timer0` / `VCount` 範囲を解決
+///
+/// - `auto_ranges = true`: `default_ranges` の結果 (`ranges` は無視)
+/// - `auto_ranges = false`: `ranges` をそのまま使用
+///
+/// # Errors
+/// `auto_ranges = true` で既定範囲が未収集の組み合わせの場合
+pub(crate) fn resolve_ranges(
+    ds: &DsConfig,
+    ranges: &[Timer0VCountRange],
+    auto_ranges: bool,
+) -> Result<Vec<Timer0VCountRange>, String> {
+    if !auto_ranges {
+        return Ok(ranges.to_vec());
+    }
+    default_ranges(ds).ok_or_else(|| {
+        format!(
+            "Default Timer0/VCount ranges are unknown for {:?}/{:?}/{:?}",
+            ds.hardware, ds.version, ds.region
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_ds(hardware: Hardware, version: RomVersion, region: RomRegion) -> DsConfig {
+        DsConfig {
+            mac: [0; 6],
+            hardware,
+            version,
+            region,
+        }
+    }
+
+    #[test]
+    fn test_ds_lite_group_complete() {
+        for hardware in [Hardware::Ds, Hardware::DsLite] {
+            for version in [Black, White, Black2, White2] {
+                for region in [Jpn, Kor, Usa, Ger, Fra, Spa, Ita] {
+                    assert!(default_ranges(&make_ds(hardware, version, region)).is_some());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_known_values() {
+        let ranges = default_ranges(&make_ds(Hardware::DsLite, Black, Jpn)).unwrap();
+        assert_eq!(
+            ranges,
+            vec![Timer0VCountRange::timer0_range(0x0C79, 0x0C7A, 0x60)]
+        );
+
+        // 複数 VCount を持つ組み合わせ
+        let ranges = default_ranges(&make_ds(Hardware::Ds, Black2, Ger)).unwrap();
+        assert_eq!(ranges.len(), 2);
+
+        // DSi と 3DS は同一群
+        assert_eq!(
+            default_ranges(&make_ds(Hardware::Dsi, White2, Jpn)),
+            default_ranges(&make_ds(Hardware::N3ds, White2, Jpn))
+        );
+    }
+
+    #[test]
+    fn test_unknown_combination() {
+        let ds = make_ds(Hardware::N3ds, Black, Usa);
+        assert!(default_ranges(&ds).is_none());
+        assert!(resolve_ranges(&ds, &[], true).is_err());
+        // auto_ranges 無効時は指定値をそのまま使用
+        let manual = [Timer0VCountRange::fixed(0x1200, 0x8C)];
+        assert_eq!(
+            resolve_ranges(&ds, &manual, false).unwrap(),
+            manual.to_vec()
+        );
+    }
+}
@@ -0,0 +1,440 @@
+//! 本体較正検索
+//!
+//! 起動日時・キー入力が既知の観測サンプルから、本体固有のパラメータ
+//! (`Timer0` / `VCount` / `GX_STAT` / frame) を特定する。
+//! 既定値が未調査の本体 (`DSi` / 3DS の一部リージョン等) をユーザー自身で較正するために使用する。
+
+use wasm_bindgen::prelude::*;
+
+use crate::core::offset::calculate_trainer_info;
+use crate::core::sha1::{
+    BaseMessageBuilder, GX_STAT, NazoValues, build_date_code, build_time_code,
+    calculate_pokemon_sha1, get_frame, get_nazo_values,
+};
+use crate::generation::algorithm::generate_rng_ivs_with_offset;
+use crate::types::keyinput::KeyCode;
+use crate::types::{
+    CalibrationOutcome, CalibrationResult, CalibrationSearchBatch, CalibrationSearchParams,
+    Hardware, RomVersion, StartMode, Timer0VCountRange,
+};
+
+/// SHA-1 計算用に前処理したサンプル
+struct PreparedSample {
+    date_code: u32,
+    time_code: u32,
+    key_code: KeyCode,
+    outcome: CalibrationOutcome,
+}
+
+/// 本体較正検索器
+///
+/// `Timer0` × `VCount` × `GX_STAT` × frame の組み合わせを順に走査する。
+#[wasm_bindgen]
+pub struct CalibrationSearcher {
+    /// MAC アドレス
+    mac: [u8; 6],
+    /// ナゾ値
+    nazo: NazoValues,
+    /// ROM バージョン (`TrainerInfo` 算出用)
+    version: RomVersion,
+    /// 観測サンプル
+    samples: Vec<PreparedSample>,
+    /// `Timer0` / `VCount` の走査範囲
+    range: Timer0VCountRange,
+    /// `GX_STAT` 候補
+    gx_stat_values: Vec<u32>,
+    /// frame の走査範囲 (min, max)
+    frame_range: (u8, u8),
+    // 進捗管理
+    total_count: u64,
+    processed_count: u64,
+}
+
+#[wasm_bindgen]
+impl CalibrationSearcher {
+    /// 新しい `CalibrationSearcher` を作成
+    ///
+    /// # Errors
+    ///
+    /// - `samples` が空の場合
+    /// - 走査範囲 (`range` / `frame_range`) が不正な場合
+    /// - `gx_stat_values` が空の場合
+    /// - `TrainerInfo` サンプルの起動設定が `NewGame` でない、または無効な場合
+    #[wasm_bindgen(constructor)]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn new(params: CalibrationSearchParams) -> Result<CalibrationSearcher, String> {
+        if params.samples.is_empty() {
+            return Err("samples is empty".into());
+        }
+        if params.range.timer0_min > params.range.timer0_max
+            || params.range.vcount_min > params.range.vcount_max
+        {
+            return Err("Invalid Timer0/VCount range".into());
+        }
+
+        let default_frame = get_frame(params.ds.hardware, params.ds.version);
+        let frame_range = params.frame_range.unwrap_or((default_frame, default_frame));
+        if frame_range.0 > frame_range.1 {
+            return Err("Invalid frame range".into());
+        }
+
+        let gx_stat_values = params.gx_stat_values.unwrap_or_else(|| vec![GX_STAT]);
+        if gx_stat_values.is_empty() {
+            return Err("gx_stat_values is empty".into());
+        }
+
+        let is_ds_or_lite = matches!(params.ds.hardware, Hardware::Ds | Hardware::DsLite);
+        let samples = params
+            .samples
+            .into_iter()
+            .map(|sample| {
+                if let CalibrationOutcome::TrainerInfo { game_start, .. } = &sample.outcome {
+                    if game_start.start_mode == StartMode::Continue {
+                        return Err("TrainerInfo sample requires NewGame mode".to_string());
+                    }
+                    game_start.validate(params.ds.version)?;
+                }
+
+                let datetime = sample.datetime;
+                Ok(PreparedSample {
+                    date_code: build_date_code(datetime.year, datetime.month, datetime.day),
+                    time_code: build_time_code(
+                        datetime.hour,
+                        datetime.minute,
+                        datetime.second,
+                        is_ds_or_lite,
+                    ),
+                    key_code: KeyCode::from_mask(sample.key_input.to_key_mask()),
+                    outcome: sample.outcome,
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        // 全域 (VCount 0..=0xFF 等) でも溢れないよう u64 に拡張してから件数を計算
+        let total_count = (u64::from(params.range.timer0_max) - u64::from(params.range.timer0_min)
+            + 1)
+            * (u64::from(params.range.vcount_max) - u64::from(params.range.vcount_min) + 1)
+            * gx_stat_values.len() as u64
+            * (u64::from(frame_range.1) - u64::from(frame_range.0) + 1);
+
+        Ok(Self {
+            mac: params.ds.mac,
+            nazo: get_nazo_values(&params.ds),
+            version: params.ds.version,
+            samples,
+            range: params.range,
+            gx_stat_values,
+            frame_range,
+            total_count,
+            processed_count: 0,
+        })
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn is_done(&self) -> bool {
+        self.processed_count >= self.total_count
+    }
+
+    #[wasm_bindgen(getter)]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn progress(&self) -> f64 {
+        if self.is_done() {
+            return 1.0;
+        }
+        self.processed_count as f64 / self.total_count as f64
+    }
+
+    /// 次のバッチを検索
+    pub fn next_batch(&mut self, chunk_count: u32) -> CalibrationSearchBatch {
+        let mut results = Vec::new();
+        let end = (self.processed_count + u64::from(chunk_count)).min(self.total_count);
+
+        for index in self.processed_count..end {
+            let candidate = self.candidate_at(index);
+            if self
+                .samples
+                .iter()
+                .all(|sample| self.matches(sample, candidate))
+            {
+                results.push(candidate);
+            }
+        }
+        self.processed_count = end;
+
+        CalibrationSearchBatch {
+            results,
+            processed_count: self.processed_count,
+            total_count: self.total_count,
+        }
+    }
+}
+
+impl CalibrationSearcher {
+    /// 通し番号からパラメータの組み合わせを復元
+    ///
+    /// 走査順は `Timer0` → `VCount` → `GX_STAT` → frame (frame が最内)。
+    #[allow(clippy::cast_possible_truncation)]
+    fn candidate_at(&self, index: u64) -> CalibrationResult {
+        let frame_count = u64::from(self.frame_range.1) - u64::from(self.frame_range.0) + 1;
+        let gx_count = self.gx_stat_values.len() as u64;
+        let vcount_count = u64::from(self.range.vcount_max) - u64::from(self.range.vcount_min) + 1;
+
+        let frame = index % frame_count;
+        let rest = index / frame_count;
+        let gx_index = rest % gx_count;
+        let rest = rest / gx_count;
+        let vcount = rest % vcount_count;
+        let timer0 = rest / vcount_count;
+
+        CalibrationResult {
+            timer0: self.range.timer0_min + timer0 as u16,
+            vcount: self.range.vcount_min + vcount as u8,
+            gx_stat: self.gx_stat_values[gx_index as usize],
+            frame: self.frame_range.0 + frame as u8,
+        }
+    }
+
+    /// サンプルがパラメータと矛盾しないか判定
+    fn matches(&self, sample: &PreparedSample, candidate: CalibrationResult) -> bool {
+        let mut builder = BaseMessageBuilder::with_gx_stat(
+            &self.nazo,
+            self.mac,
+            candidate.vcount,
+            candidate.timer0,
+            sample.key_code,
+            candidate.gx_stat,
+            candidate.frame,
+        );
+        builder.set_datetime(sample.date_code, sample.time_code);
+        let lcg_seed = calculate_pokemon_sha1(builder.message()).to_lcg_seed();
+
+        match &sample.outcome {
+            CalibrationOutcome::Iv {
+                ivs,
+                mt_offset,
+                is_roamer,
+            } => {
+                generate_rng_ivs_with_offset(lcg_seed.derive_mt_seed(), *mt_offset, *is_roamer)
+                    == *ivs
+            }
+            CalibrationOutcome::TrainerInfo {
+                trainer,
+                game_start,
+            } => calculate_trainer_info(lcg_seed, self.version, *game_start)
+                .is_ok_and(|actual| actual.tid == trainer.tid && actual.sid == trainer.sid),
+        }
+    }
+}
+
+// ===== タスク生成関数 =====
+
+/// タスク生成関数
+///
+/// `Timer0` 範囲を Worker 数で分割してタスクを生成する。
+///
+/// # Arguments
+/// - `params`: 較正検索パラメータ
+/// - `worker_count`: Worker 数
+#[wasm_bindgen]
+#[allow(clippy::needless_pass_by_value)]
+#[allow(clippy::cast_possible_truncation)]
+pub fn generate_calibration_search_tasks(
+    params: CalibrationSearchParams,
+    worker_count: u32,
+) -> Vec<CalibrationSearchParams> {
+    let timer0_min = u32::from(params.range.timer0_min);
+    let timer0_max = u32::from(params.range.timer0_max);
+    if timer0_min > timer0_max {
+        return vec![params];
+    }
+
+    let timer0_count = timer0_max - timer0_min + 1;
+    let chunk = timer0_count.div_ceil(worker_count.clamp(1, timer0_count));
+
+    (timer0_min..=timer0_max)
+        .step_by(chunk as usize)
+        .map(|start| {
+            let end = (start + chunk - 1).min(timer0_max);
+            CalibrationSearchParams {
+                range: Timer0VCountRange {
+                    timer0_min: start as u16,
+                    timer0_max: end as u16,
+                    ..params.range
+                },
+                ..params.clone()
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{
+        CalibrationSample, Datetime, DsConfig, GameStartConfig, KeyInput, LcgSeed, MemoryLinkState,
+        RomRegion, SavePresence, ShinyCharmState,
+    };
+
+    /// 既知の条件
+    ///
+    /// - ROM: Black (JPN)
+    /// - Hardware: DS Lite
+    /// - MAC: `8C:56:C5:86:15:28`
+    /// - Timer0: `0x0C79`
+    /// - `VCount`: `0x60`
+    /// - 2010/09/18 18:13:11 で LCG Seed `0x768360781D1CE6DD`
+    const KNOWN_SEED: LcgSeed = LcgSeed::new(0x7683_6078_1D1C_E6DD);
+
+    fn make_ds() -> DsConfig {
+        DsConfig {
+            mac: [0x8C, 0x56, 0xC5, 0x86, 0x15, 0x28],
+            hardware: Hardware::DsLite,
+            version: RomVersion::Black,
+            region: RomRegion::Jpn,
+        }
+    }
+
+    fn make_iv_sample() -> CalibrationSample {
+        CalibrationSample {
+            datetime: Datetime::new(2010, 9, 18, 18, 13, 11),
+            key_input: KeyInput::new(),
+            outcome: CalibrationOutcome::Iv {
+                ivs: generate_rng_ivs_with_offset(KNOWN_SEED.derive_mt_seed(), 7, false),
+                mt_offset: 7,
+                is_roamer: false,
+            },
+        }
+    }
+
+    fn make_params(samples: Vec<CalibrationSample>) -> CalibrationSearchParams {
+        CalibrationSearchParams {
+            ds: make_ds(),
+            samples,
+            range: Timer0VCountRange {
+                timer0_min: 0x0C70,
+                timer0_max: 0x0C7F,
+                vcount_min: 0x5F,
+                vcount_max: 0x61,
+            },
+            gx_stat_values: None,
+            frame_range: Some((5, 7)),
+        }
+    }
+
+    fn run(params: CalibrationSearchParams) -> Vec<CalibrationResult> {
+        let mut searcher = CalibrationSearcher::new(params).unwrap();
+        let mut results = Vec::new();
+        while !searcher.is_done() {
+            results.extend(searcher.next_batch(50).results);
+        }
+        results
+    }
+
+    #[test]
+    fn test_searcher_empty_samples() {
+        assert!(CalibrationSearcher::new(make_params(vec![])).is_err());
+    }
+
+    #[test]
+    fn test_searcher_finds_known_parameters() {
+        let searcher = CalibrationSearcher::new(make_params(vec![make_iv_sample()])).unwrap();
+        // 16 (Timer0) * 3 (VCount) * 1 (GX_STAT) * 3 (frame)
+        assert_eq!(searcher.total_count, 144);
+
+        let results = run(make_params(vec![make_iv_sample()]));
+        assert_eq!(
+            results,
+            vec![CalibrationResult {
+                timer0: 0x0C79,
+                vcount: 0x60,
+                gx_stat: GX_STAT,
+                frame: 6,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_searcher_full_vcount_and_frame_range() {
+        let mut params = make_params(vec![make_iv_sample()]);
+        params.range = Timer0VCountRange {
+            timer0_min: 0x0C79,
+            timer0_max: 0x0C7A,
+            vcount_min: 0x00,
+            vcount_max: 0xFF,
+        };
+        params.frame_range = Some((0, 255));
+
+        let searcher = CalibrationSearcher::new(params).unwrap();
+        // 2 (Timer0) * 256 (VCount) * 1 (GX_STAT) * 256 (frame)
+        assert_eq!(searcher.total_count, 131_072);
+        assert_eq!(
+            searcher.candidate_at(searcher.total_count - 1),
+            CalibrationResult {
+                timer0: 0x0C7A,
+                vcount: 0xFF,
+                gx_stat: GX_STAT,
+                frame: 255,
+            }
+        );
+    }
+
+    #[test]
+    fn test_searcher_full_timer0_range() {
+        let mut params = make_params(vec![make_iv_sample()]);
+        params.range.timer0_min = 0x0000;
+        params.range.timer0_max = 0xFFFF;
+
+        let searcher = CalibrationSearcher::new(params).unwrap();
+        // 65536 (Timer0) * 3 (VCount) * 1 (GX_STAT) * 3 (frame)
+        assert_eq!(searcher.total_count, 589_824);
+        assert_eq!(
+            searcher.candidate_at(searcher.total_count - 1),
+            CalibrationResult {
+                timer0: 0xFFFF,
+                vcount: 0x61,
+                gx_stat: GX_STAT,
+                frame: 7,
+            }
+        );
+    }
+
+    #[test]
+    fn test_searcher_trainer_info_sample() {
+        let game_start = GameStartConfig {
+            start_mode: StartMode::NewGame,
+            save: SavePresence::NoSave,
+            memory_link: MemoryLinkState::Disabled,
+            shiny_charm: ShinyCharmState::NotObtained,
+        };
+        let trainer = calculate_trainer_info(KNOWN_SEED, RomVersion::Black, game_start).unwrap();
+        let sample = CalibrationSample {
+            outcome: CalibrationOutcome::TrainerInfo {
+                trainer,
+                game_start,
+            },
+            ..make_iv_sample()
+        };
+
+        let results = run(make_params(vec![make_iv_sample(), sample]));
+        assert!(
+            results
+                .iter()
+                .any(|r| r.timer0 == 0x0C79 && r.vcount == 0x60 && r.frame == 6)
+        );
+    }
+
+    #[test]
+    fn test_generate_calibration_search_tasks() {
+        let tasks = generate_calibration_search_tasks(make_params(vec![make_iv_sample()]), 3);
+
+        // 16 Timer0 を 3 分割 (6, 6, 4)
+        assert_eq!(tasks.len(), 3);
+        assert_eq!(tasks[0].range.timer0_min, 0x0C70);
+        assert_eq!(tasks[2].range.timer0_max, 0x0C7F);
+        let covered: u32 = tasks
+            .iter()
+            .map(|t| u32::from(t.range.timer0_max - t.range.timer0_min + 1))
+            .sum();
+        assert_eq!(covered, 16);
+    }
+}
@@ -156,6 +156,10 @@ impl EggDatetimeSearcher {
 /// - `gen_config`: 生成共通設定
 /// - `filter`: フィルター (None の場合は全件返却)
 /// - `worker_count`: Worker 数
+///
+/// # Errors
+///
+/// `auto_ranges` 指定時に既定範囲が未収集の組み合わせの場合
 #[wasm_bindgen]
 #[allow(clippy::needless_pass_by_value)]
 #[allow(clippy::cast_possible_truncation)]
@@ -165,9 +169,9 @@ pub fn generate_egg_search_tasks(
     gen_config: GenerationConfig,
     filter: Option<EggFilter>,
     worker_count: u32,
-) -> Vec<EggDatetimeSearchParams> {
+) -> Result<Vec<EggDatetimeSearchParams>, String> {
     let search_range = context.date_range.to_search_range();
-    let combinations = expand_combinations(&context);
+    let combinations = expand_combinations(&context)?;
     let combo_count = combinations.len() as u32;
 
     // 時間分割数を計算
@@ -175,7 +179,7 @@ pub fn generate_egg_search_tasks(
     let ranges = split_search_range(search_range, time_chunks);
 
     // 組み合わせ × 時間チャンク のクロス積でタスク生成
-    combinations
+    Ok(combinations
         .into_iter()
         .flat_map(|condition| {
             let ds = context.ds.clone();
@@ -193,7 +197,7 @@ pub fn generate_egg_search_tasks(
                 filter: filter.clone(),
             })
         })
-        .collect()
+        .collect())
 }
 
 #[cfg(test)]
@@ -244,8 +248,11 @@ mod tests {
                 masuda_method: false,
                 parent_male: [31, 31, 31, 31, 31, 31].into(),
                 parent_female: [31, 31, 31, 31, 31, 31].into(),
+                parent_male_item: None,
+                parent_female_item: None,
                 consider_npc: false,
                 species_id: None,
+                parent_species: None,
             },
             gen_config: GenerationConfig {
                 version: RomVersion::Black,
@@ -310,6 +317,7 @@ mod tests {
                 vcount_max: 0x5A,
             }],
             key_spec: KeySpec::from_buttons(vec![]),
+            auto_ranges: false,
         };
 
         let egg_params = EggGenerationParams {
@@ -325,8 +333,11 @@ mod tests {
             masuda_method: false,
             parent_male: [31, 31, 31, 31, 31, 31].into(),
             parent_female: [31, 31, 31, 31, 31, 31].into(),
+            parent_male_item: None,
+            parent_female_item: None,
             consider_npc: false,
             species_id: None,
+            parent_species: None,
         };
 
         let gen_config = GenerationConfig {
@@ -342,7 +353,7 @@ mod tests {
         };
 
         // worker_count = 1, combo_count = 1 → 1 task
-        let tasks = generate_egg_search_tasks(context, egg_params, gen_config, None, 1);
+        let tasks = generate_egg_search_tasks(context, egg_params, gen_config, None, 1).unwrap();
 
         // 1 timer0 × 1 vcount × 1 key × 1 time chunk = 1 task
         assert_eq!(tasks.len(), 1);
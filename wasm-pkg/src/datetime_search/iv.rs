@@ -0,0 +1,313 @@
+//! IV 起動時刻検索
+//!
+//! 起動時刻ごとに MT Seed を導出し、その場で IV を算出して `IvFilter` で判定する。
+//! めざパ条件のような緩いフィルタで MT Seed 候補が膨大になる場合に、
+//! `MtseedSearcher` による全探索を経由せずに検索できる。
+
+use wasm_bindgen::prelude::*;
+
+use crate::generation::algorithm::generate_rng_ivs_with_offset_x4;
+use crate::types::{
+    DatetimeSearchContext, IvDatetimeSearchBatch, IvDatetimeSearchParams, IvDatetimeSearchResult,
+    IvFilter, MtSeed, MtseedSearchContext, SeedOrigin, StartupCondition,
+};
+
+use super::base::DatetimeHashGenerator;
+use super::{calculate_time_chunks, expand_combinations, split_search_range};
+
+/// IV 起動時刻検索器
+#[wasm_bindgen]
+pub struct IvDatetimeSearcher {
+    /// 起動時刻とハッシュ値の生成器
+    generator: DatetimeHashGenerator,
+    /// 起動条件 (結果生成用)
+    condition: StartupCondition,
+    /// IV フィルタ条件
+    iv_filter: IvFilter,
+    /// MT オフセット
+    mt_offset: u32,
+    /// 徘徊ポケモンモード
+    is_roamer: bool,
+    // 進捗管理
+    total_count: u64,
+    processed_count: u64,
+}
+
+#[wasm_bindgen]
+impl IvDatetimeSearcher {
+    /// 新しい `IvDatetimeSearcher` を作成
+    ///
+    /// # Errors
+    ///
+    /// - `time_range` のバリデーション失敗
+    #[wasm_bindgen(constructor)]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn new(params: IvDatetimeSearchParams) -> Result<IvDatetimeSearcher, String> {
+        let generator = DatetimeHashGenerator::new(
+            &params.ds,
+            &params.time_range,
+            &params.search_range,
+            params.condition,
+        )?;
+
+        // 進捗計算: 有効秒数 (time_range 内の秒数 × 日数相当)
+        let valid_seconds_per_day = params.time_range.count_valid_seconds();
+        let days = params.search_range.range_seconds.div_ceil(86400);
+        let total_count = u64::from(valid_seconds_per_day) * u64::from(days);
+
+        Ok(Self {
+            generator,
+            condition: params.condition,
+            iv_filter: params.iv_filter,
+            mt_offset: params.mt_offset,
+            is_roamer: params.is_roamer,
+            total_count,
+            processed_count: 0,
+        })
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn is_done(&self) -> bool {
+        self.generator.is_exhausted()
+    }
+
+    #[wasm_bindgen(getter)]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn progress(&self) -> f64 {
+        if self.generator.is_exhausted() {
+            return 1.0;
+        }
+        self.processed_count as f64 / self.total_count as f64
+    }
+
+    /// 次のバッチを検索
+    pub fn next_batch(&mut self, chunk_count: u32) -> IvDatetimeSearchBatch {
+        let mut results = Vec::new();
+        let mut remaining = u64::from(chunk_count);
+
+        while remaining > 0 && !self.generator.is_exhausted() {
+            let (entries, len) = self.generator.next_quad();
+            if len == 0 {
+                break;
+            }
+
+            let processed = u64::from(len);
+            self.processed_count += processed;
+            remaining = remaining.saturating_sub(processed);
+
+            // 4 件まとめて IV を算出 (len < 4 の場合、余りのレーンは無視)
+            let mt_seeds: [MtSeed; 4] = std::array::from_fn(|i| entries[i].1.to_mt_seed());
+            let ivs_x4 = generate_rng_ivs_with_offset_x4(mt_seeds, self.mt_offset, self.is_roamer);
+
+            for ((datetime, hash_values), ivs) in
+                entries.iter().zip(ivs_x4.iter()).take(len as usize)
+            {
+                if self.iv_filter.matches(ivs) {
+                    let lcg_seed = hash_values.to_lcg_seed();
+                    results.push(IvDatetimeSearchResult {
+                        seed_origin: SeedOrigin::startup(lcg_seed, *datetime, self.condition),
+                        ivs: *ivs,
+                    });
+                }
+            }
+        }
+
+        IvDatetimeSearchBatch {
+            results,
+            processed_count: self.processed_count,
+            total_count: self.total_count,
+        }
+    }
+}
+
+// ===== タスク生成関数 =====
+
+/// タスク生成関数
+///
+/// `DatetimeSearchContext` から、
+/// 組み合わせ × 時間チャンク のクロス積でタスクを生成する。
+/// Worker 数を考慮して時間分割を行い、Worker 活用率を最大化する。
+///
+/// # Arguments
+/// - `context`: 検索コンテキスト (日付範囲、時刻範囲、Timer0/VCount/KeyMask 範囲)
+/// - `iv_context`: IV 検索条件 (`iv_filter`, `mt_offset`, `is_roamer`)
+/// - `worker_count`: Worker 数
+///
+/// # Errors
+///
+/// `auto_ranges` 指定時に既定範囲が未収集の組み合わせの場合
+#[wasm_bindgen]
+#[allow(clippy::needless_pass_by_value)]
+#[allow(clippy::cast_possible_truncation)]
+pub fn generate_iv_search_tasks(
+    context: DatetimeSearchContext,
+    iv_context: MtseedSearchContext,
+    worker_count: u32,
+) -> Result<Vec<IvDatetimeSearchParams>, String> {
+    let search_range = context.date_range.to_search_range();
+    let combinations = expand_combinations(&context)?;
+    let combo_count = combinations.len() as u32;
+
+    // 時間分割数を計算
+    let time_chunks = calculate_time_chunks(combo_count, worker_count);
+    let ranges = split_search_range(search_range, time_chunks);
+
+    // 組み合わせ × 時間チャンク のクロス積でタスク生成
+    Ok(combinations
+        .into_iter()
+        .flat_map(|condition| {
+            let ds = context.ds.clone();
+            let time_range = context.time_range.clone();
+            let iv_filter = iv_context.iv_filter.clone();
+            let mt_offset = iv_context.mt_offset;
+            let is_roamer = iv_context.is_roamer;
+            ranges.iter().map(move |range| IvDatetimeSearchParams {
+                ds: ds.clone(),
+                time_range: time_range.clone(),
+                search_range: range.clone(),
+                condition,
+                iv_filter: iv_filter.clone(),
+                mt_offset,
+                is_roamer,
+            })
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generation::algorithm::generate_rng_ivs_with_offset;
+    use crate::types::{
+        DateRangeParams, DsConfig, Hardware, KeyMask, KeySpec, LcgSeed, RomRegion, RomVersion,
+        SearchRangeParams, StartupCondition, TimeRangeParams, Timer0VCountRange,
+    };
+
+    use super::*;
+
+    /// 既知の条件 (2010/09/18 18:13:xx) の検索パラメータ
+    ///
+    /// - ROM: Black (JPN)
+    /// - Hardware: DS Lite
+    /// - MAC: `8C:56:C5:86:15:28`
+    /// - Timer0: `0x0C79`
+    /// - `VCount`: `0x60`
+    /// - 18:13:11 で LCG Seed `0x768360781D1CE6DD`
+    fn create_known_params(iv_filter: IvFilter, mt_offset: u32) -> IvDatetimeSearchParams {
+        IvDatetimeSearchParams {
+            ds: DsConfig {
+                mac: [0x8C, 0x56, 0xC5, 0x86, 0x15, 0x28],
+                hardware: Hardware::DsLite,
+                version: RomVersion::Black,
+                region: RomRegion::Jpn,
+            },
+            time_range: TimeRangeParams {
+                hour_start: 18,
+                hour_end: 18,
+                minute_start: 13,
+                minute_end: 13,
+                second_start: 0,
+                second_end: 59,
+            },
+            search_range: SearchRangeParams {
+                start_year: 2010,
+                start_month: 9,
+                start_day: 18,
+                start_second_offset: 0,
+                range_seconds: 86400,
+            },
+            condition: StartupCondition::new(0x0C79, 0x60, KeyMask::NONE),
+            iv_filter,
+            mt_offset,
+            is_roamer: false,
+        }
+    }
+
+    fn exact_filter(ivs: crate::types::Ivs) -> IvFilter {
+        IvFilter {
+            hp: (ivs.hp, ivs.hp),
+            atk: (ivs.atk, ivs.atk),
+            def: (ivs.def, ivs.def),
+            spa: (ivs.spa, ivs.spa),
+            spd: (ivs.spd, ivs.spd),
+            spe: (ivs.spe, ivs.spe),
+            ..IvFilter::any()
+        }
+    }
+
+    #[test]
+    fn test_searcher_finds_known_seed() {
+        let lcg_seed = LcgSeed::new(0x7683_6078_1D1C_E6DD);
+        let expected_ivs = generate_rng_ivs_with_offset(lcg_seed.derive_mt_seed(), 7, false);
+
+        let params = create_known_params(exact_filter(expected_ivs), 7);
+        let mut searcher = IvDatetimeSearcher::new(params).unwrap();
+
+        let mut results = Vec::new();
+        while !searcher.is_done() {
+            results.extend(searcher.next_batch(1000).results);
+        }
+
+        let found = results
+            .iter()
+            .find(|r| r.seed_origin.base_seed() == lcg_seed)
+            .expect("known seed not found");
+        assert_eq!(found.ivs, expected_ivs);
+        assert_eq!(searcher.processed_count, 60);
+    }
+
+    #[test]
+    fn test_searcher_any_filter_returns_all() {
+        let params = create_known_params(IvFilter::any(), 0);
+        let mut searcher = IvDatetimeSearcher::new(params).unwrap();
+
+        // 60 秒 (4 の倍数でない端数処理も含めて確認するため 7 件ずつ)
+        let mut total = 0;
+        while !searcher.is_done() {
+            total += searcher.next_batch(7).results.len();
+        }
+        assert_eq!(total, 60);
+        assert!((searcher.progress() - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_generate_iv_search_tasks() {
+        let context = DatetimeSearchContext {
+            ds: DsConfig {
+                mac: [0x00, 0x09, 0xBF, 0x12, 0x34, 0x56],
+                hardware: Hardware::DsLite,
+                version: RomVersion::Black,
+                region: RomRegion::Jpn,
+            },
+            date_range: DateRangeParams {
+                start_year: 2023,
+                start_month: 1,
+                start_day: 1,
+                end_year: 2023,
+                end_month: 1,
+                end_day: 1,
+            },
+            time_range: TimeRangeParams {
+                hour_start: 0,
+                hour_end: 23,
+                minute_start: 0,
+                minute_end: 59,
+                second_start: 0,
+                second_end: 59,
+            },
+            ranges: vec![Timer0VCountRange::fixed(0x0C79, 0x5A)],
+            key_spec: KeySpec::from_buttons(vec![]),
+            auto_ranges: false,
+        };
+        let iv_context = MtseedSearchContext {
+            iv_filter: IvFilter::six_v(),
+            mt_offset: 7,
+            is_roamer: false,
+        };
+
+        let tasks = generate_iv_search_tasks(context, iv_context, 4).unwrap();
+
+        // 1 combination * 4 time chunks = 4 tasks
+        assert_eq!(tasks.len(), 4);
+        assert!(tasks.iter().all(|t| t.mt_offset == 7 && !t.is_roamer));
+    }
+}
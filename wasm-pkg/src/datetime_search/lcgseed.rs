@@ -0,0 +1,337 @@
+//! LCG Seed 起動時刻検索
+//!
+//! 既知の 64bit LCG Seed (またはマスクによる部分一致) を再現する起動条件を検索する。
+
+use std::collections::HashSet;
+
+use wasm_bindgen::prelude::*;
+
+use crate::types::{
+    DatetimeSearchContext, LcgSeed, LcgseedDatetimeSearchBatch, LcgseedDatetimeSearchParams,
+    SeedOrigin, StartupCondition,
+};
+
+use super::base::DatetimeHashGenerator;
+use super::{calculate_time_chunks, expand_combinations, split_search_range};
+
+/// LCG Seed 起動時刻検索器
+#[wasm_bindgen]
+pub struct LcgseedDatetimeSearcher {
+    /// 検索対象 Seed (マスク適用済み)
+    target_seeds: HashSet<u64>,
+    /// 比較マスク
+    mask: u64,
+    /// 起動時刻とハッシュ値の生成器
+    generator: DatetimeHashGenerator,
+    /// 起動条件 (結果生成用)
+    condition: StartupCondition,
+    // 進捗管理
+    total_count: u64,
+    processed_count: u64,
+}
+
+#[wasm_bindgen]
+impl LcgseedDatetimeSearcher {
+    /// 新しい `LcgseedDatetimeSearcher` を作成
+    ///
+    /// # Errors
+    ///
+    /// - `target_seeds` が空の場合
+    /// - `mask` が 0 の場合
+    /// - `time_range` のバリデーション失敗
+    #[wasm_bindgen(constructor)]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn new(params: LcgseedDatetimeSearchParams) -> Result<LcgseedDatetimeSearcher, String> {
+        if params.target_seeds.is_empty() {
+            return Err("target_seeds is empty".into());
+        }
+
+        let mask = params.mask.unwrap_or(u64::MAX);
+        if mask == 0 {
+            return Err("mask must not be zero".into());
+        }
+
+        let generator = DatetimeHashGenerator::new(
+            &params.ds,
+            &params.time_range,
+            &params.search_range,
+            params.condition,
+        )?;
+
+        // 進捗計算: 有効秒数 (time_range 内の秒数 × 日数相当)
+        let valid_seconds_per_day = params.time_range.count_valid_seconds();
+        let days = params.search_range.range_seconds.div_ceil(86400);
+        let total_count = u64::from(valid_seconds_per_day) * u64::from(days);
+
+        Ok(Self {
+            target_seeds: params
+                .target_seeds
+                .iter()
+                .map(|seed| seed.value() & mask)
+                .collect(),
+            mask,
+            generator,
+            condition: params.condition,
+            total_count,
+            processed_count: 0,
+        })
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn is_done(&self) -> bool {
+        self.generator.is_exhausted()
+    }
+
+    #[wasm_bindgen(getter)]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn progress(&self) -> f64 {
+        if self.generator.is_exhausted() {
+            return 1.0;
+        }
+        self.processed_count as f64 / self.total_count as f64
+    }
+
+    /// 次のバッチを検索
+    pub fn next_batch(&mut self, chunk_count: u32) -> LcgseedDatetimeSearchBatch {
+        let mut results = Vec::new();
+        let mut remaining = u64::from(chunk_count);
+
+        while remaining > 0 && !self.generator.is_exhausted() {
+            let (entries, len) = self.generator.next_quad();
+            if len == 0 {
+                break;
+            }
+
+            let processed = u64::from(len);
+            self.processed_count += processed;
+            remaining = remaining.saturating_sub(processed);
+
+            for (datetime, hash_values) in entries.iter().take(len as usize) {
+                let lcg_seed = hash_values.to_lcg_seed();
+                if self.target_seeds.contains(&(lcg_seed.value() & self.mask)) {
+                    results.push(SeedOrigin::startup(lcg_seed, *datetime, self.condition));
+                }
+            }
+        }
+
+        LcgseedDatetimeSearchBatch {
+            results,
+            processed_count: self.processed_count,
+            total_count: self.total_count,
+        }
+    }
+}
+
+// ===== タスク生成関数 =====
+
+/// タスク生成関数
+///
+/// `DatetimeSearchContext` から、
+/// 組み合わせ × 時間チャンク のクロス積でタスクを生成する。
+/// Worker 数を考慮して時間分割を行い、Worker 活用率を最大化する。
+///
+/// # Arguments
+/// - `context`: 検索コンテキスト (日付範囲、時刻範囲、Timer0/VCount/KeyMask 範囲)
+/// - `target_seeds`: 検索対象の LCG Seed
+/// - `mask`: 比較マスク (None の場合は完全一致)
+/// - `worker_count`: Worker 数
+///
+/// # Errors
+///
+/// `auto_ranges` 指定時に既定範囲が未収集の組み合わせの場合
+#[wasm_bindgen]
+#[allow(clippy::needless_pass_by_value)]
+#[allow(clippy::cast_possible_truncation)]
+pub fn generate_lcgseed_search_tasks(
+    context: DatetimeSearchContext,
+    target_seeds: Vec<LcgSeed>,
+    mask: Option<u64>,
+    worker_count: u32,
+) -> Result<Vec<LcgseedDatetimeSearchParams>, String> {
+    let search_range = context.date_range.to_search_range();
+    let combinations = expand_combinations(&context)?;
+    let combo_count = combinations.len() as u32;
+
+    // 時間分割数を計算
+    let time_chunks = calculate_time_chunks(combo_count, worker_count);
+    let ranges = split_search_range(search_range, time_chunks);
+
+    // 組み合わせ × 時間チャンク のクロス積でタスク生成
+    Ok(combinations
+        .into_iter()
+        .flat_map(|condition| {
+            let target_seeds = target_seeds.clone();
+            let ds = context.ds.clone();
+            let time_range = context.time_range.clone();
+            ranges.iter().map(move |range| LcgseedDatetimeSearchParams {
+                target_seeds: target_seeds.clone(),
+                mask,
+                ds: ds.clone(),
+                time_range: time_range.clone(),
+                search_range: range.clone(),
+                condition,
+            })
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{
+        DateRangeParams, DsButton, DsConfig, Hardware, KeyMask, KeySpec, RomRegion, RomVersion,
+        SearchRangeParams, StartupCondition, TimeRangeParams, Timer0VCountRange,
+    };
+
+    use super::*;
+
+    /// 既知の条件 (2010/09/18 18:13:11 付近) の検索パラメータ
+    ///
+    /// - ROM: Black (JPN)
+    /// - Hardware: DS Lite
+    /// - MAC: `8C:56:C5:86:15:28`
+    /// - Timer0: `0x0C79`
+    /// - `VCount`: `0x60`
+    /// - keyMask: `0x0000` (入力なし)
+    /// - 期待 LCG Seed: `0x768360781D1CE6DD`
+    fn create_known_params(
+        target_seeds: Vec<LcgSeed>,
+        mask: Option<u64>,
+    ) -> LcgseedDatetimeSearchParams {
+        LcgseedDatetimeSearchParams {
+            target_seeds,
+            mask,
+            ds: DsConfig {
+                mac: [0x8C, 0x56, 0xC5, 0x86, 0x15, 0x28],
+                hardware: Hardware::DsLite,
+                version: RomVersion::Black,
+                region: RomRegion::Jpn,
+            },
+            time_range: TimeRangeParams {
+                hour_start: 18,
+                hour_end: 18,
+                minute_start: 13,
+                minute_end: 13,
+                second_start: 0,
+                second_end: 59,
+            },
+            search_range: SearchRangeParams {
+                start_year: 2010,
+                start_month: 9,
+                start_day: 18,
+                start_second_offset: 0,
+                range_seconds: 86400,
+            },
+            condition: StartupCondition::new(0x0C79, 0x60, KeyMask::NONE),
+        }
+    }
+
+    fn run_all(searcher: &mut LcgseedDatetimeSearcher) -> Vec<SeedOrigin> {
+        let mut all_results = Vec::new();
+        while !searcher.is_done() {
+            all_results.extend(searcher.next_batch(10000).results);
+        }
+        all_results
+    }
+
+    #[test]
+    fn test_searcher_empty_seeds() {
+        let params = create_known_params(vec![], None);
+        assert!(LcgseedDatetimeSearcher::new(params).is_err());
+    }
+
+    #[test]
+    fn test_searcher_zero_mask() {
+        let params = create_known_params(vec![LcgSeed::new(1)], Some(0));
+        assert!(LcgseedDatetimeSearcher::new(params).is_err());
+    }
+
+    #[test]
+    fn test_searcher_finds_known_seed() {
+        let expected = LcgSeed::new(0x7683_6078_1D1C_E6DD);
+        let params = create_known_params(vec![expected], None);
+        let mut searcher = LcgseedDatetimeSearcher::new(params).unwrap();
+
+        let results = run_all(&mut searcher);
+        assert_eq!(results.len(), 1);
+
+        let SeedOrigin::Startup {
+            base_seed,
+            datetime,
+            condition,
+            ..
+        } = &results[0]
+        else {
+            panic!("Expected SeedOrigin::Startup")
+        };
+        assert_eq!(*base_seed, expected);
+        assert_eq!(
+            (datetime.hour, datetime.minute, datetime.second),
+            (18, 13, 11)
+        );
+        assert_eq!(condition.timer0, 0x0C79);
+        assert_eq!(condition.vcount, 0x60);
+    }
+
+    #[test]
+    fn test_searcher_partial_match_with_mask() {
+        // 上位 32bit のみ一致させる
+        let target = LcgSeed::new(0x7683_6078_0000_0000);
+        let params = create_known_params(vec![target], Some(0xFFFF_FFFF_0000_0000));
+        let mut searcher = LcgseedDatetimeSearcher::new(params).unwrap();
+
+        let results = run_all(&mut searcher);
+        assert!(
+            results
+                .iter()
+                .any(|r| r.base_seed() == LcgSeed::new(0x7683_6078_1D1C_E6DD))
+        );
+
+        // 完全一致では見つからない
+        let params = create_known_params(vec![target], None);
+        let mut searcher = LcgseedDatetimeSearcher::new(params).unwrap();
+        assert!(run_all(&mut searcher).is_empty());
+    }
+
+    #[test]
+    fn test_generate_lcgseed_search_tasks() {
+        let context = DatetimeSearchContext {
+            ds: DsConfig {
+                mac: [0x00, 0x09, 0xBF, 0x12, 0x34, 0x56],
+                hardware: Hardware::DsLite,
+                version: RomVersion::Black,
+                region: RomRegion::Jpn,
+            },
+            date_range: DateRangeParams {
+                start_year: 2023,
+                start_month: 1,
+                start_day: 1,
+                end_year: 2023,
+                end_month: 1,
+                end_day: 1,
+            },
+            time_range: TimeRangeParams {
+                hour_start: 0,
+                hour_end: 0,
+                minute_start: 0,
+                minute_end: 0,
+                second_start: 0,
+                second_end: 0,
+            },
+            ranges: vec![Timer0VCountRange::fixed(0x0C79, 0x5A)],
+            key_spec: KeySpec::from_buttons(vec![DsButton::A, DsButton::B]), // 4 combinations
+            auto_ranges: false,
+        };
+
+        let tasks = generate_lcgseed_search_tasks(
+            context,
+            vec![LcgSeed::new(0x1234_5678_9ABC_DEF0)],
+            Some(0xFFFF_FFFF_0000_0000),
+            4,
+        )
+        .unwrap();
+
+        // 4 combinations * 1 time chunk = 4 tasks
+        assert_eq!(tasks.len(), 4);
+        assert!(tasks.iter().all(|t| t.mask == Some(0xFFFF_FFFF_0000_0000)));
+    }
+}
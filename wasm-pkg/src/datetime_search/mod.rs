@@ -1,29 +1,49 @@
 //! 起動時刻検索
 
 pub mod base;
+pub mod calibration;
 pub mod egg;
+pub mod iv;
+pub mod lcgseed;
 pub mod mtseed;
+pub mod needle;
+pub mod pokemon;
 pub mod trainer_info;
 
 use wasm_bindgen::prelude::*;
 
+use crate::data::timer0_defaults::resolve_ranges;
 use crate::types::{DatetimeSearchContext, SearchRangeParams, StartupCondition};
 
 use base::{datetime_to_seconds, seconds_to_datetime};
 
 // Searcher と関数のみ re-export (型は types モジュールから)
+pub use calibration::{CalibrationSearcher, generate_calibration_search_tasks};
 pub use egg::{EggDatetimeSearcher, generate_egg_search_tasks};
-pub use mtseed::{MtseedDatetimeSearcher, generate_mtseed_search_tasks};
+pub use iv::{IvDatetimeSearcher, generate_iv_search_tasks};
+pub use lcgseed::{LcgseedDatetimeSearcher, generate_lcgseed_search_tasks};
+pub use mtseed::{
+    MtseedDatetimeSearcher, generate_mtseed_search_tasks,
+    generate_mtseed_search_tasks_from_batches, generate_mtseed_search_tasks_from_egg_batches,
+};
+pub use needle::{NeedleDatetimeSearcher, generate_needle_search_tasks};
+pub use pokemon::{PokemonDatetimeSearcher, generate_pokemon_search_tasks};
 pub use trainer_info::{TrainerInfoSearcher, generate_trainer_info_search_tasks};
 
 /// 組み合わせ展開 (共通関数)
 ///
 /// `DatetimeSearchContext` から `Timer0` × `VCount` × `KeyMask` の全組み合わせを展開する。
-pub(crate) fn expand_combinations(context: &DatetimeSearchContext) -> Vec<StartupCondition> {
+///
+/// # Errors
+/// `auto_ranges` 指定時に既定範囲が未収集の組み合わせの場合
+pub(crate) fn expand_combinations(
+    context: &DatetimeSearchContext,
+) -> Result<Vec<StartupCondition>, String> {
+    let ranges = resolve_ranges(&context.ds, &context.ranges, context.auto_ranges)?;
     let key_masks = context.key_spec.combinations();
     let mut combinations = Vec::new();
 
-    for range in &context.ranges {
+    for range in &ranges {
         for timer0 in range.timer0_min..=range.timer0_max {
             for vcount in range.vcount_min..=range.vcount_max {
                 for &key_mask in &key_masks {
@@ -32,7 +52,7 @@ pub(crate) fn expand_combinations(context: &DatetimeSearchContext) -> Vec<Startu
             }
         }
     }
-    combinations
+    Ok(combinations)
 }
 
 /// 組み合わせ数と Worker 数から時間分割数を計算
@@ -324,4 +344,47 @@ mod tests {
         // 割り切れない場合、切り上げ
         assert_eq!(calculate_time_chunks(3, 10), 4); // ceil(10 / 3) = 4
     }
+
+    #[test]
+    fn test_expand_combinations_auto_ranges() {
+        use crate::types::{
+            DateRangeParams, DsConfig, Hardware, KeySpec, RomRegion, RomVersion, TimeRangeParams,
+        };
+
+        let mut context = DatetimeSearchContext {
+            ds: DsConfig {
+                mac: [0x00, 0x09, 0xBF, 0x12, 0x34, 0x56],
+                hardware: Hardware::DsLite,
+                version: RomVersion::Black2,
+                region: RomRegion::Ger,
+            },
+            date_range: DateRangeParams {
+                start_year: 2023,
+                start_month: 1,
+                start_day: 1,
+                end_year: 2023,
+                end_month: 1,
+                end_day: 1,
+            },
+            time_range: TimeRangeParams {
+                hour_start: 0,
+                hour_end: 0,
+                minute_start: 0,
+                minute_end: 0,
+                second_start: 0,
+                second_end: 0,
+            },
+            ranges: vec![],
+            key_spec: KeySpec::from_buttons(vec![]),
+            auto_ranges: true,
+        };
+
+        // Black2 GER (DS Lite): 0x10E5-0x10E8 @ 0x81, 0x10E9-0x10EC @ 0x82
+        assert_eq!(expand_combinations(&context).unwrap().len(), 8);
+
+        // 未収集の組み合わせはエラー (タスク生成も失敗する)
+        context.ds.hardware = Hardware::N3ds;
+        assert!(expand_combinations(&context).is_err());
+        assert!(generate_mtseed_search_tasks(context, vec![], 4).is_err());
+    }
 }
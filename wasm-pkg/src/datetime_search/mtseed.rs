@@ -1,22 +1,76 @@
 //! MT Seed 起動時刻検索
 
-use std::collections::BTreeSet;
-
 use wasm_bindgen::prelude::*;
 
 use crate::types::{
-    DatetimeSearchContext, MtSeed, MtseedDatetimeSearchBatch, MtseedDatetimeSearchParams,
-    SeedOrigin, StartupCondition,
+    DatetimeSearchContext, EggMtseedSearchBatch, MtSeed, MtseedDatetimeSearchBatch,
+    MtseedDatetimeSearchParams, MtseedSearchBatch, SeedOrigin, StartupCondition,
 };
 
 use super::base::DatetimeHashGenerator;
 use super::{calculate_time_chunks, expand_combinations, split_search_range};
 
+// ===== MtseedTargetSet =====
+
+/// 上位 16bit バケット数
+const BUCKET_COUNT: usize = 1 << 16;
+
+/// 検索対象 MT Seed の集合
+///
+/// ソート済み配列を上位 16bit でバケット分割して保持する。
+/// 判定はバケット範囲内の二分探索のみで、対象数が 100k 件を超えても
+/// ルックアップコストはほぼ一定となる。
+pub(crate) struct MtseedTargetSet {
+    /// バケット `i` の開始位置 (`offsets[i]..offsets[i + 1]`)
+    offsets: Box<[u32]>,
+    /// ソート・重複除去済みの Seed 値
+    seeds: Box<[u32]>,
+}
+
+impl MtseedTargetSet {
+    /// Seed 列から集合を構築
+    #[allow(clippy::cast_possible_truncation)]
+    pub(crate) fn new(target_seeds: impl IntoIterator<Item = MtSeed>) -> Self {
+        let mut seeds: Vec<u32> = target_seeds.into_iter().map(MtSeed::value).collect();
+        seeds.sort_unstable();
+        seeds.dedup();
+
+        // 各バケットの開始位置を計算
+        let mut offsets = vec![0u32; BUCKET_COUNT + 1];
+        for &seed in &seeds {
+            offsets[(seed >> 16) as usize + 1] += 1;
+        }
+        for i in 0..BUCKET_COUNT {
+            offsets[i + 1] += offsets[i];
+        }
+
+        Self {
+            offsets: offsets.into_boxed_slice(),
+            seeds: seeds.into_boxed_slice(),
+        }
+    }
+
+    /// 集合に含まれるか判定
+    #[inline]
+    pub(crate) fn contains(&self, seed: MtSeed) -> bool {
+        let value = seed.value();
+        let bucket = (value >> 16) as usize;
+        let start = self.offsets[bucket] as usize;
+        let end = self.offsets[bucket + 1] as usize;
+        self.seeds[start..end].binary_search(&value).is_ok()
+    }
+
+    /// 空判定
+    pub(crate) fn is_empty(&self) -> bool {
+        self.seeds.is_empty()
+    }
+}
+
 /// MT Seed 起動時刻検索器
 #[wasm_bindgen]
 pub struct MtseedDatetimeSearcher {
-    /// 検索対象 Seed (型安全な `BTreeSet`)
-    target_seeds: BTreeSet<MtSeed>,
+    /// 検索対象 Seed (バケット分割済みソート配列)
+    target_seeds: MtseedTargetSet,
     /// 起動時刻とハッシュ値の生成器
     generator: DatetimeHashGenerator,
     /// 起動条件 (結果生成用)
@@ -37,7 +91,8 @@ impl MtseedDatetimeSearcher {
     #[wasm_bindgen(constructor)]
     #[allow(clippy::needless_pass_by_value)]
     pub fn new(params: MtseedDatetimeSearchParams) -> Result<MtseedDatetimeSearcher, String> {
-        if params.target_seeds.is_empty() {
+        let target_seeds = MtseedTargetSet::new(params.target_seeds.iter().copied());
+        if target_seeds.is_empty() {
             return Err("target_seeds is empty".into());
         }
 
@@ -54,7 +109,7 @@ impl MtseedDatetimeSearcher {
         let total_count = u64::from(valid_seconds_per_day) * u64::from(days);
 
         Ok(Self {
-            target_seeds: params.target_seeds.into_iter().collect(),
+            target_seeds,
             generator,
             condition: params.condition,
             total_count,
@@ -93,7 +148,7 @@ impl MtseedDatetimeSearcher {
 
             for (datetime, hash_values) in entries.iter().take(len as usize) {
                 let mt_seed = hash_values.to_mt_seed();
-                if self.target_seeds.contains(&mt_seed) {
+                if self.target_seeds.contains(mt_seed) {
                     let lcg_seed = hash_values.to_lcg_seed();
                     // SeedOrigin::Startup を直接生成
                     results.push(SeedOrigin::startup(lcg_seed, *datetime, self.condition));
@@ -121,6 +176,10 @@ impl MtseedDatetimeSearcher {
 /// - `context`: 検索コンテキスト (日付範囲、時刻範囲、Timer0/VCount/KeyMask 範囲)
 /// - `target_seeds`: 検索対象の MT Seed
 /// - `worker_count`: Worker 数
+///
+/// # Errors
+///
+/// `auto_ranges` 指定時に既定範囲が未収集の組み合わせの場合
 #[wasm_bindgen]
 #[allow(clippy::needless_pass_by_value)]
 #[allow(clippy::cast_possible_truncation)]
@@ -128,9 +187,9 @@ pub fn generate_mtseed_search_tasks(
     context: DatetimeSearchContext,
     target_seeds: Vec<MtSeed>,
     worker_count: u32,
-) -> Vec<MtseedDatetimeSearchParams> {
+) -> Result<Vec<MtseedDatetimeSearchParams>, String> {
     let search_range = context.date_range.to_search_range();
-    let combinations = expand_combinations(&context);
+    let combinations = expand_combinations(&context)?;
     let combo_count = combinations.len() as u32;
 
     // 時間分割数を計算
@@ -138,7 +197,7 @@ pub fn generate_mtseed_search_tasks(
     let ranges = split_search_range(search_range, time_chunks);
 
     // 組み合わせ × 時間チャンク のクロス積でタスク生成
-    combinations
+    Ok(combinations
         .into_iter()
         .flat_map(|condition| {
             let target_seeds = target_seeds.clone();
@@ -152,7 +211,74 @@ pub fn generate_mtseed_search_tasks(
                 condition,
             })
         })
-        .collect()
+        .collect())
+}
+
+/// `MtseedSearcher` の出力からタスクを生成
+///
+/// `MtseedSearchBatch` の候補 Seed をそのまま検索対象として扱う。
+/// 複数バッチ間の重複は除去される。
+///
+/// # Arguments
+/// - `context`: 検索コンテキスト (日付範囲、時刻範囲、Timer0/VCount/KeyMask 範囲)
+/// - `batches`: `MtseedSearcher::next_batch` の出力
+/// - `worker_count`: Worker 数
+///
+/// # Errors
+///
+/// `auto_ranges` 指定時に既定範囲が未収集の組み合わせの場合
+#[wasm_bindgen]
+#[allow(clippy::needless_pass_by_value)]
+pub fn generate_mtseed_search_tasks_from_batches(
+    context: DatetimeSearchContext,
+    batches: Vec<MtseedSearchBatch>,
+    worker_count: u32,
+) -> Result<Vec<MtseedDatetimeSearchParams>, String> {
+    let target_seeds = collect_target_seeds(
+        batches
+            .iter()
+            .flat_map(|batch| batch.candidates.iter().map(|c| c.seed)),
+    );
+
+    generate_mtseed_search_tasks(context, target_seeds, worker_count)
+}
+
+/// `EggMtseedSearcher` の出力からタスクを生成
+///
+/// 孵化個体の IV は起動時の MT Seed から決まるため、
+/// `EggMtseedSearchBatch` の候補 Seed を起動時刻の検索対象として扱う。
+/// 複数バッチ間の重複は除去される。
+///
+/// # Arguments
+/// - `context`: 検索コンテキスト (日付範囲、時刻範囲、Timer0/VCount/KeyMask 範囲)
+/// - `batches`: `EggMtseedSearcher::next_batch` の出力
+/// - `worker_count`: Worker 数
+///
+/// # Errors
+///
+/// `auto_ranges` 指定時に既定範囲が未収集の組み合わせの場合
+#[wasm_bindgen]
+#[allow(clippy::needless_pass_by_value)]
+pub fn generate_mtseed_search_tasks_from_egg_batches(
+    context: DatetimeSearchContext,
+    batches: Vec<EggMtseedSearchBatch>,
+    worker_count: u32,
+) -> Result<Vec<MtseedDatetimeSearchParams>, String> {
+    let target_seeds = collect_target_seeds(
+        batches
+            .iter()
+            .flat_map(|batch| batch.candidates.iter().map(|c| c.seed)),
+    );
+
+    generate_mtseed_search_tasks(context, target_seeds, worker_count)
+}
+
+/// 検索対象 Seed をソート・重複除去して収集
+fn collect_target_seeds(seeds: impl Iterator<Item = MtSeed>) -> Vec<MtSeed> {
+    let mut target_seeds: Vec<MtSeed> = seeds.collect();
+    target_seeds.sort_unstable();
+    target_seeds.dedup();
+    target_seeds
 }
 
 #[cfg(test)]
@@ -353,9 +479,11 @@ mod tests {
             },
             ranges: vec![Timer0VCountRange::fixed(0x0C79, 0x5A)],
             key_spec: KeySpec::from_buttons(vec![DsButton::A, DsButton::B]), // 4 combinations
+            auto_ranges: false,
         };
 
-        let tasks = generate_mtseed_search_tasks(context, vec![MtSeed::new(0x1234_5678)], 4);
+        let tasks =
+            generate_mtseed_search_tasks(context, vec![MtSeed::new(0x1234_5678)], 4).unwrap();
 
         // 4 combinations * 1 time chunk = 4 tasks
         // (worker_count = 4, combo_count = 4 → time_chunks = 1)
@@ -389,9 +517,11 @@ mod tests {
             },
             ranges: vec![Timer0VCountRange::fixed(0x0C79, 0x5A)],
             key_spec: KeySpec::from_buttons(vec![]), // 1 combination (no buttons)
+            auto_ranges: false,
         };
 
-        let tasks = generate_mtseed_search_tasks(context, vec![MtSeed::new(0x1234_5678)], 4);
+        let tasks =
+            generate_mtseed_search_tasks(context, vec![MtSeed::new(0x1234_5678)], 4).unwrap();
 
         // 1 combination * 4 time chunks = 4 tasks
         // (worker_count = 4, combo_count = 1 → time_chunks = 4)
@@ -465,6 +595,8 @@ mod tests {
 
     #[test]
     fn test_mtseed_ord() {
+        use std::collections::BTreeSet;
+
         let seed1 = MtSeed::new(100);
         let seed2 = MtSeed::new(200);
         assert!(seed1 < seed2);
@@ -527,10 +659,11 @@ mod tests {
             },
             ranges: vec![Timer0VCountRange::fixed(0x0C79, 0x60)],
             key_spec: KeySpec::from_buttons(vec![]), // 1 combination
+            auto_ranges: false,
         };
 
         // 4 Worker で時間分割 (組み合わせ数 = 1 なので 4 チャンク)
-        let tasks = generate_mtseed_search_tasks(context, vec![expected_mt_seed], 4);
+        let tasks = generate_mtseed_search_tasks(context, vec![expected_mt_seed], 4).unwrap();
 
         assert_eq!(tasks.len(), 4);
 
@@ -563,4 +696,125 @@ mod tests {
             "Expected MT Seed should be found in one of the split tasks"
         );
     }
+
+    #[test]
+    fn test_target_set_contains() {
+        let seeds = [
+            0x0000_0000,
+            0x0000_FFFF,
+            0x0001_0000,
+            0x1234_5678,
+            0x1234_5679,
+            0xFFFF_FFFF,
+            0x1234_5678, // 重複
+        ];
+        let set = MtseedTargetSet::new(seeds.iter().map(|&s| MtSeed::new(s)));
+
+        for &seed in &seeds {
+            assert!(set.contains(MtSeed::new(seed)), "{seed:08X} not found");
+        }
+        assert!(!set.contains(MtSeed::new(0x0000_0001)));
+        assert!(!set.contains(MtSeed::new(0x1234_5677)));
+        assert!(!set.contains(MtSeed::new(0xFFFF_FFFE)));
+        assert!(!set.contains(MtSeed::new(0x0002_0000)));
+    }
+
+    #[test]
+    fn test_target_set_large() {
+        // 100k 件以上の対象でも正しく判定できること
+        let set =
+            MtseedTargetSet::new((0..200_000u32).map(|i| MtSeed::new(i.wrapping_mul(21_473))));
+        assert!(set.contains(MtSeed::new(12_345 * 21_473)));
+        assert!(!set.contains(MtSeed::new(12_345 * 21_473 + 1)));
+    }
+
+    #[test]
+    fn test_target_set_empty() {
+        let set = MtseedTargetSet::new(std::iter::empty());
+        assert!(set.is_empty());
+        assert!(!set.contains(MtSeed::new(0)));
+    }
+
+    fn make_search_context() -> DatetimeSearchContext {
+        DatetimeSearchContext {
+            ds: DsConfig {
+                mac: [0x00, 0x09, 0xBF, 0x12, 0x34, 0x56],
+                hardware: Hardware::DsLite,
+                version: RomVersion::Black,
+                region: RomRegion::Jpn,
+            },
+            date_range: DateRangeParams {
+                start_year: 2023,
+                start_month: 1,
+                start_day: 1,
+                end_year: 2023,
+                end_month: 1,
+                end_day: 1,
+            },
+            time_range: TimeRangeParams {
+                hour_start: 0,
+                hour_end: 23,
+                minute_start: 0,
+                minute_end: 59,
+                second_start: 0,
+                second_end: 59,
+            },
+            ranges: vec![Timer0VCountRange::fixed(0x0C79, 0x5A)],
+            key_spec: KeySpec::from_buttons(vec![]),
+            auto_ranges: false,
+        }
+    }
+
+    #[test]
+    fn test_generate_mtseed_search_tasks_from_batches() {
+        use crate::types::{Ivs, MtseedResult};
+
+        let context = make_search_context();
+        let make_batch = |seeds: &[u32]| MtseedSearchBatch {
+            candidates: seeds
+                .iter()
+                .map(|&s| MtseedResult {
+                    seed: MtSeed::new(s),
+                    ivs: Ivs::new(31, 31, 31, 31, 31, 31),
+                })
+                .collect(),
+            processed: 0,
+            total: 0,
+        };
+
+        let batches = vec![make_batch(&[3, 1]), make_batch(&[2, 1])];
+        let tasks = generate_mtseed_search_tasks_from_batches(context, batches, 1).unwrap();
+
+        assert_eq!(tasks.len(), 1);
+        // 重複除去・ソート済み
+        assert_eq!(
+            tasks[0].target_seeds,
+            vec![MtSeed::new(1), MtSeed::new(2), MtSeed::new(3)]
+        );
+    }
+
+    #[test]
+    fn test_generate_mtseed_search_tasks_from_egg_batches() {
+        use crate::types::{EggMtseedResult, Ivs};
+
+        let context = make_search_context();
+        let make_batch = |seeds: &[u32]| EggMtseedSearchBatch {
+            candidates: seeds
+                .iter()
+                .map(|&s| EggMtseedResult {
+                    seed: MtSeed::new(s),
+                    rng_ivs: Ivs::new(31, 31, 31, 31, 31, 31),
+                    patterns: vec![],
+                })
+                .collect(),
+            processed: 0,
+            total: 0,
+        };
+
+        let batches = vec![make_batch(&[5, 4]), make_batch(&[4])];
+        let tasks = generate_mtseed_search_tasks_from_egg_batches(context, batches, 1).unwrap();
+
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].target_seeds, vec![MtSeed::new(4), MtSeed::new(5)]);
+    }
 }
@@ -0,0 +1,345 @@
+//! レポート針起動時刻検索
+//!
+//! 観測した針パターンから、実際に起動した日時・起動条件を特定する。
+//! 各起動候補について `game_offset` 適用後の針列を走査し、
+//! advance 範囲内にパターンが出現する候補を返す。
+
+use wasm_bindgen::prelude::*;
+
+use crate::misc::needle_search::search_needle_pattern_in_origin;
+use crate::types::{
+    DatetimeSearchContext, GameStartConfig, GenerationConfig, NeedleDatetimeSearchBatch,
+    NeedleDatetimeSearchParams, NeedlePattern, SeedOrigin, StartupCondition,
+};
+
+use super::base::DatetimeHashGenerator;
+use super::{calculate_time_chunks, expand_combinations, split_search_range};
+
+/// レポート針起動時刻検索器
+#[wasm_bindgen]
+pub struct NeedleDatetimeSearcher {
+    /// 起動時刻とハッシュ値の生成器
+    generator: DatetimeHashGenerator,
+    /// 起動条件 (結果生成用)
+    condition: StartupCondition,
+    /// 観測した針パターン
+    pattern: NeedlePattern,
+    /// 針検索用の生成設定 (`version`, `game_start`, advance 範囲)
+    config: GenerationConfig,
+    // 進捗管理
+    total_count: u64,
+    processed_count: u64,
+}
+
+#[wasm_bindgen]
+impl NeedleDatetimeSearcher {
+    /// 新しい `NeedleDatetimeSearcher` を作成
+    ///
+    /// # Errors
+    ///
+    /// - `pattern` が空の場合
+    /// - `user_offset > max_advance` の場合
+    /// - `GameStartConfig` の検証失敗
+    /// - `time_range` のバリデーション失敗
+    #[wasm_bindgen(constructor)]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn new(params: NeedleDatetimeSearchParams) -> Result<NeedleDatetimeSearcher, String> {
+        if params.pattern.is_empty() {
+            return Err("Pattern is empty".into());
+        }
+        if params.user_offset > params.max_advance {
+            return Err("user_offset must be less than or equal to max_advance".into());
+        }
+
+        params.game_start.validate(params.ds.version)?;
+
+        let generator = DatetimeHashGenerator::new(
+            &params.ds,
+            &params.time_range,
+            &params.search_range,
+            params.condition,
+        )?;
+
+        // 進捗計算: 有効秒数 (time_range 内の秒数 × 日数相当)
+        let valid_seconds_per_day = params.time_range.count_valid_seconds();
+        let days = params.search_range.range_seconds.div_ceil(86400);
+        let total_count = u64::from(valid_seconds_per_day) * u64::from(days);
+
+        let config = GenerationConfig {
+            version: params.ds.version,
+            game_start: params.game_start,
+            user_offset: params.user_offset,
+            max_advance: params.max_advance,
+        };
+
+        Ok(Self {
+            generator,
+            condition: params.condition,
+            pattern: params.pattern,
+            config,
+            total_count,
+            processed_count: 0,
+        })
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn is_done(&self) -> bool {
+        self.generator.is_exhausted()
+    }
+
+    #[wasm_bindgen(getter)]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn progress(&self) -> f64 {
+        if self.generator.is_exhausted() {
+            return 1.0;
+        }
+        self.processed_count as f64 / self.total_count as f64
+    }
+
+    /// 次のバッチを検索
+    pub fn next_batch(&mut self, chunk_count: u32) -> NeedleDatetimeSearchBatch {
+        let mut results = Vec::new();
+        let mut remaining = u64::from(chunk_count);
+
+        while remaining > 0 && !self.generator.is_exhausted() {
+            let (entries, len) = self.generator.next_quad();
+            if len == 0 {
+                break;
+            }
+
+            let processed = u64::from(len);
+            self.processed_count += processed;
+            remaining = remaining.saturating_sub(processed);
+
+            for (datetime, hash_values) in entries.iter().take(len as usize) {
+                let lcg_seed = hash_values.to_lcg_seed();
+                let origin = SeedOrigin::startup(lcg_seed, *datetime, self.condition);
+
+                // 起動設定はコンストラクタで検証済みのためエラーは発生しない
+                let _ = search_needle_pattern_in_origin(
+                    &origin,
+                    self.pattern.directions(),
+                    &self.config,
+                    &mut results,
+                );
+            }
+        }
+
+        NeedleDatetimeSearchBatch {
+            results,
+            processed_count: self.processed_count,
+            total_count: self.total_count,
+        }
+    }
+}
+
+// ===== タスク生成関数 =====
+
+/// タスク生成関数
+///
+/// `DatetimeSearchContext` から、
+/// 組み合わせ × 時間チャンク のクロス積でタスクを生成する。
+/// Worker 数を考慮して時間分割を行い、Worker 活用率を最大化する。
+///
+/// # Arguments
+/// - `context`: 検索コンテキスト (日付範囲、時刻範囲、Timer0/VCount/KeyMask 範囲)
+/// - `game_start`: 起動設定
+/// - `pattern`: 観測した針パターン
+/// - `user_offset`: 検索開始位置
+/// - `max_advance`: 検索終了位置
+/// - `worker_count`: Worker 数
+///
+/// # Errors
+///
+/// `auto_ranges` 指定時に既定範囲が未収集の組み合わせの場合
+#[wasm_bindgen]
+#[allow(clippy::needless_pass_by_value)]
+#[allow(clippy::cast_possible_truncation)]
+pub fn generate_needle_search_tasks(
+    context: DatetimeSearchContext,
+    game_start: GameStartConfig,
+    pattern: NeedlePattern,
+    user_offset: u32,
+    max_advance: u32,
+    worker_count: u32,
+) -> Result<Vec<NeedleDatetimeSearchParams>, String> {
+    let search_range = context.date_range.to_search_range();
+    let combinations = expand_combinations(&context)?;
+    let combo_count = combinations.len() as u32;
+
+    // 時間分割数を計算
+    let time_chunks = calculate_time_chunks(combo_count, worker_count);
+    let ranges = split_search_range(search_range, time_chunks);
+
+    // 組み合わせ × 時間チャンク のクロス積でタスク生成
+    Ok(combinations
+        .into_iter()
+        .flat_map(|condition| {
+            let ds = context.ds.clone();
+            let time_range = context.time_range.clone();
+            let pattern = pattern.clone();
+            ranges.iter().map(move |range| NeedleDatetimeSearchParams {
+                ds: ds.clone(),
+                time_range: time_range.clone(),
+                search_range: range.clone(),
+                condition,
+                game_start,
+                pattern: pattern.clone(),
+                user_offset,
+                max_advance,
+            })
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generation::algorithm::calculate_game_offset;
+    use crate::misc::get_needle_pattern_at;
+    use crate::types::{
+        DateRangeParams, DsConfig, Hardware, KeyMask, KeySpec, LcgSeed, MemoryLinkState, RomRegion,
+        RomVersion, SavePresence, SearchRangeParams, ShinyCharmState, StartMode, StartupCondition,
+        TimeRangeParams, Timer0VCountRange,
+    };
+
+    use super::*;
+
+    fn make_game_start() -> GameStartConfig {
+        GameStartConfig {
+            start_mode: StartMode::Continue,
+            save: SavePresence::WithSave,
+            memory_link: MemoryLinkState::Disabled,
+            shiny_charm: ShinyCharmState::NotObtained,
+        }
+    }
+
+    /// 既知の条件 (2010/09/18 18:13:xx) の検索パラメータ
+    ///
+    /// - ROM: Black (JPN)
+    /// - Hardware: DS Lite
+    /// - MAC: `8C:56:C5:86:15:28`
+    /// - Timer0: `0x0C79`
+    /// - `VCount`: `0x60`
+    /// - 18:13:11 で LCG Seed `0x768360781D1CE6DD`
+    fn create_known_params(pattern: NeedlePattern) -> NeedleDatetimeSearchParams {
+        NeedleDatetimeSearchParams {
+            ds: DsConfig {
+                mac: [0x8C, 0x56, 0xC5, 0x86, 0x15, 0x28],
+                hardware: Hardware::DsLite,
+                version: RomVersion::Black,
+                region: RomRegion::Jpn,
+            },
+            time_range: TimeRangeParams {
+                hour_start: 18,
+                hour_end: 18,
+                minute_start: 13,
+                minute_end: 13,
+                second_start: 0,
+                second_end: 59,
+            },
+            search_range: SearchRangeParams {
+                start_year: 2010,
+                start_month: 9,
+                start_day: 18,
+                start_second_offset: 0,
+                range_seconds: 86400,
+            },
+            condition: StartupCondition::new(0x0C79, 0x60, KeyMask::NONE),
+            game_start: make_game_start(),
+            pattern,
+            user_offset: 0,
+            max_advance: 50,
+        }
+    }
+
+    #[test]
+    fn test_searcher_empty_pattern() {
+        let params = create_known_params(NeedlePattern::new(vec![]));
+        assert!(NeedleDatetimeSearcher::new(params).is_err());
+    }
+
+    #[test]
+    fn test_searcher_finds_known_boot() {
+        let seed = LcgSeed::new(0x7683_6078_1D1C_E6DD);
+        let game_offset =
+            calculate_game_offset(seed, RomVersion::Black, make_game_start()).unwrap();
+
+        // advance 20 から 8 回分の針を観測したとする
+        let observed = get_needle_pattern_at(seed.value(), game_offset + 20, 8);
+        let params = create_known_params(NeedlePattern::from_values(&observed));
+        let mut searcher = NeedleDatetimeSearcher::new(params).unwrap();
+
+        let mut results = Vec::new();
+        while !searcher.is_done() {
+            results.extend(searcher.next_batch(1000).results);
+        }
+
+        let found = results
+            .iter()
+            .find(|r| r.source.base_seed() == seed)
+            .expect("known boot not found");
+        // パターン末尾位置 (20 + 8 - 1)
+        assert_eq!(found.advance, 27);
+
+        let SeedOrigin::Startup {
+            datetime,
+            condition,
+            ..
+        } = &found.source
+        else {
+            panic!("Expected SeedOrigin::Startup")
+        };
+        assert_eq!(datetime.second, 11);
+        assert_eq!(condition.timer0, 0x0C79);
+    }
+
+    #[test]
+    fn test_generate_needle_search_tasks() {
+        let context = DatetimeSearchContext {
+            ds: DsConfig {
+                mac: [0x00, 0x09, 0xBF, 0x12, 0x34, 0x56],
+                hardware: Hardware::DsLite,
+                version: RomVersion::Black,
+                region: RomRegion::Jpn,
+            },
+            date_range: DateRangeParams {
+                start_year: 2023,
+                start_month: 1,
+                start_day: 1,
+                end_year: 2023,
+                end_month: 1,
+                end_day: 1,
+            },
+            time_range: TimeRangeParams {
+                hour_start: 0,
+                hour_end: 23,
+                minute_start: 0,
+                minute_end: 59,
+                second_start: 0,
+                second_end: 59,
+            },
+            ranges: vec![Timer0VCountRange {
+                timer0_min: 0x0C79,
+                timer0_max: 0x0C7A,
+                vcount_min: 0x60,
+                vcount_max: 0x60,
+            }],
+            key_spec: KeySpec::from_buttons(vec![]),
+            auto_ranges: false,
+        };
+
+        let tasks = generate_needle_search_tasks(
+            context,
+            make_game_start(),
+            NeedlePattern::from_values(&[0, 1, 2]),
+            0,
+            100,
+            2,
+        )
+        .unwrap();
+
+        // 2 combinations * 1 time chunk = 2 tasks
+        assert_eq!(tasks.len(), 2);
+        assert!(tasks.iter().all(|t| t.pattern.len() == 3));
+    }
+}
@@ -0,0 +1,409 @@
+//! ポケモン起動時刻検索
+//!
+//! 野生 / 固定シンボルの個体生成結果に対してフィルタを適用し、
+//! 条件を満たす起動時刻を検索する。
+
+use wasm_bindgen::prelude::*;
+
+use crate::generation::flows::generator::{
+    PokemonGenerator, validate_encounter_slots, validate_single_encounter_type,
+};
+use crate::types::{
+    DatetimeSearchContext, GenerationConfig, LcgSeed, PokemonDatetimeSearchBatch,
+    PokemonDatetimeSearchParams, PokemonDatetimeSearchResult, PokemonFilter,
+    PokemonGenerationParams, SeedOrigin, StartupCondition,
+};
+
+use super::base::DatetimeHashGenerator;
+use super::{calculate_time_chunks, expand_combinations, split_search_range};
+
+/// ポケモン起動時刻検索器
+#[wasm_bindgen]
+pub struct PokemonDatetimeSearcher {
+    /// 起動時刻とハッシュ値の生成器
+    generator: DatetimeHashGenerator,
+    /// 起動条件 (結果生成用)
+    condition: StartupCondition,
+    /// ポケモン生成パラメータ
+    pokemon_params: PokemonGenerationParams,
+    /// 生成共通設定
+    gen_config: GenerationConfig,
+    /// フィルター
+    filter: Option<PokemonFilter>,
+    // 進捗管理
+    total_count: u64,
+    processed_count: u64,
+}
+
+#[wasm_bindgen]
+impl PokemonDatetimeSearcher {
+    /// 新しい `PokemonDatetimeSearcher` を作成
+    ///
+    /// # Errors
+    ///
+    /// - エンカウントスロットが空の場合
+    /// - Static で複数スロットが指定された場合
+    /// - `encounter_type` が `DarkGrass` の場合
+    /// - `GameStartConfig` の検証失敗
+    /// - `time_range` のバリデーション失敗
+    #[wasm_bindgen(constructor)]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn new(params: PokemonDatetimeSearchParams) -> Result<PokemonDatetimeSearcher, String> {
+        validate_single_encounter_type(&params.pokemon_params)?;
+        validate_encounter_slots(&params.pokemon_params)?;
+
+        params
+            .gen_config
+            .game_start
+            .validate(params.gen_config.version)?;
+
+        let generator = DatetimeHashGenerator::new(
+            &params.ds,
+            &params.time_range,
+            &params.search_range,
+            params.condition,
+        )?;
+
+        // 進捗計算
+        let valid_seconds_per_day = params.time_range.count_valid_seconds();
+        let days = params.search_range.range_seconds.div_ceil(86400);
+        let total_count = u64::from(valid_seconds_per_day) * u64::from(days);
+
+        Ok(Self {
+            generator,
+            condition: params.condition,
+            pokemon_params: params.pokemon_params,
+            gen_config: params.gen_config,
+            filter: params.filter,
+            total_count,
+            processed_count: 0,
+        })
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn is_done(&self) -> bool {
+        self.generator.is_exhausted()
+    }
+
+    #[wasm_bindgen(getter)]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn progress(&self) -> f64 {
+        if self.generator.is_exhausted() {
+            return 1.0;
+        }
+        self.processed_count as f64 / self.total_count as f64
+    }
+
+    /// 次のバッチを検索
+    pub fn next_batch(&mut self, chunk_count: u32) -> PokemonDatetimeSearchBatch {
+        let mut results = Vec::new();
+        let mut remaining = u64::from(chunk_count);
+
+        while remaining > 0 && !self.generator.is_exhausted() {
+            let (entries, len) = self.generator.next_quad();
+            if len == 0 {
+                break;
+            }
+
+            let processed = u64::from(len);
+            self.processed_count += processed;
+            remaining = remaining.saturating_sub(processed);
+
+            for (datetime, hash_values) in entries.iter().take(len as usize) {
+                let lcg_seed = hash_values.to_lcg_seed();
+                let source = SeedOrigin::startup(lcg_seed, *datetime, self.condition);
+
+                // PokemonGenerator で個体生成
+                self.generate_and_filter(lcg_seed, source, &mut results);
+            }
+        }
+
+        PokemonDatetimeSearchBatch {
+            results,
+            processed_count: self.processed_count,
+            total_count: self.total_count,
+        }
+    }
+}
+
+impl PokemonDatetimeSearcher {
+    /// 指定 Seed から個体を生成し、フィルターに一致するものを追加
+    fn generate_and_filter(
+        &self,
+        base_seed: LcgSeed,
+        source: SeedOrigin,
+        results: &mut Vec<PokemonDatetimeSearchResult>,
+    ) {
+        let Ok(mut generator) =
+            PokemonGenerator::new(base_seed, source, &self.pokemon_params, &self.gen_config)
+        else {
+            return;
+        };
+
+        // advance 範囲内の個体を生成・フィルタリング
+        let advance_count = self.gen_config.max_advance - self.gen_config.user_offset;
+        for _ in 0..advance_count {
+            // 生成エラー (スロット不整合など) の位置はスキップ
+            let Some(pokemon) = generator.generate_next() else {
+                continue;
+            };
+
+            // フィルター判定
+            let matches = match &self.filter {
+                Some(filter) => filter.matches(&pokemon),
+                None => true,
+            };
+
+            if matches {
+                results.push(PokemonDatetimeSearchResult { pokemon });
+            }
+        }
+    }
+}
+
+// ===== タスク生成関数 =====
+
+/// タスク生成関数
+///
+/// `DatetimeSearchContext` から、
+/// 組み合わせ × 時間チャンク のクロス積でタスクを生成する。
+/// Worker 数を考慮して時間分割を行い、Worker 活用率を最大化する。
+///
+/// # Arguments
+/// - `context`: 検索コンテキスト (日付範囲、時刻範囲、Timer0/VCount/KeyMask 範囲)
+/// - `pokemon_params`: ポケモン生成パラメータ
+/// - `gen_config`: 生成共通設定
+/// - `filter`: フィルター (None の場合は全件返却)
+/// - `worker_count`: Worker 数
+///
+/// # Errors
+///
+/// `auto_ranges` 指定時に既定範囲が未収集の組み合わせの場合
+#[wasm_bindgen]
+#[allow(clippy::needless_pass_by_value)]
+#[allow(clippy::cast_possible_truncation)]
+pub fn generate_pokemon_search_tasks(
+    context: DatetimeSearchContext,
+    pokemon_params: PokemonGenerationParams,
+    gen_config: GenerationConfig,
+    filter: Option<PokemonFilter>,
+    worker_count: u32,
+) -> Result<Vec<PokemonDatetimeSearchParams>, String> {
+    let search_range = context.date_range.to_search_range();
+    let combinations = expand_combinations(&context)?;
+    let combo_count = combinations.len() as u32;
+
+    // 時間分割数を計算
+    let time_chunks = calculate_time_chunks(combo_count, worker_count);
+    let ranges = split_search_range(search_range, time_chunks);
+
+    // 組み合わせ × 時間チャンク のクロス積でタスク生成
+    Ok(combinations
+        .into_iter()
+        .flat_map(|condition| {
+            let ds = context.ds.clone();
+            let time_range = context.time_range.clone();
+            let pokemon_params = pokemon_params.clone();
+            let gen_config = gen_config.clone();
+            let filter = filter.clone();
+            ranges.iter().map(move |range| PokemonDatetimeSearchParams {
+                ds: ds.clone(),
+                time_range: time_range.clone(),
+                search_range: range.clone(),
+                condition,
+                pokemon_params: pokemon_params.clone(),
+                gen_config: gen_config.clone(),
+                filter: filter.clone(),
+            })
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{
+        DateRangeParams, DsConfig, EncounterMethod, EncounterModifiers, EncounterSlotConfig,
+        EncounterType, GameStartConfig, GenderRatio, Hardware, KeyMask, KeySpec, LeadAbilityEffect,
+        MemoryLinkState, RomRegion, RomVersion, SavePresence, SearchRangeParams, ShinyCharmState,
+        StartMode, StartupCondition, TimeRangeParams, Timer0VCountRange, TrainerInfo,
+    };
+
+    use super::*;
+
+    fn create_pokemon_params() -> PokemonGenerationParams {
+        PokemonGenerationParams {
+            trainer: TrainerInfo {
+                tid: 12345,
+                sid: 54321,
+            },
+            encounter_type: EncounterType::Normal,
+            encounter_method: EncounterMethod::Stationary,
+            lead_ability: LeadAbilityEffect::None,
+            slots: vec![EncounterSlotConfig {
+                species_id: 504,
+                level_min: 2,
+                level_max: 4,
+                gender_ratio: GenderRatio::F1M1,
+                has_held_item: false,
+                shiny_locked: false,
+            }],
+            seasonal_slots: None,
+            encounter_modifiers: EncounterModifiers::default(),
+        }
+    }
+
+    fn create_gen_config() -> GenerationConfig {
+        GenerationConfig {
+            version: RomVersion::Black,
+            game_start: GameStartConfig {
+                start_mode: StartMode::Continue,
+                save: SavePresence::WithSave,
+                memory_link: MemoryLinkState::Disabled,
+                shiny_charm: ShinyCharmState::NotObtained,
+            },
+            user_offset: 0,
+            max_advance: 10,
+        }
+    }
+
+    fn create_test_params() -> PokemonDatetimeSearchParams {
+        PokemonDatetimeSearchParams {
+            ds: DsConfig {
+                mac: [0x00, 0x09, 0xBF, 0x12, 0x34, 0x56],
+                hardware: Hardware::DsLite,
+                version: RomVersion::Black,
+                region: RomRegion::Jpn,
+            },
+            time_range: TimeRangeParams {
+                hour_start: 0,
+                hour_end: 23,
+                minute_start: 0,
+                minute_end: 59,
+                second_start: 0,
+                second_end: 59,
+            },
+            search_range: SearchRangeParams {
+                start_year: 2023,
+                start_month: 1,
+                start_day: 1,
+                start_second_offset: 0,
+                range_seconds: 60,
+            },
+            condition: StartupCondition::new(0x0C79, 0x5A, KeyMask::NONE),
+            pokemon_params: create_pokemon_params(),
+            gen_config: create_gen_config(),
+            filter: None,
+        }
+    }
+
+    #[test]
+    fn test_searcher_creation() {
+        let params = create_test_params();
+        let searcher = PokemonDatetimeSearcher::new(params);
+        assert!(searcher.is_ok());
+    }
+
+    #[test]
+    fn test_searcher_empty_slots_error() {
+        let mut params = create_test_params();
+        params.pokemon_params.slots.clear();
+        assert!(PokemonDatetimeSearcher::new(params).is_err());
+    }
+
+    #[test]
+    fn test_searcher_static_multiple_slots_error() {
+        let mut params = create_test_params();
+        params.pokemon_params.encounter_type = EncounterType::StaticSymbol;
+        let slot = params.pokemon_params.slots[0].clone();
+        params.pokemon_params.slots.push(slot);
+        assert!(PokemonDatetimeSearcher::new(params).is_err());
+    }
+
+    #[test]
+    fn test_searcher_progress() {
+        let params = create_test_params();
+        let searcher = PokemonDatetimeSearcher::new(params).unwrap();
+        assert!(searcher.progress() >= 0.0);
+        assert!(searcher.progress() <= 1.0);
+    }
+
+    #[test]
+    fn test_batch_without_filter_returns_all() {
+        let params = create_test_params();
+        let mut searcher = PokemonDatetimeSearcher::new(params).unwrap();
+
+        let batch = searcher.next_batch(1000);
+        assert!(searcher.is_done());
+        assert_eq!(batch.processed_count, 60);
+        // 60 秒 × 10 消費 (通常エンカウントは常に成功)
+        assert_eq!(batch.results.len(), 600);
+
+        // 結果は起動条件付き
+        for result in &batch.results {
+            assert!(matches!(result.pokemon.source, SeedOrigin::Startup { .. }));
+        }
+    }
+
+    #[test]
+    fn test_batch_with_filter() {
+        let mut params = create_test_params();
+        // 存在しない種族を指定 → 結果なし
+        params.filter = Some(PokemonFilter {
+            species_ids: Some(vec![1]),
+            ..PokemonFilter::any()
+        });
+        let mut searcher = PokemonDatetimeSearcher::new(params).unwrap();
+
+        let batch = searcher.next_batch(1000);
+        assert_eq!(batch.processed_count, 60);
+        assert!(batch.results.is_empty());
+    }
+
+    #[test]
+    fn test_generate_pokemon_search_tasks() {
+        let context = DatetimeSearchContext {
+            ds: DsConfig {
+                mac: [0x00, 0x09, 0xBF, 0x12, 0x34, 0x56],
+                hardware: Hardware::DsLite,
+                version: RomVersion::Black,
+                region: RomRegion::Jpn,
+            },
+            date_range: DateRangeParams {
+                start_year: 2023,
+                start_month: 1,
+                start_day: 1,
+                end_year: 2023,
+                end_month: 1,
+                end_day: 1,
+            },
+            time_range: TimeRangeParams {
+                hour_start: 0,
+                hour_end: 23,
+                minute_start: 0,
+                minute_end: 59,
+                second_start: 0,
+                second_end: 59,
+            },
+            ranges: vec![Timer0VCountRange {
+                timer0_min: 0x0C79,
+                timer0_max: 0x0C7A,
+                vcount_min: 0x5A,
+                vcount_max: 0x5A,
+            }],
+            key_spec: KeySpec::from_buttons(vec![]),
+            auto_ranges: false,
+        };
+
+        let tasks = generate_pokemon_search_tasks(
+            context,
+            create_pokemon_params(),
+            create_gen_config(),
+            None,
+            1,
+        )
+        .unwrap();
+
+        // 2 timer0 × 1 vcount × 1 key × 1 time chunk = 2 tasks
+        assert_eq!(tasks.len(), 2);
+    }
+}
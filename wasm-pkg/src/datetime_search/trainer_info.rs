@@ -151,6 +151,10 @@ impl TrainerInfoSearcher {
 /// - `filter`: 検索フィルタ
 /// - `game_start`: 起動設定
 /// - `worker_count`: Worker 数
+///
+/// # Errors
+///
+/// `auto_ranges` 指定時に既定範囲が未収集の組み合わせの場合
 #[wasm_bindgen]
 #[allow(clippy::needless_pass_by_value)]
 #[allow(clippy::cast_possible_truncation)]
@@ -159,9 +163,9 @@ pub fn generate_trainer_info_search_tasks(
     filter: TrainerInfoFilter,
     game_start: GameStartConfig,
     worker_count: u32,
-) -> Vec<TrainerInfoSearchParams> {
+) -> Result<Vec<TrainerInfoSearchParams>, String> {
     let search_range = context.date_range.to_search_range();
-    let combinations = expand_combinations(&context);
+    let combinations = expand_combinations(&context)?;
     let combo_count = combinations.len() as u32;
 
     // 時間分割数を計算
@@ -169,7 +173,7 @@ pub fn generate_trainer_info_search_tasks(
     let ranges = split_search_range(search_range, time_chunks);
 
     // 組み合わせ × 時間チャンク のクロス積でタスク生成
-    combinations
+    Ok(combinations
         .into_iter()
         .flat_map(|condition| {
             let filter = filter.clone();
@@ -184,7 +188,7 @@ pub fn generate_trainer_info_search_tasks(
                 game_start,
             })
         })
-        .collect()
+        .collect())
 }
 
 #[cfg(test)]
@@ -370,6 +374,7 @@ mod tests {
                 vcount_max: 0x5F,
             }],
             key_spec: KeySpec::from_buttons(vec![]),
+            auto_ranges: false,
         };
         let game_start = GameStartConfig {
             start_mode: StartMode::NewGame,
@@ -378,7 +383,7 @@ mod tests {
             shiny_charm: ShinyCharmState::NotObtained,
         };
 
-        let tasks = generate_trainer_info_search_tasks(context, filter, game_start, 2);
+        let tasks = generate_trainer_info_search_tasks(context, filter, game_start, 2).unwrap();
 
         // Timer0: 2パターン × VCount: 1パターン × KeyMask: 1パターン × time_chunks: 1 = 2タスク
         // (worker_count = 2, combo_count = 2 → time_chunks = 1)
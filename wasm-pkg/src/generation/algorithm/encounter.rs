@@ -1,9 +1,11 @@
 //! エンカウント処理アルゴリズム
 
-use crate::core::lcg::Lcg64;
+use crate::core::lcg::{Lcg64, roll_fraction};
+use crate::data::get_species_entry;
 use crate::types::{
-    EncounterResult, EncounterType, HeldItemSlot, ItemContent, LeadAbilityEffect,
-    MovingEncounterInfo, MovingEncounterLikelihood, RomVersion, SpecialEncounterDirection,
+    EncounterModifiers, EncounterResult, EncounterSlotConfig, EncounterType, FishingBite,
+    FluteEffect, HeldItemSlot, ItemContent, LeadAbilityEffect, MovingEncounterInfo,
+    MovingEncounterLikelihood, PokemonType, RomVersion, SpecialEncounterDirection,
     SpecialEncounterInfo,
 };
 
@@ -16,22 +18,39 @@ const BW2_ENCOUNTER_MIN_RATE: u32 = 5;
 /// BW2 最高エンカウント率 (十分な歩数後)
 const BW2_ENCOUNTER_MAX_RATE: u32 = 14;
 
+/// ビードロ効果をエンカウント率 (%) に適用
+///
+/// しろいビードロは 1.5 倍、くろいビードロは 0.5 倍 (いずれも切り捨て)。
+const fn apply_flute(rate: u32, flute: FluteEffect) -> u32 {
+    match flute {
+        FluteEffect::None => rate,
+        FluteEffect::White => rate * 3 / 2,
+        FluteEffect::Black => rate / 2,
+    }
+}
+
 /// 移動エンカウント判定
-pub fn check_moving_encounter(version: RomVersion, rand_value: u32) -> MovingEncounterLikelihood {
+///
+/// ビードロ使用時は各閾値にビードロ効果を適用して判定する。
+pub fn check_moving_encounter(
+    version: RomVersion,
+    rand_value: u32,
+    flute: FluteEffect,
+) -> MovingEncounterLikelihood {
     let percent = rand_to_percent(version, rand_value);
 
     match version {
         RomVersion::Black | RomVersion::White => {
-            if percent < BW_ENCOUNTER_THRESHOLD {
+            if percent < apply_flute(BW_ENCOUNTER_THRESHOLD, flute) {
                 MovingEncounterLikelihood::Guaranteed
             } else {
                 MovingEncounterLikelihood::NoEncounter
             }
         }
         RomVersion::Black2 | RomVersion::White2 => {
-            if percent < BW2_ENCOUNTER_MIN_RATE {
+            if percent < apply_flute(BW2_ENCOUNTER_MIN_RATE, flute) {
                 MovingEncounterLikelihood::Guaranteed
-            } else if percent < BW2_ENCOUNTER_MAX_RATE {
+            } else if percent < apply_flute(BW2_ENCOUNTER_MAX_RATE, flute) {
                 MovingEncounterLikelihood::Possible
             } else {
                 MovingEncounterLikelihood::NoEncounter
@@ -41,9 +60,13 @@ pub fn check_moving_encounter(version: RomVersion, rand_value: u32) -> MovingEnc
 }
 
 /// 移動エンカウント情報を生成
-pub fn generate_moving_encounter_info(version: RomVersion, rand_value: u32) -> MovingEncounterInfo {
+pub fn generate_moving_encounter_info(
+    version: RomVersion,
+    rand_value: u32,
+    flute: FluteEffect,
+) -> MovingEncounterInfo {
     MovingEncounterInfo {
-        likelihood: check_moving_encounter(version, rand_value),
+        likelihood: check_moving_encounter(version, rand_value, flute),
         rand_value,
     }
 }
@@ -99,7 +122,7 @@ pub fn is_special_encounter_type(encounter_type: EncounterType) -> bool {
 pub fn is_moving_encounter_type(encounter_type: EncounterType) -> bool {
     matches!(
         encounter_type,
-        EncounterType::Normal | EncounterType::Surfing
+        EncounterType::Normal | EncounterType::Surfing | EncounterType::DarkGrass
     )
 }
 
@@ -118,10 +141,59 @@ pub fn rand_to_percent(version: RomVersion, rand_value: u32) -> u32 {
 
 // ===== レベル決定 =====
 
+/// やる気 / プレッシャー / はりきり判定
+///
+/// 先頭が該当特性の場合のみ乱数を 1 消費し、50% で成功。
+pub fn level_boost_check(lcg: &mut Lcg64, lead_ability: LeadAbilityEffect) -> bool {
+    if lead_ability != LeadAbilityEffect::LevelBoost {
+        return false; // 乱数消費なし
+    }
+    let r = lcg.next().unwrap_or(0);
+    ((u64::from(r) * 2) >> 32) == 1
+}
+
+/// むしよけスプレーによるエンカウント回避判定 (乱数消費なし)
+///
+/// 使用中かつ出現レベルが先頭のレベルより低い場合に回避。
+pub fn repel_check(modifiers: EncounterModifiers, level: u8) -> bool {
+    modifiers
+        .repel_level
+        .is_some_and(|lead_level| level < lead_level)
+}
+
+/// いかく / するどいめによるエンカウント回避判定
+///
+/// 先頭が該当特性かつ出現レベルが先頭より 5 以上低い場合のみ乱数を 1 消費し、50% で回避。
+pub fn low_level_suppression_check(
+    lcg: &mut Lcg64,
+    lead_ability: LeadAbilityEffect,
+    level: u8,
+) -> bool {
+    let LeadAbilityEffect::Intimidate(lead_level) = lead_ability else {
+        return false; // 乱数消費なし
+    };
+    if lead_level.saturating_sub(level) < 5 {
+        return false; // 乱数消費なし
+    }
+    let r = lcg.next().unwrap_or(0);
+    ((u64::from(r) * 2) >> 32) == 1
+}
+
 /// レベル乱数値からレベルを決定
+///
+/// `level_boost` (やる気 / プレッシャー / はりきり成功) 時は最大レベル。
 #[inline]
 #[allow(clippy::cast_possible_truncation)]
-pub fn calculate_level(version: RomVersion, rand_value: u32, min_level: u8, max_level: u8) -> u8 {
+pub fn calculate_level(
+    version: RomVersion,
+    rand_value: u32,
+    min_level: u8,
+    max_level: u8,
+    level_boost: bool,
+) -> u8 {
+    if level_boost {
+        return max_level;
+    }
     if min_level == max_level {
         return min_level;
     }
@@ -190,17 +262,51 @@ pub fn calculate_encounter_slot(
     let percent = rand_to_percent(version, rand_value);
 
     match encounter_type {
-        // 揺れる草むら / 土煙 / 橋の影 は通常エンカウントと同じ12スロット分布
+        // 揺れる草むら / 土煙 / 橋の影 / 濃い草むら は通常エンカウントと同じ12スロット分布
         EncounterType::Normal
         | EncounterType::ShakingGrass
         | EncounterType::DustCloud
-        | EncounterType::PokemonShadow => normal_encounter_slot(percent),
+        | EncounterType::PokemonShadow
+        | EncounterType::DarkGrass => normal_encounter_slot(percent),
         EncounterType::Surfing | EncounterType::SurfingBubble => surfing_encounter_slot(percent),
         EncounterType::Fishing | EncounterType::FishingBubble => fishing_encounter_slot(percent),
         _ => 0, // 固定エンカウント
     }
 }
 
+/// スロット決定 (じりょく / せいでんき考慮)
+///
+/// スロット決定乱数を 1 消費する。`forced_type` 指定時 (じりょく / せいでんき成功) かつ
+/// 該当タイプのスロットが存在する場合は乱数を 1 追加消費し、該当スロットから等確率で選び直す。
+#[allow(clippy::cast_possible_truncation)]
+pub fn determine_encounter_slot(
+    lcg: &mut Lcg64,
+    encounter_type: EncounterType,
+    version: RomVersion,
+    slots: &[EncounterSlotConfig],
+    forced_type: Option<PokemonType>,
+) -> usize {
+    let slot_rand = lcg.next().unwrap_or(0);
+    let slot_idx = (calculate_encounter_slot(encounter_type, slot_rand, version) as usize)
+        .min(slots.len() - 1);
+
+    let Some(pokemon_type) = forced_type else {
+        return slot_idx;
+    };
+    let matching: Vec<usize> = slots
+        .iter()
+        .enumerate()
+        .filter(|(_, slot)| get_species_entry(slot.species_id).has_type(pokemon_type))
+        .map(|(i, _)| i)
+        .collect();
+    if matching.is_empty() {
+        return slot_idx; // 該当タイプなし: 追加消費なし
+    }
+
+    let reroll_rand = lcg.next().unwrap_or(0);
+    matching[roll_fraction(reroll_rand, matching.len() as u32) as usize]
+}
+
 /// 砂煙の結果を判定
 pub(crate) fn dust_cloud_result(slot_value: u32) -> EncounterResult {
     if slot_value <= 69 {
@@ -251,6 +357,22 @@ pub fn fishing_success(rand_value: u32) -> bool {
     ((u64::from(rand_value) * 2) >> 32) == 0
 }
 
+/// 釣りの当たり判定
+///
+/// きゅうばん / ねんちゃく先頭時は判定自体が行われず、乱数を消費せず確定で当たる。
+/// それ以外は乱数を 1 消費し、`fishing_success` で判定。
+pub fn fishing_bite_check(lcg: &mut Lcg64, lead_ability: LeadAbilityEffect) -> FishingBite {
+    if lead_ability == LeadAbilityEffect::SuctionCups {
+        return FishingBite::Guaranteed; // 乱数消費なし
+    }
+    let r = lcg.next().unwrap_or(0);
+    if fishing_success(r) {
+        FishingBite::Hooked
+    } else {
+        FishingBite::Missed
+    }
+}
+
 /// 持ち物判定
 pub fn determine_held_item_slot(
     version: RomVersion,
@@ -376,17 +498,252 @@ mod tests {
     #[test]
     fn test_calculate_level_same_min_max() {
         // min == max の場合は固定レベル
-        assert_eq!(calculate_level(RomVersion::Black, 0, 10, 10), 10);
-        assert_eq!(calculate_level(RomVersion::Black2, 0xFFFF_FFFF, 10, 10), 10);
+        assert_eq!(calculate_level(RomVersion::Black, 0, 10, 10, false), 10);
+        assert_eq!(
+            calculate_level(RomVersion::Black2, 0xFFFF_FFFF, 10, 10, false),
+            10
+        );
+    }
+
+    fn make_slot(species_id: u16) -> EncounterSlotConfig {
+        EncounterSlotConfig {
+            species_id,
+            level_min: 20,
+            level_max: 20,
+            gender_ratio: crate::types::GenderRatio::Genderless,
+            has_held_item: false,
+            shiny_locked: false,
+        }
+    }
+
+    #[test]
+    fn test_determine_encounter_slot_forced_type() {
+        // スロット 10 のみコイル (でんき/はがね)、スロット 11 のみバチュル (むし/でんき)
+        let mut slots: Vec<_> = (0..12).map(|_| make_slot(524)).collect();
+        slots[10] = make_slot(81);
+        slots[11] = make_slot(595);
+        let seed = 0x1234_5678_9ABC_DEF0;
+
+        // タイプ指定なし: 1 消費、通常のスロット決定
+        let mut lcg = Lcg64::from_raw(seed);
+        let mut expected = Lcg64::from_raw(seed);
+        let normal_idx = calculate_encounter_slot(
+            EncounterType::Normal,
+            expected.next().unwrap(),
+            RomVersion::Black,
+        ) as usize;
+        let idx = determine_encounter_slot(
+            &mut lcg,
+            EncounterType::Normal,
+            RomVersion::Black,
+            &slots,
+            None,
+        );
+        assert_eq!(idx, normal_idx);
+        assert_eq!(lcg.current_seed(), expected.current_seed());
+
+        // はがね: 該当スロットは 10 のみ、2 消費
+        let mut lcg = Lcg64::from_raw(seed);
+        let idx = determine_encounter_slot(
+            &mut lcg,
+            EncounterType::Normal,
+            RomVersion::Black,
+            &slots,
+            Some(PokemonType::Steel),
+        );
+        assert_eq!(idx, 10);
+        expected.next();
+        assert_eq!(lcg.current_seed(), expected.current_seed());
+
+        // でんき: 該当スロットは 10, 11
+        let mut lcg = Lcg64::from_raw(seed);
+        let idx = determine_encounter_slot(
+            &mut lcg,
+            EncounterType::Normal,
+            RomVersion::Black,
+            &slots,
+            Some(PokemonType::Electric),
+        );
+        assert!(idx == 10 || idx == 11);
+
+        // 該当タイプなし: 追加消費なし
+        let mut lcg = Lcg64::from_raw(seed);
+        let idx = determine_encounter_slot(
+            &mut lcg,
+            EncounterType::Normal,
+            RomVersion::Black,
+            &slots,
+            Some(PokemonType::Dragon),
+        );
+        assert_eq!(idx, normal_idx);
+        let mut expected = Lcg64::from_raw(seed);
+        expected.next();
+        assert_eq!(lcg.current_seed(), expected.current_seed());
+    }
+
+    #[test]
+    fn test_calculate_level_boost() {
+        // やる気 / プレッシャー / はりきり成功時は乱数に関わらず最大レベル
+        assert_eq!(calculate_level(RomVersion::Black, 0, 10, 15, true), 15);
+        assert_eq!(calculate_level(RomVersion::Black2, 0, 10, 15, true), 15);
+    }
+
+    #[test]
+    fn test_level_boost_check() {
+        // 該当特性以外は乱数消費なし
+        let mut lcg = Lcg64::from_raw(0x1234_5678_9ABC_DEF0);
+        let initial_seed = lcg.current_seed();
+        assert!(!level_boost_check(&mut lcg, LeadAbilityEffect::None));
+        assert_eq!(lcg.current_seed(), initial_seed);
+
+        // 該当特性は 1 消費し、最上位ビット 1 で成功
+        let mut expected = lcg.clone();
+        let r = expected.next().unwrap();
+        assert_eq!(
+            level_boost_check(&mut lcg, LeadAbilityEffect::LevelBoost),
+            r >= 0x8000_0000
+        );
+        assert_eq!(lcg.current_seed(), expected.current_seed());
+    }
+
+    #[test]
+    fn test_check_moving_encounter_flute() {
+        // BW: 閾値 9% → しろ 13% / くろ 4%
+        // rand_to_percent(BW) = (rand * 0xFFFF / 0x290) >> 32
+        let bw_rand = |percent: u64| ((percent << 32) * 0x290).div_ceil(0xFFFF) as u32;
+        let v = RomVersion::Black;
+        for (percent, flute, expected) in [
+            (8, FluteEffect::None, MovingEncounterLikelihood::Guaranteed),
+            (9, FluteEffect::None, MovingEncounterLikelihood::NoEncounter),
+            (
+                12,
+                FluteEffect::White,
+                MovingEncounterLikelihood::Guaranteed,
+            ),
+            (
+                13,
+                FluteEffect::White,
+                MovingEncounterLikelihood::NoEncounter,
+            ),
+            (3, FluteEffect::Black, MovingEncounterLikelihood::Guaranteed),
+            (
+                4,
+                FluteEffect::Black,
+                MovingEncounterLikelihood::NoEncounter,
+            ),
+        ] {
+            let rand = bw_rand(percent);
+            assert_eq!(rand_to_percent(v, rand), percent as u32);
+            assert_eq!(
+                check_moving_encounter(v, rand, flute),
+                expected,
+                "{percent} {flute:?}"
+            );
+        }
+
+        // BW2: 閾値 5% / 14% → しろ 7% / 21%、くろ 2% / 7%
+        let bw2_rand = |percent: u64| ((percent << 32).div_ceil(100)) as u32;
+        let v = RomVersion::Black2;
+        for (percent, flute, expected) in [
+            (6, FluteEffect::White, MovingEncounterLikelihood::Guaranteed),
+            (20, FluteEffect::White, MovingEncounterLikelihood::Possible),
+            (
+                21,
+                FluteEffect::White,
+                MovingEncounterLikelihood::NoEncounter,
+            ),
+            (1, FluteEffect::Black, MovingEncounterLikelihood::Guaranteed),
+            (6, FluteEffect::Black, MovingEncounterLikelihood::Possible),
+            (
+                7,
+                FluteEffect::Black,
+                MovingEncounterLikelihood::NoEncounter,
+            ),
+        ] {
+            let rand = bw2_rand(percent);
+            assert_eq!(rand_to_percent(v, rand), percent as u32);
+            assert_eq!(
+                check_moving_encounter(v, rand, flute),
+                expected,
+                "{percent} {flute:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_repel_check() {
+        let none = EncounterModifiers::default();
+        assert!(!repel_check(none, 1));
+
+        let repel = EncounterModifiers {
+            repel_level: Some(30),
+            ..EncounterModifiers::default()
+        };
+        assert!(repel_check(repel, 29));
+        assert!(!repel_check(repel, 30));
+        assert!(!repel_check(repel, 31));
+    }
+
+    #[test]
+    fn test_fishing_bite_check() {
+        // きゅうばん / ねんちゃく: 乱数消費なしで確定
+        let mut lcg = Lcg64::from_raw(0x1234_5678_9ABC_DEF0);
+        let initial_seed = lcg.current_seed();
+        assert_eq!(
+            fishing_bite_check(&mut lcg, LeadAbilityEffect::SuctionCups),
+            FishingBite::Guaranteed
+        );
+        assert_eq!(lcg.current_seed(), initial_seed);
+
+        // それ以外: 1 消費し、最上位ビット 0 で成功
+        let mut expected = lcg.clone();
+        let r = expected.next().unwrap();
+        let bite = fishing_bite_check(&mut lcg, LeadAbilityEffect::None);
+        if r < 0x8000_0000 {
+            assert_eq!(bite, FishingBite::Hooked);
+        } else {
+            assert_eq!(bite, FishingBite::Missed);
+        }
+        assert_eq!(lcg.current_seed(), expected.current_seed());
+    }
+
+    #[test]
+    fn test_low_level_suppression_check() {
+        let lead = LeadAbilityEffect::Intimidate(30);
+        let seed = 0x1234_5678_9ABC_DEF0;
+
+        // 先頭との差が 5 未満: 乱数消費なし
+        let mut lcg = Lcg64::from_raw(seed);
+        assert!(!low_level_suppression_check(&mut lcg, lead, 26));
+        assert_eq!(lcg.current_seed(), Lcg64::from_raw(seed).current_seed());
+
+        // 差が 5 以上: 1 消費し、最上位ビット 1 で回避
+        let mut lcg = Lcg64::from_raw(seed);
+        let mut expected = Lcg64::from_raw(seed);
+        let r = expected.next().unwrap();
+        assert_eq!(
+            low_level_suppression_check(&mut lcg, lead, 25),
+            r >= 0x8000_0000
+        );
+        assert_eq!(lcg.current_seed(), expected.current_seed());
+
+        // 該当特性以外: 乱数消費なし
+        let mut lcg = Lcg64::from_raw(seed);
+        assert!(!low_level_suppression_check(
+            &mut lcg,
+            LeadAbilityEffect::None,
+            5
+        ));
+        assert_eq!(lcg.current_seed(), Lcg64::from_raw(seed).current_seed());
     }
 
     #[test]
     fn test_calculate_level_bw() {
         // BW: percent % range
         // rand = 0 → percent = 0 → offset = 0
-        assert_eq!(calculate_level(RomVersion::Black, 0, 10, 15), 10);
+        assert_eq!(calculate_level(RomVersion::Black, 0, 10, 15, false), 10);
         // rand = 0x8000_0000 → percent ≈ 50 → offset = 50 % 6 = 2
-        let level = calculate_level(RomVersion::Black, 0x8000_0000, 10, 15);
+        let level = calculate_level(RomVersion::Black, 0x8000_0000, 10, 15, false);
         assert!((10..=15).contains(&level));
     }
 
@@ -394,10 +751,16 @@ mod tests {
     fn test_calculate_level_bw2() {
         // BW2: (rand * range) >> 32
         // rand = 0 → offset = 0
-        assert_eq!(calculate_level(RomVersion::Black2, 0, 10, 15), 10);
+        assert_eq!(calculate_level(RomVersion::Black2, 0, 10, 15, false), 10);
         // rand = 0xFFFF_FFFF → offset = 5
-        assert_eq!(calculate_level(RomVersion::Black2, 0xFFFF_FFFF, 10, 15), 15);
+        assert_eq!(
+            calculate_level(RomVersion::Black2, 0xFFFF_FFFF, 10, 15, false),
+            15
+        );
         // rand = 0x8000_0000 → offset = 3 (half of 6)
-        assert_eq!(calculate_level(RomVersion::Black2, 0x8000_0000, 10, 15), 13);
+        assert_eq!(
+            calculate_level(RomVersion::Black2, 0x8000_0000, 10, 15, false),
+            13
+        );
     }
 }
@@ -14,15 +14,19 @@ pub(crate) use crate::core::offset::{calculate_game_offset, calculate_mt_offset}
 
 // その他のアルゴリズムは crate 内部のみ (使用されている関数のみ re-export)
 pub(crate) use encounter::{
-    calculate_encounter_slot, calculate_level, determine_held_item_slot,
+    calculate_level, determine_encounter_slot, determine_held_item_slot,
     dust_cloud_item_table_consume, dust_cloud_result, encounter_type_supports_held_item,
-    fishing_success, generate_moving_encounter_info, generate_special_encounter_info,
-    is_moving_encounter_type, is_special_encounter_type, pokemon_shadow_item_table_consume,
-    pokemon_shadow_result, rand_to_percent,
+    fishing_bite_check, generate_moving_encounter_info, generate_special_encounter_info,
+    is_moving_encounter_type, is_special_encounter_type, level_boost_check,
+    low_level_suppression_check, pokemon_shadow_item_table_consume, pokemon_shadow_result,
+    rand_to_percent, repel_check,
 };
 pub(crate) use iv::apply_inheritance;
 pub use iv::{generate_rng_ivs_with_offset, generate_rng_ivs_with_offset_x4};
-pub(crate) use nature::{determine_egg_nature, determine_nature, nature_roll, perform_sync_check};
+pub(crate) use nature::{
+    LeadCheckResult, cute_charm_gender_force, determine_egg_nature, determine_nature, nature_roll,
+    perform_lead_check, perform_sync_check,
+};
 pub(crate) use npc::resolve_egg_npc_advance;
 pub(crate) use pid::{
     apply_shiny_lock, generate_egg_pid_with_reroll, generate_event_pid,
@@ -1,7 +1,11 @@
-//! 性格決定・シンクロアルゴリズム
+//! 性格決定・シンクロ・メロメロボディアルゴリズム
 
 use crate::core::lcg::Lcg64;
-use crate::types::{EncounterType, EverstonePlan, LeadAbilityEffect, Nature};
+use crate::types::{
+    EncounterType, EverstonePlan, Gender, GenderRatio, LeadAbilityEffect, Nature, PokemonType,
+};
+
+use super::pid::GenderForce;
 
 /// 乱数から性格 ID を決定 (0-24)
 #[inline]
@@ -16,6 +20,12 @@ pub fn sync_check(r: u32) -> bool {
     ((u64::from(r) * 2) >> 32) == 1
 }
 
+/// メロメロボディ成否判定 (2/3 で成功)
+#[inline]
+pub fn cute_charm_check(r: u32) -> bool {
+    ((u64::from(r) * 3) >> 32) != 0
+}
+
 #[inline]
 fn everstone_inheritance_check(r: u32) -> bool {
     ((u64::from(r) * 2) >> 32) == 1
@@ -31,6 +41,7 @@ pub fn supports_sync(encounter_type: EncounterType) -> bool {
             | EncounterType::ShakingGrass
             | EncounterType::DustCloud
             | EncounterType::PokemonShadow
+            | EncounterType::DarkGrass
             | EncounterType::SurfingBubble
             | EncounterType::FishingBubble
             | EncounterType::StaticSymbol
@@ -38,20 +49,89 @@ pub fn supports_sync(encounter_type: EncounterType) -> bool {
     )
 }
 
-/// シンクロ判定を実行
-/// 対応エンカウントでは常に乱数を消費
-pub fn perform_sync_check(
+/// 先頭特性判定の結果
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LeadCheckResult {
+    /// シンクロ成功
+    pub sync_success: bool,
+    /// メロメロボディ成功
+    pub cute_charm_success: bool,
+    /// じりょく / せいでんき成功時に優先されるタイプ
+    pub forced_type: Option<PokemonType>,
+}
+
+/// 先頭特性判定 (シンクロ / メロメロボディ / じりょく / せいでんき) を実行
+///
+/// いずれの特性も同じ乱数 1 つで判定する。対応エンカウントでは常に乱数を消費。
+pub fn perform_lead_check(
     lcg: &mut Lcg64,
     encounter_type: EncounterType,
     lead_ability: LeadAbilityEffect,
-) -> bool {
+) -> LeadCheckResult {
     if !supports_sync(encounter_type) {
-        return false; // 乱数消費なし
+        return LeadCheckResult::default(); // 乱数消費なし
     }
 
     let r = lcg.next().unwrap_or(0); // 対応エンカウントでは常に消費
 
-    matches!(lead_ability, LeadAbilityEffect::Synchronize(_)) && sync_check(r)
+    match lead_ability {
+        LeadAbilityEffect::Synchronize(_) => LeadCheckResult {
+            sync_success: sync_check(r),
+            ..LeadCheckResult::default()
+        },
+        LeadAbilityEffect::CuteCharm(_) => LeadCheckResult {
+            cute_charm_success: cute_charm_check(r),
+            ..LeadCheckResult::default()
+        },
+        // シンクロと同じ 50% 判定
+        LeadAbilityEffect::MagnetPull => LeadCheckResult {
+            forced_type: sync_check(r).then_some(PokemonType::Steel),
+            ..LeadCheckResult::default()
+        },
+        LeadAbilityEffect::Static => LeadCheckResult {
+            forced_type: sync_check(r).then_some(PokemonType::Electric),
+            ..LeadCheckResult::default()
+        },
+        _ => LeadCheckResult::default(),
+    }
+}
+
+/// シンクロ判定を実行
+/// 対応エンカウントでは常に乱数を消費
+pub fn perform_sync_check(
+    lcg: &mut Lcg64,
+    encounter_type: EncounterType,
+    lead_ability: LeadAbilityEffect,
+) -> bool {
+    perform_lead_check(lcg, encounter_type, lead_ability).sync_success
+}
+
+/// メロメロボディ成功時の性別固定指定を取得
+///
+/// 先頭と異なる性別に固定する。先頭が性別不明、または出現個体の性別が
+/// 固定 (♂のみ / ♀のみ / 性別不明) の場合は効果なし。
+pub fn cute_charm_gender_force(
+    lead_ability: LeadAbilityEffect,
+    cute_charm_success: bool,
+    ratio: GenderRatio,
+) -> Option<GenderForce> {
+    let LeadAbilityEffect::CuteCharm(lead_gender) = lead_ability else {
+        return None;
+    };
+    if !cute_charm_success
+        || matches!(
+            ratio,
+            GenderRatio::Genderless | GenderRatio::MaleOnly | GenderRatio::FemaleOnly
+        )
+    {
+        return None;
+    }
+    let gender = match lead_gender {
+        Gender::Male => Gender::Female,
+        Gender::Female => Gender::Male,
+        Gender::Genderless => return None,
+    };
+    Some(GenderForce { gender, ratio })
 }
 
 /// 性格決定 (シンクロ考慮)
@@ -126,6 +206,81 @@ mod tests {
         assert!(!sync_check(0x0000_0000));
     }
 
+    #[test]
+    fn test_cute_charm_check() {
+        // ((r as u64) * 3) >> 32 != 0 で成功 (2/3)
+        assert!(!cute_charm_check(0x0000_0000));
+        assert!(!cute_charm_check(0x5555_5555));
+        assert!(cute_charm_check(0x5555_5556));
+        assert!(cute_charm_check(0xFFFF_FFFF));
+    }
+
+    #[test]
+    fn test_perform_lead_check_consumes_once() {
+        let lead = LeadAbilityEffect::CuteCharm(Gender::Male);
+        let mut lcg = Lcg64::from_raw(0x1234_5678_9ABC_DEF0);
+        let mut expected = lcg.clone();
+        let r = expected.next().unwrap();
+
+        let result = perform_lead_check(&mut lcg, EncounterType::Normal, lead);
+
+        assert_eq!(lcg.current_seed(), expected.current_seed());
+        assert_eq!(result.cute_charm_success, cute_charm_check(r));
+        assert!(!result.sync_success);
+    }
+
+    #[test]
+    fn test_perform_lead_check_magnet_pull_and_static() {
+        for (lead, pokemon_type) in [
+            (LeadAbilityEffect::MagnetPull, PokemonType::Steel),
+            (LeadAbilityEffect::Static, PokemonType::Electric),
+        ] {
+            let mut lcg = Lcg64::from_raw(0x1234_5678_9ABC_DEF0);
+            let r = lcg.clone().next().unwrap();
+
+            let result = perform_lead_check(&mut lcg, EncounterType::Normal, lead);
+
+            assert_eq!(result.forced_type, sync_check(r).then_some(pokemon_type));
+            assert!(!result.sync_success && !result.cute_charm_success);
+        }
+    }
+
+    #[test]
+    fn test_cute_charm_gender_force() {
+        let lead = LeadAbilityEffect::CuteCharm(Gender::Male);
+        assert_eq!(
+            cute_charm_gender_force(lead, true, GenderRatio::F1M1),
+            Some(GenderForce {
+                gender: Gender::Female,
+                ratio: GenderRatio::F1M1
+            })
+        );
+        assert_eq!(
+            cute_charm_gender_force(lead, false, GenderRatio::F1M1),
+            None
+        );
+        assert_eq!(
+            cute_charm_gender_force(lead, true, GenderRatio::Genderless),
+            None
+        );
+        assert_eq!(
+            cute_charm_gender_force(lead, true, GenderRatio::MaleOnly),
+            None
+        );
+        assert_eq!(
+            cute_charm_gender_force(
+                LeadAbilityEffect::CuteCharm(Gender::Genderless),
+                true,
+                GenderRatio::F1M1
+            ),
+            None
+        );
+        assert_eq!(
+            cute_charm_gender_force(LeadAbilityEffect::None, true, GenderRatio::F1M1),
+            None
+        );
+    }
+
     #[test]
     fn test_everstone_inheritance_check() {
         assert!(everstone_inheritance_check(0x8000_0000));
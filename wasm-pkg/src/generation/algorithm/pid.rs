@@ -1,7 +1,16 @@
 //! PID 生成・色違い判定アルゴリズム
 
 use crate::core::lcg::Lcg64;
-use crate::types::{Pid, ShinyType, TrainerInfo};
+use crate::types::{Gender, GenderRatio, Pid, ShinyType, TrainerInfo};
+
+/// 性別固定指定 (メロメロボディ)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GenderForce {
+    /// 固定する性別
+    pub gender: Gender,
+    /// 出現個体の性別比
+    pub ratio: GenderRatio,
+}
 
 /// 基本 PID 生成 (XOR 0x10000)
 /// BW/BW2 統一仕様: 固定・野生共通
@@ -24,12 +33,42 @@ fn apply_id_correction(pid: Pid, trainer: TrainerInfo) -> Pid {
     }
 }
 
+/// 性別補正処理
+/// 性格値下位 8bit を指定性別の範囲に収まる値で置き換える
+#[inline]
+#[allow(clippy::cast_possible_truncation)]
+fn apply_gender_force(pid: Pid, r: u32, force: GenderForce) -> Pid {
+    let threshold = u64::from(force.ratio.to_threshold());
+    let gender_value = match force.gender {
+        // threshold..=252
+        Gender::Male => ((u64::from(r) * (253 - threshold)) >> 32) + threshold,
+        // 1..threshold
+        Gender::Female => ((u64::from(r) * (threshold - 1)) >> 32) + 1,
+        Gender::Genderless => return pid,
+    };
+    Pid((pid.raw() & 0xFFFF_FF00) | gender_value as u32)
+}
+
 /// 野生/固定/徘徊 PID 生成 (ID補正あり)
 #[inline]
 fn generate_wild_pid(r: u32, trainer: TrainerInfo) -> Pid {
     apply_id_correction(generate_base_pid(r), trainer)
 }
 
+/// 野生 PID 1 回分の試行
+/// 性別固定時は PID 乱数の直後に性別補正用の乱数を 1 消費し、ID 補正前に適用
+fn next_wild_pid(lcg: &mut Lcg64, trainer: TrainerInfo, gender_force: Option<GenderForce>) -> Pid {
+    let r = lcg.next().unwrap_or(0);
+    match gender_force {
+        Some(force) => {
+            let gender_rand = lcg.next().unwrap_or(0);
+            let pid = apply_gender_force(generate_base_pid(r), gender_rand, force);
+            apply_id_correction(pid, trainer)
+        }
+        None => generate_wild_pid(r, trainer),
+    }
+}
+
 /// イベント/御三家 PID 生成 (ID補正なし)
 #[inline]
 pub fn generate_event_pid(r: u32) -> Pid {
@@ -56,14 +95,16 @@ pub fn apply_shiny_lock(pid: Pid, trainer: TrainerInfo) -> Pid {
 
 /// ひかるおまもり付き野生 PID 生成
 /// 最大 `reroll_count` 回リロール
+///
+/// `gender_force` 指定時は各試行で性別補正を行う (試行ごとに 1 消費追加)。
 pub fn generate_wild_pid_with_reroll(
     lcg: &mut Lcg64,
     trainer: TrainerInfo,
     reroll_count: u8,
+    gender_force: Option<GenderForce>,
 ) -> (Pid, ShinyType) {
     for _ in 0..reroll_count {
-        let r = lcg.next().unwrap_or(0);
-        let pid = generate_wild_pid(r, trainer);
+        let pid = next_wild_pid(lcg, trainer, gender_force);
         let shiny = pid.shiny_type(trainer);
         if shiny != ShinyType::None {
             return (pid, shiny);
@@ -71,15 +112,14 @@ pub fn generate_wild_pid_with_reroll(
     }
 
     // 最後の試行
-    let r = lcg.next().unwrap_or(0);
-    let pid = generate_wild_pid(r, trainer);
+    let pid = next_wild_pid(lcg, trainer, gender_force);
     let shiny = pid.shiny_type(trainer);
     (pid, shiny)
 }
 
 /// 孵化 PID 生成 (リロール付き、1乱数方式)
 ///
-/// 国際孵化時は `reroll_count = 5`。
+/// 国際孵化で 5 回、ひかるおまもり所持で 2 回のリロールを加算した `reroll_count` を指定する。
 pub fn generate_egg_pid_with_reroll(
     lcg: &mut Lcg64,
     trainer: TrainerInfo,
@@ -182,6 +222,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_apply_gender_force() {
+        let pid = Pid(0x1234_5678_u32);
+        for ratio in [GenderRatio::F1M7, GenderRatio::F1M1, GenderRatio::F3M1] {
+            for r in [0x0000_0000, 0x8000_0000, 0xFFFF_FFFF] {
+                for gender in [Gender::Male, Gender::Female] {
+                    let forced = apply_gender_force(pid, r, GenderForce { gender, ratio });
+                    assert_eq!(forced.gender(ratio), gender, "{ratio:?} {r:#x}");
+                    assert_eq!(forced.raw() & 0xFFFF_FF00, pid.raw() & 0xFFFF_FF00);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_generate_wild_pid_with_gender_force_consumption() {
+        let trainer = make_trainer(12345, 54321);
+        let force = GenderForce {
+            gender: Gender::Female,
+            ratio: GenderRatio::F1M1,
+        };
+        // 性別補正あり: PID(1) + 性別(1) = 2 消費
+        let mut lcg = Lcg64::from_raw(0x1234_5678_9ABC_DEF0);
+        let mut expected = lcg.clone();
+        let (pid, _) = generate_wild_pid_with_reroll(&mut lcg, trainer, 0, Some(force));
+        expected.jump(2);
+        assert_eq!(lcg.current_seed(), expected.current_seed());
+        assert_eq!(pid.gender(GenderRatio::F1M1), Gender::Female);
+    }
+
     #[test]
     fn test_generate_event_pid() {
         // イベント PID = r ^ 0x10000 (ID補正なし)
@@ -3,12 +3,20 @@
 use crate::core::lcg::Lcg64;
 use crate::core::roll_fraction;
 use crate::generation::algorithm::{determine_egg_nature, generate_egg_pid_with_reroll};
-use crate::types::{AbilitySlot, EggGenerationParams, Gender, InheritanceSlot, Pid};
+use crate::types::{
+    AbilitySlot, EggGenerationParams, Gender, InheritanceSlot, Pid, PowerItem, ShinyCharmState,
+};
 
 use super::types::RawEggData;
 
 /// 卵の個体生成 (参照実装準拠)
-pub fn generate_egg(lcg: &mut Lcg64, params: &EggGenerationParams) -> RawEggData {
+///
+/// `shiny_charm` は BW2 のひかるおまもり所持状態 (PID リロール回数に反映)。
+pub fn generate_egg(
+    lcg: &mut Lcg64,
+    params: &EggGenerationParams,
+    shiny_charm: ShinyCharmState,
+) -> RawEggData {
     // 1. 性格決定
     let nature = determine_egg_nature(lcg, params.everstone);
 
@@ -20,8 +28,9 @@ pub fn generate_egg(lcg: &mut Lcg64, params: &EggGenerationParams) -> RawEggData
         let _ = lcg.next();
     }
 
-    // 4. 遺伝スロット決定
-    let inheritance = determine_inheritance(lcg);
+    // 4. 遺伝スロット決定 (パワー系アイテム所持時は確定遺伝を含む)
+    let inheritance =
+        determine_inheritance(lcg, params.parent_male_item, params.parent_female_item);
 
     // 5. ニドランロール
     let nidoran_roll = if params.nidoran_flag {
@@ -31,8 +40,8 @@ pub fn generate_egg(lcg: &mut Lcg64, params: &EggGenerationParams) -> RawEggData
         None
     };
 
-    // 6. PID 生成 (リロール付き、国際孵化時は 5 回)
-    let reroll_count = if params.masuda_method { 5 } else { 0 };
+    // 6. PID 生成 (リロール付き、国際孵化で 5 回、ひかるおまもりで 2 回追加)
+    let reroll_count = egg_pid_reroll_count(params.masuda_method, shiny_charm);
     let (pid, shiny_type) = generate_egg_pid_with_reroll(lcg, params.trainer, reroll_count);
 
     // 7. 性別判定
@@ -56,12 +65,56 @@ pub fn generate_egg(lcg: &mut Lcg64, params: &EggGenerationParams) -> RawEggData
     }
 }
 
+/// 孵化 PID のリロール回数
+///
+/// 国際孵化で +5、ひかるおまもり所持で +2 (併用時は 7)。
+fn egg_pid_reroll_count(masuda_method: bool, shiny_charm: ShinyCharmState) -> u8 {
+    let masuda = if masuda_method { 5 } else { 0 };
+    let charm = match shiny_charm {
+        ShinyCharmState::Obtained => 2,
+        ShinyCharmState::NotObtained => 0,
+    };
+    masuda + charm
+}
+
 /// 遺伝スロット決定
-fn determine_inheritance(lcg: &mut Lcg64) -> [InheritanceSlot; 3] {
+///
+/// パワー系アイテム所持時は、所持親から対応ステータスを確定で遺伝する (先頭スロット)。
+/// - 片親のみ所持: 乱数消費なし
+/// - 両親とも所持: 1 消費し、50% でどちらの親のアイテムを適用するか決定
+///
+/// 残りのスロットは通常通りランダムに決定する。
+fn determine_inheritance(
+    lcg: &mut Lcg64,
+    male_item: Option<PowerItem>,
+    female_item: Option<PowerItem>,
+) -> [InheritanceSlot; 3] {
     let mut slots = [InheritanceSlot::default(); 3];
     let mut used = [false; 6];
 
-    for slot in &mut slots {
+    // パワー系アイテムによる確定遺伝 (0 = Male, 1 = Female)
+    let forced = match (male_item, female_item) {
+        (None, None) => None,
+        (Some(item), None) => Some(InheritanceSlot::new_forced(item.stat_index(), 0)),
+        (None, Some(item)) => Some(InheritanceSlot::new_forced(item.stat_index(), 1)),
+        (Some(male), Some(female)) => {
+            if (lcg.next().unwrap_or(0) >> 31) == 1 {
+                Some(InheritanceSlot::new_forced(female.stat_index(), 1))
+            } else {
+                Some(InheritanceSlot::new_forced(male.stat_index(), 0))
+            }
+        }
+    };
+
+    let random_slots = if let Some(forced) = forced {
+        used[usize::from(forced.stat)] = true;
+        slots[0] = forced;
+        &mut slots[1..]
+    } else {
+        &mut slots[..]
+    };
+
+    for slot in random_slots {
         // 遺伝先ステータスと遺伝元親を1セットで決定 (ステータス重複時はセットごと破棄)
         let (stat, parent) = loop {
             let r = lcg.next().unwrap_or(0);
@@ -107,7 +160,9 @@ fn determine_ability_slot(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::{AbilitySlot, EverstonePlan, GenderRatio, Ivs, Nature, TrainerInfo};
+    use crate::types::{
+        AbilitySlot, EverstonePlan, GenderRatio, Ivs, Nature, ShinyType, TrainerInfo,
+    };
 
     fn make_params() -> EggGenerationParams {
         EggGenerationParams {
@@ -123,8 +178,11 @@ mod tests {
             masuda_method: false,
             parent_male: Ivs::new(31, 31, 31, 0, 0, 0),
             parent_female: Ivs::new(0, 0, 0, 31, 31, 31),
+            parent_male_item: None,
+            parent_female_item: None,
             consider_npc: false,
             species_id: None,
+            parent_species: None,
         }
     }
 
@@ -133,7 +191,7 @@ mod tests {
         let mut lcg = Lcg64::from_raw(0x1234_5678_9ABC_DEF0);
         let params = make_params();
 
-        let egg = generate_egg(&mut lcg, &params);
+        let egg = generate_egg(&mut lcg, &params, ShinyCharmState::NotObtained);
 
         // 基本的な検証: ability_slot は First, Second, Hidden のいずれか
         assert!(matches!(
@@ -150,7 +208,7 @@ mod tests {
             ..make_params()
         };
 
-        let egg = generate_egg(&mut lcg, &params);
+        let egg = generate_egg(&mut lcg, &params, ShinyCharmState::NotObtained);
 
         // かわらずのいし使用時のテスト
         assert!((egg.nature as u8) < 25);
@@ -160,7 +218,7 @@ mod tests {
     fn test_determine_inheritance() {
         let mut lcg = Lcg64::from_raw(0x1234_5678_9ABC_DEF0);
 
-        let slots = determine_inheritance(&mut lcg);
+        let slots = determine_inheritance(&mut lcg, None, None);
 
         // 3つのスロットがあること
         assert_eq!(slots.len(), 3);
@@ -178,7 +236,7 @@ mod tests {
     fn test_determine_inheritance_consumes_parent_roll_for_duplicate_stat() {
         let mut lcg = Lcg64::from_raw(0x1234_5678_9ABC_DEF0);
 
-        let slots = determine_inheritance(&mut lcg);
+        let slots = determine_inheritance(&mut lcg, None, None);
 
         assert_eq!(
             slots,
@@ -194,7 +252,7 @@ mod tests {
     fn test_determine_inheritance_parent_bit_mapping() {
         let mut lcg = Lcg64::from_raw(0x0000_0000_0000_0000);
 
-        let slots = determine_inheritance(&mut lcg);
+        let slots = determine_inheritance(&mut lcg, None, None);
 
         assert_eq!(
             slots,
@@ -206,6 +264,87 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_determine_inheritance_single_power_item() {
+        // 片親のみ所持: 確定遺伝は乱数消費なし、残り 2 スロットは通常抽選
+        let seed = 0x1234_5678_9ABC_DEF0;
+        for (male_item, female_item, expected) in [
+            (
+                Some(PowerItem::PowerBracer),
+                None,
+                InheritanceSlot::new_forced(1, 0),
+            ),
+            (
+                None,
+                Some(PowerItem::PowerAnklet),
+                InheritanceSlot::new_forced(5, 1),
+            ),
+        ] {
+            let mut lcg = Lcg64::from_raw(seed);
+            let slots = determine_inheritance(&mut lcg, male_item, female_item);
+
+            assert_eq!(slots[0], expected);
+            for slot in &slots[1..] {
+                assert!(!slot.forced);
+                assert_ne!(slot.stat, expected.stat);
+            }
+            assert_ne!(slots[1].stat, slots[2].stat);
+
+            // 最初の乱数は 2 スロット目の抽選に使用される
+            let mut expected_lcg = Lcg64::from_raw(seed);
+            let r = expected_lcg.next().unwrap();
+            let first_roll = roll_fraction(r, 6) as u8;
+            if first_roll != expected.stat {
+                assert_eq!(slots[1].stat, first_roll);
+            }
+        }
+    }
+
+    #[test]
+    fn test_determine_inheritance_both_power_items() {
+        // 両親とも所持: 1 消費で適用する親を決定
+        let male_item = Some(PowerItem::PowerWeight);
+        let female_item = Some(PowerItem::PowerLens);
+        for (seed, expected) in [
+            // 最上位ビット 1: メス親 (とくこう)
+            (
+                0x9E37_79B9_7F4A_7C15,
+                [
+                    InheritanceSlot::new_forced(3, 1),
+                    InheritanceSlot::new(5, 1),
+                    InheritanceSlot::new(1, 0),
+                ],
+            ),
+            // 最上位ビット 0: オス親 (HP)
+            (
+                0x3C6E_F372_FE94_F82A,
+                [
+                    InheritanceSlot::new_forced(0, 0),
+                    InheritanceSlot::new(5, 1),
+                    InheritanceSlot::new(4, 1),
+                ],
+            ),
+        ] {
+            let mut lcg = Lcg64::from_raw(seed);
+            let slots = determine_inheritance(&mut lcg, male_item, female_item);
+            assert_eq!(slots, expected, "{seed:#018X}");
+        }
+    }
+
+    #[test]
+    fn test_generate_egg_power_item_inherits_stat() {
+        // パワーアンクル (♀親): 素早さは必ずメス親から遺伝
+        let params = EggGenerationParams {
+            parent_female_item: Some(PowerItem::PowerAnklet),
+            ..make_params()
+        };
+        for seed in [0x0000_0000_0000_0000, 0x1234_5678_9ABC_DEF0] {
+            let mut lcg = Lcg64::from_raw(seed);
+            let egg = generate_egg(&mut lcg, &params, ShinyCharmState::NotObtained);
+            assert_eq!(egg.inheritance[0], InheritanceSlot::new_forced(5, 1));
+        }
+    }
+
     #[test]
     fn test_determine_gender_from_pid() {
         use crate::types::GenderRatio;
@@ -270,8 +409,16 @@ mod tests {
             ..make_params()
         };
 
-        let _ = generate_egg(&mut lcg_no_ditto, &params_no_ditto);
-        let _ = generate_egg(&mut lcg_with_ditto, &params_with_ditto);
+        let _ = generate_egg(
+            &mut lcg_no_ditto,
+            &params_no_ditto,
+            ShinyCharmState::NotObtained,
+        );
+        let _ = generate_egg(
+            &mut lcg_with_ditto,
+            &params_with_ditto,
+            ShinyCharmState::NotObtained,
+        );
 
         // メタモン使用時は追加で 1 消費されるため、シードが異なる
         assert_ne!(lcg_no_ditto.current_seed(), lcg_with_ditto.current_seed());
@@ -300,11 +447,52 @@ mod tests {
         let mut lcg1 = Lcg64::from_raw(0x0000_0000_0000_0000);
         let mut lcg2 = Lcg64::from_raw(0xFFFF_FFFF_FFFF_FFFF);
 
-        let egg1 = generate_egg(&mut lcg1, &params);
-        let egg2 = generate_egg(&mut lcg2, &params);
+        let egg1 = generate_egg(&mut lcg1, &params, ShinyCharmState::NotObtained);
+        let egg2 = generate_egg(&mut lcg2, &params, ShinyCharmState::NotObtained);
 
         // ニドランフラグ有効時は性別が Male または Female のみ (Genderless 不可)
         assert!(egg1.gender == Gender::Male || egg1.gender == Gender::Female);
         assert!(egg2.gender == Gender::Male || egg2.gender == Gender::Female);
     }
+
+    #[test]
+    fn test_egg_pid_reroll_count() {
+        use ShinyCharmState::{NotObtained, Obtained};
+        assert_eq!(egg_pid_reroll_count(false, NotObtained), 0);
+        assert_eq!(egg_pid_reroll_count(true, NotObtained), 5);
+        assert_eq!(egg_pid_reroll_count(false, Obtained), 2);
+        assert_eq!(egg_pid_reroll_count(true, Obtained), 7);
+    }
+
+    #[test]
+    fn test_shiny_charm_egg_reroll_consumption() {
+        // ひかるおまもり所持時、色違いでなければ PID 試行 2 回分多く消費する
+        // (初期 Seed, 国際孵化, 消費数 (なし), 消費数 (所持))
+        for (seed, masuda_method, plain_consumed, charm_consumed) in [
+            (0x0000_0000_0000_0000, false, 11, 13),
+            (0x3C6E_F372_FE94_F82A, false, 9, 11),
+            (0x0000_0000_0000_0000, true, 16, 18),
+        ] {
+            let params = EggGenerationParams {
+                masuda_method,
+                ..make_params()
+            };
+            for (shiny_charm, consumed) in [
+                (ShinyCharmState::NotObtained, plain_consumed),
+                (ShinyCharmState::Obtained, charm_consumed),
+            ] {
+                let mut lcg = Lcg64::from_raw(seed);
+                let egg = generate_egg(&mut lcg, &params, shiny_charm);
+                assert_eq!(egg.shiny_type, ShinyType::None);
+
+                let mut expected = Lcg64::from_raw(seed);
+                expected.jump(consumed);
+                assert_eq!(
+                    lcg.current_seed(),
+                    expected.current_seed(),
+                    "{seed:#018X} {shiny_charm:?}"
+                );
+            }
+        }
+    }
 }
@@ -3,13 +3,14 @@
 //! Iterator パターンで連続的にタマゴを生成。
 
 use crate::core::lcg::Lcg64;
+use crate::data::resolve_egg_species;
 use crate::generation::algorithm::{
     apply_inheritance, calc_report_needle_direction, calculate_game_offset, calculate_mt_offset,
     generate_rng_ivs_with_offset, resolve_egg_npc_advance,
 };
 use crate::types::{
     EggGenerationParams, EncounterType, GeneratedEggData, GenerationConfig, Ivs, LcgSeed,
-    SeedOrigin,
+    SeedOrigin, ShinyCharmState,
 };
 
 use super::super::egg::generate_egg;
@@ -22,6 +23,7 @@ pub struct EggGenerator {
     rng_ivs: Ivs,
     source: SeedOrigin,
     params: EggGenerationParams,
+    shiny_charm: ShinyCharmState,
 }
 
 impl EggGenerator {
@@ -35,7 +37,7 @@ impl EggGenerator {
     /// * `config` - 共通設定
     ///
     /// # Errors
-    /// 無効な起動設定の場合にエラーを返す。
+    /// 無効な起動設定、またはタマゴを作れない両親の組み合わせの場合にエラーを返す。
     pub fn new(
         base_seed: LcgSeed,
         source: SeedOrigin,
@@ -43,6 +45,7 @@ impl EggGenerator {
         config: &GenerationConfig,
     ) -> Result<Self, String> {
         let game_offset = calculate_game_offset(base_seed, config.version, config.game_start)?;
+        let params = resolve_parent_species(params)?;
         let mt_offset = calculate_mt_offset(config.version, EncounterType::Egg);
         let mt_seed = base_seed.derive_mt_seed();
         let rng_ivs = generate_rng_ivs_with_offset(mt_seed, mt_offset, false);
@@ -59,7 +62,8 @@ impl EggGenerator {
             current_advance: config.user_offset,
             rng_ivs,
             source,
-            params: params.clone(),
+            params,
+            shiny_charm: config.game_start.shiny_charm,
         })
     }
 
@@ -93,7 +97,7 @@ impl EggGenerator {
         };
 
         let mut gen_lcg = gen_lcg;
-        let raw = generate_egg(&mut gen_lcg, &self.params);
+        let raw = generate_egg(&mut gen_lcg, &self.params, self.shiny_charm);
 
         // 遺伝適用
         let final_ivs = apply_inheritance(
@@ -124,12 +128,28 @@ impl EggGenerator {
     }
 }
 
+/// `parent_species` 指定時、両親の種族から孵化個体の種族情報を埋めたパラメータを返す
+fn resolve_parent_species(params: &EggGenerationParams) -> Result<EggGenerationParams, String> {
+    let Some(parents) = params.parent_species else {
+        return Ok(params.clone());
+    };
+    let resolution = resolve_egg_species(&parents)?;
+    Ok(EggGenerationParams {
+        species_id: Some(resolution.species_id),
+        gender_ratio: resolution.gender_ratio,
+        nidoran_flag: resolution.nidoran_flag,
+        uses_ditto: resolution.uses_ditto,
+        ..params.clone()
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::types::{
-        AbilitySlot, EverstonePlan, GameStartConfig, GenderRatio, Ivs, LcgSeed, MemoryLinkState,
-        RomVersion, SavePresence, SeedOrigin, ShinyCharmState, StartMode, TrainerInfo,
+        AbilitySlot, BreedingParents, EverstonePlan, GameStartConfig, GenderRatio, Ivs, LcgSeed,
+        MemoryLinkState, RomVersion, SavePresence, SeedOrigin, ShinyCharmState, StartMode,
+        TrainerInfo,
     };
 
     fn make_game_start() -> GameStartConfig {
@@ -175,8 +195,11 @@ mod tests {
             masuda_method: false,
             parent_male: Ivs::new(31, 31, 31, 0, 0, 0),
             parent_female: Ivs::new(0, 0, 0, 31, 31, 31),
+            parent_male_item: None,
+            parent_female_item: None,
             consider_npc: false,
             species_id: None,
+            parent_species: None,
         };
         let config = make_config();
 
@@ -210,8 +233,11 @@ mod tests {
             masuda_method: false,
             parent_male: Ivs::new(31, 31, 31, 0, 0, 0),
             parent_female: Ivs::new(0, 0, 0, 31, 31, 31),
+            parent_male_item: None,
+            parent_female_item: None,
             consider_npc: true,
             species_id: None,
+            parent_species: None,
         };
         let config = make_config();
 
@@ -224,4 +250,48 @@ mod tests {
         assert!(egg.core.ivs.hp <= 31);
         assert!(egg.margin_frames.is_some());
     }
+
+    #[test]
+    fn test_egg_generator_resolves_parent_species() {
+        let base_seed = LcgSeed::new(0x1234_5678_9ABC_DEF0);
+        let params = EggGenerationParams {
+            trainer: make_trainer(),
+            everstone: EverstonePlan::None,
+            female_ability_slot: AbilitySlot::First,
+            uses_ditto: false,
+            gender_ratio: GenderRatio::F1M1,
+            nidoran_flag: false,
+            masuda_method: false,
+            parent_male: Ivs::new(31, 31, 31, 0, 0, 0),
+            parent_female: Ivs::new(0, 0, 0, 31, 31, 31),
+            parent_male_item: None,
+            parent_female_item: None,
+            consider_npc: false,
+            species_id: None,
+            // メタモン × ガブリアス → フカマル (F1M1)
+            parent_species: Some(BreedingParents {
+                male_species: 132,
+                female_species: 445,
+                incense: false,
+            }),
+        };
+        let config = make_config();
+
+        let mut g = EggGenerator::new(base_seed, make_source(base_seed), &params, &config).unwrap();
+        assert!(g.params.uses_ditto);
+        assert_eq!(g.params.gender_ratio, GenderRatio::F1M1);
+        let egg = g.generate_next();
+        assert_eq!(egg.core.species_id, 443);
+
+        // タマゴを作れない組み合わせはエラー
+        let params = EggGenerationParams {
+            parent_species: Some(BreedingParents {
+                male_species: 445,
+                female_species: 16,
+                incense: false,
+            }),
+            ..params
+        };
+        assert!(EggGenerator::new(base_seed, make_source(base_seed), &params, &config).is_err());
+    }
 }
@@ -11,6 +11,7 @@
 //!
 //! - `generate_pokemon_list` - ポケモン一括生成 (解決済み Seed 対応、フィルタ対応)
 //! - `generate_egg_list` - タマゴ一括生成 (解決済み Seed 対応、フィルタ対応)
+//! - `generate_double_encounter_list` - ダブルバトル (濃い草むら) 一括生成
 
 mod egg;
 mod pokemon;
@@ -21,8 +22,9 @@ pub use pokemon::PokemonGenerator;
 use wasm_bindgen::prelude::*;
 
 use crate::types::{
-    EggFilter, EggGenerationParams, EncounterType, GeneratedEggData, GeneratedPokemonData,
-    GenerationConfig, PokemonFilter, PokemonGenerationParams, SeedOrigin,
+    EggFilter, EggGenerationParams, EncounterSlotConfig, EncounterType,
+    GeneratedDoubleEncounterData, GeneratedEggData, GeneratedPokemonData, GenerationConfig,
+    PokemonFilter, PokemonGenerationParams, SeedOrigin,
 };
 
 // ===== 公開 API =====
@@ -43,6 +45,8 @@ use crate::types::{
 ///
 /// - 起動設定が無効な場合
 /// - エンカウントスロットが空の場合
+/// - Static で複数スロットが指定された場合
+/// - `encounter_type` が `DarkGrass` の場合 (`generate_double_encounter_list` を使用)
 #[wasm_bindgen]
 #[allow(clippy::needless_pass_by_value)]
 pub fn generate_pokemon_list(
@@ -52,21 +56,57 @@ pub fn generate_pokemon_list(
     filter: Option<PokemonFilter>,
 ) -> Result<Vec<GeneratedPokemonData>, JsValue> {
     // バリデーション
-    if params.slots.is_empty() {
-        return Err(JsValue::from_str("Encounter slots is empty"));
-    }
+    validate_single_encounter_type(&params).map_err(|e| JsValue::from_str(&e))?;
+    validate_encounter_slots(&params).map_err(|e| JsValue::from_str(&e))?;
 
-    // Static の場合はスロットが1件のみ許容
-    if is_static_encounter(params.encounter_type) && params.slots.len() > 1 {
+    // 各 Seed に対して生成
+    let results: Result<Vec<_>, String> = origins
+        .into_iter()
+        .map(|origin| generate_pokemon_for_seed(origin, &params, &config, filter.as_ref()))
+        .collect();
+
+    results
+        .map(|v| v.into_iter().flatten().collect())
+        .map_err(|e| JsValue::from_str(&e))
+}
+
+/// ダブルバトル (濃い草むら) 一括生成 (公開 API)
+///
+/// 各消費位置でダブルバトルが発生した場合の 2 体組を返す。
+/// `filter` が Some の場合、いずれか 1 体が条件に合致する組のみ返却。
+///
+/// # Arguments
+///
+/// * `origins` - 解決済み Seed リスト
+/// * `params` - 生成パラメータ (`encounter_type` は `DarkGrass`)
+/// * `config` - 共通設定 (バージョン、オフセット、検索範囲)
+/// * `filter` - ポケモンフィルタ (None の場合は全件返却)
+///
+/// # Errors
+///
+/// - `encounter_type` が `DarkGrass` でない場合
+/// - 起動設定が無効な場合
+/// - エンカウントスロットが空の場合
+#[wasm_bindgen]
+#[allow(clippy::needless_pass_by_value)]
+pub fn generate_double_encounter_list(
+    origins: Vec<SeedOrigin>,
+    params: PokemonGenerationParams,
+    config: GenerationConfig,
+    filter: Option<PokemonFilter>,
+) -> Result<Vec<GeneratedDoubleEncounterData>, JsValue> {
+    // バリデーション
+    if params.encounter_type != EncounterType::DarkGrass {
         return Err(JsValue::from_str(
-            "Static encounter requires exactly one slot",
+            "Double encounter requires DarkGrass encounter type",
         ));
     }
+    validate_encounter_slots(&params).map_err(|e| JsValue::from_str(&e))?;
 
     // 各 Seed に対して生成
     let results: Result<Vec<_>, String> = origins
         .into_iter()
-        .map(|origin| generate_pokemon_for_seed(origin, &params, &config, filter.as_ref()))
+        .map(|origin| generate_double_encounter_for_seed(origin, &params, &config, filter.as_ref()))
         .collect();
 
     results
@@ -109,7 +149,7 @@ pub fn generate_egg_list(
 }
 
 /// エンカウント種別が Static かどうか判定
-pub(super) fn is_static_encounter(encounter_type: EncounterType) -> bool {
+pub(crate) fn is_static_encounter(encounter_type: EncounterType) -> bool {
     matches!(
         encounter_type,
         EncounterType::StaticSymbol
@@ -120,6 +160,49 @@ pub(super) fn is_static_encounter(encounter_type: EncounterType) -> bool {
     )
 }
 
+/// エンカウントスロットの検証
+///
+/// `seasonal_slots` 指定時は季節別スロットを検証する。
+/// 積雪等でエンカウントのない季節があるため、空の季節は全季節が空の場合のみエラーとする。
+///
+/// # Errors
+///
+/// - エンカウントスロットが空の場合
+/// - Static で複数スロットが指定された場合
+pub(crate) fn validate_encounter_slots(params: &PokemonGenerationParams) -> Result<(), String> {
+    let slot_sets: Vec<&[EncounterSlotConfig]> = match &params.seasonal_slots {
+        Some(seasonal) => seasonal.iter().collect(),
+        None => vec![&params.slots],
+    };
+
+    if slot_sets.iter().all(|slots| slots.is_empty()) {
+        return Err("Encounter slots is empty".into());
+    }
+
+    // Static の場合はスロットが1件のみ許容
+    if is_static_encounter(params.encounter_type) && slot_sets.iter().any(|slots| slots.len() > 1) {
+        return Err("Static encounter requires exactly one slot".into());
+    }
+    Ok(())
+}
+
+/// 1 体ずつ生成するエンカウント種別か検証
+///
+/// `DarkGrass` はダブルバトル (2 体組) の消費順序で生成されるため、
+/// 1 体ずつの生成経路では扱わない。
+///
+/// # Errors
+///
+/// `encounter_type` が `DarkGrass` の場合
+pub(crate) fn validate_single_encounter_type(
+    params: &PokemonGenerationParams,
+) -> Result<(), String> {
+    if params.encounter_type == EncounterType::DarkGrass {
+        return Err("DarkGrass requires double encounter generation".into());
+    }
+    Ok(())
+}
+
 /// 単一 Seed に対してポケモンを生成 (内部関数)
 fn generate_pokemon_for_seed(
     origin: SeedOrigin,
@@ -127,6 +210,11 @@ fn generate_pokemon_for_seed(
     config: &GenerationConfig,
     filter: Option<&PokemonFilter>,
 ) -> Result<Vec<GeneratedPokemonData>, String> {
+    // 季節によりエンカウントのない Seed は生成対象外
+    if params.slots_for(&origin).is_empty() {
+        return Ok(Vec::new());
+    }
+
     let base_seed = origin.base_seed();
     let mut generator = PokemonGenerator::new(base_seed, origin, params, config)?;
 
@@ -135,6 +223,29 @@ fn generate_pokemon_for_seed(
     Ok(apply_pokemon_filter(pokemons, filter))
 }
 
+/// 単一 Seed に対してダブルバトルを生成 (内部関数)
+fn generate_double_encounter_for_seed(
+    origin: SeedOrigin,
+    params: &PokemonGenerationParams,
+    config: &GenerationConfig,
+    filter: Option<&PokemonFilter>,
+) -> Result<Vec<GeneratedDoubleEncounterData>, String> {
+    // 季節によりエンカウントのない Seed は生成対象外
+    if params.slots_for(&origin).is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let base_seed = origin.base_seed();
+    let mut generator = PokemonGenerator::new(base_seed, origin, params, config)?;
+
+    let count = config.max_advance - config.user_offset;
+    let pairs = generator.take_double(count);
+    Ok(match filter {
+        Some(f) => pairs.into_iter().filter(|p| f.matches_double(p)).collect(),
+        None => pairs,
+    })
+}
+
 /// 単一 Seed に対してタマゴを生成 (内部関数)
 fn generate_egg_for_seed(
     origin: SeedOrigin,
@@ -177,9 +288,10 @@ mod tests {
     use super::*;
     use crate::generation::algorithm::calculate_mt_offset;
     use crate::types::{
-        EncounterMethod, EncounterSlotConfig, EncounterType, GameStartConfig, GenderRatio, LcgSeed,
-        LeadAbilityEffect, MemoryLinkState, Nature, Pid, RomVersion, SavePresence, SeedOrigin,
-        ShinyCharmState, StartMode, TrainerInfo,
+        CoreDataFilter, Datetime, EncounterMethod, EncounterModifiers, EncounterSlotConfig,
+        EncounterType, GameStartConfig, GenderRatio, KeyMask, LcgSeed, LeadAbilityEffect,
+        MemoryLinkState, Nature, Pid, RomVersion, SavePresence, Season, SeasonalSlots, SeedOrigin,
+        ShinyCharmState, StartMode, StartupCondition, TrainerInfo,
     };
 
     fn make_source(seed: LcgSeed) -> SeedOrigin {
@@ -264,6 +376,8 @@ mod tests {
             lead_ability: LeadAbilityEffect::Synchronize(Nature::Adamant),
 
             slots: slots.clone(),
+            seasonal_slots: None,
+            encounter_modifiers: EncounterModifiers::default(),
         };
 
         let source = make_source(initial_seed);
@@ -326,6 +440,8 @@ mod tests {
             lead_ability: LeadAbilityEffect::None,
 
             slots: slots.clone(),
+            seasonal_slots: None,
+            encounter_modifiers: EncounterModifiers::default(),
         };
 
         let source = make_source(initial_seed);
@@ -384,6 +500,8 @@ mod tests {
             lead_ability: LeadAbilityEffect::None,
 
             slots: slots.clone(),
+            seasonal_slots: None,
+            encounter_modifiers: EncounterModifiers::default(),
         };
 
         let source = make_source(initial_seed);
@@ -442,6 +560,8 @@ mod tests {
             lead_ability: LeadAbilityEffect::None,
 
             slots: slots.clone(),
+            seasonal_slots: None,
+            encounter_modifiers: EncounterModifiers::default(),
         };
 
         let source = make_source(initial_seed);
@@ -500,6 +620,8 @@ mod tests {
             lead_ability: LeadAbilityEffect::None,
 
             slots: slots.clone(),
+            seasonal_slots: None,
+            encounter_modifiers: EncounterModifiers::default(),
         };
 
         let source = make_source(initial_seed);
@@ -518,4 +640,250 @@ mod tests {
             pokemon.core.nature as u8, expected_nature
         );
     }
+
+    fn make_single_slot(species_id: u16) -> Vec<EncounterSlotConfig> {
+        vec![EncounterSlotConfig {
+            species_id,
+            level_min: 30,
+            level_max: 30,
+            gender_ratio: GenderRatio::F1M1,
+            has_held_item: false,
+            shiny_locked: false,
+        }]
+    }
+
+    fn make_seasonal_params() -> PokemonGenerationParams {
+        PokemonGenerationParams {
+            trainer: TrainerInfo { tid: 0, sid: 0 },
+            encounter_type: EncounterType::Normal,
+            encounter_method: EncounterMethod::Stationary,
+            lead_ability: LeadAbilityEffect::None,
+            slots: vec![],
+            seasonal_slots: Some(SeasonalSlots {
+                spring: make_single_slot(585),
+                summer: make_single_slot(586),
+                autumn: make_single_slot(587),
+                winter: make_single_slot(588),
+            }),
+            encounter_modifiers: EncounterModifiers::default(),
+        }
+    }
+
+    /// 季節別スロット: 起動日時の月に応じたスロットが選択される
+    #[test]
+    fn test_generate_pokemon_list_seasonal_slots() {
+        let seed = LcgSeed::new(0x1234_5678_9ABC_DEF0);
+        let condition = StartupCondition::new(0x0C79, 0x60, KeyMask::NONE);
+        let origins: Vec<SeedOrigin> = [5, 6, 11, 12]
+            .into_iter()
+            .map(|month| {
+                SeedOrigin::startup(seed, Datetime::new(2011, month, 1, 0, 0, 0), condition)
+            })
+            .collect();
+        let config = GenerationConfig {
+            version: RomVersion::Black,
+            game_start: GameStartConfig {
+                start_mode: StartMode::Continue,
+                save: SavePresence::WithSave,
+                memory_link: MemoryLinkState::Disabled,
+                shiny_charm: ShinyCharmState::NotObtained,
+            },
+            user_offset: 0,
+            max_advance: 1,
+        };
+
+        let results =
+            generate_pokemon_list(origins, make_seasonal_params(), config.clone(), None).unwrap();
+        let species: Vec<u16> = results.iter().map(|p| p.core.species_id).collect();
+        // 5 月: 春, 6 月: 夏, 11 月: 秋, 12 月: 冬
+        assert_eq!(species, vec![585, 586, 587, 588]);
+        let seasons: Vec<Option<Season>> = results.iter().map(|p| p.season).collect();
+        assert_eq!(
+            seasons,
+            vec![
+                Some(Season::Spring),
+                Some(Season::Summer),
+                Some(Season::Autumn),
+                Some(Season::Winter),
+            ]
+        );
+
+        // 起動日時を持たない Seed は `slots` を使用 (空のためエラー)
+        let mut params = make_seasonal_params();
+        assert!(PokemonGenerator::new(seed, SeedOrigin::seed(seed), &params, &config).is_err());
+
+        // エンカウントのない季節の Seed は結果なし
+        params.seasonal_slots.as_mut().unwrap().winter.clear();
+        let winter = SeedOrigin::startup(seed, Datetime::new(2011, 12, 1, 0, 0, 0), condition);
+        assert!(
+            generate_pokemon_list(vec![winter], params, config, None)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_generate_double_encounter_list() {
+        let seed = LcgSeed::new(0x1234_5678_9ABC_DEF0);
+        let config = GenerationConfig {
+            version: RomVersion::Black,
+            game_start: GameStartConfig {
+                start_mode: StartMode::Continue,
+                save: SavePresence::WithSave,
+                memory_link: MemoryLinkState::Disabled,
+                shiny_charm: ShinyCharmState::NotObtained,
+            },
+            user_offset: 0,
+            max_advance: 5,
+        };
+        let mut params = PokemonGenerationParams {
+            encounter_type: EncounterType::DarkGrass,
+            slots: make_single_slot(585),
+            seasonal_slots: None,
+            encounter_modifiers: EncounterModifiers::default(),
+            ..make_seasonal_params()
+        };
+
+        let results = generate_double_encounter_list(
+            vec![make_source(seed)],
+            params.clone(),
+            config.clone(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(results.len(), 5);
+        for (i, data) in results.iter().enumerate() {
+            assert_eq!(data.advance, i as u32);
+            assert_eq!(data.first.species_id, 585);
+            assert_eq!(data.second.species_id, 585);
+            // 2 体目の個体値は 1 体目に続く MT 位置から生成
+            assert_ne!(data.first.ivs, data.second.ivs);
+        }
+
+        // フィルタ: いずれか 1 体が一致する組のみ
+        let filter_nature = results[2].second.nature;
+        let filter = PokemonFilter {
+            base: CoreDataFilter {
+                natures: Some(vec![filter_nature]),
+                ..CoreDataFilter::any()
+            },
+            ..PokemonFilter::any()
+        };
+        let filtered = generate_double_encounter_list(
+            vec![make_source(seed)],
+            params.clone(),
+            config.clone(),
+            Some(filter.clone()),
+        )
+        .unwrap();
+        let expected: Vec<u32> = results
+            .iter()
+            .filter(|d| d.first.nature == filter_nature || d.second.nature == filter_nature)
+            .map(|d| d.advance)
+            .collect();
+        let advances: Vec<u32> = filtered.iter().map(|d| d.advance).collect();
+        assert!(advances.contains(&2));
+        assert_eq!(advances, expected);
+
+        // DarkGrass 以外ではダブルバトルを生成しない
+        params.encounter_type = EncounterType::Normal;
+        let mut generator =
+            PokemonGenerator::new(seed, make_source(seed), &params, &config).unwrap();
+        assert!(generator.take_double(5).is_empty());
+    }
+
+    /// 濃い草むら + Moving: 移動エンカウント判定 (消費 2) の後に 2 体組を生成
+    #[test]
+    fn test_double_encounter_moving() {
+        let seed = LcgSeed::new(0x1234_5678_9ABC_DEF0);
+        let config = GenerationConfig {
+            version: RomVersion::White,
+            game_start: GameStartConfig {
+                start_mode: StartMode::Continue,
+                save: SavePresence::WithSave,
+                memory_link: MemoryLinkState::Disabled,
+                shiny_charm: ShinyCharmState::NotObtained,
+            },
+            user_offset: 0,
+            max_advance: 5,
+        };
+        let params = PokemonGenerationParams {
+            encounter_type: EncounterType::DarkGrass,
+            encounter_method: EncounterMethod::Moving,
+            slots: make_single_slot(585),
+            seasonal_slots: None,
+            ..make_seasonal_params()
+        };
+        let stationary = PokemonGenerationParams {
+            encounter_method: EncounterMethod::Stationary,
+            ..params.clone()
+        };
+        let offset_config = GenerationConfig {
+            user_offset: 2,
+            max_advance: 7,
+            ..config.clone()
+        };
+
+        let mut moving_gen =
+            PokemonGenerator::new(seed, make_source(seed), &params, &config).unwrap();
+        let mut stationary_gen =
+            PokemonGenerator::new(seed, make_source(seed), &stationary, &offset_config).unwrap();
+
+        let moving = moving_gen.take_double(5);
+        let expected = stationary_gen.take_double(5);
+        for (m, s) in moving.iter().zip(&expected) {
+            assert!(m.moving_encounter.is_some());
+            assert!(s.moving_encounter.is_none());
+            assert_eq!(m.first.pid, s.first.pid);
+            assert_eq!(m.second.pid, s.second.pid);
+        }
+    }
+
+    #[test]
+    fn test_validate_single_encounter_type() {
+        let mut params = make_seasonal_params();
+        assert!(validate_single_encounter_type(&params).is_ok());
+
+        params.encounter_type = EncounterType::DarkGrass;
+        assert!(validate_single_encounter_type(&params).is_err());
+
+        // 1 体ずつの生成経路では DarkGrass を生成しない
+        let seed = LcgSeed::new(0x1234_5678_9ABC_DEF0);
+        params.slots = make_single_slot(585);
+        params.seasonal_slots = None;
+        let config = GenerationConfig {
+            version: RomVersion::Black,
+            game_start: GameStartConfig {
+                start_mode: StartMode::Continue,
+                save: SavePresence::WithSave,
+                memory_link: MemoryLinkState::Disabled,
+                shiny_charm: ShinyCharmState::NotObtained,
+            },
+            user_offset: 0,
+            max_advance: 5,
+        };
+        let mut generator =
+            PokemonGenerator::new(seed, make_source(seed), &params, &config).unwrap();
+        assert!(generator.take(5).is_empty());
+    }
+
+    #[test]
+    fn test_validate_encounter_slots_seasonal() {
+        let mut params = make_seasonal_params();
+        assert!(validate_encounter_slots(&params).is_ok());
+
+        // 冬のみエンカウントなしは許容
+        let seasonal = params.seasonal_slots.as_mut().unwrap();
+        seasonal.winter.clear();
+        assert!(validate_encounter_slots(&params).is_ok());
+
+        let seasonal = params.seasonal_slots.as_mut().unwrap();
+        seasonal.spring.clear();
+        seasonal.summer.clear();
+        seasonal.autumn.clear();
+        assert!(validate_encounter_slots(&params).is_err());
+
+        params.seasonal_slots = None;
+        assert!(validate_encounter_slots(&params).is_err());
+    }
 }
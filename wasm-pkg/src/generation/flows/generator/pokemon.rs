@@ -9,10 +9,13 @@ use crate::generation::algorithm::{
     generate_moving_encounter_info, generate_rng_ivs_with_offset, generate_special_encounter_info,
     is_moving_encounter_type, is_special_encounter_type,
 };
-use crate::generation::flows::pokemon::{generate_static_pokemon, generate_wild_pokemon};
+use crate::generation::flows::pokemon::{
+    generate_dark_grass_pair, generate_static_pokemon, generate_wild_pokemon,
+};
 use crate::types::{
-    EncounterMethod, GeneratedPokemonData, GenerationConfig, Ivs, LcgSeed, MovingEncounterInfo,
-    PokemonGenerationParams, SeedOrigin, SpecialEncounterInfo,
+    EncounterMethod, EncounterType, GeneratedDoubleEncounterData, GeneratedPokemonData,
+    GenerationConfig, Ivs, LcgSeed, MovingEncounterInfo, PokemonGenerationParams, SeedOrigin,
+    SpecialEncounterInfo,
 };
 
 use super::is_static_encounter;
@@ -26,6 +29,8 @@ pub struct PokemonGenerator {
     user_offset: u32,
     current_advance: u32,
     rng_ivs: Ivs,
+    /// ダブルバトル 2 体目の個体値 (`DarkGrass` のみ)
+    partner_ivs: Option<Ivs>,
     source: SeedOrigin,
     params: PokemonGenerationParams,
     config: GenerationConfig,
@@ -41,9 +46,12 @@ impl PokemonGenerator {
     /// * `params` - 生成パラメータ
     /// * `config` - 共通設定
     ///
+    /// `params.seasonal_slots` 指定時は `source` の起動日時の季節のスロットを使用する。
+    ///
     /// # Errors
     ///
-    /// 無効な起動設定の場合にエラーを返す。
+    /// - 無効な起動設定の場合
+    /// - `source` に対応するエンカウントスロットが空の場合
     pub fn new(
         base_seed: LcgSeed,
         source: SeedOrigin,
@@ -51,10 +59,22 @@ impl PokemonGenerator {
         config: &GenerationConfig,
     ) -> Result<Self, String> {
         let game_offset = calculate_game_offset(base_seed, config.version, config.game_start)?;
+        let slots = params.slots_for(&source);
+        if slots.is_empty() {
+            return Err("Encounter slots is empty".into());
+        }
+        let params = PokemonGenerationParams {
+            slots: slots.to_vec(),
+            seasonal_slots: None,
+            ..params.clone()
+        };
         let mt_offset = calculate_mt_offset(config.version, params.encounter_type);
         let mt_seed = base_seed.derive_mt_seed();
-        let is_roamer = params.encounter_type == crate::types::EncounterType::Roamer;
+        let is_roamer = params.encounter_type == EncounterType::Roamer;
         let rng_ivs = generate_rng_ivs_with_offset(mt_seed, mt_offset, is_roamer);
+        // ダブルバトルの 2 体目は 1 体目の個体値 (6 消費) に続く位置から生成
+        let partner_ivs = (params.encounter_type == EncounterType::DarkGrass)
+            .then(|| generate_rng_ivs_with_offset(mt_seed, mt_offset + 6, false));
 
         // 初期位置へジャンプ
         let mut lcg = Lcg64::new(base_seed);
@@ -67,8 +87,9 @@ impl PokemonGenerator {
             user_offset: config.user_offset,
             current_advance: config.user_offset,
             rng_ivs,
+            partner_ivs,
             source,
-            params: params.clone(),
+            params,
             config: config.clone(),
         })
     }
@@ -143,7 +164,7 @@ impl PokemonGenerator {
 
     /// エンカウント付加情報を計算
     ///
-    /// - Normal/Surfing + Moving: 移動エンカウント情報 (消費 2)
+    /// - Normal/Surfing/DarkGrass + Moving: 移動エンカウント情報 (消費 2)
     /// - 特殊エンカウント種別: 特殊エンカウント情報 (消費なし、参考情報)
     fn calculate_encounter_info(
         &self,
@@ -158,7 +179,11 @@ impl PokemonGenerator {
         {
             gen_lcg.next(); // 空消費 1
             let rand_value = gen_lcg.next().unwrap_or(0); // エンカウント判定 1
-            let moving_info = generate_moving_encounter_info(self.config.version, rand_value);
+            let moving_info = generate_moving_encounter_info(
+                self.config.version,
+                rand_value,
+                self.params.encounter_modifiers.flute,
+            );
             return (Some(moving_info), None);
         }
 
@@ -178,15 +203,48 @@ impl PokemonGenerator {
     pub fn take(&mut self, count: u32) -> Vec<GeneratedPokemonData> {
         (0..count).filter_map(|_| self.generate_next()).collect()
     }
+
+    /// 次のダブルバトル (2 体組) を生成
+    ///
+    /// `DarkGrass` 以外では `None` を返す (消費位置は進めない)。
+    pub fn generate_next_double(&mut self) -> Option<GeneratedDoubleEncounterData> {
+        let partner_ivs = self.partner_ivs?;
+        let current_seed = self.lcg.current_seed();
+        let needle = calc_report_needle_direction(current_seed);
+        let advance = self.current_advance;
+
+        let mut gen_lcg = self.lcg.clone();
+        let (moving_encounter, _) = self.calculate_encounter_info(current_seed, &mut gen_lcg);
+        let pair = generate_dark_grass_pair(&mut gen_lcg, &self.params, &self.config);
+
+        self.lcg.next();
+        self.current_advance += 1;
+
+        Some(GeneratedDoubleEncounterData::from_raw_pair(
+            &pair,
+            [self.rng_ivs, partner_ivs],
+            advance,
+            needle,
+            self.source.clone(),
+            moving_encounter,
+        ))
+    }
+
+    /// 指定数のダブルバトル (2 体組) を生成
+    pub fn take_double(&mut self, count: u32) -> Vec<GeneratedDoubleEncounterData> {
+        (0..count)
+            .map_while(|_| self.generate_next_double())
+            .collect()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::types::{
-        EncounterMethod, EncounterSlotConfig, EncounterType, GameStartConfig, GenderRatio, LcgSeed,
-        LeadAbilityEffect, MemoryLinkState, RomVersion, SavePresence, SeedOrigin, ShinyCharmState,
-        StartMode, TrainerInfo,
+        EncounterMethod, EncounterModifiers, EncounterSlotConfig, EncounterType, GameStartConfig,
+        GenderRatio, LcgSeed, LeadAbilityEffect, MemoryLinkState, RomVersion, SavePresence,
+        SeedOrigin, ShinyCharmState, StartMode, TrainerInfo,
     };
 
     fn make_game_start() -> GameStartConfig {
@@ -222,6 +280,8 @@ mod tests {
             lead_ability: LeadAbilityEffect::None,
 
             slots: vec![],
+            seasonal_slots: None,
+            encounter_modifiers: EncounterModifiers::default(),
         }
     }
 
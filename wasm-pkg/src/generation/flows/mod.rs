@@ -6,8 +6,14 @@ pub mod pokemon;
 pub mod types;
 
 pub use egg::generate_egg;
-pub use generator::{EggGenerator, PokemonGenerator, generate_egg_list, generate_pokemon_list};
-pub use pokemon::{generate_hidden_grotto_pokemon, generate_static_pokemon, generate_wild_pokemon};
+pub use generator::{
+    EggGenerator, PokemonGenerator, generate_double_encounter_list, generate_egg_list,
+    generate_pokemon_list,
+};
+pub use pokemon::{
+    generate_dark_grass_pair, generate_hidden_grotto_pokemon, generate_static_pokemon,
+    generate_wild_pokemon,
+};
 
 // 内部型のみ再エクスポート
 pub use types::{EncounterSlotConfig, GenerationError, RawEggData, RawPokemonData};
@@ -0,0 +1,428 @@
+//! 濃い草むら (ダブルバトル) 野生ポケモン生成
+//!
+//! 対象: `DarkGrass`
+
+use crate::core::lcg::Lcg64;
+use crate::generation::algorithm::{
+    LeadCheckResult, cute_charm_gender_force, determine_encounter_slot, determine_nature,
+    generate_wild_pid_with_reroll, level_boost_check, low_level_suppression_check,
+    perform_lead_check, repel_check,
+};
+use crate::generation::flows::types::RawPokemonData;
+use crate::types::{
+    EncounterResult, GenerationConfig, HeldItemSlot, LeadAbilityEffect, PokemonGenerationParams,
+    ShinyCharmState,
+};
+
+/// 濃い草むらのダブルバトルで出現する 2 体を生成
+///
+/// 対象: `DarkGrass`
+///
+/// # 乱数消費順序
+/// 1. 先頭特性判定 (2 体共通)
+/// 2. 1 体目: スロット決定 → レベル消費 (値未使用) → PID 生成 → 性格決定
+/// 3. 2 体目: スロット決定 → レベル消費 (値未使用) → PID 生成 → 性格決定
+/// 4. BW 末尾消費
+///
+/// メロメロボディ成功時は PID 生成時の性別補正 1 消費を、
+/// じりょく / せいでんき成功時はスロット再抽選 1 消費を、
+/// やる気 / プレッシャー / はりきり先頭時はレベル消費直後の判定 1 消費を各体で追加する。
+///
+/// レベル決定後、各体でむしよけスプレー判定 (消費なし) →
+/// いかく / するどいめ判定 (先頭より 5 以上低い場合のみ 1 消費) を行う。
+/// いずれかの個体で回避した場合はエンカウント自体が発生しないものとし、
+/// 以降の生成と BW 末尾消費を行わずに 2 体とも回避結果
+/// (`EncounterResult::Repelled` / `EncounterResult::Suppressed`) を返す。
+pub fn generate_dark_grass_pair(
+    lcg: &mut Lcg64,
+    params: &PokemonGenerationParams,
+    config: &GenerationConfig,
+) -> [RawPokemonData; 2] {
+    let is_compound_eyes = matches!(params.lead_ability, LeadAbilityEffect::CompoundEyes);
+
+    // 1. 先頭特性判定 (ふくがん先頭時はスキップ)
+    let lead_check = if is_compound_eyes {
+        LeadCheckResult::default()
+    } else {
+        perform_lead_check(lcg, params.encounter_type, params.lead_ability)
+    };
+
+    // 2-3. 1 体目 → 2 体目 (回避時は打ち切り)
+    let first = generate_member(lcg, params, config, lead_check);
+    if first.encounter_result != EncounterResult::Pokemon {
+        return [first.clone(), first];
+    }
+    let second = generate_member(lcg, params, config, lead_check);
+    if second.encounter_result != EncounterResult::Pokemon {
+        return [second.clone(), second];
+    }
+
+    // 4. BW のみ: 最後の消費
+    if config.version.is_bw() {
+        lcg.next();
+    }
+
+    [first, second]
+}
+
+/// ダブルバトルの 1 体分を生成 (スロット決定 〜 性格決定)
+///
+/// むしよけスプレーで回避した場合は `EncounterResult::Repelled`、
+/// いかく / するどいめで回避した場合は `EncounterResult::Suppressed` を持つ `RawPokemonData` を返す。
+fn generate_member(
+    lcg: &mut Lcg64,
+    params: &PokemonGenerationParams,
+    config: &GenerationConfig,
+    lead_check: LeadCheckResult,
+) -> RawPokemonData {
+    // スロット決定 (通常エンカウントと同じ12スロット分布)
+    //   じりょく / せいでんき成功時は該当タイプから再抽選
+    let slot_idx = determine_encounter_slot(
+        lcg,
+        params.encounter_type,
+        config.version,
+        &params.slots,
+        lead_check.forced_type,
+    );
+    let slot_config = &params.slots[slot_idx];
+
+    // レベル消費 (値未使用、テーブル定義の level_min を使用)
+    //   やる気 / プレッシャー / はりきり成功時は level_max
+    let _level_rand = lcg.next();
+    let level = if level_boost_check(lcg, params.lead_ability) {
+        slot_config.level_max
+    } else {
+        slot_config.level_min
+    };
+
+    // むしよけスプレー判定 (乱数消費なし)
+    if repel_check(params.encounter_modifiers, level) {
+        return RawPokemonData::not_pokemon(EncounterResult::Repelled);
+    }
+
+    // いかく / するどいめ判定 (先頭より 5 以上低いレベルのみ 1 消費)
+    if low_level_suppression_check(lcg, params.lead_ability, level) {
+        return RawPokemonData::not_pokemon(EncounterResult::Suppressed);
+    }
+
+    // PID 生成
+    let reroll_count = match config.game_start.shiny_charm {
+        ShinyCharmState::Obtained => 2,
+        ShinyCharmState::NotObtained => 0,
+    };
+    let gender_force = cute_charm_gender_force(
+        params.lead_ability,
+        lead_check.cute_charm_success,
+        slot_config.gender_ratio,
+    );
+    let (pid, shiny_type) =
+        generate_wild_pid_with_reroll(lcg, params.trainer, reroll_count, gender_force);
+
+    // 性格決定
+    let (nature, sync_applied) =
+        determine_nature(lcg, lead_check.sync_success, params.lead_ability);
+
+    // === Resolve (乱数消費なし) ===
+    let gender = pid.gender(slot_config.gender_ratio);
+    let ability_slot = pid.ability_slot();
+
+    RawPokemonData {
+        pid,
+        species_id: slot_config.species_id,
+        level,
+        nature,
+        sync_applied,
+        cute_charm_applied: gender_force.is_some(),
+        ability_slot,
+        gender,
+        shiny_type,
+        held_item_slot: HeldItemSlot::None,
+        fishing_bite: None,
+        encounter_result: EncounterResult::Pokemon,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{
+        EncounterMethod, EncounterModifiers, EncounterSlotConfig, EncounterType, GameStartConfig,
+        Gender, GenderRatio, MemoryLinkState, Nature, RomVersion, SavePresence, StartMode,
+        TrainerInfo,
+    };
+
+    fn make_slots() -> Vec<EncounterSlotConfig> {
+        (0..12_u8)
+            .map(|i| EncounterSlotConfig {
+                species_id: 500 + u16::from(i),
+                level_min: 40 + i,
+                level_max: 45 + i,
+                gender_ratio: GenderRatio::F1M1,
+                has_held_item: false,
+                shiny_locked: false,
+            })
+            .collect()
+    }
+
+    fn make_params(lead_ability: LeadAbilityEffect) -> PokemonGenerationParams {
+        PokemonGenerationParams {
+            trainer: TrainerInfo {
+                tid: 12345,
+                sid: 54321,
+            },
+            encounter_type: EncounterType::DarkGrass,
+            encounter_method: EncounterMethod::Stationary,
+            lead_ability,
+            slots: make_slots(),
+            seasonal_slots: None,
+            encounter_modifiers: EncounterModifiers::default(),
+        }
+    }
+
+    fn make_config(version: RomVersion) -> GenerationConfig {
+        GenerationConfig {
+            version,
+            game_start: GameStartConfig {
+                start_mode: StartMode::Continue,
+                save: SavePresence::WithSave,
+                memory_link: MemoryLinkState::Disabled,
+                shiny_charm: ShinyCharmState::NotObtained,
+            },
+            user_offset: 0,
+            max_advance: 1000,
+        }
+    }
+
+    #[test]
+    fn test_dark_grass_pair_follows_slot_table() {
+        let params = make_params(LeadAbilityEffect::None);
+        let config = make_config(RomVersion::Black);
+        let mut lcg = Lcg64::from_raw(0x1234_5678_9ABC_DEF0);
+        let initial_seed = lcg.current_seed();
+
+        let [first, second] = generate_dark_grass_pair(&mut lcg, &params, &config);
+
+        // 1 体目は先頭特性判定(1) の直後、2 体目は 1 体目の 4 消費後の乱数でスロットが決まる
+        let mut expected = Lcg64::new(initial_seed);
+        expected.next();
+        let mut next_slot = |lcg: &mut Lcg64| {
+            let idx = determine_encounter_slot(
+                lcg,
+                EncounterType::DarkGrass,
+                config.version,
+                &params.slots,
+                None,
+            );
+            u8::try_from(idx).unwrap()
+        };
+        let first_slot = next_slot(&mut expected);
+        expected.jump(3);
+        let second_slot = next_slot(&mut expected);
+
+        assert_eq!(first.species_id, 500 + u16::from(first_slot));
+        assert_eq!(first.level, 40 + first_slot);
+        assert_eq!(second.species_id, 500 + u16::from(second_slot));
+        assert_eq!(second.level, 40 + second_slot);
+        assert_eq!(first.encounter_result, EncounterResult::Pokemon);
+        assert_eq!(second.encounter_result, EncounterResult::Pokemon);
+    }
+
+    #[test]
+    fn test_dark_grass_consumption() {
+        // BW: sync(1) + 2 × (slot(1) + level(1) + pid(1) + nature(1)) + bw_tail(1) = 10
+        // BW2: bw_tail なしで 9
+        for (version, expected_count) in [(RomVersion::Black, 10), (RomVersion::Black2, 9)] {
+            let mut lcg = Lcg64::from_raw(0x1234_5678_9ABC_DEF0);
+            let initial_seed = lcg.current_seed();
+
+            let _ = generate_dark_grass_pair(
+                &mut lcg,
+                &make_params(LeadAbilityEffect::None),
+                &make_config(version),
+            );
+
+            let mut expected = Lcg64::new(initial_seed);
+            expected.jump(expected_count);
+            assert_eq!(lcg.current_seed(), expected.current_seed(), "{version:?}");
+        }
+    }
+
+    /// `initial` から `n` 消費した位置に `lcg` があるか検証
+    fn assert_consumed(lcg: &Lcg64, initial: u64, n: u64) {
+        let mut expected = Lcg64::from_raw(initial);
+        expected.jump(n);
+        assert_eq!(
+            lcg.current_seed(),
+            expected.current_seed(),
+            "{initial:#018X}"
+        );
+    }
+
+    #[test]
+    fn test_dark_grass_level_boost_consumption() {
+        // BW: sync(1) + 2 × (slot(1) + level(1) + boost(1) + pid(1) + nature(1)) + bw_tail(1) = 12
+        // 判定成功時は level_max (45 + slot)、失敗時は level_min (40 + slot)
+        let params = make_params(LeadAbilityEffect::LevelBoost);
+        let config = make_config(RomVersion::Black);
+        // (初期 Seed, 1 体目 (種族, レベル), 2 体目 (種族, レベル))
+        for (seed, first, second) in [
+            // 2 体とも成功
+            (0x9E37_79B9_7F4A_7C15, (504, 49), (502, 47)),
+            // 2 体とも失敗
+            (0x1715_609F_7C74_6C69, (503, 43), (501, 41)),
+            // 2 体目のみ成功
+            (0xB54C_DA58_FBBE_E87E, (505, 45), (508, 53)),
+        ] {
+            let mut lcg = Lcg64::from_raw(seed);
+            let pair = generate_dark_grass_pair(&mut lcg, &params, &config);
+
+            assert_eq!((pair[0].species_id, pair[0].level), first, "{seed:#018X}");
+            assert_eq!((pair[1].species_id, pair[1].level), second, "{seed:#018X}");
+            assert_consumed(&lcg, seed, 12);
+        }
+    }
+
+    #[test]
+    fn test_dark_grass_magnet_pull() {
+        // じりょく成功時は 2 体ともはがねタイプのスロットから再抽選 (各 1 消費追加)
+        let mut params = make_params(LeadAbilityEffect::MagnetPull);
+        params.slots[11].species_id = 599; // ギアル (はがね)
+        let config = make_config(RomVersion::Black);
+        // (初期 Seed, 種族, 消費数)
+        for (seed, species, consumed) in [
+            // 成功
+            (0x9E37_79B9_7F4A_7C15, [599, 599], 12),
+            // 失敗
+            (0x0000_0000_0000_0000, [502, 500], 10),
+        ] {
+            let mut lcg = Lcg64::from_raw(seed);
+            let [first, second] = generate_dark_grass_pair(&mut lcg, &params, &config);
+
+            assert_eq!(
+                [first.species_id, second.species_id],
+                species,
+                "{seed:#018X}"
+            );
+            assert_consumed(&lcg, seed, consumed);
+        }
+    }
+
+    #[test]
+    fn test_dark_grass_cute_charm() {
+        // メロメロボディ成功時: 2 体とも先頭と異性に固定され、各体で性別補正 1 消費追加
+        let params = make_params(LeadAbilityEffect::CuteCharm(Gender::Male));
+        let config = make_config(RomVersion::Black);
+        // (初期 Seed, 適用, 性別, 消費数)
+        for (seed, applied, genders, consumed) in [
+            (
+                0x9E37_79B9_7F4A_7C15,
+                true,
+                [Gender::Female, Gender::Female],
+                12,
+            ),
+            (
+                0x3C6E_F372_FE94_F82A,
+                false,
+                [Gender::Male, Gender::Male],
+                10,
+            ),
+        ] {
+            let mut lcg = Lcg64::from_raw(seed);
+            let [first, second] = generate_dark_grass_pair(&mut lcg, &params, &config);
+
+            assert_eq!(first.cute_charm_applied, applied, "{seed:#018X}");
+            assert_eq!(second.cute_charm_applied, applied, "{seed:#018X}");
+            assert_eq!([first.gender, second.gender], genders, "{seed:#018X}");
+            assert_consumed(&lcg, seed, consumed);
+        }
+    }
+
+    #[test]
+    fn test_dark_grass_intimidate_suppression() {
+        // 先頭 Lv.60 (全スロットと 5 以上差): 各体で判定 1 消費
+        let params = make_params(LeadAbilityEffect::Intimidate(60));
+        let config = make_config(RomVersion::Black);
+        // (初期 Seed, 結果, 消費数)
+        for (seed, result, consumed) in [
+            // 出現: 12 消費
+            (0x0000_0000_0000_0000, EncounterResult::Pokemon, 12),
+            // 1 体目で回避: sync(1) + slot(1) + level(1) + 判定(1) = 4 消費
+            (0x9E37_79B9_7F4A_7C15, EncounterResult::Suppressed, 4),
+            // 2 体目で回避: 1 体目 (4 + pid(1) + nature(1)) + 2 体目 (slot + level + 判定) = 9 消費
+            (0xB54C_DA58_FBBE_E87E, EncounterResult::Suppressed, 9),
+        ] {
+            let mut lcg = Lcg64::from_raw(seed);
+            let [first, second] = generate_dark_grass_pair(&mut lcg, &params, &config);
+
+            assert_eq!(first.encounter_result, result, "{seed:#018X}");
+            assert_eq!(second.encounter_result, result, "{seed:#018X}");
+            if result == EncounterResult::Suppressed {
+                assert_eq!(first.species_id, 0);
+                assert_eq!(second.species_id, 0);
+            }
+            assert_consumed(&lcg, seed, consumed);
+        }
+
+        // 先頭 Lv.40 (全スロットと 5 未満差): 消費なし
+        let params = make_params(LeadAbilityEffect::Intimidate(40));
+        let mut lcg = Lcg64::from_raw(0x1234_5678_9ABC_DEF0);
+        let pair = generate_dark_grass_pair(&mut lcg, &params, &config);
+        assert!(
+            pair.iter()
+                .all(|p| p.encounter_result == EncounterResult::Pokemon)
+        );
+        assert_consumed(&lcg, 0x1234_5678_9ABC_DEF0, 10);
+    }
+
+    #[test]
+    fn test_dark_grass_repel() {
+        // 先頭 Lv.46: Lv.40〜45 のスロットは回避 (判定で消費なし)
+        let params = PokemonGenerationParams {
+            encounter_modifiers: EncounterModifiers {
+                repel_level: Some(46),
+                ..EncounterModifiers::default()
+            },
+            ..make_params(LeadAbilityEffect::None)
+        };
+        let config = make_config(RomVersion::Black);
+        // (初期 Seed, 結果, 消費数)
+        for (seed, result, consumed) in [
+            // 1 体目で回避: sync(1) + slot(1) + level(1) = 3 消費
+            (0x0000_0000_0000_0000, EncounterResult::Repelled, 3),
+            // 2 体目で回避: 1 体目 (3 + pid(1) + nature(1)) + 2 体目 (slot + level) = 7 消費
+            (0x3C6E_F372_FE94_F82A, EncounterResult::Repelled, 7),
+            // 出現: 10 消費
+            (0xCC62_3AF8_7833_54E7, EncounterResult::Pokemon, 10),
+        ] {
+            let mut lcg = Lcg64::from_raw(seed);
+            let [first, second] = generate_dark_grass_pair(&mut lcg, &params, &config);
+
+            assert_eq!(first.encounter_result, result, "{seed:#018X}");
+            assert_eq!(second.encounter_result, result, "{seed:#018X}");
+            if result == EncounterResult::Pokemon {
+                assert!(first.level >= 46 && second.level >= 46);
+            }
+            assert_consumed(&lcg, seed, consumed);
+        }
+    }
+
+    #[test]
+    fn test_dark_grass_sync_shared() {
+        // シンクロ成功時は 2 体とも同じ性格になる
+        let params = make_params(LeadAbilityEffect::Synchronize(Nature::Timid));
+        let config = make_config(RomVersion::White);
+        // (初期 Seed, 適用, 性格)
+        for (seed, applied, natures) in [
+            (0x9E37_79B9_7F4A_7C15, true, [Nature::Timid, Nature::Timid]),
+            (0x0000_0000_0000_0000, false, [Nature::Rash, Nature::Bold]),
+        ] {
+            let mut lcg = Lcg64::from_raw(seed);
+            let [first, second] = generate_dark_grass_pair(&mut lcg, &params, &config);
+
+            assert_eq!(first.sync_applied, applied, "{seed:#018X}");
+            assert_eq!(second.sync_applied, applied, "{seed:#018X}");
+            assert_eq!([first.nature, second.nature], natures, "{seed:#018X}");
+            assert_consumed(&lcg, seed, 10);
+        }
+    }
+}
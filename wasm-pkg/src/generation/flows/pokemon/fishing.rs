@@ -4,13 +4,14 @@
 
 use crate::core::lcg::Lcg64;
 use crate::generation::algorithm::{
-    calculate_encounter_slot, calculate_level, determine_held_item_slot, determine_nature,
-    encounter_type_supports_held_item, fishing_success, generate_wild_pid_with_reroll,
-    perform_sync_check,
+    LeadCheckResult, calculate_level, cute_charm_gender_force, determine_encounter_slot,
+    determine_held_item_slot, determine_nature, encounter_type_supports_held_item,
+    fishing_bite_check, generate_wild_pid_with_reroll, level_boost_check,
+    low_level_suppression_check, perform_lead_check, repel_check,
 };
 use crate::generation::flows::types::RawPokemonData;
 use crate::types::{
-    EncounterResult, EncounterType, GenerationConfig, HeldItemSlot, LeadAbilityEffect,
+    EncounterResult, EncounterType, FishingBite, GenerationConfig, HeldItemSlot, LeadAbilityEffect,
     PokemonGenerationParams, ShinyCharmState,
 };
 
@@ -19,16 +20,21 @@ use crate::types::{
 /// 対象: `Fishing`, `FishingBubble`
 ///
 /// # 乱数消費順序
-/// 1. シンクロ判定
-/// 2. 釣り成功判定 (通常釣りのみ)
-/// 3. スロット決定
-/// 4. レベル決定 (Range)
-/// 5. PID 生成
+/// 1. 先頭特性判定 (シンクロ / メロメロボディ / じりょく / せいでんき)
+/// 2. 釣り成功判定 (通常釣りのみ、きゅうばん / ねんちゃく先頭時は消費なしで確定)
+/// 3. スロット決定 (じりょく / せいでんき成功時は再抽選 1 消費を追加)
+/// 4. レベル決定 (Range、やる気 / プレッシャー / はりきり先頭時は判定 1 消費を追加)
+///    → むしよけスプレー判定 (消費なし)
+///    → いかく / するどいめ判定 (先頭より 5 以上低い場合のみ 1 消費)
+/// 5. PID 生成 (メロメロボディ成功時は性別補正を含む)
 /// 6. 性格決定
 /// 7. 持ち物判定
 /// 8. BW 末尾消費
 ///
 /// 釣り失敗時は `EncounterResult::FishingFailed` を持つ `RawPokemonData` を返す。
+/// 通常釣りでは当たり判定の結果を `fishing_bite` に格納する。
+/// いかく / するどいめで回避した場合は `EncounterResult::Suppressed` を返す。
+/// むしよけスプレーで回避した場合は `EncounterResult::Repelled` を返す。
 pub fn generate_fishing_pokemon(
     lcg: &mut Lcg64,
     params: &PokemonGenerationParams,
@@ -37,44 +43,73 @@ pub fn generate_fishing_pokemon(
     let enc_type = params.encounter_type;
     let is_compound_eyes = matches!(params.lead_ability, LeadAbilityEffect::CompoundEyes);
 
-    // 1. シンクロ判定 (ふくがん先頭時はスキップ)
-    let sync_success = if is_compound_eyes {
-        false
+    // 1. 先頭特性判定 (ふくがん先頭時はスキップ)
+    let lead_check = if is_compound_eyes {
+        LeadCheckResult::default()
     } else {
-        perform_sync_check(lcg, enc_type, params.lead_ability)
+        perform_lead_check(lcg, enc_type, params.lead_ability)
     };
 
     // 2. 釣り成功判定 (50%) - 通常釣りのみ (泡釣りはスキップ)
-    if enc_type == EncounterType::Fishing {
-        let fishing_result = lcg.next().unwrap_or(0);
-        if !fishing_success(fishing_result) {
-            return RawPokemonData::not_pokemon(EncounterResult::FishingFailed);
+    let fishing_bite = if enc_type == EncounterType::Fishing {
+        let bite = fishing_bite_check(lcg, params.lead_ability);
+        if bite == FishingBite::Missed {
+            let mut raw = RawPokemonData::not_pokemon(EncounterResult::FishingFailed);
+            raw.fishing_bite = Some(bite);
+            return raw;
         }
-    }
+        Some(bite)
+    } else {
+        None
+    };
 
-    // 3. スロット決定
-    let slot_rand = lcg.next().unwrap_or(0);
-    let slot_idx = calculate_encounter_slot(enc_type, slot_rand, config.version) as usize;
-    let slot_config = &params.slots[slot_idx.min(params.slots.len() - 1)];
+    // 3. スロット決定 (じりょく / せいでんき成功時は該当タイプから再抽選)
+    let slot_idx = determine_encounter_slot(
+        lcg,
+        enc_type,
+        config.version,
+        &params.slots,
+        lead_check.forced_type,
+    );
+    let slot_config = &params.slots[slot_idx];
 
     // 4. レベル決定 (Range パターン: 乱数値からレベルを計算)
     let level_rand = lcg.next().unwrap_or(0);
+    let level_boost = level_boost_check(lcg, params.lead_ability);
     let level = calculate_level(
         config.version,
         level_rand,
         slot_config.level_min,
         slot_config.level_max,
+        level_boost,
     );
 
+    // むしよけスプレー判定 (乱数消費なし)
+    if repel_check(params.encounter_modifiers, level) {
+        return RawPokemonData::not_pokemon(EncounterResult::Repelled);
+    }
+
+    // いかく / するどいめ判定 (先頭より 5 以上低いレベルのみ 1 消費)
+    if low_level_suppression_check(lcg, params.lead_ability, level) {
+        return RawPokemonData::not_pokemon(EncounterResult::Suppressed);
+    }
+
     // 5. PID 生成
     let reroll_count = match config.game_start.shiny_charm {
         ShinyCharmState::Obtained => 2,
         ShinyCharmState::NotObtained => 0,
     };
-    let (pid, shiny_type) = generate_wild_pid_with_reroll(lcg, params.trainer, reroll_count);
+    let gender_force = cute_charm_gender_force(
+        params.lead_ability,
+        lead_check.cute_charm_success,
+        slot_config.gender_ratio,
+    );
+    let (pid, shiny_type) =
+        generate_wild_pid_with_reroll(lcg, params.trainer, reroll_count, gender_force);
 
     // 6. 性格決定
-    let (nature, sync_applied) = determine_nature(lcg, sync_success, params.lead_ability);
+    let (nature, sync_applied) =
+        determine_nature(lcg, lead_check.sync_success, params.lead_ability);
 
     // 7. 持ち物判定
     let held_item_slot = if encounter_type_supports_held_item(enc_type) && slot_config.has_held_item
@@ -106,10 +141,12 @@ pub fn generate_fishing_pokemon(
         level,
         nature,
         sync_applied,
+        cute_charm_applied: gender_force.is_some(),
         ability_slot,
         gender,
         shiny_type,
         held_item_slot,
+        fishing_bite,
         encounter_result: EncounterResult::Pokemon,
     }
 }
@@ -118,8 +155,9 @@ pub fn generate_fishing_pokemon(
 mod tests {
     use super::*;
     use crate::types::{
-        EncounterMethod, EncounterSlotConfig, GameStartConfig, GenderRatio, GenerationConfig,
-        MemoryLinkState, RomVersion, SavePresence, ShinyCharmState, StartMode, TrainerInfo,
+        EncounterMethod, EncounterModifiers, EncounterSlotConfig, GameStartConfig, GenderRatio,
+        GenerationConfig, MemoryLinkState, RomVersion, SavePresence, ShinyCharmState, StartMode,
+        TrainerInfo,
     };
 
     fn make_slots() -> Vec<EncounterSlotConfig> {
@@ -144,6 +182,8 @@ mod tests {
             lead_ability: LeadAbilityEffect::None,
 
             slots: make_slots(),
+            seasonal_slots: None,
+            encounter_modifiers: EncounterModifiers::default(),
         }
     }
 
@@ -182,6 +222,8 @@ mod tests {
                 assert_eq!(pokemon.species_id, 0);
             }
             EncounterResult::Item(_) => panic!("Unexpected item result"),
+            EncounterResult::Suppressed => panic!("Unexpected suppressed result"),
+            EncounterResult::Repelled => panic!("Unexpected repelled result"),
         }
     }
 
@@ -240,4 +282,58 @@ mod tests {
         assert_eq!(pokemon.species_id, 129);
         assert!((10..=25).contains(&pokemon.level));
     }
+
+    #[test]
+    fn test_fishing_suction_cups() {
+        // きゅうばん先頭: 釣り判定を消費せず必ず成功
+        // lead(1) + slot(1) + level(1) + pid(1) + nature(1) = 5 (BW2)
+        let mut params = make_params(EncounterType::Fishing);
+        params.lead_ability = LeadAbilityEffect::SuctionCups;
+        let config = make_config(RomVersion::Black2);
+
+        // 通常の釣りでは判定に失敗する Seed
+        let seed = 0x9E37_79B9_7F4A_7C15;
+        let mut lcg = Lcg64::from_raw(seed);
+        let pokemon = generate_fishing_pokemon(&mut lcg, &params, &config);
+
+        assert_eq!(pokemon.encounter_result, EncounterResult::Pokemon);
+        assert_eq!(pokemon.fishing_bite, Some(FishingBite::Guaranteed));
+        let mut expected = Lcg64::from_raw(seed);
+        expected.jump(5);
+        assert_eq!(lcg.current_seed(), expected.current_seed());
+    }
+
+    #[test]
+    fn test_fishing_bite_recorded() {
+        // 通常釣りは判定結果、泡釣りは None
+        let config = make_config(RomVersion::Black2);
+        // (初期 Seed, 判定結果, エンカウント結果)
+        for (seed, bite, result) in [
+            (
+                0x9E37_79B9_7F4A_7C15,
+                FishingBite::Missed,
+                EncounterResult::FishingFailed,
+            ),
+            (
+                0xDAA6_6D2C_7DDF_743F,
+                FishingBite::Hooked,
+                EncounterResult::Pokemon,
+            ),
+        ] {
+            let pokemon = generate_fishing_pokemon(
+                &mut Lcg64::from_raw(seed),
+                &make_params(EncounterType::Fishing),
+                &config,
+            );
+            assert_eq!(pokemon.fishing_bite, Some(bite), "{seed:#018X}");
+            assert_eq!(pokemon.encounter_result, result, "{seed:#018X}");
+
+            let bubble = generate_fishing_pokemon(
+                &mut Lcg64::from_raw(seed),
+                &make_params(EncounterType::FishingBubble),
+                &config,
+            );
+            assert_eq!(bubble.fishing_bite, None);
+        }
+    }
 }
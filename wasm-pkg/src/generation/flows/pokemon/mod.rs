@@ -2,6 +2,7 @@
 //!
 //! 固定エンカウント (static) と野生エンカウント (wild) のロジックを提供。
 
+mod dark_grass;
 mod fishing;
 mod normal;
 mod phenomena;
@@ -12,6 +13,7 @@ use crate::core::lcg::Lcg64;
 use crate::generation::flows::types::{GenerationError, RawPokemonData};
 use crate::types::{EncounterType, GenerationConfig, PokemonGenerationParams};
 
+pub use dark_grass::generate_dark_grass_pair;
 pub use static_encounter::{generate_hidden_grotto_pokemon, generate_static_pokemon};
 
 /// 野生ポケモン生成 (エンカウント種別に応じてディスパッチ)
@@ -22,11 +24,13 @@ pub use static_encounter::{generate_hidden_grotto_pokemon, generate_static_pokem
 /// - `Surfing`, `SurfingBubble`: 波乗りエンカウント
 /// - `Fishing`, `FishingBubble`: 釣りエンカウント
 ///
+/// `DarkGrass` はダブルバトル専用の消費順序となるため対象外 (`generate_dark_grass_pair` を使用)。
+///
 /// 釣り失敗時は `EncounterResult::FishingFailed` を持つ `RawPokemonData` を返す。
 ///
 /// # Errors
 ///
-/// - `GenerationError::UnsupportedEncounterType`: 非対応エンカウント種別
+/// - `GenerationError::UnsupportedEncounterType`: 非対応エンカウント種別 (`DarkGrass` を含む)
 pub fn generate_wild_pokemon(
     lcg: &mut Lcg64,
     params: &PokemonGenerationParams,
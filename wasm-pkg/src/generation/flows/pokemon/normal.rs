@@ -4,8 +4,9 @@
 
 use crate::core::lcg::Lcg64;
 use crate::generation::algorithm::{
-    calculate_encounter_slot, determine_held_item_slot, determine_nature,
-    encounter_type_supports_held_item, generate_wild_pid_with_reroll, perform_sync_check,
+    LeadCheckResult, cute_charm_gender_force, determine_encounter_slot, determine_held_item_slot,
+    determine_nature, encounter_type_supports_held_item, generate_wild_pid_with_reroll,
+    level_boost_check, low_level_suppression_check, perform_lead_check, repel_check,
 };
 use crate::generation::flows::types::RawPokemonData;
 use crate::types::{
@@ -18,13 +19,18 @@ use crate::types::{
 /// 対象: `Normal`, `ShakingGrass`
 ///
 /// # 乱数消費順序
-/// 1. シンクロ判定
-/// 2. スロット決定
-/// 3. レベル消費 (値未使用)
-/// 4. PID 生成
+/// 1. 先頭特性判定 (シンクロ / メロメロボディ / じりょく / せいでんき)
+/// 2. スロット決定 (じりょく / せいでんき成功時は再抽選 1 消費を追加)
+/// 3. レベル消費 (値未使用、やる気 / プレッシャー / はりきり先頭時は判定 1 消費を追加)
+///    → むしよけスプレー判定 (消費なし)
+///    → いかく / するどいめ判定 (先頭より 5 以上低い場合のみ 1 消費)
+/// 4. PID 生成 (メロメロボディ成功時は性別補正を含む)
 /// 5. 性格決定
 /// 6. 持ち物判定 (`ShakingGrass` のみ)
 /// 7. BW 末尾消費
+///
+/// いかく / するどいめで回避した場合は `EncounterResult::Suppressed` を持つ `RawPokemonData` を返す。
+/// むしよけスプレーで回避した場合は `EncounterResult::Repelled` を返す。
 pub fn generate_normal_pokemon(
     lcg: &mut Lcg64,
     params: &PokemonGenerationParams,
@@ -33,30 +39,58 @@ pub fn generate_normal_pokemon(
     let enc_type = params.encounter_type;
     let is_compound_eyes = matches!(params.lead_ability, LeadAbilityEffect::CompoundEyes);
 
-    // 1. シンクロ判定 (ふくがん先頭時はスキップ)
-    let sync_success = if is_compound_eyes {
-        false
+    // 1. 先頭特性判定 (ふくがん先頭時はスキップ)
+    let lead_check = if is_compound_eyes {
+        LeadCheckResult::default()
     } else {
-        perform_sync_check(lcg, enc_type, params.lead_ability)
+        perform_lead_check(lcg, enc_type, params.lead_ability)
     };
 
-    // 2. スロット決定
-    let slot_rand = lcg.next().unwrap_or(0);
-    let slot_idx = calculate_encounter_slot(enc_type, slot_rand, config.version) as usize;
-    let slot_config = &params.slots[slot_idx.min(params.slots.len() - 1)];
+    // 2. スロット決定 (じりょく / せいでんき成功時は該当タイプから再抽選)
+    let slot_idx = determine_encounter_slot(
+        lcg,
+        enc_type,
+        config.version,
+        &params.slots,
+        lead_check.forced_type,
+    );
+    let slot_config = &params.slots[slot_idx];
 
     // 3. レベル消費 (値未使用、テーブル定義の level_min を使用)
+    //    やる気 / プレッシャー / はりきり成功時は level_max
     let _level_rand = lcg.next();
+    let level = if level_boost_check(lcg, params.lead_ability) {
+        slot_config.level_max
+    } else {
+        slot_config.level_min
+    };
+
+    // むしよけスプレー判定 (乱数消費なし)
+    if repel_check(params.encounter_modifiers, level) {
+        return RawPokemonData::not_pokemon(EncounterResult::Repelled);
+    }
+
+    // いかく / するどいめ判定 (先頭より 5 以上低いレベルのみ 1 消費)
+    if low_level_suppression_check(lcg, params.lead_ability, level) {
+        return RawPokemonData::not_pokemon(EncounterResult::Suppressed);
+    }
 
     // 4. PID 生成
     let reroll_count = match config.game_start.shiny_charm {
         ShinyCharmState::Obtained => 2,
         ShinyCharmState::NotObtained => 0,
     };
-    let (pid, shiny_type) = generate_wild_pid_with_reroll(lcg, params.trainer, reroll_count);
+    let gender_force = cute_charm_gender_force(
+        params.lead_ability,
+        lead_check.cute_charm_success,
+        slot_config.gender_ratio,
+    );
+    let (pid, shiny_type) =
+        generate_wild_pid_with_reroll(lcg, params.trainer, reroll_count, gender_force);
 
     // 5. 性格決定
-    let (nature, sync_applied) = determine_nature(lcg, sync_success, params.lead_ability);
+    let (nature, sync_applied) =
+        determine_nature(lcg, lead_check.sync_success, params.lead_ability);
 
     // 6. 持ち物判定 (ShakingGrass のみ)
     let held_item_slot = if encounter_type_supports_held_item(enc_type) && slot_config.has_held_item
@@ -85,13 +119,15 @@ pub fn generate_normal_pokemon(
     RawPokemonData {
         pid,
         species_id: slot_config.species_id,
-        level: slot_config.level_min,
+        level,
         nature,
         sync_applied,
+        cute_charm_applied: gender_force.is_some(),
         ability_slot,
         gender,
         shiny_type,
         held_item_slot,
+        fishing_bite: None,
         encounter_result: EncounterResult::Pokemon,
     }
 }
@@ -100,8 +136,9 @@ pub fn generate_normal_pokemon(
 mod tests {
     use super::*;
     use crate::types::{
-        EncounterMethod, EncounterSlotConfig, GameStartConfig, GenderRatio, GenerationConfig,
-        MemoryLinkState, RomVersion, SavePresence, ShinyCharmState, StartMode, TrainerInfo,
+        EncounterMethod, EncounterModifiers, EncounterSlotConfig, GameStartConfig, Gender,
+        GenderRatio, GenerationConfig, MemoryLinkState, RomVersion, SavePresence, ShinyCharmState,
+        StartMode, TrainerInfo,
     };
 
     fn make_slots() -> Vec<EncounterSlotConfig> {
@@ -126,6 +163,8 @@ mod tests {
             lead_ability: LeadAbilityEffect::None,
 
             slots: make_slots(),
+            seasonal_slots: None,
+            encounter_modifiers: EncounterModifiers::default(),
         }
     }
 
@@ -176,6 +215,38 @@ mod tests {
         assert_eq!(lcg.current_seed(), expected_lcg.current_seed());
     }
 
+    #[test]
+    fn test_normal_repel() {
+        let config = make_config(RomVersion::Black);
+        let seed = 0x1234_5678_9ABC_DEF0;
+
+        // 先頭 Lv.6 (スロット Lv.5 より高い): 回避、乱数は sync(1) + slot(1) + level(1) のみ
+        let params = PokemonGenerationParams {
+            encounter_modifiers: EncounterModifiers {
+                repel_level: Some(6),
+                ..EncounterModifiers::default()
+            },
+            ..make_params(EncounterType::Normal)
+        };
+        let mut lcg = Lcg64::from_raw(seed);
+        let pokemon = generate_normal_pokemon(&mut lcg, &params, &config);
+        assert_eq!(pokemon.encounter_result, EncounterResult::Repelled);
+        let mut expected_lcg = Lcg64::from_raw(seed);
+        expected_lcg.advance(3);
+        assert_eq!(lcg.current_seed(), expected_lcg.current_seed());
+
+        // 先頭 Lv.5 (同レベル): 出現
+        let params = PokemonGenerationParams {
+            encounter_modifiers: EncounterModifiers {
+                repel_level: Some(5),
+                ..EncounterModifiers::default()
+            },
+            ..make_params(EncounterType::Normal)
+        };
+        let pokemon = generate_normal_pokemon(&mut Lcg64::from_raw(seed), &params, &config);
+        assert_eq!(pokemon.encounter_result, EncounterResult::Pokemon);
+    }
+
     #[test]
     fn test_normal_consumption_bw2() {
         // BW2: sync(1) + slot(1) + level(1) + pid(1) + nature(1) = 5
@@ -190,6 +261,115 @@ mod tests {
         assert_eq!(lcg.current_seed(), expected_lcg.current_seed());
     }
 
+    /// `initial` から `n` 消費した位置に `lcg` があるか検証
+    fn assert_consumed(lcg: &Lcg64, initial: u64, n: u32) {
+        let mut expected_lcg = Lcg64::from_raw(initial);
+        expected_lcg.advance(n);
+        assert_eq!(
+            lcg.current_seed(),
+            expected_lcg.current_seed(),
+            "{initial:#018X}"
+        );
+    }
+
+    #[test]
+    fn test_normal_cute_charm() {
+        // メロメロボディ成功時: 先頭と異性に固定され、性別補正で 1 消費追加
+        let params = PokemonGenerationParams {
+            lead_ability: LeadAbilityEffect::CuteCharm(Gender::Male),
+            ..make_params(EncounterType::Normal)
+        };
+        let config = make_config(RomVersion::Black);
+        // (初期 Seed, 適用, 性別, 消費数)
+        for (seed, applied, gender, consumed) in [
+            (0x9E37_79B9_7F4A_7C15, true, Gender::Female, 7),
+            (0x3C6E_F372_FE94_F82A, false, Gender::Male, 6),
+        ] {
+            let mut lcg = Lcg64::from_raw(seed);
+            let pokemon = generate_normal_pokemon(&mut lcg, &params, &config);
+
+            assert_eq!(pokemon.cute_charm_applied, applied, "{seed:#018X}");
+            assert_eq!(pokemon.gender, gender, "{seed:#018X}");
+            assert!(!pokemon.sync_applied);
+            assert_consumed(&lcg, seed, consumed);
+        }
+    }
+
+    #[test]
+    fn test_normal_level_boost() {
+        // やる気 / プレッシャー / はりきり: 判定で 1 消費追加、成功時は最大レベル
+        let params = PokemonGenerationParams {
+            lead_ability: LeadAbilityEffect::LevelBoost,
+            ..make_params(EncounterType::Normal)
+        };
+        let config = make_config(RomVersion::Black);
+        // (初期 Seed, レベル)
+        for (seed, level) in [(0x9E37_79B9_7F4A_7C15, 10), (0x0000_0000_0000_0000, 5)] {
+            let mut lcg = Lcg64::from_raw(seed);
+            let pokemon = generate_normal_pokemon(&mut lcg, &params, &config);
+
+            assert_eq!(pokemon.level, level, "{seed:#018X}");
+            assert_consumed(&lcg, seed, 7);
+        }
+    }
+
+    #[test]
+    fn test_normal_magnet_pull() {
+        // じりょく成功時: はがねタイプのスロットから再抽選 (1 消費追加)
+        let mut slots: Vec<_> = (0..12).map(|_| make_slots()[0].clone()).collect();
+        slots[11].species_id = 599; // ギアル (はがね)
+        let params = PokemonGenerationParams {
+            lead_ability: LeadAbilityEffect::MagnetPull,
+            slots,
+            ..make_params(EncounterType::Normal)
+        };
+        let config = make_config(RomVersion::Black2);
+        // (初期 Seed, 種族, 消費数)
+        for (seed, species_id, consumed) in [
+            (0x9E37_79B9_7F4A_7C15, 599, 6),
+            (0x0000_0000_0000_0000, 1, 5),
+        ] {
+            let mut lcg = Lcg64::from_raw(seed);
+            let pokemon = generate_normal_pokemon(&mut lcg, &params, &config);
+
+            assert_eq!(pokemon.species_id, species_id, "{seed:#018X}");
+            assert_consumed(&lcg, seed, consumed);
+        }
+    }
+
+    #[test]
+    fn test_normal_intimidate_suppression() {
+        let config = make_config(RomVersion::Black);
+
+        // 先頭 Lv.10 (スロット Lv.5 との差 5): 判定で 1 消費、回避時は Suppressed
+        let params = PokemonGenerationParams {
+            lead_ability: LeadAbilityEffect::Intimidate(10),
+            ..make_params(EncounterType::Normal)
+        };
+        // (初期 Seed, 結果, 消費数)
+        for (seed, result, consumed) in [
+            // sync(1) + slot(1) + level(1) + 回避判定(1)
+            (0x9E37_79B9_7F4A_7C15, EncounterResult::Suppressed, 4),
+            (0x0000_0000_0000_0000, EncounterResult::Pokemon, 7),
+        ] {
+            let mut lcg = Lcg64::from_raw(seed);
+            let pokemon = generate_normal_pokemon(&mut lcg, &params, &config);
+
+            assert_eq!(pokemon.encounter_result, result, "{seed:#018X}");
+            assert_consumed(&lcg, seed, consumed);
+        }
+
+        // 先頭 Lv.9 (差 4): 判定なし
+        let params = PokemonGenerationParams {
+            lead_ability: LeadAbilityEffect::Intimidate(9),
+            ..make_params(EncounterType::Normal)
+        };
+        let mut lcg = Lcg64::from_raw(0x1234_5678_9ABC_DEF0);
+        let pokemon = generate_normal_pokemon(&mut lcg, &params, &config);
+        assert_eq!(pokemon.encounter_result, EncounterResult::Pokemon);
+        assert_consumed(&lcg, 0x1234_5678_9ABC_DEF0, 6);
+    }
+
     #[test]
     fn test_determine_gender() {
         use crate::types::{Gender, Pid};
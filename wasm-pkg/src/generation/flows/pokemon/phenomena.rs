@@ -4,9 +4,10 @@
 
 use crate::core::lcg::Lcg64;
 use crate::generation::algorithm::{
-    calculate_encounter_slot, determine_held_item_slot, determine_nature,
-    dust_cloud_item_table_consume, dust_cloud_result, generate_wild_pid_with_reroll,
-    perform_sync_check, pokemon_shadow_item_table_consume, pokemon_shadow_result, rand_to_percent,
+    LeadCheckResult, cute_charm_gender_force, determine_encounter_slot, determine_held_item_slot,
+    determine_nature, dust_cloud_item_table_consume, dust_cloud_result,
+    generate_wild_pid_with_reroll, level_boost_check, low_level_suppression_check,
+    perform_lead_check, pokemon_shadow_item_table_consume, pokemon_shadow_result, rand_to_percent,
 };
 use crate::generation::flows::types::RawPokemonData;
 use crate::types::{
@@ -20,15 +21,17 @@ use crate::types::{
 ///
 /// # 乱数消費順序
 /// 0. エンカウント判定 (Pokemon vs Item)
-/// 1. シンクロ判定
-/// 2. スロット決定
-/// 3. レベル消費 (値未使用)
-/// 4. PID 生成
+/// 1. 先頭特性判定 (シンクロ / メロメロボディ / じりょく / せいでんき)
+/// 2. スロット決定 (じりょく / せいでんき成功時は再抽選 1 消費を追加)
+/// 3. レベル消費 (値未使用、やる気 / プレッシャー / はりきり先頭時は判定 1 消費を追加)
+///    → いかく / するどいめ判定 (先頭より 5 以上低い場合のみ 1 消費)
+/// 4. PID 生成 (メロメロボディ成功時は性別補正を含む)
 /// 5. 性格決定
 /// 6. 持ち物判定
 /// 7. BW 末尾消費
 ///
 /// Item 取得時は `EncounterResult::Item` を持つ `RawPokemonData` を返す。
+/// いかく / するどいめで回避した場合は `EncounterResult::Suppressed` を返す。
 pub fn generate_phenomena_pokemon(
     lcg: &mut Lcg64,
     params: &PokemonGenerationParams,
@@ -62,30 +65,53 @@ pub fn generate_phenomena_pokemon(
         _ => EncounterResult::Pokemon,
     };
 
-    // 1. シンクロ判定 (ふくがん先頭時はスキップ)
-    let sync_success = if is_compound_eyes {
-        false
+    // 1. 先頭特性判定 (ふくがん先頭時はスキップ)
+    let lead_check = if is_compound_eyes {
+        LeadCheckResult::default()
     } else {
-        perform_sync_check(lcg, enc_type, params.lead_ability)
+        perform_lead_check(lcg, enc_type, params.lead_ability)
     };
 
-    // 2. スロット決定
-    let slot_rand = lcg.next().unwrap_or(0);
-    let slot_idx = calculate_encounter_slot(enc_type, slot_rand, config.version) as usize;
-    let slot_config = &params.slots[slot_idx.min(params.slots.len() - 1)];
+    // 2. スロット決定 (じりょく / せいでんき成功時は該当タイプから再抽選)
+    let slot_idx = determine_encounter_slot(
+        lcg,
+        enc_type,
+        config.version,
+        &params.slots,
+        lead_check.forced_type,
+    );
+    let slot_config = &params.slots[slot_idx];
 
     // 3. レベル消費 (値未使用、テーブル定義の level_min を使用)
+    //    やる気 / プレッシャー / はりきり成功時は level_max
     let _level_rand = lcg.next();
+    let level = if level_boost_check(lcg, params.lead_ability) {
+        slot_config.level_max
+    } else {
+        slot_config.level_min
+    };
+
+    // いかく / するどいめ判定 (先頭より 5 以上低いレベルのみ 1 消費)
+    if low_level_suppression_check(lcg, params.lead_ability, level) {
+        return RawPokemonData::not_pokemon(EncounterResult::Suppressed);
+    }
 
     // 4. PID 生成
     let reroll_count = match config.game_start.shiny_charm {
         ShinyCharmState::Obtained => 2,
         ShinyCharmState::NotObtained => 0,
     };
-    let (pid, shiny_type) = generate_wild_pid_with_reroll(lcg, params.trainer, reroll_count);
+    let gender_force = cute_charm_gender_force(
+        params.lead_ability,
+        lead_check.cute_charm_success,
+        slot_config.gender_ratio,
+    );
+    let (pid, shiny_type) =
+        generate_wild_pid_with_reroll(lcg, params.trainer, reroll_count, gender_force);
 
     // 5. 性格決定
-    let (nature, sync_applied) = determine_nature(lcg, sync_success, params.lead_ability);
+    let (nature, sync_applied) =
+        determine_nature(lcg, lead_check.sync_success, params.lead_ability);
 
     // 6. 持ち物判定
     let held_item_slot = if slot_config.has_held_item {
@@ -107,13 +133,15 @@ pub fn generate_phenomena_pokemon(
     RawPokemonData {
         pid,
         species_id: slot_config.species_id,
-        level: slot_config.level_min,
+        level,
         nature,
         sync_applied,
+        cute_charm_applied: gender_force.is_some(),
         ability_slot,
         gender,
         shiny_type,
         held_item_slot,
+        fishing_bite: None,
         encounter_result,
     }
 }
@@ -122,9 +150,9 @@ pub fn generate_phenomena_pokemon(
 mod tests {
     use super::*;
     use crate::types::{
-        EncounterMethod, EncounterSlotConfig, GameStartConfig, GenderRatio, GenerationConfig,
-        ItemContent, MemoryLinkState, RomVersion, SavePresence, ShinyCharmState, StartMode,
-        TrainerInfo,
+        EncounterMethod, EncounterModifiers, EncounterSlotConfig, GameStartConfig, GenderRatio,
+        GenerationConfig, ItemContent, MemoryLinkState, RomVersion, SavePresence, ShinyCharmState,
+        StartMode, TrainerInfo,
     };
 
     fn make_slots() -> Vec<EncounterSlotConfig> {
@@ -149,6 +177,8 @@ mod tests {
             lead_ability: LeadAbilityEffect::None,
 
             slots: make_slots(),
+            seasonal_slots: None,
+            encounter_modifiers: EncounterModifiers::default(),
         }
     }
 
@@ -2,8 +2,9 @@
 
 use crate::core::lcg::Lcg64;
 use crate::generation::algorithm::{
-    apply_shiny_lock, calculate_level, determine_held_item_slot, generate_event_pid,
-    generate_wild_pid_with_reroll, nature_roll, perform_sync_check,
+    LeadCheckResult, apply_shiny_lock, calculate_level, cute_charm_gender_force,
+    determine_held_item_slot, generate_event_pid, generate_wild_pid_with_reroll, nature_roll,
+    perform_lead_check, perform_sync_check,
 };
 use crate::generation::flows::types::{EncounterSlotConfig, RawPokemonData};
 use crate::types::{
@@ -21,21 +22,28 @@ pub fn generate_static_pokemon(
     let enc_type = params.encounter_type;
     let is_compound_eyes = matches!(params.lead_ability, LeadAbilityEffect::CompoundEyes);
 
-    // シンクロ判定 (StaticSymbol のみ)
-    let sync_success = if enc_type == EncounterType::StaticSymbol && !is_compound_eyes {
-        perform_sync_check(lcg, enc_type, params.lead_ability)
+    // 先頭特性判定 (StaticSymbol のみ)
+    let lead_check = if enc_type == EncounterType::StaticSymbol && !is_compound_eyes {
+        perform_lead_check(lcg, enc_type, params.lead_ability)
     } else {
-        false
+        LeadCheckResult::default()
     };
+    let sync_success = lead_check.sync_success;
 
-    // PID 生成
+    // PID 生成 (メロメロボディ成功時は性別補正を含む)
+    let gender_force = cute_charm_gender_force(
+        params.lead_ability,
+        lead_check.cute_charm_success,
+        slot.gender_ratio,
+    );
     let (pid, shiny_type) = match enc_type {
         EncounterType::StaticSymbol | EncounterType::Roamer => {
             let reroll_count = match config.game_start.shiny_charm {
                 ShinyCharmState::Obtained => 2,
                 ShinyCharmState::NotObtained => 0,
             };
-            let (pid, shiny) = generate_wild_pid_with_reroll(lcg, params.trainer, reroll_count);
+            let (pid, shiny) =
+                generate_wild_pid_with_reroll(lcg, params.trainer, reroll_count, gender_force);
             if slot.shiny_locked {
                 (apply_shiny_lock(pid, params.trainer), ShinyType::None)
             } else {
@@ -92,10 +100,12 @@ pub fn generate_static_pokemon(
         level: slot.level_min,
         nature,
         sync_applied,
+        cute_charm_applied: gender_force.is_some(),
         ability_slot,
         gender,
         shiny_type,
         held_item_slot: HeldItemSlot::None,
+        fishing_bite: None,
         encounter_result: EncounterResult::Pokemon,
     }
 }
@@ -123,6 +133,7 @@ pub fn generate_hidden_grotto_pokemon(
         level_rand,
         slot.level_min,
         slot.level_max,
+        false,
     );
 
     // 2. シンクロ判定
@@ -166,10 +177,12 @@ pub fn generate_hidden_grotto_pokemon(
         level,
         nature,
         sync_applied: sync_success,
+        cute_charm_applied: false,
         ability_slot,
         gender,
         shiny_type: ShinyType::None, // 色違い無効
         held_item_slot,
+        fishing_bite: None,
         encounter_result: EncounterResult::Pokemon,
     }
 }
@@ -178,8 +191,8 @@ pub fn generate_hidden_grotto_pokemon(
 mod tests {
     use super::*;
     use crate::types::{
-        EncounterMethod, GameStartConfig, GenderRatio, GenerationConfig, MemoryLinkState,
-        RomVersion, SavePresence, ShinyCharmState, StartMode, TrainerInfo,
+        EncounterMethod, EncounterModifiers, GameStartConfig, GenderRatio, GenerationConfig,
+        MemoryLinkState, RomVersion, SavePresence, ShinyCharmState, StartMode, TrainerInfo,
     };
 
     fn make_params(encounter_type: EncounterType) -> PokemonGenerationParams {
@@ -193,6 +206,8 @@ mod tests {
             lead_ability: LeadAbilityEffect::None,
 
             slots: vec![],
+            seasonal_slots: None,
+            encounter_modifiers: EncounterModifiers::default(),
         }
     }
 
@@ -4,8 +4,10 @@
 
 use crate::core::lcg::Lcg64;
 use crate::generation::algorithm::{
-    calculate_encounter_slot, calculate_level, determine_held_item_slot, determine_nature,
-    encounter_type_supports_held_item, generate_wild_pid_with_reroll, perform_sync_check,
+    LeadCheckResult, calculate_level, cute_charm_gender_force, determine_encounter_slot,
+    determine_held_item_slot, determine_nature, encounter_type_supports_held_item,
+    generate_wild_pid_with_reroll, level_boost_check, low_level_suppression_check,
+    perform_lead_check, repel_check,
 };
 use crate::generation::flows::types::RawPokemonData;
 use crate::types::{
@@ -19,13 +21,18 @@ use crate::types::{
 ///
 /// # 乱数消費順序
 /// 0. `SurfingBubble`: 泡判定 (TBD)
-/// 1. シンクロ判定
-/// 2. スロット決定
-/// 3. レベル決定 (Range)
-/// 4. PID 生成
+/// 1. 先頭特性判定 (シンクロ / メロメロボディ / じりょく / せいでんき)
+/// 2. スロット決定 (じりょく / せいでんき成功時は再抽選 1 消費を追加)
+/// 3. レベル決定 (Range、やる気 / プレッシャー / はりきり先頭時は判定 1 消費を追加)
+///    → むしよけスプレー判定 (消費なし)
+///    → いかく / するどいめ判定 (先頭より 5 以上低い場合のみ 1 消費)
+/// 4. PID 生成 (メロメロボディ成功時は性別補正を含む)
 /// 5. 性格決定
 /// 6. 持ち物判定
 /// 7. BW 末尾消費
+///
+/// いかく / するどいめで回避した場合は `EncounterResult::Suppressed` を持つ `RawPokemonData` を返す。
+/// むしよけスプレーで回避した場合は `EncounterResult::Repelled` を返す。
 pub fn generate_surfing_pokemon(
     lcg: &mut Lcg64,
     params: &PokemonGenerationParams,
@@ -39,36 +46,60 @@ pub fn generate_surfing_pokemon(
         lcg.next();
     }
 
-    // 1. シンクロ判定 (ふくがん先頭時はスキップ)
-    let sync_success = if is_compound_eyes {
-        false
+    // 1. 先頭特性判定 (ふくがん先頭時はスキップ)
+    let lead_check = if is_compound_eyes {
+        LeadCheckResult::default()
     } else {
-        perform_sync_check(lcg, enc_type, params.lead_ability)
+        perform_lead_check(lcg, enc_type, params.lead_ability)
     };
 
-    // 2. スロット決定
-    let slot_rand = lcg.next().unwrap_or(0);
-    let slot_idx = calculate_encounter_slot(enc_type, slot_rand, config.version) as usize;
-    let slot_config = &params.slots[slot_idx.min(params.slots.len() - 1)];
+    // 2. スロット決定 (じりょく / せいでんき成功時は該当タイプから再抽選)
+    let slot_idx = determine_encounter_slot(
+        lcg,
+        enc_type,
+        config.version,
+        &params.slots,
+        lead_check.forced_type,
+    );
+    let slot_config = &params.slots[slot_idx];
 
     // 3. レベル決定 (Range パターン: 乱数値からレベルを計算)
     let level_rand = lcg.next().unwrap_or(0);
+    let level_boost = level_boost_check(lcg, params.lead_ability);
     let level = calculate_level(
         config.version,
         level_rand,
         slot_config.level_min,
         slot_config.level_max,
+        level_boost,
     );
 
+    // むしよけスプレー判定 (乱数消費なし)
+    if repel_check(params.encounter_modifiers, level) {
+        return RawPokemonData::not_pokemon(EncounterResult::Repelled);
+    }
+
+    // いかく / するどいめ判定 (先頭より 5 以上低いレベルのみ 1 消費)
+    if low_level_suppression_check(lcg, params.lead_ability, level) {
+        return RawPokemonData::not_pokemon(EncounterResult::Suppressed);
+    }
+
     // 4. PID 生成
     let reroll_count = match config.game_start.shiny_charm {
         ShinyCharmState::Obtained => 2,
         ShinyCharmState::NotObtained => 0,
     };
-    let (pid, shiny_type) = generate_wild_pid_with_reroll(lcg, params.trainer, reroll_count);
+    let gender_force = cute_charm_gender_force(
+        params.lead_ability,
+        lead_check.cute_charm_success,
+        slot_config.gender_ratio,
+    );
+    let (pid, shiny_type) =
+        generate_wild_pid_with_reroll(lcg, params.trainer, reroll_count, gender_force);
 
     // 5. 性格決定
-    let (nature, sync_applied) = determine_nature(lcg, sync_success, params.lead_ability);
+    let (nature, sync_applied) =
+        determine_nature(lcg, lead_check.sync_success, params.lead_ability);
 
     // 6. 持ち物判定
     let held_item_slot = if encounter_type_supports_held_item(enc_type) && slot_config.has_held_item
@@ -100,10 +131,12 @@ pub fn generate_surfing_pokemon(
         level,
         nature,
         sync_applied,
+        cute_charm_applied: gender_force.is_some(),
         ability_slot,
         gender,
         shiny_type,
         held_item_slot,
+        fishing_bite: None,
         encounter_result: EncounterResult::Pokemon,
     }
 }
@@ -112,8 +145,9 @@ pub fn generate_surfing_pokemon(
 mod tests {
     use super::*;
     use crate::types::{
-        EncounterMethod, EncounterSlotConfig, GameStartConfig, GenderRatio, GenerationConfig,
-        MemoryLinkState, RomVersion, SavePresence, ShinyCharmState, StartMode, TrainerInfo,
+        EncounterMethod, EncounterModifiers, EncounterSlotConfig, GameStartConfig, GenderRatio,
+        GenerationConfig, MemoryLinkState, RomVersion, SavePresence, ShinyCharmState, StartMode,
+        TrainerInfo,
     };
 
     fn make_slots() -> Vec<EncounterSlotConfig> {
@@ -138,6 +172,8 @@ mod tests {
             lead_ability: LeadAbilityEffect::None,
 
             slots: make_slots(),
+            seasonal_slots: None,
+            encounter_modifiers: EncounterModifiers::default(),
         }
     }
 
@@ -5,9 +5,10 @@
 
 use crate::data::{Stats, calculate_stats, get_species_entry};
 use crate::types::{
-    AbilitySlot, CorePokemonData, EncounterResult, Gender, GeneratedEggData, GeneratedPokemonData,
-    HeldItemSlot, InheritanceSlot, Ivs, MovingEncounterInfo, Nature, NeedleDirection, Pid,
-    SeedOrigin, ShinyType, SpecialEncounterInfo,
+    AbilitySlot, CorePokemonData, EncounterResult, FishingBite, Gender,
+    GeneratedDoubleEncounterData, GeneratedEggData, GeneratedPokemonData, HeldItemSlot,
+    InheritanceSlot, Ivs, MovingEncounterInfo, Nature, NeedleDirection, Pid, Season, SeedOrigin,
+    ShinyType, SpecialEncounterInfo,
 };
 
 // Re-export for internal use
@@ -32,10 +33,12 @@ pub struct RawPokemonData {
     pub level: u8,
     pub nature: Nature,
     pub sync_applied: bool,
+    pub cute_charm_applied: bool,
     pub ability_slot: AbilitySlot,
     pub gender: Gender,
     pub shiny_type: ShinyType,
     pub held_item_slot: HeldItemSlot,
+    pub fishing_bite: Option<FishingBite>,
     pub encounter_result: EncounterResult,
 }
 
@@ -50,10 +53,12 @@ impl RawPokemonData {
             level: 0,
             nature: Nature::Hardy,
             sync_applied: false,
+            cute_charm_applied: false,
             ability_slot: AbilitySlot::First,
             gender: Gender::Genderless,
             shiny_type: ShinyType::None,
             held_item_slot: HeldItemSlot::None,
+            fishing_bite: None,
             encounter_result,
         }
     }
@@ -83,33 +88,70 @@ impl GeneratedPokemonData {
         moving_encounter: Option<MovingEncounterInfo>,
         special_encounter: Option<SpecialEncounterInfo>,
     ) -> Self {
-        let stats = if raw.species_id > 0 {
-            let entry = get_species_entry(raw.species_id);
-            calculate_stats(entry.base_stats, ivs, raw.nature, raw.level)
-        } else {
-            Stats::UNKNOWN
-        };
-
+        let season = source.datetime().map(|d| Season::from_month(d.month));
         Self {
             advance,
             needle_direction,
             source,
-            core: CorePokemonData {
-                pid: raw.pid,
-                nature: raw.nature,
-                ability_slot: raw.ability_slot,
-                gender: raw.gender,
-                shiny_type: raw.shiny_type,
-                ivs,
-                stats,
-                species_id: raw.species_id,
-                level: raw.level,
-            },
+            core: CorePokemonData::from_raw(raw, ivs),
             sync_applied: raw.sync_applied,
+            cute_charm_applied: raw.cute_charm_applied,
             held_item_slot: raw.held_item_slot,
+            fishing_bite: raw.fishing_bite,
             moving_encounter,
             special_encounter,
             encounter_result: raw.encounter_result,
+            season,
+        }
+    }
+}
+
+impl GeneratedDoubleEncounterData {
+    /// 2 体分の `RawPokemonData` と個体値から構築
+    pub fn from_raw_pair(
+        raws: &[RawPokemonData; 2],
+        ivs: [Ivs; 2],
+        advance: u32,
+        needle_direction: NeedleDirection,
+        source: SeedOrigin,
+        moving_encounter: Option<MovingEncounterInfo>,
+    ) -> Self {
+        let season = source.datetime().map(|d| Season::from_month(d.month));
+        Self {
+            advance,
+            needle_direction,
+            source,
+            first: CorePokemonData::from_raw(&raws[0], ivs[0]),
+            second: CorePokemonData::from_raw(&raws[1], ivs[1]),
+            sync_applied: raws[0].sync_applied,
+            cute_charm_applied: [raws[0].cute_charm_applied, raws[1].cute_charm_applied],
+            moving_encounter,
+            encounter_result: raws[0].encounter_result,
+            season,
+        }
+    }
+}
+
+impl CorePokemonData {
+    /// `RawPokemonData` と個体値から構築 (実ステータスを算出)
+    fn from_raw(raw: &RawPokemonData, ivs: Ivs) -> Self {
+        let stats = if raw.species_id > 0 {
+            let entry = get_species_entry(raw.species_id);
+            calculate_stats(entry.base_stats, ivs, raw.nature, raw.level)
+        } else {
+            Stats::UNKNOWN
+        };
+
+        Self {
+            pid: raw.pid,
+            nature: raw.nature,
+            ability_slot: raw.ability_slot,
+            gender: raw.gender,
+            shiny_type: raw.shiny_type,
+            ivs,
+            stats,
+            species_id: raw.species_id,
+            level: raw.level,
         }
     }
 }
@@ -10,7 +10,8 @@ pub use algorithm::calc_report_needle_direction;
 pub use flows::{
     EggGenerator, EncounterMethod, EncounterSlotConfig, GeneratedEggData, GeneratedPokemonData,
     GenerationError, MovingEncounterInfo, MovingEncounterLikelihood, PokemonGenerator, RawEggData,
-    RawPokemonData, SpecialEncounterDirection, SpecialEncounterInfo, generate_egg,
-    generate_egg_list, generate_hidden_grotto_pokemon, generate_pokemon_list,
-    generate_static_pokemon, generate_wild_pokemon,
+    RawPokemonData, SpecialEncounterDirection, SpecialEncounterInfo,
+    generate_double_encounter_list, generate_egg, generate_egg_list,
+    generate_hidden_grotto_pokemon, generate_pokemon_list, generate_static_pokemon,
+    generate_wild_pokemon,
 };
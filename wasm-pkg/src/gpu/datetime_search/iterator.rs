@@ -85,6 +85,7 @@ impl GpuDatetimeSearchIterator {
     ///
     /// - GPU デバイスが利用不可の場合
     /// - `target_seeds` が空の場合
+    /// - `auto_ranges` 指定時に既定範囲が未収集の場合
     /// - 組み合わせが空の場合
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = "create"))]
     pub async fn create(
@@ -96,7 +97,7 @@ impl GpuDatetimeSearchIterator {
         }
 
         // 組み合わせ展開
-        let combinations = expand_combinations(&context);
+        let combinations = expand_combinations(&context)?;
         if combinations.is_empty() {
             return Err("no valid combinations".into());
         }
@@ -306,6 +307,7 @@ mod tests {
                 vcount_max: 0x5B,
             }],
             key_spec: KeySpec::from_buttons(vec![]),
+            auto_ranges: false,
         }
     }
 
@@ -412,6 +414,7 @@ mod tests {
                 vcount_max: 0x60,
             }],
             key_spec: KeySpec::from_buttons(vec![]),
+            auto_ranges: false,
         };
         let target_seeds = vec![MtSeed::new(0x32bf_6858)];
 
@@ -19,26 +19,39 @@ pub mod gpu;
 
 // Re-export datetime_search (Searcher と関数のみ)
 pub use datetime_search::{
-    EggDatetimeSearcher, MtseedDatetimeSearcher, TrainerInfoSearcher, generate_egg_search_tasks,
-    generate_mtseed_search_tasks, generate_trainer_info_search_tasks, split_search_range,
+    CalibrationSearcher, EggDatetimeSearcher, IvDatetimeSearcher, LcgseedDatetimeSearcher,
+    MtseedDatetimeSearcher, NeedleDatetimeSearcher, PokemonDatetimeSearcher, TrainerInfoSearcher,
+    generate_calibration_search_tasks, generate_egg_search_tasks, generate_iv_search_tasks,
+    generate_lcgseed_search_tasks, generate_mtseed_search_tasks,
+    generate_mtseed_search_tasks_from_batches, generate_mtseed_search_tasks_from_egg_batches,
+    generate_needle_search_tasks, generate_pokemon_search_tasks,
+    generate_trainer_info_search_tasks, split_search_range,
 };
 
 // Re-export common types
 pub use types::{
-    AbilitySlot, CoreDataFilter, CorePokemonData, DateRangeParams, Datetime, DatetimeSearchContext,
-    DsButton, DsConfig, EggDatetimeSearchBatch, EggDatetimeSearchParams, EggDatetimeSearchResult,
-    EggFilter, EggGenerationParams, EncounterMethod, EncounterResult, EncounterSlotConfig,
-    EncounterType, EverstonePlan, GameStartConfig, GenderRatio, GeneratedEggData,
-    GeneratedPokemonData, GenerationConfig, HeldItemSlot, HiddenPowerType, IV_VALUE_UNKNOWN,
-    ItemContent, IvFilter, Ivs, KeyInput, KeyMask, KeySpec, LcgSeed, LeadAbilityEffect,
-    MemoryLinkState, MovingEncounterInfo, MovingEncounterLikelihood, MtSeed,
-    MtseedDatetimeSearchBatch, MtseedDatetimeSearchParams, MtseedResult, MtseedSearchBatch,
-    MtseedSearchContext, MtseedSearchParams, NeedleDirection, NeedlePattern, Pid, PokemonFilter,
-    PokemonGenerationParams, RomVersion, SavePresence, SearchRangeParams, SeedOrigin, SeedSpec,
-    ShinyCharmState, ShinyFilter, SpecialEncounterDirection, SpecialEncounterInfo, StartMode,
-    Stats, StatsFilter, TimeRangeParams, Timer0VCountRange, TrainerInfo, TrainerInfoFilter,
-    TrainerInfoSearchBatch, TrainerInfoSearchParams, TrainerInfoSearchResult, UiEggData,
-    UiPokemonData,
+    AbilitySlot, BreedingParents, CalibrationOutcome, CalibrationResult, CalibrationSample,
+    CalibrationSearchBatch, CalibrationSearchParams, CoreDataFilter, CorePokemonData,
+    DateRangeParams, Datetime, DatetimeSearchContext, DsButton, DsConfig, EggDatetimeSearchBatch,
+    EggDatetimeSearchParams, EggDatetimeSearchResult, EggFilter, EggGenerationParams, EggGroup,
+    EggMtseedResult, EggMtseedSearchBatch, EggMtseedSearchContext, EggMtseedSearchParams,
+    EggSpeciesResolution, EncounterMethod, EncounterModifiers, EncounterResult,
+    EncounterSlotConfig, EncounterType, EverstonePlan, FishingBite, FluteEffect, GameStartConfig,
+    GenderRatio, GeneratedDoubleEncounterData, GeneratedEggData, GeneratedPokemonData,
+    GenerationConfig, HeldItemSlot, HiddenPowerType, IV_VALUE_UNKNOWN, ItemContent,
+    IvDatetimeSearchBatch, IvDatetimeSearchParams, IvDatetimeSearchResult, IvFilter, Ivs, KeyInput,
+    KeyMask, KeySpec, LcgSeed, LcgseedDatetimeSearchBatch, LcgseedDatetimeSearchParams,
+    LeadAbilityEffect, MemoryLinkState, MissDiagnosisParams, MissDiagnosisResult, MissObservation,
+    MovingEncounterInfo, MovingEncounterLikelihood, MtSeed, MtseedDatetimeSearchBatch,
+    MtseedDatetimeSearchParams, MtseedResult, MtseedSearchBatch, MtseedSearchContext,
+    MtseedSearchParams, NeedleDatetimeSearchBatch, NeedleDatetimeSearchParams, NeedleDirection,
+    NeedlePattern, Pid, PokemonDatetimeSearchBatch, PokemonDatetimeSearchParams,
+    PokemonDatetimeSearchResult, PokemonFilter, PokemonGenerationParams, PokemonObservation,
+    PokemonType, PowerItem, RomVersion, SavePresence, SearchRangeParams, Season, SeasonalSlots,
+    SeedOrigin, SeedSpec, ShinyCharmState, ShinyFilter, SpecialEncounterDirection,
+    SpecialEncounterInfo, StartMode, Stats, StatsFilter, TimeRangeParams, Timer0VCountRange,
+    TrainerInfo, TrainerInfoFilter, TrainerInfoSearchBatch, TrainerInfoSearchParams,
+    TrainerInfoSearchResult, UiEggData, UiPokemonData,
 };
 
 // Re-export core functions
@@ -47,11 +60,17 @@ pub use core::seed_resolver::resolve_seeds;
 
 // Re-export misc module (Searcher と関数)
 pub use misc::{
-    MtseedSearcher, generate_mtseed_iv_search_tasks, get_needle_pattern_at, search_needle_pattern,
+    EggMtseedSearcher, MtseedSearcher, NeedleTracker, diagnose_missed_hit, find_egg_by_observation,
+    find_pokemon_by_observation, generate_egg_mtseed_iv_search_tasks,
+    generate_mtseed_iv_search_tasks, get_needle_pattern_at, search_needle_pattern,
+    search_needle_pattern_fuzzy,
 };
 
 // Re-export needle search types
-pub use types::NeedleSearchResult;
+pub use types::{
+    FuzzyNeedlePattern, NeedleFuzzySearchResult, NeedleMatchMode, NeedlePatternEntry,
+    NeedleSearchResult, NeedleTrackerCandidate, NeedleTrackerStatus,
+};
 
 // Re-export resolve module
 pub use resolve::{resolve_egg_data, resolve_pokemon_data};
@@ -81,6 +100,119 @@ pub fn get_species_gender_ratio(species_id: u16) -> types::GenderRatio {
     data::species::get_species_entry(species_id).gender_ratio
 }
 
+/// 両親の種族から孵化個体の種族を決定
+///
+/// # Arguments
+/// * `parents` - 両親の種族とおこうの有無
+///
+/// # Errors
+/// 種族 ID が範囲外、またはタマゴを作れない組み合わせの場合にエラーを返す。
+#[wasm_bindgen]
+#[allow(clippy::needless_pass_by_value)]
+pub fn resolve_egg_species(parents: BreedingParents) -> Result<EggSpeciesResolution, JsValue> {
+    data::resolve_egg_species(&parents).map_err(|e| JsValue::from_str(&e))
+}
+
+/// DS 設定に対応する既定の `Timer0` / `VCount` 範囲を取得
+///
+/// # Arguments
+/// * `ds` - DS 設定 (ハードウェア・ROM バージョン・リージョンを参照)
+///
+/// # Returns
+/// 既定範囲。未収集の組み合わせの場合は `None`。
+#[wasm_bindgen]
+#[allow(clippy::needless_pass_by_value)]
+pub fn get_default_timer0_vcount_ranges(ds: DsConfig) -> Option<Vec<Timer0VCountRange>> {
+    data::default_ranges(&ds)
+}
+
+/// 指定ロケーションのエンカウントスロットを取得
+///
+/// # Arguments
+/// * `version` - ROMバージョン
+/// * `location` - ロケーションキー (空白・ダッシュ類・ピリオドは無視)
+/// * `encounter_type` - エンカウント種別
+///
+/// # Returns
+/// `PokemonGenerationParams.slots` にそのまま渡せるスロット設定。存在しない場合は `None`。
+#[wasm_bindgen]
+pub fn get_encounter_slots(
+    version: RomVersion,
+    location: &str,
+    encounter_type: EncounterType,
+) -> Option<Vec<EncounterSlotConfig>> {
+    data::get_encounter_slots(version, location, encounter_type)
+}
+
+/// 季節で出現ポケモンが変わるロケーションの季節別スロットを取得
+///
+/// # Arguments
+/// * `version` - ROMバージョン
+/// * `location` - 季節サフィックスを除いたロケーションキー (例: `"route_6"`)
+/// * `encounter_type` - エンカウント種別
+///
+/// # Returns
+/// `PokemonGenerationParams.seasonal_slots` にそのまま渡せる季節別スロット。存在しない場合は `None`。
+#[wasm_bindgen]
+pub fn get_seasonal_encounter_slots(
+    version: RomVersion,
+    location: &str,
+    encounter_type: EncounterType,
+) -> Option<SeasonalSlots> {
+    data::get_seasonal_encounter_slots(version, location, encounter_type)
+}
+
+/// 指定バージョン・エンカウント種別で利用可能なロケーションキー一覧を取得
+///
+/// # Arguments
+/// * `version` - ROMバージョン
+/// * `encounter_type` - エンカウント種別
+#[wasm_bindgen]
+pub fn list_encounter_locations(version: RomVersion, encounter_type: EncounterType) -> Vec<String> {
+    data::list_encounter_locations(version, encounter_type)
+        .into_iter()
+        .map(str::to_string)
+        .collect()
+}
+
+/// 固定・ギフトエンカウントのポケモン生成パラメータを取得
+///
+/// # Arguments
+/// * `version` - ROMバージョン
+/// * `id` - エンカウント ID (例: `"reshiram-n-castle"`)
+/// * `trainer` - トレーナー情報
+/// * `lead_ability` - 先頭特性効果
+///
+/// # Returns
+/// 生成パラメータ。該当エントリが存在しない場合は `None`。
+#[wasm_bindgen]
+#[allow(clippy::needless_pass_by_value)]
+pub fn get_static_encounter_params(
+    version: RomVersion,
+    id: &str,
+    trainer: TrainerInfo,
+    lead_ability: LeadAbilityEffect,
+) -> Option<PokemonGenerationParams> {
+    data::get_static_encounter(version, id)
+        .map(|entry| entry.to_generation_params(trainer, lead_ability))
+}
+
+/// 指定バージョン・エンカウント種別の固定・ギフトエンカウント ID 一覧を取得
+///
+/// # Arguments
+/// * `version` - ROMバージョン
+/// * `encounter_type` - エンカウント種別
+#[wasm_bindgen]
+pub fn list_static_encounter_ids(
+    version: RomVersion,
+    encounter_type: EncounterType,
+) -> Vec<String> {
+    data::list_static_encounters(version, encounter_type)
+        .into_iter()
+        .map(|entry| entry.id.to_string())
+        .collect()
+}
+
 // Re-export GPU module (when enabled)
 #[cfg(feature = "gpu")]
 pub use gpu::{
@@ -0,0 +1,364 @@
+//! 孵化 MT Seed 全探索
+//!
+//! 両親の個体値と遺伝を考慮し、孵化個体の IV が検索条件を満たし得る MT Seed を全探索する機能。
+//! 各 Seed について、条件を満たす遺伝パターン (遺伝ステータス 3 種 × 遺伝元親) を列挙する。
+//!
+//! 見つかった Seed の起動時刻は、`generate_mtseed_search_tasks_from_egg_batches` で
+//! `MtseedDatetimeSearcher` のタスクに変換して検索する。
+//! 得られた `SeedOrigin::Startup` の日時・`StartupCondition` を `EggDatetimeSearchParams` の
+//! `search_range` / `condition` に指定すれば、性格・色違い等を含めて孵化個体を絞り込める。
+
+use wasm_bindgen::prelude::*;
+
+use super::mtseed_search::split_seed_space;
+use crate::generation::algorithm::{
+    apply_inheritance, generate_rng_ivs_with_offset, generate_rng_ivs_with_offset_x4,
+};
+use crate::types::{
+    EggMtseedResult, EggMtseedSearchBatch, EggMtseedSearchContext, EggMtseedSearchParams,
+    InheritanceSlot, IvFilter, Ivs, MtSeed,
+};
+
+/// 遺伝ステータスの組み合わせ (6 種から 3 種、昇順)
+const STAT_TRIPLES: [[u8; 3]; 20] = [
+    [0, 1, 2],
+    [0, 1, 3],
+    [0, 1, 4],
+    [0, 1, 5],
+    [0, 2, 3],
+    [0, 2, 4],
+    [0, 2, 5],
+    [0, 3, 4],
+    [0, 3, 5],
+    [0, 4, 5],
+    [1, 2, 3],
+    [1, 2, 4],
+    [1, 2, 5],
+    [1, 3, 4],
+    [1, 3, 5],
+    [1, 4, 5],
+    [2, 3, 4],
+    [2, 3, 5],
+    [2, 4, 5],
+    [3, 4, 5],
+];
+
+/// 孵化 MT Seed 検索器
+#[wasm_bindgen]
+pub struct EggMtseedSearcher {
+    iv_filter: IvFilter,
+    parent_male: Ivs,
+    parent_female: Ivs,
+    /// 親ごとの各ステータスが範囲条件を満たすか (`[Male, Female]`)
+    parent_matches: [[bool; 6]; 2],
+    mt_offset: u32,
+    current_seed: u64,
+    /// 検索開始 Seed (進捗計算用)
+    start_seed: u64,
+    /// 半開区間の終端 (`end_seed_inclusive` + 1)
+    end_seed: u64,
+}
+
+#[wasm_bindgen]
+impl EggMtseedSearcher {
+    #[wasm_bindgen(constructor)]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn new(params: EggMtseedSearchParams) -> EggMtseedSearcher {
+        let start = u64::from(params.start_seed);
+        let end = u64::from(params.end_seed) + 1; // 閉区間 → 半開区間
+        let parent_matches = [params.parent_male, params.parent_female]
+            .map(|parent| std::array::from_fn(|i| stat_matches(&params.iv_filter, parent, i)));
+        Self {
+            iv_filter: params.iv_filter,
+            parent_male: params.parent_male,
+            parent_female: params.parent_female,
+            parent_matches,
+            mt_offset: params.mt_offset,
+            current_seed: start,
+            start_seed: start,
+            end_seed: end,
+        }
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn is_done(&self) -> bool {
+        self.current_seed >= self.end_seed
+    }
+
+    #[wasm_bindgen(getter)]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn progress(&self) -> f64 {
+        let total = self.end_seed - self.start_seed;
+        if total == 0 {
+            return 1.0;
+        }
+        (self.current_seed - self.start_seed) as f64 / total as f64
+    }
+
+    /// 次のバッチを検索
+    pub fn next_batch(&mut self, chunk_size: u32) -> EggMtseedSearchBatch {
+        let mut candidates = Vec::new();
+        let batch_end = (self.current_seed + u64::from(chunk_size)).min(self.end_seed);
+        let total = self.end_seed - self.start_seed;
+
+        // 4 Seed 単位で SIMD 処理
+        while self.current_seed + 4 <= batch_end {
+            #[allow(clippy::cast_possible_truncation)]
+            let base = self.current_seed as u32;
+            #[allow(clippy::cast_possible_truncation)]
+            let seeds: [MtSeed; 4] = std::array::from_fn(|i| MtSeed::new(base + i as u32));
+            let ivs_x4 = generate_rng_ivs_with_offset_x4(seeds, self.mt_offset, false);
+
+            for (seed, ivs) in seeds.into_iter().zip(ivs_x4) {
+                if let Some(result) = self.evaluate(seed, ivs) {
+                    candidates.push(result);
+                }
+            }
+
+            self.current_seed += 4;
+        }
+
+        // 端数処理 (残り 1〜3 Seed)
+        while self.current_seed < batch_end {
+            #[allow(clippy::cast_possible_truncation)]
+            let seed = MtSeed::new(self.current_seed as u32);
+
+            let ivs = generate_rng_ivs_with_offset(seed, self.mt_offset, false);
+
+            if let Some(result) = self.evaluate(seed, ivs) {
+                candidates.push(result);
+            }
+
+            self.current_seed += 1;
+        }
+
+        EggMtseedSearchBatch {
+            candidates,
+            processed: self.current_seed - self.start_seed,
+            total,
+        }
+    }
+}
+
+impl EggMtseedSearcher {
+    /// 乱数 IV に対して条件を満たす遺伝パターンを列挙
+    ///
+    /// 1 つも存在しない場合は `None` を返す。
+    fn evaluate(&self, seed: MtSeed, rng_ivs: Ivs) -> Option<EggMtseedResult> {
+        // 範囲条件を満たさないステータス (遺伝で上書きが必要)
+        let mut required_mask = 0u8;
+        for i in 0..6 {
+            if !stat_matches(&self.iv_filter, rng_ivs, i) {
+                required_mask |= 1 << i;
+            }
+        }
+        if required_mask.count_ones() > 3 {
+            return None;
+        }
+
+        let mut patterns = Vec::new();
+        for stats in STAT_TRIPLES {
+            let stat_mask = stats.iter().fold(0u8, |mask, &stat| mask | (1 << stat));
+            if required_mask & !stat_mask != 0 {
+                continue;
+            }
+
+            for parent_bits in 0..8_u8 {
+                let slots: [InheritanceSlot; 3] =
+                    std::array::from_fn(|i| InheritanceSlot::new(stats[i], (parent_bits >> i) & 1));
+                if !slots.iter().all(|slot| {
+                    self.parent_matches[usize::from(slot.parent)][usize::from(slot.stat)]
+                }) {
+                    continue;
+                }
+
+                // めざパ条件は遺伝適用後の IV で判定
+                let ivs = apply_inheritance(rng_ivs, self.parent_male, self.parent_female, slots);
+                if self.iv_filter.matches(&ivs) {
+                    patterns.push(slots);
+                }
+            }
+        }
+
+        if patterns.is_empty() {
+            None
+        } else {
+            Some(EggMtseedResult {
+                seed,
+                rng_ivs,
+                patterns,
+            })
+        }
+    }
+}
+
+/// 指定ステータスの IV が範囲条件を満たすか判定
+fn stat_matches(filter: &IvFilter, ivs: Ivs, stat: usize) -> bool {
+    let range = match stat {
+        0 => filter.hp,
+        1 => filter.atk,
+        2 => filter.def,
+        3 => filter.spa,
+        4 => filter.spd,
+        _ => filter.spe,
+    };
+    IvFilter::check_stat(ivs.get(stat), range)
+}
+
+/// 孵化 MT Seed IV 検索タスクを生成
+///
+/// 全 Seed 空間 (0〜2^32-1) を `worker_count` 個のタスクに均等分割する。
+///
+/// # Arguments
+/// - `context`: 検索コンテキスト (`iv_filter`, 両親の個体値, `mt_offset`)
+/// - `worker_count`: Worker 数
+///
+/// # Returns
+/// 分割されたタスクのリスト（各タスクは閉区間 `[start_seed, end_seed]`）
+#[wasm_bindgen]
+#[allow(clippy::needless_pass_by_value)]
+pub fn generate_egg_mtseed_iv_search_tasks(
+    context: EggMtseedSearchContext,
+    worker_count: u32,
+) -> Vec<EggMtseedSearchParams> {
+    split_seed_space(worker_count)
+        .into_iter()
+        .map(|(start_seed, end_seed)| EggMtseedSearchParams {
+            iv_filter: context.iv_filter.clone(),
+            parent_male: context.parent_male,
+            parent_female: context.parent_female,
+            mt_offset: context.mt_offset,
+            start_seed,
+            end_seed,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::IV_VALUE_UNKNOWN;
+
+    fn make_params(iv_filter: IvFilter, start_seed: u32, end_seed: u32) -> EggMtseedSearchParams {
+        EggMtseedSearchParams {
+            iv_filter,
+            parent_male: Ivs::new(31, 31, 31, 0, 0, 0),
+            parent_female: Ivs::new(0, 0, 0, 31, 31, 31),
+            mt_offset: 7,
+            start_seed,
+            end_seed,
+        }
+    }
+
+    #[test]
+    fn test_egg_mtseed_searcher_any() {
+        // 全範囲フィルタなら全遺伝パターン (20 × 8) が条件を満たす
+        let mut searcher = EggMtseedSearcher::new(make_params(IvFilter::any(), 0, 99));
+        let batch = searcher.next_batch(200);
+
+        assert_eq!(batch.candidates.len(), 100);
+        assert_eq!(batch.processed, 100);
+        assert_eq!(batch.total, 100);
+        assert!(searcher.is_done());
+        assert!(batch.candidates.iter().all(|c| c.patterns.len() == 160));
+    }
+
+    #[test]
+    fn test_egg_mtseed_searcher_patterns_reach_target() {
+        // 両親から 3V ずつ: 遺伝で 31 を 3 箇所確保できる Seed のみ一致
+        let filter = IvFilter {
+            hp: (31, 31),
+            def: (31, 31),
+            spe: (31, 31),
+            ..IvFilter::any()
+        };
+        let params = make_params(filter.clone(), 0, 0xFFFF);
+        let (male, female) = (params.parent_male, params.parent_female);
+        let mut searcher = EggMtseedSearcher::new(params);
+        let batch = searcher.next_batch(0x1_0000);
+
+        assert!(!batch.candidates.is_empty());
+        for candidate in &batch.candidates {
+            assert_eq!(
+                candidate.rng_ivs,
+                generate_rng_ivs_with_offset(candidate.seed, 7, false)
+            );
+            for &slots in &candidate.patterns {
+                assert!(slots[0].stat < slots[1].stat && slots[1].stat < slots[2].stat);
+                let ivs = apply_inheritance(candidate.rng_ivs, male, female, slots);
+                assert!(filter.matches(&ivs));
+            }
+        }
+
+        // 乱数 IV が H/B/S すべて 31 でない Seed は、全パターンで 3 箇所を遺伝が担う
+        let inherited_only = batch
+            .candidates
+            .iter()
+            .find(|c| c.rng_ivs.hp != 31 && c.rng_ivs.def != 31 && c.rng_ivs.spe != 31)
+            .unwrap();
+        let expected = [
+            InheritanceSlot::new(0, 0),
+            InheritanceSlot::new(2, 0),
+            InheritanceSlot::new(5, 1),
+        ];
+        assert_eq!(inherited_only.patterns, vec![expected]);
+    }
+
+    #[test]
+    fn test_egg_mtseed_searcher_unreachable() {
+        // 遺伝する 3 箇所はすべて 0 になるため 6V には到達しない
+        let params = EggMtseedSearchParams {
+            parent_male: Ivs::new(0, 0, 0, 0, 0, 0),
+            parent_female: Ivs::new(0, 0, 0, 0, 0, 0),
+            ..make_params(IvFilter::six_v(), 0, 999)
+        };
+        let mut searcher = EggMtseedSearcher::new(params);
+        let batch = searcher.next_batch(1000);
+        assert!(batch.candidates.is_empty());
+    }
+
+    #[test]
+    fn test_egg_mtseed_searcher_unknown_parent_iv() {
+        // 親の個体値が不明なステータスは範囲指定があると遺伝元にならない
+        let filter = IvFilter {
+            hp: (31, 31),
+            ..IvFilter::any()
+        };
+        let params = EggMtseedSearchParams {
+            parent_male: Ivs::new(IV_VALUE_UNKNOWN, 0, 0, 0, 0, 0),
+            parent_female: Ivs::new(31, 0, 0, 0, 0, 0),
+            ..make_params(filter, 0, 0xFFF)
+        };
+        let mut searcher = EggMtseedSearcher::new(params);
+        let batch = searcher.next_batch(0x1000);
+
+        for candidate in batch.candidates.iter().filter(|c| c.rng_ivs.hp != 31) {
+            for slots in &candidate.patterns {
+                let hp_slot = slots.iter().find(|slot| slot.stat == 0).unwrap();
+                assert_eq!(hp_slot.parent, 1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_generate_egg_mtseed_iv_search_tasks() {
+        let ctx = EggMtseedSearchContext {
+            iv_filter: IvFilter::six_v(),
+            parent_male: Ivs::new(31, 31, 31, 31, 31, 31),
+            parent_female: Ivs::new(0, 0, 0, 0, 0, 0),
+            mt_offset: 7,
+        };
+        let tasks = generate_egg_mtseed_iv_search_tasks(ctx, 3);
+
+        assert_eq!(tasks.len(), 3);
+        assert_eq!(tasks[0].start_seed, 0);
+        assert_eq!(tasks[2].end_seed, 0xFFFF_FFFF);
+        for i in 1..tasks.len() {
+            assert_eq!(tasks[i].start_seed, tasks[i - 1].end_seed + 1);
+        }
+        for task in &tasks {
+            assert_eq!(task.mt_offset, 7);
+            assert_eq!(task.parent_male, Ivs::new(31, 31, 31, 31, 31, 31));
+        }
+    }
+}
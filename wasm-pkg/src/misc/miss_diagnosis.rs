@@ -0,0 +1,444 @@
+//! 外れ診断
+//!
+//! 狙った起動条件で目的の結果が得られなかった場合に、
+//! 起動日時 (± 秒)・`Timer0`・`VCount`・キー入力の周辺を探索し、
+//! 実際に得られた結果 (捕獲個体・針パターン・個体値) と一致する起動条件と消費位置を推定する。
+
+use wasm_bindgen::prelude::*;
+
+use crate::data::default_ranges;
+use crate::datetime_search::base::{
+    DatetimeHashGenerator, datetime_to_seconds, seconds_to_datetime,
+};
+use crate::generation::algorithm::generate_rng_ivs_with_offset;
+use crate::types::{
+    Datetime, KeyMask, KeySpec, MissDiagnosisParams, MissDiagnosisResult, MissObservation,
+    SearchRangeParams, SeedOrigin, StartupCondition, TimeRangeParams, Timer0VCountRange,
+};
+
+use super::reverse_lookup::{find_egg_by_observation, find_pokemon_by_observation};
+use super::search_needle_pattern;
+
+/// 照合 1 回あたりの `SeedOrigin` 数の上限
+///
+/// 探索幅が大きい場合でも候補を全件保持せず、この件数ごとに観測値と照合する。
+const ORIGIN_BATCH_SIZE: usize = 4096;
+
+/// 外れ診断 (公開 API)
+///
+/// `intended` の周辺の起動条件を全て列挙し、観測値と一致する候補を
+/// 狙った条件に近い順 (`distance` 昇順、同距離は `advance` 昇順) で返す。
+///
+/// `Timer0` / `VCount` の探索範囲は `ds` の既定範囲 (`default_ranges`) 内に制限する。
+/// 既定範囲が未収集の組み合わせの場合は ± 探索幅をそのまま使用する。
+///
+/// # Errors
+/// - `intended` が `SeedOrigin::Startup` でない場合
+/// - 観測値の検証失敗 (種族 ID・実ステータス・スロット・針パターン等)
+/// - 起動設定が無効な場合
+#[wasm_bindgen]
+#[allow(clippy::needless_pass_by_value)]
+pub fn diagnose_missed_hit(
+    params: MissDiagnosisParams,
+) -> Result<Vec<MissDiagnosisResult>, String> {
+    let SeedOrigin::Startup {
+        datetime: intended_datetime,
+        condition: intended_condition,
+        ..
+    } = params.intended
+    else {
+        return Err("intended must be SeedOrigin::Startup".into());
+    };
+
+    let search_range = neighbour_search_range(intended_datetime, params.second_radius);
+    let time_range = TimeRangeParams {
+        hour_start: 0,
+        hour_end: 23,
+        minute_start: 0,
+        minute_end: 59,
+        second_start: 0,
+        second_end: 59,
+    };
+
+    let mut matches: Vec<(SeedOrigin, Option<u32>)> = Vec::new();
+    let mut batch: Vec<SeedOrigin> = Vec::with_capacity(ORIGIN_BATCH_SIZE);
+    for candidate in neighbour_conditions(&params, intended_condition) {
+        let mut generator =
+            DatetimeHashGenerator::new(&params.ds, &time_range, &search_range, candidate)?;
+
+        while !generator.is_exhausted() {
+            let (entries, len) = generator.next_quad();
+            if len == 0 {
+                break;
+            }
+            for (datetime, hash_values) in entries.iter().take(len as usize) {
+                batch.push(SeedOrigin::startup(
+                    hash_values.to_lcg_seed(),
+                    *datetime,
+                    candidate,
+                ));
+            }
+            if batch.len() >= ORIGIN_BATCH_SIZE {
+                matches.extend(match_observation(std::mem::take(&mut batch), &params)?);
+            }
+        }
+    }
+    if !batch.is_empty() {
+        matches.extend(match_observation(batch, &params)?);
+    }
+
+    let mut results: Vec<MissDiagnosisResult> = matches
+        .into_iter()
+        .map(|(source, advance)| {
+            diagnose_result(source, advance, intended_datetime, intended_condition)
+        })
+        .collect();
+    results.sort_by_key(|r| (r.distance, r.advance));
+
+    Ok(results)
+}
+
+/// `SeedOrigin` 群を観測値と照合
+///
+/// # Returns
+/// 一致した `SeedOrigin` と消費位置 (`Iv` 観測の場合は None) の組
+fn match_observation(
+    origins: Vec<SeedOrigin>,
+    params: &MissDiagnosisParams,
+) -> Result<Vec<(SeedOrigin, Option<u32>)>, String> {
+    let config = &params.config;
+
+    let matches = match &params.observation {
+        MissObservation::Pokemon {
+            params: pokemon_params,
+            observation,
+        } => find_pokemon_by_observation(
+            origins,
+            pokemon_params.clone(),
+            config.clone(),
+            observation.clone(),
+        )?
+        .into_iter()
+        .map(|data| (data.source, Some(data.advance)))
+        .collect(),
+        MissObservation::Egg {
+            params: egg_params,
+            observation,
+        } => find_egg_by_observation(
+            origins,
+            egg_params.clone(),
+            config.clone(),
+            observation.clone(),
+        )?
+        .into_iter()
+        .map(|data| (data.source, Some(data.advance)))
+        .collect(),
+        MissObservation::Needle { pattern } => {
+            search_needle_pattern(origins, pattern.clone(), config)?
+                .into_iter()
+                .map(|result| (result.source, Some(result.advance)))
+                .collect()
+        }
+        MissObservation::Iv {
+            iv_filter,
+            mt_offset,
+            is_roamer,
+        } => origins
+            .into_iter()
+            .filter(|origin| {
+                let ivs = generate_rng_ivs_with_offset(origin.mt_seed(), *mt_offset, *is_roamer);
+                iv_filter.matches(&ivs)
+            })
+            .map(|origin| (origin, None))
+            .collect(),
+    };
+
+    Ok(matches)
+}
+
+/// 周辺の起動条件を列挙
+///
+/// `intended` の ± 探索幅の `Timer0` × `VCount` のうち、`ds` の既定範囲に含まれるものと
+/// `KeyMask` の各組み合わせを返す。
+/// 狙った条件自体は既定範囲外でも常に含める。
+fn neighbour_conditions(
+    params: &MissDiagnosisParams,
+    condition: StartupCondition,
+) -> Vec<StartupCondition> {
+    let mut key_masks: Vec<KeyMask> = params
+        .key_spec
+        .as_ref()
+        .map_or_else(Vec::new, KeySpec::combinations);
+    if !key_masks.contains(&condition.key_mask) {
+        key_masks.push(condition.key_mask);
+    }
+
+    let timer0_min = condition.timer0.saturating_sub(params.timer0_radius);
+    let timer0_max = condition.timer0.saturating_add(params.timer0_radius);
+    let vcount_min = condition.vcount.saturating_sub(params.vcount_radius);
+    let vcount_max = condition.vcount.saturating_add(params.vcount_radius);
+    let window = Timer0VCountRange {
+        timer0_min,
+        timer0_max,
+        vcount_min,
+        vcount_max,
+    };
+
+    // 既定範囲との共通部分。未収集の場合は探索幅をそのまま使用する
+    let ranges: Vec<Timer0VCountRange> = default_ranges(&params.ds).map_or_else(
+        || vec![window],
+        |defaults| {
+            defaults
+                .iter()
+                .filter_map(|range| {
+                    let clamped = Timer0VCountRange {
+                        timer0_min: timer0_min.max(range.timer0_min),
+                        timer0_max: timer0_max.min(range.timer0_max),
+                        vcount_min: vcount_min.max(range.vcount_min),
+                        vcount_max: vcount_max.min(range.vcount_max),
+                    };
+                    (clamped.timer0_min <= clamped.timer0_max
+                        && clamped.vcount_min <= clamped.vcount_max)
+                        .then_some(clamped)
+                })
+                .collect()
+        },
+    );
+
+    let mut pairs: Vec<(u16, u8)> = vec![(condition.timer0, condition.vcount)];
+    for range in &ranges {
+        for timer0 in range.timer0_min..=range.timer0_max {
+            for vcount in range.vcount_min..=range.vcount_max {
+                if !pairs.contains(&(timer0, vcount)) {
+                    pairs.push((timer0, vcount));
+                }
+            }
+        }
+    }
+
+    pairs
+        .into_iter()
+        .flat_map(|(timer0, vcount)| {
+            key_masks
+                .iter()
+                .map(move |&key_mask| StartupCondition::new(timer0, vcount, key_mask))
+        })
+        .collect()
+}
+
+/// `datetime` ± `radius` 秒の検索範囲を作成
+///
+/// DS の日付範囲 (2000/01/01 - 2099/12/31) を超える部分は切り詰める。
+#[allow(clippy::cast_possible_truncation)]
+fn neighbour_search_range(datetime: Datetime, radius: u32) -> SearchRangeParams {
+    let center = datetime_seconds(datetime);
+    let last = datetime_to_seconds(2099, 12, 31, 23, 59, 59);
+
+    let start = center.saturating_sub(u64::from(radius));
+    let end = (center + u64::from(radius)).min(last);
+    let (year, month, day, hour, minute, second) = seconds_to_datetime(start);
+
+    SearchRangeParams {
+        start_year: year,
+        start_month: month,
+        start_day: day,
+        start_second_offset: u32::from(hour) * 3600 + u32::from(minute) * 60 + u32::from(second),
+        // 探索幅は u32 の秒数で指定されるため truncation は発生しない
+        range_seconds: (end - start + 1) as u32,
+    }
+}
+
+/// 狙った条件との差分から診断結果を作成
+#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+fn diagnose_result(
+    source: SeedOrigin,
+    advance: Option<u32>,
+    intended_datetime: Datetime,
+    intended_condition: StartupCondition,
+) -> MissDiagnosisResult {
+    let (second_offset, timer0_offset, vcount_offset, key_mismatch) = match &source {
+        SeedOrigin::Startup {
+            datetime,
+            condition,
+            ..
+        } => (
+            (datetime_seconds(*datetime) as i64 - datetime_seconds(intended_datetime) as i64)
+                as i32,
+            i32::from(condition.timer0) - i32::from(intended_condition.timer0),
+            i32::from(condition.vcount) - i32::from(intended_condition.vcount),
+            condition.key_mask != intended_condition.key_mask,
+        ),
+        SeedOrigin::Seed { .. } => (0, 0, 0, false),
+    };
+
+    let distance = second_offset.unsigned_abs()
+        + timer0_offset.unsigned_abs()
+        + vcount_offset.unsigned_abs()
+        + u32::from(key_mismatch);
+
+    MissDiagnosisResult {
+        source,
+        advance,
+        second_offset,
+        timer0_offset,
+        vcount_offset,
+        key_mismatch,
+        distance,
+    }
+}
+
+/// `Datetime` を 2000/01/01 00:00:00 からの経過秒数に変換
+fn datetime_seconds(datetime: Datetime) -> u64 {
+    datetime_to_seconds(
+        datetime.year,
+        datetime.month,
+        datetime.day,
+        datetime.hour,
+        datetime.minute,
+        datetime.second,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::algorithm::calculate_game_offset;
+    use crate::misc::get_needle_pattern_at;
+    use crate::types::{
+        DsButton, DsConfig, GameStartConfig, GenerationConfig, Hardware, IvFilter, LcgSeed,
+        MemoryLinkState, NeedlePattern, RomRegion, RomVersion, SavePresence, ShinyCharmState,
+        StartMode,
+    };
+
+    fn make_ds() -> DsConfig {
+        DsConfig {
+            mac: [0x8C, 0x56, 0xC5, 0x86, 0x15, 0x28],
+            hardware: Hardware::DsLite,
+            version: RomVersion::Black,
+            region: RomRegion::Jpn,
+        }
+    }
+
+    fn make_config() -> GenerationConfig {
+        GenerationConfig {
+            version: RomVersion::Black,
+            game_start: GameStartConfig {
+                start_mode: StartMode::Continue,
+                save: SavePresence::WithSave,
+                memory_link: MemoryLinkState::Disabled,
+                shiny_charm: ShinyCharmState::NotObtained,
+            },
+            user_offset: 0,
+            max_advance: 50,
+        }
+    }
+
+    /// 狙った起動条件: 既知の条件 (18:13:11) から 2 秒・`Timer0` 1 つずれた位置
+    fn make_intended() -> SeedOrigin {
+        SeedOrigin::startup(
+            LcgSeed::new(0),
+            Datetime::new(2010, 9, 18, 18, 13, 13),
+            StartupCondition::new(0x0C7A, 0x60, KeyMask::NONE),
+        )
+    }
+
+    fn make_params(observation: MissObservation) -> MissDiagnosisParams {
+        MissDiagnosisParams {
+            ds: make_ds(),
+            intended: make_intended(),
+            second_radius: 3,
+            timer0_radius: 1,
+            vcount_radius: 1,
+            key_spec: None,
+            config: make_config(),
+            observation,
+        }
+    }
+
+    #[test]
+    fn test_diagnose_requires_startup() {
+        let mut params = make_params(MissObservation::Needle {
+            pattern: NeedlePattern::from_values(&[0]),
+        });
+        params.intended = SeedOrigin::seed(LcgSeed::new(1));
+        assert!(diagnose_missed_hit(params).is_err());
+    }
+
+    #[test]
+    fn test_neighbour_conditions_clamped_to_default_range() {
+        // DsLite / Black / JPN の既定範囲: Timer0 0x0C79-0x0C7A, VCount 0x60
+        let params = make_params(MissObservation::Needle {
+            pattern: NeedlePattern::from_values(&[0]),
+        });
+        let conditions =
+            neighbour_conditions(&params, StartupCondition::new(0x0C7A, 0x60, KeyMask::NONE));
+        let pairs: Vec<(u16, u8)> = conditions.iter().map(|c| (c.timer0, c.vcount)).collect();
+        assert_eq!(pairs, vec![(0x0C7A, 0x60), (0x0C79, 0x60)]);
+
+        // 既定範囲が未収集の組み合わせは探索幅をそのまま使用
+        let mut params = params;
+        params.ds.hardware = Hardware::Dsi;
+        params.ds.region = RomRegion::Usa;
+        let conditions =
+            neighbour_conditions(&params, StartupCondition::new(0x0C7A, 0x60, KeyMask::NONE));
+        assert_eq!(conditions.len(), 9);
+    }
+
+    #[test]
+    fn test_diagnose_needle_finds_actual_boot() {
+        // 実際は既知の条件 (18:13:11, Timer0 0x0C79) で起動していた
+        let seed = LcgSeed::new(0x7683_6078_1D1C_E6DD);
+        let config = make_config();
+        let game_offset = calculate_game_offset(seed, config.version, config.game_start).unwrap();
+        let observed = get_needle_pattern_at(seed.value(), game_offset + 10, 10);
+
+        let results = diagnose_missed_hit(make_params(MissObservation::Needle {
+            pattern: NeedlePattern::from_values(&observed),
+        }))
+        .unwrap();
+
+        let found = results
+            .iter()
+            .find(|r| r.source.base_seed() == seed)
+            .expect("actual boot not found");
+        assert_eq!(found.second_offset, -2);
+        assert_eq!(found.timer0_offset, -1);
+        assert_eq!(found.vcount_offset, 0);
+        assert!(!found.key_mismatch);
+        assert_eq!(found.distance, 3);
+        assert_eq!(found.advance, Some(19));
+
+        // distance 昇順
+        assert!(results.windows(2).all(|w| w[0].distance <= w[1].distance));
+    }
+
+    #[test]
+    fn test_diagnose_iv_with_key_spec() {
+        let seed = LcgSeed::new(0x7683_6078_1D1C_E6DD);
+        let ivs = generate_rng_ivs_with_offset(seed.derive_mt_seed(), 7, false);
+        let iv_filter = IvFilter {
+            hp: (ivs.hp, ivs.hp),
+            atk: (ivs.atk, ivs.atk),
+            def: (ivs.def, ivs.def),
+            spa: (ivs.spa, ivs.spa),
+            spd: (ivs.spd, ivs.spd),
+            spe: (ivs.spe, ivs.spe),
+            ..IvFilter::any()
+        };
+
+        let mut params = make_params(MissObservation::Iv {
+            iv_filter,
+            mt_offset: 7,
+            is_roamer: false,
+        });
+        params.key_spec = Some(KeySpec::from_buttons(vec![DsButton::A]));
+
+        let results = diagnose_missed_hit(params).unwrap();
+        let found = results
+            .iter()
+            .find(|r| r.source.base_seed() == seed)
+            .expect("actual boot not found");
+        assert_eq!(found.advance, None);
+        assert_eq!(found.distance, 3);
+    }
+}
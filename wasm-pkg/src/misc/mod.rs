@@ -1,10 +1,20 @@
 //! 雑多なユーティリティ
 //!
-//! レポート針パターン検索と MT Seed 全探索機能を提供する。
+//! レポート針パターン検索・追跡、捕獲個体の逆引き、外れ診断と MT Seed 全探索 (通常・孵化) 機能を提供する。
 
+pub mod egg_mtseed_search;
+pub mod miss_diagnosis;
 pub mod mtseed_search;
 pub mod needle_search;
+pub mod needle_tracker;
+pub mod reverse_lookup;
 
 // Searcher と関数を re-export (型は types モジュールから)
+pub use egg_mtseed_search::{EggMtseedSearcher, generate_egg_mtseed_iv_search_tasks};
+pub use miss_diagnosis::diagnose_missed_hit;
 pub use mtseed_search::{MtseedSearcher, generate_mtseed_iv_search_tasks};
-pub use needle_search::{get_needle_pattern_at, search_needle_pattern};
+pub use needle_search::{
+    get_needle_pattern_at, search_needle_pattern, search_needle_pattern_fuzzy,
+};
+pub use needle_tracker::NeedleTracker;
+pub use reverse_lookup::{find_egg_by_observation, find_pokemon_by_observation};
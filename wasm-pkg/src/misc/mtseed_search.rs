@@ -117,16 +117,30 @@ impl MtseedSearcher {
 /// 分割されたタスクのリスト（各タスクは閉区間 `[start_seed, end_seed]`）
 #[wasm_bindgen]
 #[allow(clippy::needless_pass_by_value)]
-#[allow(clippy::cast_possible_truncation)]
 pub fn generate_mtseed_iv_search_tasks(
     context: MtseedSearchContext,
     worker_count: u32,
 ) -> Vec<MtseedSearchParams> {
+    split_seed_space(worker_count)
+        .into_iter()
+        .map(|(start_seed, end_seed)| MtseedSearchParams {
+            iv_filter: context.iv_filter.clone(),
+            mt_offset: context.mt_offset,
+            is_roamer: context.is_roamer,
+            start_seed,
+            end_seed,
+        })
+        .collect()
+}
+
+/// 全 Seed 空間 (0〜2^32-1) を `worker_count` 個の閉区間に均等分割
+#[allow(clippy::cast_possible_truncation)]
+pub(crate) fn split_seed_space(worker_count: u32) -> Vec<(u32, u32)> {
     let total: u64 = 0x1_0000_0000; // 2^32
     let chunk_size = total / u64::from(worker_count);
     let remainder = total % u64::from(worker_count);
 
-    let mut tasks = Vec::with_capacity(worker_count as usize);
+    let mut ranges = Vec::with_capacity(worker_count as usize);
     let mut current: u64 = 0;
 
     for i in 0..worker_count {
@@ -138,19 +152,11 @@ pub fn generate_mtseed_iv_search_tasks(
         }
 
         let task_end = current + size - 1; // 閉区間の終端
-
-        tasks.push(MtseedSearchParams {
-            iv_filter: context.iv_filter.clone(),
-            mt_offset: context.mt_offset,
-            is_roamer: context.is_roamer,
-            start_seed: current as u32,
-            end_seed: task_end as u32,
-        });
-
+        ranges.push((current as u32, task_end as u32));
         current = task_end + 1;
     }
 
-    tasks
+    ranges
 }
 
 #[cfg(test)]
@@ -9,7 +9,9 @@ use crate::core::lcg::Lcg64;
 use crate::core::needle::calc_report_needle_direction;
 use crate::generation::algorithm::calculate_game_offset;
 use crate::types::{
-    GenerationConfig, NeedleDirection, NeedlePattern, NeedleSearchResult, SeedOrigin,
+    FuzzyNeedlePattern, GenerationConfig, NeedleDirection, NeedleEntryMatch,
+    NeedleFuzzySearchResult, NeedleMatchMode, NeedlePattern, NeedlePatternEntry,
+    NeedleSearchResult, SeedOrigin,
 };
 
 /// レポート針パターン検索 (公開 API)
@@ -43,43 +45,60 @@ pub fn search_needle_pattern(
     let mut results = Vec::new();
 
     for origin in origins {
-        let seed = origin.base_seed();
+        search_needle_pattern_in_origin(&origin, pattern_dirs, config, &mut results)?;
+    }
 
-        // game_offset 計算
-        let game_offset = calculate_game_offset(seed, config.version, config.game_start)?;
+    Ok(results)
+}
 
-        // 検索範囲: user_offset ～ max_advance
-        let start = config.user_offset;
-        let end = config.max_advance;
+/// 単一の `SeedOrigin` についてパターン出現位置を検索し、結果に追加
+///
+/// 起動時刻検索 (`NeedleDatetimeSearcher`) からも使用する。
+///
+/// # Errors
+/// - 起動設定が無効な場合
+pub(crate) fn search_needle_pattern_in_origin(
+    origin: &SeedOrigin,
+    pattern_dirs: &[NeedleDirection],
+    config: &GenerationConfig,
+    results: &mut Vec<NeedleSearchResult>,
+) -> Result<(), String> {
+    let seed = origin.base_seed();
 
-        // パターン長を考慮した終了位置の調整
-        // パターン末尾が end を超えないようにする
-        #[allow(clippy::cast_possible_truncation)]
-        let pattern_len = pattern_dirs.len() as u32;
-        if pattern_len == 0 || start + pattern_len - 1 > end {
-            continue;
-        }
-        let search_end = end - pattern_len + 1;
+    // game_offset 計算
+    let game_offset = calculate_game_offset(seed, config.version, config.game_start)?;
 
-        // LCG を初期化してジャンプ
-        let mut lcg = Lcg64::new(seed);
-        lcg.jump(u64::from(game_offset + start));
+    // 検索範囲: user_offset ～ max_advance
+    let start = config.user_offset;
+    let end = config.max_advance;
 
-        // パターン検索
-        for advance in start..=search_end {
-            if matches_pattern(&lcg, pattern_dirs) {
-                // パターン末尾位置は、複数回の針観測後に確定した現在位置。
-                let end_advance = advance + pattern_len - 1;
-                results.push(NeedleSearchResult {
-                    advance: end_advance,
-                    source: origin.clone(),
-                });
-            }
-            lcg.next();
+    // パターン長を考慮した終了位置の調整
+    // パターン末尾が end を超えないようにする
+    #[allow(clippy::cast_possible_truncation)]
+    let pattern_len = pattern_dirs.len() as u32;
+    if pattern_len == 0 || start + pattern_len - 1 > end {
+        return Ok(());
+    }
+    let search_end = end - pattern_len + 1;
+
+    // LCG を初期化してジャンプ
+    let mut lcg = Lcg64::new(seed);
+    lcg.jump(u64::from(game_offset + start));
+
+    // パターン検索
+    for advance in start..=search_end {
+        if matches_pattern(&lcg, pattern_dirs) {
+            // パターン末尾位置は、複数回の針観測後に確定した現在位置。
+            let end_advance = advance + pattern_len - 1;
+            results.push(NeedleSearchResult {
+                advance: end_advance,
+                source: origin.clone(),
+            });
         }
+        lcg.next();
     }
 
-    Ok(results)
+    Ok(())
 }
 
 /// 現在の LCG 位置からパターンが一致するか確認
@@ -98,6 +117,137 @@ fn matches_pattern(lcg: &Lcg64, pattern: &[NeedleDirection]) -> bool {
     true
 }
 
+/// あいまい針パターン検索 (公開 API)
+///
+/// ワイルドカード・方向集合を含むパターンで検索する。
+/// `NeedleMatchMode::Fuzzy` では隣接方向の許容と不一致数の上限を指定できる。
+/// 結果は `score` の降順 (同点は `advance` の昇順) で返却する。
+///
+/// # Arguments
+/// * `origins` - 既に解決された Seed リスト
+/// * `pattern` - 検索するあいまい針パターン
+/// * `mode` - 照合モード
+/// * `config` - 生成設定 (`version`, `game_start`, `user_offset`, `max_advance`)
+///
+/// # Errors
+/// - パターンが空の場合
+/// - 方向が空の `AnyOf` 要素を含む場合
+/// - 起動設定が無効な場合
+#[wasm_bindgen]
+#[allow(clippy::needless_pass_by_value)]
+pub fn search_needle_pattern_fuzzy(
+    origins: Vec<SeedOrigin>,
+    pattern: FuzzyNeedlePattern,
+    mode: NeedleMatchMode,
+    config: &GenerationConfig,
+) -> Result<Vec<NeedleFuzzySearchResult>, String> {
+    let entries = pattern.entries();
+    if entries.is_empty() {
+        return Err("Pattern is empty".into());
+    }
+    if entries
+        .iter()
+        .any(|e| matches!(e, NeedlePatternEntry::AnyOf { directions } if directions.is_empty()))
+    {
+        return Err("AnyOf entry has no directions".into());
+    }
+
+    let (adjacent_tolerance, max_mismatches) = match mode {
+        NeedleMatchMode::Strict => (false, 0),
+        NeedleMatchMode::Fuzzy {
+            adjacent_tolerance,
+            max_mismatches,
+        } => (adjacent_tolerance, max_mismatches),
+    };
+
+    #[allow(clippy::cast_possible_truncation)]
+    let pattern_len = entries.len() as u32;
+    let start = config.user_offset;
+    let end = config.max_advance;
+
+    let mut results = Vec::new();
+
+    for origin in origins {
+        let seed = origin.base_seed();
+        let game_offset = calculate_game_offset(seed, config.version, config.game_start)?;
+
+        // パターン末尾が end を超えないようにする
+        if start + pattern_len - 1 > end {
+            continue;
+        }
+
+        // 検索範囲の針方向を事前計算
+        let mut lcg = Lcg64::new(seed);
+        lcg.jump(u64::from(game_offset + start));
+        let directions: Vec<NeedleDirection> = (start..=end)
+            .map(|_| {
+                let direction = calc_report_needle_direction(lcg.current_seed());
+                lcg.next();
+                direction
+            })
+            .collect();
+
+        for (offset, window) in (0u32..).zip(directions.windows(entries.len())) {
+            let Some(score) = score_window(window, entries, adjacent_tolerance, max_mismatches)
+            else {
+                continue;
+            };
+            results.push(NeedleFuzzySearchResult {
+                advance: start + offset + pattern_len - 1,
+                source: origin.clone(),
+                score: score.score,
+                adjacent_count: score.adjacent_count,
+                mismatch_count: score.mismatch_count,
+            });
+        }
+    }
+
+    results.sort_by(|a, b| b.score.cmp(&a.score).then(a.advance.cmp(&b.advance)));
+    Ok(results)
+}
+
+/// 窓単位の照合スコア
+struct WindowScore {
+    score: u32,
+    adjacent_count: u32,
+    mismatch_count: u32,
+}
+
+/// 針方向の窓とパターンを照合
+///
+/// 不一致数が `max_mismatches` を超えた時点で `None` を返す。
+fn score_window(
+    window: &[NeedleDirection],
+    entries: &[NeedlePatternEntry],
+    adjacent_tolerance: bool,
+    max_mismatches: u32,
+) -> Option<WindowScore> {
+    let mut result = WindowScore {
+        score: 0,
+        adjacent_count: 0,
+        mismatch_count: 0,
+    };
+
+    for (entry, &actual) in entries.iter().zip(window) {
+        match entry.evaluate(actual, adjacent_tolerance) {
+            NeedleEntryMatch::Hit => result.score += 2,
+            NeedleEntryMatch::Adjacent => {
+                result.score += 1;
+                result.adjacent_count += 1;
+            }
+            NeedleEntryMatch::Wildcard => {}
+            NeedleEntryMatch::Miss => {
+                result.mismatch_count += 1;
+                if result.mismatch_count > max_mismatches {
+                    return None;
+                }
+            }
+        }
+    }
+
+    Some(result)
+}
+
 /// 針パターンを取得 (ユーティリティ関数)
 ///
 /// 指定した Seed と advance から始まる針パターンを取得。
@@ -195,4 +345,142 @@ mod tests {
             assert!(*direction < 8);
         }
     }
+
+    fn make_origin(seed: LcgSeed) -> SeedOrigin {
+        SeedOrigin::seed(seed)
+    }
+
+    #[test]
+    fn test_fuzzy_strict_matches_strict_search() {
+        let seed = LcgSeed::new(0x1234_5678_9ABC_DEF0);
+        let config = make_config();
+        let game_offset = calculate_game_offset(seed, config.version, config.game_start).unwrap();
+
+        let values = get_needle_pattern_at(seed.value(), game_offset + 10, 4);
+        let pattern = NeedlePattern::from_values(&values);
+
+        let strict =
+            search_needle_pattern(vec![make_origin(seed)], pattern.clone(), &config).unwrap();
+        let fuzzy = search_needle_pattern_fuzzy(
+            vec![make_origin(seed)],
+            FuzzyNeedlePattern::from(&pattern),
+            NeedleMatchMode::Strict,
+            &config,
+        )
+        .unwrap();
+
+        let mut strict_advances: Vec<u32> = strict.iter().map(|r| r.advance).collect();
+        strict_advances.sort_unstable();
+        let mut fuzzy_advances: Vec<u32> = fuzzy.iter().map(|r| r.advance).collect();
+        fuzzy_advances.sort_unstable();
+        assert_eq!(strict_advances, fuzzy_advances);
+        assert!(fuzzy.iter().all(|r| r.score == 8 && r.mismatch_count == 0));
+    }
+
+    #[test]
+    fn test_fuzzy_wildcard_and_mismatch_budget() {
+        let seed = LcgSeed::new(0x1234_5678_9ABC_DEF0);
+        let config = make_config();
+        let game_offset = calculate_game_offset(seed, config.version, config.game_start).unwrap();
+
+        let values = get_needle_pattern_at(seed.value(), game_offset + 30, 6);
+        let mut entries: Vec<NeedlePatternEntry> = values
+            .iter()
+            .map(|&v| NeedlePatternEntry::Exact {
+                direction: NeedleDirection::from_value(v),
+            })
+            .collect();
+        // 2 番目をワイルドカード、4 番目を誤読 (反対方向) にする
+        entries[1] = NeedlePatternEntry::Wildcard;
+        entries[3] = NeedlePatternEntry::Exact {
+            direction: NeedleDirection::from_value(values[3] + 4),
+        };
+        let pattern = FuzzyNeedlePattern::new(entries);
+
+        // 完全一致では誤読を含むため見つからない
+        let strict = search_needle_pattern_fuzzy(
+            vec![make_origin(seed)],
+            pattern.clone(),
+            NeedleMatchMode::Strict,
+            &config,
+        )
+        .unwrap();
+        assert!(strict.iter().all(|r| r.advance != 35));
+
+        // 不一致 1 件まで許容すると見つかる
+        let fuzzy = search_needle_pattern_fuzzy(
+            vec![make_origin(seed)],
+            pattern,
+            NeedleMatchMode::Fuzzy {
+                adjacent_tolerance: false,
+                max_mismatches: 1,
+            },
+            &config,
+        )
+        .unwrap();
+        let found = fuzzy.iter().find(|r| r.advance == 35).expect("not found");
+        assert_eq!(found.mismatch_count, 1);
+        // 一致 4 件 × 2 点 (ワイルドカード・不一致は 0 点)
+        assert_eq!(found.score, 8);
+
+        // スコア降順で並んでいること
+        assert!(fuzzy.windows(2).all(|w| w[0].score >= w[1].score));
+    }
+
+    #[test]
+    fn test_fuzzy_adjacent_tolerance() {
+        let seed = LcgSeed::new(0x1234_5678_9ABC_DEF0);
+        let config = make_config();
+        let game_offset = calculate_game_offset(seed, config.version, config.game_start).unwrap();
+
+        let values = get_needle_pattern_at(seed.value(), game_offset + 20, 5);
+        // 先頭を隣接方向に誤読
+        let entries: Vec<NeedlePatternEntry> = values
+            .iter()
+            .enumerate()
+            .map(|(i, &v)| NeedlePatternEntry::Exact {
+                direction: NeedleDirection::from_value(if i == 0 { v + 1 } else { v }),
+            })
+            .collect();
+
+        let results = search_needle_pattern_fuzzy(
+            vec![make_origin(seed)],
+            FuzzyNeedlePattern::new(entries),
+            NeedleMatchMode::Fuzzy {
+                adjacent_tolerance: true,
+                max_mismatches: 0,
+            },
+            &config,
+        )
+        .unwrap();
+
+        let found = results.iter().find(|r| r.advance == 24).expect("not found");
+        assert_eq!(found.adjacent_count, 1);
+        assert_eq!(found.score, 9);
+    }
+
+    #[test]
+    fn test_fuzzy_empty_pattern() {
+        let result = search_needle_pattern_fuzzy(
+            vec![make_origin(LcgSeed::new(1))],
+            FuzzyNeedlePattern::default(),
+            NeedleMatchMode::Strict,
+            &make_config(),
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_fuzzy_empty_any_of() {
+        let result = search_needle_pattern_fuzzy(
+            vec![make_origin(LcgSeed::new(1))],
+            FuzzyNeedlePattern::new(vec![
+                NeedlePatternEntry::Wildcard,
+                NeedlePatternEntry::AnyOf { directions: vec![] },
+            ]),
+            NeedleMatchMode::Strict,
+            &make_config(),
+        );
+        assert!(result.is_err());
+    }
 }
@@ -0,0 +1,309 @@
+//! レポート針トラッキング
+//!
+//! レポートを書くたびに観測した針を 1 件ずつ受け取り、候補の消費位置を絞り込む。
+//! レポート 1 回につき 1 消費されることを前提に、各候補の位置を進めていく。
+
+use wasm_bindgen::prelude::*;
+
+use crate::core::lcg::Lcg64;
+use crate::core::needle::calc_report_needle_direction;
+use crate::generation::algorithm::calculate_game_offset;
+use crate::types::{
+    GenerationConfig, LcgSeed, NeedleDirection, NeedleTrackerCandidate, NeedleTrackerStatus,
+    SeedOrigin,
+};
+
+/// 追跡中の候補
+#[derive(Clone, Copy)]
+struct TrackedCandidate {
+    /// `origins` のインデックス
+    origin_index: u32,
+    /// 現在位置 (次の針が表示される位置)
+    advance: u32,
+    /// 現在位置の LCG Seed
+    seed: LcgSeed,
+}
+
+/// レポート針トラッカー
+///
+/// 1 回目の観測で `user_offset..=max_advance` を走査して候補を作成し、
+/// 2 回目以降は残った候補のみを 1 消費ずつ進めて照合する。
+#[wasm_bindgen]
+pub struct NeedleTracker {
+    /// 解決済み Seed と `game_offset`
+    origins: Vec<(SeedOrigin, u32)>,
+    /// 初回観測の開始位置
+    user_offset: u32,
+    /// 初回観測の終了位置
+    max_advance: u32,
+    /// 候補 (初回観測前は空)
+    candidates: Vec<TrackedCandidate>,
+    /// 観測済みの針数
+    observation_count: u32,
+}
+
+#[wasm_bindgen]
+impl NeedleTracker {
+    /// 新しい `NeedleTracker` を作成
+    ///
+    /// # Arguments
+    /// * `origins` - 既に解決された Seed リスト
+    /// * `config` - 生成設定 (`version`, `game_start`, `user_offset`, `max_advance`)
+    ///
+    /// # Errors
+    /// - `origins` が空の場合
+    /// - `user_offset > max_advance` の場合
+    /// - 起動設定が無効な場合
+    #[wasm_bindgen(constructor)]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn new(
+        origins: Vec<SeedOrigin>,
+        config: GenerationConfig,
+    ) -> Result<NeedleTracker, String> {
+        if origins.is_empty() {
+            return Err("origins is empty".into());
+        }
+        if config.user_offset > config.max_advance {
+            return Err("user_offset must be less than or equal to max_advance".into());
+        }
+
+        let origins = origins
+            .into_iter()
+            .map(|origin| {
+                let game_offset =
+                    calculate_game_offset(origin.base_seed(), config.version, config.game_start)?;
+                Ok((origin, game_offset))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        Ok(Self {
+            origins,
+            user_offset: config.user_offset,
+            max_advance: config.max_advance,
+            candidates: Vec::new(),
+            observation_count: 0,
+        })
+    }
+
+    /// 観測した針を追加し、候補を絞り込む
+    ///
+    /// 観測した針の位置で一致した候補のみを残し、レポートによる 1 消費分だけ位置を進める。
+    pub fn observe(&mut self, direction: NeedleDirection) -> NeedleTrackerStatus {
+        if self.observation_count == 0 {
+            self.scan_initial(direction);
+        } else {
+            self.candidates.retain_mut(|candidate| {
+                if calc_report_needle_direction(candidate.seed) != direction {
+                    return false;
+                }
+                candidate.seed = Lcg64::compute_next(candidate.seed);
+                candidate.advance += 1;
+                true
+            });
+        }
+
+        self.observation_count += 1;
+        self.status()
+    }
+
+    /// 現在の状態を取得
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn status(&self) -> NeedleTrackerStatus {
+        // 初回観測前は全位置が候補
+        if self.observation_count == 0 {
+            let window = u64::from(self.max_advance - self.user_offset) + 1;
+            let total = window * self.origins.len() as u64;
+            return NeedleTrackerStatus {
+                observation_count: 0,
+                candidate_count: u32::try_from(total).unwrap_or(u32::MAX),
+                origin_count: self.origins.len() as u32,
+                current_advance: (window == 1).then_some(self.user_offset),
+                min_advance: Some(self.user_offset),
+                max_advance: Some(self.max_advance),
+            };
+        }
+
+        let min_advance = self.candidates.iter().map(|c| c.advance).min();
+        let max_advance = self.candidates.iter().map(|c| c.advance).max();
+
+        // 候補は origin 順に並んでいるため、隣接要素の比較で Seed 数を数えられる
+        let origin_count = self
+            .candidates
+            .iter()
+            .enumerate()
+            .filter(|(i, c)| *i == 0 || self.candidates[i - 1].origin_index != c.origin_index)
+            .count();
+
+        NeedleTrackerStatus {
+            observation_count: self.observation_count,
+            candidate_count: self.candidates.len() as u32,
+            origin_count: origin_count as u32,
+            current_advance: min_advance.filter(|&min| Some(min) == max_advance),
+            min_advance,
+            max_advance,
+        }
+    }
+
+    /// 残り候補を取得
+    ///
+    /// 初回観測前は空を返す。
+    ///
+    /// # Arguments
+    /// * `limit` - 最大取得件数
+    pub fn candidates(&self, limit: u32) -> Vec<NeedleTrackerCandidate> {
+        self.candidates
+            .iter()
+            .take(limit as usize)
+            .map(|c| NeedleTrackerCandidate {
+                advance: c.advance,
+                source: self.origins[c.origin_index as usize].0.clone(),
+            })
+            .collect()
+    }
+
+    /// 観測をリセット
+    pub fn reset(&mut self) {
+        self.candidates.clear();
+        self.observation_count = 0;
+    }
+}
+
+impl NeedleTracker {
+    /// 初回観測: 検索範囲全体を走査して候補を作成
+    #[allow(clippy::cast_possible_truncation)]
+    fn scan_initial(&mut self, direction: NeedleDirection) {
+        self.candidates.clear();
+
+        for (index, (origin, game_offset)) in self.origins.iter().enumerate() {
+            let mut lcg = Lcg64::new(origin.base_seed());
+            lcg.jump(u64::from(*game_offset + self.user_offset));
+
+            for advance in self.user_offset..=self.max_advance {
+                let seed = lcg.current_seed();
+                let next_seed = lcg.next_seed();
+                if calc_report_needle_direction(seed) == direction {
+                    self.candidates.push(TrackedCandidate {
+                        origin_index: index as u32,
+                        advance: advance + 1,
+                        seed: next_seed,
+                    });
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::misc::get_needle_pattern_at;
+    use crate::misc::search_needle_pattern;
+    use crate::types::{
+        GameStartConfig, MemoryLinkState, NeedlePattern, RomVersion, SavePresence, ShinyCharmState,
+        StartMode,
+    };
+
+    fn make_config() -> GenerationConfig {
+        GenerationConfig {
+            version: RomVersion::Black,
+            game_start: GameStartConfig {
+                start_mode: StartMode::Continue,
+                save: SavePresence::WithSave,
+                memory_link: MemoryLinkState::Disabled,
+                shiny_charm: ShinyCharmState::NotObtained,
+            },
+            user_offset: 0,
+            max_advance: 200,
+        }
+    }
+
+    #[test]
+    fn test_tracker_empty_origins() {
+        assert!(NeedleTracker::new(vec![], make_config()).is_err());
+    }
+
+    #[test]
+    fn test_tracker_initial_status() {
+        let origins = vec![
+            SeedOrigin::seed(LcgSeed::new(1)),
+            SeedOrigin::seed(LcgSeed::new(2)),
+        ];
+        let tracker = NeedleTracker::new(origins, make_config()).unwrap();
+        let status = tracker.status();
+        assert_eq!(status.observation_count, 0);
+        assert_eq!(status.candidate_count, 2 * 201);
+        assert_eq!(status.origin_count, 2);
+        assert_eq!(status.current_advance, None);
+    }
+
+    #[test]
+    fn test_tracker_converges_to_true_advance() {
+        let seed = LcgSeed::new(0x1234_5678_9ABC_DEF0);
+        let config = make_config();
+        let game_offset = calculate_game_offset(seed, config.version, config.game_start).unwrap();
+
+        // advance 50 から 12 回レポートを書いたとする
+        let observed = get_needle_pattern_at(seed.value(), game_offset + 50, 12);
+        let mut tracker = NeedleTracker::new(vec![SeedOrigin::seed(seed)], config).unwrap();
+
+        let mut previous = u32::MAX;
+        let mut status = tracker.status();
+        for &value in &observed {
+            status = tracker.observe(NeedleDirection::from_value(value));
+            // 候補数は単調減少
+            assert!(status.candidate_count <= previous);
+            previous = status.candidate_count;
+        }
+
+        // 真の位置は候補に残る (12 回観測後の現在位置は 62)
+        assert!(tracker.candidates(u32::MAX).iter().any(|c| c.advance == 62));
+        assert_eq!(status.observation_count, 12);
+        assert_eq!(status.origin_count, 1);
+    }
+
+    #[test]
+    fn test_tracker_consistent_with_pattern_search() {
+        let seed = LcgSeed::new(0x0FED_CBA9_8765_4321);
+        let config = make_config();
+        let game_offset = calculate_game_offset(seed, config.version, config.game_start).unwrap();
+        let observed = get_needle_pattern_at(seed.value(), game_offset + 30, 4);
+
+        let mut tracker = NeedleTracker::new(vec![SeedOrigin::seed(seed)], config.clone()).unwrap();
+        for &value in &observed {
+            tracker.observe(NeedleDirection::from_value(value));
+        }
+
+        // パターン検索の末尾位置 + 1 がトラッカーの現在位置
+        let mut expected: Vec<u32> = search_needle_pattern(
+            vec![SeedOrigin::seed(seed)],
+            NeedlePattern::from_values(&observed),
+            &config,
+        )
+        .unwrap()
+        .iter()
+        .map(|r| r.advance + 1)
+        .collect();
+        expected.sort_unstable();
+
+        // トラッカーは初回位置を max_advance まで走査するため、末尾が範囲外の候補も含む
+        let mut actual: Vec<u32> = tracker
+            .candidates(u32::MAX)
+            .iter()
+            .map(|c| c.advance)
+            .filter(|&a| a <= config.max_advance + 1)
+            .collect();
+        actual.sort_unstable();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_tracker_reset() {
+        let mut tracker =
+            NeedleTracker::new(vec![SeedOrigin::seed(LcgSeed::new(1))], make_config()).unwrap();
+        tracker.observe(NeedleDirection::N);
+        tracker.reset();
+        assert_eq!(tracker.status().observation_count, 0);
+        assert!(tracker.candidates(10).is_empty());
+    }
+}
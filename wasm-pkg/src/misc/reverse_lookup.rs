@@ -0,0 +1,330 @@
+//! 捕獲個体の逆引き
+//!
+//! 捕獲・孵化した個体の観測値 (種族・レベル・性格・性別・特性・実ステータス) から、
+//! 実際に生成された消費位置を特定する。
+//! 実ステータスは `data::derive_iv_ranges` で個体値範囲に変換して照合する。
+
+use wasm_bindgen::prelude::*;
+
+use crate::data::{derive_iv_ranges, get_species_entry};
+use crate::generation::flows::generator::{
+    EggGenerator, PokemonGenerator, validate_encounter_slots, validate_single_encounter_type,
+};
+use crate::types::{
+    CorePokemonData, EggGenerationParams, EncounterResult, GeneratedEggData, GeneratedPokemonData,
+    GenerationConfig, IV_VALUE_UNKNOWN, PokemonGenerationParams, PokemonObservation, SeedOrigin,
+};
+
+/// 観測値と一致するポケモンを逆引き
+///
+/// 各 Seed について `user_offset..max_advance` の個体を生成し、
+/// 観測値と矛盾しない個体を全て返す。
+///
+/// # Arguments
+/// * `origins` - 既に解決された Seed リスト
+/// * `params` - 生成パラメータ (Wild / Static 統合)
+/// * `config` - 共通設定 (バージョン、オフセット、検索範囲)
+/// * `observation` - 捕獲個体の観測値
+///
+/// # Errors
+/// - 種族 ID が範囲外 (1-649) の場合
+/// - 観測した実ステータスに一致する個体値が存在しない場合
+/// - エンカウントスロットが空の場合
+/// - `encounter_type` が `DarkGrass` の場合
+/// - 起動設定が無効な場合
+#[wasm_bindgen]
+#[allow(clippy::needless_pass_by_value)]
+pub fn find_pokemon_by_observation(
+    origins: Vec<SeedOrigin>,
+    params: PokemonGenerationParams,
+    config: GenerationConfig,
+    observation: PokemonObservation,
+) -> Result<Vec<GeneratedPokemonData>, String> {
+    validate_single_encounter_type(&params)?;
+    validate_encounter_slots(&params)?;
+    let iv_ranges = observation_iv_ranges(&observation)?;
+
+    let mut results = Vec::new();
+    for origin in origins {
+        if params.slots_for(&origin).is_empty() {
+            continue;
+        }
+        let mut generator = PokemonGenerator::new(origin.base_seed(), origin, &params, &config)?;
+        let count = config.max_advance - config.user_offset;
+        results.extend(generator.take(count).into_iter().filter(|data| {
+            matches!(data.encounter_result, EncounterResult::Pokemon)
+                && data.core.species_id == observation.species_id
+                && data.core.level == observation.level
+                && matches_observation(&data.core, &observation, &iv_ranges)
+        }));
+    }
+
+    Ok(results)
+}
+
+/// 観測値と一致するタマゴを逆引き
+///
+/// 孵化した個体の観測値から、タマゴを受け取った消費位置を特定する。
+/// 実ステータスは孵化後に観測したレベルで評価するため、レベルの一致判定は行わない。
+/// `params.species_id` が未指定の場合は観測値の種族を使用する。
+///
+/// # Arguments
+/// * `origins` - 既に解決された Seed リスト
+/// * `params` - 生成パラメータ
+/// * `config` - 共通設定 (バージョン、オフセット、検索範囲)
+/// * `observation` - 孵化個体の観測値
+///
+/// # Errors
+/// - 種族 ID が範囲外 (1-649) の場合
+/// - 観測した実ステータスに一致する個体値が存在しない場合
+/// - 起動設定が無効な場合
+#[wasm_bindgen]
+#[allow(clippy::needless_pass_by_value)]
+pub fn find_egg_by_observation(
+    origins: Vec<SeedOrigin>,
+    params: EggGenerationParams,
+    config: GenerationConfig,
+    observation: PokemonObservation,
+) -> Result<Vec<GeneratedEggData>, String> {
+    let iv_ranges = observation_iv_ranges(&observation)?;
+
+    let mut params = params;
+    params.species_id = params.species_id.or(Some(observation.species_id));
+
+    let mut results = Vec::new();
+    for origin in origins {
+        let mut generator = EggGenerator::new(origin.base_seed(), origin, &params, &config)?;
+        let count = config.max_advance - config.user_offset;
+        results.extend(generator.take(count).into_iter().filter(|data| {
+            data.core.species_id == observation.species_id
+                && matches_observation(&data.core, &observation, &iv_ranges)
+        }));
+    }
+
+    Ok(results)
+}
+
+/// 観測値から個体値範囲を導出
+fn observation_iv_ranges(observation: &PokemonObservation) -> Result<[(u8, u8); 6], String> {
+    if observation.species_id == 0 || observation.species_id > 649 {
+        return Err(format!("Invalid species_id: {}", observation.species_id));
+    }
+    if observation.level == 0 || observation.level > 100 {
+        return Err(format!("Invalid level: {}", observation.level));
+    }
+
+    let entry = get_species_entry(observation.species_id);
+    derive_iv_ranges(
+        entry.base_stats,
+        &observation.stats,
+        observation.nature,
+        observation.level,
+    )
+    .ok_or_else(|| "Observed stats are inconsistent with species and nature".into())
+}
+
+/// 性格・性別・特性・個体値が観測値と矛盾しないか判定
+///
+/// 遺伝元の個体値が不明 (`IV_VALUE_UNKNOWN`) の場合は矛盾なしとして扱う。
+fn matches_observation(
+    core: &CorePokemonData,
+    observation: &PokemonObservation,
+    iv_ranges: &[(u8, u8); 6],
+) -> bool {
+    if core.nature != observation.nature {
+        return false;
+    }
+    if let Some(gender) = observation.gender
+        && core.gender != gender
+    {
+        return false;
+    }
+    if let Some(slot) = observation.ability_slot
+        && core.ability_slot != slot
+    {
+        return false;
+    }
+
+    core.ivs
+        .to_array()
+        .iter()
+        .zip(iv_ranges)
+        .all(|(&iv, &(min, max))| iv == IV_VALUE_UNKNOWN || (min..=max).contains(&iv))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{Stats, calculate_stats};
+    use crate::types::{
+        EncounterMethod, EncounterModifiers, EncounterSlotConfig, EncounterType, EverstonePlan,
+        GameStartConfig, GenderRatio, Ivs, LcgSeed, LeadAbilityEffect, MemoryLinkState, Nature,
+        RomVersion, SavePresence, ShinyCharmState, StartMode, TrainerInfo,
+    };
+
+    fn make_config() -> GenerationConfig {
+        GenerationConfig {
+            version: RomVersion::Black,
+            game_start: GameStartConfig {
+                start_mode: StartMode::Continue,
+                save: SavePresence::WithSave,
+                memory_link: MemoryLinkState::Disabled,
+                shiny_charm: ShinyCharmState::NotObtained,
+            },
+            user_offset: 0,
+            max_advance: 100,
+        }
+    }
+
+    fn make_static_params() -> PokemonGenerationParams {
+        PokemonGenerationParams {
+            trainer: TrainerInfo {
+                tid: 12345,
+                sid: 54321,
+            },
+            encounter_type: EncounterType::StaticSymbol,
+            encounter_method: EncounterMethod::Stationary,
+            lead_ability: LeadAbilityEffect::None,
+            slots: vec![EncounterSlotConfig {
+                species_id: 25,
+                level_min: 50,
+                level_max: 50,
+                gender_ratio: GenderRatio::F1M1,
+                has_held_item: false,
+                shiny_locked: false,
+            }],
+            seasonal_slots: None,
+            encounter_modifiers: EncounterModifiers::default(),
+        }
+    }
+
+    fn make_egg_params() -> EggGenerationParams {
+        EggGenerationParams {
+            trainer: TrainerInfo {
+                tid: 12345,
+                sid: 54321,
+            },
+            everstone: EverstonePlan::None,
+            female_ability_slot: crate::types::AbilitySlot::First,
+            uses_ditto: false,
+            gender_ratio: GenderRatio::F1M1,
+            nidoran_flag: false,
+            masuda_method: false,
+            parent_male: Ivs::new(31, 31, 31, 31, 31, 31),
+            parent_female: Ivs::new(31, 31, 31, 31, 31, 31),
+            parent_male_item: None,
+            parent_female_item: None,
+            consider_npc: false,
+            species_id: None,
+            parent_species: None,
+        }
+    }
+
+    fn observe(core: &CorePokemonData, level: u8) -> PokemonObservation {
+        let entry = get_species_entry(core.species_id);
+        PokemonObservation {
+            species_id: core.species_id,
+            level,
+            nature: core.nature,
+            gender: Some(core.gender),
+            ability_slot: Some(core.ability_slot),
+            stats: calculate_stats(entry.base_stats, core.ivs, core.nature, level),
+        }
+    }
+
+    #[test]
+    fn test_find_pokemon_contains_true_advance() {
+        let seed = LcgSeed::new(0x1234_5678_9ABC_DEF0);
+        let origins = vec![SeedOrigin::seed(seed)];
+        let params = make_static_params();
+        let config = make_config();
+
+        let generated = crate::generation::flows::generator::generate_pokemon_list(
+            origins.clone(),
+            params.clone(),
+            config.clone(),
+            None,
+        )
+        .unwrap();
+        let target = &generated[42];
+
+        let results =
+            find_pokemon_by_observation(origins, params, config, observe(&target.core, 50))
+                .unwrap();
+
+        assert!(results.iter().any(|r| r.advance == 42));
+        assert!(
+            results
+                .iter()
+                .all(|r| r.core.nature == target.core.nature && r.core.ivs == target.core.ivs)
+        );
+    }
+
+    #[test]
+    fn test_find_pokemon_invalid_species() {
+        let observation = PokemonObservation {
+            species_id: 0,
+            level: 50,
+            nature: Nature::Hardy,
+            gender: None,
+            ability_slot: None,
+            stats: Stats::UNKNOWN,
+        };
+        let result = find_pokemon_by_observation(
+            vec![SeedOrigin::seed(LcgSeed::new(1))],
+            make_static_params(),
+            make_config(),
+            observation,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_find_pokemon_inconsistent_stats() {
+        let observation = PokemonObservation {
+            species_id: 25,
+            level: 50,
+            nature: Nature::Hardy,
+            gender: None,
+            ability_slot: None,
+            stats: Stats {
+                hp: Some(999),
+                ..Stats::UNKNOWN
+            },
+        };
+        let result = find_pokemon_by_observation(
+            vec![SeedOrigin::seed(LcgSeed::new(1))],
+            make_static_params(),
+            make_config(),
+            observation,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_find_egg_contains_true_advance() {
+        let seed = LcgSeed::new(0x0FED_CBA9_8765_4321);
+        let origins = vec![SeedOrigin::seed(seed)];
+        let mut params = make_egg_params();
+        params.species_id = Some(25);
+        let config = make_config();
+
+        let generated = crate::generation::flows::generator::generate_egg_list(
+            origins.clone(),
+            params.clone(),
+            config.clone(),
+            None,
+        )
+        .unwrap();
+        let target = &generated[17];
+
+        // 孵化後 Lv.5 まで育ててから観測したとする
+        let mut params = params;
+        params.species_id = None;
+        let results =
+            find_egg_by_observation(origins, params, config, observe(&target.core, 5)).unwrap();
+
+        assert!(results.iter().any(|r| r.advance == 17));
+        assert!(results.iter().all(|r| r.core.species_id == 25));
+    }
+}
@@ -138,6 +138,7 @@ pub fn resolve_pokemon_data(
         hidden_power_power,
         pid,
         sync_applied: data.sync_applied,
+        cute_charm_applied: data.cute_charm_applied,
         held_item_name: get_held_item_name(
             data.core.species_id,
             version.held_item_index(),
@@ -183,6 +184,8 @@ fn format_encounter_result(result: crate::types::EncounterResult, _locale: &str)
             format!("Item:{item_str}")
         }
         crate::types::EncounterResult::FishingFailed => "FishingFailed".to_string(),
+        crate::types::EncounterResult::Suppressed => "Suppressed".to_string(),
+        crate::types::EncounterResult::Repelled => "Repelled".to_string(),
     }
 }
 
@@ -192,7 +195,8 @@ mod tests {
     use crate::data::{calculate_stats, get_species_entry};
     use crate::types::{
         AbilitySlot, CorePokemonData, Datetime, EncounterResult, Gender, HeldItemSlot, Ivs,
-        KeyMask, LcgSeed, MtSeed, Nature, NeedleDirection, Pid, ShinyType, StartupCondition,
+        KeyMask, LcgSeed, MtSeed, Nature, NeedleDirection, Pid, Season, ShinyType,
+        StartupCondition,
     };
 
     fn make_test_data() -> GeneratedPokemonData {
@@ -235,10 +239,13 @@ mod tests {
                 level,
             },
             sync_applied: false,
+            cute_charm_applied: false,
             held_item_slot: HeldItemSlot::None,
+            fishing_bite: None,
             moving_encounter: None,
             special_encounter: None,
             encounter_result: EncounterResult::Pokemon,
+            season: Some(Season::Spring),
         }
     }
 
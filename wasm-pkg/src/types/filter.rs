@@ -5,7 +5,10 @@
 use serde::{Deserialize, Serialize};
 use tsify::Tsify;
 
-use super::generation::{CorePokemonData, EncounterResult, GeneratedEggData, GeneratedPokemonData};
+use super::generation::{
+    CorePokemonData, EncounterResult, GeneratedDoubleEncounterData, GeneratedEggData,
+    GeneratedPokemonData,
+};
 use super::pokemon::{
     AbilitySlot, Gender, HeldItemSlot, HiddenPowerType, Ivs, Nature, Pid, ShinyType, TrainerInfo,
 };
@@ -123,7 +126,7 @@ impl IvFilter {
     /// - `value == IV_VALUE_UNKNOWN` かつ 特定範囲指定なら不通過
     /// - それ以外は通常の範囲チェック
     #[inline]
-    fn check_stat(value: u8, range: (u8, u8)) -> bool {
+    pub(crate) fn check_stat(value: u8, range: (u8, u8)) -> bool {
         use super::pokemon::IV_VALUE_UNKNOWN;
         if value == IV_VALUE_UNKNOWN {
             // Unknown は任意範囲 (min=0, max>=31) のみ通過
@@ -358,7 +361,7 @@ impl CoreDataFilter {
 #[derive(Tsify, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub enum EncounterResultFilter {
-    /// ポケモンのみ通過 (`Item` / `FishingFailed` を除外)
+    /// ポケモンのみ通過 (`Item` / `FishingFailed` / `Suppressed` / `Repelled` を除外)
     PokemonOnly,
     /// アイテムのみ通過 (`DustCloud` / `PokemonShadow` 用)
     ItemOnly,
@@ -386,6 +389,9 @@ pub struct PokemonFilter {
     /// 特殊エンカウント発生判定
     #[serde(default)]
     pub special_encounter_triggered: Option<bool>,
+    /// むしよけスプレーで回避された結果 (`Repelled`) を除外
+    #[serde(default)]
+    pub exclude_repelled: bool,
 }
 
 impl PokemonFilter {
@@ -398,27 +404,53 @@ impl PokemonFilter {
             held_item_slots: None,
             encounter_result_filter: None,
             special_encounter_triggered: None,
+            exclude_repelled: false,
         }
     }
 
     /// `GeneratedPokemonData` が条件に一致するか判定
     pub fn matches(&self, data: &GeneratedPokemonData) -> bool {
+        self.matches_member(
+            &data.core,
+            data.held_item_slot,
+            data.encounter_result,
+            data.special_encounter.as_ref().map(|info| info.triggered),
+        )
+    }
+
+    /// `GeneratedDoubleEncounterData` のいずれか 1 体が条件に一致するか判定
+    ///
+    /// 濃い草むらの個体は持ち物判定を行わないため、持ち物スロットは `None` として扱う。
+    pub fn matches_double(&self, data: &GeneratedDoubleEncounterData) -> bool {
+        [&data.first, &data.second]
+            .into_iter()
+            .any(|core| self.matches_member(core, HeldItemSlot::None, data.encounter_result, None))
+    }
+
+    /// 1 体分の個体情報が条件に一致するか判定
+    fn matches_member(
+        &self,
+        core: &CorePokemonData,
+        held_item_slot: HeldItemSlot,
+        encounter_result: EncounterResult,
+        special_triggered: Option<bool>,
+    ) -> bool {
         // 共通条件
-        if !self.base.matches_pokemon(data) {
+        if !self.base.matches(core) {
             return false;
         }
 
         // 種族 ID
         if let Some(ref ids) = self.species_ids
             && !ids.is_empty()
-            && !ids.contains(&data.core.species_id)
+            && !ids.contains(&core.species_id)
         {
             return false;
         }
 
         // レベル範囲
         if let Some((min, max)) = self.level_range
-            && (data.core.level < min || data.core.level > max)
+            && (core.level < min || core.level > max)
         {
             return false;
         }
@@ -426,7 +458,7 @@ impl PokemonFilter {
         // 持ち物スロットフィルタ
         if let Some(ref slots) = self.held_item_slots
             && !slots.is_empty()
-            && !slots.contains(&data.held_item_slot)
+            && !slots.contains(&held_item_slot)
         {
             return false;
         }
@@ -435,22 +467,26 @@ impl PokemonFilter {
         if let Some(ref result_filter) = self.encounter_result_filter {
             match result_filter {
                 EncounterResultFilter::PokemonOnly => {
-                    if !matches!(data.encounter_result, EncounterResult::Pokemon) {
+                    if !matches!(encounter_result, EncounterResult::Pokemon) {
                         return false;
                     }
                 }
                 EncounterResultFilter::ItemOnly => {
-                    if !matches!(data.encounter_result, EncounterResult::Item { .. }) {
+                    if !matches!(encounter_result, EncounterResult::Item { .. }) {
                         return false;
                     }
                 }
             }
         }
 
+        // むしよけスプレー回避の除外
+        if self.exclude_repelled && encounter_result == EncounterResult::Repelled {
+            return false;
+        }
+
         // 特殊エンカウント発生判定
         if let Some(required_triggered) = self.special_encounter_triggered
-            && data.special_encounter.as_ref().map(|info| info.triggered)
-                != Some(required_triggered)
+            && special_triggered != Some(required_triggered)
         {
             return false;
         }
@@ -502,6 +538,31 @@ impl EggFilter {
     }
 }
 
+// ===== PokemonObservation =====
+
+/// 捕獲・孵化済み個体の観測値
+///
+/// 逆引き (`find_pokemon_by_observation` / `find_egg_by_observation`) の入力。
+/// 実ステータスは `data::derive_iv_ranges` で個体値範囲に変換して照合する。
+#[derive(Tsify, Serialize, Deserialize, Clone, Debug)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct PokemonObservation {
+    /// 種族 ID
+    pub species_id: u16,
+    /// 実ステータスを観測したレベル
+    pub level: u8,
+    /// 性格
+    pub nature: Nature,
+    /// 性別 (None で条件なし)
+    #[serde(default)]
+    pub gender: Option<Gender>,
+    /// 特性スロット (None で条件なし)
+    #[serde(default)]
+    pub ability_slot: Option<AbilitySlot>,
+    /// 実ステータス (不明な項目は None)
+    pub stats: Stats,
+}
+
 // ===== Tests =====
 
 #[cfg(test)]
@@ -565,10 +626,13 @@ mod tests {
                 level,
             },
             sync_applied: false,
+            cute_charm_applied: false,
             held_item_slot,
+            fishing_bite: None,
             moving_encounter: None,
             special_encounter: None,
             encounter_result,
+            season: None,
         }
     }
 
@@ -1141,6 +1205,55 @@ mod tests {
         assert!(!filter.matches(&fishing_failed));
     }
 
+    #[test]
+    fn test_encounter_result_filter_pokemon_only_excludes_suppressed() {
+        let filter = PokemonFilter {
+            encounter_result_filter: Some(EncounterResultFilter::PokemonOnly),
+            ..Default::default()
+        };
+        let suppressed = make_pokemon_ext(
+            Ivs::uniform(15),
+            Nature::Adamant,
+            Gender::Male,
+            AbilitySlot::First,
+            ShinyType::None,
+            1,
+            50,
+            HeldItemSlot::None,
+            EncounterResult::Suppressed,
+        );
+        assert!(!filter.matches(&suppressed));
+    }
+
+    #[test]
+    fn test_exclude_repelled() {
+        let make = |encounter_result| {
+            make_pokemon_ext(
+                Ivs::uniform(15),
+                Nature::Adamant,
+                Gender::Male,
+                AbilitySlot::First,
+                ShinyType::None,
+                1,
+                50,
+                HeldItemSlot::None,
+                encounter_result,
+            )
+        };
+        let repelled = make(EncounterResult::Repelled);
+        let pokemon = make(EncounterResult::Pokemon);
+
+        // 既定では除外しない
+        assert!(PokemonFilter::any().matches(&repelled));
+
+        let filter = PokemonFilter {
+            exclude_repelled: true,
+            ..Default::default()
+        };
+        assert!(!filter.matches(&repelled));
+        assert!(filter.matches(&pokemon));
+    }
+
     #[test]
     fn test_encounter_result_filter_none_passes_all() {
         let filter = PokemonFilter {
@@ -16,7 +16,7 @@ use crate::data::Stats;
 
 // ===== エンカウント結果 =====
 
-/// エンカウント結果 (`DustCloud` / `PokemonShadow` / `Fishing` 用)
+/// エンカウント結果 (`DustCloud` / `PokemonShadow` / `Fishing` / 先頭特性による回避用)
 #[derive(Tsify, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 #[serde(tag = "type", content = "item")]
@@ -28,6 +28,22 @@ pub enum EncounterResult {
     Item(ItemContent),
     /// 釣り失敗
     FishingFailed,
+    /// いかく / するどいめによるエンカウント回避
+    Suppressed,
+    /// むしよけスプレーによるエンカウント回避
+    Repelled,
+}
+
+/// 釣りの当たり判定結果 (通常釣りのみ)
+#[derive(Tsify, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub enum FishingBite {
+    /// 判定成功 (50%)
+    Hooked,
+    /// 判定失敗 (`FishingFailed`)
+    Missed,
+    /// きゅうばん / ねんちゃく先頭により確定 (判定の乱数消費なし)
+    Guaranteed,
 }
 
 /// アイテム内容 (`DustCloud` / `PokemonShadow` 用)
@@ -55,6 +71,8 @@ pub enum EncounterType {
     ShakingGrass,
     DustCloud,
     PokemonShadow,
+    /// 濃い草むら (ダブルバトル)
+    DarkGrass,
     // 野生エンカウント - 水上
     Surfing,
     SurfingBubble,
@@ -84,6 +102,31 @@ pub enum EncounterMethod {
     Moving,
 }
 
+/// 季節
+///
+/// BW/BW2 の季節は月で決まり、1 か月ごとに春→夏→秋→冬を繰り返す
+/// (1・5・9 月が春、2・6・10 月が夏、3・7・11 月が秋、4・8・12 月が冬)。
+#[derive(Tsify, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub enum Season {
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+impl Season {
+    /// 月 (1-12) から季節を判定
+    pub const fn from_month(month: u8) -> Self {
+        match month.wrapping_sub(1) % 4 {
+            0 => Self::Spring,
+            1 => Self::Summer,
+            2 => Self::Autumn,
+            _ => Self::Winter,
+        }
+    }
+}
+
 // ===== 起動設定 =====
 
 /// 起動方法
@@ -308,14 +351,50 @@ pub struct GeneratedPokemonData {
     pub core: CorePokemonData,
     // ポケモン固有
     pub sync_applied: bool,
+    /// メロメロボディによる性別固定が適用されたか
+    pub cute_charm_applied: bool,
     pub held_item_slot: HeldItemSlot,
+    /// 釣りの当たり判定結果 (`Fishing` 時のみ Some)
+    pub fishing_bite: Option<FishingBite>,
     // === エンカウント付加情報 (排反) ===
-    /// 移動エンカウント情報 (Normal/Surfing + Moving 時のみ Some)
+    /// 移動エンカウント情報 (Normal/Surfing/DarkGrass + Moving 時のみ Some)
     pub moving_encounter: Option<MovingEncounterInfo>,
     /// 特殊エンカウント情報 (ShakingGrass/DustCloud/SurfingBubble/FishingBubble/PokemonShadow 時のみ Some)
     pub special_encounter: Option<SpecialEncounterInfo>,
     /// エンカウント結果 (DustCloud/PokemonShadow 時に使用。通常は Pokemon)
     pub encounter_result: EncounterResult,
+    /// 起動日時の季節 (`SeedOrigin::Startup` 時のみ Some)
+    ///
+    /// シキジカ・メブキジカのフォルムは出現時の季節で決まる。
+    pub season: Option<Season>,
+}
+
+/// ダブルバトル (濃い草むら) の個体データ
+///
+/// 1 回のエンカウントで出現する 2 体をまとめて保持する。
+#[derive(Tsify, Serialize, Deserialize, Clone)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+pub struct GeneratedDoubleEncounterData {
+    // 列挙コンテキスト
+    pub advance: u32,
+    /// `advance` 時点でレポートを書いた場合に表示される針方向
+    pub needle_direction: NeedleDirection,
+    /// 生成元情報
+    pub source: SeedOrigin,
+    /// 1 体目
+    pub first: CorePokemonData,
+    /// 2 体目
+    pub second: CorePokemonData,
+    /// シンクロ適用 (2 体共通)
+    pub sync_applied: bool,
+    /// メロメロボディ適用 (1 体目, 2 体目)
+    pub cute_charm_applied: [bool; 2],
+    /// 移動エンカウント情報 (Moving 時のみ Some)
+    pub moving_encounter: Option<MovingEncounterInfo>,
+    /// エンカウント結果 (回避時は 2 体とも個体なし)
+    pub encounter_result: EncounterResult,
+    /// 起動日時の季節 (`SeedOrigin::Startup` 時のみ Some)
+    pub season: Option<Season>,
 }
 
 /// 完全な卵データ
@@ -350,8 +429,37 @@ pub enum EverstonePlan {
     Fixed(Nature),
 }
 
+/// パワー系アイテム (孵化時の親の持ち物)
+///
+/// 所持親から対応するステータスの個体値が確定で遺伝する。
+#[derive(Tsify, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[repr(u8)]
+pub enum PowerItem {
+    /// パワーウエイト (HP)
+    PowerWeight = 0,
+    /// パワーリスト (攻撃)
+    PowerBracer = 1,
+    /// パワーベルト (防御)
+    PowerBelt = 2,
+    /// パワーレンズ (特攻)
+    PowerLens = 3,
+    /// パワーバンド (特防)
+    PowerBand = 4,
+    /// パワーアンクル (素早さ)
+    PowerAnklet = 5,
+}
+
+impl PowerItem {
+    /// 対応するステータスのインデックス (0=HP, 1=Atk, 2=Def, 3=SpA, 4=SpD, 5=Spe)
+    #[inline]
+    pub const fn stat_index(self) -> u8 {
+        self as u8
+    }
+}
+
 /// エンカウントスロット設定
-#[derive(Tsify, Serialize, Deserialize, Clone, Debug)]
+#[derive(Tsify, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct EncounterSlotConfig {
     /// ポケモン種族 ID
@@ -368,6 +476,41 @@ pub struct EncounterSlotConfig {
     pub shiny_locked: bool,
 }
 
+/// 季節別エンカウントスロット
+///
+/// 積雪等でエンカウントのない季節は空。
+#[derive(Tsify, Serialize, Deserialize, Clone, Debug)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct SeasonalSlots {
+    pub spring: Vec<EncounterSlotConfig>,
+    pub summer: Vec<EncounterSlotConfig>,
+    pub autumn: Vec<EncounterSlotConfig>,
+    pub winter: Vec<EncounterSlotConfig>,
+}
+
+impl SeasonalSlots {
+    /// 指定季節のスロットを取得
+    pub fn get(&self, season: Season) -> &[EncounterSlotConfig] {
+        match season {
+            Season::Spring => &self.spring,
+            Season::Summer => &self.summer,
+            Season::Autumn => &self.autumn,
+            Season::Winter => &self.winter,
+        }
+    }
+
+    /// 全季節のスロットを列挙
+    pub fn iter(&self) -> impl Iterator<Item = &[EncounterSlotConfig]> {
+        [
+            self.spring.as_slice(),
+            self.summer.as_slice(),
+            self.autumn.as_slice(),
+            self.winter.as_slice(),
+        ]
+        .into_iter()
+    }
+}
+
 /// ポケモン生成パラメータ
 ///
 /// `GenerationConfig` を含まない。生成条件のみを定義。
@@ -383,7 +526,59 @@ pub struct PokemonGenerationParams {
     /// 先頭特性効果
     pub lead_ability: LeadAbilityEffect,
     /// エンカウントスロット (Wild: 複数、Static: 1件)
+    ///
+    /// `seasonal_slots` 指定時は起動日時を持たない `SeedOrigin::Seed` 用のスロット。
     pub slots: Vec<EncounterSlotConfig>,
+    /// 季節別エンカウントスロット
+    ///
+    /// 指定時、`SeedOrigin::Startup` の起動日時の月から季節を判定し、
+    /// `slots` の代わりに該当季節のスロットを使用する。
+    /// 該当季節のスロットが空の Seed からは個体を生成しない。
+    #[serde(default)]
+    pub seasonal_slots: Option<SeasonalSlots>,
+    /// エンカウント補正 (むしよけスプレー / ビードロ)
+    #[serde(default)]
+    pub encounter_modifiers: EncounterModifiers,
+}
+
+/// ビードロ効果
+#[derive(Tsify, Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub enum FluteEffect {
+    /// 使用なし
+    #[default]
+    None,
+    /// しろいビードロ: エンカウント率 1.5 倍
+    White,
+    /// くろいビードロ: エンカウント率 0.5 倍
+    Black,
+}
+
+/// エンカウント補正
+///
+/// 道具によるエンカウント条件の変化を指定する。いずれも乱数消費には影響しない。
+#[derive(Tsify, Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct EncounterModifiers {
+    /// むしよけスプレー使用時の先頭ポケモンのレベル
+    ///
+    /// 指定時、これより低いレベルの野生ポケモン (`Normal` / `Surfing` / `Fishing` / `DarkGrass`) は
+    /// `EncounterResult::Repelled` となる。`DarkGrass` はいずれか 1 体が該当すれば 2 体とも回避。
+    #[serde(default)]
+    pub repel_level: Option<u8>,
+    /// ビードロ効果 (移動エンカウント判定の閾値に反映)
+    #[serde(default)]
+    pub flute: FluteEffect,
+}
+
+impl PokemonGenerationParams {
+    /// 生成元に対応するエンカウントスロットを取得
+    pub fn slots_for(&self, origin: &SeedOrigin) -> &[EncounterSlotConfig] {
+        match (&self.seasonal_slots, origin.datetime()) {
+            (Some(seasonal), Some(datetime)) => seasonal.get(Season::from_month(datetime.month)),
+            _ => &self.slots,
+        }
+    }
 }
 
 /// 卵生成パラメータ
@@ -411,6 +606,12 @@ pub struct EggGenerationParams {
     pub parent_male: Ivs,
     /// メス親の個体値
     pub parent_female: Ivs,
+    /// オス親の持ち物 (パワー系アイテム)
+    #[serde(default)]
+    pub parent_male_item: Option<PowerItem>,
+    /// メス親の持ち物 (パワー系アイテム)
+    #[serde(default)]
+    pub parent_female_item: Option<PowerItem>,
     /// NPC消費を考慮するか
     pub consider_npc: bool,
     /// 孵化対象の種族 ID (オプション)
@@ -421,6 +622,39 @@ pub struct EggGenerationParams {
     /// ニドラン♀ (#29) / イルミーゼ (#314) を指定した場合、
     /// 性別に応じて自動的に ニドラン♂ (#32) / バルビート (#313) に変換される。
     pub species_id: Option<u16>,
+    /// 両親の種族 (オプション)
+    ///
+    /// 指定時、`species_id` / `gender_ratio` / `nidoran_flag` / `uses_ditto` を
+    /// 両親の種族から自動決定する (手動指定値は上書きされる)。
+    #[serde(default)]
+    pub parent_species: Option<BreedingParents>,
+}
+
+/// 両親の種族 (孵化個体の種族自動決定用)
+#[derive(Tsify, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct BreedingParents {
+    /// オス親の種族 ID (メタモン / 性別不明の場合もいずれかの親として指定)
+    pub male_species: u16,
+    /// メス親の種族 ID
+    pub female_species: u16,
+    /// いずれかの親に対応するおこうを持たせているか (ベビィポケモン用)
+    #[serde(default)]
+    pub incense: bool,
+}
+
+/// 孵化個体の種族決定結果
+#[derive(Tsify, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct EggSpeciesResolution {
+    /// 孵化個体の種族 ID
+    pub species_id: u16,
+    /// 孵化個体の性別比
+    pub gender_ratio: GenderRatio,
+    /// ニドラン♀ / イルミーゼ系 (性別で種族が分かれる)
+    pub nidoran_flag: bool,
+    /// メタモンを使用しているか
+    pub uses_ditto: bool,
 }
 
 // ===== Seed 指定仕様 =====
@@ -453,6 +687,9 @@ pub enum SeedSpec {
         ranges: Vec<Timer0VCountRange>,
         /// キー入力
         key_input: KeyInput,
+        /// `Timer0` / `VCount` 範囲を `ds` の既定値から自動設定する (`true` の場合 `ranges` は無視)
+        #[serde(default)]
+        auto_ranges: bool,
     },
 }
 
@@ -502,4 +739,19 @@ mod tests {
         };
         assert_eq!(info.triggered_symbol(), "×");
     }
+
+    #[test]
+    fn test_season_from_month() {
+        let seasons: Vec<Season> = (1..=12).map(Season::from_month).collect();
+        assert_eq!(
+            seasons,
+            [
+                Season::Spring,
+                Season::Summer,
+                Season::Autumn,
+                Season::Winter,
+            ]
+            .repeat(3)
+        );
+    }
 }
@@ -25,34 +25,47 @@ pub use keyinput::{DsButton, KeyInput, KeyMask, KeySpec};
 
 // search
 pub use search::{
-    DateRangeParams, DatetimeSearchContext, EggDatetimeSearchBatch, EggDatetimeSearchParams,
-    EggDatetimeSearchResult, MtseedDatetimeSearchBatch, MtseedDatetimeSearchParams, MtseedResult,
-    MtseedSearchBatch, MtseedSearchContext, MtseedSearchParams, SearchRangeParams, TimeRangeParams,
-    TrainerInfoSearchBatch, TrainerInfoSearchParams, TrainerInfoSearchResult,
+    CalibrationOutcome, CalibrationResult, CalibrationSample, CalibrationSearchBatch,
+    CalibrationSearchParams, DateRangeParams, DatetimeSearchContext, EggDatetimeSearchBatch,
+    EggDatetimeSearchParams, EggDatetimeSearchResult, EggMtseedResult, EggMtseedSearchBatch,
+    EggMtseedSearchContext, EggMtseedSearchParams, IvDatetimeSearchBatch, IvDatetimeSearchParams,
+    IvDatetimeSearchResult, LcgseedDatetimeSearchBatch, LcgseedDatetimeSearchParams,
+    MissDiagnosisParams, MissDiagnosisResult, MissObservation, MtseedDatetimeSearchBatch,
+    MtseedDatetimeSearchParams, MtseedResult, MtseedSearchBatch, MtseedSearchContext,
+    MtseedSearchParams, NeedleDatetimeSearchBatch, NeedleDatetimeSearchParams,
+    PokemonDatetimeSearchBatch, PokemonDatetimeSearchParams, PokemonDatetimeSearchResult,
+    SearchRangeParams, TimeRangeParams, TrainerInfoSearchBatch, TrainerInfoSearchParams,
+    TrainerInfoSearchResult,
 };
 
 // filter
 pub use filter::{
-    CoreDataFilter, EggFilter, EncounterResultFilter, IvFilter, PokemonFilter, ShinyFilter,
-    StatsFilter, TrainerInfoFilter,
+    CoreDataFilter, EggFilter, EncounterResultFilter, IvFilter, PokemonFilter, PokemonObservation,
+    ShinyFilter, StatsFilter, TrainerInfoFilter,
 };
 
 // generation
 pub use generation::{
-    CorePokemonData, EggGenerationParams, EncounterMethod, EncounterResult, EncounterSlotConfig,
-    EncounterType, EverstonePlan, GameStartConfig, GeneratedEggData, GeneratedPokemonData,
-    GenerationConfig, ItemContent, MemoryLinkState, MovingEncounterInfo, MovingEncounterLikelihood,
-    PokemonGenerationParams, SavePresence, SeedSpec, ShinyCharmState, SpecialEncounterDirection,
-    SpecialEncounterInfo, StartMode,
+    BreedingParents, CorePokemonData, EggGenerationParams, EggSpeciesResolution, EncounterMethod,
+    EncounterModifiers, EncounterResult, EncounterSlotConfig, EncounterType, EverstonePlan,
+    FishingBite, FluteEffect, GameStartConfig, GeneratedDoubleEncounterData, GeneratedEggData,
+    GeneratedPokemonData, GenerationConfig, ItemContent, MemoryLinkState, MovingEncounterInfo,
+    MovingEncounterLikelihood, PokemonGenerationParams, PowerItem, SavePresence, Season,
+    SeasonalSlots, SeedSpec, ShinyCharmState, SpecialEncounterDirection, SpecialEncounterInfo,
+    StartMode,
 };
 
 // needle
-pub use needle::{NeedleDirection, NeedlePattern, NeedleSearchResult};
+pub use needle::{
+    FuzzyNeedlePattern, NeedleDirection, NeedleEntryMatch, NeedleFuzzySearchResult,
+    NeedleMatchMode, NeedlePattern, NeedlePatternEntry, NeedleSearchResult, NeedleTrackerCandidate,
+    NeedleTrackerStatus,
+};
 
 // pokemon
 pub use pokemon::{
-    AbilitySlot, Gender, GenderRatio, HeldItemSlot, HiddenPowerType, IV_VALUE_UNKNOWN,
-    InheritanceSlot, Ivs, LeadAbilityEffect, Nature, Pid, ShinyType, TrainerInfo,
+    AbilitySlot, EggGroup, Gender, GenderRatio, HeldItemSlot, HiddenPowerType, IV_VALUE_UNKNOWN,
+    InheritanceSlot, Ivs, LeadAbilityEffect, Nature, Pid, PokemonType, ShinyType, TrainerInfo,
 };
 
 // seeds
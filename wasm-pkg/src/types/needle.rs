@@ -58,6 +58,15 @@ impl NeedleDirection {
             Self::NW => "↖",
         }
     }
+
+    /// 隣接方向 (±1) かどうか
+    ///
+    /// 目視で読み取る際に取り違えやすい隣り合った方向を判定する。
+    #[inline]
+    pub const fn is_adjacent(self, other: Self) -> bool {
+        let diff = (self.value() + 8 - other.value()) & 7;
+        diff == 1 || diff == 7
+    }
 }
 
 /// レポート針パターン
@@ -114,6 +123,111 @@ impl NeedlePattern {
     }
 }
 
+// ===== あいまい針パターン =====
+
+/// あいまい針パターンの要素
+#[derive(Tsify, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(tag = "type")]
+pub enum NeedlePatternEntry {
+    /// 単一方向
+    Exact { direction: NeedleDirection },
+    /// 許容する方向の集合 (いずれかに一致、空は不可)
+    AnyOf { directions: Vec<NeedleDirection> },
+    /// ワイルドカード (読み取れなかった針)
+    Wildcard,
+}
+
+/// 要素単位の判定結果
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NeedleEntryMatch {
+    /// 一致
+    Hit,
+    /// 隣接方向で一致 (`adjacent_tolerance` 有効時のみ)
+    Adjacent,
+    /// ワイルドカード
+    Wildcard,
+    /// 不一致
+    Miss,
+}
+
+impl NeedlePatternEntry {
+    /// 実際の針方向と照合
+    pub fn evaluate(&self, actual: NeedleDirection, adjacent_tolerance: bool) -> NeedleEntryMatch {
+        let allowed: &[NeedleDirection] = match self {
+            Self::Wildcard => return NeedleEntryMatch::Wildcard,
+            Self::Exact { direction } => std::slice::from_ref(direction),
+            Self::AnyOf { directions } => directions,
+        };
+
+        if allowed.contains(&actual) {
+            NeedleEntryMatch::Hit
+        } else if adjacent_tolerance && allowed.iter().any(|d| d.is_adjacent(actual)) {
+            NeedleEntryMatch::Adjacent
+        } else {
+            NeedleEntryMatch::Miss
+        }
+    }
+}
+
+/// あいまい針パターン
+///
+/// ワイルドカードや方向集合を含むパターン。
+/// `NeedlePattern` からは全要素 `Exact` として変換される。
+#[derive(Tsify, Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct FuzzyNeedlePattern(pub Vec<NeedlePatternEntry>);
+
+impl FuzzyNeedlePattern {
+    /// 新しいパターンを作成
+    pub fn new(entries: Vec<NeedlePatternEntry>) -> Self {
+        Self(entries)
+    }
+
+    /// 内部の要素リストへの参照を取得
+    pub fn entries(&self) -> &[NeedlePatternEntry] {
+        &self.0
+    }
+
+    /// パターン長を取得
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// パターンが空かどうか
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl From<&NeedlePattern> for FuzzyNeedlePattern {
+    fn from(pattern: &NeedlePattern) -> Self {
+        Self(
+            pattern
+                .iter()
+                .map(|&direction| NeedlePatternEntry::Exact { direction })
+                .collect(),
+        )
+    }
+}
+
+/// 針パターン照合モード
+#[derive(Tsify, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(tag = "type")]
+pub enum NeedleMatchMode {
+    /// 完全一致 (ワイルドカード・方向集合は有効、不一致・隣接は許容しない)
+    #[default]
+    Strict,
+    /// あいまい一致
+    Fuzzy {
+        /// 隣接方向 (±1) を一致として扱う (減点あり)
+        adjacent_tolerance: bool,
+        /// 許容する不一致数
+        max_mismatches: u32,
+    },
+}
+
 // ===== Needle 検索関連型 =====
 
 /// レポート針パターン検索結果
@@ -130,6 +244,57 @@ pub struct NeedleSearchResult {
     pub source: SeedOrigin,
 }
 
+/// あいまい針パターン検索結果
+///
+/// `score` の降順 (同点は `advance` の昇順) で返却される。
+#[derive(Tsify, Serialize, Deserialize, Clone, Debug)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+pub struct NeedleFuzzySearchResult {
+    /// パターン末尾消費位置 (`game_offset` からの相対)
+    pub advance: u32,
+    /// 生成元情報
+    pub source: SeedOrigin,
+    /// 一致スコア (一致 2 点、隣接 1 点、ワイルドカード・不一致 0 点)
+    pub score: u32,
+    /// 隣接方向で一致した要素数
+    pub adjacent_count: u32,
+    /// 不一致要素数
+    pub mismatch_count: u32,
+}
+
+// ===== 針トラッキング関連型 =====
+
+/// 針トラッキング候補
+#[derive(Tsify, Serialize, Deserialize, Clone, Debug)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+pub struct NeedleTrackerCandidate {
+    /// 現在位置 (`game_offset` からの相対)
+    ///
+    /// 次にレポートを書いた時に表示される針の位置。
+    /// 観測済みの最後の針は `advance - 1` で表示されたもの。
+    pub advance: u32,
+    /// 生成元情報
+    pub source: SeedOrigin,
+}
+
+/// 針トラッキング状態
+#[derive(Tsify, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct NeedleTrackerStatus {
+    /// 観測済みの針数
+    pub observation_count: u32,
+    /// 残り候補数 (Seed × 消費位置)
+    pub candidate_count: u32,
+    /// 残り候補に含まれる Seed 数
+    pub origin_count: u32,
+    /// 現在位置 (全候補で一致する場合のみ)
+    pub current_advance: Option<u32>,
+    /// 現在位置の最小値 (候補なしの場合は None)
+    pub min_advance: Option<u32>,
+    /// 現在位置の最大値 (候補なしの場合は None)
+    pub max_advance: Option<u32>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -172,4 +337,65 @@ mod tests {
         let pattern = NeedlePattern::from_values(&[0, 2, 4, 6]);
         assert_eq!(pattern.to_arrows(), "↑→↓←");
     }
+
+    #[test]
+    fn test_needle_direction_is_adjacent() {
+        assert!(NeedleDirection::N.is_adjacent(NeedleDirection::NE));
+        assert!(NeedleDirection::N.is_adjacent(NeedleDirection::NW));
+        assert!(!NeedleDirection::N.is_adjacent(NeedleDirection::N));
+        assert!(!NeedleDirection::N.is_adjacent(NeedleDirection::E));
+    }
+
+    #[test]
+    fn test_needle_pattern_entry_evaluate() {
+        let exact = NeedlePatternEntry::Exact {
+            direction: NeedleDirection::E,
+        };
+        assert_eq!(
+            exact.evaluate(NeedleDirection::E, false),
+            NeedleEntryMatch::Hit
+        );
+        assert_eq!(
+            exact.evaluate(NeedleDirection::SE, false),
+            NeedleEntryMatch::Miss
+        );
+        assert_eq!(
+            exact.evaluate(NeedleDirection::SE, true),
+            NeedleEntryMatch::Adjacent
+        );
+
+        let any_of = NeedlePatternEntry::AnyOf {
+            directions: vec![NeedleDirection::N, NeedleDirection::S],
+        };
+        assert_eq!(
+            any_of.evaluate(NeedleDirection::S, false),
+            NeedleEntryMatch::Hit
+        );
+        assert_eq!(
+            any_of.evaluate(NeedleDirection::E, true),
+            NeedleEntryMatch::Miss
+        );
+
+        assert_eq!(
+            NeedlePatternEntry::Wildcard.evaluate(NeedleDirection::W, false),
+            NeedleEntryMatch::Wildcard
+        );
+    }
+
+    #[test]
+    fn test_fuzzy_pattern_from_needle_pattern() {
+        let pattern = NeedlePattern::from_values(&[0, 4]);
+        let fuzzy = FuzzyNeedlePattern::from(&pattern);
+        assert_eq!(
+            fuzzy.entries(),
+            &[
+                NeedlePatternEntry::Exact {
+                    direction: NeedleDirection::N
+                },
+                NeedlePatternEntry::Exact {
+                    direction: NeedleDirection::S
+                },
+            ]
+        );
+    }
 }
@@ -344,13 +344,30 @@ pub struct InheritanceSlot {
     pub stat: u8,
     /// 遺伝元親 (0=Male, 1=Female)
     pub parent: u8,
+    /// パワー系アイテムによる確定遺伝か
+    #[serde(default)]
+    pub forced: bool,
 }
 
 impl InheritanceSlot {
     /// 新しい遺伝スロットを作成
     #[inline]
     pub const fn new(stat: u8, parent: u8) -> Self {
-        Self { stat, parent }
+        Self {
+            stat,
+            parent,
+            forced: false,
+        }
+    }
+
+    /// パワー系アイテムによる確定遺伝スロットを作成
+    #[inline]
+    pub const fn new_forced(stat: u8, parent: u8) -> Self {
+        Self {
+            stat,
+            parent,
+            forced: true,
+        }
     }
 }
 
@@ -380,6 +397,82 @@ pub enum LeadAbilityEffect {
     Synchronize(Nature),
     /// ふくがん: 持ち物確率上昇
     CompoundEyes,
+    /// メロメロボディ: 2/3 で先頭ポケモン (指定性別) と異なる性別に固定
+    CuteCharm(Gender),
+    /// やる気 / プレッシャー / はりきり: 50% でスロットの最大レベル
+    LevelBoost,
+    /// じりょく: 50% ではがねタイプのスロットを優先
+    MagnetPull,
+    /// せいでんき: 50% ででんきタイプのスロットを優先
+    Static,
+    /// いかく / するどいめ: 先頭のレベル (指定値) より 5 以上低い野生ポケモンを 50% で回避
+    Intimidate(u8),
+    /// きゅうばん / ねんちゃく: 釣りの当たり判定が確定で成功
+    SuctionCups,
+}
+
+// ===== タイプ =====
+
+/// ポケモンのタイプ (第5世代、フェアリーなし)
+#[derive(Tsify, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[repr(u8)]
+pub enum PokemonType {
+    Normal = 0,
+    Fighting = 1,
+    Flying = 2,
+    Poison = 3,
+    Ground = 4,
+    Rock = 5,
+    Bug = 6,
+    Ghost = 7,
+    Steel = 8,
+    Fire = 9,
+    Water = 10,
+    Grass = 11,
+    Electric = 12,
+    Psychic = 13,
+    Ice = 14,
+    Dragon = 15,
+    Dark = 16,
+}
+
+// ===== タマゴグループ =====
+
+/// タマゴグループ
+#[derive(Tsify, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub enum EggGroup {
+    /// かいじゅう
+    Monster,
+    /// すいちゅう1
+    Water1,
+    /// むし
+    Bug,
+    /// ひこう
+    Flying,
+    /// りくじょう
+    Field,
+    /// ようせい
+    Fairy,
+    /// しょくぶつ
+    Grass,
+    /// ひとがた
+    HumanLike,
+    /// すいちゅう3
+    Water3,
+    /// こうぶつ
+    Mineral,
+    /// ふていけい
+    Amorphous,
+    /// すいちゅう2
+    Water2,
+    /// メタモン
+    Ditto,
+    /// ドラゴン
+    Dragon,
+    /// タマゴみはっけん (タマゴを作れない)
+    Undiscovered,
 }
 
 // ===== めざめるパワー =====
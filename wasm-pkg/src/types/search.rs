@@ -5,12 +5,16 @@
 use serde::{Deserialize, Serialize};
 use tsify::Tsify;
 
-use super::config::{DsConfig, StartupCondition, Timer0VCountRange};
-use super::filter::{EggFilter, IvFilter, TrainerInfoFilter};
-use super::generation::{EggGenerationParams, GeneratedEggData, GenerationConfig};
-use super::keyinput::KeySpec;
-use super::pokemon::{Ivs, ShinyType, TrainerInfo};
-use super::seeds::{MtSeed, SeedOrigin};
+use super::config::{Datetime, DsConfig, StartupCondition, Timer0VCountRange};
+use super::filter::{EggFilter, IvFilter, PokemonFilter, PokemonObservation, TrainerInfoFilter};
+use super::generation::{
+    EggGenerationParams, GeneratedEggData, GeneratedPokemonData, GenerationConfig,
+    PokemonGenerationParams,
+};
+use super::keyinput::{KeyInput, KeySpec};
+use super::needle::{NeedlePattern, NeedleSearchResult};
+use super::pokemon::{InheritanceSlot, Ivs, ShinyType, TrainerInfo};
+use super::seeds::{LcgSeed, MtSeed, SeedOrigin};
 
 // ===== 時刻範囲パラメータ =====
 
@@ -150,6 +154,12 @@ pub struct DatetimeSearchContext {
     pub ranges: Vec<Timer0VCountRange>,
     /// キー入力仕様 (全組み合わせを探索)
     pub key_spec: KeySpec,
+    /// Timer0/VCount 範囲を `ds` の既定値から自動設定する
+    ///
+    /// `true` の場合 `ranges` は無視される。
+    /// 既定値が未収集の場合、タスク生成 (`generate_*_search_tasks`) は `Err` を返す。
+    #[serde(default)]
+    pub auto_ranges: bool,
 }
 
 // ===== 内部ヘルパー関数 =====
@@ -211,6 +221,87 @@ pub struct MtseedDatetimeSearchBatch {
     pub total_count: u64,
 }
 
+// ===== LCG Seed 起動時刻検索 =====
+
+/// LCG Seed 検索パラメータ (単一組み合わせ)
+///
+/// `mask` を指定すると `seed & mask == target & mask` の部分一致で判定する。
+/// 上位 32bit のみ一致させる場合は `0xFFFF_FFFF_0000_0000` を指定する。
+#[derive(Tsify, Serialize, Deserialize, Clone)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+pub struct LcgseedDatetimeSearchParams {
+    /// 検索対象の LCG Seed セット
+    pub target_seeds: Vec<LcgSeed>,
+    /// 比較マスク (None の場合は 64bit 完全一致)
+    pub mask: Option<u64>,
+    /// DS 設定
+    pub ds: DsConfig,
+    /// 1日内の時刻範囲
+    pub time_range: TimeRangeParams,
+    /// 検索範囲 (秒単位)
+    pub search_range: SearchRangeParams,
+    /// 起動条件 (単一)
+    pub condition: StartupCondition,
+}
+
+/// LCG Seed 検索バッチ結果
+#[derive(Tsify, Serialize, Deserialize, Clone, Debug)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+pub struct LcgseedDatetimeSearchBatch {
+    /// 見つかった結果 (`SeedOrigin::Startup` 形式)
+    pub results: Vec<SeedOrigin>,
+    /// 処理済み件数
+    pub processed_count: u64,
+    /// 総件数
+    pub total_count: u64,
+}
+
+// ===== IV 起動時刻検索 =====
+
+/// IV 起動時刻検索パラメータ (単一組み合わせ)
+///
+/// MT Seed リストを経由せず、起動時刻ごとに IV を算出して `IvFilter` で判定する。
+#[derive(Tsify, Serialize, Deserialize, Clone)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct IvDatetimeSearchParams {
+    /// DS 設定
+    pub ds: DsConfig,
+    /// 1日内の時刻範囲
+    pub time_range: TimeRangeParams,
+    /// 検索範囲 (秒単位)
+    pub search_range: SearchRangeParams,
+    /// 起動条件 (単一)
+    pub condition: StartupCondition,
+    /// IV フィルタ条件
+    pub iv_filter: IvFilter,
+    /// MT オフセット (IV 生成開始位置)
+    pub mt_offset: u32,
+    /// 徘徊ポケモンモード
+    pub is_roamer: bool,
+}
+
+/// IV 起動時刻検索結果
+#[derive(Tsify, Serialize, Deserialize, Clone, Debug)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct IvDatetimeSearchResult {
+    /// 生成元情報 (`Datetime` + `StartupCondition`)
+    pub seed_origin: SeedOrigin,
+    /// 生成された IV
+    pub ivs: Ivs,
+}
+
+/// IV 起動時刻検索バッチ結果
+#[derive(Tsify, Serialize, Deserialize, Clone, Debug)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+pub struct IvDatetimeSearchBatch {
+    /// 見つかった結果
+    pub results: Vec<IvDatetimeSearchResult>,
+    /// 処理済み件数
+    pub processed_count: u64,
+    /// 総件数
+    pub total_count: u64,
+}
+
 // ===== トレーナー情報検索 =====
 
 use super::generation::GameStartConfig;
@@ -310,6 +401,191 @@ pub struct EggDatetimeSearchBatch {
     pub total_count: u64,
 }
 
+// ===== ポケモン起動時刻検索 =====
+
+/// ポケモン起動時刻検索パラメータ
+///
+/// 野生 / 固定シンボルの個体を起動時刻単位で検索する。
+#[derive(Tsify, Serialize, Deserialize, Clone)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct PokemonDatetimeSearchParams {
+    // === 起動時刻検索 ===
+    /// DS 設定
+    pub ds: DsConfig,
+    /// 1日内の時刻範囲
+    pub time_range: TimeRangeParams,
+    /// 検索範囲 (秒単位)
+    pub search_range: SearchRangeParams,
+    /// 起動条件 (単一)
+    pub condition: StartupCondition,
+
+    // === 個体生成 ===
+    /// ポケモン生成パラメータ (Wild / Static 統合)
+    pub pokemon_params: PokemonGenerationParams,
+    /// 生成共通設定
+    pub gen_config: GenerationConfig,
+
+    // === フィルタリング ===
+    /// フィルター (None の場合は全件返却)
+    pub filter: Option<PokemonFilter>,
+}
+
+/// ポケモン起動時刻検索結果
+///
+/// `GeneratedPokemonData` に起動条件 (`SeedOrigin::Startup`) が含まれるため、
+/// 追加フィールドは不要。
+#[derive(Tsify, Serialize, Deserialize, Clone)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct PokemonDatetimeSearchResult {
+    /// 生成された個体データ
+    ///
+    /// `source` フィールドに `SeedOrigin::Startup` が格納されており、
+    /// 起動日時・条件を取得可能。
+    pub pokemon: GeneratedPokemonData,
+}
+
+/// ポケモン起動時刻検索バッチ結果
+#[derive(Tsify, Serialize, Deserialize, Clone)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+pub struct PokemonDatetimeSearchBatch {
+    /// 見つかった結果
+    pub results: Vec<PokemonDatetimeSearchResult>,
+    /// 処理済み件数
+    pub processed_count: u64,
+    /// 総件数
+    pub total_count: u64,
+}
+
+// ===== レポート針起動時刻検索 =====
+
+/// レポート針起動時刻検索パラメータ (単一組み合わせ)
+///
+/// 観測した針パターンから、実際に起動した日時・起動条件を特定する。
+#[derive(Tsify, Serialize, Deserialize, Clone)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct NeedleDatetimeSearchParams {
+    // === 起動時刻検索 ===
+    /// DS 設定
+    pub ds: DsConfig,
+    /// 1日内の時刻範囲
+    pub time_range: TimeRangeParams,
+    /// 検索範囲 (秒単位)
+    pub search_range: SearchRangeParams,
+    /// 起動条件 (単一)
+    pub condition: StartupCondition,
+
+    // === 針パターン ===
+    /// 起動設定 (`game_offset` 計算用)
+    pub game_start: GameStartConfig,
+    /// 観測した針パターン
+    pub pattern: NeedlePattern,
+    /// 検索開始位置 (`game_offset` からの相対)
+    pub user_offset: u32,
+    /// 検索終了位置 (パターン末尾の上限)
+    pub max_advance: u32,
+}
+
+/// レポート針起動時刻検索バッチ結果
+///
+/// 各結果の `source` に `SeedOrigin::Startup` が格納される。
+#[derive(Tsify, Serialize, Deserialize, Clone, Debug)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+pub struct NeedleDatetimeSearchBatch {
+    /// 見つかった結果
+    pub results: Vec<NeedleSearchResult>,
+    /// 処理済み件数
+    pub processed_count: u64,
+    /// 総件数
+    pub total_count: u64,
+}
+
+// ===== 本体較正検索 =====
+
+/// 較正用の観測結果
+#[derive(Tsify, Serialize, Deserialize, Clone, Debug)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(tag = "type")]
+pub enum CalibrationOutcome {
+    /// MT 由来の個体値 (完全一致で判定)
+    Iv {
+        /// 観測した個体値
+        ivs: Ivs,
+        /// MT オフセット (IV 生成開始位置、通常 7)
+        mt_offset: u32,
+        /// 徘徊ポケモンモード
+        is_roamer: bool,
+    },
+    /// 「はじめから」で決定したトレーナー情報
+    TrainerInfo {
+        /// 観測した TID + SID
+        trainer: TrainerInfo,
+        /// 起動設定 (`StartMode::NewGame` のみ)
+        game_start: GameStartConfig,
+    },
+}
+
+/// 較正用の観測サンプル
+///
+/// 起動日時とキー入力が既知の 1 回の起動で得られた結果。
+#[derive(Tsify, Serialize, Deserialize, Clone, Debug)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct CalibrationSample {
+    /// 起動日時
+    pub datetime: Datetime,
+    /// 起動時のキー入力
+    #[serde(default)]
+    pub key_input: KeyInput,
+    /// 観測結果
+    pub outcome: CalibrationOutcome,
+}
+
+/// 本体較正検索パラメータ
+///
+/// `Timer0` × `VCount` × `GX_STAT` × frame の全組み合わせを走査し、
+/// 全サンプルと矛盾しない本体パラメータを検索する。
+#[derive(Tsify, Serialize, Deserialize, Clone, Debug)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct CalibrationSearchParams {
+    /// DS 設定 (MAC・ハードウェア・ROM)
+    pub ds: DsConfig,
+    /// 観測サンプル (1 件以上)
+    pub samples: Vec<CalibrationSample>,
+    /// `Timer0` / `VCount` の走査範囲
+    pub range: Timer0VCountRange,
+    /// `GX_STAT` 候補 (None の場合は既定値のみ)
+    #[serde(default)]
+    pub gx_stat_values: Option<Vec<u32>>,
+    /// frame の走査範囲 (min, max) (None の場合はハードウェア既定値のみ)
+    #[serde(default)]
+    pub frame_range: Option<(u8, u8)>,
+}
+
+/// 本体較正検索結果 (全サンプルと矛盾しないパラメータ)
+#[derive(Tsify, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct CalibrationResult {
+    /// `Timer0` 値
+    pub timer0: u16,
+    /// `VCount` 値
+    pub vcount: u8,
+    /// `GX_STAT` 値
+    pub gx_stat: u32,
+    /// frame 値
+    pub frame: u8,
+}
+
+/// 本体較正検索バッチ結果
+#[derive(Tsify, Serialize, Deserialize, Clone, Debug)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+pub struct CalibrationSearchBatch {
+    /// 見つかった結果
+    pub results: Vec<CalibrationResult>,
+    /// 処理済み件数
+    pub processed_count: u64,
+    /// 総件数
+    pub total_count: u64,
+}
+
 // ===== MT Seed 検索 (misc) =====
 
 /// MT Seed 検索コンテキスト (ユーザー入力用)
@@ -368,6 +644,155 @@ pub struct MtseedSearchBatch {
     pub total: u64,
 }
 
+// ===== 孵化 MT Seed 全探索 (misc) =====
+
+/// 孵化 MT Seed 検索コンテキスト (ユーザー入力用)
+///
+/// `generate_egg_mtseed_iv_search_tasks` に渡すと、範囲付きの `EggMtseedSearchParams` に変換される。
+/// 親の個体値が不明なステータスは `IV_VALUE_UNKNOWN` (32) を指定する。
+#[derive(Tsify, Serialize, Deserialize, Clone)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct EggMtseedSearchContext {
+    /// 孵化個体の IV フィルタ条件 (遺伝適用後)
+    pub iv_filter: IvFilter,
+    /// オス親の個体値
+    pub parent_male: Ivs,
+    /// メス親の個体値
+    pub parent_female: Ivs,
+    /// MT オフセット (IV 生成開始位置、孵化は 7)
+    pub mt_offset: u32,
+}
+
+/// 孵化 MT Seed 検索パラメータ (タスク用)
+///
+/// `start_seed` / `end_seed` は閉区間 `[start_seed, end_seed]` を表す。
+#[derive(Tsify, Serialize, Deserialize, Clone)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct EggMtseedSearchParams {
+    /// 孵化個体の IV フィルタ条件 (遺伝適用後)
+    pub iv_filter: IvFilter,
+    /// オス親の個体値
+    pub parent_male: Ivs,
+    /// メス親の個体値
+    pub parent_female: Ivs,
+    /// MT オフセット (IV 生成開始位置、孵化は 7)
+    pub mt_offset: u32,
+    /// 検索開始 Seed (inclusive)
+    pub start_seed: u32,
+    /// 検索終了 Seed (inclusive)
+    pub end_seed: u32,
+}
+
+/// 孵化 MT Seed 検索結果
+#[derive(Tsify, Serialize, Deserialize, Clone, Debug)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct EggMtseedResult {
+    /// 一致した MT Seed
+    pub seed: MtSeed,
+    /// 遺伝適用前の乱数 IV
+    pub rng_ivs: Ivs,
+    /// 条件を満たす遺伝パターン (各パターンはステータス昇順)
+    pub patterns: Vec<[InheritanceSlot; 3]>,
+}
+
+/// 孵化 MT Seed 検索バッチ結果
+#[derive(Tsify, Serialize, Deserialize, Clone)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+pub struct EggMtseedSearchBatch {
+    /// 条件を満たした候補
+    pub candidates: Vec<EggMtseedResult>,
+    /// 処理済み Seed 数
+    pub processed: u64,
+    /// 総 Seed 数
+    pub total: u64,
+}
+
+// ===== 外れ診断 (misc) =====
+
+/// 外れ診断の観測値
+///
+/// 実際に得られた結果の種類ごとに、照合に必要な情報を保持する。
+#[derive(Tsify, Serialize, Deserialize, Clone)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(tag = "type")]
+pub enum MissObservation {
+    /// 捕獲したポケモン
+    Pokemon {
+        /// 生成パラメータ (Wild / Static 統合)
+        params: PokemonGenerationParams,
+        /// 捕獲個体の観測値
+        observation: PokemonObservation,
+    },
+    /// 孵化したポケモン
+    Egg {
+        /// 生成パラメータ
+        params: EggGenerationParams,
+        /// 孵化個体の観測値
+        observation: PokemonObservation,
+    },
+    /// 観測したレポート針パターン
+    Needle {
+        /// 観測した針パターン
+        pattern: NeedlePattern,
+    },
+    /// 観測した個体値
+    Iv {
+        /// IV フィルタ条件
+        iv_filter: IvFilter,
+        /// MT オフセット (IV 生成開始位置、通常 7)
+        mt_offset: u32,
+        /// 徘徊ポケモンモード
+        is_roamer: bool,
+    },
+}
+
+/// 外れ診断パラメータ
+///
+/// 狙った起動条件 (`intended`) の周辺を探索し、実際に起動した条件を推定する。
+#[derive(Tsify, Serialize, Deserialize, Clone)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+pub struct MissDiagnosisParams {
+    /// DS 設定
+    pub ds: DsConfig,
+    /// 狙った起動条件 (`SeedOrigin::Startup` のみ)
+    pub intended: SeedOrigin,
+    /// 起動日時の探索幅 (± 秒)
+    pub second_radius: u32,
+    /// `Timer0` の探索幅 (±)
+    pub timer0_radius: u16,
+    /// `VCount` の探索幅 (±)
+    pub vcount_radius: u8,
+    /// 押し間違いを想定するキー入力 (None の場合は `intended` のキー入力のみ)
+    #[serde(default)]
+    pub key_spec: Option<KeySpec>,
+    /// 生成設定 (`version`, `game_start`, advance 範囲)
+    pub config: GenerationConfig,
+    /// 観測値
+    pub observation: MissObservation,
+}
+
+/// 外れ診断結果
+///
+/// `distance` の昇順 (狙った条件に近い順) に並ぶ。
+#[derive(Tsify, Serialize, Deserialize, Clone, Debug)]
+#[tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)]
+pub struct MissDiagnosisResult {
+    /// 実際の起動条件
+    pub source: SeedOrigin,
+    /// 一致した消費位置 (`Iv` 観測の場合は None)
+    pub advance: Option<u32>,
+    /// 狙った日時との差 (秒)
+    pub second_offset: i32,
+    /// 狙った `Timer0` との差
+    pub timer0_offset: i32,
+    /// 狙った `VCount` との差
+    pub vcount_offset: i32,
+    /// キー入力が狙いと異なるか
+    pub key_mismatch: bool,
+    /// 狙った条件からの距離 (各差の絶対値の和 + キー入力不一致で 1)
+    pub distance: u32,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
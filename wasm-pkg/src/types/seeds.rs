@@ -139,4 +139,12 @@ impl SeedOrigin {
             Self::Seed { mt_seed, .. } | Self::Startup { mt_seed, .. } => *mt_seed,
         }
     }
+
+    /// 起動日時を取得 (`Seed` の場合は `None`)
+    pub const fn datetime(&self) -> Option<Datetime> {
+        match self {
+            Self::Seed { .. } => None,
+            Self::Startup { datetime, .. } => Some(*datetime),
+        }
+    }
 }
@@ -62,6 +62,7 @@ pub struct UiPokemonData {
 
     // === エンカウント情報 ===
     pub sync_applied: bool,
+    pub cute_charm_applied: bool,
     pub held_item_name: Option<String>,
 
     // === 移動/特殊エンカウント情報 ===
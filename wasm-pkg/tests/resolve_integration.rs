@@ -7,7 +7,7 @@ use wasm_pkg::resolve::{resolve_egg_data, resolve_pokemon_data};
 use wasm_pkg::types::{
     AbilitySlot, CorePokemonData, Datetime, EncounterResult, Gender, GeneratedEggData,
     GeneratedPokemonData, HeldItemSlot, InheritanceSlot, Ivs, KeyMask, LcgSeed, MtSeed, Nature,
-    NeedleDirection, Pid, RomVersion, SeedOrigin, ShinyType, StartupCondition, Stats,
+    NeedleDirection, Pid, RomVersion, Season, SeedOrigin, ShinyType, StartupCondition, Stats,
 };
 
 /// テスト用のポケモンデータを生成
@@ -52,10 +52,13 @@ fn create_test_pokemon_data() -> GeneratedPokemonData {
             }
         },
         sync_applied: false,
+        cute_charm_applied: false,
         held_item_slot: HeldItemSlot::Common, // オレンのみ
+        fishing_bite: None,
         moving_encounter: None,
         special_encounter: None,
         encounter_result: EncounterResult::Pokemon,
+        season: Some(Season::Spring),
     }
 }
 